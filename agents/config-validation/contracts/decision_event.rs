@@ -12,10 +12,10 @@
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use uuid::Uuid;
 
-use super::{IssueSeverity, ValidationIssue, ValidationOutput};
+use super::{DecisionVerdict, IssueCounts, IssueSeverity, ValidationIssue, ValidationOutput, VerdictThresholds};
 
 /// Decision event for ruvector-service integration
 ///
@@ -49,6 +49,10 @@ pub struct DecisionEvent {
     /// - Input quality (presence of schema, context)
     pub confidence: f64,
 
+    /// Allow/block verdict derived from the outputs
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verdict: Option<DecisionVerdict>,
+
     /// List of constraints that were applied during validation
     pub constraints_applied: Vec<String>,
 
@@ -92,15 +96,19 @@ impl DecisionEvent {
             inputs_hash,
             outputs: ValidationOutputs::from_output(output),
             confidence: output.confidence(),
+            verdict: Some(VerdictThresholds::default().verdict(IssueCounts {
+                critical: 0,
+                errors: output.errors.len() as u32,
+                warnings: output.warnings.len() as u32,
+            })),
             constraints_applied: output.constraints_checked.clone(),
             execution_ref,
             timestamp: Utc::now(),
             metadata: HashMap::new(),
-            performance: Some(PerformanceMetrics {
-                duration_ms: output.duration_ms,
-                rules_evaluated: output.rules_applied.len() as u32,
-                memory_used_bytes: None,
-            }),
+            performance: Some(
+                PerformanceMetrics::new(output.duration_ms, output.rules_applied.len() as u32)
+                    .with_coverage(output.coverage),
+            ),
             correlation_ids: HashMap::new(),
         }
     }
@@ -121,6 +129,7 @@ impl DecisionEvent {
             inputs_hash,
             outputs,
             confidence: confidence.clamp(0.0, 1.0),
+            verdict: None,
             constraints_applied: Vec::new(),
             execution_ref,
             timestamp: Utc::now(),
@@ -142,6 +151,12 @@ impl DecisionEvent {
         self
     }
 
+    /// Set the allow/block verdict
+    pub fn with_verdict(mut self, verdict: DecisionVerdict) -> Self {
+        self.verdict = Some(verdict);
+        self
+    }
+
     /// Add performance metrics
     pub fn with_performance(mut self, metrics: PerformanceMetrics) -> Self {
         self.performance = Some(metrics);
@@ -252,6 +267,10 @@ pub struct ValidationOutputs {
     /// Fields that were skipped (no rules applied)
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub fields_skipped: Vec<String>,
+
+    /// Issue counts by severity, category, and code
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rollup: Option<IssueRollup>,
 }
 
 impl ValidationOutputs {
@@ -274,6 +293,7 @@ impl ValidationOutputs {
             rules_applied: output.rules_applied.clone(),
             fields_validated: Vec::new(), // Would be populated during validation
             fields_skipped: Vec::new(),
+            rollup: None,
         }
     }
 
@@ -291,6 +311,7 @@ impl ValidationOutputs {
             rules_applied,
             fields_validated: Vec::new(),
             fields_skipped: Vec::new(),
+            rollup: None,
         }
     }
 
@@ -309,10 +330,58 @@ impl ValidationOutputs {
             rules_applied: Vec::new(),
             fields_validated: Vec::new(),
             fields_skipped: Vec::new(),
+            rollup: None,
         }
     }
 }
 
+/// Rollup of a decision's issues for analytics
+///
+/// Lets consumers chart issues by severity, category, and code without
+/// re-deriving the counts from the individual issues.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct IssueRollup {
+    /// Total number of issues
+    pub total: u32,
+
+    /// Issue counts by severity (e.g. `critical`, `error`)
+    #[serde(default)]
+    pub by_severity: BTreeMap<String, u32>,
+
+    /// Issue counts by rule category (e.g. `security`, `bounds`)
+    #[serde(default)]
+    pub by_category: BTreeMap<String, u32>,
+
+    /// Most frequent issue codes, most frequent first
+    #[serde(default)]
+    pub top_codes: Vec<CodeCount>,
+}
+
+/// Number of issues reported under one code
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CodeCount {
+    /// Issue code
+    pub code: String,
+
+    /// Number of issues with this code
+    pub count: u32,
+}
+
+impl IssueRollup {
+    /// Keep the `n` most frequent codes from per-code counts
+    ///
+    /// Ties are broken by code so the result is deterministic.
+    pub fn top_codes(counts: BTreeMap<String, u32>, n: usize) -> Vec<CodeCount> {
+        let mut codes: Vec<CodeCount> = counts
+            .into_iter()
+            .map(|(code, count)| CodeCount { code, count })
+            .collect();
+        codes.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.code.cmp(&b.code)));
+        codes.truncate(n);
+        codes
+    }
+}
+
 /// Summarized issue for analytics
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IssueSummary {
@@ -365,6 +434,22 @@ pub struct PerformanceMetrics {
     /// Memory used during validation (if available)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub memory_used_bytes: Option<u64>,
+
+    /// Validation coverage (0.0-1.0)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub coverage: Option<f64>,
+
+    /// Estimated size of the decision outputs in tokens
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub estimated_tokens: Option<u32>,
+
+    /// Share of the token budget used by the outputs (1.0 = at budget)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token_budget_utilization: Option<f64>,
+
+    /// Share of the latency budget used by validation (1.0 = at budget)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub latency_budget_utilization: Option<f64>,
 }
 
 impl PerformanceMetrics {
@@ -374,6 +459,10 @@ impl PerformanceMetrics {
             duration_ms,
             rules_evaluated,
             memory_used_bytes: None,
+            coverage: None,
+            estimated_tokens: None,
+            token_budget_utilization: None,
+            latency_budget_utilization: None,
         }
     }
 
@@ -382,6 +471,26 @@ impl PerformanceMetrics {
         self.memory_used_bytes = Some(bytes);
         self
     }
+
+    /// Set validation coverage
+    pub fn with_coverage(mut self, coverage: f64) -> Self {
+        self.coverage = Some(coverage.clamp(0.0, 1.0));
+        self
+    }
+
+    /// Record budget utilization against the agent's token and latency budgets
+    ///
+    /// Utilization is not capped, so values above 1.0 show a budget overrun.
+    pub fn with_budgets(mut self, estimated_tokens: u32, max_tokens: u32, max_latency_ms: u64) -> Self {
+        self.estimated_tokens = Some(estimated_tokens);
+        if max_tokens > 0 {
+            self.token_budget_utilization = Some(estimated_tokens as f64 / max_tokens as f64);
+        }
+        if max_latency_ms > 0 {
+            self.latency_budget_utilization = Some(self.duration_ms as f64 / max_latency_ms as f64);
+        }
+        self
+    }
 }
 
 /// Batch of decision events for bulk submission
@@ -630,4 +739,17 @@ mod tests {
         assert_eq!(metrics.rules_evaluated, 10);
         assert_eq!(metrics.memory_used_bytes, Some(1024 * 1024));
     }
+
+    #[test]
+    fn test_performance_metrics_budgets() {
+        let metrics = PerformanceMetrics::new(750, 10).with_budgets(200, 800, 1500);
+
+        assert_eq!(metrics.estimated_tokens, Some(200));
+        assert_eq!(metrics.token_budget_utilization, Some(0.25));
+        assert_eq!(metrics.latency_budget_utilization, Some(0.5));
+
+        // Unset budget fields are omitted from the wire format
+        let json = serde_json::to_value(PerformanceMetrics::new(1, 1)).unwrap();
+        assert!(json.get("token_budget_utilization").is_none());
+    }
 }
@@ -21,6 +21,12 @@
 
 pub mod schemas;
 pub mod decision_event;
+#[path = "../../shared/environment.rs"]
+pub mod environment;
+#[path = "../../shared/verdict.rs"]
+pub mod verdict;
+#[path = "../../shared/severity.rs"]
+pub mod severity;
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -30,9 +36,16 @@ use uuid::Uuid;
 // Re-export core types
 pub use schemas::{
     ConfigSchema, FieldRule, FieldType, EnvironmentRule, CompatibilityRule,
-    SchemaDefinition, ValidationConstraint, DeprecationInfo,
+    SchemaCoverageReport, SchemaDefinition, SchemaImportError, SchemaImportWarning,
+    ValidationConstraint, DeprecationInfo,
 };
-pub use decision_event::{DecisionEvent, ValidationOutputs};
+pub use decision_event::{
+    CodeCount, DecisionEvent, DecisionType, IssueRollup, IssueSummary, PerformanceMetrics,
+    ValidationOutputs,
+};
+pub use environment::{EnvironmentRef, ParseEnvironmentError};
+pub use verdict::{DecisionVerdict, IssueCounts, VerdictThresholds};
+pub use severity::Severity;
 
 /// Input for configuration validation
 ///
@@ -123,7 +136,7 @@ impl ValidationInput {
         let mut hasher = DefaultHasher::new();
         self.namespace.hash(&mut hasher);
         self.key.hash(&mut hasher);
-        format!("{:?}", self.value).hash(&mut hasher);
+        crate::normalize::canonical_bytes(&self.value).hash(&mut hasher);
         format!("{:?}", self.environment).hash(&mut hasher);
         format!("{:x}", hasher.finish())
     }
@@ -166,29 +179,6 @@ impl ConfigValueRef {
     }
 }
 
-/// Reference to an environment (mirrors llm-config-storage Environment)
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
-pub enum EnvironmentRef {
-    Base,
-    Development,
-    Staging,
-    Production,
-    Edge,
-}
-
-impl std::fmt::Display for EnvironmentRef {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            EnvironmentRef::Base => write!(f, "base"),
-            EnvironmentRef::Development => write!(f, "development"),
-            EnvironmentRef::Staging => write!(f, "staging"),
-            EnvironmentRef::Production => write!(f, "production"),
-            EnvironmentRef::Edge => write!(f, "edge"),
-        }
-    }
-}
-
 /// Reference to a validation rule
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RuleRef {
@@ -424,16 +414,7 @@ impl ValidationIssue {
 }
 
 /// Severity level for validation issues
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
-pub enum IssueSeverity {
-    /// Blocking error - validation fails
-    Error,
-    /// Non-blocking warning
-    Warning,
-    /// Informational message
-    Info,
-}
+pub type IssueSeverity = Severity;
 
 /// Trait for implementing validation rules
 ///
@@ -535,6 +516,35 @@ mod tests {
         assert_eq!(format!("{}", EnvironmentRef::Development), "development");
     }
 
+    #[test]
+    fn test_environment_ref_parses_canonical_names() {
+        for env in EnvironmentRef::ALL {
+            assert_eq!(env.to_string().parse::<EnvironmentRef>(), Ok(env));
+        }
+        assert_eq!(" Staging ".parse::<EnvironmentRef>(), Ok(EnvironmentRef::Staging));
+    }
+
+    #[test]
+    fn test_environment_ref_parses_aliases() {
+        assert_eq!("prod".parse::<EnvironmentRef>(), Ok(EnvironmentRef::Production));
+        assert_eq!("DEV".parse::<EnvironmentRef>(), Ok(EnvironmentRef::Development));
+        assert_eq!("stage".parse::<EnvironmentRef>(), Ok(EnvironmentRef::Staging));
+
+        let env: EnvironmentRef = serde_json::from_str("\"prod\"").unwrap();
+        assert_eq!(env, EnvironmentRef::Production);
+    }
+
+    #[test]
+    fn test_environment_ref_parse_error_lists_valid_values() {
+        let err = "moon".parse::<EnvironmentRef>().unwrap_err();
+        assert_eq!(err.input, "moon");
+        assert_eq!(
+            err.to_string(),
+            "Unknown environment 'moon'; expected one of: base, development (dev), \
+             staging (stage), production (prod), edge"
+        );
+    }
+
     #[test]
     fn test_validation_output_confidence() {
         let request_id = Uuid::new_v4();
@@ -86,6 +86,180 @@ impl ConfigSchema {
     pub fn has_deprecated_fields(&self) -> bool {
         self.fields.values().any(|f| f.deprecation.is_some())
     }
+
+    /// Measure how completely the schema describes its fields
+    ///
+    /// Nested fields are counted under their dotted paths.
+    pub fn coverage_report(&self) -> SchemaCoverageReport {
+        let mut fields = Vec::new();
+        collect_field_paths(&self.fields, None, &mut fields);
+        fields.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        let mut report = SchemaCoverageReport {
+            schema_id: self.id.clone(),
+            total_fields: fields.len(),
+            ..SchemaCoverageReport::default()
+        };
+        for (path, rule) in &fields {
+            if rule.is_constrained() {
+                report.constrained_fields += 1;
+            } else {
+                report.unconstrained.push(path.clone());
+            }
+            if rule.description.as_deref().is_some_and(|d| !d.trim().is_empty()) {
+                report.described_fields += 1;
+            } else {
+                report.undescribed.push(path.clone());
+            }
+            if self
+                .environment_rules
+                .iter()
+                .any(|r| r.affected_fields.contains(path))
+            {
+                report.environment_covered_fields += 1;
+            } else {
+                report.without_environment_rules.push(path.clone());
+            }
+        }
+        report
+    }
+
+    /// Export the schema as a JSON Schema (Draft 7) document
+    ///
+    /// The result is an object schema with one property per field and the
+    /// required fields listed in `required`, nested objects and array items
+    /// included. Constraints JSON Schema cannot express, such as custom and
+    /// reference constraints, are left out, so the export may accept values
+    /// the schema rejects. It is not meant to be read back.
+    pub fn to_json_schema(&self) -> serde_json::Value {
+        let mut schema = serde_json::Map::new();
+        schema.insert("$schema".to_string(), JSON_SCHEMA_DRAFT_07.into());
+        schema.insert("title".to_string(), self.name.clone().into());
+        if let Some(description) = &self.description {
+            schema.insert("description".to_string(), description.clone().into());
+        }
+        schema.insert("type".to_string(), "object".into());
+        insert_properties(&mut schema, &self.fields);
+        serde_json::Value::Object(schema)
+    }
+}
+
+/// Meta-schema URI of JSON Schema Draft 7
+const JSON_SCHEMA_DRAFT_07: &str = "http://json-schema.org/draft-07/schema#";
+
+/// Add `properties` and `required` for `fields` to an object schema
+fn insert_properties(
+    schema: &mut serde_json::Map<String, serde_json::Value>,
+    fields: &HashMap<String, FieldRule>,
+) {
+    let properties: serde_json::Map<String, serde_json::Value> = fields
+        .iter()
+        .map(|(key, rule)| (key.clone(), rule.to_json_schema()))
+        .collect();
+    let mut required: Vec<&String> = fields
+        .iter()
+        .filter(|(_, rule)| rule.required)
+        .map(|(key, _)| key)
+        .collect();
+    required.sort();
+
+    schema.insert("properties".to_string(), serde_json::Value::Object(properties));
+    if !required.is_empty() {
+        schema.insert("required".to_string(), serde_json::json!(required));
+    }
+}
+
+/// Add a keyword to a schema, moving it into `allOf` if already present
+///
+/// Lets several constraints map to the same keyword, e.g. two patterns,
+/// with all of them applying.
+fn insert_keyword(
+    schema: &mut serde_json::Map<String, serde_json::Value>,
+    keyword: &str,
+    value: serde_json::Value,
+) {
+    if !schema.contains_key(keyword) {
+        schema.insert(keyword.to_string(), value);
+        return;
+    }
+    let all_of = schema
+        .entry("allOf")
+        .or_insert_with(|| serde_json::Value::Array(Vec::new()));
+    if let Some(all_of) = all_of.as_array_mut() {
+        all_of.push(serde_json::json!({ keyword: value }));
+    }
+}
+
+/// Gather every field with its dotted path
+fn collect_field_paths<'a>(
+    fields: &'a HashMap<String, FieldRule>,
+    prefix: Option<&str>,
+    out: &mut Vec<(String, &'a FieldRule)>,
+) {
+    for (key, rule) in fields {
+        let path = match prefix {
+            Some(prefix) => format!("{}.{}", prefix, key),
+            None => key.clone(),
+        };
+        collect_field_paths(&rule.nested_fields, Some(&path), out);
+        out.push((path, rule));
+    }
+}
+
+/// How completely a schema describes its fields
+///
+/// Produced by [`ConfigSchema::coverage_report`]. The field lists name the
+/// fields missing each kind of coverage, sorted by path.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SchemaCoverageReport {
+    /// Schema the report is for
+    pub schema_id: String,
+
+    /// Number of fields, including nested ones
+    pub total_fields: usize,
+
+    /// Fields with at least one constraint beyond their type
+    pub constrained_fields: usize,
+
+    /// Fields with a non-empty description
+    pub described_fields: usize,
+
+    /// Fields named by at least one environment rule
+    pub environment_covered_fields: usize,
+
+    /// Fields with only a bare type
+    pub unconstrained: Vec<String>,
+
+    /// Fields without a description
+    pub undescribed: Vec<String>,
+
+    /// Fields no environment rule applies to
+    pub without_environment_rules: Vec<String>,
+}
+
+impl SchemaCoverageReport {
+    fn ratio(&self, covered: usize) -> f64 {
+        if self.total_fields == 0 {
+            0.0
+        } else {
+            covered as f64 / self.total_fields as f64
+        }
+    }
+
+    /// Fraction of fields with constraints beyond their type
+    pub fn constraint_coverage(&self) -> f64 {
+        self.ratio(self.constrained_fields)
+    }
+
+    /// Fraction of fields with a description
+    pub fn description_coverage(&self) -> f64 {
+        self.ratio(self.described_fields)
+    }
+
+    /// Fraction of fields covered by environment rules
+    pub fn environment_coverage(&self) -> f64 {
+        self.ratio(self.environment_covered_fields)
+    }
 }
 
 /// Schema metadata
@@ -237,6 +411,51 @@ impl FieldRule {
         self.array_item_rule = Some(Box::new(rule));
         self
     }
+
+    /// Whether the field is restricted beyond its type
+    ///
+    /// Constraints, allowed values, custom rules, and item rules for arrays
+    /// all count.
+    pub fn is_constrained(&self) -> bool {
+        !self.constraints.is_empty()
+            || !self.allowed_values.is_empty()
+            || !self.custom_rules.is_empty()
+            || self.array_item_rule.is_some()
+    }
+
+    /// Export the field as a JSON Schema (Draft 7) subschema
+    ///
+    /// See [`ConfigSchema::to_json_schema`].
+    pub fn to_json_schema(&self) -> serde_json::Value {
+        let mut schema = self.field_type.to_json_schema();
+        if let Some(description) = &self.description {
+            schema.insert("description".to_string(), description.clone().into());
+        }
+        if let Some(default) = &self.default {
+            schema.insert("default".to_string(), default.clone());
+        }
+        if !self.allowed_values.is_empty() {
+            schema.insert("enum".to_string(), self.allowed_values.clone().into());
+        }
+        if !self.examples.is_empty() {
+            schema.insert("examples".to_string(), self.examples.clone().into());
+        }
+
+        match self.field_type {
+            FieldType::Object => insert_properties(&mut schema, &self.nested_fields),
+            FieldType::Array => {
+                if let Some(items) = &self.array_item_rule {
+                    schema.insert("items".to_string(), items.to_json_schema());
+                }
+            }
+            _ => {}
+        }
+
+        for constraint in &self.constraints {
+            constraint.add_to_json_schema(&self.field_type, &mut schema);
+        }
+        serde_json::Value::Object(schema)
+    }
 }
 
 /// Field type enumeration
@@ -299,6 +518,48 @@ impl FieldType {
             FieldType::Timestamp => "timestamp",
         }
     }
+
+    /// JSON Schema `type` and `format` keywords for values of this type
+    ///
+    /// Secrets may be stored encrypted as an object or as an `enc:` string;
+    /// `Any` has no keywords. IP addresses may be either version.
+    fn to_json_schema(&self) -> serde_json::Map<String, serde_json::Value> {
+        let (json_type, format) = match self {
+            FieldType::Integer => ("integer", None),
+            FieldType::Float => ("number", None),
+            FieldType::Boolean => ("boolean", None),
+            FieldType::Array => ("array", None),
+            FieldType::Object => ("object", None),
+            FieldType::Url => ("string", Some("uri")),
+            FieldType::Email => ("string", Some("email")),
+            FieldType::Regex => ("string", Some("regex")),
+            FieldType::Timestamp => ("string", Some("date-time")),
+            FieldType::String
+            | FieldType::Duration
+            | FieldType::FilePath
+            | FieldType::Json
+            | FieldType::IpAddress => ("string", None),
+            FieldType::Secret => {
+                let mut keywords = serde_json::Map::new();
+                keywords.insert("type".to_string(), serde_json::json!(["string", "object"]));
+                return keywords;
+            }
+            FieldType::Any => return serde_json::Map::new(),
+        };
+
+        let mut keywords = serde_json::Map::new();
+        keywords.insert("type".to_string(), json_type.into());
+        if let Some(format) = format {
+            keywords.insert("format".to_string(), format.into());
+        }
+        if *self == FieldType::IpAddress {
+            keywords.insert(
+                "anyOf".to_string(),
+                serde_json::json!([{"format": "ipv4"}, {"format": "ipv6"}]),
+            );
+        }
+        keywords
+    }
 }
 
 /// Validation constraint for field values
@@ -413,6 +674,57 @@ impl ValidationConstraint {
             Self::Reference { key_pattern, .. } => format!("must reference: {}", key_pattern),
         }
     }
+
+    /// Add the JSON Schema keywords for this constraint on a field of type
+    /// `field_type` to `schema`
+    ///
+    /// Lengths count items for arrays and properties for objects. Substring,
+    /// custom, conditional and reference constraints have no Draft 7
+    /// equivalent and add nothing.
+    fn add_to_json_schema(
+        &self,
+        field_type: &FieldType,
+        schema: &mut serde_json::Map<String, serde_json::Value>,
+    ) {
+        let (min_length, max_length) = match field_type {
+            FieldType::Array => ("minItems", "maxItems"),
+            FieldType::Object => ("minProperties", "maxProperties"),
+            _ => ("minLength", "maxLength"),
+        };
+        let minimum = |inclusive: bool| if inclusive { "minimum" } else { "exclusiveMinimum" };
+        let maximum = |inclusive: bool| if inclusive { "maximum" } else { "exclusiveMaximum" };
+
+        match self {
+            Self::Min { value, inclusive } => insert_keyword(schema, minimum(*inclusive), (*value).into()),
+            Self::Max { value, inclusive } => insert_keyword(schema, maximum(*inclusive), (*value).into()),
+            Self::Range { min, max, inclusive } => {
+                insert_keyword(schema, minimum(*inclusive), (*min).into());
+                insert_keyword(schema, maximum(*inclusive), (*max).into());
+            }
+            Self::MinLength { length } => insert_keyword(schema, min_length, (*length).into()),
+            Self::MaxLength { length } => insert_keyword(schema, max_length, (*length).into()),
+            Self::Length { length } => {
+                insert_keyword(schema, min_length, (*length).into());
+                insert_keyword(schema, max_length, (*length).into());
+            }
+            Self::Pattern { regex, .. } => insert_keyword(schema, "pattern", regex.clone().into()),
+            Self::NotEmpty => insert_keyword(schema, min_length, 1.into()),
+            Self::UniqueItems => insert_keyword(schema, "uniqueItems", true.into()),
+            Self::OneOf { types } => {
+                let branches: Vec<serde_json::Value> = types
+                    .iter()
+                    .map(|t| serde_json::Value::Object(t.to_json_schema()))
+                    .collect();
+                insert_keyword(schema, "anyOf", branches.into());
+            }
+            Self::StartsWith { .. }
+            | Self::EndsWith { .. }
+            | Self::Contains { .. }
+            | Self::Custom { .. }
+            | Self::Conditional { .. }
+            | Self::Reference { .. } => {}
+        }
+    }
 }
 
 /// Information about deprecated fields
@@ -558,6 +870,40 @@ impl EnvironmentRule {
         }
     }
 
+    /// Create a must-differ rule
+    pub fn must_differ(
+        id: impl Into<String>,
+        environments: Vec<String>,
+        from_environments: Vec<String>,
+        fields: Vec<String>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            environments,
+            description: None,
+            affected_fields: fields,
+            rule_type: EnvironmentRuleType::MustDiffer { from_environments },
+            blocking: true,
+        }
+    }
+
+    /// Create a rotation-required rule
+    pub fn rotation_required(
+        id: impl Into<String>,
+        environments: Vec<String>,
+        max_age_days: u32,
+        fields: Vec<String>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            environments,
+            description: None,
+            affected_fields: fields,
+            rule_type: EnvironmentRuleType::RotationRequired { max_age_days },
+            blocking: true,
+        }
+    }
+
     /// Add description
     pub fn with_description(mut self, description: impl Into<String>) -> Self {
         self.description = Some(description.into());
@@ -711,7 +1057,7 @@ impl SchemaDefinition {
     /// Create a new schema definition
     pub fn new(schema: ConfigSchema) -> Self {
         Self {
-            schema_format_version: "1.0".to_string(),
+            schema_format_version: Self::CURRENT_FORMAT_VERSION.to_string(),
             schema,
             checksum: None,
             signature: None,
@@ -720,6 +1066,641 @@ impl SchemaDefinition {
 
     /// Current schema format version
     pub const CURRENT_FORMAT_VERSION: &'static str = "1.0";
+
+    /// Oldest schema format version still read
+    pub const MIN_FORMAT_VERSION: &'static str = "0.9";
+
+    /// Check that a schema format version can be read
+    ///
+    /// Returns a warning for versions older than the current one, and an
+    /// error naming the supported range for versions outside it, including
+    /// formats newer than this build understands.
+    pub fn check_format_version(version: &str) -> Result<Option<String>, String> {
+        let parse = |v: &str| -> Option<(u64, u64)> {
+            let (major, minor) = v.trim().split_once('.').unwrap_or((v.trim(), "0"));
+            Some((major.parse().ok()?, minor.parse().ok()?))
+        };
+        let supported = format!(
+            "supported versions are {} through {}",
+            Self::MIN_FORMAT_VERSION,
+            Self::CURRENT_FORMAT_VERSION
+        );
+
+        let found = parse(version).ok_or_else(|| {
+            format!("Invalid schema format version '{}'; {}", version, supported)
+        })?;
+        let min = parse(Self::MIN_FORMAT_VERSION).expect("valid format version");
+        let current = parse(Self::CURRENT_FORMAT_VERSION).expect("valid format version");
+
+        if found > current || found < min {
+            Err(format!("Unsupported schema format version '{}'; {}", version, supported))
+        } else if found < current {
+            Ok(Some(format!(
+                "Schema format version '{}' is older than the current {} and should be upgraded",
+                version,
+                Self::CURRENT_FORMAT_VERSION
+            )))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+/// Strict reading of schema documents
+///
+/// These types mirror the shape of the schema types with
+/// `deny_unknown_fields`, so that misspelled keys, which the lenient types
+/// ignore, become errors. Leaf values are not checked here; the lenient types
+/// still do that when the schema is read.
+// Only the shape is checked; the fields are never read
+#[allow(dead_code)]
+mod strict {
+    use serde::de::IgnoredAny;
+    use serde::Deserialize;
+    use std::collections::HashMap;
+
+    #[derive(Deserialize)]
+    #[serde(deny_unknown_fields)]
+    pub struct SchemaDefinition {
+        pub schema_format_version: Option<IgnoredAny>,
+        pub schema: Option<ConfigSchema>,
+        pub checksum: Option<IgnoredAny>,
+        pub signature: Option<IgnoredAny>,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(deny_unknown_fields)]
+    pub struct ConfigSchema {
+        pub id: Option<IgnoredAny>,
+        pub version: Option<IgnoredAny>,
+        pub name: Option<IgnoredAny>,
+        pub description: Option<IgnoredAny>,
+        pub fields: Option<HashMap<String, FieldRule>>,
+        pub environment_rules: Option<Vec<EnvironmentRule>>,
+        pub compatibility_rules: Option<Vec<CompatibilityRule>>,
+        pub metadata: Option<SchemaMetadata>,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(deny_unknown_fields)]
+    pub struct SchemaMetadata {
+        pub created_at: Option<IgnoredAny>,
+        pub updated_at: Option<IgnoredAny>,
+        pub author: Option<IgnoredAny>,
+        pub tags: Option<IgnoredAny>,
+        pub documentation_url: Option<IgnoredAny>,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(deny_unknown_fields)]
+    pub struct FieldRule {
+        pub field_type: Option<IgnoredAny>,
+        pub required: Option<IgnoredAny>,
+        pub description: Option<IgnoredAny>,
+        pub default: Option<IgnoredAny>,
+        pub constraints: Option<Vec<ValidationConstraint>>,
+        pub allowed_values: Option<IgnoredAny>,
+        pub deprecation: Option<DeprecationInfo>,
+        pub sensitive: Option<IgnoredAny>,
+        pub examples: Option<IgnoredAny>,
+        pub custom_rules: Option<IgnoredAny>,
+        pub nested_fields: Option<HashMap<String, FieldRule>>,
+        pub array_item_rule: Option<Box<FieldRule>>,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+    pub enum ValidationConstraint {
+        Min { value: Option<IgnoredAny>, inclusive: Option<IgnoredAny> },
+        Max { value: Option<IgnoredAny>, inclusive: Option<IgnoredAny> },
+        Range { min: Option<IgnoredAny>, max: Option<IgnoredAny>, inclusive: Option<IgnoredAny> },
+        MinLength { length: Option<IgnoredAny> },
+        MaxLength { length: Option<IgnoredAny> },
+        Length { length: Option<IgnoredAny> },
+        Pattern { regex: Option<IgnoredAny>, description: Option<IgnoredAny> },
+        StartsWith { prefix: Option<IgnoredAny> },
+        EndsWith { suffix: Option<IgnoredAny> },
+        Contains { substring: Option<IgnoredAny> },
+        NotEmpty {},
+        UniqueItems {},
+        Custom { expression: Option<IgnoredAny>, message: Option<IgnoredAny> },
+        Conditional {
+            condition: Option<IgnoredAny>,
+            then_constraint: Option<Box<ValidationConstraint>>,
+            else_constraint: Option<Box<ValidationConstraint>>,
+        },
+        OneOf { types: Option<IgnoredAny> },
+        Reference { namespace: Option<IgnoredAny>, key_pattern: Option<IgnoredAny> },
+    }
+
+    #[derive(Deserialize)]
+    #[serde(deny_unknown_fields)]
+    pub struct DeprecationInfo {
+        pub since_version: Option<IgnoredAny>,
+        pub reason: Option<IgnoredAny>,
+        pub replacement: Option<IgnoredAny>,
+        pub removal_version: Option<IgnoredAny>,
+        pub migration_guide_url: Option<IgnoredAny>,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(deny_unknown_fields)]
+    pub struct EnvironmentRule {
+        pub id: Option<IgnoredAny>,
+        pub environments: Option<IgnoredAny>,
+        pub description: Option<IgnoredAny>,
+        pub affected_fields: Option<IgnoredAny>,
+        pub rule_type: Option<EnvironmentRuleType>,
+        pub blocking: Option<IgnoredAny>,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+    pub enum EnvironmentRuleType {
+        RequiredIn {},
+        ForbiddenIn {},
+        ConstraintOverride { constraints: Option<Vec<ValidationConstraint>> },
+        MustDiffer { from_environments: Option<IgnoredAny> },
+        MustEncrypt {},
+        RotationRequired { max_age_days: Option<IgnoredAny> },
+        Custom { expression: Option<IgnoredAny>, message: Option<IgnoredAny> },
+    }
+
+    #[derive(Deserialize)]
+    #[serde(deny_unknown_fields)]
+    pub struct CompatibilityRule {
+        pub id: Option<IgnoredAny>,
+        pub description: Option<IgnoredAny>,
+        pub target_service: Option<IgnoredAny>,
+        pub target_version: Option<IgnoredAny>,
+        pub requirement: Option<CompatibilityRequirement>,
+        pub blocking: Option<IgnoredAny>,
+        pub documentation_url: Option<IgnoredAny>,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+    pub enum CompatibilityRequirement {
+        RequiresField { field: Option<IgnoredAny> },
+        RequiresFormat { field: Option<IgnoredAny>, format: Option<IgnoredAny> },
+        AllowedValues { field: Option<IgnoredAny>, values: Option<IgnoredAny> },
+        SchemaMatch { schema_ref: Option<IgnoredAny> },
+        Custom { expression: Option<IgnoredAny>, message: Option<IgnoredAny> },
+        VersionRange {
+            field: Option<IgnoredAny>,
+            min_version: Option<IgnoredAny>,
+            max_version: Option<IgnoredAny>,
+        },
+        ProtocolVersion {
+            field: Option<IgnoredAny>,
+            protocol: Option<IgnoredAny>,
+            min_version: Option<IgnoredAny>,
+        },
+    }
+
+    /// Locations of the keys `T` does not know, e.g. `fields.host.requird`
+    ///
+    /// Deserialization stops at the first unknown key, so each one found is
+    /// removed and the document read again until none remain. Errors other
+    /// than unknown keys end the search; the lenient read reports them.
+    pub fn unknown_keys<T: serde::de::DeserializeOwned>(value: &serde_json::Value) -> Vec<String> {
+        use serde_path_to_error::Segment;
+
+        let mut value = value.clone();
+        let mut unknown = Vec::new();
+        loop {
+            let err = match serde_path_to_error::deserialize::<_, T>(&value) {
+                Ok(_) => break,
+                Err(err) => err,
+            };
+            let message = err.inner().to_string();
+            let Some(key) = message
+                .strip_prefix("unknown field `")
+                .and_then(|rest| rest.split('`').next())
+            else {
+                break;
+            };
+
+            // The path ends with the unknown key, except inside internally
+            // tagged enums where it ends at the enum; walk to the object
+            // holding the key
+            let mut segments: Vec<&Segment> = err.path().iter().collect();
+            if matches!(segments.last(), Some(Segment::Map { key: last }) if last == key) {
+                segments.pop();
+            }
+            let mut location = String::new();
+            let mut parent = Some(&mut value);
+            for segment in segments {
+                parent = match (segment, parent) {
+                    (Segment::Map { key }, Some(serde_json::Value::Object(map))) => {
+                        location.push_str(key);
+                        location.push('.');
+                        map.get_mut(key.as_str())
+                    }
+                    (Segment::Seq { index }, Some(serde_json::Value::Array(items))) => {
+                        if location.ends_with('.') {
+                            location.pop();
+                        }
+                        location.push_str(&format!("[{}].", index));
+                        items.get_mut(*index)
+                    }
+                    (Segment::Enum { .. }, parent) => parent,
+                    _ => None,
+                };
+            }
+            let Some(serde_json::Value::Object(map)) = parent else {
+                break;
+            };
+            if map.remove(key).is_none() {
+                break;
+            }
+            location.push_str(key);
+            unknown.push(location);
+        }
+        unknown
+    }
+}
+
+impl ConfigSchema {
+    /// Keys in a schema document that no schema type defines
+    ///
+    /// Lenient reading ignores unknown keys so older builds can read newer
+    /// schemas; strict reading uses this to catch misspellings such as
+    /// `requird`. Each key is given with its location, e.g.
+    /// `fields.host.requird`.
+    pub fn unknown_keys(value: &serde_json::Value) -> Vec<String> {
+        strict::unknown_keys::<strict::ConfigSchema>(value)
+    }
+}
+
+impl SchemaDefinition {
+    /// Keys in a schema definition document that no schema type defines
+    ///
+    /// See [`ConfigSchema::unknown_keys`].
+    pub fn unknown_keys(value: &serde_json::Value) -> Vec<String> {
+        strict::unknown_keys::<strict::SchemaDefinition>(value)
+    }
+}
+
+/// Error returned when a JSON Schema cannot be imported as a `ConfigSchema`
+///
+/// Produced by [`ConfigSchema::from_json_schema`]. Each variant gives the
+/// JSON pointer of the offending schema, e.g. `#/properties/port`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SchemaImportError {
+    /// A schema is not a JSON object
+    NotAnObject { pointer: String },
+    /// A keyword has a value this importer cannot use
+    InvalidKeyword {
+        pointer: String,
+        keyword: String,
+        expected: String,
+    },
+}
+
+impl std::fmt::Display for SchemaImportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotAnObject { pointer } => {
+                write!(f, "Schema at {} must be a JSON object", pointer)
+            }
+            Self::InvalidKeyword {
+                pointer,
+                keyword,
+                expected,
+            } => write!(f, "Invalid schema at {}: '{}' must be {}", pointer, keyword, expected),
+        }
+    }
+}
+
+impl std::error::Error for SchemaImportError {}
+
+/// A JSON Schema keyword dropped on import because `ConfigSchema` cannot
+/// represent it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SchemaImportWarning {
+    /// JSON pointer of the schema holding the keyword
+    pub pointer: String,
+    /// The dropped keyword
+    pub keyword: String,
+}
+
+impl std::fmt::Display for SchemaImportWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Ignored unsupported keyword '{}' at {}", self.keyword, self.pointer)
+    }
+}
+
+/// Root keywords the importer reads
+const IMPORTED_ROOT_KEYWORDS: &[&str] = &[
+    "$schema",
+    "$id",
+    "title",
+    "description",
+    "type",
+    "properties",
+    "required",
+];
+
+/// Field keywords the importer reads
+const IMPORTED_FIELD_KEYWORDS: &[&str] = &[
+    "type",
+    "format",
+    "description",
+    "default",
+    "examples",
+    "enum",
+    "properties",
+    "required",
+    "items",
+    "minLength",
+    "maxLength",
+    "minItems",
+    "maxItems",
+    "minimum",
+    "maximum",
+    "exclusiveMinimum",
+    "exclusiveMaximum",
+    "pattern",
+    "uniqueItems",
+];
+
+impl ConfigSchema {
+    /// Build a schema from a JSON Schema document
+    ///
+    /// See [`ConfigSchema::from_json_schema_with_warnings`], which also
+    /// reports the keywords that were dropped.
+    pub fn from_json_schema(value: &serde_json::Value) -> Result<ConfigSchema, SchemaImportError> {
+        Self::from_json_schema_with_warnings(value).map(|(schema, _)| schema)
+    }
+
+    /// Build a schema from a JSON Schema document, with a warning for each
+    /// keyword that was dropped
+    ///
+    /// The root must be an object schema. Its properties become fields,
+    /// nested object properties become nested fields, and `items` becomes
+    /// the array item rule. `type` and `format` set the field type, `enum`
+    /// the allowed values, and the length, bound, `pattern` and
+    /// `uniqueItems` keywords become constraints. Properties listed in
+    /// `required` but not defined become required fields of any type.
+    ///
+    /// The schema id is taken from `$id`, or else `title`; the name from
+    /// `title`, or else the id. The version is `1.0.0`. Other keywords, such
+    /// as `$ref` and `anyOf`, are ignored with a warning.
+    pub fn from_json_schema_with_warnings(
+        value: &serde_json::Value,
+    ) -> Result<(ConfigSchema, Vec<SchemaImportWarning>), SchemaImportError> {
+        let root = value.as_object().ok_or_else(|| SchemaImportError::NotAnObject {
+            pointer: "#".to_string(),
+        })?;
+        if root.get("type").is_some_and(|t| t != "object") {
+            return Err(invalid_keyword("#", "type", "\"object\" at the root"));
+        }
+
+        let text = |keyword: &str| match root.get(keyword) {
+            None => Ok(None),
+            Some(serde_json::Value::String(s)) => Ok(Some(s.clone())),
+            Some(_) => Err(invalid_keyword("#", keyword, "a string")),
+        };
+        let title = text("title")?;
+        let id = text("$id")?
+            .or_else(|| title.clone())
+            .unwrap_or_else(|| "imported".to_string());
+        let name = title.unwrap_or_else(|| id.clone());
+
+        let mut warnings = Vec::new();
+        let mut schema = ConfigSchema::new(id, name, "1.0.0");
+        schema.description = text("description")?;
+        schema.fields = import_properties(root, "#", &mut warnings)?;
+        warn_unsupported(root, "#", IMPORTED_ROOT_KEYWORDS, &mut warnings);
+        Ok((schema, warnings))
+    }
+}
+
+fn invalid_keyword(pointer: &str, keyword: &str, expected: &str) -> SchemaImportError {
+    SchemaImportError::InvalidKeyword {
+        pointer: pointer.to_string(),
+        keyword: keyword.to_string(),
+        expected: expected.to_string(),
+    }
+}
+
+/// Warn about every keyword of `schema` not in `supported`, in key order
+fn warn_unsupported(
+    schema: &serde_json::Map<String, serde_json::Value>,
+    pointer: &str,
+    supported: &[&str],
+    warnings: &mut Vec<SchemaImportWarning>,
+) {
+    let mut keywords: Vec<&String> = schema
+        .keys()
+        .filter(|k| !supported.contains(&k.as_str()))
+        .collect();
+    keywords.sort();
+    warnings.extend(keywords.into_iter().map(|keyword| SchemaImportWarning {
+        pointer: pointer.to_string(),
+        keyword: keyword.clone(),
+    }));
+}
+
+/// Import the `properties` and `required` keywords of an object schema
+fn import_properties(
+    schema: &serde_json::Map<String, serde_json::Value>,
+    pointer: &str,
+    warnings: &mut Vec<SchemaImportWarning>,
+) -> Result<HashMap<String, FieldRule>, SchemaImportError> {
+    let mut fields = HashMap::new();
+    match schema.get("properties") {
+        None => {}
+        Some(serde_json::Value::Object(properties)) => {
+            for (key, property) in properties {
+                let property_pointer = format!("{}/properties/{}", pointer, key);
+                fields.insert(key.clone(), import_field(property, &property_pointer, warnings)?);
+            }
+        }
+        Some(_) => return Err(invalid_keyword(pointer, "properties", "an object of schemas")),
+    }
+
+    if let Some(required) = schema.get("required") {
+        let names = required
+            .as_array()
+            .filter(|names| names.iter().all(|n| n.is_string()))
+            .ok_or_else(|| invalid_keyword(pointer, "required", "an array of property names"))?;
+        for name in names.iter().filter_map(|n| n.as_str()) {
+            fields
+                .entry(name.to_string())
+                .or_insert_with(|| FieldRule::new(FieldType::Any))
+                .required = true;
+        }
+    }
+    Ok(fields)
+}
+
+/// Field type for a JSON Schema `type` name and optional `format`
+fn import_type(name: &str, format: Option<&str>) -> Option<FieldType> {
+    Some(match (name, format) {
+        ("string", Some("email")) => FieldType::Email,
+        ("string", Some("uri" | "url")) => FieldType::Url,
+        ("string", Some("ipv4" | "ipv6")) => FieldType::IpAddress,
+        ("string", Some("date-time")) => FieldType::Timestamp,
+        ("string", Some("regex")) => FieldType::Regex,
+        ("string", _) => FieldType::String,
+        ("integer", _) => FieldType::Integer,
+        ("number", _) => FieldType::Float,
+        ("boolean", _) => FieldType::Boolean,
+        ("array", _) => FieldType::Array,
+        ("object", _) => FieldType::Object,
+        _ => return None,
+    })
+}
+
+/// Import one property schema as a field rule
+fn import_field(
+    value: &serde_json::Value,
+    pointer: &str,
+    warnings: &mut Vec<SchemaImportWarning>,
+) -> Result<FieldRule, SchemaImportError> {
+    // `true` accepts anything
+    if value == &serde_json::Value::Bool(true) {
+        return Ok(FieldRule::new(FieldType::Any));
+    }
+    let schema = value.as_object().ok_or_else(|| SchemaImportError::NotAnObject {
+        pointer: pointer.to_string(),
+    })?;
+    warn_unsupported(schema, pointer, IMPORTED_FIELD_KEYWORDS, warnings);
+
+    let format = match schema.get("format") {
+        None => None,
+        Some(serde_json::Value::String(format)) => Some(format.as_str()),
+        Some(_) => return Err(invalid_keyword(pointer, "format", "a string")),
+    };
+
+    // A list of types allows any of them; `null` has no field type
+    let mut rule = match schema.get("type") {
+        None if schema.contains_key("properties") => FieldRule::new(FieldType::Object),
+        None => FieldRule::new(FieldType::Any),
+        Some(serde_json::Value::String(name)) => match import_type(name, format) {
+            Some(field_type) => FieldRule::new(field_type),
+            None if name == "null" => FieldRule::new(FieldType::Any),
+            None => return Err(invalid_keyword(pointer, "type", "a JSON Schema type name")),
+        },
+        Some(serde_json::Value::Array(names)) => {
+            let mut types = Vec::new();
+            for name in names {
+                let name = name
+                    .as_str()
+                    .ok_or_else(|| invalid_keyword(pointer, "type", "a JSON Schema type name"))?;
+                match import_type(name, format) {
+                    Some(field_type) => types.push(field_type),
+                    None if name == "null" => {}
+                    None => return Err(invalid_keyword(pointer, "type", "a JSON Schema type name")),
+                }
+            }
+            match types.len() {
+                0 => FieldRule::new(FieldType::Any),
+                1 => FieldRule::new(types.remove(0)),
+                _ => FieldRule::new(FieldType::Any)
+                    .with_constraint(ValidationConstraint::OneOf { types }),
+            }
+        }
+        Some(_) => return Err(invalid_keyword(pointer, "type", "a type name or an array of them")),
+    };
+
+    if let Some(format) = format {
+        // Formats only carry over as string field types
+        let known = import_type("string", Some(format)).is_some_and(|t| t != FieldType::String);
+        if !known || !matches!(schema.get("type"), Some(t) if t == "string") {
+            warnings.push(SchemaImportWarning {
+                pointer: pointer.to_string(),
+                keyword: "format".to_string(),
+            });
+        }
+    }
+    match schema.get("description") {
+        None => {}
+        Some(serde_json::Value::String(description)) => {
+            rule.description = Some(description.clone())
+        }
+        Some(_) => return Err(invalid_keyword(pointer, "description", "a string")),
+    }
+    rule.default = schema.get("default").cloned();
+    match schema.get("examples") {
+        None => {}
+        Some(serde_json::Value::Array(examples)) => rule.examples = examples.clone(),
+        Some(_) => return Err(invalid_keyword(pointer, "examples", "an array")),
+    }
+    match schema.get("enum") {
+        None => {}
+        Some(serde_json::Value::Array(values)) => rule.allowed_values = values.clone(),
+        Some(_) => return Err(invalid_keyword(pointer, "enum", "an array")),
+    }
+
+    let length = |keyword: &str| match schema.get(keyword) {
+        None => Ok(None),
+        Some(value) => value
+            .as_u64()
+            .map(|n| Some(n as usize))
+            .ok_or_else(|| invalid_keyword(pointer, keyword, "a non-negative integer")),
+    };
+    for keyword in ["minLength", "minItems"] {
+        if let Some(length) = length(keyword)? {
+            rule.constraints.push(ValidationConstraint::min_length(length));
+        }
+    }
+    for keyword in ["maxLength", "maxItems"] {
+        if let Some(length) = length(keyword)? {
+            rule.constraints.push(ValidationConstraint::max_length(length));
+        }
+    }
+
+    let bound = |keyword: &str| match schema.get(keyword) {
+        None => Ok(None),
+        Some(value) => value
+            .as_f64()
+            .map(Some)
+            .ok_or_else(|| invalid_keyword(pointer, keyword, "a number")),
+    };
+    for (keyword, inclusive) in [("minimum", true), ("exclusiveMinimum", false)] {
+        if let Some(value) = bound(keyword)? {
+            rule.constraints.push(ValidationConstraint::Min { value, inclusive });
+        }
+    }
+    for (keyword, inclusive) in [("maximum", true), ("exclusiveMaximum", false)] {
+        if let Some(value) = bound(keyword)? {
+            rule.constraints.push(ValidationConstraint::Max { value, inclusive });
+        }
+    }
+
+    match schema.get("pattern") {
+        None => {}
+        Some(serde_json::Value::String(regex)) => {
+            rule.constraints.push(ValidationConstraint::pattern(regex.clone()))
+        }
+        Some(_) => return Err(invalid_keyword(pointer, "pattern", "a string")),
+    }
+    match schema.get("uniqueItems") {
+        None | Some(serde_json::Value::Bool(false)) => {}
+        Some(serde_json::Value::Bool(true)) => {
+            rule.constraints.push(ValidationConstraint::UniqueItems)
+        }
+        Some(_) => return Err(invalid_keyword(pointer, "uniqueItems", "a boolean")),
+    }
+
+    rule.nested_fields = import_properties(schema, pointer, warnings)?;
+    if let Some(items) = schema.get("items") {
+        if items.is_array() {
+            // Tuple validation has no equivalent
+            warnings.push(SchemaImportWarning {
+                pointer: pointer.to_string(),
+                keyword: "items".to_string(),
+            });
+        } else {
+            let items_pointer = format!("{}/items", pointer);
+            rule.array_item_rule = Some(Box::new(import_field(items, &items_pointer, warnings)?));
+        }
+    }
+
+    Ok(rule)
 }
 
 #[cfg(test)]
@@ -750,6 +1731,175 @@ mod tests {
         assert_eq!(rule.constraints.len(), 2);
     }
 
+    #[test]
+    fn test_to_json_schema_maps_types_and_constraints() {
+        let schema = ConfigSchema::new("app/service", "Service", "1.0.0")
+            .with_field(
+                "name",
+                FieldRule::required(FieldType::String)
+                    .with_constraint(ValidationConstraint::min_length(3))
+                    .with_constraint(ValidationConstraint::max_length(32))
+                    .with_constraint(ValidationConstraint::pattern("^[a-z-]+$")),
+            )
+            .with_field(
+                "ratio",
+                FieldRule::new(FieldType::Float).with_constraint(ValidationConstraint::Min {
+                    value: 0.0,
+                    inclusive: false,
+                }),
+            )
+            .with_field("admin", FieldRule::new(FieldType::Email))
+            .with_field("homepage", FieldRule::new(FieldType::Url))
+            .with_field("bind", FieldRule::new(FieldType::IpAddress))
+            .with_field(
+                "tags",
+                FieldRule::new(FieldType::Array)
+                    .with_array_items(FieldRule::new(FieldType::String))
+                    .with_constraint(ValidationConstraint::min_length(1)),
+            )
+            .with_field(
+                "database",
+                FieldRule::required(FieldType::Object)
+                    .with_nested_field("host", FieldRule::required(FieldType::String))
+                    .with_nested_field(
+                        "port",
+                        FieldRule::new(FieldType::Integer)
+                            .with_constraint(ValidationConstraint::range(1.0, 65535.0)),
+                    ),
+            );
+
+        let json = schema.to_json_schema();
+        assert_eq!(json["$schema"], "http://json-schema.org/draft-07/schema#");
+        assert_eq!(json["type"], "object");
+        assert_eq!(json["required"], serde_json::json!(["database", "name"]));
+
+        let properties = &json["properties"];
+        assert_eq!(
+            properties["name"],
+            serde_json::json!({
+                "type": "string", "minLength": 3, "maxLength": 32, "pattern": "^[a-z-]+$"
+            })
+        );
+        assert_eq!(properties["ratio"], serde_json::json!({"type": "number", "exclusiveMinimum": 0.0}));
+        assert_eq!(properties["admin"]["format"], "email");
+        assert_eq!(properties["homepage"]["format"], "uri");
+        assert_eq!(properties["bind"]["anyOf"][1]["format"], "ipv6");
+        assert_eq!(
+            properties["tags"],
+            serde_json::json!({"type": "array", "items": {"type": "string"}, "minItems": 1})
+        );
+
+        let database = &properties["database"];
+        assert_eq!(database["type"], "object");
+        assert_eq!(database["required"], serde_json::json!(["host"]));
+        assert_eq!(
+            database["properties"]["port"],
+            serde_json::json!({"type": "integer", "minimum": 1.0, "maximum": 65535.0})
+        );
+    }
+
+    #[test]
+    fn test_from_json_schema_imports_nested_fields() {
+        let json = serde_json::json!({
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "$id": "app/service",
+            "title": "Service",
+            "type": "object",
+            "required": ["database", "mode"],
+            "properties": {
+                "mode": {"type": "string", "enum": ["fast", "safe"]},
+                "admin": {"type": "string", "format": "email"},
+                "database": {
+                    "type": "object",
+                    "required": ["host"],
+                    "properties": {
+                        "host": {"type": "string", "minLength": 1, "maxLength": 253},
+                        "port": {"type": "integer", "minimum": 1, "maximum": 65535},
+                        "name": {"type": "string", "pattern": "^[a-z_]+$"}
+                    }
+                }
+            }
+        });
+
+        let (schema, warnings) = ConfigSchema::from_json_schema_with_warnings(&json).unwrap();
+        assert!(warnings.is_empty(), "{:?}", warnings);
+        assert_eq!((schema.id.as_str(), schema.name.as_str()), ("app/service", "Service"));
+
+        let mode = schema.get_field("mode").unwrap();
+        assert!(mode.required);
+        assert_eq!(mode.allowed_values, vec![serde_json::json!("fast"), serde_json::json!("safe")]);
+        assert_eq!(schema.get_field("admin").unwrap().field_type, FieldType::Email);
+
+        let database = schema.get_field("database").unwrap();
+        assert_eq!(database.field_type, FieldType::Object);
+        assert!(database.required);
+        let host = &database.nested_fields["host"];
+        assert!(host.required);
+        assert!(matches!(
+            host.constraints[..],
+            [
+                ValidationConstraint::MinLength { length: 1 },
+                ValidationConstraint::MaxLength { length: 253 }
+            ]
+        ));
+        let port = &database.nested_fields["port"];
+        assert_eq!(port.field_type, FieldType::Integer);
+        assert!(!port.required);
+        assert!(matches!(
+            port.constraints[..],
+            [
+                ValidationConstraint::Min { value: 1.0, inclusive: true },
+                ValidationConstraint::Max { value: 65535.0, inclusive: true }
+            ]
+        ));
+        assert!(matches!(
+            &database.nested_fields["name"].constraints[..],
+            [ValidationConstraint::Pattern { regex, .. }] if regex == "^[a-z_]+$"
+        ));
+    }
+
+    #[test]
+    fn test_from_json_schema_reports_dropped_keywords() {
+        let json = serde_json::json!({
+            "type": "object",
+            "additionalProperties": false,
+            "properties": {
+                "host": {"type": "string", "format": "hostname"},
+                "tags": {"type": "array", "items": {"type": "string", "const": "web"}},
+                "limits": {"$ref": "#/definitions/limits"}
+            },
+            "definitions": {"limits": {"type": "object"}}
+        });
+
+        let (schema, warnings) = ConfigSchema::from_json_schema_with_warnings(&json).unwrap();
+        assert_eq!(schema.id, "imported");
+        assert_eq!(schema.get_field("limits").unwrap().field_type, FieldType::Any);
+        let mut dropped: Vec<String> = warnings.iter().map(ToString::to_string).collect();
+        dropped.sort();
+        assert_eq!(
+            dropped,
+            vec![
+                "Ignored unsupported keyword '$ref' at #/properties/limits",
+                "Ignored unsupported keyword 'additionalProperties' at #",
+                "Ignored unsupported keyword 'const' at #/properties/tags/items",
+                "Ignored unsupported keyword 'definitions' at #",
+                "Ignored unsupported keyword 'format' at #/properties/host",
+            ]
+        );
+
+        // Malformed keywords are errors rather than warnings
+        let err = ConfigSchema::from_json_schema(&serde_json::json!({
+            "properties": {"port": {"type": "integer", "minimum": "1"}}
+        }))
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid schema at #/properties/port: 'minimum' must be a number"
+        );
+        assert!(ConfigSchema::from_json_schema(&serde_json::json!({"type": "array"})).is_err());
+        assert!(ConfigSchema::from_json_schema(&serde_json::json!([])).is_err());
+    }
+
     #[test]
     fn test_environment_rule_creation() {
         let rule = EnvironmentRule::must_encrypt(
@@ -786,6 +1936,116 @@ mod tests {
         assert_eq!(deprecation.replacement, Some("connection_url".to_string()));
     }
 
+    #[test]
+    fn test_coverage_report_for_partially_constrained_schema() {
+        let schema = ConfigSchema::new("app/database", "Database Config", "1.0.0")
+            .with_field(
+                "host",
+                FieldRule::required(FieldType::String).with_description("Database host"),
+            )
+            .with_field(
+                "port",
+                FieldRule::new(FieldType::Integer)
+                    .with_constraint(ValidationConstraint::range(1.0, 65535.0)),
+            )
+            .with_field(
+                "pool",
+                FieldRule::new(FieldType::Object).with_nested_field(
+                    "size",
+                    FieldRule::new(FieldType::Integer)
+                        .with_description("Connections per instance")
+                        .with_constraint(ValidationConstraint::min(1.0)),
+                ),
+            )
+            .with_environment_rule(EnvironmentRule::required_in(
+                "prod-host",
+                vec!["production".to_string()],
+                vec!["host".to_string(), "pool.size".to_string()],
+            ));
+
+        let report = schema.coverage_report();
+
+        assert_eq!(report.total_fields, 4);
+        assert_eq!(report.constrained_fields, 2);
+        assert_eq!(report.described_fields, 2);
+        assert_eq!(report.environment_covered_fields, 2);
+        assert_eq!(report.constraint_coverage(), 0.5);
+        assert_eq!(report.unconstrained, ["host", "pool"]);
+        assert_eq!(report.undescribed, ["pool", "port"]);
+        assert_eq!(report.without_environment_rules, ["pool", "port"]);
+
+        let empty = ConfigSchema::new("empty", "Empty", "1.0.0").coverage_report();
+        assert_eq!(empty.constraint_coverage(), 0.0);
+    }
+
+    #[test]
+    fn test_schema_format_version_support() {
+        assert_eq!(SchemaDefinition::check_format_version("1.0"), Ok(None));
+
+        let warning = SchemaDefinition::check_format_version("0.9").unwrap().unwrap();
+        assert!(warning.contains("older"), "{}", warning);
+
+        let err = SchemaDefinition::check_format_version("2.0").unwrap_err();
+        assert!(err.contains("Unsupported"), "{}", err);
+        assert!(err.contains("0.9 through 1.0"), "{}", err);
+        assert!(SchemaDefinition::check_format_version("next").is_err());
+    }
+
+    #[test]
+    fn test_unknown_keys_are_located() {
+        let schema = serde_json::json!({
+            "id": "app/server",
+            "name": "Server",
+            "version": "1.0.0",
+            "fields": {
+                "host": { "field_type": "string", "requird": true },
+                "port": {
+                    "field_type": "integer",
+                    "constraints": [{ "type": "min", "value": 1, "inclusive": true, "mesage": "x" }]
+                }
+            },
+            "metdata": {}
+        });
+
+        // Lenient reading ignores the misspellings
+        let lenient: ConfigSchema = serde_json::from_value(schema.clone()).unwrap();
+        assert!(!lenient.fields["host"].required);
+
+        let mut unknown = ConfigSchema::unknown_keys(&schema);
+        unknown.sort();
+        assert_eq!(
+            unknown,
+            ["fields.host.requird", "fields.port.constraints[0].mesage", "metdata"]
+        );
+
+        let definition = serde_json::json!({ "schema_format_version": "1.0", "schema": schema });
+        assert_eq!(SchemaDefinition::unknown_keys(&definition).len(), 3);
+    }
+
+    #[test]
+    fn test_serialized_schema_has_no_unknown_keys() {
+        let schema = ConfigSchema::new("app/database", "Database", "1.0.0")
+            .with_field(
+                "port",
+                FieldRule::required(FieldType::Integer)
+                    .with_constraint(ValidationConstraint::range(1.0, 65535.0))
+                    .with_constraint(ValidationConstraint::Conditional {
+                        condition: "tls == true".to_string(),
+                        then_constraint: Box::new(ValidationConstraint::min(1024.0)),
+                        else_constraint: Some(Box::new(ValidationConstraint::NotEmpty)),
+                    }),
+            )
+            .with_environment_rule(EnvironmentRule::required_in(
+                "prod_port",
+                vec!["production".to_string()],
+                vec!["port".to_string()],
+            ))
+            .with_compatibility_rule(CompatibilityRule::requires_field("api", "gateway", "port"));
+        let value = serde_json::to_value(SchemaDefinition::new(schema)).unwrap();
+
+        assert!(SchemaDefinition::unknown_keys(&value).is_empty());
+    }
+
     #[test]
     fn test_constraint_description() {
         let constraint = ValidationConstraint::range(1.0, 100.0);
@@ -0,0 +1,451 @@
+//! Append-only audit log of validation decisions
+//!
+//! Every validation run through [`ValidationEngine::validate_and_emit`] on an
+//! engine with an [`AuditLog`] is recorded as one JSON line holding the input
+//! hash, verdict, findings rollup, and timestamp. Records are written before
+//! the DecisionEvent is emitted and do not depend on it succeeding, so an
+//! operator can reconstruct what was decided even when ruvector-service was
+//! unreachable.
+//!
+//! # Rotation
+//!
+//! When appending a record would grow the log past
+//! [`AuditLog::with_max_bytes`], the current file is renamed to `<path>.1`,
+//! older files shift up by one (`<path>.1` to `<path>.2`, ...), and anything
+//! past [`AuditLog::with_max_files`] is deleted.
+//!
+//! # Replay
+//!
+//! A log created with [`AuditLog::with_inputs`] also stores the validated
+//! value, so [`replay`] can re-run the validation and report any
+//! [`Divergence`] from the recorded decision. This is what
+//! `config-validate replay --record <file>` does.
+//!
+//! [`ValidationEngine::validate_and_emit`]: crate::engine::ValidationEngine::validate_and_emit
+
+use crate::contracts::{DecisionEvent, DecisionVerdict, IssueRollup};
+use crate::engine::{self, ValidationEngine};
+use crate::error::ValidationError;
+use crate::{ConfigValue, Environment};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use uuid::Uuid;
+
+/// Default size at which the log is rotated (10 MiB)
+pub const DEFAULT_MAX_BYTES: u64 = 10 * 1024 * 1024;
+
+/// Default number of rotated files kept alongside the current log
+pub const DEFAULT_MAX_FILES: usize = 5;
+
+/// A single audited validation decision
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditRecord {
+    /// When the decision was made
+    pub timestamp: DateTime<Utc>,
+    /// Id of the DecisionEvent recording the same decision
+    pub event_id: Uuid,
+    /// Execution reference of the decision
+    pub execution_ref: String,
+    /// Version of the agent that made the decision
+    pub agent_version: String,
+    /// SHA-256 hash of the validated inputs
+    pub inputs_hash: String,
+    /// Namespace that was validated
+    pub namespace: String,
+    /// Environment validated against
+    pub environment: Environment,
+    /// Whether the configuration was valid
+    pub is_valid: bool,
+    /// Verdict reached for the configuration
+    pub decision: Option<DecisionVerdict>,
+    /// Findings rolled up by severity, category, and code
+    pub summary: Option<IssueRollup>,
+    /// The validated value, when the log stores inputs
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub input: Option<ConfigValue>,
+}
+
+impl AuditRecord {
+    /// Build the record for a decision event
+    pub fn from_event(event: &DecisionEvent, namespace: &str, environment: Environment) -> Self {
+        Self {
+            timestamp: event.timestamp,
+            event_id: event.event_id,
+            execution_ref: event.execution_ref.clone(),
+            agent_version: event.agent_version.clone(),
+            inputs_hash: event.inputs_hash.clone(),
+            namespace: namespace.to_string(),
+            environment,
+            is_valid: event.outputs.is_valid,
+            decision: event.verdict,
+            summary: event.outputs.rollup.clone(),
+            input: None,
+        }
+    }
+
+    /// Store the validated value so the decision can be replayed
+    pub fn with_input(mut self, input: ConfigValue) -> Self {
+        self.input = Some(input);
+        self
+    }
+}
+
+/// Append-only JSONL audit log with size-based rotation
+#[derive(Debug)]
+pub struct AuditLog {
+    path: PathBuf,
+    max_bytes: u64,
+    max_files: usize,
+    store_inputs: bool,
+    /// Serializes appends and rotation within the process
+    lock: Mutex<()>,
+}
+
+impl AuditLog {
+    /// Create an audit log writing to `path`
+    ///
+    /// The file and its parent directories are created on the first append.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            max_bytes: DEFAULT_MAX_BYTES,
+            max_files: DEFAULT_MAX_FILES,
+            store_inputs: false,
+            lock: Mutex::new(()),
+        }
+    }
+
+    /// Rotate once the log would grow past `max_bytes`
+    pub fn with_max_bytes(mut self, max_bytes: u64) -> Self {
+        self.max_bytes = max_bytes;
+        self
+    }
+
+    /// Keep at most `max_files` rotated files
+    pub fn with_max_files(mut self, max_files: usize) -> Self {
+        self.max_files = max_files;
+        self
+    }
+
+    /// Store each validated value in its record, enabling [`replay`]
+    ///
+    /// Off by default, since values may be large or sensitive.
+    pub fn with_inputs(mut self, store: bool) -> Self {
+        self.store_inputs = store;
+        self
+    }
+
+    /// Whether records carry the validated value
+    pub fn stores_inputs(&self) -> bool {
+        self.store_inputs
+    }
+
+    /// Path of the current log file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Path of the `index`-th rotated file (`1` is the most recent)
+    pub fn rotated_path(&self, index: usize) -> PathBuf {
+        let mut name = self.path.clone().into_os_string();
+        name.push(format!(".{}", index));
+        PathBuf::from(name)
+    }
+
+    /// Append a record, rotating first if it would exceed the size limit
+    pub fn append(&self, record: &AuditRecord) -> io::Result<()> {
+        let mut line = serde_json::to_vec(record)?;
+        line.push(b'\n');
+
+        let _guard = self.lock.lock().unwrap_or_else(|e| e.into_inner());
+
+        if let Some(parent) = self.path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)?;
+        }
+
+        let current = fs::metadata(&self.path).map(|m| m.len()).unwrap_or(0);
+        if current > 0 && current + line.len() as u64 > self.max_bytes {
+            self.rotate()?;
+        }
+
+        let mut file = OpenOptions::new().create(true).append(true).open(&self.path)?;
+        file.write_all(&line)?;
+        file.flush()
+    }
+
+    /// Read the records in the current log file, oldest first
+    pub fn read(&self) -> io::Result<Vec<AuditRecord>> {
+        read_records(&self.path)
+    }
+
+    fn rotate(&self) -> io::Result<()> {
+        if self.max_files == 0 {
+            return fs::remove_file(&self.path);
+        }
+
+        let oldest = self.rotated_path(self.max_files);
+        if oldest.exists() {
+            fs::remove_file(&oldest)?;
+        }
+        for index in (1..self.max_files).rev() {
+            let from = self.rotated_path(index);
+            if from.exists() {
+                fs::rename(&from, self.rotated_path(index + 1))?;
+            }
+        }
+        fs::rename(&self.path, self.rotated_path(1))
+    }
+}
+
+/// Read the records in an audit log file, oldest first
+///
+/// Blank lines are skipped; a line that is not a valid record is an error.
+pub fn read_records(path: impl AsRef<Path>) -> io::Result<Vec<AuditRecord>> {
+    let reader = BufReader::new(File::open(path)?);
+    let mut records = Vec::new();
+    for line in reader.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        records.push(serde_json::from_str(&line)?);
+    }
+    Ok(records)
+}
+
+/// A field whose recomputed value differs from the recorded one
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Divergence {
+    /// Name of the record field
+    pub field: String,
+    /// Value stored in the audit record
+    pub recorded: serde_json::Value,
+    /// Value produced by the replay
+    pub replayed: serde_json::Value,
+}
+
+/// Outcome of replaying one audit record
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReplayReport {
+    /// Id of the replayed decision
+    pub event_id: Uuid,
+    /// Fields that differ from the record; empty when reproduced
+    pub divergences: Vec<Divergence>,
+}
+
+impl ReplayReport {
+    /// Whether the replay reproduced the recorded decision
+    pub fn is_reproduced(&self) -> bool {
+        self.divergences.is_empty()
+    }
+}
+
+/// Re-run the validation behind an audit record and compare the outcome
+///
+/// The agent version, input hash, validity, verdict, and findings rollup are
+/// compared. Fails if the record was written without its input.
+pub async fn replay(
+    engine: &ValidationEngine,
+    record: &AuditRecord,
+) -> Result<ReplayReport, ValidationError> {
+    let input = record.input.as_ref().ok_or_else(|| {
+        ValidationError::invalid_input(format!(
+            "Audit record {} does not include its input; enable AuditLog::with_inputs to replay",
+            record.event_id
+        ))
+    })?;
+
+    let result = engine.validate(input, record.environment, &record.namespace).await;
+    let replayed = AuditRecord {
+        agent_version: DecisionEvent::AGENT_VERSION.to_string(),
+        inputs_hash: engine::inputs_hash(input, record.environment, &record.namespace),
+        is_valid: result.is_valid,
+        decision: Some(result.verdict(engine.verdict_thresholds())),
+        summary: Some(result.issue_summary()),
+        ..record.clone()
+    };
+
+    let mut divergences = Vec::new();
+    let mut compare = |field: &str, recorded: serde_json::Value, replayed: serde_json::Value| {
+        if recorded != replayed {
+            divergences.push(Divergence {
+                field: field.to_string(),
+                recorded,
+                replayed,
+            });
+        }
+    };
+    compare(
+        "agent_version",
+        serde_json::json!(record.agent_version),
+        serde_json::json!(replayed.agent_version),
+    );
+    compare(
+        "inputs_hash",
+        serde_json::json!(record.inputs_hash),
+        serde_json::json!(replayed.inputs_hash),
+    );
+    compare(
+        "is_valid",
+        serde_json::json!(record.is_valid),
+        serde_json::json!(replayed.is_valid),
+    );
+    compare(
+        "decision",
+        serde_json::json!(record.decision),
+        serde_json::json!(replayed.decision),
+    );
+    compare(
+        "summary",
+        serde_json::json!(record.summary),
+        serde_json::json!(replayed.summary),
+    );
+
+    Ok(ReplayReport {
+        event_id: record.event_id,
+        divergences,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::telemetry::{DecisionEventSink, TelemetryError};
+
+    /// Sink that always fails, as if ruvector-service were unreachable
+    struct UnreachableSink;
+
+    #[async_trait::async_trait]
+    impl DecisionEventSink for UnreachableSink {
+        async fn emit(&self, _event: DecisionEvent) -> crate::telemetry::Result<()> {
+            Err(TelemetryError::HttpError("connection refused".to_string()))
+        }
+    }
+
+    fn config(index: usize) -> ConfigValue {
+        ConfigValue::Object(
+            [("replicas".to_string(), ConfigValue::Integer(index as i64))]
+                .into_iter()
+                .collect(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_each_validation_appends_one_record() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("audit/decisions.jsonl");
+        let engine = ValidationEngine::new().with_audit_log(AuditLog::new(&path));
+
+        let mut expected = Vec::new();
+        for index in 0..5 {
+            let (result, event) = engine
+                .validate_and_emit(&config(index), Environment::Production, "myapp", &UnreachableSink)
+                .await;
+            expected.push((event.event_id, event.inputs_hash, result.is_valid));
+        }
+
+        let records = read_records(&path).unwrap();
+        assert_eq!(records.len(), 5);
+        for (record, (event_id, inputs_hash, is_valid)) in records.iter().zip(&expected) {
+            assert_eq!(&record.event_id, event_id);
+            assert_eq!(&record.inputs_hash, inputs_hash);
+            assert_eq!(record.is_valid, *is_valid);
+            assert_eq!(record.namespace, "myapp");
+            assert_eq!(record.environment, Environment::Production);
+            assert!(record.decision.is_some());
+            assert!(record.summary.is_some());
+        }
+
+        // Different inputs hash differently
+        let hashes: std::collections::HashSet<_> = records.iter().map(|r| &r.inputs_hash).collect();
+        assert_eq!(hashes.len(), 5);
+    }
+
+    #[tokio::test]
+    async fn test_log_rotates_by_size() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("decisions.jsonl");
+        let engine = ValidationEngine::empty();
+
+        let (_, event) = engine
+            .validate_and_emit(&config(0), Environment::Development, "myapp", &UnreachableSink)
+            .await;
+        let record = AuditRecord::from_event(&event, "myapp", Environment::Development);
+        let record_len = serde_json::to_vec(&record).unwrap().len() as u64 + 1;
+
+        // Room for two records per file, keeping two rotated files
+        let log = AuditLog::new(&path)
+            .with_max_bytes(record_len * 2)
+            .with_max_files(2);
+        for _ in 0..7 {
+            log.append(&record).unwrap();
+        }
+
+        assert_eq!(log.read().unwrap().len(), 1);
+        assert_eq!(read_records(log.rotated_path(1)).unwrap().len(), 2);
+        assert_eq!(read_records(log.rotated_path(2)).unwrap().len(), 2);
+        assert!(!log.rotated_path(3).exists());
+    }
+
+    async fn recorded_decision(dir: &Path) -> AuditRecord {
+        let path = dir.join("decisions.jsonl");
+        let engine = ValidationEngine::new().with_audit_log(AuditLog::new(&path).with_inputs(true));
+        let config = ConfigValue::Object(
+            [
+                ("debug".to_string(), ConfigValue::Boolean(true)),
+                ("log_level".to_string(), ConfigValue::String("trace".to_string())),
+                ("replicas".to_string(), ConfigValue::Integer(3)),
+            ]
+            .into_iter()
+            .collect(),
+        );
+        engine
+            .validate_and_emit(&config, Environment::Production, "myapp", &UnreachableSink)
+            .await;
+        read_records(&path).unwrap().remove(0)
+    }
+
+    #[tokio::test]
+    async fn test_replay_reproduces_unchanged_record() {
+        let dir = tempfile::tempdir().unwrap();
+        let record = recorded_decision(dir.path()).await;
+        assert!(record.input.is_some());
+
+        let report = replay(&ValidationEngine::new(), &record).await.unwrap();
+        assert_eq!(report.event_id, record.event_id);
+        assert!(report.is_reproduced(), "{:?}", report.divergences);
+    }
+
+    #[tokio::test]
+    async fn test_replay_reports_divergence() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut record = recorded_decision(dir.path()).await;
+
+        // Recorded by an older engine
+        record.agent_version = "0.0.1".to_string();
+        let report = replay(&ValidationEngine::new(), &record).await.unwrap();
+        let fields: Vec<&str> = report.divergences.iter().map(|d| d.field.as_str()).collect();
+        assert_eq!(fields, vec!["agent_version"]);
+        assert_eq!(report.divergences[0].recorded, serde_json::json!("0.0.1"));
+
+        // An engine without the rules that produced the findings decides differently
+        record.agent_version = DecisionEvent::AGENT_VERSION.to_string();
+        let report = replay(&ValidationEngine::empty(), &record).await.unwrap();
+        assert!(!report.is_reproduced());
+        assert!(report.divergences.iter().any(|d| d.field == "summary"));
+    }
+
+    #[tokio::test]
+    async fn test_replay_requires_stored_input() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut record = recorded_decision(dir.path()).await;
+        record.input = None;
+
+        let err = replay(&ValidationEngine::new(), &record).await.unwrap_err();
+        assert!(matches!(err, ValidationError::InvalidInput(_)));
+    }
+}
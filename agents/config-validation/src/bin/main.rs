@@ -8,13 +8,52 @@
 //! # Validate a configuration file against a schema
 //! config-validate validate --config app.yaml --schema schema.json --environment production
 //!
+//! # Validate a configuration piped in on stdin (its format is detected;
+//! # pass --input-format to skip detection)
+//! cat app.yaml | config-validate validate --config - --format json
+//!
 //! # Inspect configuration structure
 //! config-validate inspect --config app.yaml --format json
 //!
+//! # Print a canonical form for stable hashes and diffs
+//! config-validate normalize --config app.yaml --schema schema.json
+//!
+//! # List which rules apply in an environment, without running them
+//! config-validate explain --config app.yaml --environment staging
+//!
+//! # See which schema fields lack constraints, descriptions, or environment rules
+//! config-validate schema coverage --schema schema.json
+//!
 //! # Check compatibility between multiple configurations
 //! config-validate compatibility --configs config1.yaml config2.yaml
+//!
+//! # Validate every environment in a directory before deploying
+//! config-validate environments --dir config/ --schema config/schema.json
+//!
+//! # Validate a directory of configs and write a shareable report
+//! config-validate batch --configs config/ --schema schema.json --report report.html
+//!
+//! # Re-run audited validations and report divergences
+//! config-validate replay --record audit.jsonl
+//!
+//! # Check configuration and connectivity
+//! config-validate doctor
+//!
+//! # Generate shell completions
+//! config-validate completions bash > /etc/bash_completion.d/config-validate
+//!
+//! # Print the commit, build time and compiler along with the version
+//! config-validate --version --verbose
 //! ```
 //!
+//! # Defaults File
+//!
+//! Defaults for `--schema`, `--environment`, `--format` and `--strict` may be
+//! placed in a `.config-validate.toml` in the current directory or any parent.
+//! Precedence, highest first: explicit flags, environment variables
+//! (`CONFIG_VALIDATE_SCHEMA`, `CONFIG_VALIDATE_ENVIRONMENT`, `CONFIG_VALIDATE_FORMAT`),
+//! the nearest defaults file, then built-in defaults.
+//!
 //! # Exit Codes
 //!
 //! - 0: Success - validation passed
@@ -24,23 +63,39 @@
 //! - 4: File not found or inaccessible
 //! - 5: Schema-related errors
 //! - 10: Internal error
+//!
+//! JSON and YAML output also carry an `exit_reason` string (`valid`, `errors`,
+//! `warnings`, `invalid_input`, `file_error`, `schema_error`, `internal`).
 
 use clap::Parser;
+use config_validation::build_info::{build_info, verbose_version_requested};
 use config_validation::{run_cli, ValidateCli};
 
 fn main() {
-    // Initialize tracing subscriber for logging
+    if verbose_version_requested(std::env::args()) {
+        println!("{}", build_info().verbose_version("config-validate"));
+        return;
+    }
+
+    // Parse CLI arguments
+    let cli = ValidateCli::parse();
+
+    // Initialize tracing subscriber for logging; -v, -vv and -vvv raise the
+    // level, and logs go to stderr so they never mix with JSON output
+    let level = match cli.verbose {
+        0 => tracing::Level::WARN,
+        1 => tracing::Level::INFO,
+        2 => tracing::Level::DEBUG,
+        _ => tracing::Level::TRACE,
+    };
     tracing_subscriber::fmt()
         .with_env_filter(
-            tracing_subscriber::EnvFilter::from_default_env()
-                .add_directive(tracing::Level::WARN.into()),
+            tracing_subscriber::EnvFilter::from_default_env().add_directive(level.into()),
         )
         .with_target(false)
+        .with_writer(std::io::stderr)
         .init();
 
-    // Parse CLI arguments
-    let cli = ValidateCli::parse();
-
     // Run the CLI and exit with appropriate code
     let exit_code = run_cli(cli);
     std::process::exit(exit_code.into());
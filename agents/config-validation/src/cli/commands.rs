@@ -3,11 +3,19 @@
 //! Provides Clap-based command definitions for validating configurations,
 //! inspecting schemas, and checking cross-agent compatibility.
 
-use clap::{Parser, Subcommand, ValueEnum};
-use std::path::PathBuf;
-
-use super::output::{OutputFormat, ValidationOutput};
+use clap::{CommandFactory, Parser, Subcommand, ValueEnum};
+use clap_complete::Shell;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+
+use super::output::{
+    CompatibilityOutput, EnvironmentsOutput, GroupBy, OutputFormat, SummaryOutput,
+    ValidationOutput,
+};
+use super::defaults::ResolvedValidateArgs;
 use super::ExitCode;
+use crate::report::{AggregateReport, FileReport, ReportFormat};
+use crate::contracts::{EnvironmentRef, ParseEnvironmentError};
 use crate::error::ValidationError;
 
 /// Config Validation Agent CLI
@@ -27,6 +35,20 @@ pub struct ValidateCli {
     #[arg(short, long, global = true)]
     pub quiet: bool,
 
+    /// Disable colored output (also honors the NO_COLOR environment variable)
+    #[arg(long, global = true)]
+    pub no_color: bool,
+
+    /// Format of input read from stdin, or of files whose extension is wrong
+    ///
+    /// Commands taking a config or schema path read it from stdin when the
+    /// path is `-`, e.g. `cat app.yaml | config-validate validate --config -
+    /// --input-format yaml`. Without this flag, stdin and files with an
+    /// unknown extension are detected from their content, as with `auto`;
+    /// `-v` reports the detected format.
+    #[arg(long, value_enum, global = true)]
+    pub input_format: Option<InputFormat>,
+
     #[command(subcommand)]
     pub command: ValidateCommands,
 }
@@ -46,22 +68,53 @@ pub enum ValidateCommands {
         /// Path to the schema file (optional)
         ///
         /// If not provided, performs structural validation only.
-        #[arg(short, long)]
+        #[arg(short, long, env = "CONFIG_VALIDATE_SCHEMA")]
         schema: Option<PathBuf>,
 
-        /// Target environment for validation rules
-        #[arg(short, long, default_value = "production")]
-        environment: String,
+        /// Target environment for validation rules [default: production]
+        ///
+        /// One of base, development (dev), staging (stage), production (prod),
+        /// or edge.
+        #[arg(short, long, env = "CONFIG_VALIDATE_ENVIRONMENT")]
+        environment: Option<String>,
 
-        /// Output format for validation results
-        #[arg(long, value_enum, default_value = "table")]
+        /// Output format for validation results [default: table]
+        #[arg(long, value_enum, env = "CONFIG_VALIDATE_FORMAT")]
         format: Option<OutputFormat>,
 
         /// Enable strict validation mode
         ///
-        /// In strict mode, warnings are treated as errors.
+        /// In strict mode, warnings are treated as errors. Pass `--strict=false`
+        /// to turn off strict mode enabled by the environment or defaults file.
+        #[arg(
+            long,
+            env = "CONFIG_VALIDATE_STRICT",
+            num_args = 0..=1,
+            require_equals = true,
+            default_missing_value = "true"
+        )]
+        strict: Option<bool>,
+
+        /// Group findings by code, path, or severity
+        ///
+        /// The table lists each group with its count; JSON and YAML output
+        /// gain a `grouped` field.
+        #[arg(long, value_enum)]
+        group_by: Option<GroupBy>,
+
+        /// Print only the finding counts and exit reason
+        ///
+        /// Individual findings are left out; JSON and YAML output contain
+        /// just the summary object. Takes precedence over `--group-by`.
         #[arg(long)]
-        strict: bool,
+        summary_only: bool,
+
+        /// List at most this many findings, the most severe first
+        ///
+        /// Counts and the exit code still cover every finding; JSON and YAML
+        /// output mark the list `truncated` and report `total_findings`.
+        #[arg(long, value_name = "N")]
+        max_findings: Option<usize>,
     },
 
     /// Inspect configuration schema and structure
@@ -73,11 +126,60 @@ pub enum ValidateCommands {
         #[arg(short, long)]
         config: PathBuf,
 
-        /// Output format for inspection results
-        #[arg(long, value_enum, default_value = "table")]
+        /// Output format for inspection results [default: table]
+        #[arg(long, value_enum, env = "CONFIG_VALIDATE_FORMAT")]
+        format: Option<OutputFormat>,
+    },
+
+    /// Print the canonical form of a configuration
+    ///
+    /// Sorts keys and writes whole floats as integers; with a schema, also
+    /// fills in declared defaults and converts booleans and numbers written
+    /// as strings. Does not validate. JSON output is compact, so equal
+    /// configurations print identical bytes.
+    Normalize {
+        /// Path to the configuration file to normalize
+        #[arg(short, long)]
+        config: PathBuf,
+
+        /// Path to a JSON schema whose defaults and types are applied (optional)
+        #[arg(short, long, env = "CONFIG_VALIDATE_SCHEMA")]
+        schema: Option<PathBuf>,
+
+        /// Output format: yaml prints YAML, every other format compact JSON
+        #[arg(long, value_enum, env = "CONFIG_VALIDATE_FORMAT")]
         format: Option<OutputFormat>,
     },
 
+    /// List which rules would run on a configuration, without running them
+    ///
+    /// Shows each rule the agent's engine registers, whether it applies in
+    /// the target environment and namespace, and why not; useful when an
+    /// expected finding does not appear. No findings are produced.
+    Explain {
+        /// Path to the configuration file to explain
+        #[arg(short, long)]
+        config: PathBuf,
+
+        /// Target environment [default: production]
+        #[arg(short, long, env = "CONFIG_VALIDATE_ENVIRONMENT")]
+        environment: Option<String>,
+
+        /// Namespace the configuration belongs to
+        #[arg(short, long, default_value = "")]
+        namespace: String,
+
+        /// Output format for the explanation [default: table]
+        #[arg(long, value_enum, env = "CONFIG_VALIDATE_FORMAT")]
+        format: Option<OutputFormat>,
+    },
+
+    /// Inspect configuration schemas
+    Schema {
+        #[command(subcommand)]
+        command: SchemaCommands,
+    },
+
     /// Check cross-agent configuration compatibility
     ///
     /// Validates that multiple configuration files are compatible
@@ -87,10 +189,227 @@ pub enum ValidateCommands {
         #[arg(short, long, num_args = 2..)]
         configs: Vec<PathBuf>,
 
-        /// Output format for compatibility results
-        #[arg(long, value_enum, default_value = "table")]
+        /// Output format for compatibility results [default: table]
+        #[arg(long, value_enum, env = "CONFIG_VALIDATE_FORMAT")]
         format: Option<OutputFormat>,
     },
+
+    /// Validate every environment in a directory of per-environment configs
+    ///
+    /// Loads `base`, `dev`, `staging`, `prod` (and `edge`) config files from
+    /// the directory, resolves each environment's effective config through
+    /// inheritance, validates each one, and checks the schema's
+    /// `environment_rules` (MustDiffer, MustEncrypt) across environments.
+    Environments {
+        /// Directory holding one config file per environment
+        #[arg(short, long)]
+        dir: PathBuf,
+
+        /// Path to the schema file (optional)
+        #[arg(short, long, env = "CONFIG_VALIDATE_SCHEMA")]
+        schema: Option<PathBuf>,
+
+        /// Output format for validation results [default: table]
+        #[arg(long, value_enum, env = "CONFIG_VALIDATE_FORMAT")]
+        format: Option<OutputFormat>,
+
+        /// Enable strict validation mode
+        #[arg(
+            long,
+            env = "CONFIG_VALIDATE_STRICT",
+            num_args = 0..=1,
+            require_equals = true,
+            default_missing_value = "true"
+        )]
+        strict: Option<bool>,
+    },
+
+    /// Validate many configuration files and write one consolidated report
+    ///
+    /// Accepts files and directories; directories contribute every json,
+    /// yaml, yml, and toml file directly inside them. Prints a per-file status
+    /// and exits with the validation error code when any file has errors.
+    Batch {
+        /// Configuration files or directories to validate
+        #[arg(short, long, num_args = 1.., required = true)]
+        configs: Vec<PathBuf>,
+
+        /// Path to the schema file (optional)
+        #[arg(short, long, env = "CONFIG_VALIDATE_SCHEMA")]
+        schema: Option<PathBuf>,
+
+        /// Target environment for validation rules [default: production]
+        #[arg(short, long, env = "CONFIG_VALIDATE_ENVIRONMENT")]
+        environment: Option<String>,
+
+        /// Output format for the per-file status [default: table]
+        #[arg(long, value_enum, env = "CONFIG_VALIDATE_FORMAT")]
+        format: Option<OutputFormat>,
+
+        /// Enable strict validation mode
+        #[arg(
+            long,
+            env = "CONFIG_VALIDATE_STRICT",
+            num_args = 0..=1,
+            require_equals = true,
+            default_missing_value = "true"
+        )]
+        strict: Option<bool>,
+
+        /// Write a consolidated report to this path
+        ///
+        /// The format follows the extension: `.html` for a self-contained
+        /// page, `.json` for JSON, anything else for Markdown.
+        #[arg(long)]
+        report: Option<PathBuf>,
+    },
+
+    /// Re-run past validations from audit records
+    ///
+    /// Replays every record in an audit log written with stored inputs and
+    /// reports any field where the recomputed decision differs. Exits with
+    /// the validation error code when any record diverges.
+    Replay {
+        /// Path to the audit log (JSONL) holding the records to replay
+        #[arg(short, long)]
+        record: PathBuf,
+
+        /// Output format for replay results [default: table]
+        #[arg(long, value_enum, env = "CONFIG_VALIDATE_FORMAT")]
+        format: Option<OutputFormat>,
+    },
+
+    /// Check configuration, connectivity, and the latency budget
+    ///
+    /// Prints a pass/fail report and exits with the validation error code
+    /// when any check fails.
+    Doctor {
+        /// ruvector-service URL to check
+        #[arg(long, env = "RUVECTOR_ENDPOINT")]
+        telemetry_url: Option<String>,
+
+        /// Output format for the report [default: table]
+        #[arg(long, value_enum, env = "CONFIG_VALIDATE_FORMAT")]
+        format: Option<OutputFormat>,
+    },
+
+    /// Generate shell completion scripts
+    ///
+    /// Prints a completion script for the given shell to stdout,
+    /// e.g. `config-validate completions bash > /etc/bash_completion.d/config-validate`.
+    Completions {
+        /// Shell to generate completions for
+        #[arg(value_enum)]
+        shell: Shell,
+    },
+}
+
+/// Schema commands
+#[derive(Subcommand, Debug)]
+pub enum SchemaCommands {
+    /// Report how completely a schema describes its fields
+    ///
+    /// Shows the fraction of fields with constraints beyond a bare type,
+    /// with descriptions, and covered by environment rules, and lists the
+    /// fields missing each.
+    Coverage {
+        /// Path to the schema file (a schema or a schema definition document)
+        #[arg(short, long, env = "CONFIG_VALIDATE_SCHEMA")]
+        schema: PathBuf,
+
+        /// Output format for the report [default: table]
+        #[arg(long, value_enum, env = "CONFIG_VALIDATE_FORMAT")]
+        format: Option<OutputFormat>,
+
+        /// Reject keys the schema format does not define
+        ///
+        /// By default unknown keys are ignored so schemas written for newer
+        /// versions still load; this reports each one with its location to
+        /// catch misspellings such as `requird`.
+        #[arg(long)]
+        strict_schema: bool,
+    },
+}
+
+/// Formats configuration and schema files are parsed from
+#[derive(Copy, Clone, PartialEq, Eq, ValueEnum, Debug)]
+pub enum InputFormat {
+    /// Detect the format from the content
+    Auto,
+    /// JSON
+    Json,
+    /// YAML
+    Yaml,
+    /// TOML
+    Toml,
+}
+
+impl InputFormat {
+    /// Format a file extension names, if it names one
+    pub fn from_extension(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?.to_lowercase();
+        match extension.as_str() {
+            "json" => Some(InputFormat::Json),
+            "yaml" | "yml" => Some(InputFormat::Yaml),
+            "toml" => Some(InputFormat::Toml),
+            _ => None,
+        }
+    }
+
+    /// Detect the format of a configuration from its content
+    ///
+    /// Tries JSON (content opening with `{` or `[`), then TOML, then YAML,
+    /// which must hold a mapping or sequence since nearly any text is a
+    /// YAML scalar. Returns `None` when none fits.
+    pub fn detect(content: &str) -> Option<Self> {
+        let trimmed = content.trim_start();
+        if trimmed.is_empty() {
+            return None;
+        }
+        if (trimmed.starts_with('{') || trimmed.starts_with('['))
+            && serde_json::from_str::<serde_json::Value>(content).is_ok()
+        {
+            return Some(InputFormat::Json);
+        }
+        if toml::from_str::<toml::Value>(content).is_ok() {
+            return Some(InputFormat::Toml);
+        }
+        match serde_yaml::from_str::<serde_yaml::Value>(content) {
+            Ok(serde_yaml::Value::Mapping(_) | serde_yaml::Value::Sequence(_)) => {
+                Some(InputFormat::Yaml)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for InputFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputFormat::Auto => write!(f, "auto"),
+            InputFormat::Json => write!(f, "JSON"),
+            InputFormat::Yaml => write!(f, "YAML"),
+            InputFormat::Toml => write!(f, "TOML"),
+        }
+    }
+}
+
+/// Path that stands for stdin
+pub const STDIN_PATH: &str = "-";
+
+/// Whether a path stands for stdin
+pub fn is_stdin(path: &Path) -> bool {
+    path.as_os_str() == STDIN_PATH
+}
+
+/// Fail when more than one of the paths reads from stdin, which can be read once
+fn ensure_stdin_once<'a>(paths: impl IntoIterator<Item = &'a Path>) -> Result<(), ValidationError> {
+    if paths.into_iter().filter(|path| is_stdin(path)).count() > 1 {
+        return Err(ValidationError::InvalidInput(
+            "Only one input can be read from stdin ('-')".to_string(),
+        ));
+    }
+    Ok(())
 }
 
 /// Environment types for validation context
@@ -130,35 +449,53 @@ impl std::fmt::Display for ValidationEnvironment {
     }
 }
 
+impl From<EnvironmentRef> for ValidationEnvironment {
+    fn from(env: EnvironmentRef) -> Self {
+        match env {
+            EnvironmentRef::Base => ValidationEnvironment::Base,
+            EnvironmentRef::Development => ValidationEnvironment::Development,
+            EnvironmentRef::Staging => ValidationEnvironment::Staging,
+            EnvironmentRef::Production => ValidationEnvironment::Production,
+            EnvironmentRef::Edge => ValidationEnvironment::Edge,
+        }
+    }
+}
+
 impl std::str::FromStr for ValidationEnvironment {
     type Err = String;
 
+    /// Parse with the same names and aliases as [`EnvironmentRef`]
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s.to_lowercase().as_str() {
-            "base" => Ok(ValidationEnvironment::Base),
-            "dev" | "development" => Ok(ValidationEnvironment::Development),
-            "staging" | "stage" => Ok(ValidationEnvironment::Staging),
-            "prod" | "production" => Ok(ValidationEnvironment::Production),
-            "edge" => Ok(ValidationEnvironment::Edge),
-            _ => Err(format!("Unknown environment: {}", s)),
-        }
+        s.parse::<EnvironmentRef>()
+            .map(ValidationEnvironment::from)
+            .map_err(|e| e.to_string())
     }
 }
 
 /// Execute the validate command
 pub fn execute_validate(
     config: PathBuf,
-    schema: Option<PathBuf>,
-    environment: String,
-    format: Option<OutputFormat>,
-    strict: bool,
+    args: ResolvedValidateArgs,
+    group_by: Option<GroupBy>,
+    summary_only: bool,
+    max_findings: Option<usize>,
+    input_format: Option<InputFormat>,
 ) -> Result<ExitCode, ValidationError> {
-    use crate::validation::{ValidationContext, ValidationSeverity, Validator};
+    use crate::validation::{ValidationContext, Validator};
+
+    let ResolvedValidateArgs {
+        schema,
+        environment,
+        format,
+        strict,
+    } = args;
+
+    ensure_stdin_once([config.as_path()].into_iter().chain(schema.as_deref()))?;
 
     // Parse environment
-    let env: ValidationEnvironment = environment
+    let env: EnvironmentRef = environment
         .parse()
-        .map_err(|e: String| ValidationError::InvalidInput(e))?;
+        .map_err(|e: ParseEnvironmentError| ValidationError::InvalidInput(e.to_string()))?;
 
     // Create validation context
     let context = ValidationContext::new()
@@ -166,71 +503,54 @@ pub fn execute_validate(
         .with_strict_mode(strict);
 
     // Load configuration
-    let config_content = std::fs::read_to_string(&config).map_err(|e| {
-        ValidationError::FileError(format!(
-            "Failed to read config file '{}': {}",
-            config.display(),
-            e
-        ))
-    })?;
+    let config_content = read_text_file(&config, "config")?;
 
-    // Parse configuration based on extension
-    let config_value = parse_config_file(&config, &config_content)?;
+    // Parse configuration based on extension, noting keys the source repeats
+    let (config_value, duplicates) =
+        parse_config_source(&config, &config_content, input_format)?;
 
     // Create validator
     let mut validator = Validator::new(context);
 
     // Load schema if provided
     if let Some(schema_path) = &schema {
-        let schema_content = std::fs::read_to_string(schema_path).map_err(|e| {
-            ValidationError::FileError(format!(
-                "Failed to read schema file '{}': {}",
-                schema_path.display(),
-                e
-            ))
-        })?;
-        validator.load_schema(&schema_content)?;
+        load_schema_file(&mut validator, schema_path, input_format)?;
     }
 
     // Perform validation
-    let result = validator.validate(&config_value)?;
+    let result = validator.validate_source(&config_value, &duplicates)?;
 
     // Format and output results
-    let output_format = format.unwrap_or(OutputFormat::Table);
-    let output = ValidationOutput::from_result(&result);
-    output.render(output_format)?;
-
-    // Determine exit code
-    let has_errors = result
-        .findings
-        .iter()
-        .any(|f| f.severity == ValidationSeverity::Error);
-    let has_warnings = result
-        .findings
-        .iter()
-        .any(|f| f.severity == ValidationSeverity::Warning);
+    let output_format = format;
+    let mut output = ValidationOutput::from_result(&result);
+    if summary_only {
+        SummaryOutput::from_output(&output).render(output_format)?;
+    } else {
+        if let Some(max) = max_findings {
+            output = output.with_max_findings(max);
+        }
+        if let Some(group_by) = group_by {
+            output = output.with_grouping(group_by);
+        }
+        output.render(output_format)?;
+    }
 
-    Ok(ExitCode::from_validation_result(has_errors, has_warnings))
+    Ok(output.exit_code())
 }
 
 /// Execute the inspect command
 pub fn execute_inspect(
     config: PathBuf,
     format: Option<OutputFormat>,
+    input_format: Option<InputFormat>,
 ) -> Result<ExitCode, ValidationError> {
     use crate::schema::{SchemaInference, TypeInfo};
 
     // Load configuration
-    let config_content = std::fs::read_to_string(&config).map_err(|e| {
-        ValidationError::FileError(format!(
-            "Failed to read config file '{}': {}",
-            config.display(),
-            e
-        ))
-    })?;
+    let config_content = read_text_file(&config, "config")?;
 
     // Parse configuration
-    let config_value = parse_config_file(&config, &config_content)?;
+    let config_value = parse_config_file(&config, &config_content, input_format)?;
 
     // Infer schema
     let inference = SchemaInference::new();
@@ -250,7 +570,7 @@ pub fn execute_inspect(
                 .map_err(|e| ValidationError::SerializationError(e.to_string()))?;
             println!("{}", yaml);
         }
-        OutputFormat::Table => {
+        OutputFormat::Table | OutputFormat::Html => {
             print_schema_table(&inferred_schema, &config);
         }
     }
@@ -258,12 +578,159 @@ pub fn execute_inspect(
     Ok(ExitCode::Success)
 }
 
+/// Execute the normalize command
+pub fn execute_normalize(
+    config: PathBuf,
+    schema: Option<PathBuf>,
+    format: Option<OutputFormat>,
+    input_format: Option<InputFormat>,
+) -> Result<ExitCode, ValidationError> {
+    ensure_stdin_once([config.as_path()].into_iter().chain(schema.as_deref()))?;
+    let config_content = read_text_file(&config, "config")?;
+    let config_value = parse_config_file(&config, &config_content, input_format)?;
+
+    let schema_value = match &schema {
+        Some(schema_path) => {
+            let schema_content = read_text_file(schema_path, "schema")?;
+            let schema_value = parse_config_file(schema_path, &schema_content, input_format)
+                .map_err(|e| ValidationError::SchemaError(format!("Invalid schema: {}", e)))?;
+            crate::validation::check_schema_shape(&schema_value)?;
+            Some(schema_value)
+        }
+        None => None,
+    };
+
+    let normalized = crate::normalize::normalize(&config_value, schema_value.as_ref())?;
+
+    match format.unwrap_or(OutputFormat::Json) {
+        OutputFormat::Yaml => {
+            let yaml = serde_yaml::to_string(&normalized)
+                .map_err(|e| ValidationError::SerializationError(e.to_string()))?;
+            print!("{}", yaml);
+        }
+        OutputFormat::Json | OutputFormat::Table | OutputFormat::Html => {
+            let json = String::from_utf8(crate::normalize::canonical_bytes(&normalized))
+                .map_err(|e| ValidationError::SerializationError(e.to_string()))?;
+            println!("{}", json);
+        }
+    }
+
+    Ok(ExitCode::Success)
+}
+
+/// Execute the explain command
+pub fn execute_explain(
+    config: PathBuf,
+    environment: &str,
+    namespace: &str,
+    format: Option<OutputFormat>,
+    input_format: Option<InputFormat>,
+) -> Result<ExitCode, ValidationError> {
+    use crate::engine::ValidationEngine;
+    use crate::{ConfigValue, Environment};
+
+    let environment: Environment = environment
+        .parse::<EnvironmentRef>()
+        .map_err(|e: ParseEnvironmentError| ValidationError::InvalidInput(e.to_string()))?
+        .to_string()
+        .parse()
+        .map_err(ValidationError::InvalidInput)?;
+
+    let config_content = read_text_file(&config, "config")?;
+    let config_value = parse_config_file(&config, &config_content, input_format)?;
+    let value: ConfigValue =
+        serde_json::from_value(crate::handler::routes::strip_nulls(&config_value)).map_err(|e| {
+            ValidationError::InvalidInput(format!("Config cannot be evaluated by rules: {}", e))
+        })?;
+
+    let explained = ValidationEngine::new().explain(&value, environment, namespace);
+
+    match format.unwrap_or(OutputFormat::Table) {
+        OutputFormat::Json => {
+            let json = serde_json::to_string_pretty(&explained)
+                .map_err(|e| ValidationError::SerializationError(e.to_string()))?;
+            println!("{}", json);
+        }
+        OutputFormat::Yaml => {
+            let yaml = serde_yaml::to_string(&explained)
+                .map_err(|e| ValidationError::SerializationError(e.to_string()))?;
+            println!("{}", yaml);
+        }
+        OutputFormat::Table | OutputFormat::Html => {
+            print_explain_table(&explained, environment, namespace);
+        }
+    }
+
+    Ok(ExitCode::Success)
+}
+
+/// Execute the schema coverage command
+pub fn execute_schema_coverage(
+    schema: PathBuf,
+    format: Option<OutputFormat>,
+    input_format: Option<InputFormat>,
+    strict_schema: bool,
+) -> Result<ExitCode, ValidationError> {
+    use crate::contracts::{ConfigSchema, SchemaDefinition};
+
+    let schema_content = read_text_file(&schema, "schema")?;
+    let schema_value = parse_config_file(&schema, &schema_content, input_format)?;
+
+    // Accept a bare schema or one wrapped in a definition document
+    let is_definition = schema_value.get("schema_format_version").is_some();
+    if strict_schema {
+        let unknown = if is_definition {
+            SchemaDefinition::unknown_keys(&schema_value)
+        } else {
+            ConfigSchema::unknown_keys(&schema_value)
+        };
+        if !unknown.is_empty() {
+            return Err(ValidationError::SchemaError(format!(
+                "Unknown schema keys (--strict-schema): {}",
+                unknown.join(", ")
+            )));
+        }
+    }
+    let parsed = if let Some(version) = schema_value.get("schema_format_version") {
+        let warning = SchemaDefinition::check_format_version(version.as_str().unwrap_or_default())
+            .map_err(ValidationError::SchemaError)?;
+        if let Some(warning) = warning {
+            eprintln!("Warning: {}", warning);
+        }
+        serde_json::from_value::<SchemaDefinition>(schema_value).map(|d| d.schema)
+    } else {
+        serde_json::from_value::<ConfigSchema>(schema_value)
+    };
+    let config_schema =
+        parsed.map_err(|e| ValidationError::SchemaError(format!("Invalid schema: {}", e)))?;
+    let report = config_schema.coverage_report();
+
+    match format.unwrap_or(OutputFormat::Table) {
+        OutputFormat::Json => {
+            let json = serde_json::to_string_pretty(&report)
+                .map_err(|e| ValidationError::SerializationError(e.to_string()))?;
+            println!("{}", json);
+        }
+        OutputFormat::Yaml => {
+            let yaml = serde_yaml::to_string(&report)
+                .map_err(|e| ValidationError::SerializationError(e.to_string()))?;
+            println!("{}", yaml);
+        }
+        OutputFormat::Table | OutputFormat::Html => {
+            print_coverage_table(&report);
+        }
+    }
+
+    Ok(ExitCode::Success)
+}
+
 /// Execute the compatibility command
 pub fn execute_compatibility(
     configs: Vec<PathBuf>,
     format: Option<OutputFormat>,
+    input_format: Option<InputFormat>,
 ) -> Result<ExitCode, ValidationError> {
-    use crate::compatibility::{CompatibilityChecker, CompatibilityResult};
+    use crate::compatibility::CompatibilityChecker;
 
     if configs.len() < 2 {
         return Err(ValidationError::InvalidInput(
@@ -272,16 +739,11 @@ pub fn execute_compatibility(
     }
 
     // Load all configurations
+    ensure_stdin_once(configs.iter().map(PathBuf::as_path))?;
     let mut config_values = Vec::new();
     for config_path in &configs {
-        let content = std::fs::read_to_string(config_path).map_err(|e| {
-            ValidationError::FileError(format!(
-                "Failed to read config file '{}': {}",
-                config_path.display(),
-                e
-            ))
-        })?;
-        let value = parse_config_file(config_path, &content)?;
+        let content = read_text_file(config_path, "config")?;
+        let value = parse_config_file(config_path, &content, input_format)?;
         config_values.push((config_path.clone(), value));
     }
 
@@ -291,47 +753,516 @@ pub fn execute_compatibility(
 
     // Format and output results
     let output_format = format.unwrap_or(OutputFormat::Table);
+    let output = CompatibilityOutput::from_result(&result);
 
     match output_format {
         OutputFormat::Json => {
-            let json = serde_json::to_string_pretty(&result)
+            let json = serde_json::to_string_pretty(&output)
                 .map_err(|e| ValidationError::SerializationError(e.to_string()))?;
             println!("{}", json);
         }
         OutputFormat::Yaml => {
-            let yaml = serde_yaml::to_string(&result)
+            let yaml = serde_yaml::to_string(&output)
                 .map_err(|e| ValidationError::SerializationError(e.to_string()))?;
             println!("{}", yaml);
         }
-        OutputFormat::Table => {
+        OutputFormat::Table | OutputFormat::Html => {
             print_compatibility_table(&result);
         }
     }
 
-    // Determine exit code
-    let has_errors = !result.is_compatible;
-    let has_warnings = !result.warnings.is_empty();
+    Ok(output.exit_code())
+}
+
+/// Load one config layer per environment from a directory
+///
+/// Files are matched to environments by name (`prod.yaml`,
+/// `development.json`, ...); files with other names, such as the schema, are
+/// ignored.
+pub fn load_environment_dir(
+    dir: &std::path::Path,
+) -> Result<crate::environments::EnvironmentLayers, ValidationError> {
+    let entries = std::fs::read_dir(dir).map_err(|e| {
+        ValidationError::FileError(format!(
+            "Failed to read config directory '{}': {}",
+            dir.display(),
+            e
+        ))
+    })?;
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.is_file())
+        .collect();
+    paths.sort();
+
+    let mut layers = crate::environments::EnvironmentLayers::new();
+    let mut sources: std::collections::BTreeMap<EnvironmentRef, PathBuf> = Default::default();
+    for path in paths {
+        let Some(env) = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .and_then(|stem| stem.parse::<EnvironmentRef>().ok())
+        else {
+            continue;
+        };
+        if let Some(previous) = sources.get(&env) {
+            return Err(ValidationError::InvalidInput(format!(
+                "Both '{}' and '{}' configure {}",
+                previous.display(),
+                path.display(),
+                env
+            )));
+        }
+
+        let content = read_text_file(&path, "config")?;
+        layers.insert(env, parse_config_file(&path, &content, None)?);
+        sources.insert(env, path);
+    }
+
+    if layers.is_empty() {
+        return Err(ValidationError::InvalidInput(format!(
+            "No environment configs found in '{}' (expected files named base, dev, staging, prod, or edge)",
+            dir.display()
+        )));
+    }
+    Ok(layers)
+}
+
+/// Expand files and directories into the config files to validate
+///
+/// Directories are not searched recursively and contribute their json, yaml,
+/// yml, and toml files in name order. Duplicates are validated once.
+pub fn collect_config_files(paths: &[PathBuf]) -> Result<Vec<PathBuf>, ValidationError> {
+    const EXTENSIONS: [&str; 4] = ["json", "yaml", "yml", "toml"];
+
+    let mut files = Vec::new();
+    for path in paths {
+        if !path.is_dir() {
+            files.push(path.clone());
+            continue;
+        }
+        let entries = std::fs::read_dir(path).map_err(|e| {
+            ValidationError::FileError(format!(
+                "Failed to read config directory '{}': {}",
+                path.display(),
+                e
+            ))
+        })?;
+        let mut found: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|p| p.is_file())
+            .filter(|p| {
+                p.extension()
+                    .and_then(|e| e.to_str())
+                    .is_some_and(|e| EXTENSIONS.contains(&e.to_lowercase().as_str()))
+            })
+            .collect();
+        found.sort();
+        files.extend(found);
+    }
+
+    let mut seen = std::collections::HashSet::new();
+    files.retain(|f| seen.insert(f.clone()));
+    if files.is_empty() {
+        return Err(ValidationError::InvalidInput(
+            "No configuration files found to validate".to_string(),
+        ));
+    }
+    Ok(files)
+}
+
+/// Validate each file and collect the outcomes into one report
+///
+/// Files that cannot be read or parsed are reported as failed rather than
+/// aborting the run; an unreadable or invalid schema aborts.
+pub fn validate_files(
+    files: &[PathBuf],
+    schema: Option<&std::path::Path>,
+    environment: &str,
+    strict: bool,
+) -> Result<AggregateReport, ValidationError> {
+    use crate::validation::{ValidationContext, Validator};
+
+    let env: EnvironmentRef = environment
+        .parse()
+        .map_err(|e: ParseEnvironmentError| ValidationError::InvalidInput(e.to_string()))?;
+    let context = ValidationContext::new()
+        .with_environment(&env.to_string())
+        .with_strict_mode(strict);
+    let mut validator = Validator::new(context);
+    if let Some(schema_path) = schema {
+        load_schema_file(&mut validator, schema_path, None)?;
+    }
+
+    let mut report = AggregateReport::new();
+    for file in files {
+        let name = file.display().to_string();
+        let outcome = read_text_file(file, "config")
+            .and_then(|content| parse_config_source(file, &content, None))
+            .and_then(|(config, duplicates)| validator.validate_source(&config, &duplicates));
+        report.push(match outcome {
+            Ok(result) => FileReport::from_result(name, &result),
+            Err(e) => FileReport::failed(name, &e),
+        });
+    }
+    Ok(report)
+}
+
+/// Execute the batch command
+pub fn execute_batch(
+    configs: Vec<PathBuf>,
+    schema: Option<PathBuf>,
+    environment: String,
+    format: Option<OutputFormat>,
+    strict: bool,
+    report_path: Option<PathBuf>,
+) -> Result<ExitCode, ValidationError> {
+    let files = collect_config_files(&configs)?;
+    let report = validate_files(&files, schema.as_deref(), &environment, strict)?;
+
+    if let Some(path) = &report_path {
+        report.write_to(path, ReportFormat::from_path(path))?;
+    }
+
+    match format.unwrap_or(OutputFormat::Table) {
+        OutputFormat::Json => {
+            let json = serde_json::to_string_pretty(&report)
+                .map_err(|e| ValidationError::SerializationError(e.to_string()))?;
+            println!("{}", json);
+        }
+        OutputFormat::Yaml => {
+            let yaml = serde_yaml::to_string(&report)
+                .map_err(|e| ValidationError::SerializationError(e.to_string()))?;
+            println!("{}", yaml);
+        }
+        OutputFormat::Html => print!("{}", report.to_html()),
+        OutputFormat::Table => {
+            print_batch_table(&report);
+            if let Some(path) = &report_path {
+                println!("Report written to {}", path.display());
+            }
+        }
+    }
+
+    Ok(ExitCode::from_severities(
+        report.has_critical(),
+        report.has_errors(),
+        report.has_warnings(),
+    ))
+}
+
+/// Print one status line per file followed by the totals
+fn print_batch_table(report: &AggregateReport) {
+    use colored::Colorize;
 
-    Ok(ExitCode::from_validation_result(has_errors, has_warnings))
+    println!();
+    println!("{}", "Batch Validation".cyan().bold());
+    println!("{}", "=".repeat(60));
+    for file in &report.files {
+        let icon = match file.status() {
+            "valid" => "+".green(),
+            "warnings" => "!".yellow(),
+            _ => "x".red(),
+        };
+        match &file.failure {
+            Some(failure) => println!("{} {}: {}", icon, file.path, failure),
+            None => println!(
+                "{} {} ({} error(s), {} warning(s))",
+                icon, file.path, file.error_count, file.warning_count
+            ),
+        }
+    }
+    let t = &report.totals;
+    println!();
+    println!(
+        "{} file(s): {} valid, {} invalid; {} error(s), {} warning(s)",
+        t.files, t.valid_files, t.invalid_files, t.errors, t.warnings
+    );
 }
 
-/// Parse a configuration file based on its extension
+/// Execute the environments command
+pub fn execute_environments(
+    dir: PathBuf,
+    schema: Option<PathBuf>,
+    format: Option<OutputFormat>,
+    strict: bool,
+) -> Result<ExitCode, ValidationError> {
+    let layers = load_environment_dir(&dir)?;
+
+    let schema_content = match &schema {
+        Some(schema_path) => Some(read_text_file(schema_path, "schema")?),
+        None => None,
+    };
+
+    let report =
+        crate::environments::validate_environments(&layers, schema_content.as_deref(), strict)?;
+    let output = EnvironmentsOutput::from_report(&report);
+
+    match format.unwrap_or(OutputFormat::Table) {
+        OutputFormat::Json => {
+            let json = serde_json::to_string_pretty(&output)
+                .map_err(|e| ValidationError::SerializationError(e.to_string()))?;
+            println!("{}", json);
+        }
+        OutputFormat::Yaml => {
+            let yaml = serde_yaml::to_string(&output)
+                .map_err(|e| ValidationError::SerializationError(e.to_string()))?;
+            println!("{}", yaml);
+        }
+        OutputFormat::Table | OutputFormat::Html => print_environments_table(&report),
+    }
+
+    Ok(output.exit_code())
+}
+
+/// Execute the replay command
+pub fn execute_replay(
+    record: PathBuf,
+    format: Option<OutputFormat>,
+) -> Result<ExitCode, ValidationError> {
+    use crate::audit::{self, ReplayReport};
+    use crate::engine::ValidationEngine;
+
+    let records = audit::read_records(&record).map_err(|e| {
+        ValidationError::FileError(format!(
+            "Failed to read audit records '{}': {}",
+            record.display(),
+            e
+        ))
+    })?;
+    if records.is_empty() {
+        return Err(ValidationError::InvalidInput(format!(
+            "No audit records in '{}'",
+            record.display()
+        )));
+    }
+
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .map_err(|e| ValidationError::InternalError(e.to_string()))?;
+    let engine = ValidationEngine::new();
+    let reports = runtime.block_on(async {
+        let mut reports = Vec::with_capacity(records.len());
+        for record in &records {
+            reports.push(audit::replay(&engine, record).await?);
+        }
+        Ok::<_, ValidationError>(reports)
+    })?;
+
+    match format.unwrap_or(OutputFormat::Table) {
+        OutputFormat::Json => {
+            let json = serde_json::to_string_pretty(&reports)
+                .map_err(|e| ValidationError::SerializationError(e.to_string()))?;
+            println!("{}", json);
+        }
+        OutputFormat::Yaml => {
+            let yaml = serde_yaml::to_string(&reports)
+                .map_err(|e| ValidationError::SerializationError(e.to_string()))?;
+            println!("{}", yaml);
+        }
+        OutputFormat::Table | OutputFormat::Html => {
+            print_replay_table(&reports);
+        }
+    }
+
+    if reports.iter().all(ReplayReport::is_reproduced) {
+        Ok(ExitCode::Success)
+    } else {
+        Ok(ExitCode::ValidationError)
+    }
+}
+
+/// Execute the doctor command
+pub fn execute_doctor(
+    telemetry_url: Option<String>,
+    format: Option<OutputFormat>,
+) -> Result<ExitCode, ValidationError> {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .map_err(|e| ValidationError::InternalError(e.to_string()))?;
+    let report = runtime.block_on(crate::doctor::run(telemetry_url.as_deref()));
+
+    match format.unwrap_or(OutputFormat::Table) {
+        OutputFormat::Json => {
+            let json = serde_json::to_string_pretty(&report)
+                .map_err(|e| ValidationError::SerializationError(e.to_string()))?;
+            println!("{}", json);
+        }
+        OutputFormat::Yaml => {
+            let yaml = serde_yaml::to_string(&report)
+                .map_err(|e| ValidationError::SerializationError(e.to_string()))?;
+            println!("{}", yaml);
+        }
+        OutputFormat::Table | OutputFormat::Html => {
+            print!("{}", report.render());
+        }
+    }
+
+    if report.passed() {
+        Ok(ExitCode::Success)
+    } else {
+        Ok(ExitCode::ValidationError)
+    }
+}
+
+/// Execute the completions command
+pub fn execute_completions(shell: Shell) -> Result<ExitCode, ValidationError> {
+    let mut stdout = std::io::stdout();
+    write_completions(shell, &mut stdout);
+    Ok(ExitCode::Success)
+}
+
+/// Write the completion script for the given shell to a writer
+pub fn write_completions<W: Write>(shell: Shell, out: &mut W) {
+    let mut command = ValidateCli::command();
+    let name = command.get_name().to_string();
+    clap_complete::generate(shell, &mut command, name, out);
+}
+
+/// Read a text file, such as a config or schema, as UTF-8
+///
+/// A leading UTF-8 byte order mark is stripped. Binary files, UTF-16 files
+/// and invalid UTF-8 are reported as a [`ValidationError::FileError`] naming
+/// the file and, for invalid UTF-8, the byte offset of the first bad byte.
+/// `kind` names the file in messages ("config", "schema", ...). The path
+/// `-` reads stdin.
+pub fn read_text_file(path: &Path, kind: &str) -> Result<String, ValidationError> {
+    const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+    // Git's heuristic: text files have no NUL bytes near the start
+    const SNIFF_LEN: usize = 8000;
+
+    let source = if is_stdin(path) {
+        format!("{} from stdin", kind)
+    } else {
+        format!("{} file '{}'", kind, path.display())
+    };
+    let read = if is_stdin(path) {
+        let mut bytes = Vec::new();
+        std::io::stdin().read_to_end(&mut bytes).map(|_| bytes)
+    } else {
+        std::fs::read(path)
+    };
+    let bytes = read
+        .map_err(|e| ValidationError::FileError(format!("Failed to read {}: {}", source, e)))?;
+
+    if bytes.starts_with(b"\xFF\xFE") || bytes.starts_with(b"\xFE\xFF") {
+        return Err(ValidationError::FileError(format!(
+            "{} is UTF-16 encoded; save it as UTF-8",
+            capitalize(&source)
+        )));
+    }
+    let (offset, text) = match bytes.strip_prefix(UTF8_BOM) {
+        Some(text) => (UTF8_BOM.len(), text),
+        None => (0, bytes.as_slice()),
+    };
+    if text[..text.len().min(SNIFF_LEN)].contains(&0) {
+        return Err(ValidationError::FileError(format!(
+            "{} looks like a binary file, not text",
+            capitalize(&source)
+        )));
+    }
+
+    std::str::from_utf8(text).map(str::to_string).map_err(|e| {
+        ValidationError::FileError(format!(
+            "{} is not valid UTF-8: invalid byte at offset {}",
+            capitalize(&source),
+            offset + e.valid_up_to()
+        ))
+    })
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Read a JSON Schema file in any supported format into a validator
+///
+/// The schema's shape is checked before any config is validated against it,
+/// so a config passed as the schema fails here with a schema error.
+fn load_schema_file(
+    validator: &mut crate::validation::Validator,
+    path: &Path,
+    input_format: Option<InputFormat>,
+) -> Result<(), ValidationError> {
+    let content = read_text_file(path, "schema")?;
+    let schema = parse_config_file(path, &content, input_format)
+        .map_err(|e| ValidationError::SchemaError(format!("Invalid schema: {}", e)))?;
+    validator.load_schema_value(schema)
+}
+
+/// Parse a configuration file in `input_format`, or based on its extension
+///
+/// Stdin, files with an unknown extension, and `InputFormat::Auto` fall back
+/// to detecting the format from the content.
 fn parse_config_file(
-    path: &PathBuf,
+    path: &Path,
     content: &str,
+    input_format: Option<InputFormat>,
 ) -> Result<serde_json::Value, ValidationError> {
-    let extension = path
-        .extension()
-        .and_then(|e| e.to_str())
-        .unwrap_or("")
-        .to_lowercase();
-
-    match extension.as_str() {
-        "json" => serde_json::from_str(content)
+    let format = resolve_config_format(path, content, input_format)?;
+    parse_config_as(content, format)
+}
+
+/// Parse a configuration file and find the keys its source repeats, which
+/// parsing alone would silently collapse
+fn parse_config_source(
+    path: &Path,
+    content: &str,
+    input_format: Option<InputFormat>,
+) -> Result<(serde_json::Value, Vec<crate::duplicate_keys::DuplicateKey>), ValidationError> {
+    use crate::duplicate_keys::{json_duplicate_keys, yaml_duplicate_keys};
+
+    let format = resolve_config_format(path, content, input_format)?;
+    let value = parse_config_as(content, format)?;
+    let duplicates = match format {
+        InputFormat::Json => json_duplicate_keys(content),
+        InputFormat::Yaml => yaml_duplicate_keys(content),
+        // The TOML parser rejects duplicate keys itself
+        InputFormat::Toml | InputFormat::Auto => Vec::new(),
+    };
+    Ok((value, duplicates))
+}
+
+/// The format of a configuration file: the explicit one, else the file
+/// extension's, else the one detected from its content
+fn resolve_config_format(
+    path: &Path,
+    content: &str,
+    input_format: Option<InputFormat>,
+) -> Result<InputFormat, ValidationError> {
+    Ok(match input_format.or_else(|| InputFormat::from_extension(path)) {
+        Some(InputFormat::Auto) | None => {
+            let source = if is_stdin(path) {
+                "stdin".to_string()
+            } else {
+                format!("'{}'", path.display())
+            };
+            let format = InputFormat::detect(content).ok_or_else(|| {
+                ValidationError::InvalidInput(format!(
+                    "Could not detect the format of {}; pass --input-format json, yaml, or toml",
+                    source
+                ))
+            })?;
+            tracing::info!("Detected {} in {}", format, source);
+            format
+        }
+        Some(format) => format,
+    })
+}
+
+/// Parse configuration text in a resolved format
+fn parse_config_as(content: &str, format: InputFormat) -> Result<serde_json::Value, ValidationError> {
+    match format {
+        InputFormat::Json => serde_json::from_str(content)
             .map_err(|e| ValidationError::ParseError(format!("Invalid JSON: {}", e))),
-        "yaml" | "yml" => serde_yaml::from_str(content)
+        InputFormat::Yaml => serde_yaml::from_str(content)
             .map_err(|e| ValidationError::ParseError(format!("Invalid YAML: {}", e))),
-        "toml" => {
+        InputFormat::Toml => {
             let toml_value: toml::Value = toml::from_str(content)
                 .map_err(|e| ValidationError::ParseError(format!("Invalid TOML: {}", e)))?;
             // Convert TOML to JSON Value for uniform processing
@@ -340,20 +1271,22 @@ fn parse_config_file(
             serde_json::from_str(&json_str)
                 .map_err(|e| ValidationError::ParseError(format!("Conversion error: {}", e)))
         }
-        _ => Err(ValidationError::InvalidInput(format!(
-            "Unsupported file format: {}. Supported formats: json, yaml, yml, toml",
-            extension
-        ))),
+        InputFormat::Auto => unreachable!("auto is resolved to a detected format by resolve_config_format"),
     }
 }
 
 /// Print schema inspection results in table format
-fn print_schema_table(schema: &crate::schema::InferredSchema, config_path: &PathBuf) {
+fn print_schema_table(schema: &crate::schema::InferredSchema, config_path: &Path) {
     use colored::Colorize;
 
+    let source = if is_stdin(config_path) {
+        "stdin".to_string()
+    } else {
+        config_path.display().to_string()
+    };
     println!(
         "{}",
-        format!("Configuration Schema: {}", config_path.display())
+        format!("Configuration Schema: {}", source)
             .green()
             .bold()
     );
@@ -484,6 +1417,162 @@ fn print_compatibility_table(result: &crate::compatibility::CompatibilityResult)
     }
 }
 
+fn print_environments_table(report: &crate::environments::EnvironmentsReport) {
+    use colored::Colorize;
+
+    println!("{}", "Environment Validation Results".cyan().bold());
+    println!();
+
+    for findings in &report.environments {
+        let errors = findings.result.errors().len();
+        let warnings = findings.result.warnings().len();
+        let status = if errors > 0 {
+            "x".red()
+        } else if warnings > 0 {
+            "!".yellow()
+        } else {
+            "+".green()
+        };
+        println!(
+            "{} {:<12} {} error(s), {} warning(s)",
+            status,
+            findings.environment.to_string(),
+            errors,
+            warnings
+        );
+        for finding in &findings.result.findings {
+            println!(
+                "    {} {} at '{}': {}",
+                finding.severity.to_string().dimmed(),
+                finding.code,
+                finding.path,
+                finding.message
+            );
+        }
+    }
+    println!();
+
+    if report.violations.is_empty() {
+        println!("{} No cross-environment violations", "+".green());
+    } else {
+        println!("{}", "Cross-environment violations:".red().bold());
+        for violation in &report.violations {
+            let marker = if violation.blocking {
+                "x".red()
+            } else {
+                "!".yellow()
+            };
+            println!("  {} [{}] {}", marker, violation.rule_id, violation.message);
+        }
+    }
+}
+
+fn print_replay_table(reports: &[crate::audit::ReplayReport]) {
+    use colored::Colorize;
+
+    println!("{}", "Replay Results".cyan().bold());
+    println!();
+
+    for report in reports {
+        if report.is_reproduced() {
+            println!("  {} {}", "ok".green(), report.event_id);
+            continue;
+        }
+
+        println!("  {} {}", "x".red(), report.event_id);
+        for divergence in &report.divergences {
+            println!(
+                "    {} recorded {} replayed {}",
+                format!("{}:", divergence.field).dimmed(),
+                divergence.recorded.to_string().yellow(),
+                divergence.replayed.to_string().yellow()
+            );
+        }
+    }
+
+    let diverged = reports.iter().filter(|r| !r.is_reproduced()).count();
+    println!();
+    println!("{} of {} records reproduced", reports.len() - diverged, reports.len());
+}
+
+fn print_explain_table(
+    explained: &[crate::engine::RuleApplicability],
+    environment: crate::Environment,
+    namespace: &str,
+) {
+    use colored::Colorize;
+
+    println!("{}", "Rule Applicability".cyan().bold());
+    println!("Environment: {}", environment);
+    if !namespace.is_empty() {
+        println!("Namespace: {}", namespace);
+    }
+    println!();
+
+    for rule in explained {
+        let mark = if rule.applicable {
+            "ok".green()
+        } else {
+            "skip".yellow()
+        };
+        println!(
+            "  {} {} {}",
+            mark,
+            rule.rule_id,
+            format!("({}, {})", rule.name, rule.category).dimmed()
+        );
+        if let Some(reason) = &rule.reason {
+            println!("    {}", reason.dimmed());
+        }
+    }
+
+    let applicable = explained.iter().filter(|r| r.applicable).count();
+    println!();
+    println!("{} of {} rules apply", applicable, explained.len());
+}
+
+fn print_coverage_table(report: &crate::contracts::SchemaCoverageReport) {
+    use colored::Colorize;
+
+    println!("{}", "Schema Coverage".cyan().bold());
+    println!("Schema: {}", report.schema_id);
+    println!("Fields: {}", report.total_fields);
+    println!();
+
+    let rows = [
+        (
+            "Constraints",
+            report.constrained_fields,
+            report.constraint_coverage(),
+            &report.unconstrained,
+        ),
+        (
+            "Descriptions",
+            report.described_fields,
+            report.description_coverage(),
+            &report.undescribed,
+        ),
+        (
+            "Environment rules",
+            report.environment_covered_fields,
+            report.environment_coverage(),
+            &report.without_environment_rules,
+        ),
+    ];
+    for (label, covered, ratio, missing) in rows {
+        println!(
+            "  {:<18} {:>4}/{:<4} {:>6.1}%",
+            label,
+            covered,
+            report.total_fields,
+            ratio * 100.0
+        );
+        if !missing.is_empty() {
+            println!("    {} {}", "missing:".dimmed(), missing.join(", ").yellow());
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -498,7 +1587,13 @@ mod tests {
             "production".parse::<ValidationEnvironment>().unwrap(),
             ValidationEnvironment::Production
         );
-        assert!("invalid".parse::<ValidationEnvironment>().is_err());
+        assert_eq!(
+            "Stage".parse::<ValidationEnvironment>().unwrap(),
+            ValidationEnvironment::Staging
+        );
+        let err = "invalid".parse::<ValidationEnvironment>().unwrap_err();
+        assert!(err.contains("'invalid'"));
+        assert!(err.contains("production (prod)"));
     }
 
     #[test]
@@ -508,11 +1603,32 @@ mod tests {
         assert_eq!(ValidationEnvironment::Dev.to_string(), "development");
     }
 
+    #[test]
+    fn test_bash_completions_include_subcommands() {
+        let mut buffer = Vec::new();
+        write_completions(Shell::Bash, &mut buffer);
+        let script = String::from_utf8(buffer).unwrap();
+
+        assert!(script.contains("config-validate"));
+        for subcommand in [
+            "validate",
+            "inspect",
+            "compatibility",
+            "environments",
+            "batch",
+            "replay",
+            "doctor",
+            "completions",
+        ] {
+            assert!(script.contains(subcommand), "missing subcommand {}", subcommand);
+        }
+    }
+
     #[test]
     fn test_parse_config_json() {
         let content = r#"{"key": "value", "number": 42}"#;
         let path = PathBuf::from("test.json");
-        let result = parse_config_file(&path, content);
+        let result = parse_config_file(&path, content, None);
         assert!(result.is_ok());
         let value = result.unwrap();
         assert_eq!(value["key"], "value");
@@ -523,18 +1639,156 @@ mod tests {
     fn test_parse_config_yaml() {
         let content = "key: value\nnumber: 42";
         let path = PathBuf::from("test.yaml");
-        let result = parse_config_file(&path, content);
+        let result = parse_config_file(&path, content, None);
         assert!(result.is_ok());
         let value = result.unwrap();
         assert_eq!(value["key"], "value");
         assert_eq!(value["number"], 42);
     }
 
+    #[test]
+    fn test_duplicate_yaml_key_is_reported_as_a_warning() {
+        use crate::validation::{ValidationContext, Validator};
+
+        let content = "host: db1
+port: 5432
+host: db2
+";
+        let (value, duplicates) =
+            parse_config_source(&PathBuf::from("app.yaml"), content, None).unwrap();
+        // The parser keeps the last value and forgets the first
+        assert_eq!(value["host"], "db2");
+        assert_eq!(duplicates.len(), 1);
+
+        let validator = Validator::new(ValidationContext::new());
+        let result = validator.validate_source(&value, &duplicates).unwrap();
+        let finding = result
+            .findings
+            .iter()
+            .find(|f| f.code == crate::duplicate_keys::DUPLICATE_KEY)
+            .unwrap();
+        assert_eq!(finding.path, "$.host");
+        assert_eq!(finding.severity, crate::validation::ValidationSeverity::Warning);
+        assert!(finding.message.contains("line 1") && finding.message.contains("line 3"));
+        assert!(result.valid);
+
+        let (_, duplicates) =
+            parse_config_source(&PathBuf::from("app.toml"), "host = 'db1'\n", None).unwrap();
+        assert!(duplicates.is_empty());
+    }
+
+    #[test]
+    fn test_read_text_file_strips_bom() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("app.yaml");
+        std::fs::write(&path, b"\xEF\xBB\xBFkey: value\nnumber: 42\n").unwrap();
+
+        let content = read_text_file(&path, "config").unwrap();
+        let value = parse_config_file(&path, &content, None).unwrap();
+        assert_eq!(value["key"], "value");
+        assert_eq!(value["number"], 42);
+    }
+
+    #[test]
+    fn test_read_text_file_rejects_binary_and_invalid_utf8() {
+        let dir = tempfile::tempdir().unwrap();
+        let binary = dir.path().join("app.json");
+        std::fs::write(&binary, b"\x7fELF\x02\x01\x01\x00\x00\x00").unwrap();
+        let err = read_text_file(&binary, "config").unwrap_err();
+        assert!(matches!(err, ValidationError::FileError(_)));
+        assert!(err.to_string().contains("app.json"), "{}", err);
+        assert!(err.to_string().contains("binary"), "{}", err);
+
+        let latin1 = dir.path().join("app.yaml");
+        std::fs::write(&latin1, b"name: caf\xE9\n").unwrap();
+        let err = read_text_file(&latin1, "config").unwrap_err();
+        assert!(err.to_string().contains("offset 9"), "{}", err);
+    }
+
+    #[test]
+    fn test_detect_format_from_content() {
+        assert_eq!(InputFormat::detect(r#"{"port": 8080}"#), Some(InputFormat::Json));
+        assert_eq!(InputFormat::detect("  [1, 2]\n"), Some(InputFormat::Json));
+        assert_eq!(
+            InputFormat::detect("[server]\nport = 8080\n"),
+            Some(InputFormat::Toml)
+        );
+        assert_eq!(InputFormat::detect("port = 8080"), Some(InputFormat::Toml));
+        assert_eq!(
+            InputFormat::detect("---\nserver:\n  port: 8080\n"),
+            Some(InputFormat::Yaml)
+        );
+        assert_eq!(InputFormat::detect("just some words"), None);
+        assert_eq!(InputFormat::detect(""), None);
+    }
+
+    #[test]
+    fn test_parse_config_without_extension_detects_format() {
+        let path = PathBuf::from("app");
+        let json = parse_config_file(&path, r#"{"port": 8080}"#, None).unwrap();
+        assert_eq!(json["port"], 8080);
+
+        let toml = parse_config_file(&path, "[server]\nport = 8080\n", None).unwrap();
+        assert_eq!(toml["server"]["port"], 8080);
+
+        // An explicit format wins over a misleading extension
+        let path = PathBuf::from("app.json");
+        let yaml = parse_config_file(&path, "port: 8080", Some(InputFormat::Auto)).unwrap();
+        assert_eq!(yaml["port"], 8080);
+    }
+
+    #[test]
+    fn test_config_file_as_schema_is_a_schema_error() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = dir.path().join("app.yaml");
+        std::fs::write(&config, "host: db.internal\nport: 5432\n").unwrap();
+        let args = ResolvedValidateArgs {
+            schema: Some(config.clone()),
+            environment: "development".to_string(),
+            format: OutputFormat::Json,
+            strict: false,
+        };
+
+        let err = execute_validate(config, args, None, false, None, None).unwrap_err();
+        assert!(matches!(err, ValidationError::SchemaError(_)), "{:?}", err);
+        assert!(err.to_string().contains("passed as the schema"), "{}", err);
+    }
+
+    #[test]
+    fn test_yaml_schema_is_accepted() {
+        let dir = tempfile::tempdir().unwrap();
+        let schema = dir.path().join("schema.yaml");
+        std::fs::write(&schema, "type: object\nrequired: [port]\n").unwrap();
+
+        let mut validator = crate::validation::Validator::new(Default::default());
+        load_schema_file(&mut validator, &schema, None).unwrap();
+        let result = validator.validate(&serde_json::json!({})).unwrap();
+        assert!(!result.valid);
+    }
+
+    #[test]
+    fn test_strict_schema_reports_misspelled_keys() {
+        let dir = tempfile::tempdir().unwrap();
+        let schema = dir.path().join("schema.yaml");
+        std::fs::write(
+            &schema,
+            "id: app/server\nname: Server\nversion: 1.0.0\nfields:\n  host:\n    field_type: string\n    requird: true\n",
+        )
+        .unwrap();
+
+        let lenient = execute_schema_coverage(schema.clone(), Some(OutputFormat::Json), None, false);
+        assert!(matches!(lenient, Ok(ExitCode::Success)));
+
+        let err = execute_schema_coverage(schema, Some(OutputFormat::Json), None, true).unwrap_err();
+        assert!(matches!(err, ValidationError::SchemaError(_)), "{:?}", err);
+        assert!(err.to_string().contains("fields.host.requird"), "{}", err);
+    }
+
     #[test]
     fn test_parse_config_unsupported() {
         let content = "some content";
         let path = PathBuf::from("test.txt");
-        let result = parse_config_file(&path, content);
+        let result = parse_config_file(&path, content, None);
         assert!(result.is_err());
     }
 }
@@ -0,0 +1,232 @@
+//! Project-level CLI defaults for the Config Validation Agent
+//!
+//! The CLI looks for a `.config-validate.toml` file in the current directory
+//! and then in each parent directory, using the first one it finds. The file
+//! supplies default values for flags that were not otherwise given:
+//!
+//! ```toml
+//! schema = "schemas/app.schema.json"
+//! environment = "staging"
+//! format = "json"
+//! strict = true
+//! ```
+//!
+//! Relative `schema` paths are resolved against the directory containing the
+//! defaults file, so the file works regardless of where the CLI is invoked.
+//!
+//! Precedence, highest first:
+//!
+//! 1. Explicit command-line flags (`--schema`, `--environment`, `--format`,
+//!    `--strict` / `--strict=false`)
+//! 2. Environment variables (`CONFIG_VALIDATE_SCHEMA`, `CONFIG_VALIDATE_ENVIRONMENT`,
+//!    `CONFIG_VALIDATE_FORMAT`, `CONFIG_VALIDATE_STRICT`)
+//! 3. The nearest `.config-validate.toml`
+//! 4. Built-in defaults (`production` environment, `table` format, non-strict)
+
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+
+use super::output::OutputFormat;
+use crate::error::ValidationError;
+
+/// File name searched for when discovering CLI defaults
+pub const DEFAULTS_FILE_NAME: &str = ".config-validate.toml";
+
+/// Environment used when none is given by flag, env var, or defaults file
+pub const DEFAULT_ENVIRONMENT: &str = "production";
+
+/// Default flag values loaded from a `.config-validate.toml` file
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CliDefaults {
+    /// Default schema file path
+    pub schema: Option<PathBuf>,
+    /// Default target environment
+    pub environment: Option<String>,
+    /// Default output format
+    pub format: Option<OutputFormat>,
+    /// Default strict mode
+    pub strict: Option<bool>,
+}
+
+/// Effective `validate` arguments after applying defaults
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResolvedValidateArgs {
+    /// Schema file path, if any
+    pub schema: Option<PathBuf>,
+    /// Target environment
+    pub environment: String,
+    /// Output format
+    pub format: OutputFormat,
+    /// Strict mode
+    pub strict: bool,
+}
+
+impl CliDefaults {
+    /// Load defaults from a specific file
+    ///
+    /// Relative schema paths are made relative to the file's directory.
+    pub fn load(path: &Path) -> Result<Self, ValidationError> {
+        let content = super::commands::read_text_file(path, "defaults")?;
+
+        let mut defaults: CliDefaults = toml::from_str(&content).map_err(|e| {
+            ValidationError::ParseError(format!(
+                "Invalid defaults file '{}': {}",
+                path.display(),
+                e
+            ))
+        })?;
+
+        if let (Some(schema), Some(dir)) = (&defaults.schema, path.parent()) {
+            if schema.is_relative() {
+                defaults.schema = Some(dir.join(schema));
+            }
+        }
+
+        Ok(defaults)
+    }
+
+    /// Find the nearest defaults file, starting at `start` and walking up
+    pub fn find_file(start: &Path) -> Option<PathBuf> {
+        start
+            .ancestors()
+            .map(|dir| dir.join(DEFAULTS_FILE_NAME))
+            .find(|candidate| candidate.is_file())
+    }
+
+    /// Discover and load defaults starting at `start`
+    ///
+    /// Returns empty defaults when no file is found.
+    pub fn discover(start: &Path) -> Result<Self, ValidationError> {
+        match Self::find_file(start) {
+            Some(path) => Self::load(&path),
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// Discover and load defaults starting at the current working directory
+    pub fn discover_from_cwd() -> Result<Self, ValidationError> {
+        match std::env::current_dir() {
+            Ok(cwd) => Self::discover(&cwd),
+            Err(_) => Ok(Self::default()),
+        }
+    }
+
+    /// Resolve an output format, preferring the explicit value
+    pub fn resolve_format(&self, format: Option<OutputFormat>) -> OutputFormat {
+        format.or(self.format).unwrap_or_default()
+    }
+
+    /// Resolve `validate` arguments, preferring explicit (flag or env) values
+    pub fn resolve_validate(
+        &self,
+        schema: Option<PathBuf>,
+        environment: Option<String>,
+        format: Option<OutputFormat>,
+        strict: Option<bool>,
+    ) -> ResolvedValidateArgs {
+        ResolvedValidateArgs {
+            schema: schema.or_else(|| self.schema.clone()),
+            environment: environment
+                .or_else(|| self.environment.clone())
+                .unwrap_or_else(|| DEFAULT_ENVIRONMENT.to_string()),
+            format: self.resolve_format(format),
+            strict: strict.or(self.strict).unwrap_or(false),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_defaults(dir: &Path, content: &str) -> PathBuf {
+        let path = dir.join(DEFAULTS_FILE_NAME);
+        std::fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_discover_walks_up_from_nested_directory() {
+        let root = tempfile::tempdir().unwrap();
+        write_defaults(root.path(), "environment = \"staging\"\n");
+        let nested = root.path().join("a").join("b");
+        std::fs::create_dir_all(&nested).unwrap();
+
+        let defaults = CliDefaults::discover(&nested).unwrap();
+        assert_eq!(defaults.environment.as_deref(), Some("staging"));
+    }
+
+    #[test]
+    fn test_discover_without_file_is_empty() {
+        let root = tempfile::tempdir().unwrap();
+        let defaults = CliDefaults::discover(root.path()).unwrap();
+        assert_eq!(defaults, CliDefaults::default());
+    }
+
+    #[test]
+    fn test_file_default_used_when_flag_absent() {
+        let root = tempfile::tempdir().unwrap();
+        write_defaults(
+            root.path(),
+            "schema = \"app.schema.json\"\nenvironment = \"dev\"\nformat = \"json\"\nstrict = true\n",
+        );
+        let defaults = CliDefaults::discover(root.path()).unwrap();
+
+        let resolved = defaults.resolve_validate(None, None, None, None);
+        assert_eq!(resolved.schema, Some(root.path().join("app.schema.json")));
+        assert_eq!(resolved.environment, "dev");
+        assert_eq!(resolved.format, OutputFormat::Json);
+        assert!(resolved.strict);
+    }
+
+    #[test]
+    fn test_file_default_ignored_when_flag_present() {
+        let root = tempfile::tempdir().unwrap();
+        write_defaults(
+            root.path(),
+            "schema = \"app.schema.json\"\nenvironment = \"dev\"\nformat = \"json\"\n",
+        );
+        let defaults = CliDefaults::discover(root.path()).unwrap();
+
+        let resolved = defaults.resolve_validate(
+            Some(PathBuf::from("other.json")),
+            Some("production".to_string()),
+            Some(OutputFormat::Yaml),
+            None,
+        );
+        assert_eq!(resolved.schema, Some(PathBuf::from("other.json")));
+        assert_eq!(resolved.environment, "production");
+        assert_eq!(resolved.format, OutputFormat::Yaml);
+        assert!(!resolved.strict);
+    }
+
+    #[test]
+    fn test_explicit_strict_false_overrides_file() {
+        let root = tempfile::tempdir().unwrap();
+        write_defaults(root.path(), "strict = true\n");
+        let defaults = CliDefaults::discover(root.path()).unwrap();
+
+        assert!(defaults.resolve_validate(None, None, None, None).strict);
+        assert!(!defaults.resolve_validate(None, None, None, Some(false)).strict);
+    }
+
+    #[test]
+    fn test_builtin_defaults_without_file() {
+        let resolved = CliDefaults::default().resolve_validate(None, None, None, None);
+        assert_eq!(resolved.schema, None);
+        assert_eq!(resolved.environment, DEFAULT_ENVIRONMENT);
+        assert_eq!(resolved.format, OutputFormat::Table);
+        assert!(!resolved.strict);
+    }
+
+    #[test]
+    fn test_unknown_key_is_rejected() {
+        let root = tempfile::tempdir().unwrap();
+        let path = write_defaults(root.path(), "enviroment = \"dev\"\n");
+        assert!(matches!(
+            CliDefaults::load(&path),
+            Err(ValidationError::ParseError(_))
+        ));
+    }
+}
@@ -3,12 +3,22 @@
 //! This module provides command-line interface functionality for validating
 //! configurations against schemas, inspecting configuration structures,
 //! and checking cross-agent compatibility.
+//!
+//! Flag defaults can be supplied by a `.config-validate.toml` file; see
+//! [`defaults`] for discovery and precedence rules.
 
 pub mod commands;
+pub mod defaults;
 pub mod output;
 
-pub use commands::{ValidateCli, ValidateCommands};
-pub use output::{OutputFormat, ValidationOutput};
+pub use commands::{InputFormat, SchemaCommands, ValidateCli, ValidateCommands};
+pub use defaults::CliDefaults;
+pub use output::{
+    CompatibilityOutput, EnvironmentsOutput, ErrorOutput, FindingGroup, GroupBy, OutputFormat,
+    SummaryOutput, ValidationOutput,
+};
+
+use serde::{Deserialize, Serialize};
 
 use crate::error::ValidationError;
 
@@ -28,6 +38,8 @@ pub enum ExitCode {
     FileError = 4,
     /// Schema-related errors
     SchemaError = 5,
+    /// Validation failed with critical findings, such as a plaintext secret
+    SecurityError = 6,
     /// Internal error
     InternalError = 10,
 }
@@ -49,10 +61,102 @@ impl ExitCode {
             ExitCode::Success
         }
     }
+
+    /// Determine exit code from validation result, ranking critical findings
+    /// above plain errors
+    pub fn from_severities(has_critical: bool, has_errors: bool, has_warnings: bool) -> Self {
+        if has_critical {
+            ExitCode::SecurityError
+        } else {
+            Self::from_validation_result(has_errors, has_warnings)
+        }
+    }
+
+    /// Determine exit code for an error that aborted the command
+    pub fn from_error(error: &ValidationError) -> Self {
+        match error {
+            ValidationError::InvalidInput(_) | ValidationError::ParseError(_) => {
+                ExitCode::InvalidInput
+            }
+            ValidationError::FileError(_) => ExitCode::FileError,
+            ValidationError::SchemaError(_) => ExitCode::SchemaError,
+            _ => ExitCode::InternalError,
+        }
+    }
+
+    /// Stable machine-readable reason for this exit code
+    pub fn reason(self) -> ExitReason {
+        ExitReason::from(self)
+    }
+}
+
+/// Machine-readable reason for the CLI exit status
+///
+/// Mirrors [`ExitCode`] one-to-one and is included in JSON/YAML output so
+/// scripts can branch on a stable string instead of the numeric code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExitReason {
+    /// All validations passed
+    Valid,
+    /// Validation failed with errors
+    Errors,
+    /// Validation passed with warnings
+    Warnings,
+    /// Invalid input or arguments
+    InvalidInput,
+    /// File not found or inaccessible
+    FileError,
+    /// Schema-related errors
+    SchemaError,
+    /// Validation failed with critical findings
+    Critical,
+    /// Internal error
+    Internal,
+}
+
+impl ExitReason {
+    /// Get the reason as its serialized string
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ExitReason::Valid => "valid",
+            ExitReason::Errors => "errors",
+            ExitReason::Warnings => "warnings",
+            ExitReason::InvalidInput => "invalid_input",
+            ExitReason::FileError => "file_error",
+            ExitReason::SchemaError => "schema_error",
+            ExitReason::Critical => "critical",
+            ExitReason::Internal => "internal",
+        }
+    }
+}
+
+impl From<ExitCode> for ExitReason {
+    fn from(code: ExitCode) -> Self {
+        match code {
+            ExitCode::Success => ExitReason::Valid,
+            ExitCode::ValidationError => ExitReason::Errors,
+            ExitCode::ValidationWarning => ExitReason::Warnings,
+            ExitCode::InvalidInput => ExitReason::InvalidInput,
+            ExitCode::FileError => ExitReason::FileError,
+            ExitCode::SchemaError => ExitReason::SchemaError,
+            ExitCode::SecurityError => ExitReason::Critical,
+            ExitCode::InternalError => ExitReason::Internal,
+        }
+    }
+}
+
+impl std::fmt::Display for ExitReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
 }
 
 /// Run the CLI with the given arguments and return the exit code
 pub fn run(cli: ValidateCli) -> Result<ExitCode, ValidationError> {
+    output::configure_color(cli.no_color);
+    let input_format = cli.input_format;
+
     match cli.command {
         ValidateCommands::Validate {
             config,
@@ -60,18 +164,146 @@ pub fn run(cli: ValidateCli) -> Result<ExitCode, ValidationError> {
             environment,
             format,
             strict,
+            group_by,
+            summary_only,
+            max_findings,
         } => {
-            commands::execute_validate(config, schema, environment, format, strict)
+            let args = discover_defaults(format)?
+                .resolve_validate(schema, environment, format, strict);
+            let format = args.format;
+            let result = commands::execute_validate(
+                config,
+                args,
+                group_by,
+                summary_only,
+                max_findings,
+                input_format,
+            );
+            report_error(result, format)
         }
         ValidateCommands::Inspect { config, format } => {
-            commands::execute_inspect(config, format)
+            let format = discover_defaults(format)?.resolve_format(format);
+            report_error(commands::execute_inspect(config, Some(format), input_format), format)
+        }
+        ValidateCommands::Normalize {
+            config,
+            schema,
+            format,
+        } => {
+            let args = discover_defaults(format)?.resolve_validate(schema, None, format, None);
+            let result =
+                commands::execute_normalize(config, args.schema, Some(args.format), input_format);
+            report_error(result, args.format)
+        }
+        ValidateCommands::Explain {
+            config,
+            environment,
+            namespace,
+            format,
+        } => {
+            let args = discover_defaults(format)?.resolve_validate(None, environment, format, None);
+            let result = commands::execute_explain(
+                config,
+                &args.environment,
+                &namespace,
+                Some(args.format),
+                input_format,
+            );
+            report_error(result, args.format)
+        }
+        ValidateCommands::Schema {
+            command:
+                SchemaCommands::Coverage {
+                    schema,
+                    format,
+                    strict_schema,
+                },
+        } => {
+            let format = discover_defaults(format)?.resolve_format(format);
+            let result = commands::execute_schema_coverage(
+                schema,
+                Some(format),
+                input_format,
+                strict_schema,
+            );
+            report_error(result, format)
         }
         ValidateCommands::Compatibility { configs, format } => {
-            commands::execute_compatibility(configs, format)
+            let format = discover_defaults(format)?.resolve_format(format);
+            let result = commands::execute_compatibility(configs, Some(format), input_format);
+            report_error(result, format)
+        }
+        ValidateCommands::Environments {
+            dir,
+            schema,
+            format,
+            strict,
+        } => {
+            let args = discover_defaults(format)?.resolve_validate(schema, None, format, strict);
+            let result =
+                commands::execute_environments(dir, args.schema, Some(args.format), args.strict);
+            report_error(result, args.format)
+        }
+        ValidateCommands::Batch {
+            configs,
+            schema,
+            environment,
+            format,
+            strict,
+            report,
+        } => {
+            let args = discover_defaults(format)?
+                .resolve_validate(schema, environment, format, strict);
+            let result = commands::execute_batch(
+                configs,
+                args.schema,
+                args.environment,
+                Some(args.format),
+                args.strict,
+                report,
+            );
+            report_error(result, args.format)
         }
+        ValidateCommands::Replay { record, format } => {
+            let format = discover_defaults(format)?.resolve_format(format);
+            report_error(commands::execute_replay(record, Some(format)), format)
+        }
+        ValidateCommands::Doctor {
+            telemetry_url,
+            format,
+        } => {
+            let format = discover_defaults(format)?.resolve_format(format);
+            report_error(commands::execute_doctor(telemetry_url, Some(format)), format)
+        }
+        ValidateCommands::Completions { shell } => commands::execute_completions(shell),
     }
 }
 
+/// Load the defaults file, reporting a failure in the requested output format
+///
+/// The defaults file is what normally supplies the format, so a broken one is
+/// reported using the `--format` flag, or table output when it is absent.
+fn discover_defaults(format: Option<OutputFormat>) -> Result<CliDefaults, ValidationError> {
+    CliDefaults::discover_from_cwd()
+        .or_else(|e| report_error(Err(e), format.unwrap_or_default()))
+}
+
+/// Emit a machine-readable error record for JSON/YAML output, then propagate the error
+///
+/// Table and HTML output are left to the caller, which prints the error to
+/// stderr.
+fn report_error<T>(
+    result: Result<T, ValidationError>,
+    format: OutputFormat,
+) -> Result<T, ValidationError> {
+    if let Err(e) = &result {
+        if matches!(format, OutputFormat::Json | OutputFormat::Yaml) {
+            ErrorOutput::from_error(e).render(format)?;
+        }
+    }
+    result
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -81,6 +313,7 @@ mod tests {
         assert_eq!(i32::from(ExitCode::Success), 0);
         assert_eq!(i32::from(ExitCode::ValidationError), 1);
         assert_eq!(i32::from(ExitCode::ValidationWarning), 2);
+        assert_eq!(i32::from(ExitCode::SecurityError), 6);
     }
 
     #[test]
@@ -102,4 +335,75 @@ mod tests {
             ExitCode::ValidationError
         );
     }
+
+    #[test]
+    fn test_exit_code_from_severities() {
+        assert_eq!(
+            ExitCode::from_severities(true, true, true),
+            ExitCode::SecurityError
+        );
+        assert_eq!(
+            ExitCode::from_severities(false, true, true),
+            ExitCode::ValidationError
+        );
+        assert_eq!(
+            ExitCode::from_severities(false, false, true),
+            ExitCode::ValidationWarning
+        );
+        assert_eq!(
+            ExitCode::from_severities(false, false, false),
+            ExitCode::Success
+        );
+    }
+
+    #[test]
+    fn test_exit_reason_strings() {
+        let cases = [
+            (ExitCode::Success, "valid"),
+            (ExitCode::ValidationError, "errors"),
+            (ExitCode::ValidationWarning, "warnings"),
+            (ExitCode::InvalidInput, "invalid_input"),
+            (ExitCode::FileError, "file_error"),
+            (ExitCode::SchemaError, "schema_error"),
+            (ExitCode::SecurityError, "critical"),
+            (ExitCode::InternalError, "internal"),
+        ];
+        for (code, expected) in cases {
+            let reason = code.reason();
+            assert_eq!(reason.as_str(), expected);
+            assert_eq!(
+                serde_json::to_value(reason).unwrap(),
+                serde_json::json!(expected)
+            );
+        }
+    }
+
+    #[test]
+    fn test_exit_code_from_error() {
+        let cases = [
+            (ValidationError::invalid_input("x"), ExitReason::InvalidInput),
+            (ValidationError::parse_error("x"), ExitReason::InvalidInput),
+            (ValidationError::file_error("x"), ExitReason::FileError),
+            (ValidationError::schema_error("x"), ExitReason::SchemaError),
+            (ValidationError::InternalError("x".to_string()), ExitReason::Internal),
+        ];
+        for (error, expected) in cases {
+            assert_eq!(ExitCode::from_error(&error).reason(), expected);
+        }
+    }
+
+    #[test]
+    fn test_strict_flag_parsing() {
+        use clap::Parser;
+
+        let strict_of = |args: &[&str]| match ValidateCli::try_parse_from(args).unwrap().command {
+            ValidateCommands::Validate { strict, .. } => strict,
+            _ => unreachable!(),
+        };
+
+        let base = ["config-validate", "validate", "--config", "app.yaml"];
+        assert_eq!(strict_of(&base), None);
+        assert_eq!(strict_of(&[&base[..], &["--strict"]].concat()), Some(true));
+        assert_eq!(strict_of(&[&base[..], &["--strict=false"]].concat()), Some(false));
+    }
 }
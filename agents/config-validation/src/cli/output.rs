@@ -2,17 +2,27 @@
 //!
 //! Provides structured output formatting in JSON, YAML, and human-readable table formats
 //! with severity-based coloring for validation findings.
+//!
+//! Coloring is automatically disabled when stdout is not a terminal, when the
+//! `NO_COLOR` environment variable is set, or when `--no-color` is passed.
 
 use clap::ValueEnum;
 use colored::Colorize;
 use serde::{Deserialize, Serialize};
-use std::io::{self, Write};
+use std::io::{self, IsTerminal, Write};
 
+use super::{ExitCode, ExitReason};
+use crate::compatibility::CompatibilityResult;
+use crate::environments::EnvironmentsReport;
 use crate::error::ValidationError;
+use crate::report::escape_html;
 use crate::validation::{ValidationFinding, ValidationResult, ValidationSeverity};
 
 /// Output format options for CLI results
-#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum, Debug, Default)]
+#[derive(
+    Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum, Debug, Default, Serialize, Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
 pub enum OutputFormat {
     /// Human-readable table format with colors
     #[default]
@@ -21,6 +31,69 @@ pub enum OutputFormat {
     Json,
     /// YAML format for configuration output
     Yaml,
+    /// Standalone HTML page with a sortable, filterable findings table
+    ///
+    /// Commands without an HTML view print their table instead.
+    Html,
+}
+
+/// Key used to group findings in validation output
+#[derive(Copy, Clone, PartialEq, Eq, ValueEnum, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GroupBy {
+    /// Group by finding code, e.g. `E002`
+    Code,
+    /// Group by configuration path
+    Path,
+    /// Group by severity
+    Severity,
+}
+
+impl GroupBy {
+    /// The grouping key of a finding
+    fn key_of<'a>(&self, finding: &'a FindingOutput) -> &'a str {
+        match self {
+            GroupBy::Code => &finding.code,
+            GroupBy::Path => &finding.path,
+            GroupBy::Severity => &finding.severity,
+        }
+    }
+}
+
+/// Findings sharing one grouping key
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FindingGroup {
+    /// Shared code, path, or severity
+    pub key: String,
+    /// Number of findings in the group
+    pub count: usize,
+    /// The findings, in their original order
+    pub findings: Vec<FindingOutput>,
+}
+
+/// Decide whether human-readable output should be colored
+///
+/// An explicit `--no-color` always wins, followed by a non-empty `NO_COLOR`
+/// environment variable. Otherwise color is only used when writing to a TTY.
+pub fn should_colorize(no_color_flag: bool, no_color_env: Option<&str>, is_tty: bool) -> bool {
+    if no_color_flag {
+        return false;
+    }
+    if no_color_env.is_some_and(|v| !v.is_empty()) {
+        return false;
+    }
+    is_tty
+}
+
+/// Apply the color policy for this process based on flags, environment and stdout
+pub fn configure_color(no_color_flag: bool) {
+    let no_color_env = std::env::var("NO_COLOR").ok();
+    let enabled = should_colorize(
+        no_color_flag,
+        no_color_env.as_deref(),
+        io::stdout().is_terminal(),
+    );
+    colored::control::set_override(enabled);
 }
 
 /// Validation output structure for rendering
@@ -28,14 +101,28 @@ pub enum OutputFormat {
 pub struct ValidationOutput {
     /// Overall validation status
     pub valid: bool,
-    /// Number of errors found
+    /// Machine-readable reason matching the process exit code
+    pub exit_reason: ExitReason,
+    /// Number of errors found, critical findings included
     pub error_count: usize,
+    /// Number of critical findings
+    #[serde(default)]
+    pub critical_count: usize,
     /// Number of warnings found
     pub warning_count: usize,
     /// Number of info findings
     pub info_count: usize,
     /// List of validation findings
     pub findings: Vec<FindingOutput>,
+    /// Whether findings were left out to honor `--max-findings`
+    #[serde(default)]
+    pub truncated: bool,
+    /// Findings before truncation, present when truncated
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_findings: Option<usize>,
+    /// Findings grouped by `--group-by`, largest group first
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grouped: Option<Vec<FindingGroup>>,
     /// Summary message
     pub summary: String,
     /// Validation duration in milliseconds
@@ -43,6 +130,248 @@ pub struct ValidationOutput {
     pub duration_ms: Option<u64>,
 }
 
+/// Counts-only view of a [`ValidationOutput`] for `--summary-only`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SummaryOutput {
+    /// Overall validation status
+    pub valid: bool,
+    /// Machine-readable reason matching the process exit code
+    pub exit_reason: ExitReason,
+    /// Number of errors found, critical findings included
+    pub error_count: usize,
+    /// Number of critical findings
+    #[serde(default)]
+    pub critical_count: usize,
+    /// Number of warnings found
+    pub warning_count: usize,
+    /// Number of info findings
+    pub info_count: usize,
+    /// Summary message
+    pub summary: String,
+    /// Validation duration in milliseconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_ms: Option<u64>,
+}
+
+impl SummaryOutput {
+    /// Summarize full validation output
+    pub fn from_output(output: &ValidationOutput) -> Self {
+        Self {
+            valid: output.valid,
+            exit_reason: output.exit_reason,
+            error_count: output.error_count,
+            critical_count: output.critical_count,
+            warning_count: output.warning_count,
+            info_count: output.info_count,
+            summary: output.summary.clone(),
+            duration_ms: output.duration_ms,
+        }
+    }
+
+    /// Render output in the specified format
+    pub fn render(&self, format: OutputFormat) -> Result<(), ValidationError> {
+        match format {
+            OutputFormat::Json => {
+                let json = serde_json::to_string_pretty(self)
+                    .map_err(|e| ValidationError::SerializationError(e.to_string()))?;
+                println!("{}", json);
+            }
+            OutputFormat::Yaml => {
+                let yaml = serde_yaml::to_string(self)
+                    .map_err(|e| ValidationError::SerializationError(e.to_string()))?;
+                println!("{}", yaml);
+            }
+            OutputFormat::Table | OutputFormat::Html => {
+                let mut stdout = io::stdout().lock();
+                self.write_table(&mut stdout)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Write the summary as two plain lines
+    pub fn write_table<W: Write>(&self, out: &mut W) -> Result<(), ValidationError> {
+        let status = if self.valid { "+".green() } else { "x".red() };
+        writeln!(out, "{} {}", status, self.summary).ok();
+        writeln!(
+            out,
+            "critical={} errors={} warnings={} info={} exit_reason={}",
+            self.critical_count,
+            self.error_count,
+            self.warning_count,
+            self.info_count,
+            self.exit_reason.as_str()
+        )
+        .ok();
+        out.flush().ok();
+        Ok(())
+    }
+}
+
+/// Machine-readable output for a command that failed before producing results
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ErrorOutput {
+    /// Machine-readable reason matching the process exit code
+    pub exit_reason: ExitReason,
+    /// Error message
+    pub error: String,
+}
+
+impl ErrorOutput {
+    /// Create output from an error
+    pub fn from_error(error: &ValidationError) -> Self {
+        Self {
+            exit_reason: ExitCode::from_error(error).reason(),
+            error: error.to_string(),
+        }
+    }
+
+    /// Render as JSON or YAML (table and HTML errors are written to stderr by the caller)
+    pub fn render(&self, format: OutputFormat) -> Result<(), ValidationError> {
+        match format {
+            OutputFormat::Json => {
+                let json = serde_json::to_string_pretty(self)
+                    .map_err(|e| ValidationError::SerializationError(e.to_string()))?;
+                println!("{}", json);
+            }
+            OutputFormat::Yaml => {
+                let yaml = serde_yaml::to_string(self)
+                    .map_err(|e| ValidationError::SerializationError(e.to_string()))?;
+                println!("{}", yaml);
+            }
+            OutputFormat::Table | OutputFormat::Html => {}
+        }
+        Ok(())
+    }
+}
+
+/// Compatibility check output structure for rendering
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompatibilityOutput {
+    /// Machine-readable reason matching the process exit code
+    pub exit_reason: ExitReason,
+    /// Compatibility check result
+    #[serde(flatten)]
+    pub result: CompatibilityResult,
+}
+
+impl CompatibilityOutput {
+    /// Create output from a compatibility result
+    pub fn from_result(result: &CompatibilityResult) -> Self {
+        Self {
+            exit_reason: ExitCode::from_validation_result(
+                !result.is_compatible,
+                !result.warnings.is_empty(),
+            )
+            .reason(),
+            result: result.clone(),
+        }
+    }
+
+    /// Exit code matching `exit_reason`
+    pub fn exit_code(&self) -> ExitCode {
+        ExitCode::from_validation_result(
+            !self.result.is_compatible,
+            !self.result.warnings.is_empty(),
+        )
+    }
+}
+
+/// Environment-set validation output structure for rendering
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnvironmentsOutput {
+    /// Machine-readable reason matching the process exit code
+    pub exit_reason: ExitReason,
+    /// Per-environment findings and cross-environment violations
+    #[serde(flatten)]
+    pub report: EnvironmentsReport,
+}
+
+impl EnvironmentsOutput {
+    /// Create output from an environments report
+    pub fn from_report(report: &EnvironmentsReport) -> Self {
+        Self {
+            exit_reason: ExitCode::from_severities(
+                report.has_critical(),
+                report.has_errors(),
+                report.has_warnings(),
+            )
+            .reason(),
+            report: report.clone(),
+        }
+    }
+
+    /// Exit code matching `exit_reason`
+    pub fn exit_code(&self) -> ExitCode {
+        ExitCode::from_severities(
+            self.report.has_critical(),
+            self.report.has_errors(),
+            self.report.has_warnings(),
+        )
+    }
+}
+
+/// Opening of the standalone HTML page, through `<body>`
+const HTML_HEAD: &str = r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>Validation Results</title>
+<style>
+body { font-family: sans-serif; margin: 2rem; color: #222; }
+table { border-collapse: collapse; width: 100%; margin-top: 1rem; }
+th, td { border: 1px solid #ccc; padding: 0.3rem 0.6rem; text-align: left; vertical-align: top; }
+th { background: #f3f3f3; cursor: pointer; user-select: none; }
+th.asc::after { content: " \25B2"; } th.desc::after { content: " \25BC"; }
+code { background: #f3f3f3; padding: 0 0.2rem; }
+.valid { color: #1a7f37; } .invalid, .error { color: #cf222e; }
+.warning { color: #9a6700; } .info { color: #0969da; }
+.counts span { margin-right: 1rem; }
+</style>
+</head>
+<body>
+"#;
+
+/// Filtering and sorting script plus the end of the HTML page
+const HTML_TAIL: &str = r#"<script>
+(function () {
+  var table = document.getElementById("findings");
+  var body = table.tBodies[0];
+  var severity = document.getElementById("severity");
+  var filter = document.getElementById("filter");
+
+  function applyFilter() {
+    var query = filter.value.toLowerCase();
+    Array.prototype.forEach.call(body.rows, function (row) {
+      var severityMatch = !severity.value || row.dataset.severity === severity.value;
+      var textMatch = row.textContent.toLowerCase().indexOf(query) !== -1;
+      row.style.display = severityMatch && textMatch ? "" : "none";
+    });
+  }
+  severity.addEventListener("change", applyFilter);
+  filter.addEventListener("input", applyFilter);
+
+  Array.prototype.forEach.call(table.tHead.rows[0].cells, function (header, column) {
+    header.addEventListener("click", function () {
+      var ascending = !header.classList.contains("asc");
+      Array.prototype.forEach.call(table.tHead.rows[0].cells, function (h) {
+        h.classList.remove("asc", "desc");
+      });
+      header.classList.add(ascending ? "asc" : "desc");
+      var rows = Array.prototype.slice.call(body.rows);
+      rows.sort(function (a, b) {
+        var order = a.cells[column].textContent.localeCompare(b.cells[column].textContent);
+        return ascending ? order : -order;
+      });
+      rows.forEach(function (row) { body.appendChild(row); });
+    });
+  });
+})();
+</script>
+</body>
+</html>
+"#;
+
 /// Individual finding output structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FindingOutput {
@@ -68,7 +397,12 @@ impl ValidationOutput {
         let error_count = result
             .findings
             .iter()
-            .filter(|f| f.severity == ValidationSeverity::Error)
+            .filter(|f| f.severity.is_blocking())
+            .count();
+        let critical_count = result
+            .findings
+            .iter()
+            .filter(|f| f.severity == ValidationSeverity::Critical)
             .count();
         let warning_count = result
             .findings
@@ -90,6 +424,11 @@ impl ValidationOutput {
                 "Configuration is valid with {} warning(s)",
                 warning_count
             )
+        } else if critical_count > 0 {
+            format!(
+                "Configuration has {} error(s) ({} critical) and {} warning(s)",
+                error_count, critical_count, warning_count
+            )
         } else {
             format!(
                 "Configuration has {} error(s) and {} warning(s)",
@@ -103,26 +442,162 @@ impl ValidationOutput {
             .map(FindingOutput::from_finding)
             .collect();
 
+        let exit_reason =
+            ExitCode::from_severities(critical_count > 0, error_count > 0, warning_count > 0)
+                .reason();
+
         Self {
             valid,
+            exit_reason,
             error_count,
+            critical_count,
             warning_count,
             info_count,
             findings,
+            truncated: false,
+            total_findings: None,
+            grouped: None,
             summary,
             duration_ms: result.duration_ms,
         }
     }
 
+    /// Keep at most `max` findings, the most severe first
+    ///
+    /// Of findings with the same severity the earlier ones are kept, and
+    /// kept findings stay in their original order. Counts, the summary and
+    /// the exit code still cover every finding.
+    pub fn with_max_findings(mut self, max: usize) -> Self {
+        let total = self.findings.len();
+        if total <= max {
+            return self;
+        }
+
+        let rank = |finding: &FindingOutput| {
+            [
+                ValidationSeverity::Critical,
+                ValidationSeverity::Error,
+                ValidationSeverity::Warning,
+                ValidationSeverity::Info,
+            ]
+            .iter()
+            .position(|severity| severity.as_str() == finding.severity)
+            .unwrap_or(usize::MAX)
+        };
+        let mut by_severity: Vec<usize> = (0..total).collect();
+        by_severity.sort_by_key(|&index| rank(&self.findings[index]));
+        let mut keep = vec![false; total];
+        for &index in &by_severity[..max] {
+            keep[index] = true;
+        }
+        let mut keep = keep.into_iter();
+        self.findings.retain(|_| keep.next().unwrap_or(false));
+
+        self.truncated = true;
+        self.total_findings = Some(total);
+        self
+    }
+
+    /// Group the findings by `group_by`
+    ///
+    /// Groups are ordered by size, largest first, with ties broken by key.
+    pub fn with_grouping(mut self, group_by: GroupBy) -> Self {
+        let mut groups: Vec<FindingGroup> = Vec::new();
+        for finding in &self.findings {
+            let key = group_by.key_of(finding);
+            match groups.iter_mut().find(|g| g.key == key) {
+                Some(group) => {
+                    group.count += 1;
+                    group.findings.push(finding.clone());
+                }
+                None => groups.push(FindingGroup {
+                    key: key.to_string(),
+                    count: 1,
+                    findings: vec![finding.clone()],
+                }),
+            }
+        }
+        groups.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.key.cmp(&b.key)));
+        self.grouped = Some(groups);
+        self
+    }
+
+    /// Exit code matching `exit_reason`
+    pub fn exit_code(&self) -> ExitCode {
+        ExitCode::from_severities(
+            self.critical_count > 0,
+            self.error_count > 0,
+            self.warning_count > 0,
+        )
+    }
+
     /// Render output in the specified format
     pub fn render(&self, format: OutputFormat) -> Result<(), ValidationError> {
         match format {
             OutputFormat::Json => self.render_json(),
             OutputFormat::Yaml => self.render_yaml(),
             OutputFormat::Table => self.render_table(),
+            OutputFormat::Html => {
+                print!("{}", self.to_html());
+                Ok(())
+            }
         }
     }
 
+    /// Render as a standalone HTML page
+    ///
+    /// The findings table sorts by clicking a column header and filters by
+    /// severity or free text. Styles and script are inline, so the page has
+    /// no external assets and works offline.
+    pub fn to_html(&self) -> String {
+        let mut out = String::from(HTML_HEAD);
+        let status = if self.valid { "valid" } else { "invalid" };
+        out.push_str(&format!(
+            "<header>\n<h1>Validation Results</h1>\n<p class=\"{}\">{}</p>\n",
+            status,
+            escape_html(&self.summary)
+        ));
+        out.push_str(&format!(
+            "<p class=\"counts\"><span class=\"error\">{} error(s)</span> \
+             <span class=\"warning\">{} warning(s)</span> \
+             <span class=\"info\">{} info</span> \
+             <span>exit reason: {}</span>",
+            self.error_count,
+            self.warning_count,
+            self.info_count,
+            self.exit_reason.as_str()
+        ));
+        if let Some(duration) = self.duration_ms {
+            out.push_str(&format!(" <span>{} ms</span>", duration));
+        }
+        out.push_str("</p>\n</header>\n");
+
+        out.push_str(
+            "<div class=\"controls\">\n\
+             <select id=\"severity\"><option value=\"\">All severities</option>\
+             <option>critical</option><option>error</option><option>warning</option><option>info</option></select>\n\
+             <input id=\"filter\" type=\"search\" placeholder=\"Filter findings\">\n\
+             </div>\n\
+             <table id=\"findings\">\n<thead><tr>\
+             <th>Severity</th><th>Code</th><th>Path</th><th>Message</th><th>Suggestion</th>\
+             </tr></thead>\n<tbody>\n",
+        );
+        for finding in &self.findings {
+            out.push_str(&format!(
+                "<tr data-severity=\"{sev}\"><td class=\"{sev}\">{sev}</td><td><code>{}</code></td>\
+                 <td><code>{}</code></td><td>{}</td><td>{}</td></tr>\n",
+                escape_html(&finding.code),
+                escape_html(&finding.path),
+                escape_html(&finding.message),
+                escape_html(finding.suggestion.as_deref().unwrap_or("")),
+                sev = escape_html(&finding.severity),
+            ));
+        }
+        out.push_str("</tbody>\n</table>\n");
+        out.push_str(HTML_TAIL);
+        out
+    }
+
     /// Render as JSON
     fn render_json(&self) -> Result<(), ValidationError> {
         let json = serde_json::to_string_pretty(self)
@@ -141,13 +616,17 @@ impl ValidationOutput {
 
     /// Render as human-readable table
     fn render_table(&self) -> Result<(), ValidationError> {
-        let mut stdout = io::stdout();
+        let mut stdout = io::stdout().lock();
+        self.write_table(&mut stdout)
+    }
 
+    /// Write the human-readable table to an arbitrary writer
+    pub fn write_table<W: Write>(&self, out: &mut W) -> Result<(), ValidationError> {
         // Header
-        writeln!(stdout).ok();
-        writeln!(stdout, "{}", "Validation Results".cyan().bold()).ok();
-        writeln!(stdout, "{}", "=".repeat(60)).ok();
-        writeln!(stdout).ok();
+        writeln!(out).ok();
+        writeln!(out, "{}", "Validation Results".cyan().bold()).ok();
+        writeln!(out, "{}", "=".repeat(60)).ok();
+        writeln!(out).ok();
 
         // Summary line
         let status_icon = if self.valid { "+" } else { "x" };
@@ -156,15 +635,15 @@ impl ValidationOutput {
         } else {
             status_icon.red()
         };
-        writeln!(stdout, "{} {}", status_colored, self.summary).ok();
-        writeln!(stdout).ok();
+        writeln!(out, "{} {}", status_colored, self.summary).ok();
+        writeln!(out).ok();
 
         // Statistics
         if self.error_count > 0 || self.warning_count > 0 || self.info_count > 0 {
-            writeln!(stdout, "{}", "Statistics:".cyan().bold()).ok();
+            writeln!(out, "{}", "Statistics:".cyan().bold()).ok();
             if self.error_count > 0 {
                 writeln!(
-                    stdout,
+                    out,
                     "  {} Errors:   {}",
                     "x".red(),
                     self.error_count.to_string().red()
@@ -173,7 +652,7 @@ impl ValidationOutput {
             }
             if self.warning_count > 0 {
                 writeln!(
-                    stdout,
+                    out,
                     "  {} Warnings: {}",
                     "!".yellow(),
                     self.warning_count.to_string().yellow()
@@ -182,33 +661,52 @@ impl ValidationOutput {
             }
             if self.info_count > 0 {
                 writeln!(
-                    stdout,
+                    out,
                     "  {} Info:     {}",
                     "i".blue(),
                     self.info_count.to_string().blue()
                 )
                 .ok();
             }
-            writeln!(stdout).ok();
+            writeln!(out).ok();
         }
 
         // Findings
-        if !self.findings.is_empty() {
-            writeln!(stdout, "{}", "Findings:".cyan().bold()).ok();
-            writeln!(stdout, "{}", "-".repeat(60)).ok();
+        if let Some(groups) = self.grouped.as_ref().filter(|g| !g.is_empty()) {
+            writeln!(out, "{}", "Findings by group:".cyan().bold()).ok();
+            writeln!(out, "{}", "-".repeat(60)).ok();
+            for group in groups {
+                writeln!(out).ok();
+                writeln!(out, "{} ({})", group.key.bold(), group.count).ok();
+                for (index, finding) in group.findings.iter().enumerate() {
+                    finding.render_table_row(out, index + 1)?;
+                }
+            }
+        } else if !self.findings.is_empty() {
+            writeln!(out, "{}", "Findings:".cyan().bold()).ok();
+            writeln!(out, "{}", "-".repeat(60)).ok();
 
             for (index, finding) in self.findings.iter().enumerate() {
-                finding.render_table_row(&mut stdout, index + 1)?;
+                finding.render_table_row(out, index + 1)?;
             }
         }
+        if let Some(total) = self.total_findings {
+            writeln!(out).ok();
+            writeln!(
+                out,
+                "{}",
+                format!("Showing {} of {} findings", self.findings.len(), total).dimmed()
+            )
+            .ok();
+        }
 
         // Duration
         if let Some(duration) = self.duration_ms {
-            writeln!(stdout).ok();
-            writeln!(stdout, "Completed in {} ms", duration.to_string().dimmed()).ok();
+            writeln!(out).ok();
+            writeln!(out, "Completed in {} ms", duration.to_string().dimmed()).ok();
         }
 
-        stdout.flush().ok();
+        out.flush().ok();
         Ok(())
     }
 }
@@ -227,7 +725,10 @@ impl FindingOutput {
     }
 
     /// Render a single finding as a table row
-    fn render_table_row(&self, stdout: &mut io::Stdout, index: usize) -> Result<(), ValidationError> {
+    ///
+    /// Labels are padded before coloring so columns stay aligned whether or
+    /// not ANSI escape codes are emitted.
+    fn render_table_row<W: Write>(&self, out: &mut W, index: usize) -> Result<(), ValidationError> {
         let severity_icon = match self.severity.to_lowercase().as_str() {
             "error" => "x".red(),
             "warning" => "!".yellow(),
@@ -235,39 +736,35 @@ impl FindingOutput {
             _ => "-".white(),
         };
 
+        let label = format!("{:<7}", self.severity.to_uppercase());
         let severity_label = match self.severity.to_lowercase().as_str() {
-            "error" => "ERROR".red().bold(),
-            "warning" => "WARNING".yellow().bold(),
-            "info" => "INFO".blue().bold(),
-            _ => self.severity.clone().white(),
+            "error" => label.red().bold(),
+            "warning" => label.yellow().bold(),
+            "info" => label.blue().bold(),
+            _ => label.white(),
         };
 
-        writeln!(stdout).ok();
+        writeln!(out).ok();
         writeln!(
-            stdout,
-            "{} [{}] {} {}",
+            out,
+            "{:>3}. {} {} [{}] {}",
+            index,
             severity_icon,
-            self.code.dimmed(),
             severity_label,
+            self.code.dimmed(),
             self.message
         )
         .ok();
-        writeln!(stdout, "  {} {}", "Path:".dimmed(), self.path.cyan()).ok();
+        writeln!(out, "       {:<6}{}", "Path:".dimmed(), self.path.cyan()).ok();
 
         if let Some(suggestion) = &self.suggestion {
-            writeln!(
-                stdout,
-                "  {} {}",
-                "Fix:".dimmed(),
-                suggestion.green()
-            )
-            .ok();
+            writeln!(out, "       {:<6}{}", "Fix:".dimmed(), suggestion.green()).ok();
         }
 
         if let Some(doc_link) = &self.doc_link {
             writeln!(
-                stdout,
-                "  {} {}",
+                out,
+                "       {:<6}{}",
                 "Docs:".dimmed(),
                 doc_link.blue().underline()
             )
@@ -285,7 +782,9 @@ impl SeverityColorizer {
     /// Get colored string for a severity level
     pub fn colorize(severity: &ValidationSeverity, text: &str) -> String {
         match severity {
-            ValidationSeverity::Error => text.red().bold().to_string(),
+            ValidationSeverity::Critical | ValidationSeverity::Error => {
+                text.red().bold().to_string()
+            }
             ValidationSeverity::Warning => text.yellow().bold().to_string(),
             ValidationSeverity::Info => text.blue().to_string(),
         }
@@ -294,6 +793,7 @@ impl SeverityColorizer {
     /// Get the icon for a severity level
     pub fn icon(severity: &ValidationSeverity) -> String {
         match severity {
+            ValidationSeverity::Critical => "x".red().bold().to_string(),
             ValidationSeverity::Error => "x".red().to_string(),
             ValidationSeverity::Warning => "!".yellow().to_string(),
             ValidationSeverity::Info => "i".blue().to_string(),
@@ -303,6 +803,7 @@ impl SeverityColorizer {
     /// Get the colored severity label
     pub fn label(severity: &ValidationSeverity) -> String {
         match severity {
+            ValidationSeverity::Critical => "CRITICAL".red().bold().to_string(),
             ValidationSeverity::Error => "ERROR".red().bold().to_string(),
             ValidationSeverity::Warning => "WARNING".yellow().bold().to_string(),
             ValidationSeverity::Info => "INFO".blue().to_string(),
@@ -415,6 +916,259 @@ mod tests {
         assert_eq!(output.error_count, 0);
         assert_eq!(output.warning_count, 0);
         assert_eq!(output.summary, "Configuration is valid");
+        assert_eq!(output.exit_reason, ExitReason::Valid);
+    }
+
+    #[test]
+    fn test_validation_output_exit_reason() {
+        let errors = ValidationResult::with_findings(vec![ValidationFinding::error(
+            "E001", "Bad value", "$.a",
+        )]);
+        let warnings = ValidationResult::with_findings(vec![ValidationFinding::warning(
+            "W001", "Odd value", "$.b",
+        )]);
+
+        let output = ValidationOutput::from_result(&errors);
+        assert_eq!(output.exit_reason, ExitReason::Errors);
+        let json = serde_json::to_value(&output).unwrap();
+        assert_eq!(json["exit_reason"], "errors");
+
+        let output = ValidationOutput::from_result(&warnings);
+        assert_eq!(output.exit_reason, ExitReason::Warnings);
+    }
+
+    #[test]
+    fn test_max_findings_keeps_most_severe_and_reports_total() {
+        let mut findings: Vec<ValidationFinding> = (0..50)
+            .map(|i| ValidationFinding::warning("W001", "Odd value", format!("$.w{}", i)))
+            .collect();
+        findings.insert(20, ValidationFinding::critical("C001", "Exposed key", "$.key"));
+        findings.push(ValidationFinding::error("E001", "Bad value", "$.a"));
+        findings.push(ValidationFinding::critical("C002", "Plaintext secret", "$.secret"));
+        let result = ValidationResult::with_findings(findings);
+
+        let output = ValidationOutput::from_result(&result).with_max_findings(3);
+        assert!(output.truncated);
+        assert_eq!(output.total_findings, Some(53));
+        let codes: Vec<&str> = output.findings.iter().map(|f| f.code.as_str()).collect();
+        assert_eq!(codes, vec!["C001", "E001", "C002"]);
+
+        // Counts and the exit code still cover every finding
+        assert_eq!(output.critical_count, 2);
+        assert_eq!(output.warning_count, 50);
+        assert_eq!(output.exit_reason, ExitReason::Critical);
+        let json = serde_json::to_value(&output).unwrap();
+        assert_eq!(json["truncated"], true);
+        assert_eq!(json["total_findings"], 53);
+
+        let output = ValidationOutput::from_result(&result).with_max_findings(100);
+        assert!(!output.truncated);
+        assert_eq!(output.findings.len(), 53);
+        assert_eq!(output.total_findings, None);
+    }
+
+    #[test]
+    fn test_compatibility_output_exit_reason() {
+        let mut result = CompatibilityResult::compatible();
+        result.shared_keys.push("server.port".to_string());
+
+        let json = serde_json::to_value(CompatibilityOutput::from_result(&result)).unwrap();
+        assert_eq!(json["exit_reason"], "valid");
+        assert_eq!(json["is_compatible"], true);
+        assert_eq!(json["shared_keys"][0], "server.port");
+
+        result.is_compatible = false;
+        let output = CompatibilityOutput::from_result(&result);
+        assert_eq!(output.exit_reason, ExitReason::Errors);
+        assert_eq!(output.exit_code(), ExitCode::ValidationError);
+    }
+
+    #[test]
+    fn test_error_output_exit_reason() {
+        let output = ErrorOutput::from_error(&ValidationError::file_error("missing.yaml"));
+        let json = serde_json::to_value(&output).unwrap();
+        assert_eq!(json["exit_reason"], "file_error");
+        assert_eq!(json["error"], "File error: missing.yaml");
+    }
+
+    #[test]
+    fn test_should_colorize_disabled_when_not_tty() {
+        assert!(!should_colorize(false, None, false));
+        assert!(should_colorize(false, None, true));
+    }
+
+    #[test]
+    fn test_no_color_flag_and_env_suppress_color() {
+        assert!(!should_colorize(true, None, true));
+        assert!(!should_colorize(false, Some("1"), true));
+        // An empty NO_COLOR is treated as unset
+        assert!(should_colorize(false, Some(""), true));
+    }
+
+    #[test]
+    fn test_table_output_has_no_ansi_codes_when_color_disabled() {
+        colored::control::set_override(should_colorize(true, None, true));
+
+        let result = ValidationResult::with_findings(vec![
+            ValidationFinding::error("E001", "Bad value", "$.a").with_suggestion("Fix it"),
+            ValidationFinding::warning("W001", "Odd value", "$.b"),
+        ]);
+        let output = ValidationOutput::from_result(&result);
+
+        let mut buffer = Vec::new();
+        output.write_table(&mut buffer).unwrap();
+        let text = String::from_utf8(buffer).unwrap();
+
+        assert!(!text.contains('\x1b'));
+        assert!(text.contains("  1. x ERROR   [E001] Bad value"));
+        assert!(text.contains("  2. ! WARNING [W001] Odd value"));
+    }
+
+    #[test]
+    fn test_group_by_code_collapses_repeated_codes() {
+        let result = ValidationResult::with_findings(vec![
+            ValidationFinding::error("E002", "Missing field 'host'", "$.db"),
+            ValidationFinding::warning("W001", "Odd value", "$.b"),
+            ValidationFinding::error("E002", "Missing field 'port'", "$.db"),
+            ValidationFinding::error("E001", "Bad type", "$.cache"),
+            ValidationFinding::error("E002", "Missing field 'name'", "$.app"),
+        ]);
+        let output = ValidationOutput::from_result(&result).with_grouping(GroupBy::Code);
+
+        let groups = output.grouped.as_ref().unwrap();
+        let counts: Vec<(&str, usize)> = groups.iter().map(|g| (g.key.as_str(), g.count)).collect();
+        assert_eq!(counts, vec![("E002", 3), ("E001", 1), ("W001", 1)]);
+        let paths: Vec<&str> = groups[0].findings.iter().map(|f| f.path.as_str()).collect();
+        assert_eq!(paths, vec!["$.db", "$.db", "$.app"]);
+
+        let json = serde_json::to_value(&output).unwrap();
+        assert_eq!(json["grouped"][0]["key"], "E002");
+        assert_eq!(json["grouped"][0]["count"], 3);
+
+        let by_path = ValidationOutput::from_result(&result).with_grouping(GroupBy::Path);
+        assert_eq!(by_path.grouped.unwrap()[0].key, "$.db");
+        let ungrouped = serde_json::to_value(ValidationOutput::from_result(&result)).unwrap();
+        assert!(ungrouped.get("grouped").is_none());
+    }
+
+    #[test]
+    fn test_grouped_table_lists_counts() {
+        colored::control::set_override(false);
+
+        let result = ValidationResult::with_findings(vec![
+            ValidationFinding::error("E001", "Bad value", "$.a"),
+            ValidationFinding::warning("W001", "Odd value", "$.b"),
+            ValidationFinding::error("E003", "Too short", "$.c"),
+        ]);
+        let output = ValidationOutput::from_result(&result).with_grouping(GroupBy::Severity);
+
+        let mut buffer = Vec::new();
+        output.write_table(&mut buffer).unwrap();
+        let text = String::from_utf8(buffer).unwrap();
+
+        assert!(text.contains("Findings by group:"));
+        assert!(text.contains("error (2)"));
+        assert!(text.contains("warning (1)"));
+        assert!(text.find("error (2)").unwrap() < text.find("warning (1)").unwrap());
+    }
+
+    #[test]
+    fn test_critical_findings_map_to_security_exit_code() {
+        let plain = ValidationOutput::from_result(&ValidationResult::with_findings(vec![
+            ValidationFinding::error("E001", "Bad value", "$.a"),
+        ]));
+        assert_eq!(plain.critical_count, 0);
+        assert_eq!(plain.exit_code(), ExitCode::ValidationError);
+        assert_eq!(plain.exit_reason, ExitReason::Errors);
+
+        let critical = ValidationOutput::from_result(&ValidationResult::with_findings(vec![
+            ValidationFinding::error("E001", "Bad value", "$.a"),
+            ValidationFinding::critical("S001", "Potential secret in plain text", "$.db.password"),
+        ]));
+        assert_eq!((critical.error_count, critical.critical_count), (2, 1));
+        assert_eq!(critical.exit_code(), ExitCode::SecurityError);
+        assert_eq!(critical.exit_reason, ExitReason::Critical);
+        assert_eq!(
+            critical.summary,
+            "Configuration has 2 error(s) (1 critical) and 0 warning(s)"
+        );
+    }
+
+    #[test]
+    fn test_summary_output_omits_findings() {
+        colored::control::set_override(false);
+
+        let result = ValidationResult::with_findings(vec![
+            ValidationFinding::error("E001", "Bad value", "$.a").with_suggestion("Fix it"),
+            ValidationFinding::error("E002", "Missing field", "$.b"),
+            ValidationFinding::warning("W001", "Odd value", "$.c"),
+            ValidationFinding::info("I001", "Note", "$.d"),
+        ])
+        .with_duration(12);
+        let summary = SummaryOutput::from_output(&ValidationOutput::from_result(&result));
+
+        let json = serde_json::to_value(&summary).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "valid": false,
+                "exit_reason": "errors",
+                "error_count": 2,
+                "critical_count": 0,
+                "warning_count": 1,
+                "info_count": 1,
+                "summary": "Configuration has 2 error(s) and 1 warning(s)",
+                "duration_ms": 12
+            })
+        );
+
+        let mut buffer = Vec::new();
+        summary.write_table(&mut buffer).unwrap();
+        let text = String::from_utf8(buffer).unwrap();
+        assert!(text.contains("errors=2 warnings=1 info=1 exit_reason=errors"));
+        for detail in ["E001", "Bad value", "$.a", "Fix it", "W001", "Findings"] {
+            assert!(!text.contains(detail), "summary leaked '{}'", detail);
+        }
+    }
+
+    /// Check that every non-void element is closed in order
+    fn assert_well_formed(html: &str) {
+        const VOID: [&str; 3] = ["meta", "input", "br"];
+        let tag = regex::Regex::new(r"<(/?)([a-zA-Z][a-zA-Z0-9]*)[^>]*>").unwrap();
+        let mut open: Vec<String> = Vec::new();
+        for cap in tag.captures_iter(html) {
+            let name = cap[2].to_lowercase();
+            if VOID.contains(&name.as_str()) {
+                continue;
+            }
+            if &cap[1] == "/" {
+                assert_eq!(open.pop().as_deref(), Some(name.as_str()), "unbalanced </{}>", name);
+            } else {
+                open.push(name);
+            }
+        }
+        assert!(open.is_empty(), "unclosed tags: {:?}", open);
+    }
+
+    #[test]
+    fn test_html_output_has_row_per_finding() {
+        let result = ValidationResult::with_findings(vec![
+            ValidationFinding::error("E005", "String does not match pattern '<a&b>'", "$.name")
+                .with_suggestion("Use letters only"),
+            ValidationFinding::error("E001", "Bad type", "$.port"),
+            ValidationFinding::warning("W001", "Odd value", "$.b"),
+        ]);
+        let html = ValidationOutput::from_result(&result).to_html();
+
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert_well_formed(&html);
+        assert_eq!(html.matches("<tr data-severity=").count(), 3);
+        assert_eq!(html.matches("<tr data-severity=\"error\">").count(), 2);
+        assert!(html.contains("Configuration has 2 error(s) and 1 warning(s)"));
+        assert!(html.contains("&#39;&lt;a&amp;b&gt;&#39;"));
+        assert!(html.contains("<td>Use letters only</td>"));
+        // Self-contained: no external stylesheets, scripts, or links
+        assert!(!html.contains("src=") && !html.contains("href="));
     }
 
     #[test]
@@ -19,6 +19,7 @@ use tokio::time::sleep;
 
 use crate::contracts::DecisionEvent;
 use crate::contracts::decision_event::DecisionEventBatch;
+use crate::http_pool::PoolConfig;
 use crate::telemetry::{Result, TelemetryError};
 
 /// Configuration for the ruvector client
@@ -41,6 +42,12 @@ pub struct RuvectorClientConfig {
 
     /// Backoff multiplier
     pub backoff_multiplier: f64,
+
+    /// Connection pool settings, including the HTTP version
+    ///
+    /// Defaults to HTTP/2 with prior knowledge; set `RUVECTOR_HTTP_VERSION`
+    /// to `http1` for services that only speak HTTP/1.1.
+    pub pool: PoolConfig,
 }
 
 impl Default for RuvectorClientConfig {
@@ -52,6 +59,7 @@ impl Default for RuvectorClientConfig {
             initial_backoff_ms: 100,
             max_backoff_ms: 5000,
             backoff_multiplier: 2.0,
+            pool: PoolConfig::telemetry_from_env(),
         }
     }
 }
@@ -112,11 +120,13 @@ impl RuvectorClient {
     }
 
     /// Create a new ruvector client with custom configuration
+    ///
+    /// The client keeps a pool of kept-alive connections sized by
+    /// `config.pool`, so repeated emissions reuse connections.
     pub fn with_config(config: RuvectorClientConfig) -> Self {
-        let client = Client::builder()
-            .timeout(Duration::from_millis(config.timeout_ms))
-            .pool_max_idle_per_host(10)
-            .pool_idle_timeout(Duration::from_secs(30))
+        let client = config
+            .pool
+            .apply(Client::builder().timeout(Duration::from_millis(config.timeout_ms)))
             .build()
             .expect("Failed to create HTTP client");
 
@@ -357,6 +367,12 @@ impl RuvectorClientBuilder {
         self
     }
 
+    /// Set the connection pool settings
+    pub fn pool(mut self, pool: PoolConfig) -> Self {
+        self.config.pool = pool;
+        self
+    }
+
     /// Build the client
     pub fn build(self) -> RuvectorClient {
         RuvectorClient::with_config(self.config)
@@ -373,6 +389,7 @@ impl Default for RuvectorClientBuilder {
 mod tests {
     use super::*;
     use crate::contracts::decision_event::{DecisionType, ValidationOutputs};
+    use crate::http_pool::HttpVersion;
     use uuid::Uuid;
 
     #[test]
@@ -470,4 +487,60 @@ mod tests {
         let deserialized: HealthResponse = serde_json::from_str(&json).unwrap();
         assert_eq!(deserialized.status, "healthy");
     }
+
+    #[tokio::test]
+    async fn test_repeated_emissions_reuse_connection() {
+        let (url, connections) = crate::http_pool::testing::counting_server().await;
+        let client = RuvectorClientBuilder::new()
+            .base_url(url)
+            .pool(PoolConfig::default())
+            .build();
+        let event = DecisionEvent::new(
+            DecisionType::ConfigValidationResult,
+            "test_hash".to_string(),
+            ValidationOutputs::success(vec!["rule1".to_string()], 0.95),
+            0.9,
+            "exec-ref".to_string(),
+        );
+
+        for _ in 0..5 {
+            client.persist_decision_event(&event).await.unwrap();
+        }
+
+        assert_eq!(connections.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_http2_emissions_share_one_connection() {
+        let (url, connections) = crate::http_pool::testing::counting_h2_server().await;
+        let client = RuvectorClientBuilder::new()
+            .base_url(url)
+            .pool(PoolConfig::default().with_http_version(HttpVersion::Http2))
+            .build();
+        let event = DecisionEvent::new(
+            DecisionType::ConfigValidationResult,
+            "test_hash".to_string(),
+            ValidationOutputs::success(vec!["rule1".to_string()], 0.95),
+            0.9,
+            "exec-ref".to_string(),
+        );
+
+        for _ in 0..5 {
+            client.persist_decision_event(&event).await.unwrap();
+        }
+
+        assert_eq!(connections.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_http_version_parsing() {
+        assert_eq!("http2".parse::<HttpVersion>().unwrap(), HttpVersion::Http2);
+        assert_eq!("h2c".parse::<HttpVersion>().unwrap(), HttpVersion::Http2);
+        assert_eq!("HTTP/1.1".parse::<HttpVersion>().unwrap(), HttpVersion::Http1);
+        assert_eq!("auto".parse::<HttpVersion>().unwrap(), HttpVersion::Negotiate);
+
+        let err = "spdy".parse::<HttpVersion>().unwrap_err();
+        assert!(err.contains("http1"));
+        assert!(err.contains("http2"));
+    }
 }
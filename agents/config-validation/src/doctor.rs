@@ -0,0 +1,125 @@
+//! Self-test for the Config Validation Agent
+//!
+//! Backs the `doctor` subcommand: checks that the telemetry environment
+//! variables are sane, that ruvector-service is reachable, that the built-in
+//! schemas and rules load, and that a trivial validation fits in the latency
+//! budget.
+
+#[path = "../../shared/doctor.rs"]
+mod checks;
+
+pub use checks::*;
+
+use crate::engine::{ValidationEngine, MAX_LATENCY_MS};
+use crate::handler::HandlerState;
+use crate::{ConfigValue, Environment};
+use std::time::{Duration, Instant};
+
+/// ruvector-service URL used when `RUVECTOR_ENDPOINT` is not set
+pub const DEFAULT_TELEMETRY_URL: &str = "http://localhost:8080";
+
+/// How long to wait for the telemetry endpoint to answer
+pub const ENDPOINT_TIMEOUT: Duration = Duration::from_millis(MAX_LATENCY_MS);
+
+/// Run every check
+///
+/// `telemetry_url` is the configured `RUVECTOR_ENDPOINT`, if any.
+pub async fn run(telemetry_url: Option<&str>) -> DoctorReport {
+    let mut report = DoctorReport::default();
+
+    let url_check = check_url_var("RUVECTOR_ENDPOINT", telemetry_url, DEFAULT_TELEMETRY_URL);
+    let url_ok = url_check.passed;
+    report.push(url_check);
+    report.push(check_parse_var::<u64>(
+        "TELEMETRY_TIMEOUT_MS",
+        std::env::var("TELEMETRY_TIMEOUT_MS").ok().as_deref(),
+    ));
+    report.push(check_parse_var::<usize>(
+        "TELEMETRY_MAX_QUEUE_SIZE",
+        std::env::var("TELEMETRY_MAX_QUEUE_SIZE").ok().as_deref(),
+    ));
+
+    if url_ok {
+        let url = telemetry_url.unwrap_or(DEFAULT_TELEMETRY_URL);
+        report.push(check_endpoint("telemetry endpoint", url, ENDPOINT_TIMEOUT).await);
+    } else {
+        report.push(DoctorCheck::fail("telemetry endpoint", "no valid URL configured"));
+    }
+
+    report.push(check_schemas(&HandlerState::new()));
+
+    let engine = ValidationEngine::new();
+    if engine.rules().is_empty() {
+        report.push(DoctorCheck::fail("rules", "no validation rules registered"));
+    } else {
+        report.push(DoctorCheck::pass(
+            "rules",
+            format!("{} rules registered", engine.rules().len()),
+        ));
+    }
+
+    let config = ConfigValue::Object(
+        [("enabled".to_string(), ConfigValue::Boolean(true))]
+            .into_iter()
+            .collect(),
+    );
+    let start = Instant::now();
+    engine.validate(&config, Environment::Production, "doctor").await;
+    report.push(check_latency("latency budget", start.elapsed(), MAX_LATENCY_MS));
+
+    report
+}
+
+/// Check that the built-in schemas loaded and their field patterns compile
+fn check_schemas(state: &HandlerState) -> DoctorCheck {
+    if state.schemas.is_empty() {
+        return DoctorCheck::fail("schemas", "no validation schemas loaded");
+    }
+
+    for schema in state.schemas.values() {
+        for field in &schema.fields {
+            if let Some(pattern) = &field.pattern {
+                if let Err(e) = regex::Regex::new(pattern) {
+                    return DoctorCheck::fail(
+                        "schemas",
+                        format!("{} field '{}' has an invalid pattern: {}", schema.id, field.path, e),
+                    );
+                }
+            }
+        }
+    }
+
+    DoctorCheck::pass("schemas", format!("{} schemas loaded", state.schemas.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_doctor_fails_when_telemetry_unreachable() {
+        // Nothing listens on port 1
+        let report = run(Some("http://127.0.0.1:1")).await;
+
+        assert!(!report.passed());
+        let telemetry = report.check("telemetry endpoint").unwrap();
+        assert!(!telemetry.passed);
+        assert!(telemetry.detail.contains("unreachable"));
+
+        // The local checks still pass
+        assert!(report.check("env RUVECTOR_ENDPOINT").unwrap().passed);
+        assert!(report.check("schemas").unwrap().passed);
+        assert!(report.check("rules").unwrap().passed);
+        assert!(report.check("latency budget").unwrap().passed);
+        assert!(report.render().contains("[FAIL] telemetry endpoint"));
+    }
+
+    #[tokio::test]
+    async fn test_doctor_rejects_invalid_telemetry_url() {
+        let report = run(Some("not a url")).await;
+
+        assert!(!report.passed());
+        assert!(!report.check("env RUVECTOR_ENDPOINT").unwrap().passed);
+        assert!(!report.check("telemetry endpoint").unwrap().passed);
+    }
+}
@@ -0,0 +1,520 @@
+//! Duplicate key detection in configuration source text
+//!
+//! `serde_json` and `serde_yaml` both keep the last value when a mapping
+//! repeats a key, so by the time a configuration is parsed the earlier value
+//! is gone without a trace. The scanners here read the source text itself and
+//! report every repeated key with the lines of both occurrences. TOML needs no
+//! scanner: its parser already rejects duplicate keys.
+//!
+//! The JSON scanner understands the full grammar. The YAML scanner covers
+//! block mappings and sequences, quoted keys, comments and block scalars;
+//! flow collections (`{a: 1, a: 2}`) are not looked into.
+//!
+//! Paths use the `$.key` and `$.list[0]` form of validation findings.
+
+use std::collections::HashMap;
+
+use crate::validation::ValidationFinding;
+
+/// Code of findings for duplicate keys
+pub const DUPLICATE_KEY: &str = "DUPLICATE_KEY";
+
+/// A key repeated within one mapping
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateKey {
+    /// The repeated key
+    pub key: String,
+    /// Path of the key, e.g. `$.database.host`
+    pub path: String,
+    /// Line (1-based) of the first occurrence
+    pub first_line: usize,
+    /// Line (1-based) of the repeat, whose value is the one kept
+    pub duplicate_line: usize,
+}
+
+impl DuplicateKey {
+    /// A warning naming the key and both lines
+    pub fn to_finding(&self) -> ValidationFinding {
+        ValidationFinding::warning(
+            DUPLICATE_KEY,
+            format!(
+                "Key '{}' is defined on line {} and again on line {}; only the value on line {} is used",
+                self.key, self.first_line, self.duplicate_line, self.duplicate_line
+            ),
+            &self.path,
+        )
+        .with_suggestion(format!(
+            "Remove one of the '{}' keys or merge their values",
+            self.key
+        ))
+    }
+}
+
+/// Keys seen in one mapping, and where
+#[derive(Default)]
+struct KeyLines {
+    lines: HashMap<String, usize>,
+}
+
+impl KeyLines {
+    /// Record a key, returning the line it was first seen on if repeated
+    fn insert(&mut self, key: &str, line: usize) -> Option<usize> {
+        match self.lines.get(key) {
+            Some(&first) => Some(first),
+            None => {
+                self.lines.insert(key.to_string(), line);
+                None
+            }
+        }
+    }
+}
+
+fn child_path(parent: &str, key: &str) -> String {
+    format!("{}.{}", parent, key)
+}
+
+/// Repeated keys in a JSON document, in source order
+pub fn json_duplicate_keys(content: &str) -> Vec<DuplicateKey> {
+    enum Frame {
+        Object {
+            path: String,
+            keys: KeyLines,
+            expecting_key: bool,
+            key: Option<String>,
+        },
+        Array {
+            path: String,
+            index: usize,
+        },
+    }
+
+    impl Frame {
+        /// Path of a value starting at the current position in this frame
+        fn value_path(&self) -> String {
+            match self {
+                Frame::Object { path, key, .. } => {
+                    child_path(path, key.as_deref().unwrap_or_default())
+                }
+                Frame::Array { path, index } => format!("{}[{}]", path, index),
+            }
+        }
+    }
+
+    let mut duplicates = Vec::new();
+    let mut stack: Vec<Frame> = Vec::new();
+    let mut line = 1;
+    let mut chars = content.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\n' => line += 1,
+            '"' => {
+                let start_line = line;
+                let text = read_json_string(&mut chars, &mut line);
+                if let Some(Frame::Object {
+                    path,
+                    keys,
+                    expecting_key,
+                    key,
+                }) = stack.last_mut().filter(|frame| {
+                    matches!(frame, Frame::Object { expecting_key: true, .. })
+                }) {
+                    if let Some(first_line) = keys.insert(&text, start_line) {
+                        duplicates.push(DuplicateKey {
+                            path: child_path(path, &text),
+                            key: text.clone(),
+                            first_line,
+                            duplicate_line: start_line,
+                        });
+                    }
+                    *key = Some(text);
+                    *expecting_key = false;
+                }
+            }
+            '{' | '[' => {
+                let path = stack
+                    .last()
+                    .map(Frame::value_path)
+                    .unwrap_or_else(|| "$".to_string());
+                stack.push(if c == '{' {
+                    Frame::Object {
+                        path,
+                        keys: KeyLines::default(),
+                        expecting_key: true,
+                        key: None,
+                    }
+                } else {
+                    Frame::Array { path, index: 0 }
+                });
+            }
+            '}' | ']' => {
+                stack.pop();
+            }
+            ',' => match stack.last_mut() {
+                Some(Frame::Object { expecting_key, .. }) => *expecting_key = true,
+                Some(Frame::Array { index, .. }) => *index += 1,
+                None => {}
+            },
+            _ => {}
+        }
+    }
+
+    duplicates
+}
+
+/// Read a JSON string after its opening quote, decoding escapes
+fn read_json_string(chars: &mut std::str::Chars<'_>, line: &mut usize) -> String {
+    let mut text = String::new();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => break,
+            '\\' => match chars.next() {
+                Some('n') => text.push('\n'),
+                Some('t') => text.push('\t'),
+                Some('r') => text.push('\r'),
+                Some('b') => text.push('\u{8}'),
+                Some('f') => text.push('\u{c}'),
+                Some('u') => {
+                    let hex: String = chars.by_ref().take(4).collect();
+                    let unit = u32::from_str_radix(&hex, 16).unwrap_or(0xFFFD);
+                    // Surrogate pairs are kept as one replacement character;
+                    // equal keys still decode equally
+                    text.push(char::from_u32(unit).unwrap_or('\u{FFFD}'));
+                }
+                Some(other) => text.push(other),
+                None => break,
+            },
+            '\n' => {
+                *line += 1;
+                text.push(c);
+            }
+            _ => text.push(c),
+        }
+    }
+    text
+}
+
+/// Repeated keys in a YAML document, in source order
+///
+/// See the module documentation for the subset of YAML that is scanned.
+pub fn yaml_duplicate_keys(content: &str) -> Vec<DuplicateKey> {
+    enum Frame {
+        Mapping {
+            indent: usize,
+            path: String,
+            keys: KeyLines,
+            last_key: Option<String>,
+        },
+        Sequence {
+            indent: usize,
+            path: String,
+            index: Option<usize>,
+        },
+    }
+
+    impl Frame {
+        fn indent(&self) -> usize {
+            match self {
+                Frame::Mapping { indent, .. } | Frame::Sequence { indent, .. } => *indent,
+            }
+        }
+
+        /// Path of a block nested under the latest key or item
+        fn child_path(&self) -> String {
+            match self {
+                Frame::Mapping { path, last_key, .. } => {
+                    child_path(path, last_key.as_deref().unwrap_or_default())
+                }
+                Frame::Sequence { path, index, .. } => {
+                    format!("{}[{}]", path, index.unwrap_or_default())
+                }
+            }
+        }
+    }
+
+    /// Open a mapping at `indent` unless the top frame is one, and record a key
+    fn record_key(
+        stack: &mut Vec<Frame>,
+        duplicates: &mut Vec<DuplicateKey>,
+        indent: usize,
+        key: String,
+        line: usize,
+    ) {
+        if !matches!(stack.last(), Some(Frame::Mapping { indent: top, .. }) if *top == indent) {
+            let path = stack
+                .last()
+                .map(Frame::child_path)
+                .unwrap_or_else(|| "$".to_string());
+            stack.push(Frame::Mapping {
+                indent,
+                path,
+                keys: KeyLines::default(),
+                last_key: None,
+            });
+        }
+        if let Some(Frame::Mapping {
+            path,
+            keys,
+            last_key,
+            ..
+        }) = stack.last_mut()
+        {
+            // Merge keys may repeat; each one names another mapping to merge
+            if key != "<<" {
+                if let Some(first_line) = keys.insert(&key, line) {
+                    duplicates.push(DuplicateKey {
+                        path: child_path(path, &key),
+                        key: key.clone(),
+                        first_line,
+                        duplicate_line: line,
+                    });
+                }
+            }
+            *last_key = Some(key);
+        }
+    }
+
+    let mut duplicates = Vec::new();
+    let mut stack: Vec<Frame> = Vec::new();
+    // Lines indented deeper than this belong to a scalar or flow collection
+    let mut skip_deeper_than: Option<usize> = None;
+
+    for (number, raw) in content.lines().enumerate() {
+        let line = number + 1;
+        let trimmed = raw.trim_start_matches(' ');
+        let indent = raw.len() - trimmed.len();
+        if trimmed.trim().is_empty() {
+            continue;
+        }
+        if let Some(limit) = skip_deeper_than {
+            if indent > limit {
+                continue;
+            }
+            skip_deeper_than = None;
+        }
+        if trimmed.starts_with('#') {
+            continue;
+        }
+        if trimmed.starts_with("---") || trimmed.starts_with("...") {
+            stack.clear();
+            continue;
+        }
+
+        // Close blocks this line is not part of; a sequence may sit at the
+        // same indent as the key that holds it
+        while let Some(top) = stack.last() {
+            let is_item = trimmed == "-" || trimmed.starts_with("- ");
+            let closes = top.indent() > indent
+                || (top.indent() == indent && matches!(top, Frame::Sequence { .. }) && !is_item);
+            if !closes {
+                break;
+            }
+            stack.pop();
+        }
+
+        // Peel off sequence indicators: each `- ` opens or advances a
+        // sequence, and what follows it starts two columns further in
+        let mut rest = trimmed;
+        let mut column = indent;
+        while rest == "-" || rest.starts_with("- ") {
+            let advanced = match stack.last_mut() {
+                Some(Frame::Sequence {
+                    indent: top,
+                    index,
+                    ..
+                }) if *top == column => {
+                    *index = Some(index.map_or(0, |i| i + 1));
+                    true
+                }
+                _ => false,
+            };
+            if !advanced {
+                let path = stack
+                    .last()
+                    .map(Frame::child_path)
+                    .unwrap_or_else(|| "$".to_string());
+                stack.push(Frame::Sequence {
+                    indent: column,
+                    path,
+                    index: Some(0),
+                });
+            }
+            let after = rest[1..].trim_start_matches(' ');
+            column += rest.len() - after.len();
+            rest = after;
+        }
+        if rest.is_empty() {
+            continue;
+        }
+
+        let Some((key, value)) = split_yaml_key(rest) else {
+            // A scalar item or a flow collection: anything indented under it
+            // continues it
+            skip_deeper_than = Some(column);
+            continue;
+        };
+        record_key(&mut stack, &mut duplicates, column, key, line);
+
+        let value = value.trim();
+        let nested = value.is_empty()
+            || value.starts_with('#')
+            || (value.starts_with('&') && !value.contains(' '));
+        if !nested {
+            // Block scalars, flow collections and plain or quoted scalars
+            // may all run on over more deeply indented lines
+            skip_deeper_than = Some(column);
+        }
+    }
+
+    duplicates
+}
+
+/// Split a `key: value` line into its decoded key and the text after the
+/// colon, or `None` if the line holds no key
+fn split_yaml_key(text: &str) -> Option<(String, &str)> {
+    let is_separator = |rest: &str| rest.is_empty() || rest.starts_with([' ', '\t']);
+
+    match text.chars().next()? {
+        quote @ ('"' | '\'') => {
+            let mut key = String::new();
+            let mut chars = text.char_indices().skip(1);
+            while let Some((i, c)) = chars.next() {
+                if c == quote {
+                    // Single-quoted strings escape a quote by doubling it
+                    if quote == '\'' && text[i + 1..].starts_with('\'') {
+                        chars.next();
+                        key.push('\'');
+                        continue;
+                    }
+                    let after = text[i + 1..].trim_start();
+                    let value = after.strip_prefix(':').filter(|rest| is_separator(rest))?;
+                    return Some((key, value));
+                }
+                if c == '\\' && quote == '"' {
+                    if let Some((_, escaped)) = chars.next() {
+                        key.push(match escaped {
+                            'n' => '\n',
+                            't' => '\t',
+                            other => other,
+                        });
+                    }
+                    continue;
+                }
+                key.push(c);
+            }
+            None
+        }
+        '{' | '[' | '?' | '|' | '>' | '*' | '&' | '!' | '%' | '@' | '`' | '#' => None,
+        _ => {
+            let colon = text
+                .match_indices(':')
+                .map(|(i, _)| i)
+                .find(|&i| is_separator(&text[i + 1..]))?;
+            let key = text[..colon].trim_end();
+            // A ` #` before the colon starts a comment, not a key
+            if key.contains(" #") {
+                return None;
+            }
+            Some((key.to_string(), &text[colon + 1..]))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn summary(duplicates: &[DuplicateKey]) -> Vec<(&str, usize, usize)> {
+        duplicates
+            .iter()
+            .map(|d| (d.path.as_str(), d.first_line, d.duplicate_line))
+            .collect()
+    }
+
+    #[test]
+    fn test_yaml_duplicate_keys_report_both_lines() {
+        let yaml = "\
+name: api
+database:
+  host: db1
+  port: 5432
+  # host: commented out
+  host: db2
+replicas: 3
+name: api-v2
+";
+        let duplicates = yaml_duplicate_keys(yaml);
+        assert_eq!(
+            summary(&duplicates),
+            vec![("$.database.host", 3, 6), ("$.name", 1, 8)]
+        );
+        assert_eq!(duplicates[0].key, "host");
+
+        let finding = duplicates[1].to_finding();
+        assert_eq!(finding.code, DUPLICATE_KEY);
+        assert_eq!(finding.path, "$.name");
+        assert!(finding.message.contains("line 1"));
+        assert!(finding.message.contains("line 8"));
+    }
+
+    #[test]
+    fn test_yaml_sequences_and_scalars() {
+        let yaml = "\
+servers:
+  - name: a
+    port: 80
+    port: 81
+  - name: b
+    port: 80
+tags:
+- web
+- name
+notes: |
+  name: not a key
+  name: still not a key
+'quoted key': 1
+\"quoted key\": 2
+url: http://example.com:8080
+defaults: &defaults
+  retries: 3
+prod:
+  <<: *defaults
+  <<: *defaults
+";
+        assert_eq!(
+            summary(&yaml_duplicate_keys(yaml)),
+            vec![("$.servers[0].port", 3, 4), ("$.quoted key", 13, 14)]
+        );
+    }
+
+    #[test]
+    fn test_yaml_same_key_in_different_mappings_is_not_a_duplicate() {
+        let yaml = "\
+a:
+  x: 1
+b:
+  x: 2
+---
+a: 3
+";
+        assert!(yaml_duplicate_keys(yaml).is_empty());
+    }
+
+    #[test]
+    fn test_json_duplicate_keys() {
+        let json = r#"{
+  "name": "api",
+  "servers": [
+    {"port": 80, "port": 81},
+    {"port": 80}
+  ],
+  "label": "has \"name\": inside",
+  "name": "escaped"
+}"#;
+        assert_eq!(
+            summary(&json_duplicate_keys(json)),
+            vec![("$.servers[0].port", 4, 4), ("$.name", 2, 8)]
+        );
+        assert!(json_duplicate_keys(r#"{"a": {"x": 1}, "b": {"x": 2}}"#).is_empty());
+    }
+}
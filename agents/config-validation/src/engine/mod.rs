@@ -5,12 +5,31 @@
 
 pub mod rules;
 
+use crate::audit::{AuditLog, AuditRecord};
+use crate::clock::{system_clock, Clock};
+use crate::contracts::{
+    DecisionEvent, DecisionType, DecisionVerdict, IssueCounts, IssueRollup, IssueSeverity,
+    IssueSummary, PerformanceMetrics, ValidationOutputs, VerdictThresholds,
+};
+use crate::ids::{random_ids, IdGenerator};
+use crate::telemetry::DecisionEventSink;
 use crate::{ConfigValue, Environment};
+use rules::reference::ReferenceResolver;
 use rules::{BoxedRule, Rule, RuleCategory, RuleContext, Severity, ValidationFinding};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashMap};
 use std::sync::Arc;
 
+/// Maximum latency budget for a validation, in milliseconds
+pub const MAX_LATENCY_MS: u64 = 1500;
+
+/// Maximum token budget for a decision's outputs
+pub const MAX_TOKENS: u32 = 800;
+
+/// Number of most frequent issue codes kept in an [`IssueRollup`]
+pub const ROLLUP_TOP_CODES: usize = 5;
+
 /// Result of a validation operation
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ValidationResult {
@@ -20,6 +39,9 @@ pub struct ValidationResult {
     pub findings: Vec<ValidationFinding>,
     /// Number of rules evaluated
     pub rules_evaluated: usize,
+    /// Ids of the rules that were evaluated, in evaluation order
+    #[serde(default)]
+    pub rules_applied: Vec<String>,
     /// Number of rules that passed (no findings)
     pub rules_passed: usize,
     /// Number of rules that failed (produced findings)
@@ -34,8 +56,180 @@ pub struct ValidationResult {
     pub environment: Environment,
     /// Validation duration in milliseconds
     pub duration_ms: u64,
-    /// Breakdown by category
-    pub category_summary: HashMap<RuleCategory, CategorySummary>,
+    /// Time spent in each rule's evaluation, in microseconds, by rule id
+    ///
+    /// A rule evaluated more than once, as in compatibility checks, reports
+    /// its total.
+    #[serde(default)]
+    pub rule_timings: BTreeMap<String, u64>,
+    /// Findings dropped for falling below the severity threshold
+    #[serde(default)]
+    pub suppressed_count: usize,
+    /// Breakdown by category, in category order
+    pub category_summary: BTreeMap<RuleCategory, CategorySummary>,
+}
+
+impl ValidationResult {
+    /// Summarize the findings as contract [`ValidationOutputs`]
+    ///
+    /// Critical and error findings count as errors. The rule id doubles as
+    /// the issue code.
+    pub fn to_outputs(&self) -> ValidationOutputs {
+        let issues: Vec<IssueSummary> = self
+            .findings
+            .iter()
+            .map(|f| IssueSummary {
+                code: f.rule_id.clone(),
+                severity: f.severity,
+                path: (!f.field_path.is_empty()).then(|| f.field_path.clone()),
+                rule_id: Some(f.rule_id.clone()),
+            })
+            .collect();
+
+        let codes = |keep: fn(&IssueSeverity) -> bool| -> Vec<String> {
+            issues.iter().filter(|i| keep(&i.severity)).map(|i| i.code.clone()).collect()
+        };
+        let error_codes = codes(IssueSeverity::is_blocking);
+        let warning_codes = codes(|s| *s == IssueSeverity::Warning);
+        let info_count = issues.iter().filter(|i| i.severity == IssueSeverity::Info).count();
+
+        ValidationOutputs {
+            is_valid: self.is_valid,
+            error_count: error_codes.len() as u32,
+            warning_count: warning_codes.len() as u32,
+            info_count: info_count as u32,
+            coverage: self.coverage,
+            error_codes,
+            warning_codes,
+            issues,
+            rules_applied: self.rules_applied.clone(),
+            fields_validated: Vec::new(),
+            fields_skipped: Vec::new(),
+            rollup: Some(self.issue_summary()),
+        }
+    }
+
+    /// Count the findings the verdict is judged by (info is not counted)
+    pub fn issue_counts(&self) -> IssueCounts {
+        let count = |severity: Severity| {
+            self.findings.iter().filter(|f| f.severity == severity).count() as u32
+        };
+        IssueCounts {
+            critical: count(Severity::Critical),
+            errors: count(Severity::Error),
+            warnings: count(Severity::Warning),
+        }
+    }
+
+    /// Collapse findings sharing a [`ValidationFinding::fingerprint`]
+    ///
+    /// The most severe finding of each group is kept, in the position of the
+    /// group's first finding; ties keep the earliest. Category finding counts
+    /// are reduced to match. Validity is unchanged, since the most severe
+    /// finding survives. Results from [`ValidationEngine::validate`] keep
+    /// every finding unless this is called.
+    pub fn deduplicated(mut self) -> ValidationResult {
+        let mut positions: HashMap<String, usize> = HashMap::new();
+        let mut kept: Vec<ValidationFinding> = Vec::with_capacity(self.findings.len());
+        let mut dropped = Vec::new();
+
+        for finding in std::mem::take(&mut self.findings) {
+            match positions.get(&finding.fingerprint()) {
+                Some(&index) if finding.severity > kept[index].severity => {
+                    dropped.push(std::mem::replace(&mut kept[index], finding));
+                }
+                Some(_) => dropped.push(finding),
+                None => {
+                    positions.insert(finding.fingerprint(), kept.len());
+                    kept.push(finding);
+                }
+            }
+        }
+
+        for finding in dropped {
+            if let Some(summary) = self.category_summary.get_mut(&finding.category) {
+                summary.findings_count = summary.findings_count.saturating_sub(1);
+                if finding.is_blocking() {
+                    summary.blocking_count = summary.blocking_count.saturating_sub(1);
+                }
+            }
+        }
+
+        self.findings = kept;
+        self
+    }
+
+    /// Map the findings to an allow/block verdict
+    pub fn verdict(&self, thresholds: &VerdictThresholds) -> DecisionVerdict {
+        thresholds.verdict(self.issue_counts())
+    }
+
+    /// Roll up the findings by severity, rule category, and code
+    ///
+    /// Severities are the engine's own (so `critical` is kept apart from
+    /// `error`), and the [`ROLLUP_TOP_CODES`] most frequent rule ids are
+    /// listed as codes.
+    pub fn issue_summary(&self) -> IssueRollup {
+        let mut rollup = IssueRollup {
+            total: self.findings.len() as u32,
+            ..IssueRollup::default()
+        };
+        let mut codes = std::collections::BTreeMap::new();
+
+        for finding in &self.findings {
+            *rollup.by_severity.entry(finding.severity.to_string()).or_insert(0) += 1;
+            *rollup.by_category.entry(finding.category.to_string()).or_insert(0) += 1;
+            *codes.entry(finding.rule_id.clone()).or_insert(0) += 1;
+        }
+
+        rollup.top_codes = IssueRollup::top_codes(codes, ROLLUP_TOP_CODES);
+        rollup
+    }
+
+    /// Build the DecisionEvent recording this result
+    ///
+    /// The performance metrics carry this result's duration, rule count, and
+    /// coverage, plus utilization of [`MAX_TOKENS`] and [`MAX_LATENCY_MS`].
+    pub fn to_decision_event(
+        &self,
+        inputs_hash: impl Into<String>,
+        execution_ref: impl Into<String>,
+    ) -> DecisionEvent {
+        let outputs = self.to_outputs();
+        let performance = PerformanceMetrics::new(self.duration_ms, self.rules_evaluated as u32)
+            .with_coverage(self.coverage)
+            .with_budgets(estimate_tokens(&outputs), MAX_TOKENS, MAX_LATENCY_MS);
+
+        DecisionEvent::new(
+            DecisionType::ConfigValidationResult,
+            inputs_hash.into(),
+            outputs,
+            self.confidence,
+            execution_ref.into(),
+        )
+        .with_verdict(self.verdict(&VerdictThresholds::default()))
+        .with_performance(performance)
+        .with_metadata("environment", serde_json::json!(self.environment.to_string()))
+    }
+}
+
+/// Rough token count of the serialized outputs (about four bytes per token)
+fn estimate_tokens(outputs: &ValidationOutputs) -> u32 {
+    let bytes = serde_json::to_vec(outputs).map(|b| b.len()).unwrap_or(0);
+    bytes.div_ceil(4) as u32
+}
+
+/// Hash of the validated inputs, used as the decision's `inputs_hash`
+///
+/// The value is hashed through `serde_json::Value`, whose objects are sorted
+/// by key, so equal values hash the same regardless of map iteration order
+/// (for example after being read back from an audit record).
+pub(crate) fn inputs_hash(value: &ConfigValue, environment: Environment, namespace: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(namespace.as_bytes());
+    hasher.update(environment.to_string().as_bytes());
+    hasher.update(crate::normalize::canonical_bytes(value));
+    hex::encode(hasher.finalize())
 }
 
 /// Summary for a single rule category
@@ -47,12 +241,35 @@ pub struct CategorySummary {
     pub blocking_count: usize,
 }
 
+/// Whether a rule would run on a configuration, from
+/// [`ValidationEngine::explain`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RuleApplicability {
+    pub rule_id: String,
+    pub name: String,
+    pub category: RuleCategory,
+    pub applicable: bool,
+    /// Why the rule is skipped, or how its findings are changed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
 /// The core validation engine
 pub struct ValidationEngine {
     /// Registered validation rules
     rules: Vec<Arc<dyn Rule>>,
     /// Default schema version
     default_schema_version: Option<String>,
+    /// Thresholds for the verdict on emitted decisions
+    verdict_thresholds: VerdictThresholds,
+    /// Local audit log of emitted decisions
+    audit_log: Option<Arc<AuditLog>>,
+    /// Time source for durations and event timestamps
+    clock: Arc<dyn Clock>,
+    /// Source of event and execution ids
+    ids: Arc<dyn IdGenerator>,
+    /// Severities replacing those reported by a rule, by rule id
+    severity_overrides: HashMap<String, Severity>,
 }
 
 impl Default for ValidationEngine {
@@ -64,10 +281,7 @@ impl Default for ValidationEngine {
 impl ValidationEngine {
     /// Create a new validation engine with default rules
     pub fn new() -> Self {
-        let mut engine = Self {
-            rules: Vec::new(),
-            default_schema_version: None,
-        };
+        let mut engine = Self::empty();
         engine.register_default_rules();
         engine
     }
@@ -77,28 +291,52 @@ impl ValidationEngine {
         Self {
             rules: Vec::new(),
             default_schema_version: None,
+            verdict_thresholds: VerdictThresholds::default(),
+            audit_log: None,
+            clock: system_clock(),
+            ids: random_ids(),
+            severity_overrides: HashMap::new(),
         }
     }
 
     /// Register default validation rules
+    ///
+    /// Only rules that need no per-schema configuration are registered here;
+    /// field-specific rules (required, type, bounds, enum, deprecated) are
+    /// added by callers via [`ValidationEngine::register`]. The pattern,
+    /// format, collection, conditional, reference and secret rules start out
+    /// empty; [`ValidationEngine::with_schema_patterns`],
+    /// [`ValidationEngine::with_schema_formats`],
+    /// [`ValidationEngine::with_schema_collections`],
+    /// [`ValidationEngine::with_schema_conditionals`],
+    /// [`ValidationEngine::with_schema_references`] and
+    /// [`ValidationEngine::with_schema_secrets`] fill them. The placeholder
+    /// rule flags [`rules::placeholder::DEFAULT_PLACEHOLDERS`] unless
+    /// [`ValidationEngine::with_placeholders`] replaces them.
     fn register_default_rules(&mut self) {
-        // Required field rules
-        self.register(Arc::new(rules::required::RequiredFieldsRule::new()));
+        // Environment-specific rules
+        self.register(Arc::new(rules::environment::EnvironmentRule::new()));
 
-        // Type checking rules
-        self.register(Arc::new(rules::type_check::TypeCheckRule::new()));
+        // Placeholder values such as CHANGEME and TODO
+        self.register(Arc::new(rules::placeholder::PlaceholderRule::new()));
 
-        // Bounds validation rules
-        self.register(Arc::new(rules::bounds::BoundsRule::new()));
+        // Pattern rules
+        self.register(Arc::new(rules::pattern::PatternRule::new()));
 
-        // Enum validation rules
-        self.register(Arc::new(rules::enum_check::EnumRule::new()));
+        // URL, email and IP address format rules
+        self.register(Arc::new(rules::formats::FormatRule::new()));
 
-        // Deprecation detection rules
-        self.register(Arc::new(rules::deprecated::DeprecatedFieldsRule::new()));
+        // Unique-items and not-empty rules for arrays and objects
+        self.register(Arc::new(rules::collection::CollectionRule::new()));
 
-        // Environment-specific rules
-        self.register(Arc::new(rules::environment::EnvironmentRule::new()));
+        // Cross-field conditional rules
+        self.register(Arc::new(rules::conditional::ConditionalRule::new()));
+
+        // Cross-reference rules
+        self.register(Arc::new(rules::reference::ReferenceRule::new()));
+
+        // Plaintext values in sensitive and must-encrypt fields
+        self.register(Arc::new(rules::secrets::SecretRule::new()));
 
         // Compatibility rules
         self.register(Arc::new(rules::compatibility::CompatibilityRule::new()));
@@ -114,17 +352,189 @@ impl ValidationEngine {
         self.rules.push(Arc::from(rule));
     }
 
+    /// Verify secret references against a provider
+    ///
+    /// Registers [`rules::secret_refs::SecretReferenceRule`], which is not a
+    /// default rule because it needs a provider to resolve against.
+    pub fn with_secret_provider(
+        mut self,
+        provider: Arc<dyn llm_config_core::providers::SecretProvider>,
+    ) -> Self {
+        self.register(Arc::new(rules::secret_refs::SecretReferenceRule::new(
+            provider,
+        )));
+        self
+    }
+
+    /// Check that referenced `${VAR}` environment variables are defined
+    ///
+    /// Registers [`rules::env_vars::EnvVarRule`] treating only `names` as
+    /// defined, replacing any registered earlier. It is not a default rule
+    /// because what is defined depends on where the configuration deploys;
+    /// pass `std::env::vars().map(|(name, _)| name)` to check against the
+    /// current process.
+    pub fn with_env_vars<I, S>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let rule = rules::env_vars::EnvVarRule::with_variables(names);
+        self.rules.retain(|r| r.id() != rule.id());
+        self.register(Arc::new(rule));
+        self
+    }
+
+    /// Enforce the pattern constraints of a schema
+    ///
+    /// Replaces any registered [`rules::pattern::PatternRule`] with one built
+    /// from the schema's `ValidationConstraint::Pattern` constraints.
+    pub fn with_schema_patterns(mut self, schema: &crate::contracts::ConfigSchema) -> Self {
+        let rule = rules::pattern::PatternRule::from_schema(schema);
+        self.rules.retain(|r| r.id() != rule.id());
+        self.register(Arc::new(rule));
+        self
+    }
+
+    /// Check the URL, email and IP address fields of a schema
+    ///
+    /// Replaces any registered [`rules::formats::FormatRule`] with one built
+    /// from the schema's `FieldType::Url`, `FieldType::Email` and
+    /// `FieldType::IpAddress` fields.
+    pub fn with_schema_formats(mut self, schema: &crate::contracts::ConfigSchema) -> Self {
+        let rule = rules::formats::FormatRule::from_schema(schema);
+        self.rules.retain(|r| r.id() != rule.id());
+        self.register(Arc::new(rule));
+        self
+    }
+
+    /// Enforce the unique-items and not-empty constraints of a schema
+    ///
+    /// Replaces any registered [`rules::collection::CollectionRule`] with one
+    /// built from the schema's `ValidationConstraint::UniqueItems` and
+    /// `ValidationConstraint::NotEmpty` constraints.
+    pub fn with_schema_collections(mut self, schema: &crate::contracts::ConfigSchema) -> Self {
+        let rule = rules::collection::CollectionRule::from_schema(schema);
+        self.rules.retain(|r| r.id() != rule.id());
+        self.register(Arc::new(rule));
+        self
+    }
+
+    /// Enforce the conditional constraints of a schema
+    ///
+    /// Replaces any registered [`rules::conditional::ConditionalRule`] with one
+    /// built from the schema's `ValidationConstraint::Conditional` constraints.
+    pub fn with_schema_conditionals(mut self, schema: &crate::contracts::ConfigSchema) -> Self {
+        let rule = rules::conditional::ConditionalRule::from_schema(schema);
+        self.rules.retain(|r| r.id() != rule.id());
+        self.register(Arc::new(rule));
+        self
+    }
+
+    /// Enforce the reference constraints of a schema
+    ///
+    /// Replaces any registered [`rules::reference::ReferenceRule`] with one
+    /// built from the schema's `ValidationConstraint::Reference` constraints.
+    /// References are only looked up by
+    /// [`ValidationEngine::validate_with_resolver`]; other validations report
+    /// them as unverified warnings.
+    pub fn with_schema_references(mut self, schema: &crate::contracts::ConfigSchema) -> Self {
+        let rule = rules::reference::ReferenceRule::from_schema(schema);
+        self.rules.retain(|r| r.id() != rule.id());
+        self.register(Arc::new(rule));
+        self
+    }
+
+    /// Flag only the given placeholder values
+    ///
+    /// Replaces any registered [`rules::placeholder::PlaceholderRule`] with one
+    /// flagging `placeholders` instead of
+    /// [`rules::placeholder::DEFAULT_PLACEHOLDERS`].
+    pub fn with_placeholders<I, S>(mut self, placeholders: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let rule = rules::placeholder::PlaceholderRule::with_placeholders(placeholders);
+        self.rules.retain(|r| r.id() != rule.id());
+        self.register(Arc::new(rule));
+        self
+    }
+
+    /// Check the sensitive and must-encrypt fields of a schema
+    ///
+    /// Replaces any registered [`rules::secrets::SecretRule`] with one built
+    /// from the schema's sensitive fields and `EnvironmentRuleType::MustEncrypt`
+    /// environment rules.
+    pub fn with_schema_secrets(mut self, schema: &crate::contracts::ConfigSchema) -> Self {
+        let rule = rules::secrets::SecretRule::from_schema(schema);
+        self.rules.retain(|r| r.id() != rule.id());
+        self.register(Arc::new(rule));
+        self
+    }
+
+    /// Check the `MustDiffer` and `RotationRequired` environment rules of a
+    /// schema
+    ///
+    /// Replaces any registered [`rules::environment::EnvironmentRule`] with
+    /// one built from the schema. `MustDiffer` rules are checked by
+    /// [`ValidationEngine::validate_across_environments`]; `RotationRequired`
+    /// rules read [`rules::RuleContext::value_metadata`], so pass it through
+    /// [`ValidationEngine::validate_with_context`].
+    pub fn with_schema_environment_rules(mut self, schema: &crate::contracts::ConfigSchema) -> Self {
+        let rule = rules::environment::EnvironmentRule::from_schema(schema);
+        self.rules.retain(|r| r.id() != rule.id());
+        self.register(Arc::new(rule));
+        self
+    }
+
     /// Set the default schema version
     pub fn with_schema_version(mut self, version: impl Into<String>) -> Self {
         self.default_schema_version = Some(version.into());
         self
     }
 
+    /// Set the thresholds for the verdict on emitted decisions
+    pub fn with_verdict_thresholds(mut self, thresholds: VerdictThresholds) -> Self {
+        self.verdict_thresholds = thresholds;
+        self
+    }
+
+    /// Record every emitted decision in a local audit log
+    pub fn with_audit_log(mut self, log: AuditLog) -> Self {
+        self.audit_log = Some(Arc::new(log));
+        self
+    }
+
+    /// Read durations and event timestamps from `clock`
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Draw event and execution ids from `ids`
+    ///
+    /// With [`crate::ids::SeededIds`] and a [`crate::clock::MockClock`],
+    /// emitted decisions are reproducible byte for byte.
+    pub fn with_id_generator(mut self, ids: Arc<dyn IdGenerator>) -> Self {
+        self.ids = ids;
+        self
+    }
+
+    /// Thresholds for the verdict on emitted decisions
+    pub fn verdict_thresholds(&self) -> &VerdictThresholds {
+        &self.verdict_thresholds
+    }
+
     /// Get all registered rules
     pub fn rules(&self) -> &[Arc<dyn Rule>] {
         &self.rules
     }
 
+    /// Look up a registered rule by id
+    pub fn rule(&self, id: &str) -> Option<Arc<dyn Rule>> {
+        self.rules.iter().find(|r| r.id() == id).cloned()
+    }
+
     /// Get rules by category
     pub fn rules_by_category(&self, category: RuleCategory) -> Vec<Arc<dyn Rule>> {
         self.rules
@@ -134,6 +544,43 @@ impl ValidationEngine {
             .collect()
     }
 
+    /// Which registered rules would run on a configuration, in registration
+    /// order, without evaluating any of them
+    ///
+    /// Applicability depends only on the environment and namespace today;
+    /// `value` is taken so the call mirrors [`ValidationEngine::validate`].
+    pub fn explain(
+        &self,
+        _value: &ConfigValue,
+        environment: Environment,
+        namespace: &str,
+    ) -> Vec<RuleApplicability> {
+        let context = RuleContext::new(environment, namespace);
+        self.rules
+            .iter()
+            .map(|rule| {
+                let applicable = rule.is_applicable(&context);
+                let reason = if !applicable {
+                    Some(format!(
+                        "Not applicable to {} configurations in namespace '{}'",
+                        environment, namespace
+                    ))
+                } else {
+                    self.severity_overrides.get(rule.id()).map(|severity| {
+                        format!("Findings are reported as {} by a severity override", severity)
+                    })
+                };
+                RuleApplicability {
+                    rule_id: rule.id().to_string(),
+                    name: rule.name().to_string(),
+                    category: rule.category(),
+                    applicable,
+                    reason,
+                }
+            })
+            .collect()
+    }
+
     /// Validate a configuration value
     ///
     /// This method is deterministic - the same input will always produce
@@ -144,10 +591,123 @@ impl ValidationEngine {
         environment: Environment,
         namespace: &str,
     ) -> ValidationResult {
-        let start = std::time::Instant::now();
+        let start = self.clock.instant();
         let context = RuleContext::new(environment, namespace);
 
-        self.validate_with_context(value, &context).await.finalize(start.elapsed())
+        self.validate_with_context(value, &context)
+            .await
+            .finalize(self.clock.elapsed(start))
+    }
+
+    /// Validate a configuration value, keeping only findings at or above
+    /// `min_severity`
+    ///
+    /// Dropped findings are counted in `suppressed_count`. Rule counts still
+    /// cover every rule, so a rule whose findings were all dropped counts as
+    /// failed; validity and confidence are computed from the kept findings.
+    pub async fn validate_with_threshold(
+        &self,
+        value: &ConfigValue,
+        environment: Environment,
+        namespace: &str,
+        min_severity: Severity,
+    ) -> ValidationResult {
+        let start = self.clock.instant();
+        let context = RuleContext::new(environment, namespace);
+        let applicable_rules = self.applicable_rules(&context);
+
+        self.evaluate_rules(&applicable_rules, value, &context, min_severity, None)
+            .await
+            .finalize(self.clock.elapsed(start))
+    }
+
+    /// Validate a configuration value, checking that referenced keys exist
+    ///
+    /// Rules are given `resolver` to look up the targets of
+    /// `ValidationConstraint::Reference` constraints, and report a
+    /// `REFERENCE_NOT_FOUND` finding for each target it does not know.
+    pub async fn validate_with_resolver(
+        &self,
+        value: &ConfigValue,
+        context: &RuleContext,
+        resolver: &dyn ReferenceResolver,
+    ) -> ValidationResult {
+        let start = self.clock.instant();
+        let applicable_rules = self.applicable_rules(context);
+
+        self.evaluate_rules(&applicable_rules, value, context, Severity::Info, Some(resolver))
+            .await
+            .finalize(self.clock.elapsed(start))
+    }
+
+    /// Validate a configuration value and emit the resulting DecisionEvent
+    ///
+    /// Emission failures are logged and do not affect the returned result,
+    /// matching the non-blocking behavior of [`crate::DecisionEventEmitter`].
+    /// With an audit log configured, the decision is recorded there first,
+    /// whether or not emission succeeds.
+    pub async fn validate_and_emit(
+        &self,
+        value: &ConfigValue,
+        environment: Environment,
+        namespace: &str,
+        emitter: &dyn DecisionEventSink,
+    ) -> (ValidationResult, DecisionEvent) {
+        let result = self.validate(value, environment, namespace).await;
+        let mut event = result
+            .to_decision_event(
+                inputs_hash(value, environment, namespace),
+                self.ids.next_id().to_string(),
+            )
+            .with_verdict(result.verdict(&self.verdict_thresholds));
+        event.event_id = self.ids.next_id();
+        event.timestamp = self.clock.now();
+
+        if let Some(log) = &self.audit_log {
+            let mut record = AuditRecord::from_event(&event, namespace, environment);
+            if log.stores_inputs() {
+                record = record.with_input(value.clone());
+            }
+            if let Err(e) = log.append(&record) {
+                tracing::warn!(event_id = %event.event_id, error = %e, "Failed to write audit record");
+            }
+        }
+
+        if let Err(e) = emitter.emit(event.clone()).await {
+            tracing::warn!(event_id = %event.event_id, error = %e, "Failed to emit decision event");
+        }
+
+        (result, event)
+    }
+
+    /// Validate many configuration values in one call
+    ///
+    /// Each item is a namespace, a value, and the environment to validate it
+    /// for. Results are returned in the order of `items`, and each result's
+    /// `duration_ms` covers only that item's evaluation. Applicable rules are
+    /// filtered once per environment rather than once per item, using the
+    /// context of the first item for that environment.
+    pub async fn validate_batch(
+        &self,
+        items: &[(&str, &ConfigValue, Environment)],
+    ) -> Vec<ValidationResult> {
+        let mut applicable: HashMap<Environment, Vec<&Arc<dyn Rule>>> = HashMap::new();
+        let mut results = Vec::with_capacity(items.len());
+
+        for (namespace, value, environment) in items {
+            let context = RuleContext::new(*environment, *namespace);
+            let rules = applicable
+                .entry(*environment)
+                .or_insert_with(|| self.applicable_rules(&context));
+
+            let start = self.clock.instant();
+            let builder = self
+                .evaluate_rules(rules, value, &context, Severity::Info, None)
+                .await;
+            results.push(builder.finalize(self.clock.elapsed(start)));
+        }
+
+        results
     }
 
     /// Validate with a custom context
@@ -156,21 +716,67 @@ impl ValidationEngine {
         value: &ConfigValue,
         context: &RuleContext,
     ) -> ValidationResultBuilder {
-        let mut builder = ValidationResultBuilder::new(context.environment);
-        builder.schema_version = self.default_schema_version.clone();
+        let applicable_rules = self.applicable_rules(context);
+        self.evaluate_rules(&applicable_rules, value, context, Severity::Info, None)
+            .await
+    }
 
-        // Filter applicable rules
-        let applicable_rules: Vec<_> = self.rules
+    /// Report every finding of a rule at `severity`
+    ///
+    /// Overrides are applied before findings are filtered and counted, so an
+    /// override to a blocking severity fails validation and one to a
+    /// non-blocking severity no longer does. Setting a rule again replaces its
+    /// override.
+    pub fn with_severity_override(&mut self, rule_id: &str, severity: Severity) {
+        self.severity_overrides.insert(rule_id.to_string(), severity);
+    }
+
+    /// Apply any severity override for a rule to its findings
+    fn override_severity(&self, rule_id: &str, findings: &mut [ValidationFinding]) {
+        if let Some(&severity) = self.severity_overrides.get(rule_id) {
+            for finding in findings {
+                finding.severity = severity;
+            }
+        }
+    }
+
+    /// Rules applicable in a context, in registration order
+    fn applicable_rules(&self, context: &RuleContext) -> Vec<&Arc<dyn Rule>> {
+        self.rules
             .iter()
             .filter(|r| r.is_applicable(context))
-            .collect();
+            .collect()
+    }
+
+    /// Evaluate already filtered rules against a value, keeping findings at
+    /// or above `min_severity`
+    ///
+    /// With a `resolver`, rules are evaluated through
+    /// [`Rule::evaluate_with_resolver`].
+    async fn evaluate_rules(
+        &self,
+        applicable_rules: &[&Arc<dyn Rule>],
+        value: &ConfigValue,
+        context: &RuleContext,
+        min_severity: Severity,
+        resolver: Option<&dyn ReferenceResolver>,
+    ) -> ValidationResultBuilder {
+        let mut builder = ValidationResultBuilder::new(context.environment);
+        builder.schema_version = self.default_schema_version.clone();
+        builder.min_severity = min_severity;
 
         // Evaluate all rules
-        for rule in &applicable_rules {
-            let findings = rule.evaluate(value, "", context).await;
+        for rule in applicable_rules {
+            let start = self.clock.instant();
+            let mut findings = match resolver {
+                Some(resolver) => rule.evaluate_with_resolver(value, "", context, resolver).await,
+                None => rule.evaluate(value, "", context).await,
+            };
+            let elapsed = self.clock.elapsed(start);
+            self.override_severity(rule.id(), &mut findings);
             let category = rule.category();
 
-            builder.add_rule_result(rule.id(), category, findings);
+            builder.add_rule_result(rule.id(), category, findings, elapsed);
         }
 
         builder
@@ -182,7 +788,7 @@ impl ValidationEngine {
         configs: &[(&str, &ConfigValue)],
         environment: Environment,
     ) -> ValidationResult {
-        let start = std::time::Instant::now();
+        let start = self.clock.instant();
         let mut builder = ValidationResultBuilder::new(environment);
         builder.schema_version = self.default_schema_version.clone();
 
@@ -193,13 +799,52 @@ impl ValidationEngine {
             for (namespace, value) in configs {
                 let context = RuleContext::new(environment, *namespace);
                 if rule.is_applicable(&context) {
-                    let findings = rule.evaluate(value, "", &context).await;
-                    builder.add_rule_result(rule.id(), rule.category(), findings);
+                    let rule_start = self.clock.instant();
+                    let mut findings = rule.evaluate(value, "", &context).await;
+                    let elapsed = self.clock.elapsed(rule_start);
+                    self.override_severity(rule.id(), &mut findings);
+                    builder.add_rule_result(rule.id(), rule.category(), findings, elapsed);
                 }
             }
         }
 
-        builder.finalize(start.elapsed())
+        builder.finalize(self.clock.elapsed(start))
+    }
+
+    /// Compare the configurations of several environments
+    ///
+    /// Environment rules are evaluated across `configs`, reporting a
+    /// `VALUE_NOT_DIFFERENTIATED` finding for each field that a `MustDiffer`
+    /// rule requires to differ but that holds the same value in two of its
+    /// environments. The result is reported for the most production-like
+    /// environment among `configs`.
+    pub async fn validate_across_environments(
+        &self,
+        configs: &HashMap<Environment, ConfigValue>,
+        namespace: &str,
+    ) -> ValidationResult {
+        let start = self.clock.instant();
+        let environment = [
+            Environment::Production,
+            Environment::Staging,
+            Environment::Edge,
+            Environment::Development,
+        ]
+        .into_iter()
+        .find(|env| configs.contains_key(env))
+        .unwrap_or(Environment::Base);
+        let mut builder = ValidationResultBuilder::new(environment);
+        builder.schema_version = self.default_schema_version.clone();
+
+        for rule in self.rules_by_category(RuleCategory::Environment) {
+            let rule_start = self.clock.instant();
+            let mut findings = rule.evaluate_across_environments(configs, namespace).await;
+            let elapsed = self.clock.elapsed(rule_start);
+            self.override_severity(rule.id(), &mut findings);
+            builder.add_rule_result(rule.id(), rule.category(), findings, elapsed);
+        }
+
+        builder.finalize(self.clock.elapsed(start))
     }
 }
 
@@ -209,9 +854,13 @@ pub struct ValidationResultBuilder {
     schema_version: Option<String>,
     findings: Vec<ValidationFinding>,
     rules_evaluated: usize,
+    rules_applied: Vec<String>,
     rules_passed: usize,
     rules_failed: usize,
-    category_summary: HashMap<RuleCategory, CategorySummary>,
+    category_summary: BTreeMap<RuleCategory, CategorySummary>,
+    rule_timings: BTreeMap<String, u64>,
+    min_severity: Severity,
+    suppressed_count: usize,
 }
 
 impl ValidationResultBuilder {
@@ -221,14 +870,30 @@ impl ValidationResultBuilder {
             schema_version: None,
             findings: Vec::new(),
             rules_evaluated: 0,
+            rules_applied: Vec::new(),
             rules_passed: 0,
             rules_failed: 0,
-            category_summary: HashMap::new(),
+            category_summary: BTreeMap::new(),
+            rule_timings: BTreeMap::new(),
+            min_severity: Severity::Info,
+            suppressed_count: 0,
         }
     }
 
-    fn add_rule_result(&mut self, rule_id: &str, category: RuleCategory, findings: Vec<ValidationFinding>) {
+    fn add_rule_result(
+        &mut self,
+        rule_id: &str,
+        category: RuleCategory,
+        mut findings: Vec<ValidationFinding>,
+        elapsed: std::time::Duration,
+    ) {
+        // Rules walk objects in hash order; sort so output does not depend on it
+        findings.sort_by(|a, b| a.field_path.cmp(&b.field_path));
         self.rules_evaluated += 1;
+        *self.rule_timings.entry(rule_id.to_string()).or_insert(0) += elapsed.as_micros() as u64;
+        if !self.rules_applied.iter().any(|id| id == rule_id) {
+            self.rules_applied.push(rule_id.to_string());
+        }
 
         let summary = self.category_summary.entry(category).or_insert(CategorySummary {
             rules_evaluated: 0,
@@ -244,10 +909,14 @@ impl ValidationResultBuilder {
             summary.rules_passed += 1;
         } else {
             self.rules_failed += 1;
-            let blocking = findings.iter().filter(|f| f.is_blocking()).count();
-            summary.findings_count += findings.len();
+            let min_severity = self.min_severity;
+            let (kept, suppressed): (Vec<_>, Vec<_>) =
+                findings.into_iter().partition(|f| f.severity >= min_severity);
+            self.suppressed_count += suppressed.len();
+            let blocking = kept.iter().filter(|f| f.is_blocking()).count();
+            summary.findings_count += kept.len();
             summary.blocking_count += blocking;
-            self.findings.extend(findings);
+            self.findings.extend(kept);
         }
     }
 
@@ -283,6 +952,7 @@ impl ValidationResultBuilder {
             is_valid,
             findings: self.findings,
             rules_evaluated: self.rules_evaluated,
+            rules_applied: self.rules_applied,
             rules_passed: self.rules_passed,
             rules_failed: self.rules_failed,
             coverage,
@@ -291,6 +961,8 @@ impl ValidationResultBuilder {
             environment: self.environment,
             duration_ms: duration.as_millis() as u64,
             category_summary: self.category_summary,
+            rule_timings: self.rule_timings,
+            suppressed_count: self.suppressed_count,
         }
     }
 }
@@ -329,6 +1001,367 @@ mod tests {
         assert_eq!(result1.rules_evaluated, result2.rules_evaluated);
     }
 
+    #[tokio::test]
+    async fn test_schema_patterns_are_enforced_deterministically() {
+        use crate::contracts::{ConfigSchema, FieldRule, FieldType, ValidationConstraint};
+
+        let mut region = FieldRule::new(FieldType::String);
+        region.constraints.push(ValidationConstraint::pattern("^[a-z]{2}-[a-z]+-[0-9]$"));
+        let mut zone = FieldRule::new(FieldType::String);
+        zone.constraints.push(ValidationConstraint::pattern("^[a-z]$"));
+        let schema = ConfigSchema::new("app/cloud", "Cloud", "1.0.0")
+            .with_field("region", region)
+            .with_field("zone", zone);
+        let engine = ValidationEngine::new().with_schema_patterns(&schema);
+        assert_eq!(engine.rules().iter().filter(|r| r.id() == "pattern_check").count(), 1);
+
+        let config = ConfigValue::Object(
+            [
+                ("region".to_string(), ConfigValue::String("EU_WEST".to_string())),
+                ("zone".to_string(), ConfigValue::String("b".to_string())),
+            ]
+            .into_iter()
+            .collect(),
+        );
+        let result1 = engine.validate(&config, Environment::Development, "test").await;
+        let result2 = engine.validate(&config, Environment::Development, "test").await;
+
+        assert!(!result1.is_valid);
+        let mismatches: Vec<_> = result1
+            .findings
+            .iter()
+            .filter(|f| f.rule_id == "pattern_check")
+            .collect();
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].field_path, "region");
+        assert_eq!(
+            serde_json::to_value(&result1.findings).unwrap(),
+            serde_json::to_value(&result2.findings).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_schema_conditionals_are_enforced() {
+        use crate::contracts::{ConfigSchema, FieldRule, FieldType, ValidationConstraint};
+
+        let mut replicas = FieldRule::new(FieldType::Integer);
+        replicas.constraints.push(ValidationConstraint::Conditional {
+            condition: "tier == production".to_string(),
+            then_constraint: Box::new(ValidationConstraint::min(3.0)),
+            else_constraint: None,
+        });
+        let schema = ConfigSchema::new("app/deploy", "Deploy", "1.0.0")
+            .with_field("replicas", replicas)
+            .with_field("tier", FieldRule::new(FieldType::String));
+        let engine = ValidationEngine::new().with_schema_conditionals(&schema);
+
+        let config = |tier: &str| {
+            ConfigValue::Object(
+                [
+                    ("replicas".to_string(), ConfigValue::Integer(1)),
+                    ("tier".to_string(), ConfigValue::String(tier.to_string())),
+                ]
+                .into_iter()
+                .collect(),
+            )
+        };
+        let result = engine.validate(&config("staging"), Environment::Staging, "test").await;
+        assert!(result.findings.iter().all(|f| f.rule_id != "conditional_check"));
+
+        let result = engine.validate(&config("production"), Environment::Staging, "test").await;
+        let failed: Vec<_> = result
+            .findings
+            .iter()
+            .filter(|f| f.rule_id == "conditional_check")
+            .collect();
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed[0].field_path, "replicas");
+        assert_eq!(failed[0].context.as_ref().unwrap()["condition"], "tier == production");
+    }
+
+    #[tokio::test]
+    async fn test_schema_references_are_resolved() {
+        use crate::contracts::{ConfigSchema, FieldRule, FieldType, ValidationConstraint};
+        use rules::reference::{MapReferenceResolver, REFERENCE_NOT_FOUND, REFERENCE_UNVERIFIED};
+
+        let mut database = FieldRule::new(FieldType::String);
+        database.constraints.push(ValidationConstraint::Reference {
+            namespace: Some("shared/databases".to_string()),
+            key_pattern: "^[a-z]+$".to_string(),
+        });
+        let schema = ConfigSchema::new("app/service", "Service", "1.0.0")
+            .with_field("database", database);
+        let engine = ValidationEngine::new().with_schema_references(&schema);
+        let config = ConfigValue::Object(
+            [("database".to_string(), ConfigValue::String("analytics".to_string()))]
+                .into_iter()
+                .collect(),
+        );
+        let context = RuleContext::new(Environment::Production, "app/service");
+        let reference_codes = |result: &ValidationResult| -> Vec<String> {
+            result
+                .findings
+                .iter()
+                .filter(|f| f.rule_id == "reference_check")
+                .map(|f| f.context.as_ref().unwrap()["code"].as_str().unwrap().to_string())
+                .collect()
+        };
+
+        let resolver = MapReferenceResolver::new().with_key("shared/databases", "primary");
+        let result = engine.validate_with_resolver(&config, &context, &resolver).await;
+        assert!(!result.is_valid);
+        assert_eq!(reference_codes(&result), vec![REFERENCE_NOT_FOUND]);
+
+        let resolver = resolver.with_key("shared/databases", "analytics");
+        let result = engine.validate_with_resolver(&config, &context, &resolver).await;
+        assert!(result.is_valid);
+        assert!(reference_codes(&result).is_empty());
+
+        // Without a resolver the reference is only a warning
+        let result = engine.validate(&config, Environment::Production, "app/service").await;
+        assert!(result.is_valid);
+        assert_eq!(reference_codes(&result), vec![REFERENCE_UNVERIFIED]);
+    }
+
+    #[tokio::test]
+    async fn test_schema_formats_are_checked() {
+        use crate::contracts::{ConfigSchema, FieldRule, FieldType};
+        use rules::formats::INVALID_EMAIL;
+
+        let schema = ConfigSchema::new("app/service", "Service", "1.0.0")
+            .with_field("owner", FieldRule::new(FieldType::Email))
+            .with_field("team", FieldRule::new(FieldType::String));
+        let engine = ValidationEngine::new().with_schema_formats(&schema);
+        let config = |owner: &str| {
+            ConfigValue::Object(
+                [
+                    ("owner".to_string(), ConfigValue::String(owner.to_string())),
+                    ("team".to_string(), ConfigValue::String("no-at-sign".to_string())),
+                ]
+                .into_iter()
+                .collect(),
+            )
+        };
+
+        let result = engine.validate(&config("platform"), Environment::Production, "app/service").await;
+        assert!(!result.is_valid);
+        let codes: Vec<_> = result
+            .findings
+            .iter()
+            .filter(|f| f.rule_id == "format_check")
+            .map(|f| (f.field_path.as_str(), f.context.as_ref().unwrap()["code"].clone()))
+            .collect();
+        assert_eq!(codes, vec![("owner", serde_json::json!(INVALID_EMAIL))]);
+
+        let result = engine
+            .validate(&config("platform@example.com"), Environment::Production, "app/service")
+            .await;
+        assert!(result.findings.iter().all(|f| f.rule_id != "format_check"));
+    }
+
+    #[tokio::test]
+    async fn test_validate_batch_keeps_order_and_times_each_item() {
+        use crate::clock::MockClock;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        /// Takes `cost` milliseconds of mock time and flags costs over 10
+        struct CostRule {
+            clock: Arc<MockClock>,
+            applicability_checks: AtomicUsize,
+        }
+
+        #[async_trait::async_trait]
+        impl Rule for CostRule {
+            fn id(&self) -> &str {
+                "cost_check"
+            }
+            fn name(&self) -> &str {
+                "Cost"
+            }
+            fn description(&self) -> &str {
+                "Advances the clock"
+            }
+            fn category(&self) -> RuleCategory {
+                RuleCategory::Bounds
+            }
+            fn is_applicable(&self, _context: &RuleContext) -> bool {
+                self.applicability_checks.fetch_add(1, Ordering::SeqCst);
+                true
+            }
+            async fn evaluate(
+                &self,
+                value: &ConfigValue,
+                path: &str,
+                _context: &RuleContext,
+            ) -> Vec<ValidationFinding> {
+                let ConfigValue::Object(map) = value else {
+                    return Vec::new();
+                };
+                let Some(ConfigValue::Integer(cost)) = map.get("cost") else {
+                    return Vec::new();
+                };
+                self.clock.advance(std::time::Duration::from_millis(*cost as u64));
+                if *cost > 10 {
+                    vec![ValidationFinding::new(
+                        "cost_check",
+                        RuleCategory::Bounds,
+                        Severity::Error,
+                        "Too costly",
+                        path,
+                    )]
+                } else {
+                    Vec::new()
+                }
+            }
+        }
+
+        let at = chrono::DateTime::parse_from_rfc3339("2024-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+        let clock = Arc::new(MockClock::new(at));
+        let rule = Arc::new(CostRule {
+            clock: clock.clone(),
+            applicability_checks: AtomicUsize::new(0),
+        });
+        let mut engine = ValidationEngine::empty().with_clock(clock);
+        engine.register(rule.clone());
+
+        let config = |cost: i64| {
+            ConfigValue::Object(
+                [("cost".to_string(), ConfigValue::Integer(cost))]
+                    .into_iter()
+                    .collect(),
+            )
+        };
+        let (a, b, c, d) = (config(5), config(20), config(7), config(30));
+        let items = [
+            ("app/a", &a, Environment::Production),
+            ("app/b", &b, Environment::Development),
+            ("app/c", &c, Environment::Production),
+            ("app/d", &d, Environment::Production),
+        ];
+        let results = engine.validate_batch(&items).await;
+
+        assert_eq!(rule.applicability_checks.load(Ordering::SeqCst), 2);
+        let timings: Vec<_> = results.iter().map(|r| r.rule_timings["cost_check"]).collect();
+        assert_eq!(timings, vec![5_000, 20_000, 7_000, 30_000]);
+        let summary: Vec<_> = results
+            .iter()
+            .map(|r| (r.environment, r.is_valid, r.duration_ms))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (Environment::Production, true, 5),
+                (Environment::Development, false, 20),
+                (Environment::Production, true, 7),
+                (Environment::Production, false, 30),
+            ]
+        );
+
+        // Each result matches validating the item on its own
+        for ((namespace, value, environment), batched) in items.iter().zip(&results) {
+            let single = engine.validate(value, *environment, namespace).await;
+            assert_eq!(
+                serde_json::to_value(&single).unwrap(),
+                serde_json::to_value(batched).unwrap()
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_rule_timings_cover_every_applied_rule() {
+        use crate::clock::MockClock;
+
+        let at = chrono::DateTime::parse_from_rfc3339("2024-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+        let engine = ValidationEngine::new().with_clock(Arc::new(MockClock::new(at)));
+        let config = ConfigValue::Object(
+            [("key".to_string(), ConfigValue::String("value".to_string()))]
+                .into_iter()
+                .collect(),
+        );
+        let result = engine.validate(&config, Environment::Production, "test").await;
+
+        let timed: Vec<&String> = result.rule_timings.keys().collect();
+        let mut applied: Vec<&String> = result.rules_applied.iter().collect();
+        applied.sort();
+        assert_eq!(timed, applied);
+
+        let json = serde_json::to_value(&result).unwrap();
+        assert_eq!(json["rule_timings"]["pattern_check"], 0);
+    }
+
+    #[tokio::test]
+    async fn test_threshold_suppresses_findings_but_counts_all_rules() {
+        /// Reports one finding per configured severity
+        struct FixedRule {
+            id: &'static str,
+            severities: Vec<Severity>,
+        }
+
+        #[async_trait::async_trait]
+        impl Rule for FixedRule {
+            fn id(&self) -> &str {
+                self.id
+            }
+            fn name(&self) -> &str {
+                self.id
+            }
+            fn description(&self) -> &str {
+                "Fixed findings"
+            }
+            fn category(&self) -> RuleCategory {
+                RuleCategory::Bounds
+            }
+            async fn evaluate(
+                &self,
+                _value: &ConfigValue,
+                _path: &str,
+                _context: &RuleContext,
+            ) -> Vec<ValidationFinding> {
+                self.severities
+                    .iter()
+                    .map(|s| {
+                        ValidationFinding::new(self.id, RuleCategory::Bounds, *s, "finding", "field")
+                    })
+                    .collect()
+            }
+        }
+
+        let mut engine = ValidationEngine::empty();
+        for (id, severities) in [
+            ("noisy", vec![Severity::Info, Severity::Warning]),
+            ("broken", vec![Severity::Error]),
+            ("clean", vec![]),
+        ] {
+            engine.register(Arc::new(FixedRule { id, severities }));
+        }
+        let config = ConfigValue::Object(HashMap::new());
+
+        let result = engine
+            .validate_with_threshold(&config, Environment::Staging, "test", Severity::Error)
+            .await;
+        assert_eq!(result.findings.len(), 1);
+        assert_eq!(result.findings[0].rule_id, "broken");
+        assert_eq!(result.suppressed_count, 2);
+        assert_eq!(result.rules_evaluated, 3);
+        assert_eq!(result.rules_passed, 1);
+        assert_eq!(result.rules_failed, 2);
+        assert!(!result.is_valid);
+        assert_eq!(result.category_summary[&RuleCategory::Bounds].findings_count, 1);
+
+        // The lowest threshold keeps everything, as validate does
+        let all = engine
+            .validate_with_threshold(&config, Environment::Staging, "test", Severity::Info)
+            .await;
+        let plain = engine.validate(&config, Environment::Staging, "test").await;
+        assert_eq!(all.suppressed_count, 0);
+        assert_eq!(all.findings.len(), plain.findings.len());
+        assert_eq!(all.confidence, plain.confidence);
+    }
+
     #[tokio::test]
     async fn test_valid_config_produces_valid_result() {
         let engine = ValidationEngine::empty();
@@ -346,4 +1379,483 @@ mod tests {
         assert!(result.is_valid);
         assert!(result.findings.is_empty());
     }
+
+    /// Sink that records emitted events in memory
+    #[derive(Default)]
+    struct RecordingSink {
+        events: std::sync::Mutex<Vec<DecisionEvent>>,
+    }
+
+    #[async_trait::async_trait]
+    impl DecisionEventSink for RecordingSink {
+        async fn emit(&self, event: DecisionEvent) -> crate::telemetry::Result<()> {
+            self.events.lock().unwrap().push(event);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_issue_summary_rolls_up_mixed_severities() {
+        let finding = |rule: &str, category, severity| {
+            ValidationFinding::new(rule, category, severity, "finding", "field")
+        };
+        let mut builder = ValidationResultBuilder::new(Environment::Production);
+        builder.add_rule_result(
+            "sec-001",
+            RuleCategory::Security,
+            vec![
+                finding("sec-001", RuleCategory::Security, Severity::Critical),
+                finding("sec-001", RuleCategory::Security, Severity::Critical),
+                finding("sec-001", RuleCategory::Security, Severity::Warning),
+            ],
+           std::time::Duration::ZERO,
+        );
+        builder.add_rule_result(
+            "bounds-001",
+            RuleCategory::Bounds,
+            vec![
+                finding("bounds-001", RuleCategory::Bounds, Severity::Error),
+                finding("bounds-001", RuleCategory::Bounds, Severity::Warning),
+            ],
+           std::time::Duration::ZERO,
+        );
+        builder.add_rule_result(
+            "dep-001",
+            RuleCategory::Deprecated,
+            vec![finding("dep-001", RuleCategory::Deprecated, Severity::Info)],
+            std::time::Duration::ZERO,
+        );
+        let result = builder.finalize(std::time::Duration::ZERO);
+
+        let rollup = result.issue_summary();
+        assert_eq!(rollup.total, 6);
+        assert_eq!(rollup.by_severity["critical"], 2);
+        assert_eq!(rollup.by_severity["error"], 1);
+        assert_eq!(rollup.by_severity["warning"], 2);
+        assert_eq!(rollup.by_severity["info"], 1);
+        assert_eq!(rollup.by_category["security"], 3);
+        assert_eq!(rollup.by_category["bounds"], 2);
+        assert_eq!(rollup.by_category["deprecated"], 1);
+
+        let top: Vec<(&str, u32)> = rollup.top_codes.iter().map(|c| (c.code.as_str(), c.count)).collect();
+        assert_eq!(top, vec![("sec-001", 3), ("bounds-001", 2), ("dep-001", 1)]);
+
+        // The decision outputs carry the same rollup
+        assert_eq!(result.to_outputs().rollup, Some(rollup));
+    }
+
+    fn result_with(severities: &[Severity]) -> ValidationResult {
+        let mut builder = ValidationResultBuilder::new(Environment::Production);
+        let findings = severities
+            .iter()
+            .map(|s| ValidationFinding::new("rule", RuleCategory::Bounds, *s, "finding", "field"))
+            .collect();
+        builder.add_rule_result("rule", RuleCategory::Bounds, findings, std::time::Duration::ZERO);
+        builder.finalize(std::time::Duration::ZERO)
+    }
+
+    #[test]
+    fn test_verdict_maps_each_outcome() {
+        let defaults = VerdictThresholds::default();
+
+        assert_eq!(result_with(&[]).verdict(&defaults), DecisionVerdict::Allow);
+        assert_eq!(result_with(&[Severity::Info]).verdict(&defaults), DecisionVerdict::Allow);
+        assert_eq!(
+            result_with(&[Severity::Warning, Severity::Info]).verdict(&defaults),
+            DecisionVerdict::AllowWithWarnings
+        );
+        assert_eq!(result_with(&[Severity::Error]).verdict(&defaults), DecisionVerdict::Block);
+        assert_eq!(result_with(&[Severity::Critical]).verdict(&defaults), DecisionVerdict::Block);
+    }
+
+    #[test]
+    fn test_verdict_thresholds_are_configurable() {
+        let result = result_with(&[Severity::Error, Severity::Warning, Severity::Warning]);
+
+        // Tolerated errors still surface as warnings
+        let lenient = VerdictThresholds::default().with_max_errors(1);
+        assert_eq!(result.verdict(&lenient), DecisionVerdict::AllowWithWarnings);
+
+        // Too many warnings block on their own
+        let strict = lenient.with_max_warnings(1);
+        assert_eq!(result.verdict(&strict), DecisionVerdict::Block);
+
+        // Critical issues are judged separately from errors
+        let critical = result_with(&[Severity::Critical]);
+        assert_eq!(critical.verdict(&lenient), DecisionVerdict::Block);
+        assert_eq!(
+            critical.verdict(&lenient.with_max_critical(1)),
+            DecisionVerdict::AllowWithWarnings
+        );
+    }
+
+    #[tokio::test]
+    async fn test_validate_and_emit_uses_engine_thresholds() {
+        let config = ConfigValue::Object(
+            [("debug".to_string(), ConfigValue::Boolean(true))].into_iter().collect(),
+        );
+        let sink = RecordingSink::default();
+
+        let (result, event) = ValidationEngine::new()
+            .validate_and_emit(&config, Environment::Production, "myapp", &sink)
+            .await;
+        assert_eq!(event.verdict, Some(result.verdict(&VerdictThresholds::default())));
+
+        let lenient = VerdictThresholds::default()
+            .with_max_critical(u32::MAX)
+            .with_max_errors(u32::MAX);
+        let (_, event) = ValidationEngine::new()
+            .with_verdict_thresholds(lenient)
+            .validate_and_emit(&config, Environment::Production, "myapp", &sink)
+            .await;
+        assert_ne!(event.verdict, Some(DecisionVerdict::Block));
+    }
+
+    #[tokio::test]
+    async fn test_validate_and_emit_records_matching_decision() {
+        let engine = ValidationEngine::new();
+        let config = ConfigValue::Object(
+            [
+                ("debug".to_string(), ConfigValue::Boolean(true)),
+                ("log_level".to_string(), ConfigValue::String("debug".to_string())),
+            ]
+            .into_iter()
+            .collect(),
+        );
+        let sink = RecordingSink::default();
+
+        let (result, event) = engine
+            .validate_and_emit(&config, Environment::Production, "myapp", &sink)
+            .await;
+
+        {
+            let recorded = sink.events.lock().unwrap();
+            assert_eq!(recorded.len(), 1);
+            assert_eq!(recorded[0].event_id, event.event_id);
+        }
+
+        assert_eq!(event.decision_type, DecisionType::ConfigValidationResult);
+        assert_eq!(event.outputs.is_valid, result.is_valid);
+        assert_eq!(event.outputs.issues.len(), result.findings.len());
+        assert_eq!(event.outputs.rules_applied, result.rules_applied);
+        assert_eq!(event.confidence, result.confidence);
+        let performance = event.performance.as_ref().unwrap();
+        assert_eq!(performance.duration_ms, result.duration_ms);
+        assert_eq!(performance.rules_evaluated as usize, result.rules_evaluated);
+        assert_eq!(performance.coverage, Some(result.coverage));
+        assert_eq!(
+            performance.latency_budget_utilization,
+            Some(result.duration_ms as f64 / MAX_LATENCY_MS as f64)
+        );
+        let tokens = performance.estimated_tokens.unwrap();
+        assert!(tokens > 0);
+        assert_eq!(performance.token_budget_utilization, Some(tokens as f64 / MAX_TOKENS as f64));
+
+        // Same inputs hash to the same value
+        let (_, again) = engine
+            .validate_and_emit(&config, Environment::Production, "myapp", &sink)
+            .await;
+        assert_eq!(again.inputs_hash, event.inputs_hash);
+    }
+
+    #[tokio::test]
+    async fn test_seeded_ids_and_mock_clock_reproduce_decisions() {
+        use crate::clock::MockClock;
+        use crate::ids::SeededIds;
+
+        let config = ConfigValue::Object(
+            [
+                ("debug".to_string(), ConfigValue::Boolean(true)),
+                ("log_level".to_string(), ConfigValue::String("debug".to_string())),
+            ]
+            .into_iter()
+            .collect(),
+        );
+        let at = chrono::DateTime::parse_from_rfc3339("2024-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+        let run = || async {
+            let engine = ValidationEngine::new()
+                .with_clock(Arc::new(MockClock::new(at)))
+                .with_id_generator(Arc::new(SeededIds::new(7)));
+            let sink = RecordingSink::default();
+            let (_, event) = engine
+                .validate_and_emit(&config, Environment::Production, "myapp", &sink)
+                .await;
+            serde_json::to_vec(&event).unwrap()
+        };
+
+        let first = run().await;
+        assert_eq!(first, run().await);
+
+        // The default generator does not repeat itself
+        let engine = ValidationEngine::new();
+        let sink = RecordingSink::default();
+        let (_, a) = engine
+            .validate_and_emit(&config, Environment::Production, "myapp", &sink)
+            .await;
+        let (_, b) = engine
+            .validate_and_emit(&config, Environment::Production, "myapp", &sink)
+            .await;
+        assert_ne!(a.event_id, b.event_id);
+    }
+
+    #[tokio::test]
+    async fn test_deduplicated_keeps_most_severe_finding() {
+        /// Reports the same field repeatedly
+        struct RepeatingRule;
+
+        #[async_trait::async_trait]
+        impl Rule for RepeatingRule {
+            fn id(&self) -> &str {
+                "repeating"
+            }
+            fn name(&self) -> &str {
+                "repeating"
+            }
+            fn description(&self) -> &str {
+                "Repeated findings"
+            }
+            fn category(&self) -> RuleCategory {
+                RuleCategory::Required
+            }
+            async fn evaluate(
+                &self,
+                _value: &ConfigValue,
+                _path: &str,
+                _context: &RuleContext,
+            ) -> Vec<ValidationFinding> {
+                let finding = |severity, message: &str, code: &str| {
+                    ValidationFinding::new(
+                        "repeating",
+                        RuleCategory::Required,
+                        severity,
+                        message,
+                        "db.host",
+                    )
+                    .with_context(serde_json::json!({ "code": code }))
+                };
+                vec![
+                    finding(Severity::Warning, "first", "MISSING"),
+                    finding(Severity::Info, "other code", "EMPTY"),
+                    finding(Severity::Error, "second", "MISSING"),
+                    finding(Severity::Error, "third", "MISSING"),
+                ]
+            }
+        }
+
+        let mut engine = ValidationEngine::empty();
+        engine.register(Arc::new(RepeatingRule));
+        let config = ConfigValue::Object(HashMap::new());
+
+        let result = engine.validate(&config, Environment::Staging, "test").await;
+        assert_eq!(result.findings.len(), 4);
+        assert_eq!(result.findings[0].fingerprint(), "repeating|db.host|MISSING");
+        assert_eq!(result.findings[0].fingerprint(), result.findings[2].fingerprint());
+
+        let deduplicated = result.clone().deduplicated();
+        let kept: Vec<_> = deduplicated
+            .findings
+            .iter()
+            .map(|f| (f.message.as_str(), f.severity))
+            .collect();
+        assert_eq!(kept, vec![("second", Severity::Error), ("other code", Severity::Info)]);
+        assert_eq!(deduplicated.is_valid, result.is_valid);
+        let summary = &deduplicated.category_summary[&RuleCategory::Required];
+        assert_eq!((summary.findings_count, summary.blocking_count), (2, 1));
+
+        // A finding without a code falls back to its rule id
+        let plain = ValidationFinding::new(
+            "required_fields",
+            RuleCategory::Required,
+            Severity::Error,
+            "missing",
+            "name",
+        );
+        assert_eq!(plain.code(), "required_fields");
+        assert_eq!(plain.fingerprint(), "required_fields|name|required_fields");
+    }
+
+    #[tokio::test]
+    async fn test_severity_override_recomputes_blocking() {
+        /// Reports one warning and one error
+        struct MixedRule;
+
+        #[async_trait::async_trait]
+        impl Rule for MixedRule {
+            fn id(&self) -> &str {
+                "mixed"
+            }
+            fn name(&self) -> &str {
+                "mixed"
+            }
+            fn description(&self) -> &str {
+                "A warning and an error"
+            }
+            fn category(&self) -> RuleCategory {
+                RuleCategory::Deprecated
+            }
+            async fn evaluate(
+                &self,
+                _value: &ConfigValue,
+                _path: &str,
+                _context: &RuleContext,
+            ) -> Vec<ValidationFinding> {
+                [(Severity::Warning, "legacy"), (Severity::Error, "removed")]
+                    .into_iter()
+                    .map(|(severity, field)| {
+                        ValidationFinding::new(
+                            "mixed",
+                            RuleCategory::Deprecated,
+                            severity,
+                            "deprecated",
+                            field,
+                        )
+                    })
+                    .collect()
+            }
+        }
+
+        let config = ConfigValue::Object(HashMap::new());
+        let mut engine = ValidationEngine::empty();
+        engine.register(Arc::new(MixedRule));
+
+        let result = engine.validate(&config, Environment::Production, "test").await;
+        assert!(!result.is_valid);
+        assert_eq!(result.category_summary[&RuleCategory::Deprecated].blocking_count, 1);
+
+        engine.with_severity_override("mixed", Severity::Error);
+        let result = engine.validate(&config, Environment::Production, "test").await;
+        assert!(result.findings.iter().all(|f| f.severity == Severity::Error));
+        assert_eq!(result.category_summary[&RuleCategory::Deprecated].blocking_count, 2);
+
+        engine.with_severity_override("mixed", Severity::Warning);
+        let result = engine.validate(&config, Environment::Development, "test").await;
+        assert!(result.is_valid);
+        assert_eq!(result.findings.len(), 2);
+        let summary = &result.category_summary[&RuleCategory::Deprecated];
+        assert_eq!((summary.findings_count, summary.blocking_count), (2, 0));
+
+        // Overrides for other rules leave this one alone
+        let mut engine = ValidationEngine::empty();
+        engine.register(Arc::new(MixedRule));
+        engine.with_severity_override("other", Severity::Info);
+        let result = engine.validate(&config, Environment::Production, "test").await;
+        assert!(!result.is_valid);
+    }
+
+    #[tokio::test]
+    async fn test_validate_across_environments_checks_must_differ() {
+        use crate::contracts::{ConfigSchema, EnvironmentRule, FieldRule, FieldType};
+
+        let schema = ConfigSchema::new("app/service", "Service", "1.0.0")
+            .with_field("db_password", FieldRule::new(FieldType::String))
+            .with_environment_rule(EnvironmentRule::must_differ(
+                "distinct-db-password",
+                vec!["production".to_string()],
+                vec!["staging".to_string()],
+                vec!["db_password".to_string()],
+            ));
+        let engine = ValidationEngine::new().with_schema_environment_rules(&schema);
+        let config = |password: &str| {
+            ConfigValue::Object(HashMap::from([(
+                "db_password".to_string(),
+                ConfigValue::String(password.to_string()),
+            )]))
+        };
+
+        let configs = HashMap::from([
+            (Environment::Staging, config("hunter2")),
+            (Environment::Production, config("hunter2")),
+        ]);
+        let result = engine.validate_across_environments(&configs, "app/service").await;
+        assert!(!result.is_valid);
+        assert_eq!(result.environment, Environment::Production);
+        assert_eq!(result.findings.len(), 1);
+        assert_eq!(result.findings[0].code(), rules::environment::VALUE_NOT_DIFFERENTIATED);
+        assert!(result.findings[0].message.contains("production"));
+        assert!(result.findings[0].message.contains("staging"));
+
+        let configs = HashMap::from([
+            (Environment::Staging, config("hunter2")),
+            (Environment::Production, config("s3cret")),
+        ]);
+        let result = engine.validate_across_environments(&configs, "app/service").await;
+        assert!(result.is_valid);
+        assert!(result.findings.is_empty());
+    }
+
+    #[test]
+    fn test_explain_reports_applicability_without_evaluating() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        /// Applies only to production and counts its evaluations
+        struct ProductionOnlyRule {
+            evaluations: AtomicUsize,
+        }
+
+        #[async_trait::async_trait]
+        impl Rule for ProductionOnlyRule {
+            fn id(&self) -> &str {
+                "production_only"
+            }
+            fn name(&self) -> &str {
+                "Production Only"
+            }
+            fn description(&self) -> &str {
+                "Runs in production"
+            }
+            fn category(&self) -> RuleCategory {
+                RuleCategory::Environment
+            }
+            fn is_applicable(&self, context: &RuleContext) -> bool {
+                context.environment == Environment::Production
+            }
+            async fn evaluate(
+                &self,
+                _value: &ConfigValue,
+                _path: &str,
+                _context: &RuleContext,
+            ) -> Vec<ValidationFinding> {
+                self.evaluations.fetch_add(1, Ordering::SeqCst);
+                Vec::new()
+            }
+        }
+
+        let rule = Arc::new(ProductionOnlyRule {
+            evaluations: AtomicUsize::new(0),
+        });
+        let mut engine = ValidationEngine::new();
+        engine.register(rule.clone());
+        engine.with_severity_override("placeholder_check", Severity::Info);
+        let config = ConfigValue::Object(HashMap::new());
+
+        let explained = engine.explain(&config, Environment::Development, "app");
+        assert_eq!(explained.len(), engine.rules().len());
+        let last = explained.last().unwrap();
+        assert_eq!(last.rule_id, "production_only");
+        assert_eq!(last.category, RuleCategory::Environment);
+        assert!(!last.applicable);
+        assert_eq!(
+            last.reason.as_deref(),
+            Some("Not applicable to development configurations in namespace 'app'")
+        );
+        let placeholder = explained
+            .iter()
+            .find(|a| a.rule_id == "placeholder_check")
+            .unwrap();
+        assert!(placeholder.applicable);
+        assert_eq!(
+            placeholder.reason.as_deref(),
+            Some("Findings are reported as info by a severity override")
+        );
+        assert!(explained
+            .iter()
+            .filter(|a| a.rule_id != "placeholder_check" && a.rule_id != "production_only")
+            .all(|a| a.applicable && a.reason.is_none()));
+
+        let explained = engine.explain(&config, Environment::Production, "app");
+        assert!(explained.last().unwrap().applicable);
+        assert_eq!(rule.evaluations.load(Ordering::SeqCst), 0);
+    }
 }
@@ -0,0 +1,353 @@
+//! Array and object content validation rules
+//!
+//! This module provides a rule enforcing `ValidationConstraint::UniqueItems`
+//! and `ValidationConstraint::NotEmpty` on arrays and objects. Empty strings
+//! and other scalars are left to the length rules.
+//!
+//! Items are compared structurally with [`items_equal`]: values of different
+//! types never match, and `NaN` is never equal to anything, itself included.
+
+use async_trait::async_trait;
+use std::collections::HashMap;
+
+use super::{Rule, RuleCategory, RuleContext, Severity, ValidationFinding};
+use crate::contracts::{ConfigSchema, FieldRule, ValidationConstraint};
+use crate::ConfigValue;
+
+/// Code carried in the context of findings for arrays with repeated items
+pub const DUPLICATE_ITEMS: &str = "DUPLICATE_ITEMS";
+
+/// Code carried in the context of findings for empty arrays and objects
+pub const EMPTY_COLLECTION: &str = "EMPTY_COLLECTION";
+
+/// Whether two values are structurally equal
+///
+/// Arrays match item by item and objects key by key. Integers and floats are
+/// different types, floats compare with `==` so `NaN` never matches, and
+/// secrets never match because their ciphertexts cannot be compared.
+pub fn items_equal(a: &ConfigValue, b: &ConfigValue) -> bool {
+    match (a, b) {
+        (ConfigValue::String(sa), ConfigValue::String(sb)) => sa == sb,
+        (ConfigValue::Integer(ia), ConfigValue::Integer(ib)) => ia == ib,
+        (ConfigValue::Float(fa), ConfigValue::Float(fb)) => fa == fb,
+        (ConfigValue::Boolean(ba), ConfigValue::Boolean(bb)) => ba == bb,
+        (ConfigValue::Array(xa), ConfigValue::Array(xb)) => {
+            xa.len() == xb.len() && xa.iter().zip(xb).all(|(x, y)| items_equal(x, y))
+        }
+        (ConfigValue::Object(ma), ConfigValue::Object(mb)) => {
+            ma.len() == mb.len()
+                && ma
+                    .iter()
+                    .all(|(key, x)| mb.get(key).is_some_and(|y| items_equal(x, y)))
+        }
+        _ => false,
+    }
+}
+
+/// The first item that repeats an earlier one, as `(index, earlier index)`
+///
+/// Items are compared pairwise, since floats cannot be hashed consistently
+/// with [`items_equal`].
+pub fn first_duplicate(items: &[ConfigValue]) -> Option<(usize, usize)> {
+    items.iter().enumerate().skip(1).find_map(|(index, item)| {
+        items[..index]
+            .iter()
+            .position(|earlier| items_equal(earlier, item))
+            .map(|earlier| (index, earlier))
+    })
+}
+
+/// A collection constraint enforced on a field
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CollectionCheck {
+    NotEmpty,
+    UniqueItems,
+}
+
+/// Rule for validating that arrays and objects are non-empty and that array
+/// items are unique
+pub struct CollectionRule {
+    id: String,
+    name: String,
+    /// Field paths and their checks, in the order they were added
+    checks: Vec<(String, CollectionCheck)>,
+    severity: Severity,
+}
+
+impl CollectionRule {
+    /// Create a collection rule with no constraints
+    pub fn new() -> Self {
+        Self {
+            id: "collection_check".to_string(),
+            name: "Collection Validation".to_string(),
+            checks: Vec::new(),
+            severity: Severity::Error,
+        }
+    }
+
+    /// Create a rule enforcing every unique-items and not-empty constraint in
+    /// a schema
+    ///
+    /// Nested fields are addressed by dotted path; fields are visited in path
+    /// order so the findings are too.
+    pub fn from_schema(schema: &ConfigSchema) -> Self {
+        fn walk(fields: &HashMap<String, FieldRule>, prefix: &str, rule: &mut CollectionRule) {
+            let mut keys: Vec<&String> = fields.keys().collect();
+            keys.sort();
+            for key in keys {
+                let field = &fields[key];
+                let path = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", prefix, key)
+                };
+                for constraint in &field.constraints {
+                    rule.add_constraint(&path, constraint);
+                }
+                walk(&field.nested_fields, &path, rule);
+            }
+        }
+
+        let mut rule = Self::new();
+        walk(&schema.fields, "", &mut rule);
+        rule
+    }
+
+    /// Enforce a constraint on a field; constraints other than
+    /// `ValidationConstraint::UniqueItems` and `ValidationConstraint::NotEmpty`
+    /// are ignored
+    pub fn with_constraint(
+        mut self,
+        field_path: impl Into<String>,
+        constraint: &ValidationConstraint,
+    ) -> Self {
+        self.add_constraint(&field_path.into(), constraint);
+        self
+    }
+
+    /// Set the severity level
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    /// Number of constraints enforced
+    pub fn len(&self) -> usize {
+        self.checks.len()
+    }
+
+    /// Whether the rule has no constraints
+    pub fn is_empty(&self) -> bool {
+        self.checks.is_empty()
+    }
+
+    fn add_constraint(&mut self, field_path: &str, constraint: &ValidationConstraint) {
+        let check = match constraint {
+            ValidationConstraint::NotEmpty => CollectionCheck::NotEmpty,
+            ValidationConstraint::UniqueItems => CollectionCheck::UniqueItems,
+            _ => return,
+        };
+        self.checks.push((field_path.to_string(), check));
+    }
+
+    fn get_value_at_path<'a>(&self, value: &'a ConfigValue, path: &str) -> Option<&'a ConfigValue> {
+        path.split('.').try_fold(value, |current, key| match current {
+            ConfigValue::Object(map) => map.get(key),
+            _ => None,
+        })
+    }
+
+    fn empty_finding(&self, full_path: &str, kind: &str) -> ValidationFinding {
+        ValidationFinding::new(
+            &self.id,
+            RuleCategory::Bounds,
+            self.severity,
+            format!("The {} must not be empty", kind),
+            full_path,
+        )
+        .with_expected(format!("a non-empty {}", kind))
+        .with_actual(format!("an empty {}", kind))
+        .with_suggestion(format!("Add at least one entry to the {} or remove the field", kind))
+        .with_context(serde_json::json!({ "code": EMPTY_COLLECTION }))
+    }
+}
+
+impl Default for CollectionRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Rule for CollectionRule {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn description(&self) -> &str {
+        "Validates that arrays and objects are not empty and array items are unique"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Bounds
+    }
+
+    fn default_severity(&self) -> Severity {
+        self.severity
+    }
+
+    async fn evaluate(
+        &self,
+        value: &ConfigValue,
+        path: &str,
+        _context: &RuleContext,
+    ) -> Vec<ValidationFinding> {
+        let mut findings = Vec::new();
+
+        for (field_path, check) in &self.checks {
+            // Missing fields and scalars are left to the required and type
+            // rules
+            let Some(field_value) = self.get_value_at_path(value, field_path) else {
+                continue;
+            };
+            let full_path = if path.is_empty() {
+                field_path.clone()
+            } else {
+                format!("{}.{}", path, field_path)
+            };
+
+            match (check, field_value) {
+                (CollectionCheck::NotEmpty, ConfigValue::Array(items)) if items.is_empty() => {
+                    findings.push(self.empty_finding(&full_path, "array"));
+                }
+                (CollectionCheck::NotEmpty, ConfigValue::Object(map)) if map.is_empty() => {
+                    findings.push(self.empty_finding(&full_path, "object"));
+                }
+                (CollectionCheck::UniqueItems, ConfigValue::Array(items)) => {
+                    if let Some((index, earlier)) = first_duplicate(items) {
+                        findings.push(
+                            ValidationFinding::new(
+                                &self.id,
+                                RuleCategory::Bounds,
+                                self.severity,
+                                format!(
+                                    "Item {} duplicates item {}; items must be unique",
+                                    index, earlier
+                                ),
+                                format!("{}[{}]", full_path, index),
+                            )
+                            .with_expected("unique items")
+                            .with_actual(format!("item {} repeated at {}", earlier, index))
+                            .with_suggestion("Remove the repeated item")
+                            .with_context(serde_json::json!({
+                                "code": DUPLICATE_ITEMS,
+                                "index": index,
+                                "duplicate_of": earlier,
+                            })),
+                        );
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        findings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Environment;
+
+    fn object(pairs: Vec<(&str, ConfigValue)>) -> ConfigValue {
+        ConfigValue::Object(pairs.into_iter().map(|(k, v)| (k.to_string(), v)).collect())
+    }
+
+    fn string(s: &str) -> ConfigValue {
+        ConfigValue::String(s.to_string())
+    }
+
+    #[test]
+    fn test_first_duplicate_is_structural() {
+        assert_eq!(first_duplicate(&[string("a"), string("b"), string("c")]), None);
+        assert_eq!(
+            first_duplicate(&[string("a"), string("b"), string("c"), string("b"), string("a")]),
+            Some((3, 1))
+        );
+
+        // Objects match regardless of key order; integers and floats differ
+        let ab = object(vec![("a", ConfigValue::Integer(1)), ("b", ConfigValue::Integer(2))]);
+        let ba = object(vec![("b", ConfigValue::Integer(2)), ("a", ConfigValue::Integer(1))]);
+        assert_eq!(first_duplicate(&[ab, ba]), Some((1, 0)));
+        assert_eq!(
+            first_duplicate(&[ConfigValue::Integer(1), ConfigValue::Float(1.0)]),
+            None
+        );
+        assert_eq!(
+            first_duplicate(&[
+                ConfigValue::Array(vec![string("x")]),
+                ConfigValue::Array(vec![string("x")]),
+            ]),
+            Some((1, 0))
+        );
+    }
+
+    #[test]
+    fn test_nan_is_never_a_duplicate() {
+        let nan = ConfigValue::Float(f64::NAN);
+        assert!(!items_equal(&nan, &nan));
+        assert_eq!(first_duplicate(&[nan.clone(), nan.clone(), ConfigValue::Float(0.5)]), None);
+        assert_eq!(
+            first_duplicate(&[nan, ConfigValue::Float(0.5), ConfigValue::Float(0.5)]),
+            Some((2, 1))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_collection_rule_reports_duplicates_and_empty_collections() {
+        let mut tags = FieldRule::new(crate::contracts::FieldType::Array);
+        tags.constraints.push(ValidationConstraint::UniqueItems);
+        tags.constraints.push(ValidationConstraint::NotEmpty);
+        let mut labels = FieldRule::new(crate::contracts::FieldType::Object);
+        labels.constraints.push(ValidationConstraint::NotEmpty);
+        let mut name = FieldRule::new(crate::contracts::FieldType::String);
+        name.constraints.push(ValidationConstraint::NotEmpty);
+        let schema = ConfigSchema::new("app/service", "Service", "1.0.0")
+            .with_field("tags", tags)
+            .with_field("labels", labels)
+            .with_field("name", name);
+        let rule = CollectionRule::from_schema(&schema);
+        assert_eq!(rule.len(), 4);
+
+        let context = RuleContext::new(Environment::Development, "app/service");
+        let config = object(vec![
+            ("tags", ConfigValue::Array(vec![string("web"), string("api"), string("edge"), string("api")])),
+            ("labels", object(vec![])),
+            ("name", string("")),
+        ]);
+        let findings = rule.evaluate(&config, "", &context).await;
+        let summary: Vec<(&str, &str)> = findings
+            .iter()
+            .map(|f| (f.field_path.as_str(), f.context.as_ref().unwrap()["code"].as_str().unwrap()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![("labels", EMPTY_COLLECTION), ("tags[3]", DUPLICATE_ITEMS)]
+        );
+        assert_eq!(findings[1].context.as_ref().unwrap()["duplicate_of"], 1);
+
+        let config = object(vec![
+            ("tags", ConfigValue::Array(vec![])),
+            ("labels", object(vec![("team", string("core"))])),
+        ]);
+        let findings = rule.evaluate(&config, "", &context).await;
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].field_path, "tags");
+        assert_eq!(findings[0].context.as_ref().unwrap()["code"], EMPTY_COLLECTION);
+    }
+}
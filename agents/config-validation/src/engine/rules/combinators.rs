@@ -0,0 +1,330 @@
+//! Boolean combinators over validation rules
+//!
+//! This module provides rules built from other rules, so related custom
+//! rules can be registered as one:
+//!
+//! - [`AndRule`] reports every finding of every child.
+//! - [`OrRule`] reports findings only when no child passed, i.e. when every
+//!   child produced at least one finding.
+//!
+//! Children run one after another in the order given, and only those
+//! applicable to the context take part. `OrRule` stops at the first child
+//! that passes, since its output is then empty whatever the rest report.
+//! Findings keep the rule ids of the children that produced them.
+
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use super::reference::ReferenceResolver;
+use super::{Rule, RuleCategory, RuleContext, Severity, ValidationFinding};
+use crate::ConfigValue;
+
+/// Category of a combinator: its first child's, or `Required` with none
+fn first_category(children: &[Arc<dyn Rule>]) -> RuleCategory {
+    children
+        .first()
+        .map_or(RuleCategory::Required, |child| child.category())
+}
+
+/// Most severe default severity of the children
+fn max_severity(children: &[Arc<dyn Rule>]) -> Severity {
+    children
+        .iter()
+        .map(|child| child.default_severity())
+        .max()
+        .unwrap_or_default()
+}
+
+/// Evaluate the applicable children in order, with `resolver` if given
+///
+/// With `stop_on_pass`, returns no findings as soon as a child passes;
+/// otherwise returns every child's findings.
+async fn evaluate_children(
+    children: &[Arc<dyn Rule>],
+    value: &ConfigValue,
+    path: &str,
+    context: &RuleContext,
+    resolver: Option<&dyn ReferenceResolver>,
+    stop_on_pass: bool,
+) -> Vec<ValidationFinding> {
+    let mut findings = Vec::new();
+    for child in children.iter().filter(|child| child.is_applicable(context)) {
+        let child_findings = match resolver {
+            Some(resolver) => child.evaluate_with_resolver(value, path, context, resolver).await,
+            None => child.evaluate(value, path, context).await,
+        };
+        if stop_on_pass && child_findings.is_empty() {
+            return Vec::new();
+        }
+        findings.extend(child_findings);
+    }
+    findings
+}
+
+/// Rule reporting the findings of all its children
+pub struct AndRule {
+    id: String,
+    name: String,
+    children: Vec<Arc<dyn Rule>>,
+}
+
+impl AndRule {
+    /// Create a rule combining `children`
+    pub fn new(id: impl Into<String>, children: Vec<Arc<dyn Rule>>) -> Self {
+        let name = format!(
+            "All of: {}",
+            children.iter().map(|c| c.name()).collect::<Vec<_>>().join(", ")
+        );
+        Self {
+            id: id.into(),
+            name,
+            children,
+        }
+    }
+
+    /// The combined rules, in evaluation order
+    pub fn children(&self) -> &[Arc<dyn Rule>] {
+        &self.children
+    }
+}
+
+#[async_trait]
+impl Rule for AndRule {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn description(&self) -> &str {
+        "Reports the findings of every combined rule"
+    }
+
+    fn category(&self) -> RuleCategory {
+        first_category(&self.children)
+    }
+
+    fn default_severity(&self) -> Severity {
+        max_severity(&self.children)
+    }
+
+    fn is_applicable(&self, context: &RuleContext) -> bool {
+        self.children.iter().any(|child| child.is_applicable(context))
+    }
+
+    async fn evaluate(
+        &self,
+        value: &ConfigValue,
+        path: &str,
+        context: &RuleContext,
+    ) -> Vec<ValidationFinding> {
+        evaluate_children(&self.children, value, path, context, None, false).await
+    }
+
+    async fn evaluate_with_resolver(
+        &self,
+        value: &ConfigValue,
+        path: &str,
+        context: &RuleContext,
+        resolver: &dyn ReferenceResolver,
+    ) -> Vec<ValidationFinding> {
+        evaluate_children(&self.children, value, path, context, Some(resolver), false).await
+    }
+}
+
+/// Rule reporting findings only when none of its children passed
+///
+/// The findings reported are those of every child. With no applicable
+/// children there is nothing to fail, so nothing is reported.
+pub struct OrRule {
+    id: String,
+    name: String,
+    children: Vec<Arc<dyn Rule>>,
+}
+
+impl OrRule {
+    /// Create a rule combining `children`
+    pub fn new(id: impl Into<String>, children: Vec<Arc<dyn Rule>>) -> Self {
+        let name = format!(
+            "Any of: {}",
+            children.iter().map(|c| c.name()).collect::<Vec<_>>().join(", ")
+        );
+        Self {
+            id: id.into(),
+            name,
+            children,
+        }
+    }
+
+    /// The combined rules, in evaluation order
+    pub fn children(&self) -> &[Arc<dyn Rule>] {
+        &self.children
+    }
+}
+
+#[async_trait]
+impl Rule for OrRule {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn description(&self) -> &str {
+        "Reports findings only when every combined rule fails"
+    }
+
+    fn category(&self) -> RuleCategory {
+        first_category(&self.children)
+    }
+
+    fn default_severity(&self) -> Severity {
+        max_severity(&self.children)
+    }
+
+    fn is_applicable(&self, context: &RuleContext) -> bool {
+        self.children.iter().any(|child| child.is_applicable(context))
+    }
+
+    async fn evaluate(
+        &self,
+        value: &ConfigValue,
+        path: &str,
+        context: &RuleContext,
+    ) -> Vec<ValidationFinding> {
+        evaluate_children(&self.children, value, path, context, None, true).await
+    }
+
+    async fn evaluate_with_resolver(
+        &self,
+        value: &ConfigValue,
+        path: &str,
+        context: &RuleContext,
+        resolver: &dyn ReferenceResolver,
+    ) -> Vec<ValidationFinding> {
+        evaluate_children(&self.children, value, path, context, Some(resolver), true).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Environment;
+    use std::collections::HashMap;
+
+    /// Reports a finding when the field it watches is missing
+    struct MissingRule {
+        id: &'static str,
+        field: &'static str,
+    }
+
+    #[async_trait]
+    impl Rule for MissingRule {
+        fn id(&self) -> &str {
+            self.id
+        }
+        fn name(&self) -> &str {
+            self.id
+        }
+        fn description(&self) -> &str {
+            "Reports a missing field"
+        }
+        fn category(&self) -> RuleCategory {
+            RuleCategory::Required
+        }
+        async fn evaluate(
+            &self,
+            value: &ConfigValue,
+            _path: &str,
+            _context: &RuleContext,
+        ) -> Vec<ValidationFinding> {
+            match value {
+                ConfigValue::Object(map) if map.contains_key(self.field) => Vec::new(),
+                _ => vec![ValidationFinding::new(
+                    self.id,
+                    RuleCategory::Required,
+                    Severity::Error,
+                    format!("'{}' is missing", self.field),
+                    self.field,
+                )],
+            }
+        }
+    }
+
+    fn missing(id: &'static str, field: &'static str) -> Arc<dyn Rule> {
+        Arc::new(MissingRule { id, field })
+    }
+
+    fn config(fields: &[&str]) -> ConfigValue {
+        ConfigValue::Object(
+            fields
+                .iter()
+                .map(|f| (f.to_string(), ConfigValue::Boolean(true)))
+                .collect::<HashMap<_, _>>(),
+        )
+    }
+
+    fn rule_ids(findings: &[ValidationFinding]) -> Vec<&str> {
+        findings.iter().map(|f| f.rule_id.as_str()).collect()
+    }
+
+    #[tokio::test]
+    async fn test_and_rule_reports_the_union_in_child_order() {
+        let context = RuleContext::new(Environment::Production, "app");
+        let rule = AndRule::new("and", vec![missing("a", "host"), missing("b", "port")]);
+        assert_eq!(rule.category(), RuleCategory::Required);
+
+        let findings = rule.evaluate(&config(&[]), "", &context).await;
+        assert_eq!(rule_ids(&findings), vec!["a", "b"]);
+
+        // A passing child contributes nothing, wherever it is
+        let findings = rule.evaluate(&config(&["host"]), "", &context).await;
+        assert_eq!(rule_ids(&findings), vec!["b"]);
+        let findings = rule.evaluate(&config(&["port"]), "", &context).await;
+        assert_eq!(rule_ids(&findings), vec!["a"]);
+        assert!(rule.evaluate(&config(&["host", "port"]), "", &context).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_or_rule_reports_only_when_every_child_fails() {
+        let context = RuleContext::new(Environment::Production, "app");
+        let rule = OrRule::new("or", vec![missing("a", "host"), missing("b", "socket")]);
+
+        let findings = rule.evaluate(&config(&[]), "", &context).await;
+        assert_eq!(rule_ids(&findings), vec!["a", "b"]);
+
+        // Whichever child passes, and whether it runs first or last, the
+        // output is the same: no findings
+        for children in [
+            vec![missing("a", "host"), missing("b", "socket")],
+            vec![missing("b", "socket"), missing("a", "host")],
+        ] {
+            let rule = OrRule::new("or", children);
+            assert!(rule.evaluate(&config(&["host"]), "", &context).await.is_empty());
+            assert!(rule.evaluate(&config(&["socket"]), "", &context).await.is_empty());
+        }
+
+        // Nothing to fail
+        let empty = OrRule::new("or", Vec::new());
+        assert!(empty.evaluate(&config(&[]), "", &context).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_combinators_nest() {
+        let context = RuleContext::new(Environment::Staging, "app");
+        let either_address: Arc<dyn Rule> = Arc::new(OrRule::new(
+            "address",
+            vec![missing("host", "host"), missing("socket", "socket")],
+        ));
+        let rule = AndRule::new("server", vec![either_address, missing("port", "port")]);
+
+        let findings = rule.evaluate(&config(&["socket"]), "", &context).await;
+        assert_eq!(rule_ids(&findings), vec!["port"]);
+        let findings = rule.evaluate(&config(&["port"]), "", &context).await;
+        assert_eq!(rule_ids(&findings), vec!["host", "socket"]);
+    }
+}
@@ -0,0 +1,552 @@
+//! Cross-field conditional validation rules
+//!
+//! This module provides a rule enforcing `ValidationConstraint::Conditional`.
+//! A condition has the form `field == value`, where `field` is a dotted path
+//! from the root of the configuration. When the field equals the value the
+//! `then` branch is applied to the constrained field, otherwise the `else`
+//! branch (if any) is. An absent field makes the condition false.
+//!
+//! Branches may themselves be conditional; evaluation follows them in a loop
+//! rather than recursing, and reports the conditions taken on the way.
+
+use async_trait::async_trait;
+use regex::Regex;
+use std::collections::HashMap;
+
+use super::collection::first_duplicate;
+use super::{Rule, RuleCategory, RuleContext, Severity, ValidationFinding};
+use crate::contracts::{ConfigSchema, FieldRule, ValidationConstraint};
+use crate::ConfigValue;
+
+/// Code carried in the context of findings for failed branches
+pub const CONDITIONAL_FAILED: &str = "CONDITIONAL_FAILED";
+
+/// Code carried in the context of findings for conditions that do not parse
+pub const INVALID_CONDITION: &str = "INVALID_CONDITION";
+
+/// A conditional constraint on a field
+struct FieldConditional {
+    field_path: String,
+    constraint: ValidationConstraint,
+}
+
+/// A parsed `field == value` condition
+#[derive(Debug)]
+struct Condition {
+    field: String,
+    value: ConfigValue,
+}
+
+impl Condition {
+    /// Parse a condition, returning why it is not of the form `field == value`
+    fn parse(condition: &str) -> Result<Self, String> {
+        let (field, literal) = condition
+            .split_once("==")
+            .ok_or_else(|| "expected the form 'field == value'".to_string())?;
+        let field = field.trim();
+        let literal = literal.trim();
+        if field.is_empty() || literal.is_empty() || literal.contains("==") {
+            return Err("expected the form 'field == value'".to_string());
+        }
+        if field.split('.').any(|part| part.is_empty()) {
+            return Err(format!("'{}' is not a field path", field));
+        }
+        Ok(Self {
+            field: field.to_string(),
+            value: parse_literal(literal),
+        })
+    }
+
+    /// Whether the condition holds; an absent field makes it false
+    fn holds(&self, config: &ConfigValue) -> bool {
+        get_value_at_path(config, &self.field)
+            .map(|actual| values_equal(actual, &self.value))
+            .unwrap_or(false)
+    }
+}
+
+/// Parse the right-hand side of a condition
+///
+/// Quoted text is a string; `true`, `false` and numbers are typed; anything
+/// else is taken as an unquoted string.
+fn parse_literal(literal: &str) -> ConfigValue {
+    for quote in ['"', '\''] {
+        if literal.len() >= 2 && literal.starts_with(quote) && literal.ends_with(quote) {
+            return ConfigValue::String(literal[1..literal.len() - 1].to_string());
+        }
+    }
+    match literal {
+        "true" => ConfigValue::Boolean(true),
+        "false" => ConfigValue::Boolean(false),
+        _ => {
+            if let Ok(i) = literal.parse::<i64>() {
+                ConfigValue::Integer(i)
+            } else if let Ok(f) = literal.parse::<f64>() {
+                ConfigValue::Float(f)
+            } else {
+                ConfigValue::String(literal.to_string())
+            }
+        }
+    }
+}
+
+fn values_equal(a: &ConfigValue, b: &ConfigValue) -> bool {
+    match (a, b) {
+        (ConfigValue::String(sa), ConfigValue::String(sb)) => sa == sb,
+        (ConfigValue::Boolean(ba), ConfigValue::Boolean(bb)) => ba == bb,
+        (ConfigValue::Integer(ia), ConfigValue::Integer(ib)) => ia == ib,
+        (ConfigValue::Integer(i), ConfigValue::Float(f))
+        | (ConfigValue::Float(f), ConfigValue::Integer(i)) => (*i as f64 - f).abs() < f64::EPSILON,
+        (ConfigValue::Float(fa), ConfigValue::Float(fb)) => (fa - fb).abs() < f64::EPSILON,
+        _ => false,
+    }
+}
+
+fn get_value_at_path<'a>(value: &'a ConfigValue, path: &str) -> Option<&'a ConfigValue> {
+    path.split('.').try_fold(value, |current, key| match current {
+        ConfigValue::Object(map) => map.get(key),
+        _ => None,
+    })
+}
+
+fn as_number(value: &ConfigValue) -> Option<f64> {
+    match value {
+        ConfigValue::Integer(i) => Some(*i as f64),
+        ConfigValue::Float(f) => Some(*f),
+        _ => None,
+    }
+}
+
+fn length_of(value: &ConfigValue) -> Option<usize> {
+    match value {
+        ConfigValue::String(s) => Some(s.chars().count()),
+        ConfigValue::Array(items) => Some(items.len()),
+        _ => None,
+    }
+}
+
+/// Check a non-conditional constraint against a value
+///
+/// Returns `None` when the constraint does not apply to the value's type or
+/// cannot be checked here (`Custom`, `Reference`, `OneOf`, and patterns that
+/// do not compile); type mismatches are the type rule's concern.
+fn satisfies(constraint: &ValidationConstraint, value: &ConfigValue) -> Option<bool> {
+    use ValidationConstraint as C;
+
+    match constraint {
+        C::Min { value: min, inclusive } => {
+            as_number(value).map(|n| if *inclusive { n >= *min } else { n > *min })
+        }
+        C::Max { value: max, inclusive } => {
+            as_number(value).map(|n| if *inclusive { n <= *max } else { n < *max })
+        }
+        C::Range { min, max, inclusive } => as_number(value).map(|n| {
+            if *inclusive {
+                n >= *min && n <= *max
+            } else {
+                n > *min && n < *max
+            }
+        }),
+        C::MinLength { length } => length_of(value).map(|len| len >= *length),
+        C::MaxLength { length } => length_of(value).map(|len| len <= *length),
+        C::Length { length } => length_of(value).map(|len| len == *length),
+        C::Pattern { regex, .. } => match value {
+            ConfigValue::String(s) => Regex::new(regex).ok().map(|re| re.is_match(s)),
+            _ => None,
+        },
+        C::StartsWith { prefix } => match value {
+            ConfigValue::String(s) => Some(s.starts_with(prefix.as_str())),
+            _ => None,
+        },
+        C::EndsWith { suffix } => match value {
+            ConfigValue::String(s) => Some(s.ends_with(suffix.as_str())),
+            _ => None,
+        },
+        C::Contains { substring } => match value {
+            ConfigValue::String(s) => Some(s.contains(substring.as_str())),
+            _ => None,
+        },
+        C::NotEmpty => match value {
+            ConfigValue::String(s) => Some(!s.is_empty()),
+            ConfigValue::Array(items) => Some(!items.is_empty()),
+            ConfigValue::Object(map) => Some(!map.is_empty()),
+            _ => None,
+        },
+        C::UniqueItems => match value {
+            ConfigValue::Array(items) => Some(first_duplicate(items).is_none()),
+            _ => None,
+        },
+        C::Custom { .. } | C::Reference { .. } | C::OneOf { .. } | C::Conditional { .. } => None,
+    }
+}
+
+fn describe(value: &ConfigValue) -> String {
+    match value {
+        ConfigValue::String(s) => s.clone(),
+        ConfigValue::Integer(i) => i.to_string(),
+        ConfigValue::Float(f) => f.to_string(),
+        ConfigValue::Boolean(b) => b.to_string(),
+        ConfigValue::Array(items) => format!("array of {} items", items.len()),
+        ConfigValue::Object(map) => format!("object with {} fields", map.len()),
+        ConfigValue::Secret(_) => "<secret>".to_string(),
+    }
+}
+
+/// Rule for validating cross-field conditional constraints
+pub struct ConditionalRule {
+    id: String,
+    name: String,
+    /// Conditional constraints in the order they were added
+    conditionals: Vec<FieldConditional>,
+    severity: Severity,
+}
+
+impl ConditionalRule {
+    /// Create a conditional rule with no constraints
+    pub fn new() -> Self {
+        Self {
+            id: "conditional_check".to_string(),
+            name: "Conditional Validation".to_string(),
+            conditionals: Vec::new(),
+            severity: Severity::Error,
+        }
+    }
+
+    /// Create a rule enforcing every conditional constraint in a schema
+    ///
+    /// Nested fields are addressed by dotted path; fields are visited in path
+    /// order so the findings are too.
+    pub fn from_schema(schema: &ConfigSchema) -> Self {
+        fn walk(fields: &HashMap<String, FieldRule>, prefix: &str, rule: &mut ConditionalRule) {
+            let mut keys: Vec<&String> = fields.keys().collect();
+            keys.sort();
+            for key in keys {
+                let field = &fields[key];
+                let path = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", prefix, key)
+                };
+                for constraint in &field.constraints {
+                    rule.add_constraint(&path, constraint);
+                }
+                walk(&field.nested_fields, &path, rule);
+            }
+        }
+
+        let mut rule = Self::new();
+        walk(&schema.fields, "", &mut rule);
+        rule
+    }
+
+    /// Enforce a constraint on a field; constraints other than
+    /// `ValidationConstraint::Conditional` are ignored
+    pub fn with_constraint(
+        mut self,
+        field_path: impl Into<String>,
+        constraint: &ValidationConstraint,
+    ) -> Self {
+        self.add_constraint(&field_path.into(), constraint);
+        self
+    }
+
+    /// Set the severity level
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    /// Number of conditional constraints enforced
+    pub fn len(&self) -> usize {
+        self.conditionals.len()
+    }
+
+    /// Whether the rule has no conditional constraints
+    pub fn is_empty(&self) -> bool {
+        self.conditionals.is_empty()
+    }
+
+    fn add_constraint(&mut self, field_path: &str, constraint: &ValidationConstraint) {
+        if matches!(constraint, ValidationConstraint::Conditional { .. }) {
+            self.conditionals.push(FieldConditional {
+                field_path: field_path.to_string(),
+                constraint: constraint.clone(),
+            });
+        }
+    }
+
+    /// Follow the branches of one conditional constraint to the constraint
+    /// that applies, and check it
+    fn check(
+        &self,
+        conditional: &FieldConditional,
+        config: &ConfigValue,
+        full_path: &str,
+    ) -> Option<ValidationFinding> {
+        // Conditions taken on the way to the applied constraint, outermost first
+        let mut taken: Vec<(&str, &str)> = Vec::new();
+        let mut constraint = &conditional.constraint;
+
+        while let ValidationConstraint::Conditional {
+            condition,
+            then_constraint,
+            else_constraint,
+        } = constraint
+        {
+            let parsed = match Condition::parse(condition) {
+                Ok(parsed) => parsed,
+                Err(error) => {
+                    return Some(
+                        ValidationFinding::new(
+                            &self.id,
+                            RuleCategory::Bounds,
+                            Severity::Info,
+                            format!(
+                                "Condition '{}' was not evaluated: {}",
+                                condition, error
+                            ),
+                            full_path,
+                        )
+                        .with_suggestion("Write the condition as 'field == value'")
+                        .with_context(serde_json::json!({
+                            "code": INVALID_CONDITION,
+                            "condition": condition,
+                        })),
+                    );
+                }
+            };
+            if parsed.holds(config) {
+                taken.push((condition, "then"));
+                constraint = then_constraint;
+            } else {
+                taken.push((condition, "else"));
+                constraint = else_constraint.as_deref()?;
+            }
+        }
+
+        let value = get_value_at_path(config, &conditional.field_path)?;
+        if satisfies(constraint, value)? {
+            return None;
+        }
+
+        let (condition, branch) = *taken.last()?;
+        let expected = constraint.description();
+        let conditions: Vec<serde_json::Value> = taken
+            .iter()
+            .map(|(condition, branch)| {
+                serde_json::json!({ "condition": condition, "branch": branch })
+            })
+            .collect();
+        let when = if branch == "then" {
+            format!("'{}' holds", condition)
+        } else {
+            format!("'{}' does not hold", condition)
+        };
+        Some(
+            ValidationFinding::new(
+                &self.id,
+                RuleCategory::Bounds,
+                self.severity,
+                format!("Value {} when {}", expected, when),
+                full_path,
+            )
+            .with_expected(expected)
+            .with_actual(describe(value))
+            .with_context(serde_json::json!({
+                "code": CONDITIONAL_FAILED,
+                "condition": condition,
+                "branch": branch,
+                "conditions": conditions,
+            })),
+        )
+    }
+}
+
+impl Default for ConditionalRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Rule for ConditionalRule {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn description(&self) -> &str {
+        "Validates constraints that depend on the value of another field"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Bounds
+    }
+
+    fn default_severity(&self) -> Severity {
+        self.severity
+    }
+
+    async fn evaluate(
+        &self,
+        value: &ConfigValue,
+        path: &str,
+        _context: &RuleContext,
+    ) -> Vec<ValidationFinding> {
+        self.conditionals
+            .iter()
+            .filter_map(|conditional| {
+                let full_path = if path.is_empty() {
+                    conditional.field_path.clone()
+                } else {
+                    format!("{}.{}", path, conditional.field_path)
+                };
+                self.check(conditional, value, &full_path)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Environment;
+
+    fn conditional(
+        condition: &str,
+        then_constraint: ValidationConstraint,
+        else_constraint: Option<ValidationConstraint>,
+    ) -> ValidationConstraint {
+        ValidationConstraint::Conditional {
+            condition: condition.to_string(),
+            then_constraint: Box::new(then_constraint),
+            else_constraint: else_constraint.map(Box::new),
+        }
+    }
+
+    fn config(environment: Option<&str>, replicas: i64) -> ConfigValue {
+        let mut map = HashMap::new();
+        if let Some(environment) = environment {
+            map.insert(
+                "deploy".to_string(),
+                ConfigValue::Object(
+                    [("environment".to_string(), ConfigValue::String(environment.to_string()))]
+                        .into_iter()
+                        .collect(),
+                ),
+            );
+        }
+        map.insert("replicas".to_string(), ConfigValue::Integer(replicas));
+        ConfigValue::Object(map)
+    }
+
+    #[tokio::test]
+    async fn test_branch_follows_condition() {
+        let rule = ConditionalRule::new().with_constraint(
+            "replicas",
+            &conditional(
+                "deploy.environment == 'production'",
+                ValidationConstraint::min(3.0),
+                Some(ValidationConstraint::max(1.0)),
+            ),
+        );
+        let context = RuleContext::new(Environment::Production, "test");
+
+        assert!(rule.evaluate(&config(Some("production"), 3), "", &context).await.is_empty());
+        assert!(rule.evaluate(&config(Some("development"), 1), "", &context).await.is_empty());
+
+        let findings = rule.evaluate(&config(Some("production"), 1), "", &context).await;
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].field_path, "replicas");
+        assert_eq!(findings[0].actual.as_deref(), Some("1"));
+        let ctx = findings[0].context.as_ref().unwrap();
+        assert_eq!(ctx["code"], CONDITIONAL_FAILED);
+        assert_eq!(ctx["condition"], "deploy.environment == 'production'");
+        assert_eq!(ctx["branch"], "then");
+
+        let findings = rule.evaluate(&config(Some("development"), 2), "", &context).await;
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].context.as_ref().unwrap()["branch"], "else");
+    }
+
+    #[tokio::test]
+    async fn test_absent_field_makes_condition_false() {
+        let context = RuleContext::new(Environment::Production, "test");
+
+        let without_else = ConditionalRule::new().with_constraint(
+            "replicas",
+            &conditional("deploy.environment == production", ValidationConstraint::min(3.0), None),
+        );
+        assert!(without_else.evaluate(&config(None, 1), "", &context).await.is_empty());
+
+        let with_else = ConditionalRule::new().with_constraint(
+            "replicas",
+            &conditional(
+                "deploy.environment == production",
+                ValidationConstraint::min(3.0),
+                Some(ValidationConstraint::max(1.0)),
+            ),
+        );
+        let findings = with_else.evaluate(&config(None, 2), "", &context).await;
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].context.as_ref().unwrap()["branch"], "else");
+    }
+
+    #[tokio::test]
+    async fn test_nested_conditionals_report_the_path_taken() {
+        let inner = conditional(
+            "replicas == 5",
+            ValidationConstraint::max(4.0),
+            Some(ValidationConstraint::min(0.0)),
+        );
+        let outer = conditional("deploy.environment == staging", inner, None);
+        let rule = ConditionalRule::new().with_constraint("replicas", &outer);
+        let context = RuleContext::new(Environment::Staging, "test");
+
+        let findings = rule.evaluate(&config(Some("staging"), 5), "", &context).await;
+        assert_eq!(findings.len(), 1);
+        let ctx = findings[0].context.as_ref().unwrap();
+        assert_eq!(ctx["condition"], "replicas == 5");
+        assert_eq!(ctx["conditions"].as_array().unwrap().len(), 2);
+        assert_eq!(ctx["conditions"][0]["condition"], "deploy.environment == staging");
+
+        // Deep nesting is followed iteratively
+        let mut deep = ValidationConstraint::max(4.0);
+        for _ in 0..64 {
+            deep = conditional("replicas == 5", deep, None);
+        }
+        let rule = ConditionalRule::new().with_constraint("replicas", &deep);
+        let findings = rule.evaluate(&config(None, 5), "", &context).await;
+        assert_eq!(findings.len(), 1);
+        assert_eq!(
+            findings[0].context.as_ref().unwrap()["conditions"].as_array().unwrap().len(),
+            64
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unparseable_condition_is_reported_as_info() {
+        let rule = ConditionalRule::new().with_constraint(
+            "replicas",
+            &conditional("deploy.environment", ValidationConstraint::min(3.0), None),
+        );
+        let context = RuleContext::new(Environment::Production, "test");
+
+        let findings = rule.evaluate(&config(Some("production"), 1), "", &context).await;
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].severity, Severity::Info);
+        assert_eq!(findings[0].context.as_ref().unwrap()["code"], INVALID_CONDITION);
+    }
+
+    #[test]
+    fn test_parse_literal_types() {
+        assert!(matches!(parse_literal("true"), ConfigValue::Boolean(true)));
+        assert!(matches!(parse_literal("42"), ConfigValue::Integer(42)));
+        assert!(matches!(parse_literal("1.5"), ConfigValue::Float(_)));
+        assert!(matches!(parse_literal("'42'"), ConfigValue::String(s) if s == "42"));
+        assert!(matches!(parse_literal("prod"), ConfigValue::String(s) if s == "prod"));
+    }
+}
@@ -0,0 +1,304 @@
+//! Environment variable reference validation
+//!
+//! This module provides a rule reporting `${VAR}` references to environment
+//! variables that are not defined, so a configuration that would interpolate
+//! to an empty or literal value is caught before deployment.
+//!
+//! References with a default, `${VAR:-default}` or `${VAR-default}`, are
+//! never reported for `VAR`. Their default may itself contain references,
+//! which are checked only when `VAR` is undefined, since only then is the
+//! default used.
+//!
+//! Variables are a fixed set of names, either supplied or snapshotted from
+//! the process environment when the rule is created, so evaluation stays
+//! deterministic.
+
+use async_trait::async_trait;
+use std::collections::BTreeSet;
+
+use super::{Rule, RuleCategory, RuleContext, Severity, ValidationFinding};
+use crate::ConfigValue;
+
+/// Code carried in the context of findings for undefined variables
+pub const UNDEFINED_ENV_VAR: &str = "UNDEFINED_ENV_VAR";
+
+/// An environment variable reference found in a string
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EnvVarReference {
+    /// Reference as written, including any default
+    pub raw: String,
+    /// Variable name
+    pub name: String,
+    /// Value used when the variable is undefined, if any
+    pub default: Option<String>,
+}
+
+/// Extract the top-level `${VAR}` references from a string
+///
+/// References nested inside a default are part of that default. A `${` not
+/// followed by a valid name and a closing brace is not a reference.
+pub fn extract_env_var_references(value: &str) -> Vec<EnvVarReference> {
+    let mut references = Vec::new();
+    let mut rest = value;
+
+    while let Some(start) = rest.find("${") {
+        let after = &rest[start + 2..];
+        match parse_reference(after) {
+            Some((name, default, len)) => {
+                references.push(EnvVarReference {
+                    raw: rest[start..start + 2 + len].to_string(),
+                    name: name.to_string(),
+                    default: default.map(str::to_string),
+                });
+                rest = &after[len..];
+            }
+            None => rest = after,
+        }
+    }
+
+    references
+}
+
+/// Parse `NAME}`, `NAME:-default}` or `NAME-default}`, returning the name,
+/// the default and the length consumed including the closing brace
+fn parse_reference(s: &str) -> Option<(&str, Option<&str>, usize)> {
+    let name_len = s
+        .char_indices()
+        .find(|&(i, c)| !(c == '_' || c.is_ascii_alphabetic() || (i > 0 && c.is_ascii_digit())))
+        .map_or(s.len(), |(i, _)| i);
+    if name_len == 0 {
+        return None;
+    }
+    let name = &s[..name_len];
+    let rest = &s[name_len..];
+
+    if rest.starts_with('}') {
+        return Some((name, None, name_len + 1));
+    }
+    let separator = if rest.starts_with(":-") {
+        2
+    } else if rest.starts_with('-') {
+        1
+    } else {
+        return None;
+    };
+
+    // The default runs to the brace closing this reference
+    let default = &rest[separator..];
+    let mut depth = 0usize;
+    for (i, c) in default.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' if depth == 0 => {
+                return Some((name, Some(&default[..i]), name_len + separator + i + 1));
+            }
+            '}' => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Rule for detecting references to undefined environment variables
+pub struct EnvVarRule {
+    id: String,
+    name: String,
+    /// Names of the defined variables
+    defined: BTreeSet<String>,
+}
+
+impl EnvVarRule {
+    /// Create a rule treating only the given variables as defined
+    pub fn with_variables<I, S>(names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            id: "env_var_check".to_string(),
+            name: "Environment Variable Resolution".to_string(),
+            defined: names.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Create a rule treating the variables currently set in the process
+    /// environment as defined
+    pub fn from_process_env() -> Self {
+        Self::with_variables(std::env::vars_os().filter_map(|(name, _)| name.into_string().ok()))
+    }
+
+    /// Whether a variable is defined
+    pub fn is_defined(&self, name: &str) -> bool {
+        self.defined.contains(name)
+    }
+
+    /// Number of defined variables
+    pub fn len(&self) -> usize {
+        self.defined.len()
+    }
+
+    /// Whether no variables are defined
+    pub fn is_empty(&self) -> bool {
+        self.defined.is_empty()
+    }
+
+    /// Undefined variables referenced by a string, in order of appearance
+    fn undefined_references(&self, value: &str, undefined: &mut Vec<EnvVarReference>) {
+        for reference in extract_env_var_references(value) {
+            if self.is_defined(&reference.name) {
+                continue;
+            }
+            match &reference.default {
+                Some(default) => self.undefined_references(default, undefined),
+                None => undefined.push(reference),
+            }
+        }
+    }
+
+    fn check_value(&self, value: &ConfigValue, path: &str, findings: &mut Vec<ValidationFinding>) {
+        match value {
+            ConfigValue::String(s) => {
+                let mut undefined = Vec::new();
+                self.undefined_references(s, &mut undefined);
+                for reference in undefined {
+                    findings.push(self.finding(path, &reference));
+                }
+            }
+            ConfigValue::Array(items) => {
+                for (index, item) in items.iter().enumerate() {
+                    self.check_value(item, &format!("{}[{}]", path, index), findings);
+                }
+            }
+            ConfigValue::Object(map) => {
+                for (key, nested) in map {
+                    let nested_path = if path.is_empty() {
+                        key.clone()
+                    } else {
+                        format!("{}.{}", path, key)
+                    };
+                    self.check_value(nested, &nested_path, findings);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn finding(&self, path: &str, reference: &EnvVarReference) -> ValidationFinding {
+        ValidationFinding::new(
+            &self.id,
+            RuleCategory::Environment,
+            Severity::Warning,
+            format!(
+                "Value at '{}' references undefined environment variable '{}'",
+                path, reference.name
+            ),
+            path,
+        )
+        .with_expected(format!("{} to be set", reference.name))
+        .with_suggestion(format!(
+            "Set {} in the deployment environment, or give it a default with ${{{}:-...}}",
+            reference.name, reference.name
+        ))
+        .with_context(serde_json::json!({
+            "code": UNDEFINED_ENV_VAR,
+            "variable": reference.name,
+        }))
+    }
+}
+
+#[async_trait]
+impl Rule for EnvVarRule {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn description(&self) -> &str {
+        "Detects references to environment variables that are not defined"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Environment
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    async fn evaluate(
+        &self,
+        value: &ConfigValue,
+        path: &str,
+        _context: &RuleContext,
+    ) -> Vec<ValidationFinding> {
+        let mut findings = Vec::new();
+        self.check_value(value, path, &mut findings);
+        findings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Environment;
+
+    fn object(pairs: Vec<(&str, &str)>) -> ConfigValue {
+        ConfigValue::Object(
+            pairs
+                .into_iter()
+                .map(|(k, v)| (k.to_string(), ConfigValue::String(v.to_string())))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_extract_env_var_references() {
+        let refs = extract_env_var_references("postgres://${DB_USER}:${DB_PASS:-secret}@db/app");
+        assert_eq!(refs.len(), 2);
+        assert_eq!((refs[0].name.as_str(), refs[0].default.as_deref()), ("DB_USER", None));
+        assert_eq!((refs[1].name.as_str(), refs[1].default.as_deref()), ("DB_PASS", Some("secret")));
+        assert_eq!(refs[1].raw, "${DB_PASS:-secret}");
+
+        let refs = extract_env_var_references("${HOST-${FALLBACK_HOST}}:8080");
+        assert_eq!(refs.len(), 1);
+        assert_eq!(refs[0].default.as_deref(), Some("${FALLBACK_HOST}"));
+
+        assert!(extract_env_var_references("${} ${1ABC} ${UNCLOSED $HOME").is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_set_unset_and_defaulted_variables() {
+        let rule = EnvVarRule::with_variables(["DB_HOST"]);
+        let context = RuleContext::new(Environment::Production, "app");
+        let config = object(vec![
+            ("host", "${DB_HOST}"),
+            ("password", "${DB_PASSWORD}"),
+            ("port", "${DB_PORT:-5432}"),
+        ]);
+
+        let findings = rule.evaluate(&config, "", &context).await;
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].field_path, "password");
+        assert_eq!(findings[0].severity, Severity::Warning);
+        let finding_context = findings[0].context.as_ref().unwrap();
+        assert_eq!(finding_context["code"], UNDEFINED_ENV_VAR);
+        assert_eq!(finding_context["variable"], "DB_PASSWORD");
+    }
+
+    #[tokio::test]
+    async fn test_nested_defaults_are_checked_only_when_used() {
+        let context = RuleContext::new(Environment::Staging, "app");
+        let config = object(vec![("host", "${DB_HOST:-${FALLBACK_HOST}}")]);
+
+        let rule = EnvVarRule::with_variables(["DB_HOST"]);
+        assert!(rule.evaluate(&config, "", &context).await.is_empty());
+
+        let rule = EnvVarRule::with_variables(Vec::<String>::new());
+        let findings = rule.evaluate(&config, "", &context).await;
+        assert_eq!(findings.len(), 1);
+        assert!(findings[0].message.contains("FALLBACK_HOST"));
+    }
+}
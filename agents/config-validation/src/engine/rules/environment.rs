@@ -2,16 +2,65 @@
 //!
 //! This module provides rules that validate configuration based on
 //! the target deployment environment (dev, staging, production, etc.).
+//!
+//! Schema `MustDiffer` rules are checked across environments: a field listed
+//! by one must not hold the same value in two of the environments it names.
+//!
+//! Schema `RotationRequired` rules are checked against the
+//! [`RuleContext::value_metadata`] of each field. A field with no recorded
+//! rotation cannot be shown to be current, so it is reported as a warning.
 
 use super::{Rule, RuleCategory, RuleContext, Severity, ValidationFinding, FindingBuilder};
+use crate::contracts::schemas::EnvironmentRuleType;
+use crate::contracts::ConfigSchema;
 use crate::{ConfigValue, Environment};
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+
+/// Code carried in the context of findings for values shared between
+/// environments that must differ
+pub const VALUE_NOT_DIFFERENTIATED: &str = "VALUE_NOT_DIFFERENTIATED";
+
+/// Code carried in the context of findings for values not rotated within
+/// their maximum age
+pub const ROTATION_OVERDUE: &str = "ROTATION_OVERDUE";
+
+/// Code carried in the context of findings for values that must be rotated
+/// but have no recorded rotation
+pub const ROTATION_UNKNOWN: &str = "ROTATION_UNKNOWN";
+
+/// A field whose value must differ between environments
+#[derive(Debug, Clone)]
+struct MustDiffer {
+    rule_id: String,
+    field_path: String,
+    environments: Vec<Environment>,
+    from_environments: Vec<Environment>,
+    severity: Severity,
+}
+
+/// A field that must be rotated at least every `max_age_days`
+#[derive(Debug, Clone)]
+struct RotationRequired {
+    rule_id: String,
+    field_path: String,
+    environments: Vec<Environment>,
+    max_age_days: u32,
+    severity: Severity,
+}
 
 /// Rule for environment-specific validation
 pub struct EnvironmentRule {
     id: String,
     name: String,
     description: String,
+    /// Fields checked across environments, in schema order
+    must_differ: Vec<MustDiffer>,
+    /// Fields checked for rotation, in schema order
+    rotation: Vec<RotationRequired>,
+    /// Time to measure value ages at (defaults to now)
+    current_time: Option<DateTime<Utc>>,
 }
 
 impl EnvironmentRule {
@@ -20,10 +69,209 @@ impl EnvironmentRule {
             id: "environment_check".to_string(),
             name: "Environment-Specific Validation".to_string(),
             description: "Validates configuration against environment-specific constraints".to_string(),
+            must_differ: Vec::new(),
+            rotation: Vec::new(),
+            current_time: None,
+        }
+    }
+
+    /// Create a rule that also checks the `MustDiffer` and
+    /// `RotationRequired` environment rules of a schema
+    ///
+    /// Blocking rules are reported as critical (`MustDiffer`) or as errors
+    /// (`RotationRequired`), and the others as warnings. Environment names
+    /// that do not parse are skipped.
+    pub fn from_schema(schema: &ConfigSchema) -> Self {
+        let mut rule = Self::new();
+        for env_rule in &schema.environment_rules {
+            let environments = parse_environments(&env_rule.environments);
+            match &env_rule.rule_type {
+                EnvironmentRuleType::MustDiffer { from_environments } => {
+                    let from_environments = parse_environments(from_environments);
+                    let severity = if env_rule.blocking {
+                        Severity::Critical
+                    } else {
+                        Severity::Warning
+                    };
+                    for field in &env_rule.affected_fields {
+                        rule = rule.with_must_differ(
+                            field,
+                            &env_rule.id,
+                            environments.clone(),
+                            from_environments.clone(),
+                            severity,
+                        );
+                    }
+                }
+                EnvironmentRuleType::RotationRequired { max_age_days } => {
+                    let severity = if env_rule.blocking {
+                        Severity::Error
+                    } else {
+                        Severity::Warning
+                    };
+                    for field in &env_rule.affected_fields {
+                        rule = rule.with_rotation_required(
+                            field,
+                            &env_rule.id,
+                            environments.clone(),
+                            *max_age_days,
+                            severity,
+                        );
+                    }
+                }
+                _ => {}
+            }
+        }
+        rule
+    }
+
+    /// Require a field to hold a different value in each of `environments`
+    /// than in each of `from_environments`, reporting shared values at
+    /// `severity`
+    pub fn with_must_differ(
+        mut self,
+        field_path: &str,
+        rule_id: impl Into<String>,
+        environments: Vec<Environment>,
+        from_environments: Vec<Environment>,
+        severity: Severity,
+    ) -> Self {
+        self.must_differ.push(MustDiffer {
+            rule_id: rule_id.into(),
+            field_path: field_path.strip_prefix("$.").unwrap_or(field_path).to_string(),
+            environments,
+            from_environments,
+            severity,
+        });
+        self
+    }
+
+    /// Require a field to have been rotated within `max_age_days` in
+    /// `environments`, reporting overdue values at `severity`
+    pub fn with_rotation_required(
+        mut self,
+        field_path: &str,
+        rule_id: impl Into<String>,
+        environments: Vec<Environment>,
+        max_age_days: u32,
+        severity: Severity,
+    ) -> Self {
+        self.rotation.push(RotationRequired {
+            rule_id: rule_id.into(),
+            field_path: field_path.strip_prefix("$.").unwrap_or(field_path).to_string(),
+            environments,
+            max_age_days,
+            severity,
+        });
+        self
+    }
+
+    /// Measure value ages at a fixed time instead of now
+    pub fn with_current_time(mut self, time: DateTime<Utc>) -> Self {
+        self.current_time = Some(time);
+        self
+    }
+
+    /// Number of fields checked across environments
+    pub fn must_differ_len(&self) -> usize {
+        self.must_differ.len()
+    }
+
+    /// Number of fields checked for rotation
+    pub fn rotation_len(&self) -> usize {
+        self.rotation.len()
+    }
+
+    fn current_time(&self) -> DateTime<Utc> {
+        self.current_time.unwrap_or_else(Utc::now)
+    }
+
+    fn check_rotation(
+        &self,
+        value: &ConfigValue,
+        base_path: &str,
+        context: &RuleContext,
+        findings: &mut Vec<ValidationFinding>,
+    ) {
+        let now = self.current_time();
+        for rule in &self.rotation {
+            if !rule.environments.contains(&context.environment)
+                || get_value_at_path(value, &rule.field_path).is_none()
+            {
+                continue;
+            }
+            let path = if base_path.is_empty() {
+                rule.field_path.clone()
+            } else {
+                format!("{}.{}", base_path, rule.field_path)
+            };
+
+            let Some(meta) = context.value_metadata.get(&rule.field_path) else {
+                findings.push(
+                    FindingBuilder::new(&self.id, RuleCategory::Environment, &path)
+                        .severity(Severity::Warning)
+                        .build(format!(
+                            "Field '{}' must be rotated every {} days but has no recorded rotation",
+                            path, rule.max_age_days
+                        ))
+                        .with_suggestion("Record when the value was last rotated so its age can be checked")
+                        .with_context(serde_json::json!({
+                            "code": ROTATION_UNKNOWN,
+                            "environment_rule": rule.rule_id,
+                            "max_age_days": rule.max_age_days,
+                        })),
+                );
+                continue;
+            };
+
+            let age_days = (now - meta.last_rotated).num_days();
+            if age_days <= i64::from(rule.max_age_days) {
+                continue;
+            }
+            findings.push(
+                FindingBuilder::new(&self.id, RuleCategory::Environment, &path)
+                    .severity(rule.severity)
+                    .build(format!(
+                        "Field '{}' was last rotated {} days ago, more than the allowed {} days",
+                        path, age_days, rule.max_age_days
+                    ))
+                    .with_expected(format!("rotated within {} days", rule.max_age_days))
+                    .with_actual(format!("last rotated {}", meta.last_rotated.to_rfc3339()))
+                    .with_suggestion(format!("Rotate '{}' and record the new rotation time", path))
+                    .with_context(serde_json::json!({
+                        "code": ROTATION_OVERDUE,
+                        "environment_rule": rule.rule_id,
+                        "max_age_days": rule.max_age_days,
+                        "age_days": age_days,
+                    })),
+            );
         }
     }
 }
 
+/// Environments named by a schema rule
+fn parse_environments(names: &[String]) -> Vec<Environment> {
+    names.iter().filter_map(|name| name.parse().ok()).collect()
+}
+
+fn get_value_at_path<'a>(value: &'a ConfigValue, path: &str) -> Option<&'a ConfigValue> {
+    path.split('.').try_fold(value, |current, key| match current {
+        ConfigValue::Object(map) => map.get(key),
+        _ => None,
+    })
+}
+
+/// Whether two values are identical
+///
+/// `ConfigValue` has no `PartialEq`, so values are compared in their JSON
+/// form; secrets are compared by ciphertext.
+fn same_value(a: &ConfigValue, b: &ConfigValue) -> bool {
+    match (serde_json::to_value(a), serde_json::to_value(b)) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => false,
+    }
+}
+
 impl Default for EnvironmentRule {
     fn default() -> Self {
         Self::new()
@@ -63,6 +311,65 @@ impl Rule for EnvironmentRule {
         if let ConfigValue::Object(obj) = value {
             self.check_environment_rules(obj, path, context, &mut findings);
         }
+        self.check_rotation(value, path, context, &mut findings);
+
+        findings
+    }
+
+    async fn evaluate_across_environments(
+        &self,
+        configs: &HashMap<Environment, ConfigValue>,
+        _namespace: &str,
+    ) -> Vec<ValidationFinding> {
+        let mut findings = Vec::new();
+        // Pairs already reported, so a rule listing an environment on both
+        // sides reports each pair once
+        let mut reported: Vec<(usize, Environment, Environment)> = Vec::new();
+
+        for (index, rule) in self.must_differ.iter().enumerate() {
+            for &env in &rule.environments {
+                let Some(value) = configs
+                    .get(&env)
+                    .and_then(|c| get_value_at_path(c, &rule.field_path))
+                else {
+                    continue;
+                };
+                for &other in &rule.from_environments {
+                    if other == env || reported.contains(&(index, other, env)) {
+                        continue;
+                    }
+                    let shared = configs
+                        .get(&other)
+                        .and_then(|c| get_value_at_path(c, &rule.field_path))
+                        .is_some_and(|other_value| same_value(value, other_value));
+                    if !shared {
+                        continue;
+                    }
+                    reported.push((index, env, other));
+
+                    // The value itself is not reported: these are usually secrets
+                    findings.push(
+                        FindingBuilder::new(&self.id, RuleCategory::Environment, &rule.field_path)
+                            .severity(rule.severity)
+                            .build(format!(
+                                "Field '{}' has the same value in {} and {}",
+                                rule.field_path, env, other
+                            ))
+                            .with_expected(format!("a value differing from {}", other))
+                            .with_actual(format!("the value used in {}", other))
+                            .with_suggestion(format!(
+                                "Use a separate value for '{}' in {}",
+                                rule.field_path, env
+                            ))
+                            .with_context(serde_json::json!({
+                                "code": VALUE_NOT_DIFFERENTIATED,
+                                "environment_rule": rule.rule_id,
+                                "environments": [env.to_string(), other.to_string()],
+                            })),
+                    );
+                }
+            }
+        }
 
         findings
     }
@@ -293,6 +600,103 @@ mod tests {
         assert!(findings.iter().any(|f| f.severity == Severity::Error));
     }
 
+    #[tokio::test]
+    async fn test_must_differ_reports_shared_values() {
+        use crate::contracts::{EnvironmentRule as SchemaEnvironmentRule, FieldRule, FieldType};
+
+        let schema = ConfigSchema::new("app/service", "Service", "1.0.0")
+            .with_field("db_password", FieldRule::new(FieldType::String))
+            .with_environment_rule(SchemaEnvironmentRule::must_differ(
+                "distinct-db-password",
+                vec!["staging".to_string(), "production".to_string()],
+                vec!["staging".to_string(), "production".to_string()],
+                vec!["db_password".to_string()],
+            ));
+        let rule = EnvironmentRule::from_schema(&schema);
+        assert_eq!(rule.must_differ_len(), 1);
+
+        let config = |password: &str| {
+            ConfigValue::Object(
+                [("db_password".to_string(), ConfigValue::String(password.to_string()))]
+                    .into_iter()
+                    .collect(),
+            )
+        };
+        let mut configs = HashMap::from([
+            (Environment::Development, config("hunter2")),
+            (Environment::Staging, config("hunter2")),
+            (Environment::Production, config("hunter2")),
+        ]);
+
+        let findings = rule.evaluate_across_environments(&configs, "app/service").await;
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].severity, Severity::Critical);
+        assert_eq!(findings[0].field_path, "db_password");
+        assert!(!findings[0].message.contains("hunter2"));
+        let context = findings[0].context.as_ref().unwrap();
+        assert_eq!(context["code"], VALUE_NOT_DIFFERENTIATED);
+        assert_eq!(context["environments"], serde_json::json!(["staging", "production"]));
+
+        // Development is not listed, so sharing its value is fine
+        configs.insert(Environment::Production, config("s3cret"));
+        assert!(rule.evaluate_across_environments(&configs, "app/service").await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_rotation_required_checks_value_age() {
+        use super::super::ValueMeta;
+        use crate::contracts::EnvironmentRule as SchemaEnvironmentRule;
+
+        let now = "2026-06-01T00:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let schema = ConfigSchema::new("app/service", "Service", "1.0.0").with_environment_rule(
+            SchemaEnvironmentRule::rotation_required(
+                "rotate-api-key",
+                vec!["production".to_string()],
+                90,
+                vec!["api_key".to_string()],
+            ),
+        );
+        let rule = EnvironmentRule::from_schema(&schema).with_current_time(now);
+        assert_eq!(rule.rotation_len(), 1);
+
+        let config = ConfigValue::Object(
+            [("api_key".to_string(), ConfigValue::String("${API_KEY}".to_string()))]
+                .into_iter()
+                .collect(),
+        );
+        let production = RuleContext::new(Environment::Production, "app/service");
+        let codes = |findings: &[ValidationFinding]| -> Vec<String> {
+            findings
+                .iter()
+                .filter(|f| f.field_path == "api_key")
+                .map(|f| f.code().to_string())
+                .collect()
+        };
+
+        // Rotated recently
+        let context = production
+            .clone()
+            .with_value_metadata("api_key", ValueMeta::new(now - chrono::Duration::days(30)));
+        assert!(codes(&rule.evaluate(&config, "", &context).await).is_empty());
+
+        // Overdue
+        let context = production
+            .clone()
+            .with_value_metadata("api_key", ValueMeta::new(now - chrono::Duration::days(120)));
+        let findings = rule.evaluate(&config, "", &context).await;
+        assert_eq!(codes(&findings), vec![ROTATION_OVERDUE]);
+        assert_eq!(findings[0].severity, Severity::Error);
+
+        // No metadata degrades to a warning
+        let findings = rule.evaluate(&config, "", &production).await;
+        assert_eq!(codes(&findings), vec![ROTATION_UNKNOWN]);
+        assert_eq!(findings[0].severity, Severity::Warning);
+
+        // Other environments are not checked
+        let development = RuleContext::new(Environment::Development, "app/service");
+        assert!(codes(&rule.evaluate(&config, "", &development).await).is_empty());
+    }
+
     #[tokio::test]
     async fn test_development_no_strict_errors() {
         let rule = EnvironmentRule::new();
@@ -0,0 +1,589 @@
+//! Parsers and rules for string-encoded value formats
+//!
+//! This module provides [`parse_duration`], which accepts Go-style durations
+//! such as `30s`, `5m`, `2h30m` and `500ms`. It backs the duration check in
+//! [`super::type_check::TypeCheckRule`] and can be reused wherever a config
+//! duration needs to be read.
+//!
+//! It also provides [`FormatRule`], which checks fields declared as
+//! `FieldType::Url`, `FieldType::Email` or `FieldType::IpAddress` hold a
+//! value of that format. Fields of other types are not touched.
+
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::time::Duration;
+use thiserror::Error;
+
+use super::{Rule, RuleCategory, RuleContext, Severity, ValidationFinding};
+use crate::contracts::{ConfigSchema, FieldRule, FieldType};
+use crate::ConfigValue;
+
+/// Code carried in the context of findings for strings that are not durations
+pub const INVALID_DURATION: &str = "INVALID_DURATION";
+
+/// Code carried in the context of findings for strings that are not URLs
+pub const INVALID_URL: &str = "INVALID_URL";
+
+/// Code carried in the context of findings for strings that are not email
+/// addresses
+pub const INVALID_EMAIL: &str = "INVALID_EMAIL";
+
+/// Code carried in the context of findings for strings that are not IP
+/// addresses
+pub const INVALID_IP: &str = "INVALID_IP";
+
+/// The duration syntax accepted by [`parse_duration`], for suggestions
+pub const DURATION_GRAMMAR: &str =
+    "one or more <number><unit> pairs such as 30s, 5m, 2h30m, 500ms or 1.5h, \
+     with units ns, us (or µs), ms, s, m and h";
+
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+/// Largest number of fractional digits kept; further digits are ignored
+const MAX_FRACTION_DIGITS: u32 = 18;
+
+/// Why a string is not a valid duration
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum DurationParseError {
+    /// The string is empty
+    #[error("duration is empty")]
+    Empty,
+
+    /// The duration starts with a minus sign
+    #[error("negative durations are not allowed")]
+    Negative,
+
+    /// A number where one was expected could not be read
+    #[error("expected a number at '{rest}'")]
+    InvalidNumber { rest: String },
+
+    /// A number is not followed by a unit
+    #[error("missing unit after '{number}'")]
+    MissingUnit { number: String },
+
+    /// A unit is not one of the accepted units
+    #[error("unknown unit '{unit}'")]
+    UnknownUnit { unit: String },
+
+    /// The duration does not fit in a [`Duration`]
+    #[error("duration is too large")]
+    Overflow,
+}
+
+/// Nanoseconds in one of `unit`
+fn unit_nanos(unit: &str) -> Option<u128> {
+    match unit {
+        "ns" => Some(1),
+        "us" | "µs" | "μs" => Some(1_000),
+        "ms" => Some(1_000_000),
+        "s" => Some(NANOS_PER_SEC),
+        "m" => Some(60 * NANOS_PER_SEC),
+        "h" => Some(3_600 * NANOS_PER_SEC),
+        _ => None,
+    }
+}
+
+/// Parse a Go-style duration such as `30s`, `5m`, `2h30m` or `500ms`
+///
+/// A duration is one or more numbers, each followed by a unit (`ns`, `us`,
+/// `µs`, `ms`, `s`, `m` or `h`). Numbers may have a fractional part, as in
+/// `1.5h`, and a leading `+` is allowed. A bare `0` is the zero duration.
+/// Negative durations and durations too large for [`Duration`] are rejected.
+pub fn parse_duration(input: &str) -> Result<Duration, DurationParseError> {
+    let mut rest = input.trim();
+    if rest.is_empty() {
+        return Err(DurationParseError::Empty);
+    }
+    if rest.starts_with('-') {
+        return Err(DurationParseError::Negative);
+    }
+    rest = rest.strip_prefix('+').unwrap_or(rest);
+    if rest == "0" {
+        return Ok(Duration::ZERO);
+    }
+    if rest.is_empty() {
+        return Err(DurationParseError::InvalidNumber { rest: String::new() });
+    }
+
+    let mut total: u128 = 0;
+    while !rest.is_empty() {
+        let int_len = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+        let (int_digits, after_int) = rest.split_at(int_len);
+        let (frac_digits, after_number) = match after_int.strip_prefix('.') {
+            Some(after_dot) => {
+                let frac_len = after_dot
+                    .find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(after_dot.len());
+                after_dot.split_at(frac_len)
+            }
+            None => ("", after_int),
+        };
+        if int_digits.is_empty() && frac_digits.is_empty() {
+            return Err(DurationParseError::InvalidNumber { rest: rest.to_string() });
+        }
+        let number = &rest[..rest.len() - after_number.len()];
+
+        let unit_len = after_number
+            .find(|c: char| c.is_ascii_digit() || c == '.')
+            .unwrap_or(after_number.len());
+        let (unit, after_unit) = after_number.split_at(unit_len);
+        if unit.is_empty() {
+            return Err(DurationParseError::MissingUnit { number: number.to_string() });
+        }
+        let scale = unit_nanos(unit).ok_or_else(|| DurationParseError::UnknownUnit {
+            unit: unit.to_string(),
+        })?;
+
+        let whole = int_digits.chars().try_fold(0u128, |acc, c| {
+            acc.checked_mul(10)?.checked_add(u128::from(c.to_digit(10)?))
+        });
+        let mut nanos = whole
+            .and_then(|whole| whole.checked_mul(scale))
+            .ok_or(DurationParseError::Overflow)?;
+
+        let kept: Vec<u32> = frac_digits
+            .chars()
+            .take(MAX_FRACTION_DIGITS as usize)
+            .filter_map(|c| c.to_digit(10))
+            .collect();
+        if !kept.is_empty() {
+            let fraction = kept.iter().fold(0u128, |acc, d| acc * 10 + u128::from(*d));
+            nanos += fraction * scale / 10u128.pow(kept.len() as u32);
+        }
+
+        total = total.checked_add(nanos).ok_or(DurationParseError::Overflow)?;
+        rest = after_unit;
+    }
+
+    let secs = u64::try_from(total / NANOS_PER_SEC).map_err(|_| DurationParseError::Overflow)?;
+    Ok(Duration::new(secs, (total % NANOS_PER_SEC) as u32))
+}
+
+/// Longest local part (before the `@`) of an email address
+const MAX_EMAIL_LOCAL_LEN: usize = 64;
+
+/// Longest domain name of an email address
+const MAX_EMAIL_DOMAIN_LEN: usize = 253;
+
+/// Longest label of a domain name
+const MAX_DOMAIN_LABEL_LEN: usize = 63;
+
+/// A string format implied by a schema field type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValueFormat {
+    /// An absolute URL, which has a scheme
+    Url,
+    /// An email address of the form `local@domain`
+    Email,
+    /// An IPv4 or IPv6 address
+    IpAddress,
+}
+
+impl ValueFormat {
+    /// The format a field type requires, if any
+    pub fn from_field_type(field_type: &FieldType) -> Option<Self> {
+        match field_type {
+            FieldType::Url => Some(ValueFormat::Url),
+            FieldType::Email => Some(ValueFormat::Email),
+            FieldType::IpAddress => Some(ValueFormat::IpAddress),
+            _ => None,
+        }
+    }
+
+    /// Get the format name, as used in `expected`
+    pub fn name(&self) -> &'static str {
+        match self {
+            ValueFormat::Url => "url",
+            ValueFormat::Email => "email",
+            ValueFormat::IpAddress => "ip_address",
+        }
+    }
+
+    /// Get the code carried by findings for values not in this format
+    pub fn code(&self) -> &'static str {
+        match self {
+            ValueFormat::Url => INVALID_URL,
+            ValueFormat::Email => INVALID_EMAIL,
+            ValueFormat::IpAddress => INVALID_IP,
+        }
+    }
+
+    /// Check that `value` is in this format, returning why it is not
+    pub fn check(&self, value: &str) -> Result<(), String> {
+        match self {
+            ValueFormat::Url => url::Url::parse(value).map(|_| ()).map_err(|e| e.to_string()),
+            ValueFormat::Email => check_email(value),
+            ValueFormat::IpAddress => value.parse::<IpAddr>().map(|_| ()).map_err(|e| e.to_string()),
+        }
+    }
+
+    fn description(&self) -> &'static str {
+        match self {
+            ValueFormat::Url => "URL",
+            ValueFormat::Email => "email address",
+            ValueFormat::IpAddress => "IP address",
+        }
+    }
+
+    fn suggestion(&self) -> &'static str {
+        match self {
+            ValueFormat::Url => "Use an absolute URL with a scheme, such as https://example.com/path",
+            ValueFormat::Email => "Use an address of the form name@example.com",
+            ValueFormat::IpAddress => "Use an IPv4 address such as 10.0.0.1 or an IPv6 address such as ::1",
+        }
+    }
+}
+
+/// Check the RFC 5322 dot-atom shape `local@domain`
+///
+/// Quoted local parts, comments and address literals are not accepted.
+fn check_email(value: &str) -> Result<(), String> {
+    let (local, domain) = match value.split_once('@') {
+        Some((local, domain)) if !domain.contains('@') => (local, domain),
+        Some(_) => return Err("contains more than one '@'".to_string()),
+        None => return Err("missing '@'".to_string()),
+    };
+
+    if local.is_empty() {
+        return Err("local part is empty".to_string());
+    }
+    if local.len() > MAX_EMAIL_LOCAL_LEN {
+        return Err(format!("local part is longer than {} bytes", MAX_EMAIL_LOCAL_LEN));
+    }
+    if let Some(c) = local
+        .chars()
+        .find(|c| !(c.is_ascii_alphanumeric() || "!#$%&'*+/=?^_`{|}~-.".contains(*c)))
+    {
+        return Err(format!("local part contains '{}'", c));
+    }
+    if local.starts_with('.') || local.ends_with('.') || local.contains("..") {
+        return Err("local part has a misplaced '.'".to_string());
+    }
+
+    if domain.len() > MAX_EMAIL_DOMAIN_LEN {
+        return Err(format!("domain is longer than {} bytes", MAX_EMAIL_DOMAIN_LEN));
+    }
+    let labels: Vec<&str> = domain.split('.').collect();
+    if labels.len() < 2 {
+        return Err(format!("domain '{}' has no top-level domain", domain));
+    }
+    for label in &labels {
+        if label.is_empty() || label.len() > MAX_DOMAIN_LABEL_LEN {
+            return Err(format!("domain '{}' has an empty or overlong label", domain));
+        }
+        if !label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+            || label.starts_with('-')
+            || label.ends_with('-')
+        {
+            return Err(format!("domain label '{}' is not a valid hostname label", label));
+        }
+    }
+    if labels.last().is_some_and(|tld| tld.chars().all(|c| c.is_ascii_digit())) {
+        return Err(format!("domain '{}' has a numeric top-level domain", domain));
+    }
+
+    Ok(())
+}
+
+/// Rule for validating that values match the format of their field type
+pub struct FormatRule {
+    id: String,
+    name: String,
+    /// Field paths and their formats, in the order they were added
+    fields: Vec<(String, ValueFormat)>,
+    severity: Severity,
+}
+
+impl FormatRule {
+    /// Create a format rule with no fields
+    pub fn new() -> Self {
+        Self {
+            id: "format_check".to_string(),
+            name: "Format Validation".to_string(),
+            fields: Vec::new(),
+            severity: Severity::Error,
+        }
+    }
+
+    /// Create a rule checking every URL, email and IP address field in a
+    /// schema
+    ///
+    /// Nested fields are addressed by dotted path; fields are visited in path
+    /// order so the findings are too.
+    pub fn from_schema(schema: &ConfigSchema) -> Self {
+        fn walk(fields: &HashMap<String, FieldRule>, prefix: &str, rule: &mut FormatRule) {
+            let mut keys: Vec<&String> = fields.keys().collect();
+            keys.sort();
+            for key in keys {
+                let field = &fields[key];
+                let path = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", prefix, key)
+                };
+                rule.add_field(&path, &field.field_type);
+                walk(&field.nested_fields, &path, rule);
+            }
+        }
+
+        let mut rule = Self::new();
+        walk(&schema.fields, "", &mut rule);
+        rule
+    }
+
+    /// Check a field declared with `field_type`; types without a format are
+    /// ignored
+    pub fn with_field(mut self, field_path: impl Into<String>, field_type: &FieldType) -> Self {
+        self.add_field(&field_path.into(), field_type);
+        self
+    }
+
+    /// Set the severity level
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    /// Number of fields checked
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    /// Whether the rule checks no fields
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    fn add_field(&mut self, field_path: &str, field_type: &FieldType) {
+        if let Some(format) = ValueFormat::from_field_type(field_type) {
+            self.fields.push((field_path.to_string(), format));
+        }
+    }
+
+    fn get_value_at_path<'a>(&self, value: &'a ConfigValue, path: &str) -> Option<&'a ConfigValue> {
+        path.split('.').try_fold(value, |current, key| match current {
+            ConfigValue::Object(map) => map.get(key),
+            _ => None,
+        })
+    }
+}
+
+impl Default for FormatRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Rule for FormatRule {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn description(&self) -> &str {
+        "Validates that URL, email and IP address fields hold values in that format"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Type
+    }
+
+    fn default_severity(&self) -> Severity {
+        self.severity
+    }
+
+    async fn evaluate(
+        &self,
+        value: &ConfigValue,
+        path: &str,
+        _context: &RuleContext,
+    ) -> Vec<ValidationFinding> {
+        let mut findings = Vec::new();
+
+        for (field_path, format) in &self.fields {
+            // Missing fields and non-strings are left to the required and
+            // type rules
+            let Some(ConfigValue::String(s)) = self.get_value_at_path(value, field_path) else {
+                continue;
+            };
+            let Err(reason) = format.check(s) else {
+                continue;
+            };
+
+            let full_path = if path.is_empty() {
+                field_path.clone()
+            } else {
+                format!("{}.{}", path, field_path)
+            };
+            findings.push(
+                ValidationFinding::new(
+                    &self.id,
+                    RuleCategory::Type,
+                    self.severity,
+                    format!("Invalid {} '{}': {}", format.description(), s, reason),
+                    &full_path,
+                )
+                .with_expected(format.name())
+                .with_actual(s.clone())
+                .with_suggestion(format.suggestion())
+                .with_context(serde_json::json!({ "code": format.code() })),
+            );
+        }
+
+        findings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Environment;
+
+    #[test]
+    fn test_parse_duration_accepts_go_style() {
+        let cases = [
+            ("30s", Duration::from_secs(30)),
+            ("5m", Duration::from_secs(300)),
+            ("2h30m", Duration::from_secs(9_000)),
+            ("500ms", Duration::from_millis(500)),
+            ("1.5h", Duration::from_secs(5_400)),
+            ("1m0.5s", Duration::from_millis(60_500)),
+            ("250us", Duration::from_micros(250)),
+            ("250µs", Duration::from_micros(250)),
+            ("10ns", Duration::from_nanos(10)),
+            ("+1s", Duration::from_secs(1)),
+            ("0", Duration::ZERO),
+            (" 45s ", Duration::from_secs(45)),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(parse_duration(input), Ok(expected), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_parse_duration_rejects_invalid() {
+        let cases = [
+            ("", DurationParseError::Empty),
+            ("-5s", DurationParseError::Negative),
+            ("5", DurationParseError::MissingUnit { number: "5".to_string() }),
+            ("1h30", DurationParseError::MissingUnit { number: "30".to_string() }),
+            ("5 potatoes", DurationParseError::UnknownUnit { unit: " potatoes".to_string() }),
+            ("5d", DurationParseError::UnknownUnit { unit: "d".to_string() }),
+            ("s", DurationParseError::InvalidNumber { rest: "s".to_string() }),
+            (".s", DurationParseError::InvalidNumber { rest: ".s".to_string() }),
+            ("+", DurationParseError::InvalidNumber { rest: String::new() }),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(parse_duration(input), Err(expected), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_parse_duration_rejects_overflow() {
+        let max_hours = u64::MAX / 3_600 + 1;
+        assert_eq!(
+            parse_duration(&format!("{}h", max_hours)),
+            Err(DurationParseError::Overflow)
+        );
+        assert_eq!(
+            parse_duration(&format!("{}s", "9".repeat(60))),
+            Err(DurationParseError::Overflow)
+        );
+        assert!(parse_duration(&format!("{}s", u64::MAX)).is_ok());
+    }
+
+    #[test]
+    fn test_url_format() {
+        for valid in ["https://example.com", "http://10.0.0.1:8080/health", "postgres://db/app", "mailto:ops@example.com"] {
+            assert_eq!(ValueFormat::Url.check(valid), Ok(()), "{}", valid);
+        }
+        for invalid in ["example.com", "/relative/path", "", "http://"] {
+            assert!(ValueFormat::Url.check(invalid).is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_email_format() {
+        for valid in ["ops@example.com", "first.last+tag@mail.example.co.uk", "o'brien@example.io"] {
+            assert_eq!(ValueFormat::Email.check(valid), Ok(()), "{}", valid);
+        }
+        for invalid in [
+            "ops",
+            "@example.com",
+            "ops@",
+            "ops@localhost",
+            "ops@@example.com",
+            "a@b@example.com",
+            ".ops@example.com",
+            "o..ps@example.com",
+            "ops @example.com",
+            "ops@-example.com",
+            "ops@example..com",
+            "ops@10.0.0.1",
+        ] {
+            assert!(ValueFormat::Email.check(invalid).is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_ip_format() {
+        for valid in ["10.0.0.1", "::1", "2001:db8::8a2e:370:7334"] {
+            assert_eq!(ValueFormat::IpAddress.check(valid), Ok(()), "{}", valid);
+        }
+        for invalid in ["256.0.0.1", "10.0.0", "localhost", "10.0.0.1/24", "::g"] {
+            assert!(ValueFormat::IpAddress.check(invalid).is_err(), "{}", invalid);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_format_rule_checks_only_format_fields() {
+        let mut server = FieldRule::new(FieldType::Object);
+        server
+            .nested_fields
+            .insert("bind".to_string(), FieldRule::new(FieldType::IpAddress));
+        let schema = ConfigSchema::new("app/service", "Service", "1.0.0")
+            .with_field("endpoint", FieldRule::new(FieldType::Url))
+            .with_field("owner", FieldRule::new(FieldType::Email))
+            .with_field("name", FieldRule::new(FieldType::String))
+            .with_field("server", server);
+        let rule = FormatRule::from_schema(&schema);
+        assert_eq!(rule.len(), 3);
+
+        let object = |pairs: Vec<(&str, ConfigValue)>| {
+            ConfigValue::Object(pairs.into_iter().map(|(k, v)| (k.to_string(), v)).collect())
+        };
+        let string = |s: &str| ConfigValue::String(s.to_string());
+        let config = object(vec![
+            ("endpoint", string("example.com/api")),
+            ("owner", string("ops@example.com")),
+            ("name", string("not a url, not an email")),
+            ("server", object(vec![("bind", string("0.0.0.300"))])),
+        ]);
+        let context = RuleContext::new(Environment::Development, "app/service");
+        let findings = rule.evaluate(&config, "", &context).await;
+
+        let summary: Vec<(&str, &str, Option<&str>)> = findings
+            .iter()
+            .map(|f| {
+                (
+                    f.field_path.as_str(),
+                    f.context.as_ref().unwrap()["code"].as_str().unwrap(),
+                    f.actual.as_deref(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("endpoint", INVALID_URL, Some("example.com/api")),
+                ("server.bind", INVALID_IP, Some("0.0.0.300")),
+            ]
+        );
+        assert!(findings.iter().all(|f| f.severity == Severity::Error));
+    }
+}
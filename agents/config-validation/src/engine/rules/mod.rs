@@ -4,19 +4,31 @@
 //! validation rules against configuration values.
 
 pub mod bounds;
+pub mod collection;
+pub mod combinators;
 pub mod compatibility;
+pub mod conditional;
 pub mod deprecated;
 pub mod enum_check;
+pub mod env_vars;
 pub mod environment;
+pub mod formats;
+pub mod pattern;
+pub mod placeholder;
+pub mod reference;
 pub mod required;
+pub mod secret_refs;
+pub mod secrets;
 pub mod type_check;
 
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
+use crate::contracts::ValidationIssue;
+
 /// Categories of validation rules
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum RuleCategory {
     /// Required field validation - checks if mandatory fields are present
@@ -33,6 +45,8 @@ pub enum RuleCategory {
     Environment,
     /// Cross-agent/service compatibility - ensures interoperability
     Compatibility,
+    /// Security checks - secret handling and references
+    Security,
 }
 
 impl fmt::Display for RuleCategory {
@@ -45,40 +59,13 @@ impl fmt::Display for RuleCategory {
             RuleCategory::Deprecated => write!(f, "deprecated"),
             RuleCategory::Environment => write!(f, "environment"),
             RuleCategory::Compatibility => write!(f, "compatibility"),
+            RuleCategory::Security => write!(f, "security"),
         }
     }
 }
 
-/// Severity level for validation findings
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
-pub enum Severity {
-    /// Informational - no action required
-    Info,
-    /// Warning - should be addressed but not blocking
-    Warning,
-    /// Error - must be fixed before deployment
-    Error,
-    /// Critical - security or stability risk
-    Critical,
-}
-
-impl fmt::Display for Severity {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Severity::Info => write!(f, "info"),
-            Severity::Warning => write!(f, "warning"),
-            Severity::Error => write!(f, "error"),
-            Severity::Critical => write!(f, "critical"),
-        }
-    }
-}
-
-impl Default for Severity {
-    fn default() -> Self {
-        Severity::Error
-    }
-}
+/// Severity level for validation findings, shared with the contracts
+pub use crate::contracts::Severity;
 
 /// A single validation finding representing an issue detected during validation
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -151,7 +138,67 @@ impl ValidationFinding {
 
     /// Check if this is a blocking finding (error or critical)
     pub fn is_blocking(&self) -> bool {
-        matches!(self.severity, Severity::Error | Severity::Critical)
+        self.severity.is_blocking()
+    }
+
+    /// The finding's code: the `code` in its context, or else its rule id
+    pub fn code(&self) -> &str {
+        self.context
+            .as_ref()
+            .and_then(|context| context.get("code"))
+            .and_then(|code| code.as_str())
+            .unwrap_or(&self.rule_id)
+    }
+
+    /// Identity of the issue this finding reports, for deduplication
+    ///
+    /// Combines the rule id, field path and [`code`](Self::code), so findings
+    /// differing only in severity or wording share a fingerprint.
+    pub fn fingerprint(&self) -> String {
+        format!("{}|{}|{}", self.rule_id, self.field_path, self.code())
+    }
+}
+
+impl ValidationFinding {
+    /// Rebuild a finding from a contract issue
+    ///
+    /// Issues carry no category, so the caller supplies it. The issue's rule
+    /// id becomes the finding's, falling back to its code; a code differing
+    /// from the rule id is kept in the context as `{"code": ...}`.
+    pub fn from_issue(issue: ValidationIssue, category: RuleCategory) -> Self {
+        let rule_id = issue.rule_id.unwrap_or_else(|| issue.code.clone());
+        let context = (issue.code != rule_id).then(|| serde_json::json!({ "code": issue.code }));
+        Self {
+            rule_id,
+            category,
+            severity: issue.severity,
+            message: issue.message,
+            field_path: issue.path.unwrap_or_default(),
+            expected: issue.expected,
+            actual: issue.actual,
+            suggestion: issue.suggestion,
+            context,
+        }
+    }
+}
+
+/// Convert a finding to a contract issue
+///
+/// The rule id doubles as the issue code and an empty field path becomes no
+/// path. The category and context are
+/// not carried over.
+impl From<ValidationFinding> for ValidationIssue {
+    fn from(finding: ValidationFinding) -> Self {
+        Self {
+            code: finding.rule_id.clone(),
+            message: finding.message,
+            severity: finding.severity,
+            path: (!finding.field_path.is_empty()).then_some(finding.field_path),
+            rule_id: Some(finding.rule_id),
+            expected: finding.expected,
+            actual: finding.actual,
+            suggestion: finding.suggestion,
+        }
     }
 }
 
@@ -165,6 +212,20 @@ impl fmt::Display for ValidationFinding {
     }
 }
 
+/// What is known about how a configuration value was set
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ValueMeta {
+    /// When the value was last rotated
+    pub last_rotated: chrono::DateTime<chrono::Utc>,
+}
+
+impl ValueMeta {
+    /// Metadata for a value last rotated at `last_rotated`
+    pub fn new(last_rotated: chrono::DateTime<chrono::Utc>) -> Self {
+        Self { last_rotated }
+    }
+}
+
 /// Context provided to rules during evaluation
 #[derive(Debug, Clone)]
 pub struct RuleContext {
@@ -174,6 +235,11 @@ pub struct RuleContext {
     pub namespace: String,
     /// Additional metadata for rule evaluation
     pub metadata: std::collections::HashMap<String, serde_json::Value>,
+    /// Metadata about individual values, by dotted field path
+    ///
+    /// Empty unless the caller knows how the values were set; rules needing
+    /// it report what they cannot check rather than passing.
+    pub value_metadata: std::collections::HashMap<String, ValueMeta>,
 }
 
 impl RuleContext {
@@ -183,6 +249,7 @@ impl RuleContext {
             environment,
             namespace: namespace.into(),
             metadata: std::collections::HashMap::new(),
+            value_metadata: std::collections::HashMap::new(),
         }
     }
 
@@ -191,6 +258,12 @@ impl RuleContext {
         self.metadata.insert(key.into(), value);
         self
     }
+
+    /// Add metadata about the value at a dotted field path
+    pub fn with_value_metadata(mut self, field_path: impl Into<String>, meta: ValueMeta) -> Self {
+        self.value_metadata.insert(field_path.into(), meta);
+        self
+    }
 }
 
 /// Trait for implementing validation rules
@@ -233,6 +306,32 @@ pub trait Rule: Send + Sync {
         path: &str,
         context: &RuleContext,
     ) -> Vec<ValidationFinding>;
+
+    /// Evaluate the rule, looking up referenced keys through `resolver`
+    ///
+    /// Only rules that check references need to override this; the default
+    /// ignores the resolver and calls [`Rule::evaluate`].
+    async fn evaluate_with_resolver(
+        &self,
+        value: &crate::ConfigValue,
+        path: &str,
+        context: &RuleContext,
+        _resolver: &dyn reference::ReferenceResolver,
+    ) -> Vec<ValidationFinding> {
+        self.evaluate(value, path, context).await
+    }
+
+    /// Evaluate the rule against the configurations of several environments
+    ///
+    /// Only rules that compare values between environments need to override
+    /// this; the default reports nothing.
+    async fn evaluate_across_environments(
+        &self,
+        _configs: &std::collections::HashMap<crate::Environment, crate::ConfigValue>,
+        _namespace: &str,
+    ) -> Vec<ValidationFinding> {
+        Vec::new()
+    }
 }
 
 /// A boxed rule for dynamic dispatch
@@ -278,6 +377,7 @@ impl FindingBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::contracts::IssueSeverity;
 
     #[test]
     fn test_severity_ordering() {
@@ -322,6 +422,59 @@ mod tests {
         assert!(!warning.is_blocking());
     }
 
+    #[test]
+    fn test_finding_issue_round_trip() {
+        let finding = ValidationFinding::new(
+            "bounds_check",
+            RuleCategory::Bounds,
+            Severity::Warning,
+            "Port out of range",
+            "server.port",
+        )
+        .with_expected("<= 65535")
+        .with_actual("70000")
+        .with_suggestion("Use a port below 65536");
+
+        let issue = ValidationIssue::from(finding.clone());
+        assert_eq!(issue.code, "bounds_check");
+        assert_eq!(issue.rule_id.as_deref(), Some("bounds_check"));
+        assert_eq!(issue.severity, IssueSeverity::Warning);
+        assert_eq!(issue.path.as_deref(), Some("server.port"));
+
+        let back = ValidationFinding::from_issue(issue, RuleCategory::Bounds);
+        assert_eq!(
+            serde_json::to_value(&back).unwrap(),
+            serde_json::to_value(&finding).unwrap()
+        );
+
+        // Critical is kept as is, and empty paths are dropped
+        let critical =
+            ValidationFinding::new("secret", RuleCategory::Security, Severity::Critical, "m", "");
+        let issue = ValidationIssue::from(critical);
+        assert_eq!(issue.severity, IssueSeverity::Critical);
+        assert_eq!(issue.path, None);
+        let back = ValidationFinding::from_issue(issue, RuleCategory::Security);
+        assert_eq!(back.severity, Severity::Critical);
+        assert!(back.is_blocking());
+    }
+
+    #[test]
+    fn test_finding_from_issue_keeps_distinct_code() {
+        let mut issue = ValidationIssue::error("PATTERN_MISMATCH", "Bad region");
+        issue.rule_id = Some("pattern_check".to_string());
+        issue.path = Some("region".to_string());
+
+        let finding = ValidationFinding::from_issue(issue.clone(), RuleCategory::Type);
+        assert_eq!(finding.rule_id, "pattern_check");
+        assert_eq!(finding.context.as_ref().unwrap()["code"], "PATTERN_MISMATCH");
+
+        let without_rule = ValidationIssue::error("E001", "Missing");
+        let finding = ValidationFinding::from_issue(without_rule, RuleCategory::Required);
+        assert_eq!(finding.rule_id, "E001");
+        assert_eq!(finding.field_path, "");
+        assert!(finding.context.is_none());
+    }
+
     #[test]
     fn test_finding_builder() {
         let finding = FindingBuilder::new("test_rule", RuleCategory::Type, "config.value")
@@ -0,0 +1,296 @@
+//! Regex pattern validation rules
+//!
+//! This module provides a rule enforcing `ValidationConstraint::Pattern`
+//! against string values. Patterns are compiled once, when the rule is built;
+//! a pattern that fails to compile is reported as an info finding on every
+//! evaluation instead of being enforced.
+
+use async_trait::async_trait;
+use regex::Regex;
+use std::collections::HashMap;
+
+use super::{Rule, RuleCategory, RuleContext, Severity, ValidationFinding};
+use crate::contracts::{ConfigSchema, FieldRule, ValidationConstraint};
+use crate::ConfigValue;
+
+/// Code carried in the context of findings for values not matching
+pub const PATTERN_MISMATCH: &str = "PATTERN_MISMATCH";
+
+/// Code carried in the context of findings for patterns that do not compile
+pub const INVALID_PATTERN: &str = "INVALID_PATTERN";
+
+/// A pattern a field's string value must match
+struct FieldPattern {
+    field_path: String,
+    regex: String,
+    description: Option<String>,
+    /// The compiled pattern, or why it failed to compile
+    compiled: Result<Regex, String>,
+}
+
+/// Rule for validating string values against regex patterns
+pub struct PatternRule {
+    id: String,
+    name: String,
+    /// Patterns in the order they were added; a field may have several
+    patterns: Vec<FieldPattern>,
+    severity: Severity,
+}
+
+impl PatternRule {
+    /// Create a pattern rule with no patterns
+    pub fn new() -> Self {
+        Self {
+            id: "pattern_check".to_string(),
+            name: "Pattern Validation".to_string(),
+            patterns: Vec::new(),
+            severity: Severity::Error,
+        }
+    }
+
+    /// Create a rule enforcing every pattern constraint in a schema
+    ///
+    /// Nested fields are addressed by dotted path; fields are visited in path
+    /// order so the findings are too.
+    pub fn from_schema(schema: &ConfigSchema) -> Self {
+        fn walk(fields: &HashMap<String, FieldRule>, prefix: &str, rule: &mut PatternRule) {
+            let mut keys: Vec<&String> = fields.keys().collect();
+            keys.sort();
+            for key in keys {
+                let field = &fields[key];
+                let path = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", prefix, key)
+                };
+                for constraint in &field.constraints {
+                    rule.add_constraint(&path, constraint);
+                }
+                walk(&field.nested_fields, &path, rule);
+            }
+        }
+
+        let mut rule = Self::new();
+        walk(&schema.fields, "", &mut rule);
+        rule
+    }
+
+    /// Require the string at `field_path` to match `regex`
+    pub fn with_pattern(mut self, field_path: impl Into<String>, regex: impl Into<String>) -> Self {
+        self.add_pattern(field_path.into(), regex.into(), None);
+        self
+    }
+
+    /// Enforce a constraint on a field; constraints other than
+    /// `ValidationConstraint::Pattern` are ignored
+    pub fn with_constraint(
+        mut self,
+        field_path: impl Into<String>,
+        constraint: &ValidationConstraint,
+    ) -> Self {
+        self.add_constraint(&field_path.into(), constraint);
+        self
+    }
+
+    /// Set the severity level
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    /// Number of patterns enforced or reported
+    pub fn len(&self) -> usize {
+        self.patterns.len()
+    }
+
+    /// Whether the rule has no patterns
+    pub fn is_empty(&self) -> bool {
+        self.patterns.is_empty()
+    }
+
+    fn add_constraint(&mut self, field_path: &str, constraint: &ValidationConstraint) {
+        if let ValidationConstraint::Pattern { regex, description } = constraint {
+            self.add_pattern(field_path.to_string(), regex.clone(), description.clone());
+        }
+    }
+
+    fn add_pattern(&mut self, field_path: String, regex: String, description: Option<String>) {
+        let compiled = Regex::new(&regex).map_err(|e| e.to_string());
+        self.patterns.push(FieldPattern {
+            field_path,
+            regex,
+            description,
+            compiled,
+        });
+    }
+
+    fn get_value_at_path<'a>(&self, value: &'a ConfigValue, path: &str) -> Option<&'a ConfigValue> {
+        path.split('.').try_fold(value, |current, key| match current {
+            ConfigValue::Object(map) => map.get(key),
+            _ => None,
+        })
+    }
+}
+
+impl Default for PatternRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Rule for PatternRule {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn description(&self) -> &str {
+        "Validates that string values match their regex patterns"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Type
+    }
+
+    fn default_severity(&self) -> Severity {
+        self.severity
+    }
+
+    async fn evaluate(
+        &self,
+        value: &ConfigValue,
+        path: &str,
+        _context: &RuleContext,
+    ) -> Vec<ValidationFinding> {
+        let mut findings = Vec::new();
+
+        for pattern in &self.patterns {
+            let full_path = if path.is_empty() {
+                pattern.field_path.clone()
+            } else {
+                format!("{}.{}", path, pattern.field_path)
+            };
+
+            let regex = match &pattern.compiled {
+                Ok(regex) => regex,
+                Err(error) => {
+                    findings.push(
+                        ValidationFinding::new(
+                            &self.id,
+                            RuleCategory::Type,
+                            Severity::Info,
+                            format!(
+                                "Pattern '{}' does not compile and was not checked: {}",
+                                pattern.regex, error
+                            ),
+                            &full_path,
+                        )
+                        .with_suggestion("Fix the pattern in the schema")
+                        .with_context(serde_json::json!({
+                            "code": INVALID_PATTERN,
+                            "pattern": pattern.regex,
+                        })),
+                    );
+                    continue;
+                }
+            };
+
+            let Some(ConfigValue::String(s)) = self.get_value_at_path(value, &pattern.field_path)
+            else {
+                continue;
+            };
+            if !regex.is_match(s) {
+                let expected = pattern
+                    .description
+                    .clone()
+                    .unwrap_or_else(|| format!("must match pattern: {}", pattern.regex));
+                findings.push(
+                    ValidationFinding::new(
+                        &self.id,
+                        RuleCategory::Type,
+                        self.severity,
+                        format!("Value '{}' does not match pattern '{}'", s, pattern.regex),
+                        &full_path,
+                    )
+                    .with_expected(expected)
+                    .with_actual(s.clone())
+                    .with_context(serde_json::json!({
+                        "code": PATTERN_MISMATCH,
+                        "pattern": pattern.regex,
+                    })),
+                );
+            }
+        }
+
+        findings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contracts::FieldType;
+    use crate::Environment;
+
+    fn config(pairs: &[(&str, &str)]) -> ConfigValue {
+        ConfigValue::Object(
+            pairs
+                .iter()
+                .map(|(k, v)| (k.to_string(), ConfigValue::String(v.to_string())))
+                .collect(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_mismatch_reports_path_and_value() {
+        let rule = PatternRule::new().with_pattern("region", "^[a-z]{2}-[a-z]+-[0-9]$");
+        let context = RuleContext::new(Environment::Production, "test");
+
+        let findings = rule.evaluate(&config(&[("region", "eu-west-1")]), "", &context).await;
+        assert!(findings.is_empty());
+
+        let findings = rule.evaluate(&config(&[("region", "EU_WEST")]), "", &context).await;
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].field_path, "region");
+        assert_eq!(findings[0].actual.as_deref(), Some("EU_WEST"));
+        assert_eq!(findings[0].context.as_ref().unwrap()["code"], PATTERN_MISMATCH);
+        assert_eq!(findings[0].category, RuleCategory::Type);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_pattern_is_reported_as_info() {
+        let rule = PatternRule::new().with_pattern("name", "([unclosed");
+        let context = RuleContext::new(Environment::Production, "test");
+
+        let findings = rule.evaluate(&config(&[("name", "anything")]), "", &context).await;
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].severity, Severity::Info);
+        assert_eq!(findings[0].context.as_ref().unwrap()["code"], INVALID_PATTERN);
+    }
+
+    #[tokio::test]
+    async fn test_from_schema_collects_nested_patterns() {
+        let mut host = FieldRule::new(FieldType::String);
+        host.constraints.push(ValidationConstraint::pattern("^[a-z.]+$"));
+        let schema = ConfigSchema::new("app/db", "Database", "1.0.0").with_field(
+            "database",
+            FieldRule::new(FieldType::Object).with_nested_field("host", host),
+        );
+        let rule = PatternRule::from_schema(&schema);
+        assert_eq!(rule.len(), 1);
+
+        let value = ConfigValue::Object(
+            [("database".to_string(), config(&[("host", "DB_HOST")]))]
+                .into_iter()
+                .collect(),
+        );
+        let context = RuleContext::new(Environment::Production, "test");
+        let findings = rule.evaluate(&value, "", &context).await;
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].field_path, "database.host");
+    }
+}
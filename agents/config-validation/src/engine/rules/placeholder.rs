@@ -0,0 +1,299 @@
+//! Placeholder value detection
+//!
+//! This module provides a rule flagging string values that were never filled
+//! in: `CHANGEME`, `TODO`, `xxx` and the like, and empty strings. Such values
+//! are warnings while a config is being written and errors in production,
+//! where they are almost always a forgotten edit.
+//!
+//! Placeholders match case-insensitively, either as the whole value or as a
+//! word within it, so `db-CHANGEME.internal` is flagged while `todos_url` is
+//! not. The empty pattern matches empty and whitespace-only strings.
+
+use async_trait::async_trait;
+
+use super::{Rule, RuleCategory, RuleContext, Severity, ValidationFinding};
+use crate::{ConfigValue, Environment};
+
+/// Code carried in the context of findings for placeholder values
+pub const PLACEHOLDER_VALUE: &str = "PLACEHOLDER_VALUE";
+
+/// Placeholders flagged by [`PlaceholderRule::new`]
+pub const DEFAULT_PLACEHOLDERS: &[&str] = &[
+    "",
+    "CHANGEME",
+    "CHANGE_ME",
+    "REPLACE_ME",
+    "TODO",
+    "FIXME",
+    "TBD",
+    "xxx",
+];
+
+/// Rule for detecting placeholder values left in a configuration
+pub struct PlaceholderRule {
+    id: String,
+    name: String,
+    /// Lowercased placeholders, in the order they were added
+    placeholders: Vec<String>,
+}
+
+impl PlaceholderRule {
+    /// Create a rule flagging the [`DEFAULT_PLACEHOLDERS`]
+    pub fn new() -> Self {
+        Self::with_placeholders(DEFAULT_PLACEHOLDERS.iter().copied())
+    }
+
+    /// Create a rule flagging only the given placeholders
+    pub fn with_placeholders<I, S>(placeholders: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut rule = Self {
+            id: "placeholder_check".to_string(),
+            name: "Placeholder Value Detection".to_string(),
+            placeholders: Vec::new(),
+        };
+        for placeholder in placeholders {
+            rule = rule.with_placeholder(placeholder.as_ref());
+        }
+        rule
+    }
+
+    /// Also flag a placeholder
+    pub fn with_placeholder(mut self, placeholder: &str) -> Self {
+        let placeholder = placeholder.trim().to_lowercase();
+        if !self.placeholders.contains(&placeholder) {
+            self.placeholders.push(placeholder);
+        }
+        self
+    }
+
+    /// Number of placeholders flagged
+    pub fn len(&self) -> usize {
+        self.placeholders.len()
+    }
+
+    /// Whether the rule flags no placeholders
+    pub fn is_empty(&self) -> bool {
+        self.placeholders.is_empty()
+    }
+
+    /// Severity of findings in an environment: errors in production,
+    /// warnings elsewhere
+    pub fn severity_for(environment: Environment) -> Severity {
+        match environment {
+            Environment::Production => Severity::Error,
+            _ => Severity::Warning,
+        }
+    }
+
+    /// The first placeholder a string contains, if any
+    pub fn matching_placeholder(&self, value: &str) -> Option<&str> {
+        let value = value.trim().to_lowercase();
+        self.placeholders
+            .iter()
+            .find(|placeholder| {
+                if placeholder.is_empty() {
+                    value.is_empty()
+                } else {
+                    contains_word(&value, placeholder)
+                }
+            })
+            .map(String::as_str)
+    }
+
+    fn check_value(
+        &self,
+        value: &ConfigValue,
+        path: &str,
+        severity: Severity,
+        findings: &mut Vec<ValidationFinding>,
+    ) {
+        match value {
+            ConfigValue::String(s) => {
+                if let Some(placeholder) = self.matching_placeholder(s) {
+                    findings.push(self.finding(path, placeholder, severity));
+                }
+            }
+            ConfigValue::Array(items) => {
+                for (index, item) in items.iter().enumerate() {
+                    self.check_value(item, &format!("{}[{}]", path, index), severity, findings);
+                }
+            }
+            ConfigValue::Object(map) => {
+                for (key, nested) in map {
+                    let nested_path = if path.is_empty() {
+                        key.clone()
+                    } else {
+                        format!("{}.{}", path, key)
+                    };
+                    self.check_value(nested, &nested_path, severity, findings);
+                }
+            }
+            // Secrets are encrypted, and numbers and booleans cannot hold
+            // placeholders
+            _ => {}
+        }
+    }
+
+    fn finding(&self, path: &str, placeholder: &str, severity: Severity) -> ValidationFinding {
+        let (message, actual) = if placeholder.is_empty() {
+            (
+                format!("Value at '{}' is empty", path),
+                "an empty string".to_string(),
+            )
+        } else {
+            (
+                format!("Value at '{}' contains the placeholder '{}'", path, placeholder),
+                format!("a value containing '{}'", placeholder),
+            )
+        };
+        ValidationFinding::new(&self.id, RuleCategory::Environment, severity, message, path)
+            .with_expected("a real value")
+            .with_actual(actual)
+            .with_suggestion("Replace the placeholder with the intended value before deploying")
+            .with_context(serde_json::json!({
+                "code": PLACEHOLDER_VALUE,
+                "placeholder": placeholder,
+            }))
+    }
+}
+
+impl Default for PlaceholderRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Whether `word` occurs in `text` with no letter, digit or underscore
+/// directly before or after it
+fn contains_word(text: &str, word: &str) -> bool {
+    let is_word_char = |c: char| c.is_alphanumeric() || c == '_';
+    text.match_indices(word).any(|(start, _)| {
+        let before = text[..start].chars().next_back();
+        let after = text[start + word.len()..].chars().next();
+        !before.is_some_and(is_word_char) && !after.is_some_and(is_word_char)
+    })
+}
+
+#[async_trait]
+impl Rule for PlaceholderRule {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn description(&self) -> &str {
+        "Detects placeholder values such as CHANGEME, TODO and empty strings"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Environment
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    async fn evaluate(
+        &self,
+        value: &ConfigValue,
+        path: &str,
+        context: &RuleContext,
+    ) -> Vec<ValidationFinding> {
+        let mut findings = Vec::new();
+        if !self.is_empty() {
+            let severity = Self::severity_for(context.environment);
+            self.check_value(value, path, severity, &mut findings);
+        }
+        findings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn object(pairs: Vec<(&str, ConfigValue)>) -> ConfigValue {
+        ConfigValue::Object(pairs.into_iter().map(|(k, v)| (k.to_string(), v)).collect())
+    }
+
+    fn string(s: &str) -> ConfigValue {
+        ConfigValue::String(s.to_string())
+    }
+
+    #[test]
+    fn test_placeholders_match_whole_words() {
+        let rule = PlaceholderRule::new();
+        assert_eq!(rule.matching_placeholder("CHANGEME"), Some("changeme"));
+        assert_eq!(rule.matching_placeholder("db-changeme.internal"), Some("changeme"));
+        assert_eq!(rule.matching_placeholder("TODO: real host"), Some("todo"));
+        assert_eq!(rule.matching_placeholder("  "), Some(""));
+        assert_eq!(rule.matching_placeholder("xxx-xxx"), Some("xxx"));
+
+        assert_eq!(rule.matching_placeholder("https://todos.example.com"), None);
+        assert_eq!(rule.matching_placeholder("max_tbd_count"), None);
+        assert_eq!(rule.matching_placeholder("xxxl"), None);
+        assert_eq!(rule.matching_placeholder("db.example.com"), None);
+    }
+
+    #[tokio::test]
+    async fn test_changeme_is_an_error_in_production_only() {
+        let rule = PlaceholderRule::new();
+        let config = object(vec![
+            ("database", object(vec![("password_hint", string("CHANGEME"))])),
+            ("hosts", ConfigValue::Array(vec![string("db.example.com")])),
+            ("port", ConfigValue::Integer(5432)),
+        ]);
+
+        let production = RuleContext::new(Environment::Production, "app");
+        let findings = rule.evaluate(&config, "", &production).await;
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].field_path, "database.password_hint");
+        assert_eq!(findings[0].severity, Severity::Error);
+        assert!(findings[0].is_blocking());
+        let context = findings[0].context.as_ref().unwrap();
+        assert_eq!(context["code"], PLACEHOLDER_VALUE);
+        assert_eq!(context["placeholder"], "changeme");
+
+        let development = RuleContext::new(Environment::Development, "app");
+        let findings = rule.evaluate(&config, "", &development).await;
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].severity, Severity::Warning);
+        assert!(!findings[0].is_blocking());
+    }
+
+    #[tokio::test]
+    async fn test_placeholder_set_is_configurable() {
+        let config = object(vec![
+            ("region", string("PICK-A-REGION")),
+            ("owner", string("")),
+            ("tags", ConfigValue::Array(vec![string("web"), string("TODO")])),
+        ]);
+        let context = RuleContext::new(Environment::Staging, "app");
+
+        let rule = PlaceholderRule::with_placeholders(["pick-a-region", "PICK-A-REGION"]);
+        assert_eq!(rule.len(), 1);
+        let findings = rule.evaluate(&config, "", &context).await;
+        let paths: Vec<&str> = findings.iter().map(|f| f.field_path.as_str()).collect();
+        assert_eq!(paths, vec!["region"]);
+
+        let rule = PlaceholderRule::new().with_placeholder("pick-a-region");
+        let mut paths: Vec<String> = rule
+            .evaluate(&config, "", &context)
+            .await
+            .into_iter()
+            .map(|f| f.field_path)
+            .collect();
+        paths.sort();
+        assert_eq!(paths, vec!["owner", "region", "tags[1]"]);
+
+        let rule = PlaceholderRule::with_placeholders(Vec::<&str>::new());
+        assert!(rule.is_empty());
+        assert!(rule.evaluate(&config, "", &context).await.is_empty());
+    }
+}
@@ -0,0 +1,439 @@
+//! Cross-reference validation rules
+//!
+//! This module provides a rule enforcing `ValidationConstraint::Reference`.
+//! The constrained field's string value names a config key, which must match
+//! the constraint's `key_pattern` (a regex) and exist in the constraint's
+//! namespace, or in the namespace being validated when none is given.
+//!
+//! Existence is checked through a [`ReferenceResolver`] passed to
+//! [`crate::engine::ValidationEngine::validate_with_resolver`]. Without one,
+//! references cannot be verified and are reported as warnings.
+
+use async_trait::async_trait;
+use regex::Regex;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use super::pattern::INVALID_PATTERN;
+use super::{Rule, RuleCategory, RuleContext, Severity, ValidationFinding};
+use crate::contracts::{ConfigSchema, FieldRule, ValidationConstraint};
+use crate::ConfigValue;
+
+/// Code carried in the context of findings for references to missing keys
+pub const REFERENCE_NOT_FOUND: &str = "REFERENCE_NOT_FOUND";
+
+/// Code carried in the context of findings for values not matching the key
+/// pattern
+pub const INVALID_REFERENCE: &str = "INVALID_REFERENCE";
+
+/// Code carried in the context of findings for references checked without a
+/// resolver
+pub const REFERENCE_UNVERIFIED: &str = "REFERENCE_UNVERIFIED";
+
+/// Looks up whether a referenced config key exists
+#[async_trait]
+pub trait ReferenceResolver: Send + Sync {
+    /// Whether `key` exists in `namespace`
+    async fn exists(&self, namespace: &str, key: &str) -> bool;
+}
+
+/// In-memory [`ReferenceResolver`] over a fixed set of keys
+#[derive(Debug, Clone, Default)]
+pub struct MapReferenceResolver {
+    keys: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl MapReferenceResolver {
+    /// Create a resolver with no keys
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a key that references may resolve to
+    pub fn with_key(mut self, namespace: impl Into<String>, key: impl Into<String>) -> Self {
+        self.insert(namespace, key);
+        self
+    }
+
+    /// Add a key that references may resolve to
+    pub fn insert(&mut self, namespace: impl Into<String>, key: impl Into<String>) {
+        self.keys.entry(namespace.into()).or_default().insert(key.into());
+    }
+}
+
+#[async_trait]
+impl ReferenceResolver for MapReferenceResolver {
+    async fn exists(&self, namespace: &str, key: &str) -> bool {
+        self.keys
+            .get(namespace)
+            .is_some_and(|keys| keys.contains(key))
+    }
+}
+
+/// A reference constraint on a field
+struct FieldReference {
+    field_path: String,
+    namespace: Option<String>,
+    key_pattern: String,
+    /// The compiled key pattern, or why it failed to compile
+    compiled: Result<Regex, String>,
+}
+
+/// Rule for validating that values reference existing config keys
+pub struct ReferenceRule {
+    id: String,
+    name: String,
+    /// Reference constraints in the order they were added
+    references: Vec<FieldReference>,
+    severity: Severity,
+}
+
+impl ReferenceRule {
+    /// Create a reference rule with no constraints
+    pub fn new() -> Self {
+        Self {
+            id: "reference_check".to_string(),
+            name: "Reference Validation".to_string(),
+            references: Vec::new(),
+            severity: Severity::Error,
+        }
+    }
+
+    /// Create a rule enforcing every reference constraint in a schema
+    ///
+    /// Nested fields are addressed by dotted path; fields are visited in path
+    /// order so the findings are too.
+    pub fn from_schema(schema: &ConfigSchema) -> Self {
+        fn walk(fields: &HashMap<String, FieldRule>, prefix: &str, rule: &mut ReferenceRule) {
+            let mut keys: Vec<&String> = fields.keys().collect();
+            keys.sort();
+            for key in keys {
+                let field = &fields[key];
+                let path = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", prefix, key)
+                };
+                for constraint in &field.constraints {
+                    rule.add_constraint(&path, constraint);
+                }
+                walk(&field.nested_fields, &path, rule);
+            }
+        }
+
+        let mut rule = Self::new();
+        walk(&schema.fields, "", &mut rule);
+        rule
+    }
+
+    /// Enforce a constraint on a field; constraints other than
+    /// `ValidationConstraint::Reference` are ignored
+    pub fn with_constraint(
+        mut self,
+        field_path: impl Into<String>,
+        constraint: &ValidationConstraint,
+    ) -> Self {
+        self.add_constraint(&field_path.into(), constraint);
+        self
+    }
+
+    /// Set the severity level
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    /// Number of reference constraints enforced
+    pub fn len(&self) -> usize {
+        self.references.len()
+    }
+
+    /// Whether the rule has no reference constraints
+    pub fn is_empty(&self) -> bool {
+        self.references.is_empty()
+    }
+
+    fn add_constraint(&mut self, field_path: &str, constraint: &ValidationConstraint) {
+        if let ValidationConstraint::Reference { namespace, key_pattern } = constraint {
+            self.references.push(FieldReference {
+                field_path: field_path.to_string(),
+                namespace: namespace.clone(),
+                key_pattern: key_pattern.clone(),
+                compiled: Regex::new(key_pattern).map_err(|e| e.to_string()),
+            });
+        }
+    }
+
+    fn get_value_at_path<'a>(&self, value: &'a ConfigValue, path: &str) -> Option<&'a ConfigValue> {
+        path.split('.').try_fold(value, |current, key| match current {
+            ConfigValue::Object(map) => map.get(key),
+            _ => None,
+        })
+    }
+
+    /// Check every reference, resolving keys through `resolver` if given
+    async fn check(
+        &self,
+        value: &ConfigValue,
+        path: &str,
+        context: &RuleContext,
+        resolver: Option<&dyn ReferenceResolver>,
+    ) -> Vec<ValidationFinding> {
+        let mut findings = Vec::new();
+
+        for reference in &self.references {
+            let full_path = if path.is_empty() {
+                reference.field_path.clone()
+            } else {
+                format!("{}.{}", path, reference.field_path)
+            };
+
+            let pattern = match &reference.compiled {
+                Ok(pattern) => pattern,
+                Err(error) => {
+                    findings.push(
+                        ValidationFinding::new(
+                            &self.id,
+                            RuleCategory::Required,
+                            Severity::Info,
+                            format!(
+                                "Key pattern '{}' does not compile and was not checked: {}",
+                                reference.key_pattern, error
+                            ),
+                            &full_path,
+                        )
+                        .with_suggestion("Fix the key pattern in the schema")
+                        .with_context(serde_json::json!({
+                            "code": INVALID_PATTERN,
+                            "pattern": reference.key_pattern,
+                        })),
+                    );
+                    continue;
+                }
+            };
+
+            let Some(ConfigValue::String(key)) =
+                self.get_value_at_path(value, &reference.field_path)
+            else {
+                continue;
+            };
+            let namespace = reference.namespace.as_deref().unwrap_or(&context.namespace);
+            let target = serde_json::json!({ "namespace": namespace, "key": key });
+
+            if !pattern.is_match(key) {
+                findings.push(
+                    ValidationFinding::new(
+                        &self.id,
+                        RuleCategory::Required,
+                        self.severity,
+                        format!(
+                            "Reference '{}' does not match key pattern '{}'",
+                            key, reference.key_pattern
+                        ),
+                        &full_path,
+                    )
+                    .with_expected(format!("must match pattern: {}", reference.key_pattern))
+                    .with_actual(key.clone())
+                    .with_context(serde_json::json!({
+                        "code": INVALID_REFERENCE,
+                        "pattern": reference.key_pattern,
+                        "target": target,
+                    })),
+                );
+                continue;
+            }
+
+            let Some(resolver) = resolver else {
+                findings.push(
+                    ValidationFinding::new(
+                        &self.id,
+                        RuleCategory::Required,
+                        Severity::Warning,
+                        format!(
+                            "Reference '{}' in namespace '{}' was not verified: no resolver",
+                            key, namespace
+                        ),
+                        &full_path,
+                    )
+                    .with_suggestion("Validate with a reference resolver to check the target exists")
+                    .with_context(serde_json::json!({
+                        "code": REFERENCE_UNVERIFIED,
+                        "target": target,
+                    })),
+                );
+                continue;
+            };
+
+            if !resolver.exists(namespace, key).await {
+                findings.push(
+                    ValidationFinding::new(
+                        &self.id,
+                        RuleCategory::Required,
+                        self.severity,
+                        format!(
+                            "Reference '{}' does not resolve in namespace '{}'",
+                            key, namespace
+                        ),
+                        &full_path,
+                    )
+                    .with_expected(format!("{}/{}", namespace, key))
+                    .with_suggestion("Create the referenced key or correct the reference")
+                    .with_context(serde_json::json!({
+                        "code": REFERENCE_NOT_FOUND,
+                        "target": target,
+                    })),
+                );
+            }
+        }
+
+        findings
+    }
+}
+
+impl Default for ReferenceRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Rule for ReferenceRule {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn description(&self) -> &str {
+        "Validates that values reference existing config keys"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Required
+    }
+
+    fn default_severity(&self) -> Severity {
+        self.severity
+    }
+
+    async fn evaluate(
+        &self,
+        value: &ConfigValue,
+        path: &str,
+        context: &RuleContext,
+    ) -> Vec<ValidationFinding> {
+        self.check(value, path, context, None).await
+    }
+
+    async fn evaluate_with_resolver(
+        &self,
+        value: &ConfigValue,
+        path: &str,
+        context: &RuleContext,
+        resolver: &dyn ReferenceResolver,
+    ) -> Vec<ValidationFinding> {
+        self.check(value, path, context, Some(resolver)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Environment;
+
+    fn config(pairs: &[(&str, &str)]) -> ConfigValue {
+        ConfigValue::Object(
+            pairs
+                .iter()
+                .map(|(k, v)| (k.to_string(), ConfigValue::String(v.to_string())))
+                .collect(),
+        )
+    }
+
+    fn reference(namespace: Option<&str>, key_pattern: &str) -> ValidationConstraint {
+        ValidationConstraint::Reference {
+            namespace: namespace.map(str::to_string),
+            key_pattern: key_pattern.to_string(),
+        }
+    }
+
+    fn code(finding: &ValidationFinding) -> &str {
+        finding.context.as_ref().unwrap()["code"].as_str().unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_missing_reference_is_reported() {
+        let rule = ReferenceRule::new()
+            .with_constraint("database", &reference(Some("shared/db"), "^[a-z_]+$"))
+            .with_constraint("cache", &reference(None, ".*"));
+        let resolver = MapReferenceResolver::new()
+            .with_key("shared/db", "primary")
+            .with_key("app", "redis");
+        let context = RuleContext::new(Environment::Production, "app");
+
+        let ok = config(&[("database", "primary"), ("cache", "redis")]);
+        assert!(rule
+            .evaluate_with_resolver(&ok, "", &context, &resolver)
+            .await
+            .is_empty());
+
+        let dangling = config(&[("database", "replica"), ("cache", "redis")]);
+        let findings = rule.evaluate_with_resolver(&dangling, "", &context, &resolver).await;
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].field_path, "database");
+        assert_eq!(findings[0].severity, Severity::Error);
+        assert_eq!(code(&findings[0]), REFERENCE_NOT_FOUND);
+        assert_eq!(findings[0].expected.as_deref(), Some("shared/db/replica"));
+    }
+
+    #[tokio::test]
+    async fn test_references_without_resolver_are_warnings() {
+        let rule = ReferenceRule::new().with_constraint("database", &reference(None, ".*"));
+        let context = RuleContext::new(Environment::Production, "app");
+
+        let findings = rule.evaluate(&config(&[("database", "primary")]), "", &context).await;
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].severity, Severity::Warning);
+        assert_eq!(code(&findings[0]), REFERENCE_UNVERIFIED);
+        assert_eq!(findings[0].context.as_ref().unwrap()["target"]["namespace"], "app");
+    }
+
+    #[tokio::test]
+    async fn test_key_pattern_is_checked_before_lookup() {
+        let rule = ReferenceRule::new()
+            .with_constraint("database", &reference(None, "^[a-z]+$"))
+            .with_constraint("cache", &reference(None, "(unclosed"));
+        let resolver = MapReferenceResolver::new().with_key("app", "Primary");
+        let context = RuleContext::new(Environment::Development, "app");
+
+        let findings = rule
+            .evaluate_with_resolver(
+                &config(&[("database", "Primary"), ("cache", "redis")]),
+                "",
+                &context,
+                &resolver,
+            )
+            .await;
+        let codes: Vec<_> = findings.iter().map(|f| (f.field_path.as_str(), code(f))).collect();
+        assert_eq!(
+            codes,
+            vec![("database", INVALID_REFERENCE), ("cache", INVALID_PATTERN)]
+        );
+        assert_eq!(findings[1].severity, Severity::Info);
+    }
+
+    #[tokio::test]
+    async fn test_absent_and_non_string_values_are_skipped() {
+        let rule = ReferenceRule::new().with_constraint("database", &reference(None, ".*"));
+        let context = RuleContext::new(Environment::Development, "app");
+        let resolver = MapReferenceResolver::new();
+
+        let absent = config(&[]);
+        assert!(rule.evaluate_with_resolver(&absent, "", &context, &resolver).await.is_empty());
+        let number = ConfigValue::Object(
+            [("database".to_string(), ConfigValue::Integer(1))].into_iter().collect(),
+        );
+        assert!(rule.evaluate_with_resolver(&number, "", &context, &resolver).await.is_empty());
+    }
+}
@@ -0,0 +1,310 @@
+//! Secret reference validation rules
+//!
+//! This module provides a rule that verifies secrets referenced from a
+//! configuration actually exist in a [`SecretProvider`], catching dangling
+//! references before deployment.
+//!
+//! Two reference forms are recognized inside string values:
+//!
+//! - `${SECRET_NAME}` - resolved in the namespace being validated
+//! - `secret://namespace/key` (or `secret://key`) - resolved in the given
+//!   namespace, or the validated namespace when omitted
+//!
+//! Only existence is checked; secret values are never read into findings.
+
+use async_trait::async_trait;
+use llm_config_core::providers::{ProviderError, SecretProvider};
+use regex::Regex;
+use std::sync::{Arc, OnceLock};
+
+use super::{Rule, RuleCategory, RuleContext, Severity, ValidationFinding};
+use crate::ConfigValue;
+
+/// Scheme prefix for URI-style secret references
+pub const SECRET_URI_SCHEME: &str = "secret://";
+
+fn placeholder_pattern() -> &'static Regex {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    PATTERN.get_or_init(|| Regex::new(r"\$\{([A-Za-z_][A-Za-z0-9_]*)\}").unwrap())
+}
+
+/// A secret reference found in a configuration value
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SecretReference {
+    /// Reference as written in the configuration
+    pub raw: String,
+    /// Namespace the secret is looked up in
+    pub namespace: String,
+    /// Secret key name
+    pub key: String,
+}
+
+/// Extract secret references from a string value
+///
+/// References without an explicit namespace resolve in `default_namespace`.
+pub fn extract_secret_references(value: &str, default_namespace: &str) -> Vec<SecretReference> {
+    let mut refs: Vec<SecretReference> = placeholder_pattern()
+        .captures_iter(value)
+        .map(|caps| SecretReference {
+            raw: caps[0].to_string(),
+            namespace: default_namespace.to_string(),
+            key: caps[1].to_string(),
+        })
+        .collect();
+
+    if let Some(target) = value.trim().strip_prefix(SECRET_URI_SCHEME) {
+        let (namespace, key) = match target.rsplit_once('/') {
+            Some((namespace, key)) => (namespace, key),
+            None => (default_namespace, target),
+        };
+        if !key.is_empty() {
+            refs.push(SecretReference {
+                raw: value.trim().to_string(),
+                namespace: namespace.to_string(),
+                key: key.to_string(),
+            });
+        }
+    }
+
+    refs
+}
+
+/// Rule verifying that every referenced secret resolves in a provider
+pub struct SecretReferenceRule {
+    id: String,
+    name: String,
+    description: String,
+    provider: Arc<dyn SecretProvider>,
+}
+
+impl SecretReferenceRule {
+    /// Create a new rule backed by the given secret provider
+    pub fn new(provider: Arc<dyn SecretProvider>) -> Self {
+        Self {
+            id: "secret_reference_check".to_string(),
+            name: "Secret Reference Resolution".to_string(),
+            description: "Verifies that referenced secrets exist in the secret provider".to_string(),
+            provider,
+        }
+    }
+
+    fn collect_references(
+        value: &ConfigValue,
+        path: &str,
+        namespace: &str,
+        refs: &mut Vec<(String, SecretReference)>,
+    ) {
+        match value {
+            ConfigValue::String(s) => {
+                for reference in extract_secret_references(s, namespace) {
+                    refs.push((path.to_string(), reference));
+                }
+            }
+            ConfigValue::Object(obj) => {
+                for (key, child) in obj {
+                    let child_path = if path.is_empty() {
+                        key.clone()
+                    } else {
+                        format!("{}.{}", path, key)
+                    };
+                    Self::collect_references(child, &child_path, namespace, refs);
+                }
+            }
+            ConfigValue::Array(items) => {
+                for (index, child) in items.iter().enumerate() {
+                    let child_path = format!("{}[{}]", path, index);
+                    Self::collect_references(child, &child_path, namespace, refs);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[async_trait]
+impl Rule for SecretReferenceRule {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn description(&self) -> &str {
+        &self.description
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Security
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    async fn evaluate(
+        &self,
+        value: &ConfigValue,
+        path: &str,
+        context: &RuleContext,
+    ) -> Vec<ValidationFinding> {
+        let mut refs = Vec::new();
+        Self::collect_references(value, path, &context.namespace, &mut refs);
+
+        let mut findings = Vec::new();
+        for (field_path, reference) in refs {
+            match self.provider.exists(&reference.namespace, &reference.key).await {
+                Ok(true) => {}
+                Ok(false) | Err(ProviderError::NotFound { .. }) => {
+                    findings.push(
+                        ValidationFinding::new(
+                            &self.id,
+                            RuleCategory::Security,
+                            self.default_severity(),
+                            format!(
+                                "Secret reference '{}' does not resolve in provider '{}'",
+                                reference.raw,
+                                self.provider.name()
+                            ),
+                            field_path,
+                        )
+                        .with_expected(format!("{}/{}", reference.namespace, reference.key))
+                        .with_suggestion("Create the secret or correct the reference before deploying"),
+                    );
+                }
+                Err(e) => {
+                    findings.push(
+                        ValidationFinding::new(
+                            &self.id,
+                            RuleCategory::Security,
+                            Severity::Warning,
+                            format!(
+                                "Secret reference '{}' could not be verified: {}",
+                                reference.raw, e
+                            ),
+                            field_path,
+                        ),
+                    );
+                }
+            }
+        }
+
+        findings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Environment;
+    use llm_config_core::providers::traits::{ConfigProvider, ProviderResult, ProviderValue, ValueMetadata};
+    use std::collections::HashMap;
+    use std::sync::RwLock;
+
+    /// In-memory secret provider keyed by `namespace/key`
+    #[derive(Debug, Default)]
+    struct MemorySecretProvider {
+        secrets: RwLock<HashMap<String, String>>,
+    }
+
+    #[async_trait]
+    impl ConfigProvider for MemorySecretProvider {
+        fn name(&self) -> &str {
+            "memory"
+        }
+
+        async fn get(&self, namespace: &str, key: &str) -> ProviderResult<ProviderValue> {
+            self.secrets
+                .read()
+                .unwrap()
+                .get(&format!("{}/{}", namespace, key))
+                .map(|v| ProviderValue::secret(v.clone(), "memory"))
+                .ok_or_else(|| ProviderError::NotFound {
+                    namespace: namespace.to_string(),
+                    key: key.to_string(),
+                })
+        }
+    }
+
+    #[async_trait]
+    impl SecretProvider for MemorySecretProvider {
+        async fn set_secret(&self, namespace: &str, key: &str, value: &str) -> ProviderResult<ValueMetadata> {
+            self.secrets
+                .write()
+                .unwrap()
+                .insert(format!("{}/{}", namespace, key), value.to_string());
+            Ok(ValueMetadata {
+                source: "memory".to_string(),
+                is_secret: true,
+                ..Default::default()
+            })
+        }
+
+        async fn delete_secret(&self, namespace: &str, key: &str) -> ProviderResult<()> {
+            self.secrets.write().unwrap().remove(&format!("{}/{}", namespace, key));
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_extract_secret_references() {
+        let refs = extract_secret_references("postgres://app:${DB_PASSWORD}@db/app", "app");
+        assert_eq!(refs.len(), 1);
+        assert_eq!(refs[0].key, "DB_PASSWORD");
+        assert_eq!(refs[0].namespace, "app");
+
+        let refs = extract_secret_references("secret://shared/api-token", "app");
+        assert_eq!(refs[0].namespace, "shared");
+        assert_eq!(refs[0].key, "api-token");
+
+        assert!(extract_secret_references("plain value", "app").is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_dangling_reference_is_reported_without_value() {
+        let provider = Arc::new(MemorySecretProvider::default());
+        provider.set_secret("app", "DB_PASSWORD", "hunter2").await.unwrap();
+        let rule = SecretReferenceRule::new(provider);
+
+        let config = ConfigValue::Object(
+            [
+                ("database_url".to_string(), ConfigValue::String("postgres://app:${DB_PASSWORD}@db".to_string())),
+                ("api_key".to_string(), ConfigValue::String("secret://app/MISSING_KEY".to_string())),
+            ]
+            .into_iter()
+            .collect(),
+        );
+        let context = RuleContext::new(Environment::Production, "app");
+
+        let findings = rule.evaluate(&config, "", &context).await;
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].field_path, "api_key");
+        assert_eq!(findings[0].severity, Severity::Error);
+        assert!(findings[0].message.contains("MISSING_KEY"));
+        assert!(findings.iter().all(|f| !f.to_string().contains("hunter2")));
+    }
+
+    #[tokio::test]
+    async fn test_engine_with_secret_provider_checks_references() {
+        let provider = Arc::new(MemorySecretProvider::default());
+        provider.set_secret("app", "DB_PASSWORD", "hunter2").await.unwrap();
+        let engine = crate::engine::ValidationEngine::empty().with_secret_provider(provider);
+        assert!(engine.rule("secret_reference_check").is_some());
+
+        let config = ConfigValue::Object(
+            [
+                ("password".to_string(), ConfigValue::String("${DB_PASSWORD}".to_string())),
+                ("token".to_string(), ConfigValue::String("${API_TOKEN}".to_string())),
+            ]
+            .into_iter()
+            .collect(),
+        );
+
+        let result = engine.validate(&config, Environment::Production, "app").await;
+        assert!(!result.is_valid);
+        assert_eq!(result.findings.len(), 1);
+        assert_eq!(result.findings[0].rule_id, "secret_reference_check");
+        assert_eq!(result.findings[0].field_path, "token");
+    }
+}
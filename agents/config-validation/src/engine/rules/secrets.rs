@@ -0,0 +1,362 @@
+//! Plaintext secret detection
+//!
+//! This module provides a rule reporting sensitive fields that hold their
+//! value in plaintext. A field is checked when its `FieldRule` is marked
+//! `sensitive`, and also in the environments of any
+//! `EnvironmentRuleType::MustEncrypt` rule naming it.
+//!
+//! Sensitive fields may hold a `ConfigValue::Secret`, an encrypted `enc:`
+//! string, or a reference to a secret (`${NAME}` or `secret://...`) that is
+//! resolved at deploy time. Fields under a must-encrypt rule must be
+//! encrypted: references do not satisfy it. Values never appear in findings.
+
+use async_trait::async_trait;
+use std::collections::{BTreeMap, HashMap};
+
+use super::secret_refs::extract_secret_references;
+use super::{Rule, RuleCategory, RuleContext, Severity, ValidationFinding};
+use crate::contracts::schemas::EnvironmentRuleType;
+use crate::contracts::{ConfigSchema, FieldRule};
+use crate::environments::ENCRYPTED_PREFIX;
+use crate::{ConfigValue, Environment};
+
+/// Code carried in the context of findings for plaintext secrets
+pub const PLAINTEXT_SECRET: &str = "PLAINTEXT_SECRET";
+
+/// A must-encrypt environment rule covering a field
+#[derive(Debug, Clone)]
+struct MustEncrypt {
+    rule_id: String,
+    environments: Vec<Environment>,
+    severity: Severity,
+}
+
+/// What is required of one field
+#[derive(Debug, Clone, Default)]
+struct SecretField {
+    sensitive: bool,
+    must_encrypt: Vec<MustEncrypt>,
+}
+
+/// Rule for detecting sensitive fields holding plaintext values
+pub struct SecretRule {
+    id: String,
+    name: String,
+    /// Checked fields by dotted path
+    fields: BTreeMap<String, SecretField>,
+}
+
+impl SecretRule {
+    /// Create a secret rule with no fields
+    pub fn new() -> Self {
+        Self {
+            id: "secret_check".to_string(),
+            name: "Plaintext Secret Detection".to_string(),
+            fields: BTreeMap::new(),
+        }
+    }
+
+    /// Create a rule checking every sensitive field of a schema and every
+    /// field named by its must-encrypt environment rules
+    ///
+    /// Nested fields are addressed by dotted path. Must-encrypt rules are
+    /// reported as critical when blocking and as warnings otherwise.
+    pub fn from_schema(schema: &ConfigSchema) -> Self {
+        fn walk(fields: &HashMap<String, FieldRule>, prefix: &str, rule: &mut SecretRule) {
+            for (key, field) in fields {
+                let path = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", prefix, key)
+                };
+                if field.sensitive {
+                    rule.add_sensitive(&path);
+                }
+                walk(&field.nested_fields, &path, rule);
+            }
+        }
+
+        let mut rule = Self::new();
+        walk(&schema.fields, "", &mut rule);
+        for env_rule in &schema.environment_rules {
+            if !matches!(env_rule.rule_type, EnvironmentRuleType::MustEncrypt) {
+                continue;
+            }
+            let environments: Vec<Environment> = env_rule
+                .environments
+                .iter()
+                .filter_map(|name| name.parse().ok())
+                .collect();
+            let severity = if env_rule.blocking {
+                Severity::Critical
+            } else {
+                Severity::Warning
+            };
+            for field in &env_rule.affected_fields {
+                rule = rule.with_must_encrypt(field, &env_rule.id, environments.clone(), severity);
+            }
+        }
+        rule
+    }
+
+    /// Check that a field never holds a plaintext value
+    pub fn with_sensitive_field(mut self, field_path: impl Into<String>) -> Self {
+        self.add_sensitive(&field_path.into());
+        self
+    }
+
+    /// Require a field to be encrypted in some environments, reporting
+    /// plaintext values at `severity`
+    pub fn with_must_encrypt(
+        mut self,
+        field_path: &str,
+        rule_id: impl Into<String>,
+        environments: Vec<Environment>,
+        severity: Severity,
+    ) -> Self {
+        let path = field_path.strip_prefix("$.").unwrap_or(field_path);
+        self.fields
+            .entry(path.to_string())
+            .or_default()
+            .must_encrypt
+            .push(MustEncrypt {
+                rule_id: rule_id.into(),
+                environments,
+                severity,
+            });
+        self
+    }
+
+    /// Number of fields checked
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    /// Whether the rule checks no fields
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    fn add_sensitive(&mut self, field_path: &str) {
+        self.fields.entry(field_path.to_string()).or_default().sensitive = true;
+    }
+
+    fn get_value_at_path<'a>(&self, value: &'a ConfigValue, path: &str) -> Option<&'a ConfigValue> {
+        path.split('.').try_fold(value, |current, key| match current {
+            ConfigValue::Object(map) => map.get(key),
+            _ => None,
+        })
+    }
+}
+
+impl Default for SecretRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Whether a value is encrypted: a secret, or an `enc:` string
+fn is_encrypted(value: &ConfigValue) -> bool {
+    match value {
+        ConfigValue::Secret(_) => true,
+        ConfigValue::String(s) => s.starts_with(ENCRYPTED_PREFIX),
+        _ => false,
+    }
+}
+
+/// Whether a string only refers to a secret held elsewhere
+fn is_secret_reference(value: &str) -> bool {
+    let references = extract_secret_references(value, "");
+    references.len() == 1 && references[0].raw == value.trim()
+}
+
+#[async_trait]
+impl Rule for SecretRule {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn description(&self) -> &str {
+        "Detects sensitive fields holding plaintext instead of encrypted values"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Security
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Critical
+    }
+
+    async fn evaluate(
+        &self,
+        value: &ConfigValue,
+        path: &str,
+        context: &RuleContext,
+    ) -> Vec<ValidationFinding> {
+        let mut findings = Vec::new();
+
+        for (field_path, field) in &self.fields {
+            // Missing fields are left to the required rules
+            let Some(field_value) = self.get_value_at_path(value, field_path) else {
+                continue;
+            };
+            if is_encrypted(field_value) {
+                continue;
+            }
+
+            let must_encrypt = field
+                .must_encrypt
+                .iter()
+                .filter(|rule| rule.environments.contains(&context.environment))
+                .max_by_key(|rule| rule.severity);
+            let plaintext_sensitive = field.sensitive
+                && matches!(field_value, ConfigValue::String(s) if !is_secret_reference(s));
+            let severity = match (plaintext_sensitive, must_encrypt) {
+                (true, _) => Severity::Critical,
+                (false, Some(rule)) => rule.severity,
+                (false, None) => continue,
+            };
+
+            let full_path = if path.is_empty() {
+                field_path.clone()
+            } else {
+                format!("{}.{}", path, field_path)
+            };
+            let (message, mut details) = match must_encrypt {
+                Some(rule) => (
+                    format!(
+                        "Field '{}' must be encrypted in {} but holds a plaintext value",
+                        full_path, context.environment
+                    ),
+                    serde_json::json!({ "code": PLAINTEXT_SECRET, "environment_rule": rule.rule_id }),
+                ),
+                None => (
+                    format!("Sensitive field '{}' holds a plaintext value", full_path),
+                    serde_json::json!({ "code": PLAINTEXT_SECRET }),
+                ),
+            };
+            details["sensitive"] = serde_json::json!(field.sensitive);
+
+            findings.push(
+                ValidationFinding::new(&self.id, RuleCategory::Security, severity, message, &full_path)
+                    .with_expected("an encrypted secret")
+                    .with_actual("a plaintext value")
+                    .with_suggestion(format!(
+                        "Store the value as a secret or an {}... encrypted value",
+                        ENCRYPTED_PREFIX
+                    ))
+                    .with_context(details),
+            );
+        }
+
+        findings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contracts::{EnvironmentRule, FieldType};
+
+    fn object(pairs: Vec<(&str, ConfigValue)>) -> ConfigValue {
+        ConfigValue::Object(pairs.into_iter().map(|(k, v)| (k.to_string(), v)).collect())
+    }
+
+    fn string(s: &str) -> ConfigValue {
+        ConfigValue::String(s.to_string())
+    }
+
+    fn secret() -> ConfigValue {
+        ConfigValue::Secret(
+            serde_json::from_value(serde_json::json!({
+                "algorithm": "aes-256-gcm",
+                "nonce": "000102030405060708090a0b",
+                "ciphertext": "deadbeef",
+            }))
+            .unwrap(),
+        )
+    }
+
+    fn schema() -> ConfigSchema {
+        let mut database = FieldRule::new(FieldType::Object);
+        database
+            .nested_fields
+            .insert("password".to_string(), FieldRule::new(FieldType::String).sensitive());
+        database
+            .nested_fields
+            .insert("host".to_string(), FieldRule::new(FieldType::String));
+        ConfigSchema::new("app/service", "Service", "1.0.0")
+            .with_field("database", database)
+            .with_field("api_token", FieldRule::new(FieldType::String))
+            .with_environment_rule(EnvironmentRule::must_encrypt(
+                "encrypt-token",
+                vec!["production".to_string()],
+                vec!["api_token".to_string()],
+            ))
+    }
+
+    #[tokio::test]
+    async fn test_plaintext_sensitive_field_is_critical() {
+        let rule = SecretRule::from_schema(&schema());
+        assert_eq!(rule.len(), 2);
+        let context = RuleContext::new(Environment::Development, "app/service");
+
+        let config = object(vec![(
+            "database",
+            object(vec![("password", string("hunter2")), ("host", string("db.internal"))]),
+        )]);
+        let findings = rule.evaluate(&config, "", &context).await;
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].field_path, "database.password");
+        assert_eq!(findings[0].severity, Severity::Critical);
+        assert_eq!(findings[0].context.as_ref().unwrap()["code"], PLAINTEXT_SECRET);
+        assert!(!findings[0].message.contains("hunter2"));
+
+        // Secrets, encrypted strings and references are all accepted
+        for password in [secret(), string("enc:AAAA"), string("${DB_PASSWORD}")] {
+            let config = object(vec![("database", object(vec![("password", password)]))]);
+            assert!(rule.evaluate(&config, "", &context).await.is_empty());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_must_encrypt_applies_in_its_environments() {
+        let rule = SecretRule::from_schema(&schema());
+        let config = object(vec![("api_token", string("tok_live_123"))]);
+
+        // Not sensitive, so plaintext is fine outside production
+        let development = RuleContext::new(Environment::Development, "app/service");
+        assert!(rule.evaluate(&config, "", &development).await.is_empty());
+
+        let production = RuleContext::new(Environment::Production, "app/service");
+        let findings = rule.evaluate(&config, "", &production).await;
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].severity, Severity::Critical);
+        assert_eq!(
+            findings[0].context.as_ref().unwrap()["environment_rule"],
+            "encrypt-token"
+        );
+
+        // A reference is not encrypted
+        let config = object(vec![("api_token", string("${API_TOKEN}"))]);
+        assert_eq!(rule.evaluate(&config, "", &production).await.len(), 1);
+        let config = object(vec![("api_token", secret())]);
+        assert!(rule.evaluate(&config, "", &production).await.is_empty());
+
+        // Non-blocking must-encrypt rules only warn
+        let rule = SecretRule::new().with_must_encrypt(
+            "$.api_token",
+            "encrypt-token",
+            vec![Environment::Production],
+            Severity::Warning,
+        );
+        let config = object(vec![("api_token", string("tok_live_123"))]);
+        let findings = rule.evaluate(&config, "", &production).await;
+        assert_eq!(findings[0].severity, Severity::Warning);
+    }
+}
@@ -6,7 +6,9 @@
 use async_trait::async_trait;
 use std::collections::HashMap;
 
+use super::formats::{parse_duration, DURATION_GRAMMAR, INVALID_DURATION};
 use super::{Rule, RuleCategory, RuleContext, Severity, ValidationFinding};
+use crate::contracts::FieldType;
 use crate::ConfigValue;
 
 /// Expected type specification for a field
@@ -16,6 +18,7 @@ pub enum ExpectedType {
     Integer,
     Float,
     Boolean,
+    Duration, // Go-style duration string, see `formats::parse_duration`
     Array(Option<Box<ExpectedType>>), // Optional inner type
     Object(Option<HashMap<String, ExpectedType>>), // Optional schema
     OneOf(Vec<ExpectedType>), // Union type
@@ -23,6 +26,32 @@ pub enum ExpectedType {
 }
 
 impl ExpectedType {
+    /// Get the expected type for a schema field type
+    ///
+    /// String-encoded types other than durations (URLs, emails, timestamps
+    /// and so on) are checked as plain strings. URL, email and IP address
+    /// formats are checked by [`super::formats::FormatRule`]; others are left
+    /// to [`StringFormatRule`].
+    pub fn from_field_type(field_type: &FieldType) -> Self {
+        match field_type {
+            FieldType::String
+            | FieldType::Url
+            | FieldType::Email
+            | FieldType::IpAddress
+            | FieldType::FilePath
+            | FieldType::Regex
+            | FieldType::Json
+            | FieldType::Timestamp => ExpectedType::String,
+            FieldType::Integer => ExpectedType::Integer,
+            FieldType::Float => ExpectedType::Float,
+            FieldType::Boolean => ExpectedType::Boolean,
+            FieldType::Duration => ExpectedType::Duration,
+            FieldType::Array => ExpectedType::Array(None),
+            FieldType::Object => ExpectedType::Object(None),
+            FieldType::Secret | FieldType::Any => ExpectedType::Any,
+        }
+    }
+
     /// Get a human-readable name for this type
     pub fn type_name(&self) -> String {
         match self {
@@ -30,6 +59,7 @@ impl ExpectedType {
             ExpectedType::Integer => "integer".to_string(),
             ExpectedType::Float => "float".to_string(),
             ExpectedType::Boolean => "boolean".to_string(),
+            ExpectedType::Duration => "duration".to_string(),
             ExpectedType::Array(inner) => {
                 if let Some(t) = inner {
                     format!("array<{}>", t.type_name())
@@ -55,6 +85,7 @@ impl ExpectedType {
             (ExpectedType::Float, ConfigValue::Float(_)) => true,
             (ExpectedType::Float, ConfigValue::Integer(_)) => true, // Allow int where float expected
             (ExpectedType::Boolean, ConfigValue::Boolean(_)) => true,
+            (ExpectedType::Duration, ConfigValue::String(s)) => parse_duration(s).is_ok(),
             (ExpectedType::Array(inner), ConfigValue::Array(arr)) => {
                 if let Some(inner_type) = inner {
                     arr.iter().all(|v| inner_type.matches(v))
@@ -191,6 +222,24 @@ impl Rule for TypeCheckRule {
             };
 
             if let Some(field_value) = self.get_value_at_path(value, field_path) {
+                if let (ExpectedType::Duration, ConfigValue::String(s)) = (expected_type, field_value) {
+                    if let Err(error) = parse_duration(s) {
+                        findings.push(
+                            ValidationFinding::new(
+                                &self.id,
+                                RuleCategory::Type,
+                                Severity::Error,
+                                format!("Invalid duration '{}': {}", s, error),
+                                &full_path,
+                            )
+                            .with_expected("duration")
+                            .with_actual(s.clone())
+                            .with_suggestion(format!("Write the duration as {}", DURATION_GRAMMAR))
+                            .with_context(serde_json::json!({ "code": INVALID_DURATION })),
+                        );
+                    }
+                    continue;
+                }
                 if !expected_type.matches(field_value) {
                     findings.push(
                         ValidationFinding::new(
@@ -268,6 +317,23 @@ impl StringFormat {
         }
     }
 
+    /// Look up the format for a JSON Schema `format` keyword value
+    ///
+    /// Returns `None` for formats without a validator, which JSON Schema
+    /// treats as annotations only.
+    pub fn from_schema_format(format: &str) -> Option<Self> {
+        match format {
+            "email" => Some(StringFormat::Email),
+            "uri" | "url" => Some(StringFormat::Url),
+            "ipv4" => Some(StringFormat::Ipv4),
+            "ipv6" => Some(StringFormat::Ipv6),
+            "uuid" => Some(StringFormat::Uuid),
+            "date" => Some(StringFormat::Date),
+            "date-time" => Some(StringFormat::DateTime),
+            _ => None,
+        }
+    }
+
     /// Check if a string matches this format
     pub fn matches(&self, value: &str) -> bool {
         match self {
@@ -515,6 +581,32 @@ mod tests {
         assert!(findings[0].message.contains("Type mismatch"));
     }
 
+    #[tokio::test]
+    async fn test_invalid_duration_is_reported() {
+        let rule = TypeCheckRule::new("type_003", "Type Check")
+            .expect_type("timeout", ExpectedType::from_field_type(&FieldType::Duration))
+            .expect_type("interval", ExpectedType::Duration)
+            .expect_type("retry", ExpectedType::Duration);
+
+        let mut obj = HashMap::new();
+        obj.insert("timeout".to_string(), ConfigValue::String("5 potatoes".to_string()));
+        obj.insert("interval".to_string(), ConfigValue::String("2h30m".to_string()));
+        obj.insert("retry".to_string(), ConfigValue::Integer(5));
+        let value = ConfigValue::Object(obj);
+
+        let mut findings = rule.evaluate(&value, "", &make_context()).await;
+        findings.sort_by(|a, b| a.field_path.cmp(&b.field_path));
+        assert_eq!(findings.len(), 2);
+
+        assert_eq!(findings[0].field_path, "retry");
+        assert!(findings[0].message.contains("Type mismatch"));
+
+        assert_eq!(findings[1].field_path, "timeout");
+        assert_eq!(findings[1].context.as_ref().unwrap()["code"], INVALID_DURATION);
+        assert!(findings[1].message.contains("unknown unit"));
+        assert!(findings[1].suggestion.as_ref().unwrap().contains("2h30m"));
+    }
+
     #[test]
     fn test_string_formats() {
         assert!(StringFormat::Email.matches("test@example.com"));
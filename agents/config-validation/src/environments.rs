@@ -0,0 +1,411 @@
+//! Validation across a set of per-environment configurations
+//!
+//! Resolves the effective configuration of every environment from its
+//! layers (base plus the environment's overrides), validates each one, and
+//! checks the schema's cross-environment rules in the same pass:
+//!
+//! - **MustDiffer**: a field must not share its value with the listed
+//!   environments (e.g. production must not reuse a development secret).
+//! - **MustEncrypt**: a field must hold an encrypted (`enc:`) value.
+//!
+//! Cross-environment rules are read from the schema's top-level
+//! `environment_rules` array, in the [`EnvironmentRule`] contract format.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+use crate::contracts::schemas::{EnvironmentRule, EnvironmentRuleType};
+use crate::contracts::EnvironmentRef;
+use crate::error::{Result, ValidationError};
+use crate::patch::apply_merge_patch;
+use crate::validation::{ValidationContext, ValidationResult, ValidationSeverity, Validator};
+
+/// Prefix marking an encrypted configuration value
+pub const ENCRYPTED_PREFIX: &str = "enc:";
+
+/// Layers merged, in order, to resolve an environment's effective config
+///
+/// Mirrors `ConfigManager::get_with_overrides`: every environment starts
+/// from base, staging also inherits development, production inherits
+/// development and staging, and edge inherits only base.
+pub fn inheritance_chain(env: EnvironmentRef) -> &'static [EnvironmentRef] {
+    use EnvironmentRef::*;
+    match env {
+        Base => &[Base],
+        Development => &[Base, Development],
+        Staging => &[Base, Development, Staging],
+        Production => &[Base, Development, Staging, Production],
+        Edge => &[Base, Edge],
+    }
+}
+
+/// Per-environment configuration layers
+#[derive(Debug, Clone, Default)]
+pub struct EnvironmentLayers {
+    layers: BTreeMap<EnvironmentRef, Value>,
+}
+
+impl EnvironmentLayers {
+    /// Create an empty set of layers
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set an environment's layer, returning the layer it replaces
+    pub fn insert(&mut self, env: EnvironmentRef, layer: Value) -> Option<Value> {
+        self.layers.insert(env, layer)
+    }
+
+    /// Whether no layers have been added
+    pub fn is_empty(&self) -> bool {
+        self.layers.is_empty()
+    }
+
+    /// Environments to validate, in promotion order
+    ///
+    /// Base is only a layer the others inherit from, so it is validated on
+    /// its own only when it is the sole layer.
+    pub fn environments(&self) -> Vec<EnvironmentRef> {
+        let deployable: Vec<_> = self
+            .layers
+            .keys()
+            .copied()
+            .filter(|env| *env != EnvironmentRef::Base)
+            .collect();
+        if deployable.is_empty() {
+            self.layers.keys().copied().collect()
+        } else {
+            deployable
+        }
+    }
+
+    /// Resolve the effective configuration for `env`
+    ///
+    /// Layers are applied as JSON Merge Patches in inheritance order, so
+    /// nested objects merge and `null` removes an inherited field.
+    pub fn effective(&self, env: EnvironmentRef) -> Value {
+        let mut effective = Value::Object(serde_json::Map::new());
+        for layer in inheritance_chain(env)
+            .iter()
+            .filter_map(|env| self.layers.get(env))
+        {
+            apply_merge_patch(&mut effective, layer);
+        }
+        effective
+    }
+}
+
+/// Kind of cross-environment rule that was violated
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CrossEnvironmentKind {
+    MustDiffer,
+    MustEncrypt,
+}
+
+/// A cross-environment rule violation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrossEnvironmentViolation {
+    /// Identifier of the violated rule
+    pub rule_id: String,
+    /// Kind of rule
+    pub kind: CrossEnvironmentKind,
+    /// Dotted path of the field
+    pub field: String,
+    /// Environment whose value violates the rule
+    pub environment: EnvironmentRef,
+    /// Environment the value must differ from (MustDiffer only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub other_environment: Option<EnvironmentRef>,
+    /// Human-readable message
+    pub message: String,
+    /// Whether the rule blocks deployment
+    pub blocking: bool,
+}
+
+/// Validation findings for one environment's effective configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnvironmentFindings {
+    /// Environment that was validated
+    pub environment: EnvironmentRef,
+    /// Findings for its effective configuration
+    pub result: ValidationResult,
+}
+
+/// Findings for every environment plus cross-environment violations
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnvironmentsReport {
+    /// Per-environment findings, in promotion order
+    pub environments: Vec<EnvironmentFindings>,
+    /// Cross-environment rule violations
+    pub violations: Vec<CrossEnvironmentViolation>,
+}
+
+impl EnvironmentsReport {
+    /// Whether any environment has errors or any blocking rule is violated
+    pub fn has_errors(&self) -> bool {
+        self.violations.iter().any(|v| v.blocking)
+            || self.environments.iter().any(|e| !e.result.errors().is_empty())
+    }
+
+    /// Whether any environment has critical findings
+    pub fn has_critical(&self) -> bool {
+        self.environments.iter().any(|e| {
+            e.result
+                .findings
+                .iter()
+                .any(|f| f.severity == ValidationSeverity::Critical)
+        })
+    }
+
+    /// Whether any environment has warnings or any warning-only rule is violated
+    pub fn has_warnings(&self) -> bool {
+        self.violations.iter().any(|v| !v.blocking)
+            || self.environments.iter().any(|e| {
+                e.result
+                    .findings
+                    .iter()
+                    .any(|f| f.severity == ValidationSeverity::Warning)
+            })
+    }
+}
+
+/// Read the cross-environment rules from a schema document
+///
+/// Schemas without an `environment_rules` array have no rules. Rules that
+/// name an unknown environment are rejected.
+pub fn rules_from_schema(schema: &Value) -> Result<Vec<EnvironmentRule>> {
+    let Some(rules) = schema.get("environment_rules") else {
+        return Ok(Vec::new());
+    };
+    let rules: Vec<EnvironmentRule> = serde_json::from_value(rules.clone())
+        .map_err(|e| ValidationError::schema_error(format!("Invalid environment_rules: {}", e)))?;
+
+    for rule in &rules {
+        let from = match &rule.rule_type {
+            EnvironmentRuleType::MustDiffer { from_environments } => from_environments.as_slice(),
+            _ => &[],
+        };
+        for name in rule.environments.iter().chain(from) {
+            name.parse::<EnvironmentRef>().map_err(|e| {
+                ValidationError::schema_error(format!("Environment rule '{}': {}", rule.id, e))
+            })?;
+        }
+    }
+    Ok(rules)
+}
+
+/// Check MustDiffer and MustEncrypt rules across effective configurations
+///
+/// Other rule types apply to a single environment and are skipped. A field
+/// missing from an environment never violates these rules.
+pub fn check_environment_rules(
+    rules: &[EnvironmentRule],
+    effective: &BTreeMap<EnvironmentRef, Value>,
+) -> Vec<CrossEnvironmentViolation> {
+    let mut violations = Vec::new();
+
+    for rule in rules {
+        for env in parse_environments(&rule.environments) {
+            let Some(config) = effective.get(&env) else {
+                continue;
+            };
+            for field in &rule.affected_fields {
+                let Some(value) = lookup(config, field) else {
+                    continue;
+                };
+                match &rule.rule_type {
+                    EnvironmentRuleType::MustEncrypt => {
+                        let encrypted = value
+                            .as_str()
+                            .is_some_and(|s| s.starts_with(ENCRYPTED_PREFIX));
+                        if !encrypted {
+                            violations.push(CrossEnvironmentViolation {
+                                rule_id: rule.id.clone(),
+                                kind: CrossEnvironmentKind::MustEncrypt,
+                                field: field.clone(),
+                                environment: env,
+                                other_environment: None,
+                                message: format!(
+                                    "'{}' must be encrypted in {} (use an {}... value)",
+                                    field, env, ENCRYPTED_PREFIX
+                                ),
+                                blocking: rule.blocking,
+                            });
+                        }
+                    }
+                    EnvironmentRuleType::MustDiffer { from_environments } => {
+                        for other in parse_environments(from_environments) {
+                            if other == env {
+                                continue;
+                            }
+                            let same = effective
+                                .get(&other)
+                                .and_then(|c| lookup(c, field))
+                                .is_some_and(|v| v == value);
+                            if same {
+                                violations.push(CrossEnvironmentViolation {
+                                    rule_id: rule.id.clone(),
+                                    kind: CrossEnvironmentKind::MustDiffer,
+                                    field: field.clone(),
+                                    environment: env,
+                                    other_environment: Some(other),
+                                    message: format!(
+                                        "'{}' in {} has the same value as in {}",
+                                        field, env, other
+                                    ),
+                                    blocking: rule.blocking,
+                                });
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    violations
+}
+
+/// Validate every environment's effective configuration and check the
+/// schema's cross-environment rules
+///
+/// `schema` is the schema document's content, if any.
+pub fn validate_environments(
+    layers: &EnvironmentLayers,
+    schema: Option<&str>,
+    strict: bool,
+) -> Result<EnvironmentsReport> {
+    if layers.is_empty() {
+        return Err(ValidationError::invalid_input(
+            "No environment configurations to validate",
+        ));
+    }
+
+    let rules = match schema {
+        Some(content) => {
+            let document: Value = serde_json::from_str(content)
+                .map_err(|e| ValidationError::schema_error(format!("Invalid schema: {}", e)))?;
+            rules_from_schema(&document)?
+        }
+        None => Vec::new(),
+    };
+
+    let mut effective = BTreeMap::new();
+    let mut environments = Vec::new();
+    for env in layers.environments() {
+        let config = layers.effective(env);
+
+        let context = ValidationContext::new()
+            .with_environment(env.as_str())
+            .with_strict_mode(strict);
+        let mut validator = Validator::new(context);
+        if let Some(content) = schema {
+            validator.load_schema(content)?;
+        }
+        environments.push(EnvironmentFindings {
+            environment: env,
+            result: validator.validate(&config)?,
+        });
+        effective.insert(env, config);
+    }
+
+    Ok(EnvironmentsReport {
+        environments,
+        violations: check_environment_rules(&rules, &effective),
+    })
+}
+
+/// Environments named by a rule; unknown names are skipped
+fn parse_environments(names: &[String]) -> Vec<EnvironmentRef> {
+    names.iter().filter_map(|name| name.parse().ok()).collect()
+}
+
+/// Look up a dotted path (optionally `$.`-prefixed) in a configuration
+fn lookup<'a>(config: &'a Value, path: &str) -> Option<&'a Value> {
+    let path = path.strip_prefix("$.").unwrap_or(path);
+    path.split('.').try_fold(config, |value, key| value.get(key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn layers() -> EnvironmentLayers {
+        let mut layers = EnvironmentLayers::new();
+        layers.insert(
+            EnvironmentRef::Base,
+            json!({"database": {"host": "db", "pool": 5}, "debug": false}),
+        );
+        layers.insert(EnvironmentRef::Development, json!({"database": {"host": "dev-db"}, "debug": true}));
+        layers.insert(EnvironmentRef::Production, json!({"database": {"host": "prod-db"}, "debug": null}));
+        layers
+    }
+
+    #[test]
+    fn test_effective_config_inherits_through_chain() {
+        let layers = layers();
+
+        assert_eq!(
+            layers.effective(EnvironmentRef::Development),
+            json!({"database": {"host": "dev-db", "pool": 5}, "debug": true})
+        );
+        // Production inherits development, then removes `debug`
+        assert_eq!(
+            layers.effective(EnvironmentRef::Production),
+            json!({"database": {"host": "prod-db", "pool": 5}})
+        );
+        // Edge inherits only base
+        assert_eq!(
+            layers.effective(EnvironmentRef::Edge),
+            json!({"database": {"host": "db", "pool": 5}, "debug": false})
+        );
+        assert_eq!(
+            layers.environments(),
+            vec![EnvironmentRef::Development, EnvironmentRef::Production]
+        );
+    }
+
+    #[test]
+    fn test_must_encrypt_and_must_differ() {
+        let rules = vec![
+            EnvironmentRule::must_encrypt("encrypt-key", vec!["production".into()], vec!["api.key".into()]),
+            EnvironmentRule {
+                rule_type: EnvironmentRuleType::MustDiffer {
+                    from_environments: vec!["dev".into()],
+                },
+                ..EnvironmentRule::required_in("distinct-key", vec!["prod".into()], vec!["api.key".into()])
+            },
+        ];
+        let mut effective = BTreeMap::new();
+        effective.insert(EnvironmentRef::Development, json!({"api": {"key": "shared"}}));
+        effective.insert(EnvironmentRef::Production, json!({"api": {"key": "shared"}}));
+
+        let violations = check_environment_rules(&rules, &effective);
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].kind, CrossEnvironmentKind::MustEncrypt);
+        assert_eq!(violations[1].kind, CrossEnvironmentKind::MustDiffer);
+        assert_eq!(violations[1].other_environment, Some(EnvironmentRef::Development));
+
+        effective.insert(EnvironmentRef::Production, json!({"api": {"key": "enc:AAAA"}}));
+        assert!(check_environment_rules(&rules, &effective).is_empty());
+    }
+
+    #[test]
+    fn test_rules_with_unknown_environment_are_rejected() {
+        let schema = json!({
+            "environment_rules": [{
+                "id": "encrypt",
+                "environments": ["moon"],
+                "affected_fields": ["api.key"],
+                "rule_type": {"type": "must_encrypt"}
+            }]
+        });
+
+        let err = rules_from_schema(&schema).unwrap_err();
+        assert!(err.to_string().contains("'moon'"));
+    }
+}
@@ -0,0 +1,357 @@
+//! Fuzzing harness for schema validation
+//!
+//! Generates random but structurally valid JSON schemas together with
+//! configurations shaped after them, some conforming and some not, and runs
+//! each pair through [`Validator`] and [`normalize`]. A case fails when either
+//! panics or when running it twice gives different results.
+//!
+//! Every case is derived from a single `u64`, so a failure is reproduced from
+//! the seed it reports. [`fuzz_schema`] drives a run of consecutive seeds;
+//! `cargo fuzz` targets and property tests call [`check_case`] on
+//! [`FuzzCase::from_seed`] directly:
+//!
+//! ```ignore
+//! fuzz_target!(|seed: u64| {
+//!     check_case(&FuzzCase::from_seed(seed)).unwrap();
+//! });
+//! ```
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use crate::normalize::normalize;
+use crate::validation::{ValidationContext, Validator};
+
+/// Deepest nesting of generated objects and arrays
+const MAX_DEPTH: u32 = 3;
+
+/// Most properties generated for one object
+const MAX_PROPERTIES: u64 = 5;
+
+/// Patterns generated schemas draw from; all are valid regexes
+const PATTERNS: &[&str] = &["^[a-z]+$", "^\\d{2,4}$", "^https?://", "(?i)^on|off$", "^\\$\\{"];
+
+/// Property names, mixing conventions and secret-looking keys
+const KEYS: &[&str] = &[
+    "host",
+    "port",
+    "timeout_ms",
+    "maxRetries",
+    "api_key",
+    "password",
+    "tls",
+    "endpoints",
+    "Region",
+    "log-level",
+];
+
+/// Formats generated schemas may assert
+const FORMATS: &[&str] = &["email", "uri", "ipv4", "date-time", "x-unknown"];
+
+/// Deterministic pseudo-random source (SplitMix64)
+#[derive(Debug, Clone)]
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n.max(1)
+    }
+
+    fn chance(&mut self, percent: u64) -> bool {
+        self.below(100) < percent
+    }
+
+    fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len() as u64) as usize]
+    }
+}
+
+/// A schema and a configuration to validate against it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FuzzCase {
+    /// Seed the case was generated from
+    pub seed: u64,
+    /// JSON schema
+    pub schema: Value,
+    /// Configuration, conforming to the schema or not
+    pub config: Value,
+}
+
+impl FuzzCase {
+    /// Generate the case for a seed
+    pub fn from_seed(seed: u64) -> Self {
+        let mut rng = Rng(seed);
+        let mut schema = gen_object_schema(&mut rng, 0);
+        if let Value::Object(map) = &mut schema {
+            map.insert("$schema".to_string(), json!("http://json-schema.org/draft-07/schema#"));
+        }
+        let config = gen_value(&mut rng, &schema, 0);
+        Self {
+            seed,
+            schema,
+            config,
+        }
+    }
+}
+
+/// Why a case failed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FuzzFailure {
+    /// The failing case
+    pub case: FuzzCase,
+    /// What went wrong
+    pub reason: String,
+}
+
+impl std::fmt::Display for FuzzFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "fuzz case {} failed: {}", self.case.seed, self.reason)
+    }
+}
+
+impl std::error::Error for FuzzFailure {}
+
+/// Outcome counts of a fuzzing run
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FuzzReport {
+    /// Cases run
+    pub iterations: usize,
+    /// Cases whose configuration validated
+    pub valid: usize,
+    /// Cases whose configuration had errors
+    pub invalid: usize,
+    /// Cases the validator returned an error for
+    pub errored: usize,
+}
+
+/// What one run of a case produced, compared between runs
+#[derive(Debug, PartialEq)]
+enum Outcome {
+    Validated { valid: bool, findings: Value, normalized: Option<Value> },
+    Failed(String),
+}
+
+fn run_case(case: &FuzzCase) -> Outcome {
+    let mut validator = Validator::new(ValidationContext::new());
+    let result = validator
+        .load_schema(&case.schema.to_string())
+        .and_then(|_| validator.validate(&case.config));
+    match result {
+        Ok(result) => Outcome::Validated {
+            valid: result.valid,
+            findings: serde_json::to_value(&result.findings).unwrap_or_default(),
+            normalized: normalize(&case.config, Some(&case.schema)).ok(),
+        },
+        Err(e) => Outcome::Failed(e.to_string()),
+    }
+}
+
+/// Run a case twice, checking it neither panics nor varies between runs
+///
+/// Returns whether the configuration validated, or `None` when the validator
+/// rejected the case with an error.
+pub fn check_case(case: &FuzzCase) -> Result<Option<bool>, FuzzFailure> {
+    let fail = |reason: String| FuzzFailure {
+        case: case.clone(),
+        reason,
+    };
+    let run = || {
+        catch_unwind(AssertUnwindSafe(|| run_case(case))).map_err(|panic| {
+            let message = panic
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| panic.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic".to_string());
+            fail(format!("validation panicked: {}", message))
+        })
+    };
+
+    let first = run()?;
+    let second = run()?;
+    if first != second {
+        return Err(fail(format!(
+            "validation is not deterministic: {:?} then {:?}",
+            first, second
+        )));
+    }
+    Ok(match first {
+        Outcome::Validated { valid, .. } => Some(valid),
+        Outcome::Failed(_) => None,
+    })
+}
+
+/// Check `iterations` cases generated from consecutive seeds from `seed`
+///
+/// Stops at the first failure, which carries the seed to reproduce it.
+pub fn fuzz_schema(seed: u64, iterations: usize) -> Result<FuzzReport, FuzzFailure> {
+    let mut report = FuzzReport::default();
+    for offset in 0..iterations {
+        let case = FuzzCase::from_seed(seed.wrapping_add(offset as u64));
+        match check_case(&case)? {
+            Some(true) => report.valid += 1,
+            Some(false) => report.invalid += 1,
+            None => report.errored += 1,
+        }
+        report.iterations += 1;
+    }
+    Ok(report)
+}
+
+fn gen_schema(rng: &mut Rng, depth: u32) -> Value {
+    let kinds: &[&str] = if depth >= MAX_DEPTH {
+        &["string", "integer", "number", "boolean"]
+    } else {
+        &["string", "integer", "number", "boolean", "array", "object"]
+    };
+    match *rng.pick(kinds) {
+        "object" => gen_object_schema(rng, depth),
+        "array" => {
+            let mut schema = json!({"type": "array", "items": gen_schema(rng, depth + 1)});
+            if rng.chance(40) {
+                schema["minItems"] = json!(rng.below(3));
+            }
+            if rng.chance(30) {
+                schema["maxItems"] = json!(1 + rng.below(5));
+            }
+            schema
+        }
+        "string" => {
+            let mut schema = json!({"type": "string"});
+            if rng.chance(30) {
+                schema["minLength"] = json!(rng.below(4));
+            }
+            if rng.chance(30) {
+                schema["maxLength"] = json!(2 + rng.below(10));
+            }
+            if rng.chance(25) {
+                schema["pattern"] = json!(rng.pick(PATTERNS));
+            }
+            if rng.chance(20) {
+                schema["format"] = json!(rng.pick(FORMATS));
+            }
+            if rng.chance(15) {
+                schema["enum"] = json!(["on", "off", "auto"]);
+            }
+            schema
+        }
+        "boolean" => json!({"type": "boolean"}),
+        number => {
+            let mut schema = json!({ "type": number });
+            if rng.chance(40) {
+                schema["minimum"] = json!(rng.below(100) as i64 - 50);
+            }
+            if rng.chance(40) {
+                schema["maximum"] = json!(rng.below(1000));
+            }
+            if rng.chance(15) {
+                schema["exclusiveMinimum"] = json!(rng.chance(50));
+            }
+            schema
+        }
+    }
+}
+
+fn gen_object_schema(rng: &mut Rng, depth: u32) -> Value {
+    let mut properties = Map::new();
+    for _ in 0..1 + rng.below(MAX_PROPERTIES) {
+        properties.insert(rng.pick(KEYS).to_string(), gen_schema(rng, depth + 1));
+    }
+    let required: Vec<String> = properties
+        .keys()
+        .filter(|_| rng.chance(40))
+        .cloned()
+        .collect();
+    let mut schema = json!({
+        "type": "object",
+        "properties": properties,
+        "required": required,
+    });
+    if rng.chance(30) {
+        schema["additionalProperties"] = json!(rng.chance(50));
+    }
+    schema
+}
+
+/// A value of a random type, ignoring any schema
+fn gen_any(rng: &mut Rng, depth: u32) -> Value {
+    match rng.below(if depth >= MAX_DEPTH { 5 } else { 7 }) {
+        0 => Value::Null,
+        1 => json!(rng.chance(50)),
+        2 => json!(rng.below(2000) as i64 - 1000),
+        3 => json!(rng.below(10_000) as f64 / 7.0),
+        4 => json!(*rng.pick(&["", "on", "abc", "1234", "http://x", "${HOST}", "a@b.c"])),
+        5 => Value::Array((0..rng.below(3)).map(|_| gen_any(rng, depth + 1)).collect()),
+        _ => {
+            let mut map = Map::new();
+            for _ in 0..rng.below(3) {
+                map.insert(rng.pick(KEYS).to_string(), gen_any(rng, depth + 1));
+            }
+            Value::Object(map)
+        }
+    }
+}
+
+/// A value shaped after `schema`, sometimes deliberately wrong
+fn gen_value(rng: &mut Rng, schema: &Value, depth: u32) -> Value {
+    if rng.chance(10) {
+        return gen_any(rng, depth);
+    }
+    match schema.get("type").and_then(Value::as_str) {
+        Some("object") => {
+            let mut map = Map::new();
+            if let Some(properties) = schema.get("properties").and_then(Value::as_object) {
+                for (key, property) in properties {
+                    if rng.chance(80) {
+                        map.insert(key.clone(), gen_value(rng, property, depth + 1));
+                    }
+                }
+            }
+            if rng.chance(15) {
+                map.insert(rng.pick(KEYS).to_string(), gen_any(rng, depth + 1));
+            }
+            Value::Object(map)
+        }
+        Some("array") => {
+            let items = schema.get("items").cloned().unwrap_or(Value::Null);
+            Value::Array(
+                (0..rng.below(4))
+                    .map(|_| gen_value(rng, &items, depth + 1))
+                    .collect(),
+            )
+        }
+        Some("string") => match schema.get("enum").and_then(Value::as_array) {
+            Some(values) if rng.chance(70) => rng.pick(values).clone(),
+            _ => json!(*rng.pick(&["", "abc", "42", "on", "https://a.io", "x@y.io", "10.0.0.1"])),
+        },
+        Some("integer") => json!(rng.below(2000) as i64 - 500),
+        Some("number") => json!(rng.below(20_000) as f64 / 8.0 - 100.0),
+        Some("boolean") => {
+            if rng.chance(85) {
+                json!(rng.chance(50))
+            } else {
+                json!("true")
+            }
+        }
+        _ => gen_any(rng, depth),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cases_are_reproducible_from_their_seed() {
+        assert_eq!(FuzzCase::from_seed(7), FuzzCase::from_seed(7));
+        assert_ne!(FuzzCase::from_seed(7), FuzzCase::from_seed(8));
+    }
+}
@@ -305,9 +305,9 @@ where
         }
     };
 
-    // Parse JSON
-    serde_json::from_slice(&bytes)
-        .map_err(|e| EdgeFunctionError::ParseError(format!("Invalid JSON: {}", e)))
+    // Parse JSON, naming the offending field on shape errors
+    super::request_shape::parse_request(&bytes)
+        .map_err(|e| EdgeFunctionError::ParseError(e.to_string()))
 }
 
 /// Validate common request headers
@@ -22,6 +22,7 @@ use std::sync::Arc;
 use std::time::Instant;
 
 use super::{ErrorInfo, InspectionResult, ValidationRequest, ValidationResult};
+use crate::health::{EmissionStatus, EmissionTracker};
 
 /// Middleware state shared across requests
 #[derive(Clone)]
@@ -32,6 +33,8 @@ pub struct MiddlewareState {
     request_counter: Arc<AtomicU64>,
     /// Telemetry buffer for batch sending
     telemetry_buffer: Arc<tokio::sync::Mutex<Vec<TelemetryEvent>>>,
+    /// Outcome of the latest event handed to the buffer
+    emission_status: EmissionTracker,
 }
 
 impl MiddlewareState {
@@ -40,6 +43,7 @@ impl MiddlewareState {
             telemetry_enabled,
             request_counter: Arc::new(AtomicU64::new(0)),
             telemetry_buffer: Arc::new(tokio::sync::Mutex::new(Vec::new())),
+            emission_status: EmissionTracker::new(),
         }
     }
 
@@ -166,9 +170,11 @@ impl MiddlewareState {
 
         // Clone to spawn background task
         let buffer = Arc::clone(&self.telemetry_buffer);
+        let status = self.emission_status.clone();
         tokio::spawn(async move {
             let mut guard = buffer.lock().await;
             guard.push(event);
+            status.record_ok();
 
             // Flush if buffer is large enough
             if guard.len() >= 100 {
@@ -182,6 +188,11 @@ impl MiddlewareState {
     pub fn request_count(&self) -> u64 {
         self.request_counter.load(Ordering::SeqCst)
     }
+
+    /// Outcome of the latest telemetry event, if one has been emitted
+    pub fn last_emission(&self) -> Option<EmissionStatus> {
+        self.emission_status.last()
+    }
 }
 
 /// Telemetry event for LLM-Observatory
@@ -10,6 +10,8 @@
 //! - `edge_function`: Google Cloud Edge Function entry point and lifecycle
 //! - `routes`: Route definitions for validation endpoints
 //! - `middleware`: Request processing, validation, and telemetry emission
+//! - `capabilities`: The `GET /capabilities` response shared by all agents
+//! - `request_shape`: Request body extraction that reports the offending field
 //!
 //! ## Design Principles
 //!
@@ -23,13 +25,20 @@ pub mod edge_function;
 pub mod middleware;
 pub mod routes;
 
+#[path = "../../../shared/capabilities.rs"]
+pub mod capabilities;
+#[path = "../../../shared/request_shape.rs"]
+pub mod request_shape;
+
 pub use edge_function::{handle_request, EdgeFunctionConfig, EdgeFunctionError};
 pub use middleware::{
     request_logging_middleware, telemetry_middleware, validation_middleware, MiddlewareState,
 };
+pub use capabilities::Capabilities;
+pub use request_shape::{CheckedJson, RequestShapeError};
 pub use routes::{
-    create_router, health_check, inspect_config, validate_config, validate_config_instrumented,
-    validation_schema, ApiError, HandlerState,
+    capabilities, create_router, health_check, inspect_config, normalize_config, validate_config,
+    validate_config_instrumented, validate_patch, validation_schema, ApiError, HandlerState,
 };
 
 use serde::{Deserialize, Serialize};
@@ -136,13 +145,70 @@ pub struct ValidationRequest {
     /// Schema to validate against (optional, uses default if not provided)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub schema: Option<String>,
+    /// Environment custom rules evaluate against (defaults to `base`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub environment: Option<crate::Environment>,
+    /// Namespace custom rules evaluate against (defaults to empty)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    /// Validation options
+    #[serde(default)]
+    pub options: ValidationOptions,
+}
+
+/// Patch validation request (JSON Merge Patch, RFC 7386)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PatchValidationRequest {
+    /// Existing configuration the patch applies to
+    pub base: serde_json::Value,
+    /// Merge patch to apply; must be a JSON object
+    pub patch: serde_json::Value,
+    /// Schema to validate against (optional, uses default if not provided)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
+    /// Environment custom rules evaluate against (defaults to `base`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub environment: Option<crate::Environment>,
+    /// Namespace custom rules evaluate against (defaults to empty)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
     /// Validation options
     #[serde(default)]
     pub options: ValidationOptions,
 }
 
+/// Patch validation result, scoped to the paths the patch changes
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PatchValidationResult {
+    /// Dotted paths changed by the patch
+    pub changed_paths: Vec<String>,
+    /// Configuration after applying the patch
+    pub patched_config: serde_json::Value,
+    /// Findings at, above, or below the changed paths
+    pub result: ValidationResult,
+}
+
+/// Normalization request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NormalizeRequest {
+    /// Configuration data to normalize
+    pub config: serde_json::Value,
+    /// JSON schema whose defaults and types are applied (optional)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<serde_json::Value>,
+}
+
+/// Normalized configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NormalizeResult {
+    /// Configuration in canonical form
+    pub config: serde_json::Value,
+    /// Hex SHA-256 of the canonical serialization
+    pub hash: String,
+}
+
 /// Options for validation behavior
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ValidationOptions {
     /// Whether to validate strictly (fail on unknown fields)
     #[serde(default)]
@@ -153,9 +219,29 @@ pub struct ValidationOptions {
     /// Whether to collect all errors or fail fast
     #[serde(default)]
     pub collect_all_errors: bool,
-    /// Custom validation rules to apply
+    /// Ids of registered engine rules to apply in addition to the schema checks
+    ///
+    /// Unknown ids are rejected with `BadRequest`.
     #[serde(default)]
     pub custom_rules: Vec<String>,
+    /// Most errors and warnings to return
+    ///
+    /// Errors are kept before warnings; a truncated result says so and
+    /// reports how many findings there were.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_findings: Option<usize>,
+}
+
+impl Default for ValidationOptions {
+    fn default() -> Self {
+        Self {
+            strict: false,
+            max_depth: default_max_depth(),
+            collect_all_errors: false,
+            custom_rules: Vec::new(),
+            max_findings: None,
+        }
+    }
 }
 
 fn default_max_depth() -> usize {
@@ -177,6 +263,28 @@ pub struct ValidationResult {
     pub schema_used: String,
     /// Validation statistics
     pub stats: ValidationStats,
+    /// Whether findings were dropped to honor `options.max_findings`
+    #[serde(default)]
+    pub truncated: bool,
+    /// Errors and warnings found before truncation, present when truncated
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_findings: Option<usize>,
+}
+
+impl ValidationResult {
+    /// Keep at most `max` errors and warnings, errors first
+    ///
+    /// `valid` still reflects every error found.
+    pub fn truncate_findings(&mut self, max: usize) {
+        let total = self.errors.len() + self.warnings.len();
+        if total <= max {
+            return;
+        }
+        self.errors.truncate(max);
+        self.warnings.truncate(max - self.errors.len());
+        self.truncated = true;
+        self.total_findings = Some(total);
+    }
 }
 
 /// Individual validation error
@@ -301,6 +409,9 @@ pub struct HealthResponse {
     pub timestamp: String,
     /// Agent version
     pub version: String,
+    /// Agent id, build, uptime, registrations and telemetry
+    #[serde(flatten)]
+    pub summary: crate::health::HealthSummary,
 }
 
 /// Health status enum
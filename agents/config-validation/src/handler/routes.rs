@@ -2,8 +2,11 @@
 //!
 //! This module defines the HTTP routes for the validation agent:
 //! - POST /validate - Full configuration validation
+//! - POST /validate/patch - Validate a JSON Merge Patch against a base config
 //! - POST /inspect - Quick schema inspection
+//! - POST /normalize - Canonical form of a configuration, without validation
 //! - GET /health - Health check endpoint
+//! - GET /capabilities - Registered rules and supported formats
 //! - GET /schema - Return validation schemas
 //!
 //! All routes return machine-readable JSON responses and emit telemetry
@@ -22,11 +25,20 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Instant;
 
+use crate::cli::OutputFormat;
+use crate::engine::rules::RuleContext;
+use crate::engine::ValidationEngine;
+use crate::health::HealthSummary;
+use crate::{ConfigValue, Environment};
+
+use super::capabilities::{Capabilities, RuleCapability};
+use super::request_shape::{CheckedJson, RequestShapeError};
 use super::{
     ApiResponse, ComponentHealth, ConfigStructure, ErrorInfo, FieldInfo, HealthResponse,
-    HealthStatus, InspectionRequest, InspectionResult, MiddlewareState, SchemaSuggestion,
-    ValidationError, ValidationOptions, ValidationRequest, ValidationResult, ValidationStats,
-    ValidationWarning,
+    HealthStatus, InspectionRequest, InspectionResult, MiddlewareState, NormalizeRequest,
+    NormalizeResult, PatchValidationRequest,
+    PatchValidationResult, SchemaSuggestion, ValidationError, ValidationOptions,
+    ValidationRequest, ValidationResult, ValidationStats, ValidationWarning,
 };
 
 /// Handler state shared across all routes
@@ -34,6 +46,8 @@ use super::{
 pub struct HandlerState {
     /// Available validation schemas
     pub schemas: Arc<HashMap<String, ValidationSchema>>,
+    /// Rule engine providing custom rules selectable via `options.custom_rules`
+    pub engine: Arc<ValidationEngine>,
     /// Start time for uptime calculation
     pub start_time: Instant,
 }
@@ -42,10 +56,53 @@ impl HandlerState {
     pub fn new() -> Self {
         Self {
             schemas: Arc::new(Self::load_default_schemas()),
+            engine: Arc::new(ValidationEngine::new()),
             start_time: Instant::now(),
         }
     }
 
+    /// Use a custom rule engine (e.g. with additional registered rules)
+    pub fn with_engine(mut self, engine: ValidationEngine) -> Self {
+        self.engine = Arc::new(engine);
+        self
+    }
+
+    /// Summarize uptime, build, registrations and telemetry for `/health`
+    pub fn health(&self, middleware_state: &MiddlewareState) -> HealthSummary {
+        let mut summary =
+            HealthSummary::new(crate::AGENT_ID, crate::AGENT_VERSION, self.start_time)
+                .with_telemetry(
+                    middleware_state.telemetry_enabled,
+                    middleware_state.last_emission(),
+                );
+        summary.rules = self.engine.rules().len();
+        summary.schemas = self.schemas.len();
+        summary
+    }
+
+    /// Describe this agent's registered rules and supported formats
+    pub fn capabilities(&self) -> Capabilities {
+        use clap::ValueEnum;
+
+        let mut capabilities = Capabilities::new(crate::AGENT_ID, crate::AGENT_VERSION);
+        capabilities.rules = self
+            .engine
+            .rules()
+            .iter()
+            .map(|rule| RuleCapability {
+                id: rule.id().to_string(),
+                name: rule.name().to_string(),
+                category: Some(rule.category().to_string()),
+            })
+            .collect();
+        capabilities.output_formats = OutputFormat::value_variants()
+            .iter()
+            .filter_map(|f| f.to_possible_value())
+            .map(|v| v.get_name().to_string())
+            .collect();
+        capabilities
+    }
+
     fn load_default_schemas() -> HashMap<String, ValidationSchema> {
         let mut schemas = HashMap::new();
 
@@ -161,6 +218,8 @@ pub struct SchemaField {
 #[derive(Debug)]
 pub enum ApiError {
     BadRequest(String),
+    /// Request body does not match the expected request shape
+    MalformedRequest(RequestShapeError),
     NotFound(String),
     InternalError(String),
     ValidationFailed(Vec<ValidationError>),
@@ -169,7 +228,7 @@ pub enum ApiError {
 impl ApiError {
     pub fn error_code(&self) -> &'static str {
         match self {
-            ApiError::BadRequest(_) => "BAD_REQUEST",
+            ApiError::BadRequest(_) | ApiError::MalformedRequest(_) => "BAD_REQUEST",
             ApiError::NotFound(_) => "NOT_FOUND",
             ApiError::InternalError(_) => "INTERNAL_ERROR",
             ApiError::ValidationFailed(_) => "VALIDATION_FAILED",
@@ -178,7 +237,7 @@ impl ApiError {
 
     pub fn status_code(&self) -> StatusCode {
         match self {
-            ApiError::BadRequest(_) => StatusCode::BAD_REQUEST,
+            ApiError::BadRequest(_) | ApiError::MalformedRequest(_) => StatusCode::BAD_REQUEST,
             ApiError::NotFound(_) => StatusCode::NOT_FOUND,
             ApiError::InternalError(_) => StatusCode::INTERNAL_SERVER_ERROR,
             ApiError::ValidationFailed(_) => StatusCode::UNPROCESSABLE_ENTITY,
@@ -191,6 +250,8 @@ impl IntoResponse for ApiError {
         let status = self.status_code();
         let error_info = match &self {
             ApiError::BadRequest(msg) => ErrorInfo::new(self.error_code(), msg),
+            ApiError::MalformedRequest(err) => ErrorInfo::new(self.error_code(), err.to_string())
+                .with_details(serde_json::json!({ "field": err.field })),
             ApiError::NotFound(msg) => ErrorInfo::new(self.error_code(), msg),
             ApiError::InternalError(msg) => ErrorInfo::new(self.error_code(), msg),
             ApiError::ValidationFailed(errors) => {
@@ -205,14 +266,29 @@ impl IntoResponse for ApiError {
     }
 }
 
+impl From<RequestShapeError> for ApiError {
+    fn from(err: RequestShapeError) -> Self {
+        ApiError::MalformedRequest(err)
+    }
+}
+
+impl IntoResponse for RequestShapeError {
+    fn into_response(self) -> Response {
+        ApiError::from(self).into_response()
+    }
+}
+
 /// Create the router with all routes
 pub fn create_router(handler_state: HandlerState, middleware_state: MiddlewareState) -> Router {
     Router::new()
         // Validation endpoints
         .route("/validate", post(validate_config))
+        .route("/validate/patch", post(validate_patch))
         .route("/inspect", post(inspect_config))
+        .route("/normalize", post(normalize_config))
         // Health and schema endpoints
         .route("/health", get(health_check))
+        .route("/capabilities", get(capabilities))
         .route("/schema", get(validation_schema))
         .route("/schema/:schema_id", get(get_schema_by_id))
         // Instrumented execution endpoint (requires X-Parent-Span-Id header)
@@ -234,13 +310,76 @@ pub struct SchemaQuery {
 /// This endpoint is deterministic and stateless.
 pub async fn validate_config(
     State((state, middleware_state)): State<(HandlerState, MiddlewareState)>,
-    Json(request): Json<ValidationRequest>,
+    CheckedJson(request): CheckedJson<ValidationRequest>,
 ) -> Result<Json<ApiResponse<ValidationResult>>, ApiError> {
-    let start_time = Instant::now();
     let request_id = uuid::Uuid::new_v4().to_string();
+    let mut result = run_validation(&state, &middleware_state, &request_id, &request).await?;
+    if let Some(max) = request.options.max_findings {
+        result.truncate_findings(max);
+    }
+
+    let response = ApiResponse::success(result, request_id);
+    Ok(Json(response))
+}
+
+/// POST /validate/patch - Validate a JSON Merge Patch (RFC 7386)
+///
+/// Applies the patch to the base configuration, validates the result, and
+/// returns only findings related to the paths the patch changes. Patches that
+/// are not JSON objects are rejected with `BadRequest`.
+pub async fn validate_patch(
+    State((state, middleware_state)): State<(HandlerState, MiddlewareState)>,
+    CheckedJson(request): CheckedJson<PatchValidationRequest>,
+) -> Result<Json<ApiResponse<PatchValidationResult>>, ApiError> {
+    let request_id = uuid::Uuid::new_v4().to_string();
+
+    let patched_config = crate::patch::patch_config(&request.base, &request.patch)
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+    let changed_paths = crate::patch::changed_paths(&request.patch);
+
+    let validation_request = ValidationRequest {
+        config: patched_config,
+        schema: request.schema,
+        environment: request.environment,
+        namespace: request.namespace,
+        options: request.options,
+    };
+    let mut result =
+        run_validation(&state, &middleware_state, &request_id, &validation_request).await?;
+
+    result
+        .errors
+        .retain(|e| crate::patch::path_in_scope(&e.path, &changed_paths));
+    result
+        .warnings
+        .retain(|w| crate::patch::path_in_scope(&w.path, &changed_paths));
+    result.valid = result.errors.is_empty();
+    if let Some(max) = validation_request.options.max_findings {
+        result.truncate_findings(max);
+    }
+
+    let response = ApiResponse::success(
+        PatchValidationResult {
+            changed_paths,
+            patched_config: validation_request.config,
+            result,
+        },
+        request_id,
+    );
+    Ok(Json(response))
+}
+
+/// Validate a request against its schema and custom rules, emitting telemetry
+async fn run_validation(
+    state: &HandlerState,
+    middleware_state: &MiddlewareState,
+    request_id: &str,
+    request: &ValidationRequest,
+) -> Result<ValidationResult, ApiError> {
+    let start_time = Instant::now();
 
     // Emit telemetry for request start
-    middleware_state.emit_validation_start(&request_id, &request);
+    middleware_state.emit_validation_start(request_id, request);
 
     // Determine which schema to use
     let schema_id = request.schema.as_deref().unwrap_or("llm-config-v1");
@@ -250,7 +389,12 @@ pub async fn validate_config(
         .ok_or_else(|| ApiError::NotFound(format!("Schema '{}' not found", schema_id)))?;
 
     // Perform validation
-    let (errors, warnings) = validate_against_schema(&request.config, schema, &request.options);
+    let (mut errors, mut warnings) =
+        validate_against_schema(&request.config, schema, &request.options);
+    let (rule_errors, rule_warnings, custom_rules_applied) =
+        apply_custom_rules(&state.engine, request).await?;
+    errors.extend(rule_errors);
+    warnings.extend(rule_warnings);
 
     let duration_us = start_time.elapsed().as_micros() as u64;
 
@@ -261,16 +405,17 @@ pub async fn validate_config(
         schema_used: schema_id.to_string(),
         stats: ValidationStats {
             fields_validated: count_fields(&request.config),
-            rules_applied: schema.fields.len(),
+            rules_applied: schema.fields.len() + custom_rules_applied,
             duration_us,
         },
+        truncated: false,
+        total_findings: None,
     };
 
     // Emit telemetry for validation complete
-    middleware_state.emit_validation_complete(&request_id, &result);
+    middleware_state.emit_validation_complete(request_id, &result);
 
-    let response = ApiResponse::success(result, request_id);
-    Ok(Json(response))
+    Ok(result)
 }
 
 /// POST /execution/validate - Instrumented configuration validation.
@@ -282,7 +427,7 @@ pub async fn validate_config(
 pub async fn validate_config_instrumented(
     exec_ctx: ExecutionContextExtractor,
     State((state, middleware_state)): State<(HandlerState, MiddlewareState)>,
-    Json(request): Json<ValidationRequest>,
+    CheckedJson(request): CheckedJson<ValidationRequest>,
 ) -> Result<Json<ExecutionEnvelope<ValidationResult>>, ApiError> {
     let ctx = exec_ctx.0;
     let mut tree = SpanTreeBuilder::new(&ctx, "config-manager");
@@ -308,24 +453,47 @@ pub async fn validate_config_instrumented(
     };
 
     // Perform validation
-    let (errors, warnings) = validate_against_schema(&request.config, schema, &request.options);
+    let (mut errors, mut warnings) =
+        validate_against_schema(&request.config, schema, &request.options);
+    let custom_rules_applied = match apply_custom_rules(&state.engine, &request).await {
+        Ok((rule_errors, rule_warnings, applied)) => {
+            errors.extend(rule_errors);
+            warnings.extend(rule_warnings);
+            applied
+        }
+        Err(e) => {
+            let err = match e {
+                ApiError::BadRequest(msg) => msg,
+                other => other.error_code().to_string(),
+            };
+            agent_span.fail(err.clone());
+            tree.add_completed_agent_span(agent_span);
+            let span_tree = tree.finalize_failed(err.clone());
+            return Ok(Json(ExecutionEnvelope::failure(err, span_tree)));
+        }
+    };
 
     let duration_us = start_time.elapsed().as_micros() as u64;
 
-    let result = ValidationResult {
+    let mut result = ValidationResult {
         valid: errors.is_empty(),
         errors,
         warnings,
         schema_used: schema_id.to_string(),
         stats: ValidationStats {
             fields_validated: count_fields(&request.config),
-            rules_applied: schema.fields.len(),
+            rules_applied: schema.fields.len() + custom_rules_applied,
             duration_us,
         },
+        truncated: false,
+        total_findings: None,
     };
 
     // Emit existing telemetry (preserved)
     middleware_state.emit_validation_complete(&request_id, &result);
+    if let Some(max) = request.options.max_findings {
+        result.truncate_findings(max);
+    }
 
     // Attach result as artifact to agent span
     if let Ok(artifact) = serde_json::to_value(&result) {
@@ -341,12 +509,30 @@ pub async fn validate_config_instrumented(
     Ok(Json(ExecutionEnvelope::success(result, span_tree)))
 }
 
+/// POST /normalize - Canonicalize a configuration
+///
+/// Sorts keys, normalizes numbers, and with a JSON schema expands defaults
+/// and converts booleans and numbers written as strings. Nothing is
+/// validated, so the result is not a verdict on the configuration.
+pub async fn normalize_config(
+    CheckedJson(request): CheckedJson<NormalizeRequest>,
+) -> Result<Json<ApiResponse<NormalizeResult>>, ApiError> {
+    let request_id = uuid::Uuid::new_v4().to_string();
+
+    let config = crate::normalize::normalize(&request.config, request.schema.as_ref())
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+    let hash = crate::normalize::canonical_hash(&config);
+
+    let response = ApiResponse::success(NormalizeResult { config, hash }, request_id);
+    Ok(Json(response))
+}
+
 /// POST /inspect - Quick schema inspection
 ///
 /// Analyzes a configuration and suggests matching schemas without full validation.
 pub async fn inspect_config(
     State((state, middleware_state)): State<(HandlerState, MiddlewareState)>,
-    Json(request): Json<InspectionRequest>,
+    CheckedJson(request): CheckedJson<InspectionRequest>,
 ) -> Result<Json<ApiResponse<InspectionResult>>, ApiError> {
     let request_id = uuid::Uuid::new_v4().to_string();
 
@@ -378,14 +564,22 @@ pub async fn inspect_config(
     Ok(Json(response))
 }
 
+/// GET /capabilities - Registered rules and supported formats
+///
+/// Lets orchestrators discover which custom rules can be requested via
+/// `options.custom_rules` before sending a validation.
+pub async fn capabilities(
+    State((state, _)): State<(HandlerState, MiddlewareState)>,
+) -> Json<Capabilities> {
+    Json(state.capabilities())
+}
+
 /// GET /health - Health check endpoint
 ///
 /// Returns the health status of the validation agent.
 pub async fn health_check(
     State((state, middleware_state)): State<(HandlerState, MiddlewareState)>,
 ) -> Json<HealthResponse> {
-    let uptime_seconds = state.start_time.elapsed().as_secs();
-
     // Check component health
     let validation_engine = true; // Always available in stateless mode
     let schema_registry = !state.schemas.is_empty();
@@ -408,6 +602,7 @@ pub async fn health_check(
         },
         timestamp: chrono::Utc::now().to_rfc3339(),
         version: env!("CARGO_PKG_VERSION").to_string(),
+        summary: state.health(&middleware_state),
     })
 }
 
@@ -550,6 +745,99 @@ fn validate_against_schema(
     (errors, warnings)
 }
 
+/// Run the engine rules named in `options.custom_rules` against the config
+///
+/// Blocking findings (error/critical) become errors, all others warnings. The
+/// rule context comes from the request's `environment` (default `base`) and
+/// `namespace` fields. Repeated ids run once, and null leaves are skipped
+/// since rules have no null value to evaluate. Unknown rule ids are rejected
+/// with `BadRequest`.
+///
+/// Also returns the number of rules that were applicable and evaluated.
+async fn apply_custom_rules(
+    engine: &ValidationEngine,
+    request: &ValidationRequest,
+) -> Result<(Vec<ValidationError>, Vec<ValidationWarning>, usize), ApiError> {
+    let mut errors = Vec::new();
+    let mut warnings = Vec::new();
+
+    if request.options.custom_rules.is_empty() {
+        return Ok((errors, warnings, 0));
+    }
+
+    let mut rule_ids: Vec<&str> = Vec::new();
+    for id in &request.options.custom_rules {
+        if !rule_ids.contains(&id.as_str()) {
+            rule_ids.push(id);
+        }
+    }
+
+    let rules = rule_ids
+        .into_iter()
+        .map(|id| {
+            engine
+                .rule(id)
+                .ok_or_else(|| ApiError::BadRequest(format!("Unknown custom rule '{}'", id)))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let value: ConfigValue = serde_json::from_value(strip_nulls(&request.config)).map_err(|e| {
+        ApiError::BadRequest(format!("Config cannot be evaluated by custom rules: {}", e))
+    })?;
+
+    let context = RuleContext::new(
+        request.environment.unwrap_or(Environment::Base),
+        request.namespace.as_deref().unwrap_or_default(),
+    );
+
+    let mut applied = 0;
+    for rule in rules {
+        if !rule.is_applicable(&context) {
+            continue;
+        }
+        applied += 1;
+        for finding in rule.evaluate(&value, "", &context).await {
+            if finding.is_blocking() {
+                errors.push(ValidationError {
+                    path: finding.field_path,
+                    code: finding.rule_id,
+                    message: finding.message,
+                    expected: finding.expected,
+                    actual: finding.actual,
+                });
+            } else {
+                warnings.push(ValidationWarning {
+                    path: finding.field_path,
+                    code: finding.rule_id,
+                    message: finding.message,
+                });
+            }
+        }
+    }
+
+    Ok((errors, warnings, applied))
+}
+
+/// Copy of `value` with null object entries and array elements removed
+pub(crate) fn strip_nulls(value: &serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(map) => serde_json::Value::Object(
+            map.iter()
+                .filter(|(_, v)| !v.is_null())
+                .map(|(k, v)| (k.clone(), strip_nulls(v)))
+                .collect(),
+        ),
+        serde_json::Value::Array(items) => serde_json::Value::Array(
+            items
+                .iter()
+                .filter(|v| !v.is_null())
+                .map(strip_nulls)
+                .collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
 fn get_json_path<'a>(value: &'a serde_json::Value, path: &str) -> Option<&'a serde_json::Value> {
     let parts: Vec<&str> = path.split('.').collect();
     let mut current = value;
@@ -853,6 +1141,271 @@ mod tests {
         assert!(patterns.contains(&"versioned".to_string()));
     }
 
+    fn custom_rule_request(config: serde_json::Value, rules: &[&str]) -> ValidationRequest {
+        ValidationRequest {
+            config,
+            schema: None,
+            environment: Some(Environment::Production),
+            namespace: Some("app/api".to_string()),
+            options: ValidationOptions {
+                custom_rules: rules.iter().map(|id| id.to_string()).collect(),
+                ..Default::default()
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn test_custom_rule_findings_are_returned() {
+        let state = (HandlerState::new(), MiddlewareState::new(false));
+        let config = serde_json::json!({
+            "namespace": "app/api",
+            "key": "endpoint",
+            "value": "http://localhost:8080",
+            "description": null
+        });
+
+        // Repeated ids run once; the environment comes from the request field
+        let request = custom_rule_request(
+            config.clone(),
+            &["environment_check", "environment_check"],
+        );
+        let Json(response) = validate_config(State(state.clone()), CheckedJson(request))
+            .await
+            .unwrap();
+        let result = response.data.unwrap();
+        let findings: Vec<_> = result
+            .errors
+            .iter()
+            .filter(|e| e.code == "environment_check" && e.path == "value")
+            .collect();
+        assert_eq!(findings.len(), 1);
+
+        // Without opting in, the rule is not applied
+        let mut request = custom_rule_request(config, &[]);
+        request.options.custom_rules.clear();
+        let Json(response) = validate_config(State(state), CheckedJson(request)).await.unwrap();
+        let result = response.data.unwrap();
+        assert!(result.errors.iter().all(|e| e.code != "environment_check"));
+    }
+
+    #[tokio::test]
+    async fn test_max_findings_keeps_errors_and_reports_total() {
+        let state = (HandlerState::new(), MiddlewareState::new(false));
+        let config = serde_json::json!({
+            "namespace": "app/api",
+            "key": "endpoint",
+            "value": "http://localhost:8080",
+            "debug_mode": true
+        });
+
+        let request = custom_rule_request(config.clone(), &["environment_check"]);
+        let Json(response) = validate_config(State(state.clone()), CheckedJson(request))
+            .await
+            .unwrap();
+        let full = response.data.unwrap();
+        assert_eq!((full.errors.len(), full.warnings.len()), (1, 1));
+        assert!(!full.truncated);
+        assert_eq!(full.total_findings, None);
+
+        let mut request = custom_rule_request(config, &["environment_check"]);
+        request.options.max_findings = Some(1);
+        let Json(response) = validate_config(State(state), CheckedJson(request)).await.unwrap();
+        let capped = response.data.unwrap();
+        assert_eq!(capped.errors.len(), 1);
+        assert!(capped.warnings.is_empty());
+        assert!(capped.truncated);
+        assert_eq!(capped.total_findings, Some(2));
+        assert!(!capped.valid);
+    }
+
+    #[tokio::test]
+    async fn test_custom_rule_environment_ignores_config_fields() {
+        let state = (HandlerState::new(), MiddlewareState::new(false));
+        // A config field named `environment` must not select the rule context
+        let config = serde_json::json!({
+            "namespace": "app/api",
+            "key": "endpoint",
+            "value": "http://localhost:8080",
+            "environment": "production"
+        });
+        let mut request = custom_rule_request(config, &["environment_check"]);
+        request.environment = None;
+
+        let Json(response) = validate_config(State(state), CheckedJson(request)).await.unwrap();
+        let result = response.data.unwrap();
+        assert!(result.errors.iter().all(|e| e.code != "environment_check"));
+    }
+
+    #[tokio::test]
+    async fn test_custom_rules_applied_counts_evaluated_rules() {
+        let state = HandlerState::new();
+        let config = serde_json::json!({"namespace": "app", "key": "k", "value": 1});
+
+        let request = custom_rule_request(config.clone(), &["environment_check"]);
+        let (_, _, applied) = apply_custom_rules(&state.engine, &request).await.unwrap();
+        assert_eq!(applied, 1);
+
+        let request = custom_rule_request(config, &[]);
+        let (_, _, applied) = apply_custom_rules(&state.engine, &request).await.unwrap();
+        assert_eq!(applied, 0);
+    }
+
+    #[tokio::test]
+    async fn test_health_reports_rule_and_schema_counts() {
+        let state = (HandlerState::new(), MiddlewareState::new(false));
+        let expected_rules = state.0.engine.rules().len();
+        let expected_schemas = state.0.schemas.len();
+        let Json(health) = health_check(State(state)).await;
+
+        assert_eq!(health.summary.rules, expected_rules);
+        assert!(health.summary.rules > 0);
+        assert_eq!(health.summary.schemas, expected_schemas);
+        assert_eq!(health.summary.checkers, 0);
+        assert!(!health.summary.agent_version.is_empty());
+        assert!(!health.summary.build.git_sha.is_empty());
+        assert!(!health.summary.telemetry.enabled);
+        assert!(health.summary.telemetry.last_emission.is_none());
+
+        let json = serde_json::to_value(&health).unwrap();
+        assert_eq!(json["agent_id"], crate::AGENT_ID);
+        assert_eq!(json["rules"], expected_rules);
+        assert!(json["uptime_seconds"].is_u64());
+    }
+
+    #[tokio::test]
+    async fn test_capabilities_list_default_rules() {
+        let state = (HandlerState::new(), MiddlewareState::new(false));
+        let Json(capabilities) = capabilities(State(state)).await;
+
+        assert_eq!(capabilities.agent_id, crate::AGENT_ID);
+        assert_eq!(capabilities.agent_version, crate::AGENT_VERSION);
+        let rules: Vec<_> = capabilities
+            .rules
+            .iter()
+            .map(|r| (r.id.as_str(), r.category.as_deref()))
+            .collect();
+        assert_eq!(
+            rules,
+            vec![
+                ("environment_check", Some("environment")),
+                ("placeholder_check", Some("environment")),
+                ("pattern_check", Some("type")),
+                ("format_check", Some("type")),
+                ("collection_check", Some("bounds")),
+                ("conditional_check", Some("bounds")),
+                ("reference_check", Some("required")),
+                ("secret_check", Some("security")),
+                ("compatibility_check", Some("compatibility")),
+            ]
+        );
+        assert_eq!(capabilities.content_types, vec!["application/json"]);
+        assert_eq!(capabilities.output_formats, vec!["table", "json", "yaml", "html"]);
+
+        let json = serde_json::to_value(&capabilities).unwrap();
+        assert!(json.get("adapter_types").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_unknown_custom_rule_is_bad_request() {
+        let state = (HandlerState::new(), MiddlewareState::new(false));
+        let config = serde_json::json!({"namespace": "app", "key": "k", "value": 1});
+        let request = custom_rule_request(config, &["no_such_rule"]);
+
+        let err = validate_config(State(state), CheckedJson(request))
+            .await
+            .unwrap_err();
+        assert_eq!(err.status_code(), StatusCode::BAD_REQUEST);
+        assert!(matches!(err, ApiError::BadRequest(msg) if msg.contains("no_such_rule")));
+    }
+
+    #[tokio::test]
+    async fn test_patch_introducing_invalid_value_is_caught() {
+        let state = (HandlerState::new(), MiddlewareState::new(false));
+        let request = PatchValidationRequest {
+            base: serde_json::json!({"namespace": "app/api", "key": "timeout", "value": 30}),
+            patch: serde_json::json!({"key": "1bad key"}),
+            schema: None,
+            environment: None,
+            namespace: None,
+            options: ValidationOptions::default(),
+        };
+
+        let Json(response) = validate_patch(State(state), CheckedJson(request)).await.unwrap();
+        let patched = response.data.unwrap();
+        assert_eq!(patched.changed_paths, vec!["key".to_string()]);
+        assert_eq!(patched.patched_config["key"], "1bad key");
+        assert!(!patched.result.valid);
+        assert!(patched.result.errors.iter().all(|e| e.path == "key"));
+        assert!(patched
+            .result
+            .errors
+            .iter()
+            .any(|e| e.code == "PATTERN_MISMATCH"));
+    }
+
+    #[tokio::test]
+    async fn test_non_object_patch_is_bad_request() {
+        let state = (HandlerState::new(), MiddlewareState::new(false));
+        let request = PatchValidationRequest {
+            base: serde_json::json!({"namespace": "app", "key": "k", "value": 1}),
+            patch: serde_json::json!(["not", "a", "patch"]),
+            schema: None,
+            environment: None,
+            namespace: None,
+            options: ValidationOptions::default(),
+        };
+
+        let err = validate_patch(State(state), CheckedJson(request)).await.unwrap_err();
+        assert_eq!(err.status_code(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_normalize_ignores_key_order() {
+        let normalize = |body: &str| {
+            let request = NormalizeRequest {
+                config: serde_json::from_str(body).unwrap(),
+                schema: Some(serde_json::json!({
+                    "properties": {"retries": {"type": "integer", "default": 3}}
+                })),
+            };
+            async move {
+                normalize_config(CheckedJson(request))
+                    .await
+                    .unwrap()
+                    .0
+                    .data
+                    .unwrap()
+            }
+        };
+
+        let a = normalize(r#"{"timeout": 30.0, "db": {"port": 5432, "host": "x"}}"#).await;
+        let b = normalize(r#"{"db": {"host": "x", "port": 5432}, "timeout": 30}"#).await;
+        assert_eq!(
+            serde_json::to_vec(&a.config).unwrap(),
+            serde_json::to_vec(&b.config).unwrap()
+        );
+        assert_eq!(a.hash, b.hash);
+        assert_eq!(a.config["retries"], 3);
+    }
+
+    #[test]
+    fn test_malformed_request_names_field() {
+        use crate::handler::request_shape::parse_request;
+
+        let err = parse_request::<ValidationRequest>(br#"{"schema": "default"}"#).unwrap_err();
+        assert_eq!(err.field, None);
+        assert_eq!(err.to_string(), "missing field `config`");
+
+        let err = parse_request::<ValidationRequest>(br#"{"config": {}, "environment": "qa"}"#)
+            .unwrap_err();
+        assert_eq!(err.field.as_deref(), Some("environment"));
+        assert!(err.to_string().starts_with("environment: unknown variant `qa`"));
+
+        let error = ApiError::from(err);
+        assert_eq!(error.status_code(), StatusCode::BAD_REQUEST);
+        assert_eq!(error.error_code(), "BAD_REQUEST");
+    }
+
     #[test]
     fn test_api_error_responses() {
         let error = ApiError::BadRequest("Invalid input".to_string());
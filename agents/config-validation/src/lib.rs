@@ -32,9 +32,34 @@
 //!
 //! 5. **Validation** (`validation/`): Core validation engine with schema support.
 //!
-//! 6. **Schema** (`schema/`): Schema inference and type detection.
+//! 6. **Engine** (`engine/`): Rule-based validation engine; registered rules can
+//!    be opted into per request via `ValidationOptions::custom_rules`.
 //!
-//! 7. **Compatibility** (`compatibility/`): Cross-configuration compatibility checking.
+//! 7. **Schema** (`schema/`): Schema inference and type detection.
+//!
+//! 8. **Compatibility** (`compatibility/`): Cross-configuration compatibility checking.
+//!
+//! 9. **Audit** (`audit`): Append-only JSONL log of validation decisions,
+//!    written independently of telemetry emission.
+//!
+//! 10. **Environments** (`environments`): Validates a set of per-environment
+//!     configs together, including cross-environment rules.
+//!
+//! 11. **Report** (`report`): Consolidated Markdown, HTML, or JSON report over
+//!     many validated files.
+//!
+//! 12. **Migration** (`migration`): Plans and applies the changes a config
+//!     needs when its schema moves to a new version.
+//!
+//! 13. **Fuzz** (`fuzz`): Seeded generator of schemas and configs that checks
+//!     validation never panics and stays deterministic.
+//!
+//! ## Performance Budgets
+//!
+//! - MAX_TOKENS: 800
+//! - MAX_LATENCY_MS: 1500
+//!
+//! Decision events report how much of each budget a validation used.
 //!
 //! ## CLI Usage
 //!
@@ -97,11 +122,21 @@
 //! ```
 
 // Core modules
+pub mod audit;
 pub mod cli;
 pub mod client;
 pub mod compatibility;
+pub mod doctor;
+pub mod duplicate_keys;
+pub mod engine;
+pub mod environments;
 pub mod error;
+pub mod fuzz;
 pub mod handler;
+pub mod migration;
+pub mod normalize;
+pub mod patch;
+pub mod report;
 pub mod schema;
 pub mod telemetry;
 pub mod validation;
@@ -110,10 +145,35 @@ pub mod validation;
 #[path = "../contracts/mod.rs"]
 pub mod contracts;
 
+// Connection pool settings shared by the agents' HTTP clients
+#[path = "../../shared/http_pool.rs"]
+pub mod http_pool;
+
+// Injectable time and identifier sources
+#[path = "../../shared/clock.rs"]
+pub mod clock;
+#[path = "../../shared/ids.rs"]
+pub mod ids;
+
+// Version, commit, build time and compiler stamped by the build script
+#[path = "../../shared/build_info.rs"]
+pub mod build_info;
+
+// Diagnostic summary served by `GET /health`
+#[path = "../../shared/health.rs"]
+pub mod health;
+
+// Constant-time comparison for tokens and API keys
+#[path = "../../shared/secure_compare.rs"]
+pub mod secure_compare;
+
+// Configuration value model evaluated by the rule engine
+pub use llm_config_storage::{ConfigValue, Environment};
+
 // Re-export commonly used types from telemetry
 pub use client::RuvectorClient;
 pub use telemetry::{
-    DecisionEventEmitter, EmitterConfig, TelemetryConfig, TelemetryError,
+    DecisionEventEmitter, DecisionEventSink, EmitterConfig, TelemetryConfig, TelemetryError,
     ValidationMetrics, ValidationMetricsRegistry,
 };
 
@@ -133,7 +193,7 @@ pub use contracts::{
     ConfigValueRef, EnvironmentRef, RuleRef,
     // Schema types
     ConfigSchema, FieldRule, FieldType, ValidationConstraint,
-    EnvironmentRule, CompatibilityRule, DeprecationInfo, SchemaDefinition,
+    EnvironmentRule, CompatibilityRule, DeprecationInfo, SchemaDefinition, SchemaCoverageReport,
     // Decision event types
     DecisionEvent, ValidationOutputs,
     // Validation rule trait
@@ -142,15 +202,15 @@ pub use contracts::{
 
 // Re-export additional types from contract submodules (schemas and decision_event are pub)
 pub use contracts::schemas::{EnvironmentRuleType, CompatibilityRequirement};
-pub use contracts::decision_event::{DecisionType, PerformanceMetrics, IssueSummary};
+pub use contracts::decision_event::{DecisionType, PerformanceMetrics, IssueRollup, IssueSummary};
 
 // Re-export CLI types for command-line usage
-pub use cli::{ExitCode, OutputFormat, ValidateCli, ValidateCommands};
+pub use cli::{ExitCode, ExitReason, OutputFormat, ValidateCli, ValidateCommands};
 pub use cli::output::ValidationOutput as CliValidationOutput;
 
 // Re-export validation engine types
 pub use validation::{
-    ValidationContext, ValidationFinding, ValidationResult as CliValidationResult,
+    check_schema_shape, ValidationContext, ValidationFinding, ValidationResult as CliValidationResult,
     ValidationSeverity, Validator,
 };
 
@@ -192,11 +252,7 @@ pub fn run_cli(cli: ValidateCli) -> ExitCode {
         Ok(code) => code,
         Err(e) => {
             eprintln!("Error: {}", e);
-            if e.is_user_error() {
-                ExitCode::InvalidInput
-            } else {
-                ExitCode::InternalError
-            }
+            ExitCode::from_error(&e)
         }
     }
 }
@@ -0,0 +1,321 @@
+//! Migration between schema versions
+//!
+//! Compares two versions of a [`ConfigSchema`] and plans what a configuration
+//! written against the old one needs to satisfy the new one. Fields are
+//! addressed by dotted path (e.g. `database.pool.size`), nested fields
+//! included.
+//!
+//! - Renamed fields come from `DeprecationInfo::replacement`, declared in
+//!   either schema. A replacement without a dot names a sibling of the
+//!   deprecated field; one with a dot is a path from the root.
+//! - Newly required fields are those required by the new schema but not the
+//!   old one. Fields without a default cannot be filled in automatically and
+//!   are reported as unresolved.
+//! - Removed fields are in the old schema only and are not renamed.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, HashMap};
+
+use crate::contracts::{ConfigSchema, FieldRule};
+use crate::error::{Result, ValidationError};
+
+/// A field moved to a new path
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldRename {
+    /// Path in the old schema
+    pub from: String,
+    /// Path in the new schema
+    pub to: String,
+}
+
+/// A field the new schema requires that the old one did not
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RequiredField {
+    /// Field path
+    pub field: String,
+    /// Default filled in when the configuration lacks the field
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<Value>,
+}
+
+/// Steps to move a configuration from one schema version to another
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MigrationPlan {
+    /// Version of the old schema
+    pub from_version: String,
+    /// Version of the new schema
+    pub to_version: String,
+    /// Fields to move, sorted by old path
+    pub renamed: Vec<FieldRename>,
+    /// Newly required fields, sorted by path
+    pub newly_required: Vec<RequiredField>,
+    /// Fields to drop, sorted by path
+    pub removed: Vec<String>,
+}
+
+impl MigrationPlan {
+    /// Plan the migration from `old` to `new`
+    pub fn between(old: &ConfigSchema, new: &ConfigSchema) -> Self {
+        let old_fields = flatten(&old.fields);
+        let new_fields = flatten(&new.fields);
+
+        let mut renames = BTreeMap::new();
+        for fields in [&old_fields, &new_fields] {
+            for (path, rule) in fields {
+                let Some(replacement) = rule
+                    .deprecation
+                    .as_ref()
+                    .and_then(|d| d.replacement.as_deref())
+                else {
+                    continue;
+                };
+                let to = replacement_path(path, replacement);
+                if old_fields.contains_key(path) && new_fields.contains_key(&to) && to != *path {
+                    renames.entry(path.clone()).or_insert(to);
+                }
+            }
+        }
+
+        let newly_required = new_fields
+            .iter()
+            .filter(|(path, rule)| {
+                rule.required
+                    && !old_fields.get(*path).is_some_and(|old| old.required)
+                    && !renames
+                        .iter()
+                        .any(|(from, to)| to == *path && old_fields[from].required)
+            })
+            .map(|(path, rule)| RequiredField {
+                field: path.clone(),
+                default: rule.default.clone(),
+            })
+            .collect();
+
+        let removed = old_fields
+            .keys()
+            .filter(|path| !new_fields.contains_key(*path) && !renames.contains_key(*path))
+            .cloned()
+            .collect();
+
+        Self {
+            from_version: old.version.clone(),
+            to_version: new.version.clone(),
+            renamed: renames
+                .into_iter()
+                .map(|(from, to)| FieldRename { from, to })
+                .collect(),
+            newly_required,
+            removed,
+        }
+    }
+
+    /// Newly required fields with no default to fill them in
+    pub fn unresolved(&self) -> Vec<&str> {
+        self.newly_required
+            .iter()
+            .filter(|f| f.default.is_none())
+            .map(|f| f.field.as_str())
+            .collect()
+    }
+
+    /// Whether the plan has nothing to do
+    pub fn is_empty(&self) -> bool {
+        self.renamed.is_empty() && self.newly_required.is_empty() && self.removed.is_empty()
+    }
+
+    /// Apply the plan to a configuration, returning the migrated copy
+    ///
+    /// Renamed values move unless the new path is already set, defaults fill
+    /// missing newly required fields, and removed fields are dropped. Fails
+    /// when a newly required field has neither a value nor a default.
+    pub fn apply(&self, config: &Value) -> Result<Value> {
+        if !config.is_object() {
+            return Err(ValidationError::invalid_input(
+                "Configuration must be a JSON object",
+            ));
+        }
+        let mut migrated = config.clone();
+
+        for rename in &self.renamed {
+            if let Some(value) = remove_path(&mut migrated, &rename.from) {
+                if get_path(&migrated, &rename.to).is_none() {
+                    insert_path(&mut migrated, &rename.to, value);
+                }
+            }
+        }
+        for path in &self.removed {
+            remove_path(&mut migrated, path);
+        }
+
+        let mut missing = Vec::new();
+        for required in &self.newly_required {
+            if get_path(&migrated, &required.field).is_some() {
+                continue;
+            }
+            match &required.default {
+                Some(default) => insert_path(&mut migrated, &required.field, default.clone()),
+                None => missing.push(required.field.as_str()),
+            }
+        }
+        if !missing.is_empty() {
+            return Err(ValidationError::schema_error(format!(
+                "Migration to {} needs values for required fields without defaults: {}",
+                self.to_version,
+                missing.join(", ")
+            )));
+        }
+
+        Ok(migrated)
+    }
+}
+
+/// Every field with its dotted path
+fn flatten(fields: &HashMap<String, FieldRule>) -> BTreeMap<String, &FieldRule> {
+    fn walk<'a>(
+        fields: &'a HashMap<String, FieldRule>,
+        prefix: &str,
+        out: &mut BTreeMap<String, &'a FieldRule>,
+    ) {
+        for (key, rule) in fields {
+            let path = if prefix.is_empty() {
+                key.clone()
+            } else {
+                format!("{}.{}", prefix, key)
+            };
+            walk(&rule.nested_fields, &path, out);
+            out.insert(path, rule);
+        }
+    }
+
+    let mut out = BTreeMap::new();
+    walk(fields, "", &mut out);
+    out
+}
+
+/// Path a deprecated field's replacement refers to
+fn replacement_path(path: &str, replacement: &str) -> String {
+    match path.rsplit_once('.') {
+        Some((parent, _)) if !replacement.contains('.') => format!("{}.{}", parent, replacement),
+        _ => replacement.to_string(),
+    }
+}
+
+fn get_path<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.').try_fold(value, |current, key| current.get(key))
+}
+
+fn remove_path(value: &mut Value, path: &str) -> Option<Value> {
+    let (parent, key) = match path.rsplit_once('.') {
+        Some((parent, key)) => (
+            parent
+                .split('.')
+                .try_fold(value, |current, key| current.get_mut(key))?,
+            key,
+        ),
+        None => (value, path),
+    };
+    parent.as_object_mut()?.remove(key)
+}
+
+fn insert_path(value: &mut Value, path: &str, new_value: Value) {
+    let mut current = value;
+    let mut keys = path.split('.').peekable();
+    while let Some(key) = keys.next() {
+        if !current.is_object() {
+            *current = Value::Object(Map::new());
+        }
+        let Value::Object(map) = current else {
+            return;
+        };
+        if keys.peek().is_none() {
+            map.insert(key.to_string(), new_value);
+            return;
+        }
+        current = map
+            .entry(key.to_string())
+            .or_insert_with(|| Value::Object(Map::new()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contracts::{DeprecationInfo, FieldType};
+    use serde_json::json;
+
+    fn old_schema() -> ConfigSchema {
+        ConfigSchema::new("app/database", "Database", "1.0.0")
+            .with_field("db_url", FieldRule::required(FieldType::Url))
+            .with_field("legacy_mode", FieldRule::new(FieldType::Boolean))
+            .with_field(
+                "pool",
+                FieldRule::new(FieldType::Object)
+                    .with_nested_field("max", FieldRule::new(FieldType::Integer)),
+            )
+    }
+
+    fn new_schema() -> ConfigSchema {
+        let mut timeout = FieldRule::required(FieldType::Integer);
+        timeout.default = Some(json!(30));
+        ConfigSchema::new("app/database", "Database", "2.0.0")
+            .with_field("connection_url", FieldRule::required(FieldType::Url))
+            .with_field(
+                "db_url",
+                FieldRule::new(FieldType::Url).deprecated(
+                    DeprecationInfo::new("2.0.0", "Renamed").with_replacement("connection_url"),
+                ),
+            )
+            .with_field("timeout_secs", timeout)
+            .with_field("region", FieldRule::required(FieldType::String))
+            .with_field(
+                "pool",
+                FieldRule::new(FieldType::Object)
+                    .with_nested_field("max", FieldRule::new(FieldType::Integer)),
+            )
+    }
+
+    #[test]
+    fn test_plan_renames_deprecated_field_and_flags_required_without_default() {
+        let plan = MigrationPlan::between(&old_schema(), &new_schema());
+
+        assert_eq!(
+            plan.renamed,
+            [FieldRename {
+                from: "db_url".to_string(),
+                to: "connection_url".to_string()
+            }]
+        );
+        assert_eq!(plan.removed, ["legacy_mode"]);
+        // connection_url inherits db_url's required flag, so it is not new
+        let required: Vec<_> = plan.newly_required.iter().map(|f| f.field.as_str()).collect();
+        assert_eq!(required, ["region", "timeout_secs"]);
+        assert_eq!(plan.unresolved(), ["region"]);
+    }
+
+    #[test]
+    fn test_apply_moves_renamed_values_and_fills_defaults() {
+        let plan = MigrationPlan::between(&old_schema(), &new_schema());
+        let config = json!({
+            "db_url": "postgres://db/app",
+            "legacy_mode": true,
+            "pool": {"max": 10}
+        });
+
+        // The required field without a default has no value to fall back on
+        let err = plan.apply(&config).unwrap_err();
+        assert!(err.to_string().contains("region"), "{}", err);
+
+        let mut config = config;
+        config["region"] = json!("eu-west-1");
+        assert_eq!(
+            plan.apply(&config).unwrap(),
+            json!({
+                "connection_url": "postgres://db/app",
+                "pool": {"max": 10},
+                "region": "eu-west-1",
+                "timeout_secs": 30
+            })
+        );
+    }
+}
@@ -0,0 +1,155 @@
+//! Configuration normalization
+//!
+//! Produces a canonical form of a configuration so equal configurations
+//! compare and hash equal across tools, whatever their key order or number
+//! spelling. Normalization does not validate: values the schema does not
+//! describe are kept as they are.
+//!
+//! - Object keys are sorted (`serde_json::Map` keeps keys ordered)
+//! - Whole floats become integers (`1.0` becomes `1`, `-0.0` becomes `0`)
+//! - With a JSON schema, missing properties that declare a `default` are
+//!   filled in, and strings spelling a boolean or number are converted where
+//!   the schema expects one
+
+use serde_json::{Map, Number, Value};
+use sha2::{Digest, Sha256};
+
+use crate::error::Result;
+use crate::validation::resolve_ref;
+
+/// Serialize a value in canonical form: sorted keys, no whitespace
+///
+/// Decision `inputs_hash` values are computed over this form.
+pub fn canonical_bytes<T: serde::Serialize + ?Sized>(value: &T) -> Vec<u8> {
+    let canonical = serde_json::to_value(value).unwrap_or_default();
+    serde_json::to_vec(&canonical).unwrap_or_default()
+}
+
+/// Hex SHA-256 of the canonical bytes of a value
+pub fn canonical_hash<T: serde::Serialize + ?Sized>(value: &T) -> String {
+    hex::encode(Sha256::digest(canonical_bytes(value)))
+}
+
+/// Normalize a configuration, expanding defaults from `schema` if given
+pub fn normalize(config: &Value, schema: Option<&Value>) -> Result<Value> {
+    match schema {
+        Some(schema) => normalize_with_schema(config, schema, schema),
+        None => Ok(normalize_value(config)),
+    }
+}
+
+/// Normalize numbers throughout a value
+fn normalize_value(value: &Value) -> Value {
+    match value {
+        Value::Number(number) => Value::Number(normalize_number(number)),
+        Value::Array(items) => Value::Array(items.iter().map(normalize_value).collect()),
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(key, value)| (key.clone(), normalize_value(value)))
+                .collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+/// Write whole floats as integers
+fn normalize_number(number: &Number) -> Number {
+    match number.as_f64() {
+        Some(f) if number.is_f64() && f.fract() == 0.0 && f.abs() < i64::MAX as f64 => {
+            Number::from(f as i64)
+        }
+        _ => number.clone(),
+    }
+}
+
+fn normalize_with_schema(config: &Value, schema: &Value, root: &Value) -> Result<Value> {
+    let schema = resolve_ref(root, schema)?;
+    let expected = schema.get("type").and_then(|t| t.as_str());
+
+    match (config, expected) {
+        (Value::String(text), Some("boolean")) => Ok(match text.to_ascii_lowercase().as_str() {
+            "true" => Value::Bool(true),
+            "false" => Value::Bool(false),
+            _ => config.clone(),
+        }),
+        (Value::String(text), Some("integer" | "number")) => Ok(text
+            .trim()
+            .parse::<Number>()
+            .map(|n| Value::Number(normalize_number(&n)))
+            .unwrap_or_else(|_| config.clone())),
+        (Value::Array(items), _) => match schema.get("items") {
+            Some(item_schema) => items
+                .iter()
+                .map(|item| normalize_with_schema(item, item_schema, root))
+                .collect::<Result<Vec<_>>>()
+                .map(Value::Array),
+            None => Ok(normalize_value(config)),
+        },
+        (Value::Object(map), _) => {
+            let properties = schema.get("properties").and_then(|p| p.as_object());
+            let mut normalized = Map::new();
+            for (key, value) in map {
+                let value = match properties.and_then(|p| p.get(key)) {
+                    Some(property) => normalize_with_schema(value, property, root)?,
+                    None => normalize_value(value),
+                };
+                normalized.insert(key.clone(), value);
+            }
+            for (key, property) in properties.into_iter().flatten() {
+                if normalized.contains_key(key) {
+                    continue;
+                }
+                let property_schema = resolve_ref(root, property)?;
+                if let Some(default) = property_schema.get("default") {
+                    let value = normalize_with_schema(default, property, root)?;
+                    normalized.insert(key.clone(), value);
+                }
+            }
+            Ok(Value::Object(normalized))
+        }
+        _ => Ok(normalize_value(config)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_key_order_does_not_change_normalized_bytes() {
+        let a: Value = serde_json::from_str(r#"{"b": 1.0, "a": {"y": true, "x": [2, 1]}}"#).unwrap();
+        let b: Value = serde_json::from_str(r#"{"a": {"x": [2, 1], "y": true}, "b": 1}"#).unwrap();
+
+        let a = canonical_bytes(&normalize(&a, None).unwrap());
+        let b = canonical_bytes(&normalize(&b, None).unwrap());
+        assert_eq!(a, b);
+        assert_eq!(a, br#"{"a":{"x":[2,1],"y":true},"b":1}"#);
+    }
+
+    #[test]
+    fn test_schema_defaults_and_coercions() {
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "port": {"type": "integer", "default": 8080},
+                "tls": {"$ref": "#/definitions/tls"},
+                "debug": {"type": "boolean"},
+                "ratio": {"type": "number"}
+            },
+            "definitions": {
+                "tls": {
+                    "type": "object",
+                    "default": {},
+                    "properties": {"enabled": {"type": "boolean", "default": false}}
+                }
+            }
+        });
+        let config = json!({"debug": "TRUE", "ratio": "0.50"});
+
+        assert_eq!(
+            normalize(&config, Some(&schema)).unwrap(),
+            json!({"debug": true, "port": 8080, "ratio": 0.5, "tls": {"enabled": false}})
+        );
+    }
+}
@@ -0,0 +1,153 @@
+//! JSON Merge Patch support
+//!
+//! Implements RFC 7386 so a proposed change can be validated in the context
+//! of the existing configuration, with findings scoped to the paths the patch
+//! touches. Paths use the same dotted notation as handler findings
+//! (e.g. `database.pool.size`).
+
+use serde_json::Value;
+
+use crate::error::{Result, ValidationError};
+
+/// Apply a JSON Merge Patch to `target` in place (RFC 7386)
+///
+/// Object members set to `null` are removed; any non-object patch value
+/// replaces the target value wholesale.
+pub fn apply_merge_patch(target: &mut Value, patch: &Value) {
+    let Value::Object(patch_map) = patch else {
+        *target = patch.clone();
+        return;
+    };
+
+    if !target.is_object() {
+        *target = Value::Object(serde_json::Map::new());
+    }
+    let Value::Object(target_map) = target else {
+        return;
+    };
+
+    for (key, patch_value) in patch_map {
+        if patch_value.is_null() {
+            target_map.remove(key);
+        } else {
+            let entry = target_map.entry(key.clone()).or_insert(Value::Null);
+            apply_merge_patch(entry, patch_value);
+        }
+    }
+}
+
+/// Apply a configuration patch, returning the patched copy of `base`
+///
+/// Configuration patches must be JSON objects; replacing the whole document
+/// is not a patch and is rejected as invalid input.
+pub fn patch_config(base: &Value, patch: &Value) -> Result<Value> {
+    if !patch.is_object() {
+        return Err(ValidationError::invalid_input(
+            "Merge patch must be a JSON object",
+        ));
+    }
+    if !base.is_object() {
+        return Err(ValidationError::invalid_input(
+            "Base configuration must be a JSON object",
+        ));
+    }
+
+    let mut patched = base.clone();
+    apply_merge_patch(&mut patched, patch);
+    Ok(patched)
+}
+
+/// Dotted paths changed by a merge patch
+///
+/// Nested objects are descended into; every other value (including `null`
+/// removals) yields the path it is set at.
+pub fn changed_paths(patch: &Value) -> Vec<String> {
+    let mut paths = Vec::new();
+    collect_paths(patch, "", &mut paths);
+    paths
+}
+
+fn collect_paths(patch: &Value, prefix: &str, paths: &mut Vec<String>) {
+    let Value::Object(map) = patch else {
+        if !prefix.is_empty() {
+            paths.push(prefix.to_string());
+        }
+        return;
+    };
+
+    for (key, value) in map {
+        let path = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{}.{}", prefix, key)
+        };
+        match value {
+            Value::Object(nested) if !nested.is_empty() => collect_paths(value, &path, paths),
+            _ => paths.push(path),
+        }
+    }
+}
+
+/// Whether a finding at `path` relates to any of the changed paths
+///
+/// A finding is in scope when it is at, below, or directly above a changed
+/// path (e.g. a type error on `db` when the patch set `db.port`).
+pub fn path_in_scope(path: &str, changed: &[String]) -> bool {
+    changed.iter().any(|c| is_same_or_descendant(path, c) || is_same_or_descendant(c, path))
+}
+
+fn is_same_or_descendant(path: &str, ancestor: &str) -> bool {
+    path == ancestor
+        || (path.starts_with(ancestor) && path[ancestor.len()..].starts_with('.'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_merge_patch_rfc_examples() {
+        let cases = [
+            (json!({"a": "b"}), json!({"a": "c"}), json!({"a": "c"})),
+            (json!({"a": "b"}), json!({"b": "c"}), json!({"a": "b", "b": "c"})),
+            (json!({"a": "b"}), json!({"a": null}), json!({})),
+            (json!({"a": [{"b": "c"}]}), json!({"a": [1]}), json!({"a": [1]})),
+            (json!({"e": null}), json!({"a": 1}), json!({"e": null, "a": 1})),
+            (json!({}), json!({"a": {"bb": {"ccc": null}}}), json!({"a": {"bb": {}}})),
+            (json!(["a", "b"]), json!({"a": "b"}), json!({"a": "b"})),
+        ];
+
+        for (mut target, patch, expected) in cases {
+            apply_merge_patch(&mut target, &patch);
+            assert_eq!(target, expected);
+        }
+    }
+
+    #[test]
+    fn test_patch_config_rejects_non_object_patch() {
+        let base = json!({"a": 1});
+        assert!(matches!(
+            patch_config(&base, &json!([1, 2])),
+            Err(ValidationError::InvalidInput(_))
+        ));
+        assert!(patch_config(&json!("x"), &json!({"a": 1})).is_err());
+    }
+
+    #[test]
+    fn test_changed_paths() {
+        let patch = json!({"database": {"port": 5433, "ssl": null}, "name": "api"});
+        let mut paths = changed_paths(&patch);
+        paths.sort();
+        assert_eq!(paths, vec!["database.port", "database.ssl", "name"]);
+    }
+
+    #[test]
+    fn test_path_in_scope() {
+        let changed = vec!["database.port".to_string()];
+        assert!(path_in_scope("database.port", &changed));
+        assert!(path_in_scope("database", &changed));
+        assert!(!path_in_scope("database.host", &changed));
+        assert!(!path_in_scope("database.portal", &changed));
+    }
+}
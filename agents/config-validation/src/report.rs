@@ -0,0 +1,415 @@
+//! Consolidated reports over many validated files
+//!
+//! Collects one [`FileReport`] per validated file into an
+//! [`AggregateReport`] and renders it as a single Markdown, HTML, or JSON
+//! document listing each file's status, its top findings, and overall totals.
+//! HTML reports inline their styles so the file can be shared on its own.
+
+use serde::{Deserialize, Serialize};
+use std::fmt::Write as _;
+use std::path::Path;
+
+use crate::error::{Result, ValidationError};
+use crate::validation::{ValidationFinding, ValidationResult, ValidationSeverity};
+
+/// Findings listed per file; the rest are only counted
+pub const TOP_FINDINGS_PER_FILE: usize = 5;
+
+/// Document format of an aggregate report
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReportFormat {
+    /// Markdown with one section per file
+    Markdown,
+    /// Self-contained HTML page
+    Html,
+    /// The report as JSON
+    Json,
+}
+
+impl ReportFormat {
+    /// Pick the format from a report path's extension
+    ///
+    /// `.html`/`.htm` and `.json` select those formats; anything else is
+    /// written as Markdown.
+    pub fn from_path(path: &Path) -> Self {
+        let extension = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or("")
+            .to_lowercase();
+        match extension.as_str() {
+            "html" | "htm" => ReportFormat::Html,
+            "json" => ReportFormat::Json,
+            _ => ReportFormat::Markdown,
+        }
+    }
+}
+
+/// Validation outcome of one file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileReport {
+    /// Path of the validated file
+    pub path: String,
+    /// Whether the file validated without errors
+    pub valid: bool,
+    /// Number of errors found, critical findings included
+    pub error_count: usize,
+    /// Number of critical findings
+    #[serde(default)]
+    pub critical_count: usize,
+    /// Number of warnings found
+    pub warning_count: usize,
+    /// Number of info findings
+    pub info_count: usize,
+    /// Most severe findings, errors first, up to [`TOP_FINDINGS_PER_FILE`]
+    pub top_findings: Vec<ValidationFinding>,
+    /// Why the file could not be validated (read or parse failure)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failure: Option<String>,
+}
+
+impl FileReport {
+    /// Report a file that was validated
+    pub fn from_result(path: impl Into<String>, result: &ValidationResult) -> Self {
+        let count = |severity: ValidationSeverity| {
+            result.findings.iter().filter(|f| f.severity == severity).count()
+        };
+
+        let mut top_findings = result.findings.clone();
+        // Stable sort keeps each severity's findings in reported order
+        top_findings.sort_by_key(|f| std::cmp::Reverse(f.severity));
+        top_findings.truncate(TOP_FINDINGS_PER_FILE);
+
+        Self {
+            path: path.into(),
+            valid: result.valid,
+            error_count: count(ValidationSeverity::Critical) + count(ValidationSeverity::Error),
+            critical_count: count(ValidationSeverity::Critical),
+            warning_count: count(ValidationSeverity::Warning),
+            info_count: count(ValidationSeverity::Info),
+            top_findings,
+            failure: None,
+        }
+    }
+
+    /// Report a file that could not be validated
+    ///
+    /// The failure counts as one error.
+    pub fn failed(path: impl Into<String>, error: &ValidationError) -> Self {
+        Self {
+            path: path.into(),
+            valid: false,
+            error_count: 1,
+            critical_count: 0,
+            warning_count: 0,
+            info_count: 0,
+            top_findings: Vec::new(),
+            failure: Some(error.to_string()),
+        }
+    }
+
+    /// Short status label
+    pub fn status(&self) -> &'static str {
+        if self.failure.is_some() {
+            "failed"
+        } else if !self.valid {
+            "invalid"
+        } else if self.warning_count > 0 {
+            "warnings"
+        } else {
+            "valid"
+        }
+    }
+}
+
+/// Totals over every file in a report
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReportTotals {
+    /// Files in the report
+    pub files: usize,
+    /// Files that validated without errors
+    pub valid_files: usize,
+    /// Files with errors or that could not be validated
+    pub invalid_files: usize,
+    /// Errors across all files, critical findings included
+    pub errors: usize,
+    /// Critical findings across all files
+    #[serde(default)]
+    pub critical: usize,
+    /// Warnings across all files
+    pub warnings: usize,
+    /// Info findings across all files
+    pub info: usize,
+}
+
+/// Report over a set of validated files
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AggregateReport {
+    /// One entry per file, in validation order
+    pub files: Vec<FileReport>,
+    /// Overall totals
+    pub totals: ReportTotals,
+}
+
+impl AggregateReport {
+    /// Create an empty report
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a file and update the totals
+    pub fn push(&mut self, file: FileReport) {
+        let totals = &mut self.totals;
+        totals.files += 1;
+        if file.valid {
+            totals.valid_files += 1;
+        } else {
+            totals.invalid_files += 1;
+        }
+        totals.errors += file.error_count;
+        totals.critical += file.critical_count;
+        totals.warnings += file.warning_count;
+        totals.info += file.info_count;
+        self.files.push(file);
+    }
+
+    /// Whether any file has errors or failed to validate
+    pub fn has_errors(&self) -> bool {
+        self.totals.invalid_files > 0
+    }
+
+    /// Whether any file has critical findings
+    pub fn has_critical(&self) -> bool {
+        self.totals.critical > 0
+    }
+
+    /// Whether any file has warnings
+    pub fn has_warnings(&self) -> bool {
+        self.totals.warnings > 0
+    }
+
+    /// Render the report in `format`
+    pub fn render(&self, format: ReportFormat) -> Result<String> {
+        match format {
+            ReportFormat::Markdown => Ok(self.to_markdown()),
+            ReportFormat::Html => Ok(self.to_html()),
+            ReportFormat::Json => serde_json::to_string_pretty(self)
+                .map_err(|e| ValidationError::SerializationError(e.to_string())),
+        }
+    }
+
+    /// Render and write the report to `path`
+    pub fn write_to(&self, path: &Path, format: ReportFormat) -> Result<()> {
+        std::fs::write(path, self.render(format)?).map_err(|e| {
+            ValidationError::FileError(format!(
+                "Failed to write report '{}': {}",
+                path.display(),
+                e
+            ))
+        })
+    }
+
+    /// Render as Markdown
+    pub fn to_markdown(&self) -> String {
+        let t = &self.totals;
+        let mut out = String::new();
+        writeln!(out, "# Validation Report\n").ok();
+        writeln!(
+            out,
+            "{} file(s): {} valid, {} invalid. {} error(s), {} warning(s), {} info.\n",
+            t.files, t.valid_files, t.invalid_files, t.errors, t.warnings, t.info
+        )
+        .ok();
+        writeln!(out, "| File | Status | Errors | Warnings | Info |").ok();
+        writeln!(out, "|------|--------|--------|----------|------|").ok();
+        for file in &self.files {
+            writeln!(
+                out,
+                "| `{}` | {} | {} | {} | {} |",
+                file.path,
+                file.status(),
+                file.error_count,
+                file.warning_count,
+                file.info_count
+            )
+            .ok();
+        }
+
+        for file in &self.files {
+            writeln!(out, "\n## {}\n", file.path).ok();
+            writeln!(out, "Status: **{}**\n", file.status()).ok();
+            if let Some(failure) = &file.failure {
+                writeln!(out, "{}", failure).ok();
+            }
+            for finding in &file.top_findings {
+                writeln!(
+                    out,
+                    "- **{}** `{}` at `{}`: {}",
+                    finding.severity, finding.code, finding.path, finding.message
+                )
+                .ok();
+            }
+            let more = file.error_count + file.warning_count + file.info_count;
+            if more > file.top_findings.len() && file.failure.is_none() {
+                writeln!(out, "- ...and {} more", more - file.top_findings.len()).ok();
+            }
+        }
+        out
+    }
+
+    /// Render as a self-contained HTML page
+    pub fn to_html(&self) -> String {
+        let t = &self.totals;
+        let mut out = String::new();
+        out.push_str(
+            "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+             <title>Validation Report</title>\n<style>\n\
+             body { font-family: sans-serif; margin: 2rem; color: #222; }\n\
+             table { border-collapse: collapse; }\n\
+             th, td { border: 1px solid #ccc; padding: 0.3rem 0.6rem; text-align: left; }\n\
+             .valid { color: #1a7f37; } .warnings { color: #9a6700; }\n\
+             .invalid, .failed { color: #cf222e; }\n\
+             code { background: #f3f3f3; padding: 0 0.2rem; }\n\
+             </style>\n</head>\n<body>\n<h1>Validation Report</h1>\n",
+        );
+        writeln!(
+            out,
+            "<p>{} file(s): {} valid, {} invalid. {} error(s), {} warning(s), {} info.</p>",
+            t.files, t.valid_files, t.invalid_files, t.errors, t.warnings, t.info
+        )
+        .ok();
+        out.push_str(
+            "<table>\n<tr><th>File</th><th>Status</th><th>Errors</th><th>Warnings</th><th>Info</th></tr>\n",
+        );
+        for file in &self.files {
+            writeln!(
+                out,
+                "<tr><td><code>{}</code></td><td class=\"{status}\">{status}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+                escape_html(&file.path),
+                file.error_count,
+                file.warning_count,
+                file.info_count,
+                status = file.status()
+            )
+            .ok();
+        }
+        out.push_str("</table>\n");
+
+        for file in &self.files {
+            out.push_str("<section>\n");
+            writeln!(out, "<h2>{}</h2>", escape_html(&file.path)).ok();
+            writeln!(
+                out,
+                "<p>Status: <strong class=\"{status}\">{status}</strong></p>",
+                status = file.status()
+            )
+            .ok();
+            if let Some(failure) = &file.failure {
+                writeln!(out, "<p>{}</p>", escape_html(failure)).ok();
+            }
+            if !file.top_findings.is_empty() {
+                out.push_str("<ul>\n");
+                for finding in &file.top_findings {
+                    writeln!(
+                        out,
+                        "<li class=\"{}\"><strong>{}</strong> <code>{}</code> at <code>{}</code>: {}</li>",
+                        finding.severity,
+                        finding.severity,
+                        escape_html(&finding.code),
+                        escape_html(&finding.path),
+                        escape_html(&finding.message)
+                    )
+                    .ok();
+                }
+                out.push_str("</ul>\n");
+            }
+            out.push_str("</section>\n");
+        }
+        out.push_str("</body>\n</html>\n");
+        out
+    }
+}
+
+/// Escape text for use in HTML content and attributes
+pub(crate) fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_format_from_path() {
+        assert_eq!(ReportFormat::from_path(Path::new("out.html")), ReportFormat::Html);
+        assert_eq!(ReportFormat::from_path(Path::new("out.JSON")), ReportFormat::Json);
+        assert_eq!(ReportFormat::from_path(Path::new("report.md")), ReportFormat::Markdown);
+        assert_eq!(ReportFormat::from_path(Path::new("report")), ReportFormat::Markdown);
+    }
+
+    #[test]
+    fn test_file_report_lists_errors_first() {
+        let mut findings = vec![ValidationFinding::info("I001", "Note", "$")];
+        findings.extend((0..6).map(|i| ValidationFinding::warning("W001", "Odd", format!("$.w{}", i))));
+        findings.push(ValidationFinding::error("E001", "Bad", "$.e"));
+        let report = FileReport::from_result("app.yaml", &ValidationResult::with_findings(findings));
+
+        assert_eq!(report.status(), "invalid");
+        assert_eq!((report.error_count, report.warning_count, report.info_count), (1, 6, 1));
+        assert_eq!(report.top_findings.len(), TOP_FINDINGS_PER_FILE);
+        assert_eq!(report.top_findings[0].code, "E001");
+
+        let mut aggregate = AggregateReport::new();
+        aggregate.push(report);
+        assert!(aggregate.to_markdown().contains("...and 3 more"));
+    }
+
+    #[test]
+    fn test_critical_findings_are_totalled() {
+        let findings = vec![
+            ValidationFinding::error("E001", "Bad", "$.e"),
+            ValidationFinding::critical("S001", "Secret", "$.password"),
+        ];
+        let report = FileReport::from_result("app.yaml", &ValidationResult::with_findings(findings));
+        assert_eq!((report.error_count, report.critical_count), (2, 1));
+        assert_eq!(report.top_findings[0].code, "S001");
+
+        let mut aggregate = AggregateReport::new();
+        assert!(!aggregate.has_critical());
+        aggregate.push(report);
+        assert_eq!(aggregate.totals.critical, 1);
+        assert!(aggregate.has_critical());
+    }
+
+    #[test]
+    fn test_html_escapes_content() {
+        let mut report = AggregateReport::new();
+        report.push(FileReport::from_result(
+            "<app>.yaml",
+            &ValidationResult::with_findings(vec![ValidationFinding::error(
+                "E005",
+                "String does not match pattern '^a&b$'",
+                "$.name",
+            )]),
+        ));
+
+        let html = report.to_html();
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<style>"));
+        assert!(html.contains("&lt;app&gt;.yaml"));
+        assert!(html.contains("&#39;^a&amp;b$&#39;"));
+        assert!(!html.contains("<app>"));
+    }
+}
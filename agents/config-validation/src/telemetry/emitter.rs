@@ -35,6 +35,7 @@ use sha2::{Digest, Sha256};
 use std::sync::Arc;
 use tokio::sync::mpsc;
 
+use super::worker_pool::{self, DEFAULT_WORKERS};
 use super::{Result, TelemetryError};
 use crate::client::ruvector::RuvectorClient;
 use crate::contracts::{DecisionEvent, ValidationInput, ValidationOutput};
@@ -62,6 +63,9 @@ pub struct EmitterConfig {
 
     /// Initial backoff delay in milliseconds
     pub initial_backoff_ms: u64,
+
+    /// Worker tasks emitting queued events concurrently
+    pub workers: usize,
 }
 
 impl Default for EmitterConfig {
@@ -74,6 +78,7 @@ impl Default for EmitterConfig {
             agent_version: DecisionEvent::AGENT_VERSION.to_string(),
             max_retries: 3,
             initial_backoff_ms: 100,
+            workers: DEFAULT_WORKERS,
         }
     }
 }
@@ -96,10 +101,8 @@ pub fn calculate_inputs_hash(input: &ValidationInput) -> String {
     hasher.update(input.namespace.as_bytes());
     hasher.update(input.key.as_bytes());
 
-    // Hash the value (serialized)
-    if let Ok(value_json) = serde_json::to_string(&input.value) {
-        hasher.update(value_json.as_bytes());
-    }
+    // Hash the value in canonical form, so object key order does not matter
+    hasher.update(crate::normalize::canonical_bytes(&input.value));
 
     // Hash the environment
     hasher.update(format!("{:?}", input.environment).as_bytes());
@@ -162,6 +165,17 @@ pub fn build_constraints_list(
     constraints
 }
 
+/// Destination for DecisionEvents
+///
+/// Implemented by [`DecisionEventEmitter`]; anything that accepts an emitter
+/// through this trait can also be handed a local sink (for example one that
+/// records events in tests).
+#[async_trait::async_trait]
+pub trait DecisionEventSink: Send + Sync {
+    /// Emit a single decision event
+    async fn emit(&self, event: DecisionEvent) -> Result<()>;
+}
+
 /// Async, non-blocking DecisionEvent emitter
 pub struct DecisionEventEmitter {
     config: EmitterConfig,
@@ -177,14 +191,16 @@ impl DecisionEventEmitter {
             config.timeout_ms,
         ));
 
-        let (sender, mut receiver) = mpsc::channel::<DecisionEvent>(config.max_queue_size);
+        let (sender, receiver) = mpsc::channel::<DecisionEvent>(config.max_queue_size);
 
-        // Spawn background task to process events
-        let client_clone = Arc::clone(&client);
-        tokio::spawn(async move {
-            while let Some(event) = receiver.recv().await {
+        // Spawn background workers to process events, so one slow request
+        // doesn't hold up the rest of the queue
+        let worker_client = Arc::clone(&client);
+        worker_pool::spawn_workers(receiver, config.workers, move |event: DecisionEvent| {
+            let client = Arc::clone(&worker_client);
+            async move {
                 // Non-blocking emission - log errors but don't fail
-                if let Err(e) = client_clone.persist_decision_event(&event).await {
+                if let Err(e) = client.persist_decision_event(&event).await {
                     tracing::warn!(
                         event_id = %event.event_id,
                         error = %e,
@@ -272,6 +288,13 @@ impl DecisionEventEmitter {
     }
 }
 
+#[async_trait::async_trait]
+impl DecisionEventSink for DecisionEventEmitter {
+    async fn emit(&self, event: DecisionEvent) -> Result<()> {
+        DecisionEventEmitter::emit(self, event).await
+    }
+}
+
 /// Builder for DecisionEventEmitter
 pub struct EmitterBuilder {
     config: EmitterConfig,
@@ -322,6 +345,12 @@ impl EmitterBuilder {
         self
     }
 
+    /// Set the number of worker tasks emitting events concurrently
+    pub fn workers(mut self, workers: usize) -> Self {
+        self.config.workers = workers;
+        self
+    }
+
     /// Build the emitter
     pub fn build(self) -> DecisionEventEmitter {
         DecisionEventEmitter::new(self.config)
@@ -338,6 +367,8 @@ impl Default for EmitterBuilder {
 mod tests {
     use super::*;
     use crate::contracts::{ConfigValueRef, EnvironmentRef};
+    use std::sync::atomic::Ordering;
+    use std::time::Duration;
     use uuid::Uuid;
 
     fn create_test_input() -> ValidationInput {
@@ -442,7 +473,8 @@ mod tests {
             .timeout_ms(10000)
             .agent_id("custom-agent")
             .agent_version("1.0.0")
-            .retry_config(5, 200);
+            .retry_config(5, 200)
+            .workers(8);
 
         assert_eq!(builder.config.endpoint, "http://custom:8080");
         assert_eq!(builder.config.max_queue_size, 500);
@@ -451,6 +483,7 @@ mod tests {
         assert_eq!(builder.config.agent_version, "1.0.0");
         assert_eq!(builder.config.max_retries, 5);
         assert_eq!(builder.config.initial_backoff_ms, 200);
+        assert_eq!(builder.config.workers, 8);
     }
 
     #[test]
@@ -468,4 +501,25 @@ mod tests {
         assert!(event.outputs.is_valid);
         assert!(event.confidence >= 0.0 && event.confidence <= 1.0);
     }
+
+    #[tokio::test]
+    async fn test_workers_emit_slow_events_concurrently() {
+        let server = crate::http_pool::testing::slow_server(Duration::from_millis(300)).await;
+        let emitter = EmitterBuilder::new()
+            .endpoint(server.url.clone())
+            .workers(4)
+            .build();
+
+        for i in 0..4 {
+            let event = DecisionEvent::from_validation(
+                format!("hash-{}", i),
+                &create_test_output(),
+                "exec-ref".to_string(),
+            );
+            emitter.emit(event).await.unwrap();
+        }
+        server.wait_for(4).await;
+
+        assert_eq!(server.peak_in_flight.load(Ordering::SeqCst), 4);
+    }
 }
@@ -211,12 +211,7 @@ impl ValidationMetrics {
 
     /// Record a finding from IssueSeverity enum
     pub fn record_finding_severity(&self, severity: IssueSeverity, code: &str, environment: &str) {
-        let severity_str = match severity {
-            IssueSeverity::Error => "error",
-            IssueSeverity::Warning => "warning",
-            IssueSeverity::Info => "info",
-        };
-        self.record_finding(severity_str, code, environment);
+        self.record_finding(severity.as_str(), code, environment);
     }
 
     /// Record multiple findings
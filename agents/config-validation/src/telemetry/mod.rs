@@ -16,7 +16,11 @@
 pub mod emitter;
 pub mod metrics;
 
-pub use emitter::{DecisionEventEmitter, EmitterConfig};
+// Bounded worker pool shared by the agents' telemetry background tasks
+#[path = "../../../shared/worker_pool.rs"]
+pub mod worker_pool;
+
+pub use emitter::{DecisionEventEmitter, DecisionEventSink, EmitterConfig};
 pub use metrics::{ValidationMetrics, ValidationMetricsRegistry};
 
 use thiserror::Error;
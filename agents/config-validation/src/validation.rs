@@ -6,29 +6,12 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use crate::contracts::ValidationIssue;
+use crate::engine::rules::type_check::StringFormat;
 use crate::error::{Result, ValidationError};
 
-/// Severity levels for validation findings
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
-pub enum ValidationSeverity {
-    /// Critical error that must be fixed
-    Error,
-    /// Warning that should be addressed
-    Warning,
-    /// Informational finding
-    Info,
-}
-
-impl std::fmt::Display for ValidationSeverity {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            ValidationSeverity::Error => write!(f, "error"),
-            ValidationSeverity::Warning => write!(f, "warning"),
-            ValidationSeverity::Info => write!(f, "info"),
-        }
-    }
-}
+/// Severity levels for validation findings, shared with the contracts
+pub type ValidationSeverity = crate::contracts::Severity;
 
 /// A single validation finding
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -50,6 +33,18 @@ pub struct ValidationFinding {
 }
 
 impl ValidationFinding {
+    /// Create a new critical finding
+    pub fn critical(code: impl Into<String>, message: impl Into<String>, path: impl Into<String>) -> Self {
+        Self {
+            severity: ValidationSeverity::Critical,
+            code: code.into(),
+            message: message.into(),
+            path: path.into(),
+            suggestion: None,
+            doc_link: None,
+        }
+    }
+
     /// Create a new error finding
     pub fn error(code: impl Into<String>, message: impl Into<String>, path: impl Into<String>) -> Self {
         Self {
@@ -97,6 +92,59 @@ impl ValidationFinding {
         self.doc_link = Some(link.into());
         self
     }
+
+    /// Stable identity of this finding, independent of severity and suggestions
+    ///
+    /// Two findings share a fingerprint when they have the same code, path and
+    /// message, which makes it usable for comparing results across runs.
+    pub fn fingerprint(&self) -> String {
+        use sha2::{Digest, Sha256};
+
+        let mut hasher = Sha256::new();
+        hasher.update(self.code.as_bytes());
+        hasher.update([0]);
+        hasher.update(self.path.as_bytes());
+        hasher.update([0]);
+        hasher.update(self.message.as_bytes());
+        hex::encode(hasher.finalize())
+    }
+}
+
+/// Convert a finding to a contract issue
+///
+/// An empty path becomes no path. The documentation link is not carried
+/// over, as issues have no field for it.
+impl From<ValidationFinding> for ValidationIssue {
+    fn from(finding: ValidationFinding) -> Self {
+        Self {
+            code: finding.code,
+            message: finding.message,
+            severity: finding.severity,
+            path: (!finding.path.is_empty()).then_some(finding.path),
+            rule_id: None,
+            expected: None,
+            actual: None,
+            suggestion: finding.suggestion,
+        }
+    }
+}
+
+/// Convert a contract issue to a finding
+///
+/// The issue's rule id, expected and actual values have no counterpart here
+/// and are dropped; engine findings keep them (see
+/// [`crate::engine::rules::ValidationFinding::from_issue`]).
+impl From<ValidationIssue> for ValidationFinding {
+    fn from(issue: ValidationIssue) -> Self {
+        Self {
+            severity: issue.severity,
+            code: issue.code,
+            message: issue.message,
+            path: issue.path.unwrap_or_default(),
+            suggestion: issue.suggestion,
+            doc_link: None,
+        }
+    }
 }
 
 /// Result of a validation operation
@@ -123,7 +171,7 @@ impl ValidationResult {
 
     /// Create a result with findings
     pub fn with_findings(findings: Vec<ValidationFinding>) -> Self {
-        let valid = !findings.iter().any(|f| f.severity == ValidationSeverity::Error);
+        let valid = !findings.iter().any(|f| f.severity.is_blocking());
         Self {
             valid,
             findings,
@@ -133,23 +181,32 @@ impl ValidationResult {
 
     /// Add a finding
     pub fn add_finding(&mut self, finding: ValidationFinding) {
-        if finding.severity == ValidationSeverity::Error {
+        if finding.severity.is_blocking() {
             self.valid = false;
         }
         self.findings.push(finding);
     }
 
+    /// Recompute `valid` so it fails on findings at or above `fail_on`
+    ///
+    /// Results fail on errors by default; a lower threshold also fails on
+    /// warnings (or on any finding at all with `Info`).
+    pub fn with_fail_threshold(mut self, fail_on: ValidationSeverity) -> Self {
+        self.valid = !self.findings.iter().any(|f| f.severity.is_at_least(fail_on));
+        self
+    }
+
     /// Set the duration
     pub fn with_duration(mut self, ms: u64) -> Self {
         self.duration_ms = Some(ms);
         self
     }
 
-    /// Get all errors
+    /// Get all errors, critical ones included
     pub fn errors(&self) -> Vec<&ValidationFinding> {
         self.findings
             .iter()
-            .filter(|f| f.severity == ValidationSeverity::Error)
+            .filter(|f| f.severity.is_blocking())
             .collect()
     }
 
@@ -162,6 +219,116 @@ impl ValidationResult {
     }
 }
 
+/// Difference between the findings of two validation results
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FindingsDiff {
+    /// Findings present before but not after
+    pub fixed: Vec<ValidationFinding>,
+    /// Findings present after but not before
+    pub introduced: Vec<ValidationFinding>,
+    /// Findings present in both with the same severity (as reported after)
+    pub unchanged: Vec<ValidationFinding>,
+    /// Findings present in both whose severity changed (as reported after)
+    #[serde(default)]
+    pub severity_changed: Vec<ValidationFinding>,
+}
+
+impl FindingsDiff {
+    /// Diff two results by finding fingerprint
+    ///
+    /// Repeated findings are matched one-to-one, so a finding reported twice
+    /// before and once after yields one unchanged and one fixed entry.
+    pub fn between(before: &ValidationResult, after: &ValidationResult) -> Self {
+        let mut remaining: HashMap<String, Vec<&ValidationFinding>> = HashMap::new();
+        for finding in &before.findings {
+            remaining
+                .entry(finding.fingerprint())
+                .or_default()
+                .push(finding);
+        }
+
+        let mut diff = Self::default();
+        for finding in &after.findings {
+            match remaining.get_mut(&finding.fingerprint()).and_then(|f| f.pop()) {
+                Some(previous) if previous.severity != finding.severity => {
+                    diff.severity_changed.push(finding.clone())
+                }
+                Some(_) => diff.unchanged.push(finding.clone()),
+                None => diff.introduced.push(finding.clone()),
+            }
+        }
+
+        // Keep fixed findings in their original order
+        let mut still_present: HashMap<String, usize> = remaining
+            .into_iter()
+            .map(|(fingerprint, findings)| (fingerprint, findings.len()))
+            .collect();
+        for finding in &before.findings {
+            if let Some(count) = still_present.get_mut(&finding.fingerprint()) {
+                if *count > 0 {
+                    *count -= 1;
+                    diff.fixed.push(finding.clone());
+                }
+            }
+        }
+
+        diff
+    }
+
+    /// Whether the newer result adds any error findings
+    ///
+    /// Includes existing findings escalated to error, e.g. warning to error.
+    pub fn introduces_errors(&self) -> bool {
+        self.introduced
+            .iter()
+            .chain(&self.severity_changed)
+            .any(|f| f.severity.is_blocking())
+    }
+}
+
+/// Result of validating one configuration against two schema versions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SchemaComparison {
+    /// Result against the current schema
+    pub before: ValidationResult,
+    /// Result against the candidate schema
+    pub after: ValidationResult,
+    /// Finding changes from `before` to `after`
+    pub diff: FindingsDiff,
+}
+
+impl SchemaComparison {
+    /// Whether upgrading would make a currently valid configuration fail
+    pub fn upgrade_breaks_config(&self) -> bool {
+        self.before.valid && !self.after.valid
+    }
+}
+
+/// Validate a configuration against two schema versions and diff the findings
+///
+/// Both runs use the same context, so only schema changes affect the diff.
+pub fn compare_schema_versions(
+    config: &serde_json::Value,
+    before_schema: &str,
+    after_schema: &str,
+    context: ValidationContext,
+) -> Result<SchemaComparison> {
+    let mut before_validator = Validator::new(context.clone());
+    before_validator.load_schema(before_schema)?;
+    let mut after_validator = Validator::new(context);
+    after_validator.load_schema(after_schema)?;
+
+    let before = before_validator.validate(config)?;
+    let after = after_validator.validate(config)?;
+    let diff = FindingsDiff::between(&before, &after);
+
+    Ok(SchemaComparison {
+        before,
+        after,
+        diff,
+    })
+}
+
 /// Context for validation operations
 #[derive(Debug, Clone)]
 pub struct ValidationContext {
@@ -169,6 +336,11 @@ pub struct ValidationContext {
     pub environment: String,
     /// Whether to use strict mode
     pub strict_mode: bool,
+    /// Whether a schema `format` mismatch is an error (assertion) or only a
+    /// warning (annotation)
+    pub assert_formats: bool,
+    /// Lowest severity that makes the result invalid
+    pub fail_on: ValidationSeverity,
     /// Custom rules to apply
     pub custom_rules: Vec<String>,
     /// Variables for rule evaluation
@@ -180,6 +352,8 @@ impl Default for ValidationContext {
         Self {
             environment: "production".to_string(),
             strict_mode: false,
+            assert_formats: true,
+            fail_on: ValidationSeverity::Error,
             custom_rules: Vec::new(),
             variables: HashMap::new(),
         }
@@ -204,6 +378,18 @@ impl ValidationContext {
         self
     }
 
+    /// Set whether schema `format` mismatches are errors or warnings
+    pub fn with_format_assertion(mut self, assert: bool) -> Self {
+        self.assert_formats = assert;
+        self
+    }
+
+    /// Set the lowest severity that fails validation
+    pub fn with_fail_on(mut self, fail_on: ValidationSeverity) -> Self {
+        self.fail_on = fail_on;
+        self
+    }
+
     /// Add a custom rule
     pub fn with_rule(mut self, rule: impl Into<String>) -> Self {
         self.custom_rules.push(rule.into());
@@ -248,6 +434,22 @@ impl Validator {
     pub fn load_schema(&mut self, schema_content: &str) -> Result<()> {
         let schema: serde_json::Value = serde_json::from_str(schema_content)
             .map_err(|e| ValidationError::SchemaError(format!("Invalid schema: {}", e)))?;
+        self.load_schema_value(schema)
+    }
+
+    /// Load an already parsed schema for validation
+    ///
+    /// The schema is checked to be a well-formed JSON Schema (see
+    /// [`check_schema_shape`]) before it is used.
+    pub fn load_schema_value(&mut self, schema: serde_json::Value) -> Result<()> {
+        check_schema_shape(&schema)?;
+        if let Some(version) = schema.get("schema_format_version").and_then(|v| v.as_str()) {
+            let warning = crate::contracts::SchemaDefinition::check_format_version(version)
+                .map_err(ValidationError::SchemaError)?;
+            if let Some(warning) = warning {
+                tracing::warn!("{}", warning);
+            }
+        }
         self.schema = Some(schema);
         Ok(())
     }
@@ -270,7 +472,27 @@ impl Validator {
         }
 
         let duration = start.elapsed().as_millis() as u64;
-        Ok(result.with_duration(duration))
+        Ok(result
+            .with_fail_threshold(self.context.fail_on)
+            .with_duration(duration))
+    }
+
+    /// Validate a configuration parsed from source text, also reporting the
+    /// keys its source repeats
+    ///
+    /// Parsers keep only the last value of a repeated key, so `duplicates`
+    /// must be found in the source beforehand (see
+    /// [`crate::duplicate_keys`]). Each becomes a warning.
+    pub fn validate_source(
+        &self,
+        config: &serde_json::Value,
+        duplicates: &[crate::duplicate_keys::DuplicateKey],
+    ) -> Result<ValidationResult> {
+        let mut result = self.validate(config)?;
+        for duplicate in duplicates {
+            result.add_finding(duplicate.to_finding());
+        }
+        Ok(result.with_fail_threshold(self.context.fail_on))
     }
 
     /// Validate configuration against schema
@@ -281,6 +503,9 @@ impl Validator {
         path: &str,
         result: &mut ValidationResult,
     ) -> Result<()> {
+        let root = self.schema.as_ref().unwrap_or(schema);
+        let schema = resolve_ref(root, schema)?;
+
         // Check type
         if let Some(expected_type) = schema.get("type").and_then(|t| t.as_str()) {
             let actual_type = get_json_type(config);
@@ -330,6 +555,36 @@ impl Validator {
             }
         }
 
+        // Check keys not declared in `properties`
+        if let (serde_json::Value::Object(config_obj), Some(additional)) =
+            (config, schema.get("additionalProperties"))
+        {
+            let declared = schema.get("properties").and_then(|p| p.as_object());
+            let extra = config_obj
+                .iter()
+                .filter(|(key, _)| !declared.is_some_and(|d| d.contains_key(key.as_str())));
+            for (key, value) in extra {
+                let prop_path = format!("{}.{}", path, key);
+                match additional {
+                    serde_json::Value::Bool(false) => result.add_finding(
+                        ValidationFinding::error(
+                            "E012",
+                            format!("Unexpected field '{}'", key),
+                            &prop_path,
+                        )
+                        .with_suggestion(format!(
+                            "Remove '{}' or declare it in the schema's properties",
+                            key
+                        )),
+                    ),
+                    serde_json::Value::Object(_) => {
+                        self.validate_against_schema(value, additional, &prop_path, result)?
+                    }
+                    _ => {}
+                }
+            }
+        }
+
         // Validate array items
         if let (serde_json::Value::Array(items), Some(items_schema)) =
             (config, schema.get("items"))
@@ -343,9 +598,90 @@ impl Validator {
         // Check constraints
         self.validate_constraints(config, schema, path, result)?;
 
+        // Check combinators
+        self.validate_combinators(config, schema, path, result)?;
+
         Ok(())
     }
 
+    /// Validate `allOf`, `anyOf`, and `oneOf` combinators
+    ///
+    /// `allOf` reports every branch's findings directly. `anyOf` and `oneOf`
+    /// check each branch on its own and report a single finding for the value
+    /// when no branch matches (or, for `oneOf`, more than one does), pointing
+    /// at the closest branch's first error.
+    fn validate_combinators(
+        &self,
+        config: &serde_json::Value,
+        schema: &serde_json::Value,
+        path: &str,
+        result: &mut ValidationResult,
+    ) -> Result<()> {
+        if let Some(branches) = schema.get("allOf").and_then(|v| v.as_array()) {
+            for branch in branches {
+                self.validate_against_schema(config, branch, path, result)?;
+            }
+        }
+
+        if let Some(branches) = schema.get("anyOf").and_then(|v| v.as_array()) {
+            let outcomes = self.evaluate_branches(config, branches, path)?;
+            if !outcomes.iter().any(|errors| errors.is_empty()) {
+                result.add_finding(no_branch_matched("E009", "anyOf", &outcomes, path));
+            }
+        }
+
+        if let Some(branches) = schema.get("oneOf").and_then(|v| v.as_array()) {
+            let outcomes = self.evaluate_branches(config, branches, path)?;
+            let matched: Vec<String> = outcomes
+                .iter()
+                .enumerate()
+                .filter(|(_, errors)| errors.is_empty())
+                .map(|(i, _)| i.to_string())
+                .collect();
+            match matched.len() {
+                0 => result.add_finding(no_branch_matched("E010", "oneOf", &outcomes, path)),
+                1 => {}
+                n => result.add_finding(
+                    ValidationFinding::error(
+                        "E011",
+                        format!(
+                            "Value matches {} oneOf schemas ({}) but must match exactly one",
+                            n,
+                            matched.join(", ")
+                        ),
+                        path,
+                    )
+                    .with_suggestion("Change the value so only one of the oneOf schemas applies"),
+                ),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Validate `config` against each combinator branch separately
+    ///
+    /// Returns the errors found for each branch, in branch order.
+    fn evaluate_branches(
+        &self,
+        config: &serde_json::Value,
+        branches: &[serde_json::Value],
+        path: &str,
+    ) -> Result<Vec<Vec<ValidationFinding>>> {
+        branches
+            .iter()
+            .map(|branch| {
+                let mut scratch = ValidationResult::valid();
+                self.validate_against_schema(config, branch, path, &mut scratch)?;
+                Ok(scratch
+                    .findings
+                    .into_iter()
+                    .filter(|f| f.severity.is_blocking())
+                    .collect())
+            })
+            .collect()
+    }
+
     /// Validate value constraints
     fn validate_constraints(
         &self,
@@ -385,6 +721,18 @@ impl Validator {
                     }
                 }
             }
+            if let Some(format) = schema.get("format").and_then(|v| v.as_str()) {
+                let known = StringFormat::from_schema_format(format);
+                if known.is_some_and(|f| !f.matches(s)) {
+                    let message = format!("String is not a valid '{}'", format);
+                    let finding = if self.context.assert_formats {
+                        ValidationFinding::error("FORMAT_MISMATCH", message, path)
+                    } else {
+                        ValidationFinding::warning("FORMAT_MISMATCH", message, path)
+                    };
+                    result.add_finding(finding);
+                }
+            }
         }
 
         // Number constraints
@@ -407,6 +755,33 @@ impl Validator {
                     ));
                 }
             }
+            if let Some(step) = schema.get("multipleOf").and_then(|v| v.as_f64()) {
+                if step > 0.0 && !is_multiple_of(num, step) {
+                    result.add_finding(ValidationFinding::error(
+                        "E013",
+                        format!("Value {} is not a multiple of {}", num, step),
+                        path,
+                    ));
+                }
+            }
+            if let Some(min) = exclusive_bound(schema, "exclusiveMinimum", "minimum") {
+                if num <= min {
+                    result.add_finding(ValidationFinding::error(
+                        "E014",
+                        format!("Value {} must be greater than {}", num, min),
+                        path,
+                    ));
+                }
+            }
+            if let Some(max) = exclusive_bound(schema, "exclusiveMaximum", "maximum") {
+                if num >= max {
+                    result.add_finding(ValidationFinding::error(
+                        "E015",
+                        format!("Value {} must be less than {}", num, max),
+                        path,
+                    ));
+                }
+            }
         }
 
         // Enum constraints
@@ -431,6 +806,246 @@ impl Validator {
     }
 }
 
+/// Keywords whose presence marks a document as a JSON Schema
+///
+/// Annotations such as `title` and `default` are left out as configurations
+/// use those names too.
+const SCHEMA_KEYWORDS: &[&str] = &[
+    "$schema",
+    "$id",
+    "$ref",
+    "$defs",
+    "definitions",
+    "type",
+    "properties",
+    "patternProperties",
+    "additionalProperties",
+    "required",
+    "items",
+    "enum",
+    "const",
+    "allOf",
+    "anyOf",
+    "oneOf",
+    "not",
+];
+
+/// Type names a JSON Schema `type` may use
+const SCHEMA_TYPES: &[&str] = &[
+    "null", "boolean", "object", "array", "number", "integer", "string",
+];
+
+/// Check that a document is a well-formed JSON Schema before validating with it
+///
+/// Catches the usual mix-ups early, with a [`ValidationError::SchemaError`]
+/// saying what is wrong instead of a validation that silently checks
+/// nothing: a configuration passed as the schema (no schema keywords), a
+/// `ConfigSchema` document (it has `fields`), or malformed `type`,
+/// `properties`, `required` and `enum` keywords anywhere in the schema.
+/// Schema definition documents (with `schema_format_version`), schemas
+/// holding only `environment_rules`, the empty schema and boolean schemas are
+/// accepted.
+pub fn check_schema_shape(schema: &serde_json::Value) -> Result<()> {
+    let Some(object) = schema.as_object() else {
+        if schema.is_boolean() {
+            return Ok(());
+        }
+        return Err(ValidationError::SchemaError(format!(
+            "Schema must be a JSON object, found {}",
+            get_json_type(schema)
+        )));
+    };
+    if object.is_empty() || object.contains_key("schema_format_version") {
+        return Ok(());
+    }
+    let has_keywords = SCHEMA_KEYWORDS.iter().any(|k| object.contains_key(*k));
+    if !has_keywords && !object.contains_key("environment_rules") {
+        if object.contains_key("fields") {
+            return Err(ValidationError::SchemaError(
+                "Schema looks like a ConfigSchema (it has 'fields'), not a JSON Schema; \
+                 this command expects a JSON Schema with 'type' and 'properties'"
+                    .to_string(),
+            ));
+        }
+        let mut keys: Vec<&str> = object.keys().map(String::as_str).take(5).collect();
+        if object.len() > keys.len() {
+            keys.push("...");
+        }
+        return Err(ValidationError::SchemaError(format!(
+            "Schema has no JSON Schema keywords such as 'type', 'properties' or '$ref' \
+             (found keys: {}); is a configuration file being passed as the schema?",
+            keys.join(", ")
+        )));
+    }
+    check_subschema(schema, "#")
+}
+
+/// Check the structural keywords of a schema and its subschemas
+fn check_subschema(schema: &serde_json::Value, pointer: &str) -> Result<()> {
+    use serde_json::Value;
+
+    let invalid = |keyword: &str, expected: &str| {
+        Err(ValidationError::SchemaError(format!(
+            "Invalid schema at {}: '{}' must be {}",
+            pointer, keyword, expected
+        )))
+    };
+    let object = match schema {
+        Value::Bool(_) => return Ok(()),
+        Value::Object(object) => object,
+        other => {
+            return Err(ValidationError::SchemaError(format!(
+                "Invalid schema at {}: expected an object, found {}",
+                pointer,
+                get_json_type(other)
+            )))
+        }
+    };
+
+    match object.get("type") {
+        None => {}
+        Some(Value::String(name)) if SCHEMA_TYPES.contains(&name.as_str()) => {}
+        Some(Value::Array(names))
+            if names
+                .iter()
+                .all(|n| n.as_str().is_some_and(|n| SCHEMA_TYPES.contains(&n))) => {}
+        Some(_) => return invalid("type", &format!("one of {}", SCHEMA_TYPES.join(", "))),
+    }
+    if object
+        .get("required")
+        .is_some_and(|r| !r.as_array().is_some_and(|r| r.iter().all(Value::is_string)))
+    {
+        return invalid("required", "an array of property names");
+    }
+    if object.get("enum").is_some_and(|e| !e.is_array()) {
+        return invalid("enum", "an array");
+    }
+
+    for keyword in ["properties", "patternProperties", "definitions", "$defs"] {
+        match object.get(keyword) {
+            None => {}
+            Some(Value::Object(children)) => {
+                for (name, child) in children {
+                    check_subschema(child, &format!("{}/{}/{}", pointer, keyword, name))?;
+                }
+            }
+            Some(_) => return invalid(keyword, "an object of schemas"),
+        }
+    }
+    for keyword in ["allOf", "anyOf", "oneOf"] {
+        match object.get(keyword) {
+            None => {}
+            Some(Value::Array(children)) => {
+                for (i, child) in children.iter().enumerate() {
+                    check_subschema(child, &format!("{}/{}/{}", pointer, keyword, i))?;
+                }
+            }
+            Some(_) => return invalid(keyword, "an array of schemas"),
+        }
+    }
+    for keyword in ["additionalProperties", "not"] {
+        if let Some(child) = object.get(keyword) {
+            check_subschema(child, &format!("{}/{}", pointer, keyword))?;
+        }
+    }
+    match object.get("items") {
+        Some(Value::Array(children)) => {
+            for (i, child) in children.iter().enumerate() {
+                check_subschema(child, &format!("{}/items/{}", pointer, i))?;
+            }
+        }
+        Some(child) => check_subschema(child, &format!("{}/items", pointer))?,
+        None => {}
+    }
+    Ok(())
+}
+
+/// Whether `value` is a whole multiple of `step`
+///
+/// Tolerates floating-point rounding, so `0.3` counts as a multiple of `0.1`.
+fn is_multiple_of(value: f64, step: f64) -> bool {
+    let quotient = value / step;
+    (quotient - quotient.round()).abs() <= 1e-9 * quotient.abs().max(1.0)
+}
+
+/// Read an exclusive numeric bound
+///
+/// Accepts both the numeric form (`"exclusiveMinimum": 0`) and the draft-04
+/// boolean form, where `"exclusiveMinimum": true` makes `minimum` exclusive.
+fn exclusive_bound(schema: &serde_json::Value, keyword: &str, inclusive: &str) -> Option<f64> {
+    match schema.get(keyword)? {
+        serde_json::Value::Bool(true) => schema.get(inclusive).and_then(|v| v.as_f64()),
+        value => value.as_f64(),
+    }
+}
+
+/// Finding for an `anyOf`/`oneOf` where no branch matched
+///
+/// The suggestion names the branch with the fewest errors, as that is most
+/// likely the shape the value was meant to have.
+fn no_branch_matched(
+    code: &str,
+    keyword: &str,
+    outcomes: &[Vec<ValidationFinding>],
+    path: &str,
+) -> ValidationFinding {
+    let finding = ValidationFinding::error(
+        code,
+        format!("Value does not match any of the {} {} schemas", outcomes.len(), keyword),
+        path,
+    );
+    let closest = outcomes
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, errors)| errors.len())
+        .and_then(|(i, errors)| errors.first().map(|e| (i, e)));
+    match closest {
+        Some((i, error)) => finding.with_suggestion(format!(
+            "Closest is {} schema {}: {} at {}",
+            keyword, i, error.message, error.path
+        )),
+        None => finding,
+    }
+}
+
+/// Follow a schema's `$ref` (and any `$ref` it points to) within `root`
+///
+/// Only local refs (`#`, `#/definitions/...`, `#/$defs/...`, or any other
+/// JSON pointer into the root schema) are supported. As in draft-07, a
+/// schema with `$ref` is replaced by the referenced schema and its other
+/// keywords are ignored. Refs that loop back on themselves without reaching
+/// a real schema are rejected.
+pub(crate) fn resolve_ref<'a>(
+    root: &'a serde_json::Value,
+    schema: &'a serde_json::Value,
+) -> Result<&'a serde_json::Value> {
+    let mut current = schema;
+    let mut seen: Vec<&str> = Vec::new();
+
+    while let Some(reference) = current.get("$ref").and_then(|r| r.as_str()) {
+        let Some(pointer) = reference.strip_prefix('#') else {
+            return Err(ValidationError::SchemaError(format!(
+                "Remote $ref '{}' is not supported; only local refs (#/...) are",
+                reference
+            )));
+        };
+        if seen.contains(&reference) {
+            return Err(ValidationError::SchemaError(format!(
+                "Cyclic $ref: {} -> {}",
+                seen.join(" -> "),
+                reference
+            )));
+        }
+        seen.push(reference);
+
+        current = root.pointer(pointer).ok_or_else(|| {
+            ValidationError::SchemaError(format!("Unresolved $ref '{}'", reference))
+        })?;
+    }
+
+    Ok(current)
+}
+
 /// Get the JSON type name
 fn get_json_type(value: &serde_json::Value) -> &'static str {
     match value {
@@ -575,7 +1190,7 @@ impl SecurityRule {
 
                 if is_secret_key && !s.starts_with("${") && !s.starts_with("enc:") && s.len() > 0 {
                     result.add_finding(
-                        ValidationFinding::error(
+                        ValidationFinding::critical(
                             "S001",
                             "Potential secret in plain text",
                             path,
@@ -668,14 +1283,67 @@ impl NamingConventionRule {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::contracts::IssueSeverity;
 
     #[test]
     fn test_validation_severity_display() {
+        assert_eq!(ValidationSeverity::Critical.to_string(), "critical");
         assert_eq!(ValidationSeverity::Error.to_string(), "error");
         assert_eq!(ValidationSeverity::Warning.to_string(), "warning");
         assert_eq!(ValidationSeverity::Info.to_string(), "info");
     }
 
+    #[test]
+    fn test_finding_issue_round_trip() {
+        let finding = ValidationFinding::warning("W010", "Timeout is low", "$.server.timeout")
+            .with_suggestion("Use at least 5s");
+
+        let issue = ValidationIssue::from(finding.clone());
+        assert_eq!(issue.severity, IssueSeverity::Warning);
+        assert_eq!(issue.path.as_deref(), Some("$.server.timeout"));
+        assert_eq!(issue.suggestion.as_deref(), Some("Use at least 5s"));
+
+        let back = ValidationFinding::from(issue);
+        assert_eq!(
+            serde_json::to_value(&back).unwrap(),
+            serde_json::to_value(&finding).unwrap()
+        );
+
+        // Engine findings reach CLI findings through the contract issue
+        let engine_finding = crate::engine::rules::ValidationFinding::new(
+            "required_field",
+            crate::engine::rules::RuleCategory::Required,
+            crate::engine::rules::Severity::Critical,
+            "Field is required",
+            "database.host",
+        );
+        let cli: ValidationFinding = ValidationIssue::from(engine_finding).into();
+        assert_eq!(cli.severity, ValidationSeverity::Critical);
+        assert_eq!(cli.code, "required_field");
+        assert_eq!(cli.path, "database.host");
+
+        // ...and back without being downgraded
+        let issue = ValidationIssue::from(cli);
+        assert_eq!(issue.severity, IssueSeverity::Critical);
+        let engine = crate::engine::rules::ValidationFinding::from_issue(
+            issue,
+            crate::engine::rules::RuleCategory::Required,
+        );
+        assert_eq!(engine.severity, crate::engine::rules::Severity::Critical);
+    }
+
+    #[test]
+    fn test_critical_findings_block() {
+        let result = ValidationResult::with_findings(vec![ValidationFinding::critical(
+            "SEC001",
+            "Plaintext secret",
+            "$.db.password",
+        )]);
+        assert!(!result.valid);
+        assert_eq!(result.errors().len(), 1);
+        assert!(ValidationSeverity::Critical > ValidationSeverity::Error);
+    }
+
     #[test]
     fn test_validation_finding_builders() {
         let finding = ValidationFinding::error("E001", "Test error", "$.path")
@@ -732,6 +1400,506 @@ mod tests {
         assert!(result.valid);
     }
 
+    fn validator_with_schema(schema: serde_json::Value) -> Validator {
+        let mut validator = Validator::new(ValidationContext::new());
+        validator.load_schema(&schema.to_string()).unwrap();
+        validator
+    }
+
+    fn schema_errors(result: &ValidationResult) -> Vec<(&str, &str)> {
+        result
+            .errors()
+            .into_iter()
+            .filter(|f| f.code.starts_with('E'))
+            .map(|f| (f.code.as_str(), f.path.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn test_schema_ref_to_shared_definition() {
+        let validator = validator_with_schema(serde_json::json!({
+            "type": "object",
+            "properties": {
+                "primary": {"$ref": "#/definitions/endpoint"},
+                "replica": {"$ref": "#/$defs/replica"}
+            },
+            "definitions": {
+                "endpoint": {
+                    "type": "object",
+                    "required": ["host"],
+                    "properties": {
+                        "host": {"type": "string"},
+                        "port": {"type": "number", "maximum": 65535}
+                    }
+                }
+            },
+            "$defs": {
+                "replica": {"$ref": "#/definitions/endpoint"}
+            }
+        }));
+
+        let valid = serde_json::json!({
+            "primary": {"host": "db1", "port": 5432},
+            "replica": {"host": "db2"}
+        });
+        assert!(schema_errors(&validator.validate(&valid).unwrap()).is_empty());
+
+        let invalid = serde_json::json!({
+            "primary": {"host": "db1", "port": 70000},
+            "replica": {"port": 5432}
+        });
+        let result = validator.validate(&invalid).unwrap();
+        let errors = schema_errors(&result);
+        assert_eq!(errors.len(), 2, "{:?}", errors);
+        assert!(errors.contains(&("E002", "$.replica")));
+        assert!(errors.iter().any(|(_, path)| *path == "$.primary.port"));
+    }
+
+    #[test]
+    fn test_schema_ref_recursive_definition() {
+        let validator = validator_with_schema(serde_json::json!({
+            "$ref": "#/definitions/node",
+            "definitions": {
+                "node": {
+                    "type": "object",
+                    "properties": {
+                        "name": {"type": "string"},
+                        "child": {"$ref": "#/definitions/node"}
+                    }
+                }
+            }
+        }));
+
+        let config = serde_json::json!({"name": "a", "child": {"name": "b", "child": {"name": 3}}});
+        let result = validator.validate(&config).unwrap();
+        assert_eq!(schema_errors(&result), vec![("E001", "$.child.child.name")]);
+    }
+
+    #[test]
+    fn test_schema_ref_errors() {
+        let config = serde_json::json!({"a": 1});
+
+        let remote = validator_with_schema(serde_json::json!({
+            "properties": {"a": {"$ref": "https://example.com/schema.json#/a"}}
+        }));
+        let err = remote.validate(&config).unwrap_err();
+        assert!(err.to_string().contains("Remote $ref"));
+
+        let cyclic = validator_with_schema(serde_json::json!({
+            "properties": {"a": {"$ref": "#/definitions/x"}},
+            "definitions": {"x": {"$ref": "#/definitions/y"}, "y": {"$ref": "#/definitions/x"}}
+        }));
+        let err = cyclic.validate(&config).unwrap_err();
+        assert!(err.to_string().contains("Cyclic $ref"));
+
+        let missing = validator_with_schema(serde_json::json!({
+            "properties": {"a": {"$ref": "#/definitions/missing"}}
+        }));
+        let err = missing.validate(&config).unwrap_err();
+        assert!(err.to_string().contains("Unresolved $ref"));
+    }
+
+    #[test]
+    fn test_schema_one_of_two_shapes() {
+        let validator = validator_with_schema(serde_json::json!({
+            "type": "object",
+            "properties": {
+                "database": {
+                    "oneOf": [
+                        {"type": "object", "required": ["url"]},
+                        {"type": "object", "required": ["host"], "properties": {"port": {"type": "number"}}}
+                    ]
+                }
+            }
+        }));
+
+        let by_url = serde_json::json!({"database": {"url": "postgres://db"}});
+        assert!(schema_errors(&validator.validate(&by_url).unwrap()).is_empty());
+
+        let by_host = serde_json::json!({"database": {"host": "db", "port": 5432}});
+        assert!(schema_errors(&validator.validate(&by_host).unwrap()).is_empty());
+
+        let neither = serde_json::json!({"database": {"port": 5432}});
+        let result = validator.validate(&neither).unwrap();
+        assert_eq!(schema_errors(&result), vec![("E010", "$.database")]);
+        assert!(result.errors()[0].suggestion.as_deref().unwrap().contains("oneOf schema 0"));
+
+        let both = serde_json::json!({"database": {"url": "postgres://db", "host": "db"}});
+        let result = validator.validate(&both).unwrap();
+        assert_eq!(schema_errors(&result), vec![("E011", "$.database")]);
+        assert!(result.errors()[0].message.contains("(0, 1)"));
+    }
+
+    #[test]
+    fn test_schema_additional_properties_false() {
+        let validator = validator_with_schema(serde_json::json!({
+            "type": "object",
+            "properties": {
+                "server": {
+                    "type": "object",
+                    "properties": {"host": {"type": "string"}, "port": {"type": "number"}},
+                    "additionalProperties": false
+                }
+            }
+        }));
+
+        let valid = serde_json::json!({"server": {"host": "localhost", "port": 80}, "extra": 1});
+        assert!(schema_errors(&validator.validate(&valid).unwrap()).is_empty());
+
+        let invalid = serde_json::json!({"server": {"host": "localhost", "prot": 80, "tls": true}});
+        let result = validator.validate(&invalid).unwrap();
+        assert_eq!(
+            schema_errors(&result),
+            vec![("E012", "$.server.prot"), ("E012", "$.server.tls")]
+        );
+    }
+
+    #[test]
+    fn test_schema_additional_properties_schema() {
+        let validator = validator_with_schema(serde_json::json!({
+            "type": "object",
+            "properties": {"default": {"type": "string"}},
+            "additionalProperties": {"type": "number", "minimum": 0}
+        }));
+
+        let valid = serde_json::json!({"default": "none", "api": 10, "worker": 0});
+        assert!(schema_errors(&validator.validate(&valid).unwrap()).is_empty());
+
+        let invalid = serde_json::json!({"default": "none", "api": "ten", "worker": -1});
+        let result = validator.validate(&invalid).unwrap();
+        assert_eq!(
+            schema_errors(&result),
+            vec![("E001", "$.api"), ("E006", "$.worker")]
+        );
+    }
+
+    #[test]
+    fn test_schema_format_keyword() {
+        let cases = [
+            ("date-time", "2024-05-01T12:30:00Z", "yesterday"),
+            ("date", "2024-05-01", "2024-5-1"),
+            ("email", "ops@example.com", "ops.example.com"),
+            ("uri", "https://example.com/api", "example.com/api"),
+            ("ipv4", "10.0.0.1", "256.0.0.1"),
+            ("ipv6", "fe80::1", "not-an-address"),
+            ("uuid", "550e8400-e29b-41d4-a716-446655440000", "550e8400"),
+        ];
+
+        for (format, valid, invalid) in cases {
+            let validator = validator_with_schema(serde_json::json!({
+                "properties": {"value": {"type": "string", "format": format}}
+            }));
+
+            let result = validator.validate(&serde_json::json!({"value": valid})).unwrap();
+            assert!(
+                !result.findings.iter().any(|f| f.code == "FORMAT_MISMATCH"),
+                "{} should accept {}",
+                format,
+                valid
+            );
+
+            let result = validator.validate(&serde_json::json!({"value": invalid})).unwrap();
+            let mismatch = result.errors().into_iter().find(|f| f.code == "FORMAT_MISMATCH");
+            assert_eq!(
+                mismatch.map(|f| f.path.as_str()),
+                Some("$.value"),
+                "{} should reject {}",
+                format,
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    fn test_load_schema_rejects_unsupported_format_version() {
+        let mut validator = Validator::new(ValidationContext::new());
+        for (version, supported) in [("1.0", true), ("0.9", true), ("2.0", false)] {
+            let schema = serde_json::json!({"schema_format_version": version, "schema": {}});
+            let result = validator.load_schema(&schema.to_string());
+            assert_eq!(result.is_ok(), supported, "format version {}", version);
+        }
+    }
+
+    #[test]
+    fn test_exported_config_schema_validates_nested_configs() {
+        use crate::contracts::{ConfigSchema, FieldRule, FieldType, ValidationConstraint};
+
+        let schema = ConfigSchema::new("app/service", "Service", "1.0.0")
+            .with_field("admin", FieldRule::required(FieldType::Email))
+            .with_field(
+                "database",
+                FieldRule::required(FieldType::Object)
+                    .with_nested_field("host", FieldRule::required(FieldType::String))
+                    .with_nested_field(
+                        "timeout",
+                        FieldRule::new(FieldType::Float)
+                            .with_constraint(ValidationConstraint::range(0.5, 30.0)),
+                    ),
+            );
+        let validator = validator_with_schema(schema.to_json_schema());
+
+        let valid = serde_json::json!({
+            "admin": "ops@example.com",
+            "database": {"host": "db.internal", "timeout": 2.5}
+        });
+        // Context checks such as the logging warning report at the root;
+        // schema findings are at the fields
+        let result = validator.validate(&valid).unwrap();
+        assert!(result.findings.iter().all(|f| !f.path.starts_with("$.")));
+
+        let invalid = serde_json::json!({
+            "admin": "ops.example.com",
+            "database": {"host": 5, "timeout": 60.0}
+        });
+        let result = validator.validate(&invalid).unwrap();
+        let mut codes: Vec<(&str, &str)> = result
+            .findings
+            .iter()
+            .filter(|f| f.path.starts_with("$."))
+            .map(|f| (f.code.as_str(), f.path.as_str()))
+            .collect();
+        codes.sort();
+        assert_eq!(
+            codes,
+            vec![
+                ("E001", "$.database.host"),
+                ("E007", "$.database.timeout"),
+                ("FORMAT_MISMATCH", "$.admin"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_config_passed_as_schema_is_a_schema_error() {
+        let mut validator = Validator::new(ValidationContext::new());
+        let config = serde_json::json!({"host": "db.internal", "port": 5432});
+        let err = validator.load_schema(&config.to_string()).unwrap_err();
+        assert!(matches!(err, ValidationError::SchemaError(_)));
+        assert!(err.to_string().contains("configuration file"), "{}", err);
+
+        let config_schema = serde_json::json!({"id": "app/db", "fields": {}});
+        let err = validator.load_schema(&config_schema.to_string()).unwrap_err();
+        assert!(err.to_string().contains("ConfigSchema"), "{}", err);
+
+        // A config that happens to have a keyword is caught by its shape
+        let config = serde_json::json!({"type": "postgres", "port": 5432});
+        let err = validator.load_schema(&config.to_string()).unwrap_err();
+        assert!(err.to_string().contains("'type' must be"), "{}", err);
+    }
+
+    #[test]
+    fn test_malformed_subschema_names_its_location() {
+        let schema = serde_json::json!({
+            "type": "object",
+            "properties": {"tags": {"type": "array", "items": {"required": "name"}}}
+        });
+        let err = check_schema_shape(&schema).unwrap_err();
+        assert!(err.to_string().contains("#/properties/tags/items"), "{}", err);
+
+        assert!(check_schema_shape(&serde_json::json!({})).is_ok());
+        assert!(check_schema_shape(&serde_json::json!(true)).is_ok());
+    }
+
+    #[test]
+    fn test_schema_format_annotation_mode() {
+        let schema = serde_json::json!({
+            "properties": {
+                "contact": {"format": "email"},
+                "build": {"format": "x-unknown"}
+            }
+        });
+        let mut validator = Validator::new(ValidationContext::new().with_format_assertion(false));
+        validator.load_schema(&schema.to_string()).unwrap();
+
+        let result = validator
+            .validate(&serde_json::json!({"contact": "nobody", "build": "anything"}))
+            .unwrap();
+        assert!(!result.errors().iter().any(|f| f.code == "FORMAT_MISMATCH"));
+        let warnings: Vec<_> = result
+            .findings
+            .iter()
+            .filter(|f| f.code == "FORMAT_MISMATCH")
+            .collect();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].severity, ValidationSeverity::Warning);
+        assert_eq!(warnings[0].path, "$.contact");
+    }
+
+    #[test]
+    fn test_schema_multiple_of() {
+        let validator = validator_with_schema(serde_json::json!({
+            "properties": {
+                "replicas": {"type": "number", "multipleOf": 2},
+                "ratio": {"type": "number", "multipleOf": 0.1}
+            }
+        }));
+
+        let valid = serde_json::json!({"replicas": 6, "ratio": 0.3});
+        assert!(schema_errors(&validator.validate(&valid).unwrap()).is_empty());
+
+        let invalid = serde_json::json!({"replicas": 5, "ratio": 0.35});
+        let result = validator.validate(&invalid).unwrap();
+        assert_eq!(
+            schema_errors(&result),
+            vec![("E013", "$.ratio"), ("E013", "$.replicas")]
+        );
+    }
+
+    #[test]
+    fn test_schema_exclusive_bounds() {
+        let validator = validator_with_schema(serde_json::json!({
+            "properties": {
+                "rate": {"exclusiveMinimum": 0, "exclusiveMaximum": 1},
+                "legacy": {"minimum": 0, "exclusiveMinimum": true}
+            }
+        }));
+
+        let inside = serde_json::json!({"rate": 0.5, "legacy": 0.1});
+        assert!(schema_errors(&validator.validate(&inside).unwrap()).is_empty());
+
+        let at_min = serde_json::json!({"rate": 0, "legacy": 0});
+        let result = validator.validate(&at_min).unwrap();
+        assert_eq!(
+            schema_errors(&result),
+            vec![("E014", "$.legacy"), ("E014", "$.rate")]
+        );
+
+        let at_max = serde_json::json!({"rate": 1});
+        let result = validator.validate(&at_max).unwrap();
+        assert_eq!(schema_errors(&result), vec![("E015", "$.rate")]);
+    }
+
+    #[test]
+    fn test_fail_threshold() {
+        let schema = serde_json::json!({
+            "properties": {"contact": {"format": "email"}}
+        });
+        let config = serde_json::json!({"contact": "nobody"});
+        let validate = |fail_on| {
+            let context = ValidationContext::new()
+                .with_format_assertion(false)
+                .with_fail_on(fail_on);
+            let mut validator = Validator::new(context);
+            validator.load_schema(&schema.to_string()).unwrap();
+            validator.validate(&config).unwrap()
+        };
+
+        // The same warning passes by default but fails a stricter threshold
+        let result = validate(ValidationSeverity::Error);
+        assert!(result.errors().is_empty());
+        assert!(result.warnings().iter().any(|f| f.code == "FORMAT_MISMATCH"));
+        assert!(result.valid);
+        assert!(!validate(ValidationSeverity::Warning).valid);
+        assert!(!validate(ValidationSeverity::Info).valid);
+
+        let info_only = ValidationResult::with_findings(vec![ValidationFinding::info(
+            "I001", "Note", "$",
+        )]);
+        assert!(info_only.clone().with_fail_threshold(ValidationSeverity::Warning).valid);
+        assert!(!info_only.with_fail_threshold(ValidationSeverity::Info).valid);
+    }
+
+    #[test]
+    fn test_schema_all_of_and_any_of() {
+        let validator = validator_with_schema(serde_json::json!({
+            "properties": {
+                "name": {"allOf": [{"type": "string"}, {"minLength": 3}, {"maxLength": 8}]},
+                "port": {"anyOf": [{"type": "number", "minimum": 1024}, {"type": "string", "pattern": "^\\$\\{"}]}
+            }
+        }));
+
+        let valid = serde_json::json!({"name": "api", "port": "${PORT}"});
+        assert!(schema_errors(&validator.validate(&valid).unwrap()).is_empty());
+
+        let valid = serde_json::json!({"name": "api", "port": 8080});
+        assert!(schema_errors(&validator.validate(&valid).unwrap()).is_empty());
+
+        // allOf reports each failing branch; anyOf reports once when nothing matches
+        let invalid = serde_json::json!({"name": "a", "port": 80});
+        let result = validator.validate(&invalid).unwrap();
+        assert_eq!(
+            schema_errors(&result),
+            vec![("E003", "$.name"), ("E009", "$.port")]
+        );
+    }
+
+    #[test]
+    fn test_finding_fingerprint_ignores_severity() {
+        let error = ValidationFinding::error("E002", "Missing field", "$");
+        let warning = ValidationFinding::warning("E002", "Missing field", "$");
+        let other = ValidationFinding::error("E002", "Missing field", "$.db");
+
+        assert_eq!(error.fingerprint(), warning.fingerprint());
+        assert_ne!(error.fingerprint(), other.fingerprint());
+    }
+
+    #[test]
+    fn test_schema_upgrade_adding_required_field_introduces_error() {
+        let v1 = r#"{"type": "object", "required": ["name"]}"#;
+        let v2 = r#"{"type": "object", "required": ["name", "port"]}"#;
+        let config = serde_json::json!({"name": "api", "timeout": 30});
+
+        let comparison =
+            compare_schema_versions(&config, v1, v2, ValidationContext::new()).unwrap();
+
+        assert!(comparison.before.valid);
+        assert!(!comparison.after.valid);
+        assert!(comparison.upgrade_breaks_config());
+        assert!(comparison.diff.introduces_errors());
+        assert_eq!(comparison.diff.introduced.len(), 1);
+        assert_eq!(comparison.diff.introduced[0].code, "E002");
+        assert!(comparison.diff.introduced[0].message.contains("port"));
+        assert!(comparison.diff.fixed.is_empty());
+        assert_eq!(
+            comparison.diff.unchanged.len(),
+            comparison.before.findings.len()
+        );
+    }
+
+    #[test]
+    fn test_findings_diff_reports_fixed_findings() {
+        let before = ValidationResult::with_findings(vec![
+            ValidationFinding::error("E001", "Bad type", "$.a"),
+            ValidationFinding::warning("W001", "Odd", "$.b"),
+            ValidationFinding::warning("W001", "Odd", "$.b"),
+        ]);
+        let after = ValidationResult::with_findings(vec![
+            ValidationFinding::warning("W001", "Odd", "$.b"),
+            ValidationFinding::error("E005", "Pattern", "$.c"),
+        ]);
+
+        let diff = FindingsDiff::between(&before, &after);
+        let codes = |findings: &[ValidationFinding]| {
+            findings.iter().map(|f| f.code.clone()).collect::<Vec<_>>()
+        };
+        assert_eq!(codes(&diff.fixed), vec!["E001", "W001"]);
+        assert_eq!(codes(&diff.introduced), vec!["E005"]);
+        assert_eq!(codes(&diff.unchanged), vec!["W001"]);
+    }
+
+    #[test]
+    fn test_findings_diff_reports_escalation_to_error() {
+        let before = ValidationResult::with_findings(vec![ValidationFinding::warning(
+            "W003", "Deprecated", "$.a",
+        )]);
+        let after = ValidationResult::with_findings(vec![ValidationFinding::error(
+            "W003", "Deprecated", "$.a",
+        )]);
+
+        let diff = FindingsDiff::between(&before, &after);
+        assert!(diff.introduced.is_empty());
+        assert!(diff.fixed.is_empty());
+        assert!(diff.unchanged.is_empty());
+        assert_eq!(diff.severity_changed.len(), 1);
+        assert_eq!(diff.severity_changed[0].severity, ValidationSeverity::Error);
+        assert!(diff.introduces_errors());
+
+        // De-escalation is a change too, but adds no errors
+        let diff = FindingsDiff::between(&after, &before);
+        assert_eq!(diff.severity_changed.len(), 1);
+        assert!(!diff.introduces_errors());
+    }
+
     #[test]
     fn test_security_rule_detects_plain_password() {
         let context = ValidationContext::new();
@@ -0,0 +1,139 @@
+//! Property tests for deterministic validation
+//!
+//! Each case builds its input twice, from scratch, so hash maps get fresh
+//! random states, and validates each copy with its own engine on a mock clock
+//! and seeded ids. The serialized results must match byte for byte.
+
+use chrono::{TimeZone, Utc};
+use config_validation::clock::MockClock;
+use config_validation::contracts::{ConfigValueRef, EnvironmentRef, ValidationInput};
+use config_validation::engine::ValidationEngine;
+use config_validation::ids::SeededIds;
+use config_validation::telemetry::emitter::calculate_inputs_hash;
+use config_validation::{ConfigValue, Environment};
+use proptest::prelude::*;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Configuration trees with keys that trip naming, secret and type rules
+fn arb_config() -> impl Strategy<Value = Value> {
+    let key = prop_oneof![
+        Just("host".to_string()),
+        Just("password".to_string()),
+        Just("apiKey".to_string()),
+        Just("timeout_ms".to_string()),
+        Just("debug".to_string()),
+        "[a-z][a-zA-Z_-]{0,8}",
+    ];
+    let leaf = prop_oneof![
+        any::<bool>().prop_map(Value::from),
+        any::<i32>().prop_map(Value::from),
+        (-1.0e6..1.0e6f64).prop_map(Value::from),
+        prop_oneof![
+            Just("localhost".to_string()),
+            Just("http://10.0.0.1".to_string()),
+            Just("hunter2".to_string()),
+            "[ -~]{0,16}",
+        ]
+        .prop_map(Value::from),
+    ];
+    leaf.prop_recursive(3, 32, 6, move |inner| {
+        prop_oneof![
+            prop::collection::vec(inner.clone(), 0..4).prop_map(Value::from),
+            prop::collection::btree_map(key.clone(), inner, 0..6)
+                .prop_map(|map| Value::Object(map.into_iter().collect())),
+        ]
+    })
+}
+
+fn arb_environment() -> impl Strategy<Value = EnvironmentRef> {
+    prop_oneof![
+        Just(EnvironmentRef::Base),
+        Just(EnvironmentRef::Development),
+        Just(EnvironmentRef::Staging),
+        Just(EnvironmentRef::Production),
+        Just(EnvironmentRef::Edge),
+    ]
+}
+
+fn to_config_value(value: &Value) -> ConfigValue {
+    match value {
+        Value::Null => ConfigValue::String(String::new()),
+        Value::Bool(b) => ConfigValue::Boolean(*b),
+        Value::Number(n) => match n.as_i64() {
+            Some(i) => ConfigValue::Integer(i),
+            None => ConfigValue::Float(n.as_f64().unwrap_or_default()),
+        },
+        Value::String(s) => ConfigValue::String(s.clone()),
+        Value::Array(items) => ConfigValue::Array(items.iter().map(to_config_value).collect()),
+        Value::Object(map) => ConfigValue::Object(
+            map.iter()
+                .map(|(k, v)| (k.clone(), to_config_value(v)))
+                .collect::<HashMap<_, _>>(),
+        ),
+    }
+}
+
+fn to_value_ref(value: &Value) -> ConfigValueRef {
+    match value {
+        Value::Null => ConfigValueRef::Null,
+        Value::Bool(b) => ConfigValueRef::Boolean(*b),
+        Value::Number(n) => match n.as_i64() {
+            Some(i) => ConfigValueRef::Integer(i),
+            None => ConfigValueRef::Float(n.as_f64().unwrap_or_default()),
+        },
+        Value::String(s) => ConfigValueRef::String(s.clone()),
+        Value::Array(items) => ConfigValueRef::Array(items.iter().map(to_value_ref).collect()),
+        Value::Object(map) => ConfigValueRef::Object(
+            map.iter()
+                .map(|(k, v)| (k.clone(), to_value_ref(v)))
+                .collect::<HashMap<_, _>>(),
+        ),
+    }
+}
+
+fn environment(env: EnvironmentRef) -> Environment {
+    match env {
+        EnvironmentRef::Base => Environment::Base,
+        EnvironmentRef::Development => Environment::Development,
+        EnvironmentRef::Staging => Environment::Staging,
+        EnvironmentRef::Production => Environment::Production,
+        EnvironmentRef::Edge => Environment::Edge,
+    }
+}
+
+/// Validate a freshly built copy of the input and serialize the result
+fn run(config: &Value, env: EnvironmentRef, namespace: &str) -> (String, String) {
+    let input = ValidationInput::new(namespace, "config", to_value_ref(config), env, "proptest");
+    let start = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+    let engine = ValidationEngine::new()
+        .with_clock(Arc::new(MockClock::new(start)))
+        .with_id_generator(Arc::new(SeededIds::new(7)));
+
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    let value = to_config_value(config);
+    let result = runtime.block_on(engine.validate(&value, environment(env), namespace));
+    (
+        calculate_inputs_hash(&input),
+        serde_json::to_string(&result).unwrap(),
+    )
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn prop_validation_output_is_byte_identical(
+        config in arb_config(),
+        env in arb_environment(),
+        namespace in "[a-z]{1,8}(/[a-z]{1,8}){0,2}",
+    ) {
+        let (hash1, output1) = run(&config, env, &namespace);
+        let (hash2, output2) = run(&config, env, &namespace);
+        prop_assert_eq!(hash1, hash2);
+        prop_assert_eq!(output1, output2);
+    }
+}
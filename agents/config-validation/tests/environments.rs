@@ -0,0 +1,66 @@
+//! Integration tests for validating a directory of per-environment configs
+
+use config_validation::cli::commands::load_environment_dir;
+use config_validation::cli::{EnvironmentsOutput, ExitCode};
+use config_validation::contracts::EnvironmentRef;
+use config_validation::environments::{validate_environments, CrossEnvironmentKind};
+use std::path::PathBuf;
+
+fn fixture_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/environments")
+}
+
+#[test]
+fn test_prod_reusing_dev_secret_is_reported() {
+    let dir = fixture_dir();
+    let layers = load_environment_dir(&dir).unwrap();
+    let schema = std::fs::read_to_string(dir.join("schema.json")).unwrap();
+
+    let report = validate_environments(&layers, Some(&schema), false).unwrap();
+
+    // Base is only inherited from; each deployable environment is validated
+    let validated: Vec<_> = report.environments.iter().map(|e| e.environment).collect();
+    assert_eq!(
+        validated,
+        vec![
+            EnvironmentRef::Development,
+            EnvironmentRef::Staging,
+            EnvironmentRef::Production,
+        ]
+    );
+    // Inherited `service_name` satisfies the schema everywhere
+    for findings in &report.environments {
+        assert!(
+            findings.result.errors().is_empty(),
+            "{}: {:?}",
+            findings.environment,
+            findings.result.findings
+        );
+    }
+
+    // Every key is encrypted, but production reuses the development key
+    assert_eq!(report.violations.len(), 1);
+    let violation = &report.violations[0];
+    assert_eq!(violation.kind, CrossEnvironmentKind::MustDiffer);
+    assert_eq!(violation.rule_id, "prod-api-key-distinct");
+    assert_eq!(violation.field, "api.key");
+    assert_eq!(violation.environment, EnvironmentRef::Production);
+    assert_eq!(violation.other_environment, Some(EnvironmentRef::Development));
+
+    let output = EnvironmentsOutput::from_report(&report);
+    assert_eq!(output.exit_code(), ExitCode::ValidationError);
+}
+
+#[test]
+fn test_effective_config_resolves_through_inheritance() {
+    let layers = load_environment_dir(&fixture_dir()).unwrap();
+
+    let prod = layers.effective(EnvironmentRef::Production);
+    assert_eq!(prod["service_name"], "billing");
+    assert_eq!(prod["database"]["host"], "prod-db.internal");
+    assert_eq!(prod["database"]["pool_size"], 50);
+    assert_eq!(prod["api"]["timeout_ms"], 5000);
+
+    let dev = layers.effective(EnvironmentRef::Development);
+    assert_eq!(dev["database"]["pool_size"], 10);
+}
@@ -0,0 +1,29 @@
+//! Bounded fuzzing of schema validation
+//!
+//! Runs a fixed number of generated cases so CI stays fast; longer runs go
+//! through `fuzz_schema` or a `cargo fuzz` target with the same generator.
+
+use config_validation::fuzz::{check_case, fuzz_schema, FuzzCase};
+use proptest::prelude::*;
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn prop_validation_never_panics_and_is_deterministic(seed in any::<u64>()) {
+        let case = FuzzCase::from_seed(seed);
+        if let Err(failure) = check_case(&case) {
+            prop_assert!(false, "{}\nschema: {}\nconfig: {}", failure, case.schema, case.config);
+        }
+    }
+}
+
+#[test]
+fn test_fuzz_run_covers_valid_and_invalid_configs() {
+    let report = fuzz_schema(0, 200).unwrap_or_else(|failure| panic!("{}", failure));
+
+    assert_eq!(report.iterations, 200);
+    assert_eq!(report.valid + report.invalid + report.errored, 200);
+    assert!(report.valid > 0, "{:?}", report);
+    assert!(report.invalid > 0, "{:?}", report);
+}
@@ -0,0 +1,61 @@
+//! Integration tests for the consolidated multi-file report
+
+use config_validation::cli::commands::{collect_config_files, validate_files};
+use config_validation::report::ReportFormat;
+use std::fs;
+
+const SCHEMA: &str = r#"{
+    "type": "object",
+    "required": ["service_name"],
+    "properties": {"service_name": {"type": "string"}, "port": {"type": "number"}}
+}"#;
+
+#[test]
+fn test_report_over_three_files() {
+    let dir = tempfile::tempdir().unwrap();
+    let configs = dir.path().join("configs");
+    fs::create_dir(&configs).unwrap();
+    fs::write(configs.join("api.yaml"), "service_name: api\nport: 8080\n").unwrap();
+    fs::write(configs.join("worker.json"), r#"{"port": "eighty"}"#).unwrap();
+    fs::write(configs.join("cache.toml"), "service_name = [unclosed\n").unwrap();
+    fs::write(configs.join("notes.txt"), "not a config").unwrap();
+    let schema = dir.path().join("schema.json");
+    fs::write(&schema, SCHEMA).unwrap();
+
+    // notes.txt is skipped, and naming a file twice validates it once
+    let files = collect_config_files(&[configs.clone(), configs.join("api.yaml")]).unwrap();
+    assert_eq!(files.len(), 3);
+
+    let report = validate_files(&files, Some(&schema), "development", false).unwrap();
+    let statuses: Vec<_> = report
+        .files
+        .iter()
+        .map(|f| (f.path.rsplit('/').next().unwrap(), f.status()))
+        .collect();
+    assert_eq!(
+        statuses,
+        vec![("api.yaml", "valid"), ("cache.toml", "failed"), ("worker.json", "invalid")]
+    );
+    let totals = &report.totals;
+    assert_eq!((totals.files, totals.valid_files, totals.invalid_files), (3, 1, 2));
+    // worker.json: missing service_name and a mistyped port; cache.toml: parse failure
+    assert_eq!(totals.errors, 3);
+    assert!(report.has_errors());
+
+    let markdown_path = dir.path().join("report.md");
+    report
+        .write_to(&markdown_path, ReportFormat::from_path(&markdown_path))
+        .unwrap();
+    let markdown = fs::read_to_string(&markdown_path).unwrap();
+    assert_eq!(markdown.matches("\n## ").count(), 3);
+    assert!(markdown.contains("3 file(s): 1 valid, 2 invalid. 3 error(s)"));
+
+    let html = report.render(ReportFormat::Html).unwrap();
+    assert_eq!(html.matches("<section>").count(), 3);
+    assert!(html.contains("<style>"));
+
+    let json: serde_json::Value =
+        serde_json::from_str(&report.render(ReportFormat::Json).unwrap()).unwrap();
+    assert_eq!(json["files"].as_array().unwrap().len(), 3);
+    assert_eq!(json["totals"]["errors"], 3);
+}
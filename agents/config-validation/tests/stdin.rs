@@ -0,0 +1,177 @@
+//! Tests piping configs and schemas to the CLI through `-`
+
+use serde_json::Value;
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+/// Run config-validate with `stdin` piped in, from an empty directory so no
+/// defaults file applies
+fn run(args: &[&str], stdin: &str) -> Output {
+    let cwd = tempfile::tempdir().unwrap();
+    let mut child = Command::new(env!("CARGO_BIN_EXE_config-validate"))
+        .args(args)
+        .current_dir(cwd.path())
+        .env_remove("CONFIG_VALIDATE_SCHEMA")
+        .env_remove("CONFIG_VALIDATE_FORMAT")
+        .env_remove("CONFIG_VALIDATE_ENVIRONMENT")
+        .env_remove("CONFIG_VALIDATE_STRICT")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    // Commands that reject their arguments exit without reading stdin
+    if let Err(e) = child.stdin.take().unwrap().write_all(stdin.as_bytes()) {
+        assert_eq!(e.kind(), std::io::ErrorKind::BrokenPipe, "{}", e);
+    }
+    child.wait_with_output().unwrap()
+}
+
+fn stdout_json(output: &Output) -> Value {
+    serde_json::from_slice(&output.stdout).unwrap_or_else(|e| {
+        panic!(
+            "stdout is not JSON ({}): {}\nstderr: {}",
+            e,
+            String::from_utf8_lossy(&output.stdout),
+            String::from_utf8_lossy(&output.stderr)
+        )
+    })
+}
+
+#[test]
+fn test_validate_reads_config_from_stdin() {
+    let output = run(
+        &[
+            "validate",
+            "--config",
+            "-",
+            "--input-format",
+            "yaml",
+            "--environment",
+            "dev",
+            "--format",
+            "json",
+        ],
+        "server:\n  host: api.example.com\n  port: 8080\n",
+    );
+    assert_eq!(output.status.code(), Some(0), "{:?}", output);
+    assert_eq!(stdout_json(&output)["valid"], true);
+}
+
+#[test]
+fn test_validate_reads_schema_from_stdin() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = dir.path().join("app.json");
+    std::fs::write(&config, r#"{"port": "not a number"}"#).unwrap();
+
+    let output = run(
+        &["validate", "--config", config.to_str().unwrap(), "--schema", "-", "--format", "json"],
+        r#"{"type": "object", "properties": {"port": {"type": "integer"}}}"#,
+    );
+    assert_eq!(output.status.code(), Some(1), "{:?}", output);
+    assert_eq!(stdout_json(&output)["valid"], false);
+}
+
+#[test]
+fn test_plaintext_secret_exits_with_security_error() {
+    let output = run(
+        &["validate", "--config", "-", "--input-format", "json", "--format", "json"],
+        r#"{"database": {"password": "hunter2"}}"#,
+    );
+    assert_eq!(output.status.code(), Some(6), "{:?}", output);
+    let json = stdout_json(&output);
+    assert_eq!(json["exit_reason"], "critical");
+    assert_eq!(json["critical_count"], 1);
+    assert_eq!(json["error_count"], 1);
+}
+
+#[test]
+fn test_stdin_format_is_detected_and_reported_when_verbose() {
+    let output = run(
+        &["-v", "normalize", "--config", "-"],
+        "[server]\nport = 8080\n",
+    );
+    assert_eq!(output.status.code(), Some(0), "{:?}", output);
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout).trim(),
+        r#"{"server":{"port":8080}}"#
+    );
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Detected TOML in stdin"));
+}
+
+#[test]
+fn test_undetectable_stdin_is_rejected() {
+    let output = run(&["validate", "--config", "-", "--format", "json"], "just words");
+    assert_eq!(output.status.code(), Some(3), "{:?}", output);
+    assert!(String::from_utf8_lossy(&output.stdout).contains("--input-format"));
+}
+
+#[test]
+fn test_only_one_input_reads_stdin() {
+    let output = run(
+        &["validate", "--config", "-", "--schema", "-", "--input-format", "json"],
+        "{}",
+    );
+    assert_eq!(output.status.code(), Some(3), "{:?}", output);
+}
+
+#[test]
+fn test_inspect_reads_config_from_stdin() {
+    let output = run(
+        &["inspect", "--config", "-", "--input-format", "toml", "--format", "json"],
+        "[server]\nport = 8080\n",
+    );
+    assert_eq!(output.status.code(), Some(0), "{:?}", output);
+    assert!(String::from_utf8_lossy(&output.stdout).contains("port"));
+}
+
+#[test]
+fn test_normalize_reads_config_from_stdin() {
+    let output = run(
+        &["normalize", "--config", "-", "--input-format", "json"],
+        r#"{"b": 1.0, "a": true}"#,
+    );
+    assert_eq!(output.status.code(), Some(0), "{:?}", output);
+    assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), r#"{"a":true,"b":1}"#);
+}
+
+#[test]
+fn test_schema_coverage_reads_schema_from_stdin() {
+    let schema = r#"{
+        "id": "app/server",
+        "name": "Server",
+        "version": "1.0.0",
+        "fields": {"port": {"field_type": "integer", "description": "Listen port"}}
+    }"#;
+    let output = run(
+        &["schema", "coverage", "--schema", "-", "--input-format", "json", "--format", "json"],
+        schema,
+    );
+    assert_eq!(output.status.code(), Some(0), "{:?}", output);
+    let report = stdout_json(&output);
+    assert_eq!(report["schema_id"], "app/server");
+    assert_eq!(report["total_fields"], 1);
+}
+
+#[test]
+fn test_compatibility_reads_one_config_from_stdin() {
+    let dir = tempfile::tempdir().unwrap();
+    let other = dir.path().join("other.json");
+    std::fs::write(&other, r#"{"port": 8080}"#).unwrap();
+
+    let output = run(
+        &[
+            "compatibility",
+            "--configs",
+            "-",
+            other.to_str().unwrap(),
+            "--input-format",
+            "json",
+            "--format",
+            "json",
+        ],
+        r#"{"port": 8080}"#,
+    );
+    assert_eq!(output.status.code(), Some(0), "{:?}", output);
+    stdout_json(&output);
+}
@@ -31,7 +31,7 @@ pub struct AdapterConfig {
 }
 
 /// Supported adapter types
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum AdapterType {
     /// AWS Systems Manager Parameter Store
@@ -67,6 +67,25 @@ pub enum AdapterType {
 }
 
 impl AdapterType {
+    /// Every adapter type, in declaration order
+    pub const ALL: [AdapterType; 15] = [
+        AdapterType::AwsSsm,
+        AdapterType::AwsSecretsManager,
+        AdapterType::GcpSecretManager,
+        AdapterType::AzureKeyVault,
+        AdapterType::HashicorpVault,
+        AdapterType::Redis,
+        AdapterType::Postgres,
+        AdapterType::Mysql,
+        AdapterType::Http,
+        AdapterType::Grpc,
+        AdapterType::Kafka,
+        AdapterType::Rabbitmq,
+        AdapterType::S3,
+        AdapterType::Tcp,
+        AdapterType::Custom,
+    ];
+
     /// Get default health check path
     pub fn default_health_path(&self) -> Option<&'static str> {
         match self {
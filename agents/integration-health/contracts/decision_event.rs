@@ -7,7 +7,10 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use uuid::Uuid;
 
-use super::{AdapterType, HealthStatus, IntegrationHealthOutput};
+use super::{
+    AdapterType, DecisionVerdict, HealthStatus, IntegrationHealthOutput, IssueCounts,
+    VerdictThresholds,
+};
 
 /// Integration health signal for ruvector-service
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -36,6 +39,10 @@ pub struct IntegrationHealthSignal {
     /// Confidence score (0.0-1.0)
     pub confidence: f64,
 
+    /// Allow/block verdict derived from adapter health
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verdict: Option<DecisionVerdict>,
+
     /// Constraints applied
     pub constraints_applied: Vec<String>,
 
@@ -78,6 +85,7 @@ impl IntegrationHealthSignal {
             inputs_hash,
             outputs: IntegrationHealthOutputs::from_output(output),
             confidence: output.confidence(),
+            verdict: Some(VerdictThresholds::default().verdict(Self::issue_counts(output))),
             constraints_applied: Vec::new(),
             execution_ref,
             timestamp: Utc::now(),
@@ -108,6 +116,7 @@ impl IntegrationHealthSignal {
             inputs_hash,
             outputs,
             confidence: confidence.clamp(0.0, 1.0),
+            verdict: None,
             constraints_applied: Vec::new(),
             execution_ref,
             timestamp: Utc::now(),
@@ -117,6 +126,33 @@ impl IntegrationHealthSignal {
         }
     }
 
+    /// Count unhealthy adapters as errors and degraded ones as warnings
+    ///
+    /// Degraded adapters count as errors when they made the output unhealthy
+    /// (the production policy), so the verdict agrees with `is_healthy`.
+    pub fn issue_counts(output: &IntegrationHealthOutput) -> IssueCounts {
+        let degraded_blocks = !output.is_healthy && output.unhealthy_count == 0;
+        if degraded_blocks {
+            IssueCounts {
+                critical: 0,
+                errors: output.degraded_count,
+                warnings: 0,
+            }
+        } else {
+            IssueCounts {
+                critical: 0,
+                errors: output.unhealthy_count,
+                warnings: output.degraded_count,
+            }
+        }
+    }
+
+    /// Set the allow/block verdict
+    pub fn with_verdict(mut self, verdict: DecisionVerdict) -> Self {
+        self.verdict = Some(verdict);
+        self
+    }
+
     /// Add metadata
     pub fn with_metadata(mut self, key: impl Into<String>, value: serde_json::Value) -> Self {
         self.metadata.insert(key.into(), value);
@@ -202,6 +238,10 @@ pub struct IntegrationHealthOutputs {
     /// Unhealthy count
     pub unhealthy_count: u32,
 
+    /// Unknown count
+    #[serde(default)]
+    pub unknown_count: u32,
+
     /// Adapter summaries
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub adapter_summaries: Vec<AdapterSummary>,
@@ -237,6 +277,7 @@ impl IntegrationHealthOutputs {
             healthy_count: output.healthy_count,
             degraded_count: output.degraded_count,
             unhealthy_count: output.unhealthy_count,
+            unknown_count: output.unknown_count,
             adapter_summaries: output
                 .adapter_results
                 .iter()
@@ -261,6 +302,7 @@ impl IntegrationHealthOutputs {
             healthy_count: adapters_checked,
             degraded_count: 0,
             unhealthy_count: 0,
+            unknown_count: 0,
             adapter_summaries: Vec::new(),
             avg_latency_ms: 0.0,
             max_latency_ms: 0,
@@ -4,13 +4,19 @@
 
 mod decision_event;
 mod adapters;
+#[path = "../../shared/environment.rs"]
+mod environment;
+#[path = "../../shared/verdict.rs"]
+mod verdict;
 
 pub use decision_event::*;
+pub use environment::{EnvironmentRef, ParseEnvironmentError};
+pub use verdict::{DecisionVerdict, IssueCounts, VerdictThresholds};
 pub use adapters::*;
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use uuid::Uuid;
 
 /// Input for integration health check
@@ -26,6 +32,10 @@ pub struct IntegrationHealthInput {
     #[serde(default)]
     pub options: HealthCheckOptions,
 
+    /// Target environment; production treats degraded adapters as unhealthy
+    #[serde(default)]
+    pub environment: EnvironmentRef,
+
     /// Request context
     #[serde(default)]
     pub context: HashMap<String, String>,
@@ -64,6 +74,10 @@ pub struct IntegrationHealthOutput {
     /// Unhealthy adapter count
     pub unhealthy_count: u32,
 
+    /// Adapters no checker could assess
+    #[serde(default)]
+    pub unknown_count: u32,
+
     /// Completion timestamp
     pub completed_at: DateTime<Utc>,
 
@@ -78,9 +92,12 @@ impl IntegrationHealthOutput {
         let healthy = results.iter().filter(|r| r.status == HealthStatus::Healthy).count() as u32;
         let degraded = results.iter().filter(|r| r.status == HealthStatus::Degraded).count() as u32;
         let unhealthy = results.iter().filter(|r| r.status == HealthStatus::Unhealthy).count() as u32;
+        let unknown = results.iter().filter(|r| r.status == HealthStatus::Unknown).count() as u32;
 
-        let score = if total > 0 {
-            (healthy as f64 + degraded as f64 * 0.5) / total as f64
+        // Unknown adapters say nothing about health, so they stay out of the score
+        let assessed = total - unknown;
+        let score = if assessed > 0 {
+            (healthy as f64 + degraded as f64 * 0.5) / assessed as f64
         } else {
             1.0
         };
@@ -94,6 +111,7 @@ impl IntegrationHealthOutput {
             healthy_count: healthy,
             degraded_count: degraded,
             unhealthy_count: unhealthy,
+            unknown_count: unknown,
             completed_at: Utc::now(),
             duration_ms: 0,
         }
@@ -105,6 +123,22 @@ impl IntegrationHealthOutput {
         self
     }
 
+    /// Set completion timestamp
+    pub fn with_completed_at(mut self, at: DateTime<Utc>) -> Self {
+        self.completed_at = at;
+        self
+    }
+
+    /// Apply environment-specific health policy
+    ///
+    /// In production any degraded adapter makes the integration unhealthy.
+    pub fn with_environment(mut self, environment: EnvironmentRef) -> Self {
+        if environment.is_production() {
+            self.is_healthy = self.is_healthy && self.degraded_count == 0;
+        }
+        self
+    }
+
     /// Calculate confidence score
     pub fn confidence(&self) -> f64 {
         let mut conf = self.health_score;
@@ -112,6 +146,8 @@ impl IntegrationHealthOutput {
         conf -= self.degraded_count as f64 * 0.05;
         // Penalty for unhealthy
         conf -= self.unhealthy_count as f64 * 0.1;
+        // Penalty for adapters that could not be assessed
+        conf -= self.unknown_count as f64 * 0.05;
         conf.clamp(0.0, 1.0)
     }
 }
@@ -134,6 +170,42 @@ pub struct HealthCheckOptions {
     /// Retry failed checks
     #[serde(default)]
     pub retry_failed: bool,
+
+    /// Check adapters sharing a type, endpoint and auth once, and reuse
+    /// results cached by earlier requests within the engine's TTL
+    #[serde(default)]
+    pub cache_results: bool,
+
+    /// Per-adapter-type timeouts in milliseconds, overriding `timeout_ms`
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub timeout_overrides: HashMap<AdapterType, u64>,
+
+    /// How much of endpoints to scrub from errors and diagnostics
+    #[serde(default)]
+    pub redact: Redaction,
+}
+
+/// Redaction applied to check errors and diagnostics before they leave the engine
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Redaction {
+    /// Report errors verbatim
+    Off,
+    /// Strip credentials from URLs and connection strings
+    #[default]
+    Credentials,
+    /// Strip credentials and replace the adapter's hostname with a stable hash
+    Hostnames,
+}
+
+impl HealthCheckOptions {
+    /// Timeout for an adapter type: its override, else the global timeout
+    pub fn timeout_for(&self, adapter_type: AdapterType) -> u64 {
+        self.timeout_overrides
+            .get(&adapter_type)
+            .copied()
+            .unwrap_or(self.timeout_ms)
+    }
 }
 
 fn default_timeout() -> u64 {
@@ -163,9 +235,9 @@ pub struct AdapterHealthResult {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<String>,
 
-    /// Diagnostic details
+    /// Diagnostic details, in key order
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub diagnostics: Option<HashMap<String, serde_json::Value>>,
+    pub diagnostics: Option<BTreeMap<String, serde_json::Value>>,
 
     /// Check timestamp
     pub checked_at: DateTime<Utc>,
@@ -220,11 +292,34 @@ impl AdapterHealthResult {
         }
     }
 
+    /// Create result for an adapter no checker can assess
+    pub fn unknown(
+        adapter_id: impl Into<String>,
+        adapter_type: AdapterType,
+        reason: impl Into<String>,
+    ) -> Self {
+        Self {
+            adapter_id: adapter_id.into(),
+            adapter_type,
+            status: HealthStatus::Unknown,
+            latency_ms: 0,
+            error: Some(reason.into()),
+            diagnostics: None,
+            checked_at: Utc::now(),
+        }
+    }
+
     /// Add diagnostics
-    pub fn with_diagnostics(mut self, diagnostics: HashMap<String, serde_json::Value>) -> Self {
+    pub fn with_diagnostics(mut self, diagnostics: BTreeMap<String, serde_json::Value>) -> Self {
         self.diagnostics = Some(diagnostics);
         self
     }
+
+    /// The same result reported under another adapter's id
+    pub fn for_adapter(mut self, adapter_id: impl Into<String>) -> Self {
+        self.adapter_id = adapter_id.into();
+        self
+    }
 }
 
 /// Health status levels
@@ -1,11 +1,19 @@
 //! Integration Health Agent entry point
 //!
 //! Deterministic external adapter health monitoring with integration_health_signal emission.
+//!
+//! `--version --verbose` also prints the commit, build time and compiler.
 
-use clap::{Parser, Subcommand};
+use clap::{CommandFactory, Parser, Subcommand, ValueEnum};
+use clap_complete::Shell;
+use integration_health::build_info::{build_info, verbose_version_requested};
 use integration_health::contracts::*;
+use integration_health::doctor;
 use integration_health::engine::HealthCheckEngine;
 use integration_health::handler::{create_router, AppState};
+use integration_health::monitor::{spawn_scheduled_checks, ScheduleConfig};
+use integration_health::source::AdapterSource;
+use std::io::{Read, Write};
 use std::net::SocketAddr;
 use std::sync::Arc;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
@@ -30,6 +38,24 @@ enum Commands {
         /// Host to bind to
         #[arg(long, default_value = "0.0.0.0")]
         host: String,
+
+        /// Config bundle file or directory listing adapters under
+        /// `integration_health.adapters`, re-read on every sourced check
+        #[arg(long, env = "ADAPTER_SOURCE")]
+        adapter_source: Option<String>,
+
+        /// Check the adapter source every N seconds and serve the latest
+        /// result at /api/v1/integration/latest
+        #[arg(long, env = "CHECK_INTERVAL_SECS", requires = "adapter_source")]
+        check_interval: Option<u64>,
+
+        /// Per-adapter timeout in milliseconds for scheduled checks
+        #[arg(long, default_value = "500")]
+        check_timeout: u64,
+
+        /// Target environment of scheduled checks
+        #[arg(long, default_value = "production")]
+        check_environment: EnvironmentRef,
     },
 
     /// Check health of an adapter
@@ -45,20 +71,91 @@ enum Commands {
         /// Timeout in milliseconds
         #[arg(long, default_value = "500")]
         timeout: u64,
+
+        /// Target environment (production applies stricter health policy)
+        #[arg(long, default_value = "production")]
+        environment: EnvironmentRef,
     },
 
     /// Probe multiple adapters from config file
     Probe {
-        /// Path to adapters config file (JSON/YAML)
-        #[arg(short, long)]
-        file: String,
+        /// Path to adapters config file (JSON/YAML), or `-` for stdin
+        #[arg(short, long, required_unless_present = "source", conflicts_with = "source")]
+        file: Option<String>,
+
+        /// Adapters file format, overriding the extension [default for stdin: json]
+        #[arg(long, value_enum, requires = "file")]
+        input_format: Option<InputFormat>,
+
+        /// Config bundle file or directory listing adapters under
+        /// `integration_health.adapters`
+        #[arg(long)]
+        source: Option<String>,
 
         /// Run checks in parallel
         #[arg(long, default_value = "true")]
         parallel: bool,
+
+        /// Target environment (production applies stricter health policy)
+        #[arg(long, default_value = "production")]
+        environment: EnvironmentRef,
+    },
+
+    /// Check configuration, connectivity, and the latency budget
+    Doctor {
+        /// ruvector-service URL to check
+        #[arg(long, env = "RUVECTOR_SERVICE_URL")]
+        telemetry_url: Option<String>,
+    },
+
+    /// Generate shell completion scripts
+    Completions {
+        /// Shell to generate completions for
+        #[arg(value_enum)]
+        shell: Shell,
     },
 }
 
+/// Formats adapters files are parsed from
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum InputFormat {
+    Json,
+    Yaml,
+}
+
+/// Read adapters from a file, or from stdin when the path is `-`
+///
+/// Without an explicit format, `.yaml` and `.yml` files are parsed as YAML
+/// and everything else, stdin included, as JSON.
+fn read_adapters(
+    file: &str,
+    input_format: Option<InputFormat>,
+) -> anyhow::Result<Vec<AdapterConfig>> {
+    let content = if file == "-" {
+        let mut content = String::new();
+        std::io::stdin().read_to_string(&mut content)?;
+        content
+    } else {
+        std::fs::read_to_string(file)?
+    };
+    let format = input_format.unwrap_or(if file.ends_with(".yaml") || file.ends_with(".yml") {
+        InputFormat::Yaml
+    } else {
+        InputFormat::Json
+    });
+    Ok(match format {
+        InputFormat::Yaml => serde_yaml::from_str(&content)?,
+        InputFormat::Json => serde_json::from_str(&content)?,
+    })
+}
+
+/// Write the completion script for the given shell to a writer
+fn write_completions<W: Write>(shell: Shell, out: &mut W) {
+    let mut command = Cli::command();
+    let name = command.get_name().to_string();
+    clap_complete::generate(shell, &mut command, name, out);
+}
+
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     // Initialize tracing
@@ -69,12 +166,35 @@ async fn main() -> anyhow::Result<()> {
         .with(tracing_subscriber::fmt::layer().json())
         .init();
 
+    if verbose_version_requested(std::env::args()) {
+        println!("{}", build_info().verbose_version("integration-health"));
+        return Ok(());
+    }
+
     let cli = Cli::parse();
 
     match cli.command {
-        Commands::Serve { port, host } => {
+        Commands::Serve {
+            port,
+            host,
+            adapter_source,
+            check_interval,
+            check_timeout,
+            check_environment,
+        } => {
             let addr: SocketAddr = format!("{}:{}", host, port).parse()?;
-            let state = Arc::new(AppState::new());
+            let mut state = AppState::new();
+            if let Some(path) = adapter_source {
+                state = state.with_adapter_source(AdapterSource::from_path(path)?);
+            }
+            let state = Arc::new(state);
+            state.warmup().await;
+            if let Some(secs) = check_interval.filter(|&s| s > 0) {
+                let config = ScheduleConfig::new(std::time::Duration::from_secs(secs), check_timeout)
+                    .with_environment(check_environment);
+                spawn_scheduled_checks(Arc::clone(&state), config);
+                tracing::info!(interval_secs = secs, "Scheduled health checks enabled");
+            }
             let router = create_router(state);
 
             tracing::info!(
@@ -95,6 +215,7 @@ async fn main() -> anyhow::Result<()> {
             adapter_type,
             endpoint,
             timeout,
+            environment,
         } => {
             let adapter_type = match adapter_type.to_lowercase().as_str() {
                 "http" => AdapterType::Http,
@@ -122,6 +243,7 @@ async fn main() -> anyhow::Result<()> {
 
             let mut input = HealthCheckEngine::create_input(vec![adapter], "cli".to_string());
             input.options.timeout_ms = timeout;
+            input.environment = environment;
 
             let engine = HealthCheckEngine::new();
             let result = engine.check(&input).await;
@@ -143,17 +265,22 @@ async fn main() -> anyhow::Result<()> {
             }
         }
 
-        Commands::Probe { file, parallel } => {
-            let content = std::fs::read_to_string(&file)?;
-            let adapters: Vec<AdapterConfig> = if file.ends_with(".yaml") || file.ends_with(".yml")
-            {
-                serde_yaml::from_str(&content)?
-            } else {
-                serde_json::from_str(&content)?
+        Commands::Probe {
+            file,
+            input_format,
+            source,
+            parallel,
+            environment,
+        } => {
+            let adapters: Vec<AdapterConfig> = match (file, source) {
+                (Some(file), _) => read_adapters(&file, input_format)?,
+                (None, Some(source)) => AdapterSource::from_path(source)?.load().await?,
+                (None, None) => unreachable!("clap requires --file or --source"),
             };
 
             let mut input = HealthCheckEngine::create_input(adapters, "cli".to_string());
             input.options.parallel = parallel;
+            input.environment = environment;
 
             let engine = HealthCheckEngine::new();
             let result = engine.check(&input).await;
@@ -164,7 +291,37 @@ async fn main() -> anyhow::Result<()> {
                 std::process::exit(1);
             }
         }
+
+        Commands::Doctor { telemetry_url } => {
+            let report = doctor::run(telemetry_url.as_deref()).await;
+            print!("{}", report.render());
+
+            if !report.passed() {
+                std::process::exit(1);
+            }
+        }
+
+        Commands::Completions { shell } => {
+            write_completions(shell, &mut std::io::stdout());
+        }
     }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bash_completions_include_subcommands() {
+        let mut buffer = Vec::new();
+        write_completions(Shell::Bash, &mut buffer);
+        let script = String::from_utf8(buffer).unwrap();
+
+        assert!(script.contains("integration-health"));
+        for subcommand in ["serve", "check", "probe", "doctor", "completions"] {
+            assert!(script.contains(subcommand), "missing subcommand {}", subcommand);
+        }
+    }
+}
@@ -0,0 +1,79 @@
+//! Self-test for the Integration Health Agent
+//!
+//! Backs the `doctor` subcommand: checks that environment variables are sane,
+//! that ruvector-service is reachable, that the health checkers load, and
+//! that a trivial health check fits in the latency budget.
+
+#[path = "../../../shared/doctor.rs"]
+mod checks;
+
+pub use checks::*;
+
+use crate::engine::{HealthCheckEngine, MAX_LATENCY_MS};
+use std::time::{Duration, Instant};
+
+/// ruvector-service URL used when `RUVECTOR_SERVICE_URL` is not set
+pub const DEFAULT_TELEMETRY_URL: &str = "http://localhost:8080";
+
+/// How long to wait for the telemetry endpoint to answer
+pub const ENDPOINT_TIMEOUT: Duration = Duration::from_millis(MAX_LATENCY_MS);
+
+/// Run every check
+///
+/// `telemetry_url` is the configured `RUVECTOR_SERVICE_URL`, if any.
+pub async fn run(telemetry_url: Option<&str>) -> DoctorReport {
+    let mut report = DoctorReport::default();
+
+    let url_check = check_url_var("RUVECTOR_SERVICE_URL", telemetry_url, DEFAULT_TELEMETRY_URL);
+    let url_ok = url_check.passed;
+    report.push(url_check);
+    report.push(check_parse_var::<u16>(
+        "PORT",
+        std::env::var("PORT").ok().as_deref(),
+    ));
+
+    if url_ok {
+        let url = telemetry_url.unwrap_or(DEFAULT_TELEMETRY_URL);
+        report.push(check_endpoint("telemetry endpoint", url, ENDPOINT_TIMEOUT).await);
+    } else {
+        report.push(DoctorCheck::fail("telemetry endpoint", "no valid URL configured"));
+    }
+
+    let engine = HealthCheckEngine::new();
+    let checkers = engine.checker_ids();
+    if checkers.is_empty() {
+        report.push(DoctorCheck::fail("checkers", "no health checkers registered"));
+    } else {
+        report.push(DoctorCheck::pass("checkers", checkers.join(", ")));
+    }
+
+    // A check with no adapters exercises the engine without touching the network
+    let input = HealthCheckEngine::create_input(Vec::new(), "doctor".to_string());
+    let start = Instant::now();
+    engine.check(&input).await;
+    report.push(check_latency("latency budget", start.elapsed(), MAX_LATENCY_MS));
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_doctor_fails_when_telemetry_unreachable() {
+        // Nothing listens on port 1
+        let report = run(Some("http://127.0.0.1:1")).await;
+
+        assert!(!report.passed());
+        let telemetry = report.check("telemetry endpoint").unwrap();
+        assert!(!telemetry.passed);
+        assert!(telemetry.detail.contains("unreachable"));
+
+        // The local checks still pass
+        assert!(report.check("env RUVECTOR_SERVICE_URL").unwrap().passed);
+        assert!(report.check("checkers").unwrap().passed);
+        assert!(report.check("latency budget").unwrap().passed);
+        assert!(report.render().contains("[FAIL] telemetry endpoint"));
+    }
+}
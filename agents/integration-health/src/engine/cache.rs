@@ -0,0 +1,117 @@
+//! Adapter check result caching
+//!
+//! Adapters that point at the same endpoint with the same credentials get the
+//! same answer from a health check, so with `options.cache_results` set the
+//! engine checks each distinct target once per request and, when a TTL is
+//! configured, reuses fresh results across requests.
+
+use crate::clock::{system_clock, Clock};
+use crate::contracts::*;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// What makes two adapter checks interchangeable
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CheckKey {
+    pub adapter_type: AdapterType,
+    /// Endpoint plus health path, since a different path is a different probe
+    pub endpoint: String,
+    /// Hash of the auth configuration, so credentials are never held as keys
+    pub auth_hash: Option<String>,
+}
+
+impl CheckKey {
+    /// Key for an adapter
+    pub fn of(adapter: &AdapterConfig) -> Self {
+        let endpoint = match &adapter.health_path {
+            Some(path) => format!("{}{}", adapter.endpoint, path),
+            None => adapter.endpoint.clone(),
+        };
+        let auth_hash = adapter.auth.as_ref().map(|auth| {
+            let mut hasher = Sha256::new();
+            hasher.update(serde_json::to_vec(auth).unwrap_or_default());
+            hex::encode(hasher.finalize())
+        });
+        Self {
+            adapter_type: adapter.adapter_type,
+            endpoint,
+            auth_hash,
+        }
+    }
+}
+
+/// Read the cross-request TTL from `HEALTH_CHECK_CACHE_TTL_MS`
+///
+/// Unset or unparsable values disable reuse across requests; results are then
+/// only shared between adapters within one request.
+pub fn cache_ttl_from_env() -> Duration {
+    std::env::var("HEALTH_CHECK_CACHE_TTL_MS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .map(Duration::from_millis)
+        .unwrap_or(Duration::ZERO)
+}
+
+/// Check results kept for reuse across requests
+pub struct ResultCache {
+    ttl: Duration,
+    entries: Mutex<HashMap<CheckKey, (Instant, AdapterHealthResult)>>,
+    clock: Arc<dyn Clock>,
+}
+
+impl ResultCache {
+    /// Cache keeping results for `ttl`; a zero TTL keeps nothing
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+            clock: system_clock(),
+        }
+    }
+
+    /// Age entries by `clock` instead of the system clock
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// How long results are reused across requests
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// A result for `key` that is still within the TTL
+    pub fn get(&self, key: &CheckKey) -> Option<AdapterHealthResult> {
+        if self.ttl.is_zero() {
+            return None;
+        }
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        match entries.get(key) {
+            Some((stored, result)) if self.clock.elapsed(*stored) < self.ttl => Some(result.clone()),
+            Some(_) => {
+                entries.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    /// Remember a result for `key`
+    pub fn insert(&self, key: CheckKey, result: &AdapterHealthResult) {
+        if self.ttl.is_zero() {
+            return;
+        }
+        self.entries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(key, (self.clock.instant(), result.clone()));
+    }
+}
+
+impl Default for ResultCache {
+    fn default() -> Self {
+        Self::new(cache_ttl_from_env())
+    }
+}
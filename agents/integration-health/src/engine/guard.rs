@@ -0,0 +1,217 @@
+//! Endpoint guard against server-side request forgery
+//!
+//! Adapter endpoints come from request bodies, so without a guard the agent
+//! will dial whatever it is asked to, including cloud metadata services and
+//! other internal addresses. When enabled, the guard runs before any checker
+//! connects: link-local and metadata addresses are always refused, denylisted
+//! hosts and networks are refused, and with an allowlist only matching
+//! targets are permitted. Hostnames are resolved and every address they
+//! resolve to is checked as well. HTTP checks additionally connect through
+//! [`EndpointGuard::safe_http`], which applies the same rules to the addresses
+//! actually dialed, so a name cannot be rebound between the check and the
+//! connection.
+//!
+//! Configured from the environment:
+//! - `ENDPOINT_ALLOWLIST`: comma-separated hosts, `*.suffix` patterns, IPs or CIDRs
+//! - `ENDPOINT_DENYLIST`: same syntax, checked before the allowlist
+//! - `ENDPOINT_GUARD`: set to `true` to enable the default blocks without lists
+
+use super::redact::endpoint_host;
+use crate::contracts::AdapterConfig;
+use crate::safe_http::{is_link_local, SafeHttp, METADATA_HOSTS};
+use std::net::IpAddr;
+
+/// A host, host pattern, or network an endpoint is matched against
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HostRule {
+    /// Exact hostname or IP address
+    Exact(String),
+    /// Any subdomain of the suffix, written `*.example.com`
+    Suffix(String),
+    /// Network in CIDR notation
+    Cidr { network: IpAddr, prefix: u8 },
+}
+
+impl HostRule {
+    /// Parse a rule; `None` for a CIDR with an out-of-range prefix
+    pub fn parse(rule: &str) -> Option<Self> {
+        let rule = rule.trim().to_ascii_lowercase();
+        if let Some(suffix) = rule.strip_prefix("*.") {
+            return Some(HostRule::Suffix(suffix.to_string()));
+        }
+        if let Some((network, prefix)) = rule.split_once('/') {
+            let network: IpAddr = network.parse().ok()?;
+            let prefix: u8 = prefix.parse().ok()?;
+            let max = if network.is_ipv4() { 32 } else { 128 };
+            return (prefix <= max).then_some(HostRule::Cidr { network, prefix });
+        }
+        Some(HostRule::Exact(rule))
+    }
+
+    /// Whether the rule names this host
+    pub fn matches_host(&self, host: &str) -> bool {
+        let host = host.to_ascii_lowercase();
+        match self {
+            HostRule::Exact(exact) => *exact == host,
+            HostRule::Suffix(suffix) => host
+                .strip_suffix(suffix.as_str())
+                .is_some_and(|rest| rest.ends_with('.')),
+            HostRule::Cidr { .. } => host.parse().is_ok_and(|ip| self.matches_ip(ip)),
+        }
+    }
+
+    /// Whether the rule covers this address
+    pub fn matches_ip(&self, ip: IpAddr) -> bool {
+        match self {
+            HostRule::Exact(exact) => exact.parse() == Ok(ip),
+            HostRule::Suffix(_) => false,
+            HostRule::Cidr { network, prefix } => match (network, ip) {
+                (IpAddr::V4(network), IpAddr::V4(ip)) => {
+                    let mask = u32::MAX.checked_shl(32 - *prefix as u32).unwrap_or(0);
+                    u32::from(*network) & mask == u32::from(ip) & mask
+                }
+                (IpAddr::V6(network), IpAddr::V6(ip)) => {
+                    let mask = u128::MAX.checked_shl(128 - *prefix as u32).unwrap_or(0);
+                    u128::from(*network) & mask == u128::from(ip) & mask
+                }
+                _ => false,
+            },
+        }
+    }
+}
+
+/// Hosts and networks adapter checks may reach
+#[derive(Debug, Clone, Default)]
+pub struct EndpointGuard {
+    allow: Vec<HostRule>,
+    deny: Vec<HostRule>,
+}
+
+impl EndpointGuard {
+    /// Guard with only the default link-local and metadata blocks
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Permit only targets matching one of these rules
+    pub fn with_allowlist<'a>(mut self, rules: impl IntoIterator<Item = &'a str>) -> Self {
+        self.allow.extend(rules.into_iter().filter_map(HostRule::parse));
+        self
+    }
+
+    /// Refuse targets matching any of these rules
+    pub fn with_denylist<'a>(mut self, rules: impl IntoIterator<Item = &'a str>) -> Self {
+        self.deny.extend(rules.into_iter().filter_map(HostRule::parse));
+        self
+    }
+
+    /// Guard configured by `ENDPOINT_ALLOWLIST`, `ENDPOINT_DENYLIST` and `ENDPOINT_GUARD`
+    ///
+    /// Returns `None`, leaving checks unguarded, when none of them is set.
+    pub fn from_env() -> Option<Self> {
+        let list = |var: &str| {
+            std::env::var(var)
+                .ok()
+                .filter(|v| !v.trim().is_empty())
+        };
+        let allow = list("ENDPOINT_ALLOWLIST");
+        let deny = list("ENDPOINT_DENYLIST");
+        let enabled = list("ENDPOINT_GUARD").is_some_and(|v| matches!(v.as_str(), "1" | "true"));
+        if allow.is_none() && deny.is_none() && !enabled {
+            return None;
+        }
+
+        let split = |v: &Option<String>| -> Vec<String> {
+            v.iter()
+                .flat_map(|v| v.split(','))
+                .map(str::trim)
+                .filter(|r| !r.is_empty())
+                .map(String::from)
+                .collect()
+        };
+        let (allow, deny) = (split(&allow), split(&deny));
+        Some(
+            Self::new()
+                .with_allowlist(allow.iter().map(String::as_str))
+                .with_denylist(deny.iter().map(String::as_str)),
+        )
+    }
+
+    /// Whether `host` may be reached at `ip`
+    pub fn permits(&self, host: &str, ip: IpAddr) -> bool {
+        let matches = |rules: &[HostRule]| rules.iter().any(|r| r.matches_host(host) || r.matches_ip(ip));
+        !METADATA_HOSTS.contains(&host.to_ascii_lowercase().as_str())
+            && !is_link_local(ip)
+            && !matches(&self.deny)
+            && (self.allow.is_empty() || matches(&self.allow))
+    }
+
+    /// Guarded HTTP settings enforcing this guard on every connection
+    pub fn safe_http(&self) -> SafeHttp {
+        let guard = self.clone();
+        SafeHttp::with_policy(move |host, ip| guard.permits(host, ip))
+    }
+
+    /// Decide whether an adapter's endpoint may be contacted
+    ///
+    /// Returns the reason when it may not.
+    pub async fn check(&self, adapter: &AdapterConfig) -> Result<(), String> {
+        let host = endpoint_host(&adapter.endpoint)
+            .map(|h| h.trim_start_matches('[').trim_end_matches(']'))
+            .ok_or_else(|| "endpoint has no host".to_string())?;
+
+        let addresses: Vec<IpAddr> = match host.parse() {
+            Ok(ip) => vec![ip],
+            Err(_) => tokio::net::lookup_host((host, 0))
+                .await
+                .map(|addrs| addrs.map(|a| a.ip()).collect())
+                .unwrap_or_default(),
+        };
+
+        if METADATA_HOSTS.contains(&host.to_ascii_lowercase().as_str())
+            || addresses.iter().any(|ip| is_link_local(*ip))
+        {
+            return Err(format!("{} is a link-local or metadata address", host));
+        }
+
+        let matches = |rules: &[HostRule]| {
+            rules.iter().any(|r| r.matches_host(host))
+                || addresses.iter().any(|ip| rules.iter().any(|r| r.matches_ip(*ip)))
+        };
+        if matches(&self.deny) {
+            return Err(format!("{} is denylisted", host));
+        }
+
+        if !self.allow.is_empty() {
+            let allowed = self.allow.iter().any(|r| r.matches_host(host))
+                || (!addresses.is_empty()
+                    && addresses
+                        .iter()
+                        .all(|ip| self.allow.iter().any(|r| r.matches_ip(*ip))));
+            if !allowed {
+                return Err(format!("{} is not allowlisted", host));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_host_rules() {
+        let cidr = HostRule::parse("10.0.0.0/8").unwrap();
+        assert!(cidr.matches_ip("10.20.30.40".parse().unwrap()));
+        assert!(!cidr.matches_ip("11.0.0.1".parse().unwrap()));
+
+        let suffix = HostRule::parse("*.corp.internal").unwrap();
+        assert!(suffix.matches_host("db.corp.internal"));
+        assert!(!suffix.matches_host("corp.internal"));
+        assert!(!suffix.matches_host("evilcorp.internal"));
+
+        assert!(HostRule::parse("10.0.0.0/40").is_none());
+    }
+}
@@ -2,12 +2,21 @@
 //!
 //! Deterministic health checking of external adapters.
 
+mod cache;
 mod checkers;
+pub mod guard;
+pub mod redact;
 
+pub use cache::*;
 pub use checkers::*;
+pub use guard::EndpointGuard;
 
+use crate::clock::{system_clock, Clock};
 use crate::contracts::*;
+use crate::http_pool::PoolConfig;
 use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::sync::Arc;
 use std::time::Instant;
 use tokio::time::{timeout, Duration};
 use uuid::Uuid;
@@ -19,6 +28,9 @@ pub const MAX_TOKENS: usize = 800;
 /// Integration health check engine
 pub struct HealthCheckEngine {
     checkers: Vec<Box<dyn HealthChecker>>,
+    cache: ResultCache,
+    guard: Option<EndpointGuard>,
+    clock: Arc<dyn Clock>,
 }
 
 impl Default for HealthCheckEngine {
@@ -28,56 +40,202 @@ impl Default for HealthCheckEngine {
 }
 
 impl HealthCheckEngine {
-    /// Create new engine with default checkers
+    /// Create new engine with default checkers, guarded per [`EndpointGuard::from_env`]
     pub fn new() -> Self {
+        Self::with_guard(EndpointGuard::from_env())
+    }
+
+    /// Create an engine whose checks must pass `guard` before connecting
+    ///
+    /// Guarded engines also connect HTTP checks through
+    /// [`EndpointGuard::safe_http`], which re-checks the addresses dialed and
+    /// stops redirects.
+    pub fn with_guard(guard: Option<EndpointGuard>) -> Self {
+        let mut clients = HttpClientPool::new(PoolConfig::from_env());
+        if let Some(guard) = &guard {
+            clients = clients.with_safe_http(guard.safe_http());
+        }
+        let clients = Arc::new(clients);
         Self {
             checkers: vec![
-                Box::new(HttpChecker),
+                Box::new(HttpChecker::new(clients.clone())),
                 Box::new(TcpChecker),
-                Box::new(VaultChecker),
+                Box::new(VaultChecker::new(clients)),
             ],
+            cache: ResultCache::default(),
+            guard,
+            clock: system_clock(),
         }
     }
 
+    /// Reuse results across requests for `ttl` when `options.cache_results` is set
+    pub fn with_cache_ttl(mut self, ttl: std::time::Duration) -> Self {
+        self.cache = ResultCache::new(ttl).with_clock(self.clock.clone());
+        self
+    }
+
+    /// Read time from `clock`, for timestamps, durations and cache TTLs
+    ///
+    /// Resets the result cache, so call this before the first check.
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.cache = ResultCache::new(self.cache.ttl()).with_clock(clock.clone());
+        self.clock = clock;
+        self
+    }
+
+    /// Register a checker, consulted before the default checkers
+    pub fn with_checker(mut self, checker: Box<dyn HealthChecker>) -> Self {
+        self.checkers.insert(0, checker);
+        self
+    }
+
+    /// Prepare the engine so the first real request meets the latency budget
+    ///
+    /// Runs a check with no adapters, which exercises the engine without
+    /// touching the network. Returns how long warmup took.
+    pub async fn warmup(&self) -> std::time::Duration {
+        let start = Instant::now();
+        self.check(&Self::create_input(Vec::new(), "warmup".to_string())).await;
+        start.elapsed()
+    }
+
+    /// Ids of the registered checkers
+    pub fn checker_ids(&self) -> Vec<&str> {
+        self.checkers.iter().map(|c| c.id()).collect()
+    }
+
+    /// Adapter types at least one registered checker can probe
+    pub fn supported_adapter_types(&self) -> Vec<AdapterType> {
+        AdapterType::ALL
+            .into_iter()
+            .filter(|t| self.checkers.iter().any(|c| c.supports(t)))
+            .collect()
+    }
+
     /// Run health checks
     pub async fn check(&self, input: &IntegrationHealthInput) -> IntegrationHealthOutput {
-        let start = Instant::now();
+        let start = self.clock.instant();
         let request_id = input.request_id;
 
         let mut results = Vec::new();
-        let timeout_ms = input.options.timeout_ms;
+        let options = &input.options;
+        let cache_results = input.options.cache_results;
+
+        // With caching, each distinct target is checked once
+        let targets: Vec<&AdapterConfig> = if cache_results {
+            let mut seen = std::collections::HashSet::new();
+            input
+                .adapters
+                .iter()
+                .filter(|adapter| seen.insert(CheckKey::of(adapter)))
+                .collect()
+        } else {
+            input.adapters.iter().collect()
+        };
 
         if input.options.parallel {
             // Run checks in parallel
-            let futures: Vec<_> = input
-                .adapters
+            let futures: Vec<_> = targets
                 .iter()
-                .map(|adapter| self.check_adapter(adapter, timeout_ms))
+                .map(|adapter| self.check_target(adapter, options))
                 .collect();
 
             let outcomes = futures::future::join_all(futures).await;
             results.extend(outcomes);
         } else {
             // Run checks sequentially
-            for adapter in &input.adapters {
+            for adapter in &targets {
                 // Check latency budget
-                if start.elapsed().as_millis() as u64 > MAX_LATENCY_MS - timeout_ms {
+                let timeout_ms = options.timeout_for(adapter.adapter_type);
+                if self.clock.elapsed(start).as_millis() as u64 > MAX_LATENCY_MS.saturating_sub(timeout_ms) {
                     tracing::warn!("Health check exceeded latency budget, stopping early");
                     break;
                 }
 
-                let result = self.check_adapter(adapter, timeout_ms).await;
+                let result = self.check_target(adapter, options).await;
                 results.push(result);
             }
         }
 
-        let duration_ms = start.elapsed().as_millis() as u64;
-        IntegrationHealthOutput::healthy(request_id, results).with_duration(duration_ms)
+        if cache_results {
+            // Hand each adapter the result of its target, under its own id
+            let by_key: HashMap<CheckKey, AdapterHealthResult> = targets
+                .iter()
+                .map(|adapter| CheckKey::of(adapter))
+                .zip(results)
+                .collect();
+            results = input
+                .adapters
+                .iter()
+                .filter_map(|adapter| {
+                    by_key
+                        .get(&CheckKey::of(adapter))
+                        .map(|result| result.clone().for_adapter(&adapter.id))
+                })
+                .collect();
+        }
+
+        let duration_ms = self.clock.elapsed(start).as_millis() as u64;
+        IntegrationHealthOutput::healthy(request_id, results)
+            .with_environment(input.environment)
+            .with_duration(duration_ms)
+            .with_completed_at(self.clock.now())
     }
 
-    /// Check a single adapter
-    async fn check_adapter(&self, adapter: &AdapterConfig, timeout_ms: u64) -> AdapterHealthResult {
-        let adapter_start = Instant::now();
+    /// Check a single adapter, consulting the result cache when enabled
+    ///
+    /// The cache holds unredacted results; each request's redaction is
+    /// applied to the copy it gets back.
+    async fn check_target(
+        &self,
+        adapter: &AdapterConfig,
+        options: &HealthCheckOptions,
+    ) -> AdapterHealthResult {
+        let result = if !options.cache_results {
+            self.check_adapter(adapter, options).await
+        } else {
+            let key = CheckKey::of(adapter);
+            match self.cache.get(&key) {
+                Some(result) => result.for_adapter(&adapter.id),
+                None => {
+                    let result = self.check_adapter(adapter, options).await;
+                    self.cache.insert(key, &result);
+                    result
+                }
+            }
+        };
+        redact::redact_result(result, adapter, options.redact)
+    }
+
+    /// Check a single adapter within its type's timeout
+    async fn check_adapter(
+        &self,
+        adapter: &AdapterConfig,
+        options: &HealthCheckOptions,
+    ) -> AdapterHealthResult {
+        let mut result = self.run_checker(adapter, options).await;
+        // Checkers stamp results with the system time; the engine's clock decides
+        result.checked_at = self.clock.now();
+        result
+    }
+
+    /// Guard the endpoint and run the checker for its adapter type
+    async fn run_checker(
+        &self,
+        adapter: &AdapterConfig,
+        options: &HealthCheckOptions,
+    ) -> AdapterHealthResult {
+        let timeout_ms = options.timeout_for(adapter.adapter_type);
+
+        if let Some(guard) = &self.guard {
+            if let Err(reason) = guard.check(adapter).await {
+                return AdapterHealthResult::unhealthy(
+                    &adapter.id,
+                    adapter.adapter_type,
+                    format!("Endpoint not permitted: {}", reason),
+                );
+            }
+        }
 
         // Find appropriate checker
         let checker = self
@@ -97,16 +255,11 @@ impl HealthCheckEngine {
                     ),
                 }
             }
-            None => {
-                // No checker available, use generic TCP check
-                let latency = adapter_start.elapsed().as_millis() as u64;
-                AdapterHealthResult::degraded(
-                    &adapter.id,
-                    adapter.adapter_type,
-                    latency,
-                    "No specialized checker available",
-                )
-            }
+            None => AdapterHealthResult::unknown(
+                &adapter.id,
+                adapter.adapter_type,
+                "No health checker supports this adapter type",
+            ),
         }
     }
 
@@ -117,6 +270,7 @@ impl HealthCheckEngine {
             hasher.update(adapter.id.as_bytes());
             hasher.update(adapter.endpoint.as_bytes());
         }
+        hasher.update(input.environment.to_string().as_bytes());
         hex::encode(hasher.finalize())
     }
 
@@ -129,6 +283,7 @@ impl HealthCheckEngine {
             request_id: Uuid::new_v4(),
             adapters,
             options: HealthCheckOptions::default(),
+            environment: EnvironmentRef::default(),
             context: std::collections::HashMap::new(),
             requested_at: chrono::Utc::now(),
             requested_by,
@@ -12,16 +12,34 @@ use axum::{
 };
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use uuid::Uuid;
 
 use crate::contracts::*;
+use crate::health::HealthSummary;
 use crate::engine::HealthCheckEngine;
+use crate::monitor::MonitorState;
+use crate::source::{AdapterSource, AdapterSourceError};
 use crate::telemetry::TelemetryEmitter;
 
+#[path = "../../../shared/capabilities.rs"]
+pub mod capabilities;
+#[path = "../../../shared/request_shape.rs"]
+pub mod request_shape;
+
+use capabilities::Capabilities;
+use request_shape::{CheckedJson, RequestShapeError};
+
 /// Application state
 pub struct AppState {
     pub engine: HealthCheckEngine,
     pub telemetry: TelemetryEmitter,
+    /// Provider-backed adapter list for `POST /api/v1/integration/check/source`
+    pub adapter_source: Option<AdapterSource>,
+    /// Latest scheduled run, served at `GET /api/v1/integration/latest`
+    pub monitor: MonitorState,
+    /// Start time for uptime calculation
+    pub start_time: Instant,
 }
 
 impl AppState {
@@ -29,7 +47,71 @@ impl AppState {
         Self {
             engine: HealthCheckEngine::new(),
             telemetry: TelemetryEmitter::new(),
+            adapter_source: None,
+            monitor: MonitorState::default(),
+            start_time: Instant::now(),
+        }
+    }
+
+    /// Resolve adapter lists from a configuration provider
+    pub fn with_adapter_source(mut self, source: AdapterSource) -> Self {
+        self.adapter_source = Some(source);
+        self
+    }
+
+    /// Warm the engine and the telemetry connection before serving
+    ///
+    /// Failing to reach ruvector-service is logged and does not fail warmup.
+    pub async fn warmup(&self) -> Duration {
+        let start = Instant::now();
+        let engine = self.engine.warmup().await;
+        if let Err(e) = self.telemetry.prime().await {
+            tracing::warn!(error = %e, "Failed to prime telemetry connection");
         }
+
+        let elapsed = start.elapsed();
+        tracing::info!(
+            duration_ms = elapsed.as_millis() as u64,
+            engine_ms = engine.as_millis() as u64,
+            "Warmup complete"
+        );
+        elapsed
+    }
+}
+
+impl AppState {
+    /// Describe the registered checkers and the adapter types they cover
+    pub fn capabilities(&self) -> Capabilities {
+        let mut capabilities = Capabilities::new(
+            IntegrationHealthSignal::AGENT_ID,
+            IntegrationHealthSignal::AGENT_VERSION,
+        );
+        capabilities.checkers = self
+            .engine
+            .checker_ids()
+            .into_iter()
+            .map(str::to_string)
+            .collect();
+        capabilities.adapter_types = self
+            .engine
+            .supported_adapter_types()
+            .into_iter()
+            .filter_map(|t| serde_json::to_value(t).ok())
+            .filter_map(|v| v.as_str().map(str::to_string))
+            .collect();
+        capabilities
+    }
+
+    /// Summarize uptime, build, registrations and telemetry for `/health`
+    pub fn health(&self) -> HealthSummary {
+        let mut summary = HealthSummary::new(
+            IntegrationHealthSignal::AGENT_ID,
+            IntegrationHealthSignal::AGENT_VERSION,
+            self.start_time,
+        )
+        .with_telemetry(true, self.telemetry.last_emission());
+        summary.checkers = self.engine.checker_ids().len();
+        summary
     }
 }
 
@@ -43,7 +125,10 @@ impl Default for AppState {
 pub fn create_router(state: Arc<AppState>) -> Router {
     Router::new()
         .route("/health", get(health_check))
+        .route("/capabilities", get(capabilities))
         .route("/api/v1/integration/check", post(check_health))
+        .route("/api/v1/integration/check/source", post(check_sourced))
+        .route("/api/v1/integration/latest", get(latest_result))
         .route("/api/v1/integration/probe", post(probe_adapter))
         // Instrumented execution endpoint (requires X-Parent-Span-Id header)
         .route(
@@ -54,18 +139,22 @@ pub fn create_router(state: Arc<AppState>) -> Router {
 }
 
 /// Health check endpoint
-async fn health_check() -> impl IntoResponse {
+async fn health_check(State(state): State<Arc<AppState>>) -> Json<HealthResponse> {
     Json(HealthResponse {
         status: "healthy".to_string(),
-        agent_id: IntegrationHealthSignal::AGENT_ID.to_string(),
-        agent_version: IntegrationHealthSignal::AGENT_VERSION.to_string(),
+        summary: state.health(),
     })
 }
 
+/// Capabilities endpoint
+async fn capabilities(State(state): State<Arc<AppState>>) -> Json<Capabilities> {
+    Json(state.capabilities())
+}
+
 /// Check health of adapters
 async fn check_health(
     State(state): State<Arc<AppState>>,
-    Json(request): Json<CheckHealthRequest>,
+    CheckedJson(request): CheckedJson<CheckHealthRequest>,
 ) -> Result<Json<ApiResponse<IntegrationHealthOutput>>, (StatusCode, Json<ApiError>)> {
     // Validate adapters
     if request.adapters.is_empty() {
@@ -75,6 +164,7 @@ async fn check_health(
                 error: "InvalidInput".to_string(),
                 message: "At least one adapter must be specified".to_string(),
                 request_id: None,
+                field: Some("adapters".to_string()),
             }),
         ));
     }
@@ -88,6 +178,7 @@ async fn check_health(
     if let Some(opts) = request.options {
         input.options = opts;
     }
+    input.environment = request.environment.unwrap_or_default();
 
     let request_id = input.request_id;
     let inputs_hash = HealthCheckEngine::compute_inputs_hash(&input);
@@ -112,12 +203,79 @@ async fn check_health(
     }))
 }
 
+/// Check health of the adapters currently listed in the adapter source
+///
+/// The list is resolved and validated on every call.
+async fn check_sourced(
+    State(state): State<Arc<AppState>>,
+    CheckedJson(request): CheckedJson<SourcedCheckRequest>,
+) -> Result<Json<ApiResponse<IntegrationHealthOutput>>, (StatusCode, Json<ApiError>)> {
+    let Some(source) = &state.adapter_source else {
+        return Err((
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(ApiError {
+                error: "NoAdapterSource".to_string(),
+                message: "No adapter source is configured".to_string(),
+                request_id: None,
+                field: None,
+            }),
+        ));
+    };
+
+    let adapters = source.load().await.map_err(|e| {
+        let status = match e {
+            AdapterSourceError::Provider(_) => StatusCode::BAD_GATEWAY,
+            AdapterSourceError::Invalid(_) => StatusCode::UNPROCESSABLE_ENTITY,
+        };
+        (
+            status,
+            Json(ApiError {
+                error: "AdapterSourceError".to_string(),
+                message: e.to_string(),
+                request_id: None,
+                field: None,
+            }),
+        )
+    })?;
+
+    let request = CheckHealthRequest {
+        adapters,
+        options: request.options,
+        requested_by: request.requested_by,
+        environment: request.environment,
+    };
+    check_health(State(state), CheckedJson(request)).await
+}
+
+/// Output of the most recent scheduled run
+async fn latest_result(
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<ApiResponse<IntegrationHealthOutput>>, (StatusCode, Json<ApiError>)> {
+    match state.monitor.latest() {
+        Some(output) => Ok(Json(ApiResponse {
+            success: output.is_healthy,
+            request_id: output.request_id,
+            data: output,
+        })),
+        None => Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiError {
+                error: "NoScheduledRun".to_string(),
+                message: "No scheduled health check has completed yet".to_string(),
+                request_id: None,
+                field: None,
+            }),
+        )),
+    }
+}
+
 /// Quick probe of a single adapter (no telemetry)
 async fn probe_adapter(
     State(state): State<Arc<AppState>>,
-    Json(request): Json<ProbeRequest>,
+    CheckedJson(request): CheckedJson<ProbeRequest>,
 ) -> Result<Json<ProbeResponse>, (StatusCode, Json<ApiError>)> {
-    let input = HealthCheckEngine::create_input(vec![request.adapter], "probe".to_string());
+    let mut input = HealthCheckEngine::create_input(vec![request.adapter], "probe".to_string());
+    input.environment = request.environment.unwrap_or_default();
 
     let output = state.engine.check(&input).await;
 
@@ -140,7 +298,7 @@ async fn probe_adapter(
 async fn check_health_instrumented(
     exec_ctx: ExecutionContextExtractor,
     State(state): State<Arc<AppState>>,
-    Json(request): Json<CheckHealthRequest>,
+    CheckedJson(request): CheckedJson<CheckHealthRequest>,
 ) -> Result<
     Json<ExecutionEnvelope<IntegrationHealthOutput>>,
     (StatusCode, Json<serde_json::Value>),
@@ -167,6 +325,7 @@ async fn check_health_instrumented(
     if let Some(opts) = request.options {
         input.options = opts;
     }
+    input.environment = request.environment.unwrap_or_default();
 
     let request_id = input.request_id;
     let inputs_hash = HealthCheckEngine::compute_inputs_hash(&input);
@@ -202,8 +361,9 @@ async fn check_health_instrumented(
 #[derive(Debug, Serialize)]
 pub struct HealthResponse {
     pub status: String,
-    pub agent_id: String,
-    pub agent_version: String,
+    /// Agent id and version, build, uptime, registrations and telemetry
+    #[serde(flatten)]
+    pub summary: HealthSummary,
 }
 
 /// Check health request
@@ -212,12 +372,25 @@ pub struct CheckHealthRequest {
     pub adapters: Vec<AdapterConfig>,
     pub options: Option<HealthCheckOptions>,
     pub requested_by: Option<String>,
+    /// Target environment (defaults to development)
+    pub environment: Option<EnvironmentRef>,
+}
+
+/// Check request for the adapters in the configured adapter source
+#[derive(Debug, Default, Deserialize)]
+pub struct SourcedCheckRequest {
+    pub options: Option<HealthCheckOptions>,
+    pub requested_by: Option<String>,
+    /// Target environment (defaults to development)
+    pub environment: Option<EnvironmentRef>,
 }
 
 /// Probe request
 #[derive(Debug, Deserialize)]
 pub struct ProbeRequest {
     pub adapter: AdapterConfig,
+    /// Target environment (defaults to development)
+    pub environment: Option<EnvironmentRef>,
 }
 
 /// API response wrapper
@@ -243,4 +416,19 @@ pub struct ApiError {
     pub error: String,
     pub message: String,
     pub request_id: Option<Uuid>,
+    /// Offending request field, for malformed requests
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub field: Option<String>,
+}
+
+impl IntoResponse for RequestShapeError {
+    fn into_response(self) -> axum::response::Response {
+        let error = ApiError {
+            error: "BadRequest".to_string(),
+            message: self.to_string(),
+            request_id: None,
+            field: self.field,
+        };
+        (StatusCode::BAD_REQUEST, Json(error)).into_response()
+    }
 }
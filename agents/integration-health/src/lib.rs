@@ -13,10 +13,37 @@
 //! - Traceable: Full audit trail via DecisionEvents
 
 pub mod client;
+pub mod doctor;
 pub mod engine;
 pub mod handler;
+pub mod monitor;
+pub mod source;
 pub mod telemetry;
 
+// Connection pool settings shared by the agents' HTTP clients
+#[path = "../../shared/http_pool.rs"]
+pub mod http_pool;
+
+// Injectable time source
+#[path = "../../shared/clock.rs"]
+pub mod clock;
+
+// Version, commit, build time and compiler stamped by the build script
+#[path = "../../shared/build_info.rs"]
+pub mod build_info;
+
+// Diagnostic summary served by `GET /health`
+#[path = "../../shared/health.rs"]
+pub mod health;
+
+// Guarded client for user-supplied URLs
+#[path = "../../shared/safe_http.rs"]
+pub mod safe_http;
+
+// Constant-time comparison for tokens and API keys
+#[path = "../../shared/secure_compare.rs"]
+pub mod secure_compare;
+
 // Re-export contracts
 #[path = "../contracts/mod.rs"]
 pub mod contracts;
@@ -0,0 +1,150 @@
+//! Scheduled health checks
+//!
+//! With a check interval configured, `serve` probes the adapter source on a
+//! fixed cadence and keeps the latest output in memory for
+//! `GET /api/v1/integration/latest`. Ticks are not jittered, and a tick that
+//! lands while the previous run is still in flight is skipped rather than
+//! queued. Nothing is persisted, so a restarted agent reports no result until
+//! its first run completes.
+
+use crate::contracts::*;
+use crate::engine::HealthCheckEngine;
+use crate::handler::AppState;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tokio::time::MissedTickBehavior;
+
+/// How scheduled runs are performed
+#[derive(Debug, Clone)]
+pub struct ScheduleConfig {
+    /// Time between the starts of consecutive runs
+    pub interval: Duration,
+    /// Options applied to every scheduled run
+    pub options: HealthCheckOptions,
+    /// Target environment of every scheduled run
+    pub environment: EnvironmentRef,
+}
+
+impl ScheduleConfig {
+    /// Run every `interval` with the given per-adapter timeout
+    pub fn new(interval: Duration, timeout_ms: u64) -> Self {
+        Self {
+            interval,
+            options: HealthCheckOptions {
+                timeout_ms,
+                parallel: true,
+                ..Default::default()
+            },
+            environment: EnvironmentRef::default(),
+        }
+    }
+
+    /// Set the target environment
+    pub fn with_environment(mut self, environment: EnvironmentRef) -> Self {
+        self.environment = environment;
+        self
+    }
+}
+
+/// Latest scheduled result and the in-flight flag
+#[derive(Debug, Default)]
+pub struct MonitorState {
+    latest: RwLock<Option<IntegrationHealthOutput>>,
+    running: AtomicBool,
+}
+
+/// Marks a scheduled run as in flight until dropped
+pub struct RunGuard<'a> {
+    running: &'a AtomicBool,
+}
+
+impl Drop for RunGuard<'_> {
+    fn drop(&mut self) {
+        self.running.store(false, Ordering::Release);
+    }
+}
+
+impl MonitorState {
+    /// Claim the run slot, or `None` if a run is already in flight
+    pub fn begin_run(&self) -> Option<RunGuard<'_>> {
+        self.running
+            .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+            .ok()
+            .map(|_| RunGuard {
+                running: &self.running,
+            })
+    }
+
+    /// Output of the most recent completed run
+    pub fn latest(&self) -> Option<IntegrationHealthOutput> {
+        self.latest.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    fn record(&self, output: IntegrationHealthOutput) {
+        *self.latest.write().unwrap_or_else(|e| e.into_inner()) = Some(output);
+    }
+}
+
+/// Perform one scheduled run against the state's adapter source
+///
+/// Returns `None` when the run was skipped: a previous run is still in flight,
+/// no adapter source is configured, or the source could not be resolved.
+pub async fn run_scheduled_check(
+    state: &AppState,
+    config: &ScheduleConfig,
+) -> Option<IntegrationHealthOutput> {
+    let Some(_guard) = state.monitor.begin_run() else {
+        tracing::warn!("Previous scheduled health check still running, skipping");
+        return None;
+    };
+
+    let source = state.adapter_source.as_ref()?;
+    let adapters = match source.load().await {
+        Ok(adapters) => adapters,
+        Err(e) => {
+            tracing::warn!(error = %e, "Failed to resolve adapters for scheduled check");
+            return None;
+        }
+    };
+
+    let mut input = HealthCheckEngine::create_input(adapters, "scheduler".to_string());
+    input.options = config.options.clone();
+    input.environment = config.environment;
+
+    let inputs_hash = HealthCheckEngine::compute_inputs_hash(&input);
+    let output = state.engine.check(&input).await;
+
+    let signal = IntegrationHealthSignal::from_health_check(
+        inputs_hash,
+        &output,
+        input.request_id.to_string(),
+    );
+    if let Err(e) = state.telemetry.emit(signal).await {
+        tracing::warn!("Failed to emit telemetry: {}", e);
+    }
+
+    state.monitor.record(output.clone());
+    Some(output)
+}
+
+/// Start running scheduled checks every `config.interval`
+///
+/// Each run is spawned on its own task so the ticker keeps a fixed cadence;
+/// ticks that arrive during a run are skipped by [`run_scheduled_check`].
+pub fn spawn_scheduled_checks(state: Arc<AppState>, config: ScheduleConfig) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let config = Arc::new(config);
+        let mut ticker = tokio::time::interval(config.interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
+        loop {
+            ticker.tick().await;
+            let state = Arc::clone(&state);
+            let config = Arc::clone(&config);
+            tokio::spawn(async move {
+                run_scheduled_check(&state, &config).await;
+            });
+        }
+    })
+}
@@ -0,0 +1,124 @@
+//! Adapter lists sourced from a configuration provider
+//!
+//! Instead of a static file baked into the deployment, the adapter topology
+//! can live behind any `ConfigProvider` (a mounted ConfigMap directory, a
+//! Vault path, environment variables). The list is refreshed, parsed, and
+//! validated on every load, so a change in the source is picked up by the
+//! next probe without a redeploy.
+
+use crate::contracts::AdapterConfig;
+use llm_config_core::providers::{BundleProvider, ConfigProvider, ProviderError};
+use std::collections::HashSet;
+use std::path::Path;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Namespace the adapter list is read from by default
+pub const DEFAULT_NAMESPACE: &str = "integration_health";
+
+/// Key the adapter list is read from by default
+pub const DEFAULT_KEY: &str = "adapters";
+
+/// Errors resolving an adapter list
+#[derive(Error, Debug)]
+pub enum AdapterSourceError {
+    /// The provider could not be read
+    #[error("Adapter source unavailable: {0}")]
+    Provider(#[from] ProviderError),
+
+    /// The value is not a valid adapter list
+    #[error("Invalid adapter list: {0}")]
+    Invalid(String),
+}
+
+/// Where to load the adapter list from
+#[derive(Debug, Clone)]
+pub struct AdapterSource {
+    provider: Arc<dyn ConfigProvider>,
+    namespace: String,
+    key: String,
+}
+
+impl AdapterSource {
+    /// Read the list from `integration_health/adapters` in `provider`
+    pub fn new(provider: Arc<dyn ConfigProvider>) -> Self {
+        Self {
+            provider,
+            namespace: DEFAULT_NAMESPACE.to_string(),
+            key: DEFAULT_KEY.to_string(),
+        }
+    }
+
+    /// Read the list from a bundle file, or every bundle in a directory
+    ///
+    /// A directory suits a mounted ConfigMap, which is updated in place.
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, AdapterSourceError> {
+        let path = path.as_ref();
+        let provider = if path.is_dir() {
+            BundleProvider::from_dir(path)?
+        } else {
+            BundleProvider::from_file(path)?
+        };
+        Ok(Self::new(Arc::new(provider)))
+    }
+
+    /// Read the list from another namespace and key
+    pub fn with_location(mut self, namespace: impl Into<String>, key: impl Into<String>) -> Self {
+        self.namespace = namespace.into();
+        self.key = key.into();
+        self
+    }
+
+    /// Name of the backing provider
+    pub fn provider_name(&self) -> &str {
+        self.provider.name()
+    }
+
+    /// Refresh the provider and resolve the current adapter list
+    pub async fn load(&self) -> Result<Vec<AdapterConfig>, AdapterSourceError> {
+        self.provider.refresh().await?;
+        let value = self.provider.get(&self.namespace, &self.key).await?;
+        parse_adapters(value.typed())
+    }
+}
+
+/// Parse and validate an adapter list
+///
+/// String values (from providers that only hold strings) are parsed as JSON,
+/// then YAML. The list must be non-empty, with unique ids and non-empty
+/// endpoints.
+pub fn parse_adapters(value: serde_json::Value) -> Result<Vec<AdapterConfig>, AdapterSourceError> {
+    let value = match value {
+        serde_json::Value::String(text) => serde_json::from_str(&text)
+            .or_else(|_| serde_yaml::from_str(&text))
+            .map_err(|e| AdapterSourceError::Invalid(e.to_string()))?,
+        other => other,
+    };
+
+    let adapters: Vec<AdapterConfig> = serde_path_to_error::deserialize(value)
+        .map_err(|e| AdapterSourceError::Invalid(format!("{}: {}", e.path(), e.inner())))?;
+
+    if adapters.is_empty() {
+        return Err(AdapterSourceError::Invalid(
+            "at least one adapter must be listed".to_string(),
+        ));
+    }
+
+    let mut ids = HashSet::new();
+    for adapter in &adapters {
+        if !ids.insert(adapter.id.as_str()) {
+            return Err(AdapterSourceError::Invalid(format!(
+                "duplicate adapter id '{}'",
+                adapter.id
+            )));
+        }
+        if adapter.endpoint.trim().is_empty() {
+            return Err(AdapterSourceError::Invalid(format!(
+                "adapter '{}' has an empty endpoint",
+                adapter.id
+            )));
+        }
+    }
+
+    Ok(adapters)
+}
@@ -0,0 +1,53 @@
+//! Tests piping adapter lists to the CLI through `-`
+
+use integration_health::contracts::*;
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+fn run(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_integration-health"))
+        .args(args)
+        .env("RUST_LOG", "off")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn probed_adapter_ids(output: &Output) -> Vec<String> {
+    let result: IntegrationHealthOutput = serde_json::from_slice(&output.stdout)
+        .unwrap_or_else(|e| panic!("{}: {:?}", e, output));
+    result.adapter_results.into_iter().map(|r| r.adapter_id).collect()
+}
+
+fn adapters() -> Vec<AdapterConfig> {
+    let mut cache = AdapterPreset::redis("127.0.0.1", 1);
+    cache.id = "cache".to_string();
+    vec![cache]
+}
+
+#[test]
+fn test_probe_reads_json_adapters_from_stdin() {
+    let output = run(
+        &["probe", "--file", "-", "--environment", "development"],
+        &serde_json::to_string(&adapters()).unwrap(),
+    );
+    assert_eq!(probed_adapter_ids(&output), ["cache"]);
+}
+
+#[test]
+fn test_probe_reads_yaml_adapters_from_stdin() {
+    let output = run(
+        &["probe", "--file", "-", "--input-format", "yaml", "--environment", "development"],
+        &serde_yaml::to_string(&adapters()).unwrap(),
+    );
+    assert_eq!(probed_adapter_ids(&output), ["cache"]);
+}
@@ -7,7 +7,10 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use uuid::Uuid;
 
-use super::{SchemaValidationOutput, SchemaViolation, ViolationSeverity};
+use super::{
+    DecisionVerdict, IssueCounts, SchemaValidationOutput, SchemaViolation, VerdictThresholds,
+    ViolationSeverity,
+};
 
 /// Schema violation signal for ruvector-service
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -36,6 +39,10 @@ pub struct SchemaViolationSignal {
     /// Confidence score (0.0-1.0)
     pub confidence: f64,
 
+    /// Allow/block verdict derived from the violations
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verdict: Option<DecisionVerdict>,
+
     /// Constraints that were applied
     pub constraints_applied: Vec<String>,
 
@@ -83,6 +90,7 @@ impl SchemaViolationSignal {
             inputs_hash,
             outputs: SchemaViolationOutputs::from_output(output),
             confidence: output.confidence(),
+            verdict: Some(VerdictThresholds::default().verdict(Self::issue_counts(output))),
             constraints_applied: output.constraints_checked.clone(),
             execution_ref,
             timestamp: Utc::now(),
@@ -113,6 +121,7 @@ impl SchemaViolationSignal {
             inputs_hash,
             outputs,
             confidence: confidence.clamp(0.0, 1.0),
+            verdict: None,
             constraints_applied: Vec::new(),
             execution_ref,
             timestamp: Utc::now(),
@@ -122,6 +131,26 @@ impl SchemaViolationSignal {
         }
     }
 
+    /// Count violations and warnings by severity for the verdict
+    pub fn issue_counts(output: &SchemaValidationOutput) -> IssueCounts {
+        let mut counts = IssueCounts::default();
+        for finding in output.violations.iter().chain(&output.warnings) {
+            match finding.severity {
+                ViolationSeverity::Critical => counts.critical += 1,
+                ViolationSeverity::Error => counts.errors += 1,
+                ViolationSeverity::Warning => counts.warnings += 1,
+                ViolationSeverity::Info => {}
+            }
+        }
+        counts
+    }
+
+    /// Set the allow/block verdict
+    pub fn with_verdict(mut self, verdict: DecisionVerdict) -> Self {
+        self.verdict = Some(verdict);
+        self
+    }
+
     /// Add metadata
     pub fn with_metadata(mut self, key: impl Into<String>, value: serde_json::Value) -> Self {
         self.metadata.insert(key.into(), value);
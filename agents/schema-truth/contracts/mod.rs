@@ -4,8 +4,17 @@
 
 mod decision_event;
 mod schemas;
+#[path = "../../shared/environment.rs"]
+mod environment;
+#[path = "../../shared/verdict.rs"]
+mod verdict;
+#[path = "../../shared/severity.rs"]
+mod severity;
 
 pub use decision_event::*;
+pub use environment::{EnvironmentRef, ParseEnvironmentError};
+pub use verdict::{DecisionVerdict, IssueCounts, VerdictThresholds};
+pub use severity::Severity;
 pub use schemas::*;
 
 use chrono::{DateTime, Utc};
@@ -26,6 +35,10 @@ pub struct SchemaValidationInput {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parent_schema: Option<SchemaDefinition>,
 
+    /// Target environment; production promotes warnings to violations
+    #[serde(default)]
+    pub environment: EnvironmentRef,
+
     /// Validation context
     #[serde(default)]
     pub context: HashMap<String, String>,
@@ -205,11 +218,4 @@ impl SchemaViolation {
 }
 
 /// Severity levels for violations
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
-pub enum ViolationSeverity {
-    Info,
-    Warning,
-    Error,
-    Critical,
-}
+pub type ViolationSeverity = Severity;
@@ -8,6 +8,10 @@ use std::collections::HashMap;
 /// Schema definition - the source of truth for configuration structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SchemaDefinition {
+    /// Schema document format version; absent means the current format
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema_format_version: Option<String>,
+
     /// Schema identifier (namespace path)
     pub id: String,
 
@@ -37,6 +41,49 @@ pub struct SchemaDefinition {
     pub compatibility: Vec<CompatibilityConstraint>,
 }
 
+impl SchemaDefinition {
+    /// Current schema format version
+    pub const CURRENT_FORMAT_VERSION: &'static str = "1.0";
+
+    /// Oldest schema format version still read
+    pub const MIN_FORMAT_VERSION: &'static str = "0.9";
+
+    /// Check that a schema format version can be read
+    ///
+    /// Returns a warning for versions older than the current one, and an
+    /// error naming the supported range for versions outside it, including
+    /// formats newer than this build understands.
+    pub fn check_format_version(version: &str) -> Result<Option<String>, String> {
+        let parse = |v: &str| -> Option<(u64, u64)> {
+            let (major, minor) = v.trim().split_once('.').unwrap_or((v.trim(), "0"));
+            Some((major.parse().ok()?, minor.parse().ok()?))
+        };
+        let supported = format!(
+            "supported versions are {} through {}",
+            Self::MIN_FORMAT_VERSION,
+            Self::CURRENT_FORMAT_VERSION
+        );
+
+        let found = parse(version).ok_or_else(|| {
+            format!("Invalid schema format version '{}'; {}", version, supported)
+        })?;
+        let min = parse(Self::MIN_FORMAT_VERSION).expect("valid format version");
+        let current = parse(Self::CURRENT_FORMAT_VERSION).expect("valid format version");
+
+        if found > current || found < min {
+            Err(format!("Unsupported schema format version '{}'; {}", version, supported))
+        } else if found < current {
+            Ok(Some(format!(
+                "Schema format version '{}' is older than the current {} and should be upgraded",
+                version,
+                Self::CURRENT_FORMAT_VERSION
+            )))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
 /// Field definition within a schema
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FieldDefinition {
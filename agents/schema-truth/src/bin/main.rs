@@ -1,11 +1,17 @@
 //! Schema Truth Agent entry point
 //!
 //! Deterministic schema validation with schema_violation_signal emission.
+//!
+//! `--version --verbose` also prints the commit, build time and compiler.
 
-use clap::{Parser, Subcommand};
+use clap::{CommandFactory, Parser, Subcommand, ValueEnum};
+use clap_complete::Shell;
+use schema_truth::build_info::{build_info, verbose_version_requested};
 use schema_truth::contracts::*;
+use schema_truth::doctor;
 use schema_truth::engine::SchemaValidationEngine;
 use schema_truth::handler::{create_router, AppState};
+use std::io::{Read, Write};
 use std::net::SocketAddr;
 use std::sync::Arc;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
@@ -34,21 +40,88 @@ enum Commands {
 
     /// Validate a schema file
     Validate {
-        /// Path to schema file (JSON/YAML)
+        /// Path to schema file (JSON/YAML), or `-` for stdin
         #[arg(short, long)]
         file: String,
 
+        /// Schema format, overriding the file extension [default for stdin: json]
+        #[arg(long, value_enum)]
+        input_format: Option<InputFormat>,
+
         /// Output format
         #[arg(short, long, default_value = "json")]
         output: String,
+
+        /// Target environment (production applies stricter checks)
+        #[arg(long, default_value = "production")]
+        environment: EnvironmentRef,
     },
 
     /// Check a schema (quick, no telemetry)
     Check {
-        /// Path to schema file
+        /// Path to schema file, or `-` for stdin
         #[arg(short, long)]
         file: String,
+
+        /// Schema format, overriding the file extension [default for stdin: json]
+        #[arg(long, value_enum)]
+        input_format: Option<InputFormat>,
+
+        /// Target environment (production applies stricter checks)
+        #[arg(long, default_value = "production")]
+        environment: EnvironmentRef,
+    },
+
+    /// Check configuration, connectivity, and the latency budget
+    Doctor {
+        /// ruvector-service URL to check
+        #[arg(long, env = "RUVECTOR_SERVICE_URL")]
+        telemetry_url: Option<String>,
     },
+
+    /// Generate shell completion scripts
+    Completions {
+        /// Shell to generate completions for
+        #[arg(value_enum)]
+        shell: Shell,
+    },
+}
+
+/// Formats schema files are parsed from
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum InputFormat {
+    Json,
+    Yaml,
+}
+
+/// Read a schema from a file, or from stdin when the path is `-`
+///
+/// Without an explicit format, `.yaml` and `.yml` files are parsed as YAML
+/// and everything else, stdin included, as JSON.
+fn read_schema(file: &str, input_format: Option<InputFormat>) -> anyhow::Result<serde_json::Value> {
+    let content = if file == "-" {
+        let mut content = String::new();
+        std::io::stdin().read_to_string(&mut content)?;
+        content
+    } else {
+        std::fs::read_to_string(file)?
+    };
+    let format = input_format.unwrap_or(if file.ends_with(".yaml") || file.ends_with(".yml") {
+        InputFormat::Yaml
+    } else {
+        InputFormat::Json
+    });
+    Ok(match format {
+        InputFormat::Yaml => serde_yaml::from_str(&content)?,
+        InputFormat::Json => serde_json::from_str(&content)?,
+    })
+}
+
+/// Write the completion script for the given shell to a writer
+fn write_completions<W: Write>(shell: Shell, out: &mut W) {
+    let mut command = Cli::command();
+    let name = command.get_name().to_string();
+    clap_complete::generate(shell, &mut command, name, out);
 }
 
 #[tokio::main]
@@ -61,12 +134,18 @@ async fn main() -> anyhow::Result<()> {
         .with(tracing_subscriber::fmt::layer().json())
         .init();
 
+    if verbose_version_requested(std::env::args()) {
+        println!("{}", build_info().verbose_version("schema-truth"));
+        return Ok(());
+    }
+
     let cli = Cli::parse();
 
     match cli.command {
         Commands::Serve { port, host } => {
             let addr: SocketAddr = format!("{}:{}", host, port).parse()?;
             let state = Arc::new(AppState::new());
+            state.warmup().await;
             let router = create_router(state);
 
             tracing::info!(
@@ -83,17 +162,18 @@ async fn main() -> anyhow::Result<()> {
             axum::serve(listener, router).await?;
         }
 
-        Commands::Validate { file, output } => {
-            let content = std::fs::read_to_string(&file)?;
-            let schema: serde_json::Value = if file.ends_with(".yaml") || file.ends_with(".yml") {
-                serde_yaml::from_str(&content)?
-            } else {
-                serde_json::from_str(&content)?
-            };
+        Commands::Validate {
+            file,
+            input_format,
+            output,
+            environment,
+        } => {
+            let schema = read_schema(&file, input_format)?;
 
             let engine = SchemaValidationEngine::new();
-            let input = SchemaValidationEngine::create_input(schema, "cli".to_string())
+            let mut input = SchemaValidationEngine::create_input(schema, "cli".to_string())
                 .map_err(|e| anyhow::anyhow!("{}", e))?;
+            input.environment = environment;
             let result = engine.validate(&input).await;
 
             match output.as_str() {
@@ -123,17 +203,17 @@ async fn main() -> anyhow::Result<()> {
             }
         }
 
-        Commands::Check { file } => {
-            let content = std::fs::read_to_string(&file)?;
-            let schema: serde_json::Value = if file.ends_with(".yaml") || file.ends_with(".yml") {
-                serde_yaml::from_str(&content)?
-            } else {
-                serde_json::from_str(&content)?
-            };
+        Commands::Check {
+            file,
+            input_format,
+            environment,
+        } => {
+            let schema = read_schema(&file, input_format)?;
 
             let engine = SchemaValidationEngine::new();
-            let input = SchemaValidationEngine::create_input(schema, "cli".to_string())
+            let mut input = SchemaValidationEngine::create_input(schema, "cli".to_string())
                 .map_err(|e| anyhow::anyhow!("{}", e))?;
+            input.environment = environment;
             let result = engine.validate(&input).await;
 
             println!(
@@ -151,7 +231,37 @@ async fn main() -> anyhow::Result<()> {
                 std::process::exit(1);
             }
         }
+
+        Commands::Doctor { telemetry_url } => {
+            let report = doctor::run(telemetry_url.as_deref()).await;
+            print!("{}", report.render());
+
+            if !report.passed() {
+                std::process::exit(1);
+            }
+        }
+
+        Commands::Completions { shell } => {
+            write_completions(shell, &mut std::io::stdout());
+        }
     }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bash_completions_include_subcommands() {
+        let mut buffer = Vec::new();
+        write_completions(Shell::Bash, &mut buffer);
+        let script = String::from_utf8(buffer).unwrap();
+
+        assert!(script.contains("schema-truth"));
+        for subcommand in ["serve", "validate", "check", "doctor", "completions"] {
+            assert!(script.contains(subcommand), "missing subcommand {}", subcommand);
+        }
+    }
+}
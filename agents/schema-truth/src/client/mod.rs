@@ -3,6 +3,7 @@
 //! Used by other services to validate schemas.
 
 use crate::contracts::*;
+use crate::http_pool::PoolConfig;
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
 
@@ -18,7 +19,7 @@ impl SchemaTruthClient {
     pub fn new(base_url: impl Into<String>) -> Self {
         Self {
             base_url: base_url.into(),
-            client: reqwest::Client::new(),
+            client: PoolConfig::from_env().client(),
             timeout: Duration::from_millis(1500), // Match MAX_LATENCY_MS
         }
     }
@@ -29,6 +30,12 @@ impl SchemaTruthClient {
         self
     }
 
+    /// Use a client built with specific pool settings
+    pub fn with_pool(mut self, pool: PoolConfig) -> Self {
+        self.client = pool.client();
+        self
+    }
+
     /// Validate a schema
     pub async fn validate(
         &self,
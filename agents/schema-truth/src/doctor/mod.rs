@@ -0,0 +1,113 @@
+//! Self-test for the Schema Truth Agent
+//!
+//! Backs the `doctor` subcommand: checks that environment variables are sane,
+//! that ruvector-service is reachable, that the validation rules load, and
+//! that a trivial validation fits in the latency budget.
+
+#[path = "../../../shared/doctor.rs"]
+mod checks;
+
+pub use checks::*;
+
+use crate::engine::{SchemaValidationEngine, MAX_LATENCY_MS};
+use std::time::{Duration, Instant};
+
+/// ruvector-service URL used when `RUVECTOR_SERVICE_URL` is not set
+pub const DEFAULT_TELEMETRY_URL: &str = "http://localhost:8080";
+
+/// How long to wait for the telemetry endpoint to answer
+pub const ENDPOINT_TIMEOUT: Duration = Duration::from_millis(MAX_LATENCY_MS);
+
+/// Run every check
+///
+/// `telemetry_url` is the configured `RUVECTOR_SERVICE_URL`, if any.
+pub async fn run(telemetry_url: Option<&str>) -> DoctorReport {
+    let mut report = DoctorReport::default();
+
+    let url_check = check_url_var("RUVECTOR_SERVICE_URL", telemetry_url, DEFAULT_TELEMETRY_URL);
+    let url_ok = url_check.passed;
+    report.push(url_check);
+    report.push(check_parse_var::<u16>(
+        "PORT",
+        std::env::var("PORT").ok().as_deref(),
+    ));
+
+    if url_ok {
+        let url = telemetry_url.unwrap_or(DEFAULT_TELEMETRY_URL);
+        report.push(check_endpoint("telemetry endpoint", url, ENDPOINT_TIMEOUT).await);
+    } else {
+        report.push(DoctorCheck::fail("telemetry endpoint", "no valid URL configured"));
+    }
+
+    let engine = SchemaValidationEngine::new();
+    let input = match SchemaValidationEngine::create_input(self_test_schema(), "doctor".to_string()) {
+        Ok(input) => input,
+        Err(e) => {
+            report.push(DoctorCheck::fail("rules", e));
+            return report;
+        }
+    };
+
+    let start = Instant::now();
+    let output = engine.validate(&input).await;
+    let elapsed = start.elapsed();
+
+    if output.rules_applied.is_empty() {
+        report.push(DoctorCheck::fail("rules", "no rules applied to the self-test schema"));
+    } else {
+        report.push(DoctorCheck::pass(
+            "rules",
+            format!("{} rules applied", output.rules_applied.len()),
+        ));
+    }
+    report.push(check_latency("latency budget", elapsed, MAX_LATENCY_MS));
+
+    report
+}
+
+/// Minimal schema validated by the self-test
+fn self_test_schema() -> serde_json::Value {
+    serde_json::json!({
+        "id": "doctor/self-test",
+        "version": "1.0.0",
+        "name": "Doctor Self-Test",
+        "fields": {
+            "enabled": {
+                "field_type": "boolean",
+                "required": false,
+                "description": "Whether the feature is enabled"
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_doctor_fails_when_telemetry_unreachable() {
+        // Nothing listens on port 1
+        let report = run(Some("http://127.0.0.1:1")).await;
+
+        assert!(!report.passed());
+        let telemetry = report.check("telemetry endpoint").unwrap();
+        assert!(!telemetry.passed);
+        assert!(telemetry.detail.contains("unreachable"));
+
+        // The local checks still pass
+        assert!(report.check("env RUVECTOR_SERVICE_URL").unwrap().passed);
+        assert!(report.check("rules").unwrap().passed);
+        assert!(report.check("latency budget").unwrap().passed);
+        assert!(report.render().contains("[FAIL] telemetry endpoint"));
+    }
+
+    #[tokio::test]
+    async fn test_doctor_rejects_invalid_telemetry_url() {
+        let report = run(Some("ruvector:8080")).await;
+
+        assert!(!report.passed());
+        assert!(!report.check("env RUVECTOR_SERVICE_URL").unwrap().passed);
+        assert!(!report.check("telemetry endpoint").unwrap().passed);
+    }
+}
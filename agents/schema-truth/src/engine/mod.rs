@@ -2,13 +2,17 @@
 //!
 //! Deterministic validation of schema definitions.
 
+mod registry;
 mod rules;
 
+pub use registry::*;
 pub use rules::*;
 
+use crate::clock::{system_clock, Clock};
 use crate::contracts::*;
 use sha2::{Digest, Sha256};
-use std::time::Instant;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 use uuid::Uuid;
 
 /// Performance budget constants
@@ -18,6 +22,11 @@ pub const MAX_TOKENS: usize = 800;
 /// Schema validation engine
 pub struct SchemaValidationEngine {
     rules: Vec<Box<dyn SchemaRule>>,
+    /// Regexes compiled by the rules, shared so warmup can prime them
+    patterns: Arc<RegexCache>,
+    /// Schemas cross-schema references are resolved against
+    registry: Option<Arc<SchemaRegistry>>,
+    clock: Arc<dyn Clock>,
 }
 
 impl Default for SchemaValidationEngine {
@@ -29,22 +38,90 @@ impl Default for SchemaValidationEngine {
 impl SchemaValidationEngine {
     /// Create new engine with default rules
     pub fn new() -> Self {
+        let patterns = Arc::new(RegexCache::default());
         Self {
             rules: vec![
                 Box::new(StructureRule),
                 Box::new(FieldTypeRule),
-                Box::new(ConstraintRule),
+                Box::new(ConstraintRule::new(patterns.clone())),
                 Box::new(RequiredFieldRule),
                 Box::new(DeprecationRule),
-                Box::new(NamingConventionRule),
-                Box::new(VersionRule),
+                Box::new(NamingConventionRule::new(patterns.clone())),
+                Box::new(VersionRule::new(patterns.clone())),
             ],
+            patterns,
+            registry: None,
+            clock: system_clock(),
         }
     }
 
+    /// Read time from `clock`, for `completed_at`, `duration_ms` and the latency budget
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Resolve cross-schema references against a registry
+    ///
+    /// Adds the `schema_reference` rule, which flags compatibility
+    /// constraints naming schemas or fields the registry does not contain.
+    pub fn with_registry(mut self, registry: SchemaRegistry) -> Self {
+        let registry = Arc::new(registry);
+        self.rules.retain(|rule| rule.id() != "schema_reference");
+        self.rules
+            .push(Box::new(SchemaReferenceRule::new(registry.clone())));
+        self.registry = Some(registry);
+        self
+    }
+
+    /// The registry references are resolved against, if any
+    pub fn registry(&self) -> Option<&SchemaRegistry> {
+        self.registry.as_deref()
+    }
+
+    /// The registered rules, in evaluation order
+    pub fn rules(&self) -> &[Box<dyn SchemaRule>] {
+        &self.rules
+    }
+
+    /// Regexes compiled so far by this engine's rules
+    pub fn patterns(&self) -> &RegexCache {
+        &self.patterns
+    }
+
+    /// Prepare the engine so the first real request meets the latency budget
+    ///
+    /// Compiles the built-in regexes and runs a trivial validation to touch
+    /// every rule. Returns how long warmup took.
+    pub async fn warmup(&self) -> Duration {
+        let start = Instant::now();
+        self.patterns.prime(BUILTIN_PATTERNS);
+
+        let schema = serde_json::json!({
+            "id": "warmup/self-test",
+            "version": "1.0.0",
+            "name": "Warmup",
+            "fields": {
+                "name": {
+                    "field_type": "string",
+                    "required": false,
+                    "constraints": [{"type": "pattern", "regex": "^[a-z]+$"}]
+                }
+            }
+        });
+        match Self::create_input(schema, "warmup".to_string()) {
+            Ok(input) => {
+                self.validate(&input).await;
+            }
+            Err(e) => tracing::warn!(error = %e, "Warmup schema failed to parse"),
+        }
+
+        start.elapsed()
+    }
+
     /// Validate a schema definition
     pub async fn validate(&self, input: &SchemaValidationInput) -> SchemaValidationOutput {
-        let start = Instant::now();
+        let start = self.clock.instant();
         let request_id = input.request_id;
 
         let mut violations = Vec::new();
@@ -60,11 +137,20 @@ impl SchemaValidationEngine {
 
             rules_applied.push(rule.id().to_string());
 
-            let findings = rule.evaluate(&input.schema, input.parent_schema.as_ref());
+            let mut findings = rule.evaluate(&input.schema, input.parent_schema.as_ref());
+            // Rules walk fields in hash order; sort so output does not depend on it
+            findings.sort_by(|a, b| a.path.cmp(&b.path));
 
-            for finding in findings {
+            for mut finding in findings {
                 constraints_checked.push(format!("{}:{}", rule.id(), finding.code));
 
+                // Production is strict: warnings block like errors
+                if input.environment.is_production()
+                    && finding.severity == ViolationSeverity::Warning
+                {
+                    finding.severity = ViolationSeverity::Error;
+                }
+
                 match finding.severity {
                     ViolationSeverity::Error | ViolationSeverity::Critical => {
                         violations.push(finding);
@@ -76,13 +162,13 @@ impl SchemaValidationEngine {
             }
 
             // Check latency budget
-            if start.elapsed().as_millis() as u64 > MAX_LATENCY_MS {
+            if self.clock.elapsed(start).as_millis() as u64 > MAX_LATENCY_MS {
                 tracing::warn!("Validation exceeded latency budget, stopping early");
                 break;
             }
         }
 
-        let duration_ms = start.elapsed().as_millis() as u64;
+        let duration_ms = self.clock.elapsed(start).as_millis() as u64;
         let coverage = if self.rules.is_empty() {
             0.0
         } else {
@@ -97,7 +183,7 @@ impl SchemaValidationEngine {
             rules_applied,
             constraints_checked,
             coverage,
-            completed_at: chrono::Utc::now(),
+            completed_at: self.clock.now(),
             duration_ms,
         }
     }
@@ -107,24 +193,34 @@ impl SchemaValidationEngine {
         let mut hasher = Sha256::new();
         hasher.update(input.schema.id.as_bytes());
         hasher.update(input.schema.version.as_bytes());
-        if let Ok(json) = serde_json::to_string(&input.schema.fields) {
+        // Through `Value`, whose maps are sorted, so field order does not matter
+        if let Ok(json) = serde_json::to_value(&input.schema.fields).map(|v| v.to_string()) {
             hasher.update(json.as_bytes());
         }
+        hasher.update(input.environment.to_string().as_bytes());
         hex::encode(hasher.finalize())
     }
 
     /// Create validation input from schema JSON
+    ///
+    /// Fails for schemas in a format version outside the supported range.
     pub fn create_input(
         schema_json: serde_json::Value,
         requested_by: String,
     ) -> Result<SchemaValidationInput, String> {
         let schema: SchemaDefinition = serde_json::from_value(schema_json)
             .map_err(|e| format!("Invalid schema JSON: {}", e))?;
+        if let Some(version) = &schema.schema_format_version {
+            if let Some(warning) = SchemaDefinition::check_format_version(version)? {
+                tracing::warn!(schema_id = %schema.id, "{}", warning);
+            }
+        }
 
         Ok(SchemaValidationInput {
             request_id: Uuid::new_v4(),
             schema,
             parent_schema: None,
+            environment: EnvironmentRef::default(),
             context: std::collections::HashMap::new(),
             requested_at: chrono::Utc::now(),
             requested_by,
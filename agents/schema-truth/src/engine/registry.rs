@@ -0,0 +1,88 @@
+//! Registry of known schemas
+//!
+//! Schemas reference each other through their compatibility constraints. A
+//! registry holds the schemas those references are resolved against, so the
+//! engine can tell a satisfiable reference from a dangling one.
+
+use crate::contracts::SchemaDefinition;
+use std::collections::HashMap;
+
+/// Schemas available for resolving cross-schema references, by id
+#[derive(Debug, Clone, Default)]
+pub struct SchemaRegistry {
+    schemas: HashMap<String, Vec<SchemaDefinition>>,
+}
+
+impl SchemaRegistry {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a schema; several versions of the same id may be registered
+    pub fn register(&mut self, schema: SchemaDefinition) {
+        self.schemas.entry(schema.id.clone()).or_default().push(schema);
+    }
+
+    /// Builder form of [`register`](Self::register)
+    pub fn with_schema(mut self, schema: SchemaDefinition) -> Self {
+        self.register(schema);
+        self
+    }
+
+    /// Whether any version of a schema is registered
+    pub fn contains(&self, id: &str) -> bool {
+        self.schemas.contains_key(id)
+    }
+
+    /// All registered versions of a schema
+    pub fn versions(&self, id: &str) -> &[SchemaDefinition] {
+        self.schemas.get(id).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Newest registered version of a schema at or above `min_version`
+    pub fn resolve(&self, id: &str, min_version: Option<&str>) -> Option<&SchemaDefinition> {
+        self.versions(id)
+            .iter()
+            .filter(|schema| min_version.is_none_or(|min| version_at_least(&schema.version, min)))
+            .max_by(|a, b| version_key(&a.version).cmp(&version_key(&b.version)))
+    }
+
+    /// Number of registered schema ids
+    pub fn len(&self) -> usize {
+        self.schemas.len()
+    }
+
+    /// Whether no schemas are registered
+    pub fn is_empty(&self) -> bool {
+        self.schemas.is_empty()
+    }
+}
+
+impl FromIterator<SchemaDefinition> for SchemaRegistry {
+    fn from_iter<I: IntoIterator<Item = SchemaDefinition>>(iter: I) -> Self {
+        let mut registry = Self::new();
+        iter.into_iter().for_each(|schema| registry.register(schema));
+        registry
+    }
+}
+
+/// Numeric `major.minor.patch` components of a version, ignoring any suffix
+fn version_key(version: &str) -> Vec<u64> {
+    version
+        .split(['-', '+'])
+        .next()
+        .unwrap_or_default()
+        .split('.')
+        .map(|part| part.parse().unwrap_or(0))
+        .collect()
+}
+
+/// Whether `version` is the same as or newer than `min`
+pub fn version_at_least(version: &str, min: &str) -> bool {
+    let (mut version, mut min) = (version_key(version), version_key(min));
+    let len = version.len().max(min.len());
+    version.resize(len, 0);
+    min.resize(len, 0);
+    version >= min
+}
@@ -3,8 +3,56 @@
 //! Deterministic rules for validating schema structure and content.
 
 use crate::contracts::*;
-use crate::engine::SchemaRule;
+use crate::engine::{version_at_least, SchemaRegistry, SchemaRule};
 use regex::Regex;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// Field names must be snake_case
+pub const SNAKE_CASE_PATTERN: &str = r"^[a-z][a-z0-9_]*$";
+
+/// Schema versions should follow semver
+pub const SEMVER_PATTERN: &str = r"^\d+\.\d+\.\d+(-[a-zA-Z0-9.]+)?(\+[a-zA-Z0-9.]+)?$";
+
+/// Patterns the built-in rules match against every schema
+pub const BUILTIN_PATTERNS: &[&str] = &[SNAKE_CASE_PATTERN, SEMVER_PATTERN];
+
+/// Compiled regexes shared by the rules of one engine
+///
+/// Patterns are compiled on first use and kept, so only the first schema to
+/// use a pattern pays for compiling it. Invalid patterns are cached too.
+#[derive(Debug, Default)]
+pub struct RegexCache {
+    compiled: Mutex<HashMap<String, Option<Regex>>>,
+}
+
+impl RegexCache {
+    /// Get the compiled regex for a pattern, or `None` if it is invalid
+    pub fn get(&self, pattern: &str) -> Option<Regex> {
+        let mut compiled = self.compiled.lock().unwrap_or_else(|e| e.into_inner());
+        compiled
+            .entry(pattern.to_string())
+            .or_insert_with(|| Regex::new(pattern).ok())
+            .clone()
+    }
+
+    /// Compile a set of patterns ahead of use
+    pub fn prime(&self, patterns: &[&str]) {
+        for pattern in patterns {
+            self.get(pattern);
+        }
+    }
+
+    /// Number of cached patterns
+    pub fn len(&self) -> usize {
+        self.compiled.lock().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    /// Whether no patterns have been compiled yet
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
 
 /// Validates schema structure
 pub struct StructureRule;
@@ -29,6 +77,32 @@ impl SchemaRule for StructureRule {
     ) -> Vec<SchemaViolation> {
         let mut violations = Vec::new();
 
+        // Schema format must be one this build reads
+        if let Some(version) = &schema.schema_format_version {
+            match SchemaDefinition::check_format_version(version) {
+                Ok(None) => {}
+                Ok(Some(warning)) => {
+                    // Informational, so production does not promote it to an error
+                    let mut finding = SchemaViolation::warning("SCHEMA_FORMAT_OUTDATED", warning)
+                        .with_path("schema_format_version");
+                    finding.severity = ViolationSeverity::Info;
+                    violations.push(finding);
+                }
+                Err(message) => violations.push(
+                    SchemaViolation::error("SCHEMA_FORMAT_UNSUPPORTED", message)
+                        .with_path("schema_format_version")
+                        .with_expected_actual(
+                            format!(
+                                "{} through {}",
+                                SchemaDefinition::MIN_FORMAT_VERSION,
+                                SchemaDefinition::CURRENT_FORMAT_VERSION
+                            ),
+                            version,
+                        ),
+                ),
+            }
+        }
+
         // Schema must have an ID
         if schema.id.is_empty() {
             violations.push(
@@ -120,7 +194,17 @@ impl SchemaRule for FieldTypeRule {
 }
 
 /// Validates field constraints
-pub struct ConstraintRule;
+#[derive(Default)]
+pub struct ConstraintRule {
+    patterns: Arc<RegexCache>,
+}
+
+impl ConstraintRule {
+    /// Create the rule compiling patterns into a shared cache
+    pub fn new(patterns: Arc<RegexCache>) -> Self {
+        Self { patterns }
+    }
+}
 
 impl SchemaRule for ConstraintRule {
     fn id(&self) -> &str {
@@ -147,7 +231,7 @@ impl SchemaRule for ConstraintRule {
                 match constraint {
                     FieldConstraint::Pattern { regex, .. } => {
                         // Validate regex is compilable
-                        if Regex::new(regex).is_err() {
+                        if self.patterns.get(regex).is_none() {
                             violations.push(
                                 SchemaViolation::error(
                                     "INVALID_REGEX",
@@ -323,7 +407,17 @@ impl SchemaRule for DeprecationRule {
 }
 
 /// Validates naming conventions
-pub struct NamingConventionRule;
+#[derive(Default)]
+pub struct NamingConventionRule {
+    patterns: Arc<RegexCache>,
+}
+
+impl NamingConventionRule {
+    /// Create the rule compiling patterns into a shared cache
+    pub fn new(patterns: Arc<RegexCache>) -> Self {
+        Self { patterns }
+    }
+}
 
 impl SchemaRule for NamingConventionRule {
     fn id(&self) -> &str {
@@ -344,7 +438,10 @@ impl SchemaRule for NamingConventionRule {
         _parent: Option<&SchemaDefinition>,
     ) -> Vec<SchemaViolation> {
         let mut violations = Vec::new();
-        let snake_case = Regex::new(r"^[a-z][a-z0-9_]*$").unwrap();
+        let snake_case = self
+            .patterns
+            .get(SNAKE_CASE_PATTERN)
+            .expect("Built-in pattern is valid");
 
         for field_name in schema.fields.keys() {
             if !snake_case.is_match(field_name) {
@@ -373,7 +470,17 @@ impl SchemaRule for NamingConventionRule {
 }
 
 /// Validates version format
-pub struct VersionRule;
+#[derive(Default)]
+pub struct VersionRule {
+    patterns: Arc<RegexCache>,
+}
+
+impl VersionRule {
+    /// Create the rule compiling patterns into a shared cache
+    pub fn new(patterns: Arc<RegexCache>) -> Self {
+        Self { patterns }
+    }
+}
 
 impl SchemaRule for VersionRule {
     fn id(&self) -> &str {
@@ -394,7 +501,10 @@ impl SchemaRule for VersionRule {
         _parent: Option<&SchemaDefinition>,
     ) -> Vec<SchemaViolation> {
         let mut violations = Vec::new();
-        let semver = Regex::new(r"^\d+\.\d+\.\d+(-[a-zA-Z0-9.]+)?(\+[a-zA-Z0-9.]+)?$").unwrap();
+        let semver = self
+            .patterns
+            .get(SEMVER_PATTERN)
+            .expect("Built-in pattern is valid");
 
         if schema.version.is_empty() {
             violations.push(
@@ -414,13 +524,136 @@ impl SchemaRule for VersionRule {
     }
 }
 
+/// Validates that schemas referenced by compatibility constraints exist
+///
+/// A reference resolves against the registry, or against the schema itself
+/// when it names its own id.
+pub struct SchemaReferenceRule {
+    registry: Arc<SchemaRegistry>,
+}
+
+impl SchemaReferenceRule {
+    /// Create the rule resolving references against a registry
+    pub fn new(registry: Arc<SchemaRegistry>) -> Self {
+        Self { registry }
+    }
+
+    fn resolve<'a>(
+        &'a self,
+        schema: &'a SchemaDefinition,
+        id: &str,
+        min_version: Option<&str>,
+    ) -> Option<&'a SchemaDefinition> {
+        if schema.id == id && min_version.is_none_or(|min| version_at_least(&schema.version, min)) {
+            return Some(schema);
+        }
+        self.registry.resolve(id, min_version)
+    }
+}
+
+impl SchemaRule for SchemaReferenceRule {
+    fn id(&self) -> &str {
+        "schema_reference"
+    }
+
+    fn name(&self) -> &str {
+        "Schema Reference Validation"
+    }
+
+    fn applies_to(&self, schema: &SchemaDefinition) -> bool {
+        !schema.compatibility.is_empty()
+    }
+
+    fn evaluate(
+        &self,
+        schema: &SchemaDefinition,
+        _parent: Option<&SchemaDefinition>,
+    ) -> Vec<SchemaViolation> {
+        let mut violations = Vec::new();
+
+        for (index, constraint) in schema.compatibility.iter().enumerate() {
+            let path = format!("compatibility[{}]", index);
+            match constraint {
+                CompatibilityConstraint::RequiresSchema {
+                    schema_id,
+                    min_version,
+                } => {
+                    if self.resolve(schema, schema_id, min_version.as_deref()).is_some() {
+                        continue;
+                    }
+                    let violation = match min_version {
+                        Some(min) if self.registry.contains(schema_id) => SchemaViolation::error(
+                            "SCHEMA_REF_VERSION_UNSATISFIED",
+                            format!("No registered version of '{}' is at least {}", schema_id, min),
+                        )
+                        .with_expected_actual(
+                            format!(">= {}", min),
+                            self.registry
+                                .versions(schema_id)
+                                .iter()
+                                .map(|s| s.version.as_str())
+                                .collect::<Vec<_>>()
+                                .join(", "),
+                        ),
+                        _ => SchemaViolation::error(
+                            "DANGLING_SCHEMA_REF",
+                            format!("Referenced schema '{}' is not registered", schema_id),
+                        ),
+                    };
+                    violations.push(violation.with_path(path));
+                }
+                CompatibilityConstraint::FieldFormat {
+                    field,
+                    target_schema,
+                    target_field,
+                } => {
+                    if !schema.fields.contains_key(field) {
+                        violations.push(
+                            SchemaViolation::error(
+                                "UNKNOWN_REF_FIELD",
+                                format!("Field '{}' is not defined in this schema", field),
+                            )
+                            .with_path(path.clone()),
+                        );
+                    }
+                    match self.resolve(schema, target_schema, None) {
+                        None => violations.push(
+                            SchemaViolation::error(
+                                "DANGLING_SCHEMA_REF",
+                                format!("Referenced schema '{}' is not registered", target_schema),
+                            )
+                            .with_path(path),
+                        ),
+                        Some(target) if !target.fields.contains_key(target_field) => violations
+                            .push(
+                                SchemaViolation::error(
+                                    "DANGLING_FIELD_REF",
+                                    format!(
+                                        "Schema '{}' has no field '{}'",
+                                        target_schema, target_field
+                                    ),
+                                )
+                                .with_path(path),
+                            ),
+                        Some(_) => {}
+                    }
+                }
+                CompatibilityConstraint::ProtocolVersion { .. }
+                | CompatibilityConstraint::Custom { .. } => {}
+            }
+        }
+
+        violations
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::collections::HashMap;
 
     fn create_test_schema() -> SchemaDefinition {
         SchemaDefinition {
+            schema_format_version: None,
             id: "test/config".to_string(),
             version: "1.0.0".to_string(),
             name: "Test Config".to_string(),
@@ -444,7 +677,7 @@ mod tests {
 
     #[test]
     fn test_version_rule_invalid_semver() {
-        let rule = VersionRule;
+        let rule = VersionRule::default();
         let mut schema = create_test_schema();
         schema.version = "invalid".to_string();
 
@@ -454,7 +687,7 @@ mod tests {
 
     #[test]
     fn test_constraint_rule_invalid_range() {
-        let rule = ConstraintRule;
+        let rule = ConstraintRule::default();
         let mut schema = create_test_schema();
         schema.fields.insert(
             "count".to_string(),
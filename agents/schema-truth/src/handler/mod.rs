@@ -12,16 +12,34 @@ use axum::{
 };
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use uuid::Uuid;
 
 use crate::contracts::*;
+use crate::health::HealthSummary;
 use crate::engine::SchemaValidationEngine;
 use crate::telemetry::TelemetryEmitter;
 
+#[path = "../../../shared/capabilities.rs"]
+pub mod capabilities;
+#[path = "../../../shared/request_shape.rs"]
+pub mod request_shape;
+
+use capabilities::{Capabilities, RuleCapability};
+use request_shape::{CheckedJson, RequestShapeError};
+
+/// Schemas validated at once by the batch endpoint
+pub const BATCH_CONCURRENCY: usize = 8;
+
+/// Most schemas accepted in one batch request
+pub const MAX_BATCH_SCHEMAS: usize = 100;
+
 /// Application state
 pub struct AppState {
     pub engine: SchemaValidationEngine,
     pub telemetry: TelemetryEmitter,
+    /// Start time for uptime calculation
+    pub start_time: Instant,
 }
 
 impl AppState {
@@ -29,8 +47,62 @@ impl AppState {
         Self {
             engine: SchemaValidationEngine::new(),
             telemetry: TelemetryEmitter::new(),
+            start_time: Instant::now(),
         }
     }
+
+    /// Warm the engine and the telemetry connection before serving
+    ///
+    /// Failing to reach ruvector-service is logged and does not fail warmup.
+    pub async fn warmup(&self) -> Duration {
+        let start = Instant::now();
+        let engine = self.engine.warmup().await;
+        if let Err(e) = self.telemetry.prime().await {
+            tracing::warn!(error = %e, "Failed to prime telemetry connection");
+        }
+
+        let elapsed = start.elapsed();
+        tracing::info!(
+            duration_ms = elapsed.as_millis() as u64,
+            engine_ms = engine.as_millis() as u64,
+            "Warmup complete"
+        );
+        elapsed
+    }
+}
+
+impl AppState {
+    /// Describe the registered rules and supported formats
+    pub fn capabilities(&self) -> Capabilities {
+        let mut capabilities = Capabilities::new(
+            SchemaViolationSignal::AGENT_ID,
+            SchemaViolationSignal::AGENT_VERSION,
+        );
+        capabilities.rules = self
+            .engine
+            .rules()
+            .iter()
+            .map(|rule| RuleCapability {
+                id: rule.id().to_string(),
+                name: rule.name().to_string(),
+                category: None,
+            })
+            .collect();
+        capabilities.output_formats = vec!["json".to_string(), "text".to_string()];
+        capabilities
+    }
+
+    /// Summarize uptime, build, registrations and telemetry for `/health`
+    pub fn health(&self) -> HealthSummary {
+        let mut summary = HealthSummary::new(
+            SchemaViolationSignal::AGENT_ID,
+            SchemaViolationSignal::AGENT_VERSION,
+            self.start_time,
+        )
+        .with_telemetry(true, self.telemetry.last_emission());
+        summary.rules = self.engine.rules().len();
+        summary
+    }
 }
 
 impl Default for AppState {
@@ -43,8 +115,10 @@ impl Default for AppState {
 pub fn create_router(state: Arc<AppState>) -> Router {
     Router::new()
         .route("/health", get(health_check))
+        .route("/capabilities", get(capabilities))
         .route("/api/v1/schema/validate", post(validate_schema))
         .route("/api/v1/schema/check", post(check_schema))
+        .route("/api/v1/schema/validate/batch", post(validate_schema_batch))
         // Instrumented execution endpoint (requires X-Parent-Span-Id header)
         .route(
             "/api/v1/execution/schema/validate",
@@ -54,21 +128,25 @@ pub fn create_router(state: Arc<AppState>) -> Router {
 }
 
 /// Health check endpoint
-async fn health_check() -> impl IntoResponse {
+async fn health_check(State(state): State<Arc<AppState>>) -> Json<HealthResponse> {
     Json(HealthResponse {
         status: "healthy".to_string(),
-        agent_id: SchemaViolationSignal::AGENT_ID.to_string(),
-        agent_version: SchemaViolationSignal::AGENT_VERSION.to_string(),
+        summary: state.health(),
     })
 }
 
+/// Capabilities endpoint
+async fn capabilities(State(state): State<Arc<AppState>>) -> Json<Capabilities> {
+    Json(state.capabilities())
+}
+
 /// Validate schema endpoint
 async fn validate_schema(
     State(state): State<Arc<AppState>>,
-    Json(request): Json<ValidateSchemaRequest>,
+    CheckedJson(request): CheckedJson<ValidateSchemaRequest>,
 ) -> Result<Json<ApiResponse<SchemaValidationOutput>>, (StatusCode, Json<ApiError>)> {
     // Create input
-    let input = match SchemaValidationEngine::create_input(
+    let mut input = match SchemaValidationEngine::create_input(
         request.schema,
         request.requested_by.unwrap_or_else(|| "anonymous".to_string()),
     ) {
@@ -80,10 +158,12 @@ async fn validate_schema(
                     error: "InvalidInput".to_string(),
                     message: e,
                     request_id: None,
+                    field: None,
                 }),
             ));
         }
     };
+    input.environment = request.environment.unwrap_or_default();
 
     let request_id = input.request_id;
     let inputs_hash = SchemaValidationEngine::compute_inputs_hash(&input);
@@ -108,12 +188,117 @@ async fn validate_schema(
     }))
 }
 
+/// Batch schema validation endpoint
+///
+/// Validates up to [`MAX_BATCH_SCHEMAS`] schemas, [`BATCH_CONCURRENCY`] at a
+/// time, and emits telemetry for each. A schema that cannot be parsed fails
+/// only its own item. Results keep the order of the request.
+async fn validate_schema_batch(
+    State(state): State<Arc<AppState>>,
+    CheckedJson(request): CheckedJson<BatchValidateRequest>,
+) -> Result<Json<ApiResponse<BatchValidateResponse>>, (StatusCode, Json<ApiError>)> {
+    if request.schemas.is_empty() || request.schemas.len() > MAX_BATCH_SCHEMAS {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiError {
+                error: "InvalidInput".to_string(),
+                message: format!(
+                    "A batch must hold between 1 and {} schemas, got {}",
+                    MAX_BATCH_SCHEMAS,
+                    request.schemas.len()
+                ),
+                request_id: None,
+                field: Some("schemas".to_string()),
+            }),
+        ));
+    }
+
+    let batch_id = Uuid::new_v4();
+    let requested_by = request.requested_by.unwrap_or_else(|| "anonymous".to_string());
+    let environment = request.environment.unwrap_or_default();
+    let permits = Arc::new(tokio::sync::Semaphore::new(BATCH_CONCURRENCY));
+
+    let tasks: Vec<_> = request
+        .schemas
+        .into_iter()
+        .enumerate()
+        .map(|(index, schema)| {
+            let state = Arc::clone(&state);
+            let permits = Arc::clone(&permits);
+            let requested_by = requested_by.clone();
+            tokio::spawn(async move {
+                let _permit = permits.acquire_owned().await;
+                validate_batch_item(&state, index, schema, requested_by, environment).await
+            })
+        })
+        .collect();
+
+    let mut results = Vec::with_capacity(tasks.len());
+    for (index, task) in tasks.into_iter().enumerate() {
+        results.push(task.await.unwrap_or_else(|e| BatchItemResult {
+            index,
+            schema_id: None,
+            output: None,
+            error: Some(format!("Validation task failed: {}", e)),
+        }));
+    }
+
+    let summary = BatchSummary::of(&results);
+    Ok(Json(ApiResponse {
+        success: summary.valid == summary.total,
+        data: BatchValidateResponse { results, summary },
+        request_id: batch_id,
+    }))
+}
+
+/// Validate one schema of a batch and emit its telemetry
+async fn validate_batch_item(
+    state: &AppState,
+    index: usize,
+    schema: serde_json::Value,
+    requested_by: String,
+    environment: EnvironmentRef,
+) -> BatchItemResult {
+    let schema_id = schema.get("id").and_then(|id| id.as_str()).map(String::from);
+    let mut input = match SchemaValidationEngine::create_input(schema, requested_by) {
+        Ok(input) => input,
+        Err(e) => {
+            return BatchItemResult {
+                index,
+                schema_id,
+                output: None,
+                error: Some(e),
+            }
+        }
+    };
+    input.environment = environment;
+
+    let inputs_hash = SchemaValidationEngine::compute_inputs_hash(&input);
+    let output = state.engine.validate(&input).await;
+
+    let signal = SchemaViolationSignal::from_validation(
+        inputs_hash,
+        &output,
+        input.request_id.to_string(),
+    );
+    if let Err(e) = state.telemetry.emit(signal).await {
+        tracing::warn!("Failed to emit telemetry: {}", e);
+    }
+
+    BatchItemResult {
+        index,
+        schema_id,
+        output: Some(output),
+        error: None,
+    }
+}
+
 /// Quick schema check endpoint (no telemetry)
 async fn check_schema(
     State(state): State<Arc<AppState>>,
-    Json(request): Json<ValidateSchemaRequest>,
+    CheckedJson(request): CheckedJson<ValidateSchemaRequest>,
 ) -> Result<Json<CheckResponse>, (StatusCode, Json<ApiError>)> {
-    let input = match SchemaValidationEngine::create_input(
+    let mut input = match SchemaValidationEngine::create_input(
         request.schema,
         request.requested_by.unwrap_or_else(|| "anonymous".to_string()),
     ) {
@@ -125,10 +310,12 @@ async fn check_schema(
                     error: "InvalidInput".to_string(),
                     message: e,
                     request_id: None,
+                    field: None,
                 }),
             ));
         }
     };
+    input.environment = request.environment.unwrap_or_default();
 
     let output = state.engine.validate(&input).await;
 
@@ -150,7 +337,7 @@ async fn check_schema(
 async fn validate_schema_instrumented(
     exec_ctx: ExecutionContextExtractor,
     State(state): State<Arc<AppState>>,
-    Json(request): Json<ValidateSchemaRequest>,
+    CheckedJson(request): CheckedJson<ValidateSchemaRequest>,
 ) -> Result<
     Json<ExecutionEnvelope<SchemaValidationOutput>>,
     (StatusCode, Json<serde_json::Value>),
@@ -160,7 +347,7 @@ async fn validate_schema_instrumented(
     let mut agent_span = tree.start_agent_span("schema-truth");
 
     // Create input
-    let input = match SchemaValidationEngine::create_input(
+    let mut input = match SchemaValidationEngine::create_input(
         request.schema,
         request.requested_by.unwrap_or_else(|| "anonymous".to_string()),
     ) {
@@ -172,6 +359,7 @@ async fn validate_schema_instrumented(
             return Ok(Json(ExecutionEnvelope::failure(e, span_tree)));
         }
     };
+    input.environment = request.environment.unwrap_or_default();
 
     let request_id = input.request_id;
     let inputs_hash = SchemaValidationEngine::compute_inputs_hash(&input);
@@ -207,8 +395,9 @@ async fn validate_schema_instrumented(
 #[derive(Debug, Serialize)]
 pub struct HealthResponse {
     pub status: String,
-    pub agent_id: String,
-    pub agent_version: String,
+    /// Agent id and version, build, uptime, registrations and telemetry
+    #[serde(flatten)]
+    pub summary: HealthSummary,
 }
 
 /// Validate schema request
@@ -216,6 +405,78 @@ pub struct HealthResponse {
 pub struct ValidateSchemaRequest {
     pub schema: serde_json::Value,
     pub requested_by: Option<String>,
+    /// Target environment (defaults to development)
+    pub environment: Option<EnvironmentRef>,
+}
+
+/// Batch validation request
+#[derive(Debug, Deserialize)]
+pub struct BatchValidateRequest {
+    /// Schemas to validate, each in the form accepted by `/api/v1/schema/validate`
+    pub schemas: Vec<serde_json::Value>,
+    pub requested_by: Option<String>,
+    /// Target environment for every schema (defaults to development)
+    pub environment: Option<EnvironmentRef>,
+}
+
+/// Outcome for one schema of a batch
+#[derive(Debug, Serialize)]
+pub struct BatchItemResult {
+    /// Position of the schema in the request
+    pub index: usize,
+    /// The schema's `id`, when it has one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema_id: Option<String>,
+    /// Validation output, when the schema could be parsed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<SchemaValidationOutput>,
+    /// Why the schema could not be validated
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Aggregate over a batch
+#[derive(Debug, Default, Serialize)]
+pub struct BatchSummary {
+    pub total: usize,
+    pub valid: usize,
+    pub invalid: usize,
+    /// Schemas that could not be parsed or validated
+    pub errored: usize,
+    pub violation_count: usize,
+    pub warning_count: usize,
+}
+
+impl BatchSummary {
+    /// Summarize batch results
+    pub fn of(results: &[BatchItemResult]) -> Self {
+        let mut summary = Self {
+            total: results.len(),
+            ..Default::default()
+        };
+        for result in results {
+            match &result.output {
+                Some(output) => {
+                    if output.is_valid {
+                        summary.valid += 1;
+                    } else {
+                        summary.invalid += 1;
+                    }
+                    summary.violation_count += output.violations.len();
+                    summary.warning_count += output.warnings.len();
+                }
+                None => summary.errored += 1,
+            }
+        }
+        summary
+    }
+}
+
+/// Batch validation response
+#[derive(Debug, Serialize)]
+pub struct BatchValidateResponse {
+    pub results: Vec<BatchItemResult>,
+    pub summary: BatchSummary,
 }
 
 /// API response wrapper
@@ -242,4 +503,19 @@ pub struct ApiError {
     pub error: String,
     pub message: String,
     pub request_id: Option<Uuid>,
+    /// Offending request field, for malformed requests
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub field: Option<String>,
+}
+
+impl IntoResponse for RequestShapeError {
+    fn into_response(self) -> axum::response::Response {
+        let error = ApiError {
+            error: "BadRequest".to_string(),
+            message: self.to_string(),
+            request_id: None,
+            field: self.field,
+        };
+        (StatusCode::BAD_REQUEST, Json(error)).into_response()
+    }
 }
@@ -3,24 +3,53 @@
 //! Non-blocking emission to ruvector-service.
 
 use crate::contracts::*;
+use crate::health::{EmissionStatus, EmissionTracker};
+use crate::http_pool::PoolConfig;
 use std::env;
+use std::time::Duration;
 use tokio::sync::mpsc;
 use tracing::{error, info};
 
+// Bounded worker pool shared by the agents' telemetry background tasks
+#[path = "../../../shared/worker_pool.rs"]
+pub mod worker_pool;
+
 /// Telemetry emitter for schema violation signals
 pub struct TelemetryEmitter {
     sender: mpsc::Sender<SchemaViolationSignal>,
+    /// Shares the background workers' connection pool
+    client: RuvectorClient,
+    /// Outcome of the latest emission, written by the workers
+    status: EmissionTracker,
 }
 
 impl TelemetryEmitter {
-    /// Create new emitter
+    /// Create new emitter from environment
+    ///
+    /// `TELEMETRY_WORKERS` sets how many signals are emitted concurrently.
     pub fn new() -> Self {
+        Self::with_client(RuvectorClient::new(), worker_pool::workers_from_env())
+    }
+
+    /// Create an emitter with `workers` background tasks sending through `client`
+    pub fn with_client(client: RuvectorClient, workers: usize) -> Self {
         let (sender, receiver) = mpsc::channel(100);
 
-        // Spawn background task
-        tokio::spawn(Self::background_emitter(receiver));
+        // Spawn background workers, so one slow request doesn't hold up the
+        // rest of the queue
+        let worker_client = client.clone();
+        let status = EmissionTracker::new();
+        let worker_status = status.clone();
+        worker_pool::spawn_workers(receiver, workers, move |signal| {
+            Self::emit_queued(worker_client.clone(), worker_status.clone(), signal)
+        });
+
+        Self { sender, client, status }
+    }
 
-        Self { sender }
+    /// Open a connection to ruvector-service ahead of the first signal
+    pub async fn prime(&self) -> Result<(), String> {
+        self.client.prime().await
     }
 
     /// Emit a signal
@@ -28,22 +57,31 @@ impl TelemetryEmitter {
         self.sender
             .send(signal)
             .await
-            .map_err(|e| format!("Failed to queue signal: {}", e))
+            .map_err(|e| {
+                let error = format!("Failed to queue signal: {}", e);
+                self.status.record_failure(&error);
+                error
+            })
     }
 
-    /// Background emission task
-    async fn background_emitter(mut receiver: mpsc::Receiver<SchemaViolationSignal>) {
-        let client = RuvectorClient::new();
+    /// Outcome of the latest emission, if one has finished
+    pub fn last_emission(&self) -> Option<EmissionStatus> {
+        self.status.last()
+    }
 
-        while let Some(signal) = receiver.recv().await {
-            info!(
-                event_id = %signal.event_id,
-                signal_type = %signal.signal_type,
-                "Emitting schema violation signal"
-            );
+    /// Send one queued signal
+    async fn emit_queued(client: RuvectorClient, status: EmissionTracker, signal: SchemaViolationSignal) {
+        info!(
+            event_id = %signal.event_id,
+            signal_type = %signal.signal_type,
+            "Emitting schema violation signal"
+        );
 
-            if let Err(e) = client.emit_signal(&signal).await {
+        match client.emit_signal(&signal).await {
+            Ok(()) => status.record_ok(),
+            Err(e) => {
                 error!(error = %e, "Failed to emit signal to ruvector-service");
+                status.record_failure(e);
             }
         }
     }
@@ -56,6 +94,12 @@ impl Default for TelemetryEmitter {
 }
 
 /// Ruvector service client
+///
+/// Holds one pooled `reqwest::Client` (see [`PoolConfig`]); clones share the
+/// same pool, so repeated emissions reuse kept-alive connections. Clients
+/// built from the environment speak HTTP/2 unless `RUVECTOR_HTTP_VERSION`
+/// says otherwise (see [`PoolConfig::telemetry_from_env`]).
+#[derive(Clone)]
 pub struct RuvectorClient {
     url: String,
     api_key: Option<String>,
@@ -65,14 +109,35 @@ pub struct RuvectorClient {
 impl RuvectorClient {
     /// Create new client from environment
     pub fn new() -> Self {
-        Self {
-            url: env::var("RUVECTOR_SERVICE_URL")
+        Self::with_url(
+            env::var("RUVECTOR_SERVICE_URL")
                 .unwrap_or_else(|_| "http://localhost:8080".to_string()),
+            PoolConfig::telemetry_from_env(),
+        )
+    }
+
+    /// Create a client for a specific service URL and pool
+    pub fn with_url(url: impl Into<String>, pool: PoolConfig) -> Self {
+        Self {
+            url: url.into(),
             api_key: env::var("RUVECTOR_API_KEY").ok(),
-            client: reqwest::Client::new(),
+            client: pool.client(),
         }
     }
 
+    /// Establish a pooled connection by requesting the health endpoint
+    ///
+    /// Any HTTP response counts as success.
+    pub async fn prime(&self) -> Result<(), String> {
+        self.client
+            .get(format!("{}/health", self.url))
+            .timeout(Duration::from_millis(crate::engine::MAX_LATENCY_MS))
+            .send()
+            .await
+            .map(|_| ())
+            .map_err(|e| format!("HTTP error: {}", e))
+    }
+
     /// Emit signal to ruvector-service
     pub async fn emit_signal(&self, signal: &SchemaViolationSignal) -> Result<(), String> {
         let url = format!("{}/api/v1/signals", self.url);
@@ -129,3 +194,78 @@ impl Default for RuvectorClient {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http_pool::testing::{counting_h2_server, counting_server, slow_server};
+    use crate::http_pool::HttpVersion;
+    use std::sync::atomic::Ordering;
+
+    #[tokio::test]
+    async fn test_repeated_emissions_reuse_connection() {
+        let (url, connections) = counting_server().await;
+        let client = RuvectorClient::with_url(url, PoolConfig::default());
+        let engine = crate::engine::SchemaValidationEngine::new();
+        let input = crate::engine::SchemaValidationEngine::create_input(
+            serde_json::json!({"id": "test/config", "version": "1.0.0", "name": "Test", "fields": {}}),
+            "test".to_string(),
+        )
+        .unwrap();
+        let output = engine.validate(&input).await;
+        let signal = SchemaViolationSignal::from_validation("hash".to_string(), &output, "exec".to_string());
+
+        for _ in 0..5 {
+            client.emit_signal(&signal).await.unwrap();
+        }
+        // Clones share the pool
+        client.clone().emit_signal(&signal).await.unwrap();
+
+        assert_eq!(connections.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_http2_emissions_share_one_connection() {
+        let (url, connections) = counting_h2_server().await;
+        let client = RuvectorClient::with_url(
+            url,
+            PoolConfig::default().with_http_version(HttpVersion::Http2),
+        );
+        let engine = crate::engine::SchemaValidationEngine::new();
+        let input = crate::engine::SchemaValidationEngine::create_input(
+            serde_json::json!({"id": "test/config", "version": "1.0.0", "name": "Test", "fields": {}}),
+            "test".to_string(),
+        )
+        .unwrap();
+        let output = engine.validate(&input).await;
+        let signal = SchemaViolationSignal::from_validation("hash".to_string(), &output, "exec".to_string());
+
+        for _ in 0..5 {
+            client.emit_signal(&signal).await.unwrap();
+        }
+
+        assert_eq!(connections.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_workers_emit_slow_signals_concurrently() {
+        let server = slow_server(Duration::from_millis(300)).await;
+        let client = RuvectorClient::with_url(server.url.clone(), PoolConfig::default());
+        let emitter = TelemetryEmitter::with_client(client, 4);
+        let engine = crate::engine::SchemaValidationEngine::new();
+        let input = crate::engine::SchemaValidationEngine::create_input(
+            serde_json::json!({"id": "test/config", "version": "1.0.0", "name": "Test", "fields": {}}),
+            "test".to_string(),
+        )
+        .unwrap();
+        let output = engine.validate(&input).await;
+        let signal = SchemaViolationSignal::from_validation("hash".to_string(), &output, "exec".to_string());
+
+        for _ in 0..4 {
+            emitter.emit(signal.clone()).await.unwrap();
+        }
+        server.wait_for(4).await;
+
+        assert_eq!(server.peak_in_flight.load(Ordering::SeqCst), 4);
+    }
+}
@@ -0,0 +1,112 @@
+//! Property tests for deterministic schema validation
+//!
+//! Each case deserializes its schema twice, so field maps get fresh random
+//! states, and validates each copy with its own engine on a mock clock. The
+//! serialized outputs and input hashes must match byte for byte.
+
+use chrono::{TimeZone, Utc};
+use proptest::prelude::*;
+use schema_truth::clock::MockClock;
+use schema_truth::contracts::*;
+use schema_truth::engine::SchemaValidationEngine;
+use serde_json::{json, Value};
+use std::sync::Arc;
+use uuid::Uuid;
+
+fn arb_constraint() -> impl Strategy<Value = Value> {
+    prop_oneof![
+        (-100.0..100.0f64, -100.0..100.0f64)
+            .prop_map(|(min, max)| json!({"type": "range", "min": min, "max": max, "inclusive": true})),
+        prop_oneof![Just("^[a-z]+$"), Just("(unclosed"), Just("^\\d{2,4}$")]
+            .prop_map(|regex| json!({"type": "pattern", "regex": regex})),
+        (0usize..20).prop_map(|length| json!({"type": "min_length", "length": length})),
+        Just(json!({"type": "not_empty"})),
+    ]
+}
+
+fn arb_field() -> impl Strategy<Value = Value> {
+    (
+        prop_oneof![
+            Just("string"),
+            Just("integer"),
+            Just("object"),
+            Just("array"),
+            Just("secret"),
+            Just("url"),
+        ],
+        any::<bool>(),
+        any::<bool>(),
+        prop::collection::vec(arb_constraint(), 0..3),
+        prop::option::of(Just(json!({"since_version": "1.0.0", "reason": "replaced"}))),
+    )
+        .prop_map(|(field_type, required, secret, constraints, deprecated)| {
+            let mut field = json!({
+                "field_type": field_type,
+                "required": required,
+                "secret": secret,
+                "constraints": constraints,
+            });
+            if let Some(deprecated) = deprecated {
+                field["deprecated"] = deprecated;
+            }
+            field
+        })
+}
+
+fn arb_schema() -> impl Strategy<Value = Value> {
+    (
+        prop_oneof![Just("1.0.0"), Just("2.1.0-beta"), Just("v3"), Just("")],
+        prop::collection::btree_map("[a-z][a-zA-Z_]{0,8}", arb_field(), 0..8),
+    )
+        .prop_map(|(version, fields)| {
+            json!({
+                "id": "proptest/schema",
+                "version": version,
+                "name": "Proptest",
+                "fields": fields,
+            })
+        })
+}
+
+fn arb_environment() -> impl Strategy<Value = EnvironmentRef> {
+    prop_oneof![
+        Just(EnvironmentRef::Development),
+        Just(EnvironmentRef::Staging),
+        Just(EnvironmentRef::Production),
+    ]
+}
+
+/// Validate a freshly deserialized copy of the schema and serialize the output
+fn run(schema: &Value, environment: EnvironmentRef) -> (String, String) {
+    let start = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+    let mut input = SchemaValidationEngine::create_input(schema.clone(), "proptest".to_string())
+        .expect("generated schemas parse");
+    input.request_id = Uuid::nil();
+    input.requested_at = start;
+    input.environment = environment;
+
+    let engine = SchemaValidationEngine::new().with_clock(Arc::new(MockClock::new(start)));
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    let output = runtime.block_on(engine.validate(&input));
+    (
+        SchemaValidationEngine::compute_inputs_hash(&input),
+        serde_json::to_string(&output).unwrap(),
+    )
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn prop_schema_validation_output_is_byte_identical(
+        schema in arb_schema(),
+        environment in arb_environment(),
+    ) {
+        let (hash1, output1) = run(&schema, environment);
+        let (hash2, output2) = run(&schema, environment);
+        prop_assert_eq!(hash1, hash2);
+        prop_assert_eq!(output1, output2);
+    }
+}
@@ -1,8 +1,7 @@
 //! Integration tests for Schema Truth Agent
 
 use schema_truth::contracts::*;
-use schema_truth::engine::SchemaValidationEngine;
-use std::collections::HashMap;
+use schema_truth::engine::{SchemaRegistry, SchemaValidationEngine};
 
 fn create_valid_schema() -> serde_json::Value {
     serde_json::json!({
@@ -42,8 +41,7 @@ fn create_valid_schema() -> serde_json::Value {
 #[tokio::test]
 async fn test_validate_valid_schema() {
     let engine = SchemaValidationEngine::new();
-    let input = engine
-        .create_input(create_valid_schema(), "test".to_string())
+    let input = SchemaValidationEngine::create_input(create_valid_schema(), "test".to_string())
         .expect("Failed to create input");
 
     let output = engine.validate(&input).await;
@@ -64,8 +62,7 @@ async fn test_validate_missing_id() {
         "fields": {}
     });
 
-    let input = engine
-        .create_input(schema, "test".to_string())
+    let input = SchemaValidationEngine::create_input(schema, "test".to_string())
         .expect("Failed to create input");
 
     let output = engine.validate(&input).await;
@@ -84,12 +81,18 @@ async fn test_validate_invalid_version() {
         "fields": {}
     });
 
-    let input = engine
-        .create_input(schema, "test".to_string())
+    let input = SchemaValidationEngine::create_input(schema, "test".to_string())
         .expect("Failed to create input");
 
     let output = engine.validate(&input).await;
 
+    // Production is the default and escalates the warning
+    assert!(output.violations.iter().any(|v| v.code == "VERSION_NOT_SEMVER"));
+
+    let mut input = input;
+    input.environment = EnvironmentRef::Development;
+    let output = engine.validate(&input).await;
+
     // Should have warning for non-semver version
     assert!(output.warnings.iter().any(|w| w.code == "VERSION_NOT_SEMVER"));
 }
@@ -111,8 +114,7 @@ async fn test_validate_invalid_range() {
         }
     });
 
-    let input = engine
-        .create_input(schema, "test".to_string())
+    let input = SchemaValidationEngine::create_input(schema, "test".to_string())
         .expect("Failed to create input");
 
     let output = engine.validate(&input).await;
@@ -123,12 +125,9 @@ async fn test_validate_invalid_range() {
 
 #[tokio::test]
 async fn test_deterministic_hash() {
-    let engine = SchemaValidationEngine::new();
-    let input1 = engine
-        .create_input(create_valid_schema(), "test".to_string())
+    let input1 = SchemaValidationEngine::create_input(create_valid_schema(), "test".to_string())
         .expect("Failed to create input");
-    let input2 = engine
-        .create_input(create_valid_schema(), "test".to_string())
+    let input2 = SchemaValidationEngine::create_input(create_valid_schema(), "test".to_string())
         .expect("Failed to create input");
 
     let hash1 = SchemaValidationEngine::compute_inputs_hash(&input1);
@@ -138,11 +137,24 @@ async fn test_deterministic_hash() {
     assert_eq!(hash1, hash2);
 }
 
+#[tokio::test]
+async fn test_hash_includes_environment() {
+    let production = SchemaValidationEngine::create_input(create_valid_schema(), "test".to_string())
+        .expect("Failed to create input");
+    let mut development = production.clone();
+    development.environment = EnvironmentRef::Development;
+
+    assert_eq!(production.environment, EnvironmentRef::Production);
+    assert_ne!(
+        SchemaValidationEngine::compute_inputs_hash(&production),
+        SchemaValidationEngine::compute_inputs_hash(&development)
+    );
+}
+
 #[tokio::test]
 async fn test_decision_event_creation() {
     let engine = SchemaValidationEngine::new();
-    let input = engine
-        .create_input(create_valid_schema(), "test".to_string())
+    let input = SchemaValidationEngine::create_input(create_valid_schema(), "test".to_string())
         .expect("Failed to create input");
 
     let inputs_hash = SchemaValidationEngine::compute_inputs_hash(&input);
@@ -159,3 +171,374 @@ async fn test_decision_event_creation() {
     assert!(signal.confidence > 0.0);
     assert!(signal.confidence <= 1.0);
 }
+
+#[tokio::test]
+async fn test_production_promotes_warnings_to_violations() {
+    let engine = SchemaValidationEngine::new();
+    let schema = serde_json::json!({
+        "id": "test/config",
+        "version": "invalid",
+        "name": "Test",
+        "fields": {}
+    });
+
+    let mut input = SchemaValidationEngine::create_input(schema.clone(), "test".to_string())
+        .expect("Failed to create input");
+    input.environment = EnvironmentRef::Development;
+    let dev_output = engine.validate(&input).await;
+
+    let mut input = SchemaValidationEngine::create_input(schema, "test".to_string())
+        .expect("Failed to create input");
+    input.environment = EnvironmentRef::Production;
+    let prod_output = engine.validate(&input).await;
+
+    assert!(dev_output.is_valid);
+    assert!(dev_output.warnings.iter().any(|w| w.code == "VERSION_NOT_SEMVER"));
+    assert!(!prod_output.is_valid);
+    assert!(prod_output
+        .violations
+        .iter()
+        .any(|v| v.code == "VERSION_NOT_SEMVER" && v.severity == ViolationSeverity::Error));
+}
+
+#[tokio::test]
+async fn test_signal_verdict_follows_violations() {
+    let engine = SchemaValidationEngine::new();
+    let signal = |output: &SchemaValidationOutput| {
+        SchemaViolationSignal::from_validation("hash".to_string(), output, "exec".to_string())
+    };
+
+    let input = SchemaValidationEngine::create_input(create_valid_schema(), "test".to_string())
+        .expect("Failed to create input");
+    let output = engine.validate(&input).await;
+    assert_eq!(signal(&output).verdict, Some(DecisionVerdict::Allow));
+
+    // An invalid version warns in development and blocks in production
+    let schema = serde_json::json!({
+        "id": "test/config",
+        "version": "invalid",
+        "name": "Test",
+        "fields": {}
+    });
+    let mut input = SchemaValidationEngine::create_input(schema, "test".to_string())
+        .expect("Failed to create input");
+    input.environment = EnvironmentRef::Development;
+    let output = engine.validate(&input).await;
+    assert_eq!(signal(&output).verdict, Some(DecisionVerdict::AllowWithWarnings));
+
+    input.environment = EnvironmentRef::Production;
+    let output = engine.validate(&input).await;
+    assert_eq!(signal(&output).verdict, Some(DecisionVerdict::Block));
+}
+
+#[test]
+fn test_environment_parsing_accepts_aliases() {
+    assert_eq!("prod".parse::<EnvironmentRef>(), Ok(EnvironmentRef::Production));
+    assert_eq!("Development".parse::<EnvironmentRef>(), Ok(EnvironmentRef::Development));
+    assert!("moon".parse::<EnvironmentRef>().is_err());
+
+    let env: EnvironmentRef = serde_json::from_str("\"prod\"").unwrap();
+    assert_eq!(env, EnvironmentRef::Production);
+}
+
+/// Schema whose fields carry pattern constraints
+fn create_patterned_schema() -> serde_json::Value {
+    serde_json::json!({
+        "id": "test/patterned",
+        "version": "2.1.0-rc.1",
+        "name": "Patterned Configuration",
+        "fields": {
+            "service_name": {
+                "field_type": "string",
+                "required": true,
+                "constraints": [{"type": "pattern", "regex": "^[a-z]+$"}]
+            },
+            "region": {
+                "field_type": "string",
+                "required": false,
+                "constraints": [{"type": "pattern", "regex": "^[a-z]+$"}]
+            }
+        }
+    })
+}
+
+#[tokio::test]
+async fn test_warmup_speeds_up_first_validation() {
+    let input = SchemaValidationEngine::create_input(create_patterned_schema(), "test".to_string())
+        .expect("Failed to create input");
+
+    // Best of several fresh engines, so one-off scheduler noise does not decide
+    let mut cold = std::time::Duration::MAX;
+    let mut warm = std::time::Duration::MAX;
+    for _ in 0..5 {
+        let engine = SchemaValidationEngine::new();
+        assert!(engine.patterns().is_empty());
+        let start = std::time::Instant::now();
+        engine.validate(&input).await;
+        cold = cold.min(start.elapsed());
+
+        let engine = SchemaValidationEngine::new();
+        engine.warmup().await;
+        let compiled = engine.patterns().len();
+        assert!(compiled >= 3);
+        let start = std::time::Instant::now();
+        engine.validate(&input).await;
+        warm = warm.min(start.elapsed());
+
+        // Nothing left to compile after warmup
+        assert_eq!(engine.patterns().len(), compiled);
+    }
+
+    assert!(warm < cold, "warm {:?} should beat cold {:?}", warm, cold);
+}
+
+#[tokio::test]
+async fn test_capabilities_endpoint_lists_default_rules() {
+    use schema_truth::handler::{create_router, AppState};
+    use tower::ServiceExt;
+
+    let request = axum::http::Request::get("/capabilities")
+        .body(axum::body::Body::empty())
+        .unwrap();
+    let response = create_router(std::sync::Arc::new(AppState::new()))
+        .oneshot(request)
+        .await
+        .unwrap();
+    assert_eq!(response.status(), axum::http::StatusCode::OK);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let capabilities: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(capabilities["agent_id"], SchemaViolationSignal::AGENT_ID);
+    assert_eq!(capabilities["agent_version"], SchemaViolationSignal::AGENT_VERSION);
+    let rule_ids: Vec<&str> = capabilities["rules"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|r| r["id"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        rule_ids,
+        vec![
+            "structure",
+            "field_type",
+            "constraint",
+            "required_field",
+            "deprecation",
+            "naming_convention",
+            "version",
+        ]
+    );
+    assert_eq!(capabilities["content_types"], serde_json::json!(["application/json"]));
+    assert!(capabilities.get("adapter_types").is_none());
+    assert_eq!(capabilities["build"]["version"], env!("CARGO_PKG_VERSION"));
+    assert!(!capabilities["build"]["rustc_version"].as_str().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_malformed_request_names_offending_field() {
+    use schema_truth::handler::{create_router, AppState};
+    use tower::ServiceExt;
+
+    let post = |body: serde_json::Value| {
+        axum::http::Request::post("/api/v1/schema/validate")
+            .header("content-type", "application/json")
+            .body(axum::body::Body::from(body.to_string()))
+            .unwrap()
+    };
+    let router = create_router(std::sync::Arc::new(AppState::new()));
+
+    let response = router
+        .clone()
+        .oneshot(post(serde_json::json!({ "requested_by": "ci" })))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), axum::http::StatusCode::BAD_REQUEST);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let error: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(error["error"], "BadRequest");
+    assert!(error.get("field").is_none());
+    assert_eq!(error["message"], "missing field `schema`");
+
+    let response = router
+        .oneshot(post(serde_json::json!({ "schema": {}, "environment": "qa" })))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), axum::http::StatusCode::BAD_REQUEST);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let error: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(error["field"], "environment");
+}
+
+#[tokio::test]
+async fn test_batch_validation_reports_each_schema() {
+    use schema_truth::handler::{create_router, AppState};
+    use tower::ServiceExt;
+
+    let post = |body: serde_json::Value| {
+        axum::http::Request::post("/api/v1/schema/validate/batch")
+            .header("content-type", "application/json")
+            .body(axum::body::Body::from(body.to_string()))
+            .unwrap()
+    };
+    let router = create_router(std::sync::Arc::new(AppState::new()));
+
+    let mut second = create_valid_schema();
+    second["id"] = serde_json::json!("test/other");
+    let invalid = serde_json::json!({
+        "id": "",
+        "version": "1.0.0",
+        "name": "Missing id",
+        "fields": {}
+    });
+    let response = router
+        .clone()
+        .oneshot(post(serde_json::json!({
+            "schemas": [create_valid_schema(), invalid, second],
+            "environment": "development"
+        })))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), axum::http::StatusCode::OK);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let response: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(response["success"], false);
+    let results = response["data"]["results"].as_array().unwrap();
+    let outcomes: Vec<(u64, bool)> = results
+        .iter()
+        .map(|r| (r["index"].as_u64().unwrap(), r["output"]["is_valid"].as_bool().unwrap()))
+        .collect();
+    assert_eq!(outcomes, vec![(0, true), (1, false), (2, true)]);
+    assert_eq!(results[2]["schema_id"], "test/other");
+    assert!(!results[1]["output"]["violations"].as_array().unwrap().is_empty());
+
+    let summary = &response["data"]["summary"];
+    assert_eq!(summary["total"], 3);
+    assert_eq!(summary["valid"], 2);
+    assert_eq!(summary["invalid"], 1);
+    assert_eq!(summary["errored"], 0);
+
+    let response = router
+        .oneshot(post(serde_json::json!({ "schemas": [] })))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), axum::http::StatusCode::BAD_REQUEST);
+}
+
+fn referencing_schema() -> serde_json::Value {
+    let mut schema = create_valid_schema();
+    schema["compatibility"] = serde_json::json!([
+        {
+            "constraint_type": "requires_schema",
+            "schema_id": "shared/database",
+            "min_version": "2.0.0"
+        },
+        {
+            "constraint_type": "field_format",
+            "field": "database_url",
+            "target_schema": "shared/database",
+            "target_field": "url"
+        }
+    ]);
+    schema
+}
+
+fn database_schema(version: &str) -> SchemaDefinition {
+    serde_json::from_value(serde_json::json!({
+        "id": "shared/database",
+        "version": version,
+        "name": "Database",
+        "fields": {"url": {"field_type": "url", "required": true}}
+    }))
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_resolvable_schema_reference_passes() {
+    let registry = SchemaRegistry::from_iter([database_schema("1.4.0"), database_schema("2.1.0")]);
+    let engine = SchemaValidationEngine::new().with_registry(registry);
+    let input = SchemaValidationEngine::create_input(referencing_schema(), "test".to_string())
+        .expect("Failed to create input");
+
+    let output = engine.validate(&input).await;
+
+    assert!(output.is_valid, "unexpected violations: {:?}", output.violations);
+    assert!(output.rules_applied.iter().any(|r| r == "schema_reference"));
+}
+
+#[tokio::test]
+async fn test_dangling_schema_reference_is_flagged() {
+    let input = SchemaValidationEngine::create_input(referencing_schema(), "test".to_string())
+        .expect("Failed to create input");
+
+    let engine = SchemaValidationEngine::new().with_registry(SchemaRegistry::new());
+    let output = engine.validate(&input).await;
+    assert!(!output.is_valid);
+    let dangling: Vec<_> = output
+        .violations
+        .iter()
+        .filter(|v| v.code == "DANGLING_SCHEMA_REF")
+        .map(|v| v.path.as_deref().unwrap())
+        .collect();
+    assert_eq!(dangling, ["compatibility[0]", "compatibility[1]"]);
+
+    // A registered schema that is too old does not satisfy the minimum version
+    let registry = SchemaRegistry::new().with_schema(database_schema("1.4.0"));
+    let engine = SchemaValidationEngine::new().with_registry(registry);
+    let output = engine.validate(&input).await;
+    assert!(output
+        .violations
+        .iter()
+        .any(|v| v.code == "SCHEMA_REF_VERSION_UNSATISFIED"));
+}
+
+#[tokio::test]
+async fn test_schema_format_version_is_checked() {
+    let engine = SchemaValidationEngine::new();
+    let with_format = |version: &str| {
+        let mut schema = create_valid_schema();
+        schema["schema_format_version"] = serde_json::json!(version);
+        SchemaValidationEngine::create_input(schema, "test".to_string())
+    };
+
+    let output = engine.validate(&with_format("1.0").unwrap()).await;
+    assert!(output.is_valid);
+    assert!(!output.warnings.iter().any(|w| w.code == "SCHEMA_FORMAT_OUTDATED"));
+
+    // Older formats are still read, with a note even in production
+    let mut input = with_format("0.9").unwrap();
+    input.environment = EnvironmentRef::Production;
+    let output = engine.validate(&input).await;
+    assert!(output.is_valid, "unexpected violations: {:?}", output.violations);
+    assert!(output.warnings.iter().any(|w| w.code == "SCHEMA_FORMAT_OUTDATED"));
+
+    let err = with_format("2.0").unwrap_err();
+    assert!(err.contains("Unsupported schema format version '2.0'"), "{}", err);
+    assert!(err.contains("0.9 through 1.0"), "{}", err);
+}
+
+#[tokio::test]
+async fn test_health_endpoint_reports_summary() {
+    use schema_truth::handler::{create_router, AppState};
+    use tower::ServiceExt;
+
+    let request = axum::http::Request::get("/health")
+        .body(axum::body::Body::empty())
+        .unwrap();
+    let response = create_router(std::sync::Arc::new(AppState::new()))
+        .oneshot(request)
+        .await
+        .unwrap();
+    assert_eq!(response.status(), axum::http::StatusCode::OK);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let health: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(health["status"], "healthy");
+    assert_eq!(health["agent_id"], SchemaViolationSignal::AGENT_ID);
+    assert!(!health["agent_version"].as_str().unwrap().is_empty());
+    assert!(!health["build"]["git_sha"].as_str().unwrap().is_empty());
+    assert_eq!(health["rules"], 7);
+    assert_eq!(health["checkers"], 0);
+    assert!(health["uptime_seconds"].is_u64());
+    assert_eq!(health["telemetry"]["enabled"], true);
+}
@@ -0,0 +1,59 @@
+//! Tests piping schemas to the CLI through `-`
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+const SCHEMA_YAML: &str = "\
+id: test/config
+version: 1.0.0
+name: Test Configuration
+description: A test schema
+fields:
+  max_connections:
+    field_type: integer
+    required: false
+    default: 10
+    description: Pool size
+";
+
+fn run(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_schema-truth"))
+        .args(args)
+        .env("RUST_LOG", "off")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn test_validate_reads_yaml_schema_from_stdin() {
+    let output = run(
+        &["validate", "--file", "-", "--input-format", "yaml", "--environment", "development"],
+        SCHEMA_YAML,
+    );
+    assert!(output.status.success(), "{:?}", output);
+    let result: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(result["is_valid"], true);
+}
+
+#[test]
+fn test_check_reads_json_schema_from_stdin() {
+    let schema: serde_json::Value = serde_yaml::from_str(SCHEMA_YAML).unwrap();
+    let output = run(
+        &["check", "--file", "-", "--environment", "development"],
+        &schema.to_string(),
+    );
+    assert!(output.status.success(), "{:?}", output);
+    let summary: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(summary["valid"], true);
+    assert_eq!(summary["violations"], 0);
+}
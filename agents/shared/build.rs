@@ -0,0 +1,74 @@
+//! Build script shared by the agents (`build = "../shared/build.rs"`)
+//!
+//! Stamps the commit being built into `AGENT_GIT_SHA`, the build time into
+//! `AGENT_BUILD_EPOCH` and the compiler into `AGENT_RUSTC_VERSION`, read by
+//! `build_info()`. A `GIT_SHA` environment variable (set by CI or container
+//! builds without a `.git` directory) wins over asking git, and
+//! `SOURCE_DATE_EPOCH` pins the build time for reproducible builds.
+//!
+//! The script only reruns when HEAD or these variables change, so the build
+//! time is that of the first build of the commit rather than of the latest
+//! incremental one.
+
+use std::path::Path;
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+fn run(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program).args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let text = String::from_utf8(output.stdout).ok()?;
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+fn git(args: &[&str]) -> Option<String> {
+    run("git", args)
+}
+
+fn main() {
+    println!("cargo:rerun-if-env-changed=GIT_SHA");
+    println!("cargo:rerun-if-env-changed=SOURCE_DATE_EPOCH");
+
+    // Rebuild when HEAD moves, so the stamp follows checkouts and commits
+    if let Some(git_dir) = git(&["rev-parse", "--absolute-git-dir"]) {
+        println!("cargo:rerun-if-changed={}/HEAD", git_dir);
+        // Packed refs have no file of their own; watching a missing path
+        // would rerun this script on every build
+        if let Some(head_ref) = git(&["symbolic-ref", "-q", "HEAD"]) {
+            let ref_path = Path::new(&git_dir).join(head_ref);
+            if ref_path.exists() {
+                println!("cargo:rerun-if-changed={}", ref_path.display());
+            }
+        }
+    }
+
+    let sha = std::env::var("GIT_SHA")
+        .ok()
+        .filter(|sha| !sha.trim().is_empty())
+        .or_else(|| git(&["rev-parse", "--short=12", "HEAD"]));
+    if let Some(sha) = sha {
+        println!("cargo:rustc-env=AGENT_GIT_SHA={}", sha.trim());
+    }
+
+    let epoch = std::env::var("SOURCE_DATE_EPOCH")
+        .ok()
+        .and_then(|epoch| epoch.trim().parse::<u64>().ok())
+        .or_else(|| {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .ok()
+                .map(|elapsed| elapsed.as_secs())
+        });
+    if let Some(epoch) = epoch {
+        println!("cargo:rustc-env=AGENT_BUILD_EPOCH={}", epoch);
+    }
+
+    // Cargo runs build scripts with the compiler it builds the crate with
+    let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    if let Some(version) = run(&rustc, &["--version"]) {
+        println!("cargo:rustc-env=AGENT_RUSTC_VERSION={}", version);
+    }
+}
@@ -0,0 +1,117 @@
+//! What an agent binary was built from
+//!
+//! Included into each agent with `#[path]`. The values are stamped at compile
+//! time by the shared build script and reported by `/health`,
+//! `/capabilities` and `--version --verbose`, so a support request can be
+//! matched to the exact commit and toolchain that produced the binary.
+
+use serde::{Deserialize, Serialize};
+
+/// Placeholder for anything the build script could not determine
+pub const UNKNOWN: &str = "unknown";
+
+/// Commit the agent was built from
+pub const GIT_SHA: &str = match option_env!("AGENT_GIT_SHA") {
+    Some(sha) => sha,
+    None => UNKNOWN,
+};
+
+/// Compiler that built the agent, as reported by `rustc --version`
+pub const RUSTC_VERSION: &str = match option_env!("AGENT_RUSTC_VERSION") {
+    Some(version) => version,
+    None => UNKNOWN,
+};
+
+/// Seconds since the Unix epoch at which the agent was built
+const BUILD_EPOCH: Option<&str> = option_env!("AGENT_BUILD_EPOCH");
+
+/// Version, commit, build time and compiler of this binary
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BuildInfo {
+    /// Crate version
+    pub version: String,
+    /// Commit the agent was built from, or `unknown`
+    pub git_sha: String,
+    /// When the agent was built (RFC 3339), or `unknown`
+    pub build_timestamp: String,
+    /// Compiler that built the agent, or `unknown`
+    pub rustc_version: String,
+}
+
+impl BuildInfo {
+    /// Multi-line description printed by `--version --verbose`
+    pub fn verbose_version(&self, name: &str) -> String {
+        format!(
+            "{} {}\ncommit: {}\nbuilt: {}\nrustc: {}",
+            name, self.version, self.git_sha, self.build_timestamp, self.rustc_version
+        )
+    }
+}
+
+/// Build information for this binary
+pub fn build_info() -> BuildInfo {
+    let build_timestamp = BUILD_EPOCH
+        .and_then(|epoch| epoch.parse::<i64>().ok())
+        .and_then(|epoch| chrono::DateTime::from_timestamp(epoch, 0))
+        .map(|at| at.to_rfc3339())
+        .unwrap_or_else(|| UNKNOWN.to_string());
+    BuildInfo {
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        git_sha: GIT_SHA.to_string(),
+        build_timestamp,
+        rustc_version: RUSTC_VERSION.to_string(),
+    }
+}
+
+/// Whether the command line asks for `--version --verbose`
+///
+/// Checked before clap parses the arguments, since clap prints its own
+/// one-line version and exits as soon as it sees `--version`. Arguments after
+/// a `--` separator are operands and are not considered.
+pub fn verbose_version_requested<I, S>(args: I) -> bool
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let (mut version, mut verbose) = (false, false);
+    for arg in args.into_iter().skip(1) {
+        match arg.as_ref() {
+            "--" => break,
+            "--version" | "-V" => version = true,
+            "--verbose" | "-v" => verbose = true,
+            _ => {}
+        }
+    }
+    version && verbose
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_info_fields_are_not_empty() {
+        let info = build_info();
+        assert!(!info.version.is_empty());
+        assert!(!info.git_sha.is_empty());
+        assert!(!info.build_timestamp.is_empty());
+        assert!(!info.rustc_version.is_empty());
+
+        // The build script always knows the build time and compiler
+        assert_ne!(info.build_timestamp, UNKNOWN);
+        assert!(info.rustc_version.starts_with("rustc "));
+
+        let verbose = info.verbose_version("agent");
+        assert!(verbose.starts_with(&format!("agent {}", info.version)));
+        assert!(verbose.contains(&info.git_sha));
+    }
+
+    #[test]
+    fn test_verbose_version_requested() {
+        assert!(verbose_version_requested(["agent", "--version", "--verbose"]));
+        assert!(verbose_version_requested(["agent", "-v", "-V"]));
+        assert!(!verbose_version_requested(["agent", "--version"]));
+        assert!(!verbose_version_requested(["agent", "serve", "--verbose"]));
+        assert!(!verbose_version_requested(["agent", "--version", "--", "--verbose"]));
+    }
+}
@@ -0,0 +1,84 @@
+//! Capability description served by each agent's `GET /capabilities`
+//!
+//! Included into each agent's handler with `#[path]` so orchestrators can
+//! discover what any agent supports from one response shape. Lists that do
+//! not apply to an agent (rules for integration-health, adapter types for
+//! the validators) are left empty and omitted from the JSON.
+
+use serde::{Deserialize, Serialize};
+
+use crate::build_info::{build_info, BuildInfo};
+
+/// Media type of every agent request and response body
+pub const JSON_CONTENT_TYPE: &str = "application/json";
+
+/// A registered validation rule
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RuleCapability {
+    /// Rule identifier
+    pub id: String,
+    /// Human-readable rule name
+    pub name: String,
+    /// Rule category, for agents that categorize their rules
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+}
+
+/// What an agent supports
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Capabilities {
+    /// Agent identifier
+    pub agent_id: String,
+    /// Agent version
+    pub agent_version: String,
+    /// Registered validation rules
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rules: Vec<RuleCapability>,
+    /// Registered health checker ids
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub checkers: Vec<String>,
+    /// Adapter types some registered checker can probe
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub adapter_types: Vec<String>,
+    /// Media types accepted and returned by the HTTP API
+    pub content_types: Vec<String>,
+    /// Output formats of the agent's CLI
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub output_formats: Vec<String>,
+    /// Cargo features compiled into this build
+    pub features: Vec<String>,
+    /// Version, commit, build time and compiler of the binary
+    pub build: BuildInfo,
+}
+
+impl Capabilities {
+    /// Capabilities with the JSON content type, the compiled-in features and
+    /// the build information
+    pub fn new(agent_id: impl Into<String>, agent_version: impl Into<String>) -> Self {
+        Self {
+            agent_id: agent_id.into(),
+            agent_version: agent_version.into(),
+            rules: Vec::new(),
+            checkers: Vec::new(),
+            adapter_types: Vec::new(),
+            content_types: vec![JSON_CONTENT_TYPE.to_string()],
+            output_formats: Vec::new(),
+            features: compiled_features(),
+            build: build_info(),
+        }
+    }
+
+    /// Whether a rule with this id is registered
+    pub fn has_rule(&self, id: &str) -> bool {
+        self.rules.iter().any(|r| r.id == id)
+    }
+}
+
+/// Cargo features enabled in this build
+pub fn compiled_features() -> Vec<String> {
+    let mut features = Vec::new();
+    if cfg!(feature = "mock") {
+        features.push("mock".to_string());
+    }
+    features
+}
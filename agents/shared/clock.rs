@@ -0,0 +1,83 @@
+//! Time source for the engines
+//!
+//! Included into each agent with `#[path]`. The engines read wall-clock time
+//! for timestamps such as `completed_at` and a monotonic clock for
+//! `duration_ms` and cache TTLs. Both come from an injected [`Clock`], so
+//! tests and replays can swap in a [`MockClock`] and advance time by hand
+//! instead of sleeping.
+
+use chrono::{DateTime, Utc};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Source of the current time
+pub trait Clock: Send + Sync + std::fmt::Debug {
+    /// Current wall-clock time
+    fn now(&self) -> DateTime<Utc>;
+
+    /// Current monotonic time, for measuring durations
+    fn instant(&self) -> Instant;
+
+    /// Time passed since `earlier`, an instant read from this clock
+    fn elapsed(&self, earlier: Instant) -> Duration {
+        self.instant().saturating_duration_since(earlier)
+    }
+}
+
+/// The system clock
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+
+    fn instant(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// Shared handle to the system clock
+pub fn system_clock() -> Arc<dyn Clock> {
+    Arc::new(SystemClock)
+}
+
+/// Clock that only moves when advanced
+#[derive(Debug)]
+pub struct MockClock {
+    wall: DateTime<Utc>,
+    base: Instant,
+    offset: Mutex<Duration>,
+}
+
+impl MockClock {
+    /// Clock stopped at `wall`
+    pub fn new(wall: DateTime<Utc>) -> Self {
+        Self {
+            wall,
+            base: Instant::now(),
+            offset: Mutex::new(Duration::ZERO),
+        }
+    }
+
+    /// Move the clock forward by `by`
+    pub fn advance(&self, by: Duration) {
+        *self.offset.lock().unwrap_or_else(|e| e.into_inner()) += by;
+    }
+
+    fn offset(&self) -> Duration {
+        *self.offset.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> DateTime<Utc> {
+        // Offsets are far below chrono's range, so the conversion cannot fail
+        self.wall + chrono::Duration::from_std(self.offset()).unwrap_or_default()
+    }
+
+    fn instant(&self) -> Instant {
+        self.base + self.offset()
+    }
+}
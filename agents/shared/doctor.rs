@@ -0,0 +1,140 @@
+//! Self-test checks shared by the agent `doctor` subcommands
+//!
+//! Included into each agent with `#[path]` so every agent checks its
+//! environment, telemetry endpoint, and latency budget, and reports the
+//! results, the same way.
+
+use serde::Serialize;
+use std::time::Duration;
+
+/// Outcome of a single doctor check
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DoctorCheck {
+    /// Short name of what was checked
+    pub name: String,
+    /// Whether the check passed
+    pub passed: bool,
+    /// What was found
+    pub detail: String,
+}
+
+impl DoctorCheck {
+    /// A passing check
+    pub fn pass(name: impl Into<String>, detail: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            passed: true,
+            detail: detail.into(),
+        }
+    }
+
+    /// A failing check
+    pub fn fail(name: impl Into<String>, detail: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            passed: false,
+            detail: detail.into(),
+        }
+    }
+}
+
+/// Results of every doctor check, in the order they ran
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct DoctorReport {
+    pub checks: Vec<DoctorCheck>,
+}
+
+impl DoctorReport {
+    /// Record a check result
+    pub fn push(&mut self, check: DoctorCheck) {
+        self.checks.push(check);
+    }
+
+    /// Whether every check passed
+    pub fn passed(&self) -> bool {
+        self.checks.iter().all(|c| c.passed)
+    }
+
+    /// Look up a check by name
+    pub fn check(&self, name: &str) -> Option<&DoctorCheck> {
+        self.checks.iter().find(|c| c.name == name)
+    }
+
+    /// Render as a plain-text pass/fail report
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        for check in &self.checks {
+            let status = if check.passed { "PASS" } else { "FAIL" };
+            out.push_str(&format!("[{}] {}: {}\n", status, check.name, check.detail));
+        }
+        let failed = self.checks.iter().filter(|c| !c.passed).count();
+        if failed == 0 {
+            out.push_str(&format!("All {} checks passed\n", self.checks.len()));
+        } else {
+            out.push_str(&format!("{} of {} checks failed\n", failed, self.checks.len()));
+        }
+        out
+    }
+}
+
+/// Check that an environment variable, if set, holds an http(s) URL
+///
+/// `value` is the variable's value; unset variables pass using `default`.
+pub fn check_url_var(var: &str, value: Option<&str>, default: &str) -> DoctorCheck {
+    let name = format!("env {}", var);
+    let url = value.unwrap_or(default);
+    match reqwest::Url::parse(url) {
+        Ok(parsed) if matches!(parsed.scheme(), "http" | "https") => match value {
+            Some(_) => DoctorCheck::pass(name, url),
+            None => DoctorCheck::pass(name, format!("not set, using {}", default)),
+        },
+        Ok(parsed) => DoctorCheck::fail(
+            name,
+            format!("{} has unsupported scheme '{}'", url, parsed.scheme()),
+        ),
+        Err(e) => DoctorCheck::fail(name, format!("'{}' is not a valid URL: {}", url, e)),
+    }
+}
+
+/// Check that an environment variable, if set, parses as `T`
+pub fn check_parse_var<T: std::str::FromStr>(var: &str, value: Option<&str>) -> DoctorCheck {
+    let name = format!("env {}", var);
+    match value {
+        None => DoctorCheck::pass(name, "not set, using default"),
+        Some(v) if v.trim().parse::<T>().is_ok() => DoctorCheck::pass(name, v),
+        Some(v) => DoctorCheck::fail(
+            name,
+            format!("'{}' is not a valid {}", v, std::any::type_name::<T>()),
+        ),
+    }
+}
+
+/// Check that `url` answers HTTP requests within `timeout`
+///
+/// Any HTTP response counts as reachable; only connection failures and
+/// timeouts fail the check.
+pub async fn check_endpoint(name: &str, url: &str, timeout: Duration) -> DoctorCheck {
+    let client = match reqwest::Client::builder().timeout(timeout).build() {
+        Ok(client) => client,
+        Err(e) => return DoctorCheck::fail(name, format!("failed to create HTTP client: {}", e)),
+    };
+    let health_url = format!("{}/health", url.trim_end_matches('/'));
+    match client.get(&health_url).send().await {
+        Ok(response) => DoctorCheck::pass(
+            name,
+            format!("{} responded with {}", health_url, response.status()),
+        ),
+        Err(e) => DoctorCheck::fail(name, format!("{} is unreachable: {}", health_url, e)),
+    }
+}
+
+/// Check that a measured request fit in the latency budget
+pub fn check_latency(name: &str, elapsed: Duration, budget_ms: u64) -> DoctorCheck {
+    let elapsed_ms = elapsed.as_millis() as u64;
+    let detail = format!("{}ms of {}ms budget", elapsed_ms, budget_ms);
+    if elapsed_ms <= budget_ms {
+        DoctorCheck::pass(name, detail)
+    } else {
+        DoctorCheck::fail(name, detail)
+    }
+}
@@ -0,0 +1,110 @@
+//! Target deployment environment shared by the agent contracts
+//!
+//! Included into each agent's `contracts` module with `#[path]` so every
+//! agent parses, prints, and defaults environments the same way.
+
+use serde::{Deserialize, Serialize};
+
+/// Target deployment environment (mirrors llm-config-storage Environment)
+///
+/// Production applies stricter checks than the other environments and is the
+/// default, matching the config-validation CLI. Environments order by
+/// promotion (base, development, staging, production), with edge last.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EnvironmentRef {
+    Base,
+    #[serde(alias = "dev")]
+    Development,
+    #[serde(alias = "stage")]
+    Staging,
+    #[default]
+    #[serde(alias = "prod")]
+    Production,
+    Edge,
+}
+
+impl EnvironmentRef {
+    /// Every environment, in promotion order
+    pub const ALL: [EnvironmentRef; 5] = [
+        EnvironmentRef::Base,
+        EnvironmentRef::Development,
+        EnvironmentRef::Staging,
+        EnvironmentRef::Production,
+        EnvironmentRef::Edge,
+    ];
+
+    /// Get the canonical name
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EnvironmentRef::Base => "base",
+            EnvironmentRef::Development => "development",
+            EnvironmentRef::Staging => "staging",
+            EnvironmentRef::Production => "production",
+            EnvironmentRef::Edge => "edge",
+        }
+    }
+
+    /// Short name also accepted when parsing, if there is one
+    pub fn alias(&self) -> Option<&'static str> {
+        match self {
+            EnvironmentRef::Development => Some("dev"),
+            EnvironmentRef::Staging => Some("stage"),
+            EnvironmentRef::Production => Some("prod"),
+            EnvironmentRef::Base | EnvironmentRef::Edge => None,
+        }
+    }
+
+    /// Whether stricter production behavior applies
+    pub fn is_production(&self) -> bool {
+        matches!(self, EnvironmentRef::Production)
+    }
+}
+
+impl std::fmt::Display for EnvironmentRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Error returned when a string names no known environment
+///
+/// The message lists every valid name and alias.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseEnvironmentError {
+    /// The string that failed to parse
+    pub input: String,
+}
+
+impl std::fmt::Display for ParseEnvironmentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Unknown environment '{}'; expected one of: ", self.input)?;
+        for (i, env) in EnvironmentRef::ALL.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            match env.alias() {
+                Some(alias) => write!(f, "{} ({})", env, alias)?,
+                None => write!(f, "{}", env)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseEnvironmentError {}
+
+impl std::str::FromStr for EnvironmentRef {
+    type Err = ParseEnvironmentError;
+
+    /// Parse a canonical name or alias, ignoring case and surrounding space
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.trim().to_lowercase();
+        EnvironmentRef::ALL
+            .into_iter()
+            .find(|env| env.as_str() == name || env.alias() == Some(name.as_str()))
+            .ok_or_else(|| ParseEnvironmentError {
+                input: s.to_string(),
+            })
+    }
+}
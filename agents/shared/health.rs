@@ -0,0 +1,155 @@
+//! Diagnostic summary served by each agent's `GET /health`
+//!
+//! Included into each agent with `#[path]`. Everything here is read from
+//! state the agent already holds, so a health probe costs no more than a few
+//! counter reads and one short lock.
+
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+use crate::build_info::{build_info, BuildInfo};
+
+/// Outcome of a telemetry emission
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EmissionStatus {
+    /// Whether the emission succeeded
+    pub ok: bool,
+    /// When the emission finished (RFC 3339)
+    pub at: String,
+    /// Why the emission failed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Remembers the outcome of the latest telemetry emission
+///
+/// Clones share the record, so emitter workers write what `/health` reads.
+#[derive(Debug, Clone, Default)]
+pub struct EmissionTracker {
+    last: Arc<Mutex<Option<EmissionStatus>>>,
+}
+
+impl EmissionTracker {
+    /// Create a tracker with nothing emitted yet
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a successful emission
+    pub fn record_ok(&self) {
+        self.record(EmissionStatus {
+            ok: true,
+            at: chrono::Utc::now().to_rfc3339(),
+            error: None,
+        });
+    }
+
+    /// Record a failed emission
+    pub fn record_failure(&self, error: impl Into<String>) {
+        self.record(EmissionStatus {
+            ok: false,
+            at: chrono::Utc::now().to_rfc3339(),
+            error: Some(error.into()),
+        });
+    }
+
+    /// The latest emission, if any
+    pub fn last(&self) -> Option<EmissionStatus> {
+        self.last.lock().map(|last| last.clone()).unwrap_or(None)
+    }
+
+    fn record(&self, status: EmissionStatus) {
+        if let Ok(mut last) = self.last.lock() {
+            *last = Some(status);
+        }
+    }
+}
+
+/// Telemetry section of the health summary
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TelemetryHealth {
+    /// Whether the agent emits telemetry
+    pub enabled: bool,
+    /// The latest emission, absent until the first one finishes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_emission: Option<EmissionStatus>,
+}
+
+/// What a `/health` response reports beyond its status
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HealthSummary {
+    /// Agent identifier
+    pub agent_id: String,
+    /// Agent version
+    pub agent_version: String,
+    /// Version, commit, build time and compiler of the binary
+    pub build: BuildInfo,
+    /// Seconds since the agent started
+    pub uptime_seconds: u64,
+    /// Registered validation rules
+    #[serde(default)]
+    pub rules: usize,
+    /// Registered health checkers
+    #[serde(default)]
+    pub checkers: usize,
+    /// Loaded schemas
+    #[serde(default)]
+    pub schemas: usize,
+    /// Telemetry emission status
+    pub telemetry: TelemetryHealth,
+}
+
+impl HealthSummary {
+    /// A summary for an agent started at `started`, with nothing loaded
+    pub fn new(
+        agent_id: impl Into<String>,
+        agent_version: impl Into<String>,
+        started: Instant,
+    ) -> Self {
+        Self {
+            agent_id: agent_id.into(),
+            agent_version: agent_version.into(),
+            build: build_info(),
+            uptime_seconds: started.elapsed().as_secs(),
+            rules: 0,
+            checkers: 0,
+            schemas: 0,
+            telemetry: TelemetryHealth {
+                enabled: false,
+                last_emission: None,
+            },
+        }
+    }
+
+    /// Report whether telemetry is enabled, and its latest emission
+    pub fn with_telemetry(mut self, enabled: bool, last_emission: Option<EmissionStatus>) -> Self {
+        self.telemetry = TelemetryHealth {
+            enabled,
+            last_emission,
+        };
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tracker_keeps_latest_emission() {
+        let tracker = EmissionTracker::new();
+        assert_eq!(tracker.last(), None);
+
+        let workers = tracker.clone();
+        workers.record_failure("connection refused");
+        let last = tracker.last().unwrap();
+        assert!(!last.ok);
+        assert_eq!(last.error.as_deref(), Some("connection refused"));
+
+        workers.record_ok();
+        let last = tracker.last().unwrap();
+        assert!(last.ok);
+        assert_eq!(last.error, None);
+    }
+}
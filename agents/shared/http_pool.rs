@@ -0,0 +1,332 @@
+//! Connection pool settings for the agents' HTTP clients
+//!
+//! Included into each agent with `#[path]`. Every long-lived client (the
+//! ruvector-service emitter, the agent clients, the health checkers) builds
+//! its `reqwest::Client` from a [`PoolConfig`] once and reuses it, so repeated
+//! requests to the same host share kept-alive connections instead of paying
+//! for a new TCP/TLS handshake each time.
+//!
+//! Clients that send many small requests to one service (the ruvector-service
+//! telemetry clients) can also speak HTTP/2 with prior knowledge, which
+//! multiplexes every request over a single connection; see [`HttpVersion`].
+
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Idle connections kept per host by default
+pub const DEFAULT_MAX_IDLE_PER_HOST: usize = 10;
+
+/// How long an idle connection is kept by default, in milliseconds
+pub const DEFAULT_IDLE_TIMEOUT_MS: u64 = 30_000;
+
+/// TCP keep-alive interval for pooled connections
+pub const TCP_KEEPALIVE: Duration = Duration::from_secs(60);
+
+/// Interval between HTTP/2 keep-alive pings
+pub const HTTP2_KEEPALIVE_INTERVAL: Duration = Duration::from_secs(30);
+
+/// How long to wait for an HTTP/2 keep-alive ping to be acknowledged
+pub const HTTP2_KEEPALIVE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// HTTP version a client speaks
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum HttpVersion {
+    /// HTTP/1.1, or HTTP/2 when the server offers it during the TLS handshake
+    #[default]
+    Negotiate,
+    /// HTTP/1.1 only
+    Http1,
+    /// HTTP/2 with prior knowledge, for servers known to accept it (h2c on
+    /// plain HTTP)
+    Http2,
+}
+
+impl HttpVersion {
+    /// Get the string representation
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Negotiate => "negotiate",
+            Self::Http1 => "http1",
+            Self::Http2 => "http2",
+        }
+    }
+
+    /// Read the version from an environment variable
+    ///
+    /// Unset or unparsable values fall back to `default`.
+    pub fn from_env(var: &str, default: Self) -> Self {
+        std::env::var(var)
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(default)
+    }
+}
+
+impl FromStr for HttpVersion {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "negotiate" | "auto" => Ok(Self::Negotiate),
+            "http1" | "http/1.1" | "1.1" | "h1" => Ok(Self::Http1),
+            "http2" | "http/2" | "2" | "h2" | "h2c" => Ok(Self::Http2),
+            other => Err(format!(
+                "unknown HTTP version '{}' (expected negotiate, http1, or http2)",
+                other
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for HttpVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Connection pool settings for a `reqwest::Client`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PoolConfig {
+    /// Maximum idle connections kept per host
+    pub max_idle_per_host: usize,
+    /// How long an idle connection is kept before closing
+    pub idle_timeout: Duration,
+    /// HTTP version spoken on pooled connections
+    pub http_version: HttpVersion,
+}
+
+impl Default for PoolConfig {
+    fn default() -> Self {
+        Self {
+            max_idle_per_host: DEFAULT_MAX_IDLE_PER_HOST,
+            idle_timeout: Duration::from_millis(DEFAULT_IDLE_TIMEOUT_MS),
+            http_version: HttpVersion::default(),
+        }
+    }
+}
+
+impl PoolConfig {
+    /// Read `HTTP_POOL_MAX_IDLE_PER_HOST` and `HTTP_POOL_IDLE_TIMEOUT_MS`
+    ///
+    /// Unset or unparsable values fall back to the defaults.
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        Self {
+            max_idle_per_host: std::env::var("HTTP_POOL_MAX_IDLE_PER_HOST")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(defaults.max_idle_per_host),
+            idle_timeout: std::env::var("HTTP_POOL_IDLE_TIMEOUT_MS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .map(Duration::from_millis)
+                .unwrap_or(defaults.idle_timeout),
+            http_version: defaults.http_version,
+        }
+    }
+
+    /// Pool settings for the ruvector-service telemetry clients
+    ///
+    /// Like [`PoolConfig::from_env`], but speaks HTTP/2 with prior knowledge
+    /// unless `RUVECTOR_HTTP_VERSION` selects another version (`http1` falls
+    /// back to HTTP/1.1).
+    pub fn telemetry_from_env() -> Self {
+        Self::from_env()
+            .with_http_version(HttpVersion::from_env("RUVECTOR_HTTP_VERSION", HttpVersion::Http2))
+    }
+
+    /// Set the maximum idle connections kept per host
+    pub fn with_max_idle_per_host(mut self, max: usize) -> Self {
+        self.max_idle_per_host = max;
+        self
+    }
+
+    /// Set how long an idle connection is kept
+    pub fn with_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.idle_timeout = timeout;
+        self
+    }
+
+    /// Set the HTTP version spoken on pooled connections
+    pub fn with_http_version(mut self, version: HttpVersion) -> Self {
+        self.http_version = version;
+        self
+    }
+
+    /// Apply the pool settings to a client builder
+    ///
+    /// HTTP/2 connections also send keep-alive pings, even while idle, so a
+    /// dead connection is noticed before the next request is sent on it.
+    pub fn apply(&self, builder: reqwest::ClientBuilder) -> reqwest::ClientBuilder {
+        let builder = builder
+            .pool_max_idle_per_host(self.max_idle_per_host)
+            .pool_idle_timeout(self.idle_timeout)
+            .tcp_keepalive(TCP_KEEPALIVE);
+        match self.http_version {
+            HttpVersion::Negotiate => builder,
+            HttpVersion::Http1 => builder.http1_only(),
+            HttpVersion::Http2 => builder
+                .http2_prior_knowledge()
+                .http2_keep_alive_interval(HTTP2_KEEPALIVE_INTERVAL)
+                .http2_keep_alive_timeout(HTTP2_KEEPALIVE_TIMEOUT)
+                .http2_keep_alive_while_idle(true)
+                .http2_adaptive_window(true),
+        }
+    }
+
+    /// Build a pooled client with no other settings
+    pub fn client(&self) -> reqwest::Client {
+        self.apply(reqwest::Client::builder())
+            .build()
+            .expect("Failed to create HTTP client")
+    }
+}
+
+/// Local HTTP servers that count the connections they accept
+#[cfg(test)]
+pub(crate) mod testing {
+    use std::convert::Infallible;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::{TcpListener, TcpStream};
+
+    /// Response sent to every request
+    const RESPONSE: &[u8] =
+        b"HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: 16\r\n\r\n{\"success\":true}";
+
+    /// Serve keep-alive HTTP/1.1 on localhost
+    ///
+    /// Returns the base URL and the number of connections accepted so far.
+    pub async fn counting_server() -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let connections = Arc::new(AtomicUsize::new(0));
+
+        let counter = connections.clone();
+        tokio::spawn(async move {
+            while let Ok((socket, _)) = listener.accept().await {
+                counter.fetch_add(1, Ordering::SeqCst);
+                tokio::spawn(serve_connection(socket));
+            }
+        });
+
+        (url, connections)
+    }
+
+    /// Serve HTTP/2 only (h2c with prior knowledge) on localhost
+    ///
+    /// Requests in any other HTTP version fail, so a successful response
+    /// shows the client spoke HTTP/2.
+    pub async fn counting_h2_server() -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let connections = Arc::new(AtomicUsize::new(0));
+
+        let counter = connections.clone();
+        tokio::spawn(async move {
+            while let Ok((socket, _)) = listener.accept().await {
+                counter.fetch_add(1, Ordering::SeqCst);
+                let service = hyper::service::service_fn(|_request| async {
+                    Ok::<_, Infallible>(
+                        hyper::Response::builder()
+                            .header("content-type", "application/json")
+                            .body(hyper::Body::from(r#"{"success":true}"#))
+                            .unwrap(),
+                    )
+                });
+                tokio::spawn(
+                    hyper::server::conn::Http::new()
+                        .http2_only(true)
+                        .serve_connection(socket, service),
+                );
+            }
+        });
+
+        (url, connections)
+    }
+
+    /// Local server that answers each request after a delay
+    pub struct SlowServer {
+        pub url: String,
+        /// Most requests that were in flight at once
+        pub peak_in_flight: Arc<AtomicUsize>,
+        /// Requests answered so far
+        pub served: Arc<AtomicUsize>,
+    }
+
+    impl SlowServer {
+        /// Wait until `count` requests have been answered
+        pub async fn wait_for(&self, count: usize) {
+            while self.served.load(Ordering::SeqCst) < count {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        }
+    }
+
+    /// Serve HTTP/1.1 and HTTP/2 on localhost, answering after `delay`
+    pub async fn slow_server(delay: Duration) -> SlowServer {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let server = SlowServer {
+            url: format!("http://{}", listener.local_addr().unwrap()),
+            peak_in_flight: Arc::new(AtomicUsize::new(0)),
+            served: Arc::new(AtomicUsize::new(0)),
+        };
+
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let peak = server.peak_in_flight.clone();
+        let served = server.served.clone();
+        tokio::spawn(async move {
+            while let Ok((socket, _)) = listener.accept().await {
+                let (in_flight, peak, served) = (in_flight.clone(), peak.clone(), served.clone());
+                let service = hyper::service::service_fn(move |_request| {
+                    let (in_flight, peak, served) = (in_flight.clone(), peak.clone(), served.clone());
+                    async move {
+                        let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                        peak.fetch_max(now, Ordering::SeqCst);
+                        tokio::time::sleep(delay).await;
+                        in_flight.fetch_sub(1, Ordering::SeqCst);
+                        served.fetch_add(1, Ordering::SeqCst);
+                        Ok::<_, Infallible>(hyper::Response::new(hyper::Body::from(
+                            r#"{"success":true}"#,
+                        )))
+                    }
+                });
+                tokio::spawn(hyper::server::conn::Http::new().serve_connection(socket, service));
+            }
+        });
+
+        server
+    }
+
+    /// Answer every request on a connection until the client closes it
+    async fn serve_connection(mut socket: TcpStream) {
+        let mut buffer = Vec::new();
+        let mut chunk = [0u8; 4096];
+        loop {
+            while let Some(len) = request_len(&buffer) {
+                buffer.drain(..len);
+                if socket.write_all(RESPONSE).await.is_err() {
+                    return;
+                }
+            }
+            match socket.read(&mut chunk).await {
+                Ok(0) | Err(_) => return,
+                Ok(n) => buffer.extend_from_slice(&chunk[..n]),
+            }
+        }
+    }
+
+    /// Length of the first complete request in `buffer`, if there is one
+    fn request_len(buffer: &[u8]) -> Option<usize> {
+        let head_end = buffer.windows(4).position(|w| w == b"\r\n\r\n")? + 4;
+        let head = String::from_utf8_lossy(&buffer[..head_end]).to_ascii_lowercase();
+        let body_len = head
+            .lines()
+            .find_map(|line| line.strip_prefix("content-length:"))
+            .and_then(|v| v.trim().parse::<usize>().ok())
+            .unwrap_or(0);
+        (buffer.len() >= head_end + body_len).then_some(head_end + body_len)
+    }
+}
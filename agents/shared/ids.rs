@@ -0,0 +1,73 @@
+//! Identifier source for the engines
+//!
+//! Included into each agent with `#[path]`. Request, event, and execution ids
+//! are drawn from an injected [`IdGenerator`] instead of `Uuid::new_v4()`
+//! directly. Production uses [`RandomIds`]; tests and replays use
+//! [`SeededIds`], which yields the same sequence for the same seed, so
+//! together with a [`MockClock`](super::clock::MockClock) two runs serialize
+//! to identical bytes.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Source of identifiers
+pub trait IdGenerator: Send + Sync + std::fmt::Debug {
+    /// The next identifier
+    fn next_id(&self) -> Uuid;
+}
+
+/// Random version 4 UUIDs
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RandomIds;
+
+impl IdGenerator for RandomIds {
+    fn next_id(&self) -> Uuid {
+        Uuid::new_v4()
+    }
+}
+
+/// Shared handle to the random generator
+pub fn random_ids() -> Arc<dyn IdGenerator> {
+    Arc::new(RandomIds)
+}
+
+/// Reproducible version 4 UUIDs derived from a seed
+///
+/// The n-th id depends only on the seed and n, so generators with the same
+/// seed produce the same sequence.
+#[derive(Debug)]
+pub struct SeededIds {
+    seed: u64,
+    next: AtomicU64,
+}
+
+impl SeededIds {
+    /// Generator starting at the beginning of `seed`'s sequence
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            next: AtomicU64::new(0),
+        }
+    }
+}
+
+/// SplitMix64 step, which spreads consecutive inputs over the output space
+fn splitmix64(state: u64) -> u64 {
+    let mut z = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+impl IdGenerator for SeededIds {
+    fn next_id(&self) -> Uuid {
+        let n = self.next.fetch_add(1, Ordering::Relaxed);
+        let high = splitmix64(self.seed ^ n.wrapping_mul(2));
+        let low = splitmix64(self.seed ^ n.wrapping_mul(2).wrapping_add(1));
+        let mut bytes = [0u8; 16];
+        bytes[..8].copy_from_slice(&high.to_be_bytes());
+        bytes[8..].copy_from_slice(&low.to_be_bytes());
+        uuid::Builder::from_random_bytes(bytes).into_uuid()
+    }
+}
@@ -0,0 +1,93 @@
+//! Request-shape validation for the agents' JSON endpoints
+//!
+//! Included into each agent's handler with `#[path]`. [`CheckedJson`] takes
+//! the place of axum's `Json` extractor for request bodies: the body is parsed
+//! and then deserialized through `serde_path_to_error`, so a malformed request
+//! is rejected before any processing with the path of the offending field
+//! (`adapters[0].adapter_type: unknown variant ...`) rather than a bare serde
+//! message.
+//!
+//! Each agent renders the [`RequestShapeError`] rejection in its own error
+//! envelope by implementing `IntoResponse` for it next to its `ApiError`.
+
+use axum::async_trait;
+use axum::body::Bytes;
+use axum::extract::{FromRequest, Request};
+use axum::http::header::CONTENT_TYPE;
+use serde::de::DeserializeOwned;
+use std::fmt;
+
+/// A request body that does not match the expected request shape
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestShapeError {
+    /// Path of the offending field, `None` when the body as a whole is wrong
+    pub field: Option<String>,
+    /// What is wrong with it
+    pub message: String,
+}
+
+impl RequestShapeError {
+    fn body(message: impl Into<String>) -> Self {
+        Self {
+            field: None,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for RequestShapeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.field {
+            Some(field) => write!(f, "{}: {}", field, self.message),
+            None => f.write_str(&self.message),
+        }
+    }
+}
+
+impl std::error::Error for RequestShapeError {}
+
+/// Deserialize a JSON request body, reporting the path of the first bad field
+pub fn parse_request<T: DeserializeOwned>(body: &[u8]) -> Result<T, RequestShapeError> {
+    let value: serde_json::Value = serde_json::from_slice(body)
+        .map_err(|e| RequestShapeError::body(format!("Invalid JSON: {}", e)))?;
+
+    serde_path_to_error::deserialize(value).map_err(|e| {
+        let path = e.path().to_string();
+        RequestShapeError {
+            // The root path renders as "."
+            field: (path != ".").then_some(path),
+            message: e.into_inner().to_string(),
+        }
+    })
+}
+
+/// JSON body extractor that validates the request shape before the handler runs
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CheckedJson<T>(pub T);
+
+#[async_trait]
+impl<T, S> FromRequest<S> for CheckedJson<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = RequestShapeError;
+
+    async fn from_request(request: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let is_json = request
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .is_some_and(|v| v.contains("application/json"));
+        if !is_json {
+            return Err(RequestShapeError::body(
+                "Content-Type must be application/json",
+            ));
+        }
+
+        let body = Bytes::from_request(request, state)
+            .await
+            .map_err(|e| RequestShapeError::body(e.body_text()))?;
+        parse_request(&body).map(CheckedJson)
+    }
+}
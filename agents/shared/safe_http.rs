@@ -0,0 +1,273 @@
+//! Guarded HTTP client for user-supplied URLs
+//!
+//! Included into each agent with `#[path]`; the including agent needs `hyper`
+//! with the `client` and `tcp` features for the resolver's name type. Any fetch whose target comes from
+//! a request or a configuration value (health paths, remote schemas, HTTP
+//! providers) goes through a client built with [`SafeHttp::apply`] instead of
+//! a bare `reqwest::Client`, so it cannot be pointed at internal services.
+//!
+//! Hostnames are resolved by [`SafeHttp`] itself, acting as the client's DNS
+//! resolver: every address a name resolves to is checked against the policy,
+//! and the connection is made to exactly those addresses. A name that resolves
+//! to a public address when checked and to a private one when dialed (DNS
+//! rebinding) therefore cannot slip through, because there is no second
+//! lookup. URLs with an IP literal never reach a resolver, so callers check
+//! them with [`SafeHttp::check_url`] before sending. Redirects are not
+//! followed, since a redirect to an IP literal would bypass the resolver too.
+//!
+//! The default policy refuses loopback, private, link-local (which covers the
+//! cloud metadata endpoints), carrier-grade NAT, unique-local, multicast and
+//! unspecified addresses, and the metadata service hostnames.
+
+use hyper::client::connect::dns::Name;
+use reqwest::dns::{Addrs, Resolve, Resolving};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::sync::Arc;
+
+/// Hostnames of cloud instance metadata services
+pub const METADATA_HOSTS: &[&str] = &["metadata.google.internal", "metadata.goog", "metadata"];
+
+/// Decides whether a host may be reached at an address
+pub type Policy = Arc<dyn Fn(&str, IpAddr) -> bool + Send + Sync>;
+
+/// Errors raised before a guarded request connects
+#[derive(Debug, thiserror::Error)]
+pub enum SafeHttpError {
+    /// The target is refused by the policy
+    #[error("{host} resolves to {ip}, which is not permitted")]
+    Refused { host: String, ip: IpAddr },
+
+    /// The hostname did not resolve
+    #[error("{host} could not be resolved: {reason}")]
+    Unresolved { host: String, reason: String },
+
+    /// The URL is not an http(s) URL with a host
+    #[error("invalid URL '{0}'")]
+    InvalidUrl(String),
+}
+
+/// Whether an address is link-local, which covers the metadata endpoints
+pub fn is_link_local(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(v4) => v4.is_link_local(),
+        IpAddr::V6(v6) => {
+            (v6.segments()[0] & 0xffc0) == 0xfe80
+                || v6.to_ipv4_mapped().is_some_and(|v4| v4.is_link_local())
+        }
+    }
+}
+
+fn is_restricted_v4(ip: Ipv4Addr) -> bool {
+    let [a, b, ..] = ip.octets();
+    ip.is_private()
+        || ip.is_loopback()
+        || ip.is_link_local()
+        || ip.is_unspecified()
+        || ip.is_broadcast()
+        || ip.is_multicast()
+        || a == 0
+        // Carrier-grade NAT, 100.64.0.0/10
+        || (a == 100 && (b & 0xc0) == 64)
+}
+
+fn is_restricted_v6(ip: Ipv6Addr) -> bool {
+    if let Some(v4) = ip.to_ipv4_mapped() {
+        return is_restricted_v4(v4);
+    }
+    let first = ip.segments()[0];
+    ip.is_loopback()
+        || ip.is_unspecified()
+        || ip.is_multicast()
+        // Unique local, fc00::/7
+        || (first & 0xfe00) == 0xfc00
+        // Link local, fe80::/10
+        || (first & 0xffc0) == 0xfe80
+}
+
+/// Whether an address is internal and refused by the default policy
+pub fn is_restricted(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(v4) => is_restricted_v4(v4),
+        IpAddr::V6(v6) => is_restricted_v6(v6),
+    }
+}
+
+/// Resolver and checks applied to guarded clients
+#[derive(Clone)]
+pub struct SafeHttp {
+    policy: Policy,
+}
+
+impl Default for SafeHttp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Debug for SafeHttp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SafeHttp").finish_non_exhaustive()
+    }
+}
+
+impl SafeHttp {
+    /// Guard with the default policy
+    pub fn new() -> Self {
+        Self::with_policy(|host, ip| {
+            !METADATA_HOSTS.contains(&host.to_ascii_lowercase().as_str()) && !is_restricted(ip)
+        })
+    }
+
+    /// Guard deciding with `policy`, called with the hostname and each address
+    pub fn with_policy(policy: impl Fn(&str, IpAddr) -> bool + Send + Sync + 'static) -> Self {
+        Self {
+            policy: Arc::new(policy),
+        }
+    }
+
+    /// Whether `host` may be reached at `ip`
+    pub fn permits(&self, host: &str, ip: IpAddr) -> bool {
+        (self.policy)(host, ip)
+    }
+
+    /// Resolve `host` and check every address it resolves to
+    pub async fn resolve(&self, host: &str, port: u16) -> Result<Vec<SocketAddr>, SafeHttpError> {
+        let addrs: Vec<SocketAddr> = match host.parse::<IpAddr>() {
+            Ok(ip) => vec![SocketAddr::new(ip, port)],
+            Err(_) => tokio::net::lookup_host((host, port))
+                .await
+                .map_err(|e| SafeHttpError::Unresolved {
+                    host: host.to_string(),
+                    reason: e.to_string(),
+                })?
+                .collect(),
+        };
+        if addrs.is_empty() {
+            return Err(SafeHttpError::Unresolved {
+                host: host.to_string(),
+                reason: "no addresses".to_string(),
+            });
+        }
+        // One refused address refuses the name, rather than silently dialing the rest
+        match addrs.iter().find(|addr| !self.permits(host, addr.ip())) {
+            Some(addr) => Err(SafeHttpError::Refused {
+                host: host.to_string(),
+                ip: addr.ip(),
+            }),
+            None => Ok(addrs),
+        }
+    }
+
+    /// Check a URL whose host is an IP literal before sending to it
+    ///
+    /// Hostnames pass here; they are checked by the resolver when the client
+    /// connects.
+    pub fn check_url(&self, url: &str) -> Result<(), SafeHttpError> {
+        let parsed =
+            reqwest::Url::parse(url).map_err(|_| SafeHttpError::InvalidUrl(url.to_string()))?;
+        let host = parsed
+            .host_str()
+            .ok_or_else(|| SafeHttpError::InvalidUrl(url.to_string()))?;
+        let Ok(ip) = host
+            .trim_start_matches('[')
+            .trim_end_matches(']')
+            .parse::<IpAddr>()
+        else {
+            return Ok(());
+        };
+        if self.permits(&ip.to_string(), ip) {
+            Ok(())
+        } else {
+            Err(SafeHttpError::Refused {
+                host: ip.to_string(),
+                ip,
+            })
+        }
+    }
+
+    /// Route a client's name resolution through the guard and stop redirects
+    pub fn apply(&self, builder: reqwest::ClientBuilder) -> reqwest::ClientBuilder {
+        builder
+            .dns_resolver(Arc::new(self.clone()))
+            .redirect(reqwest::redirect::Policy::none())
+    }
+}
+
+impl Resolve for SafeHttp {
+    fn resolve(&self, name: Name) -> Resolving {
+        let guard = self.clone();
+        Box::pin(async move {
+            let addrs = guard.resolve(name.as_str(), 0).await?;
+            Ok(Box::new(addrs.into_iter()) as Addrs)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_restricted_addresses() {
+        for ip in [
+            "127.0.0.1",
+            "10.1.2.3",
+            "172.16.0.1",
+            "192.168.1.1",
+            "169.254.169.254",
+            "100.64.0.1",
+            "::1",
+            "fd00:ec2::254",
+            "::ffff:10.0.0.1",
+        ] {
+            assert!(
+                is_restricted(ip.parse().unwrap()),
+                "{} should be restricted",
+                ip
+            );
+        }
+        for ip in ["93.184.216.34", "2606:4700::1111"] {
+            assert!(
+                !is_restricted(ip.parse().unwrap()),
+                "{} should be permitted",
+                ip
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_url_resolving_to_private_address_is_refused() {
+        let (url, connections) = crate::http_pool::testing::counting_server().await;
+        let guard = SafeHttp::new();
+
+        // IP literals are refused before sending
+        assert!(matches!(
+            guard.check_url(&url),
+            Err(SafeHttpError::Refused { .. })
+        ));
+
+        // Names are refused by the resolver, before any connection is made
+        let port = url.rsplit(':').next().unwrap();
+        let client = guard.apply(reqwest::Client::builder()).build().unwrap();
+        let err = client
+            .get(format!("http://localhost:{}/", port))
+            .send()
+            .await
+            .unwrap_err();
+        assert!(err.is_connect(), "unexpected error: {}", err);
+        assert_eq!(connections.load(std::sync::atomic::Ordering::SeqCst), 0);
+
+        // A policy may permit what the default refuses
+        let permissive = SafeHttp::with_policy(|_, _| true);
+        assert!(permissive.check_url(&url).is_ok());
+        let client = permissive
+            .apply(reqwest::Client::builder())
+            .build()
+            .unwrap();
+        let response = client
+            .get(format!("http://localhost:{}/", port))
+            .send()
+            .await;
+        assert!(response.unwrap().status().is_success());
+    }
+}
@@ -0,0 +1,41 @@
+//! Constant-time comparison for credentials
+//!
+//! Included into each agent with `#[path]`. Tokens, API keys and other
+//! secrets must be compared with [`secure_eq`] rather than `==`, which stops
+//! at the first differing byte and so leaks how much of a guess was right.
+
+use std::hint::black_box;
+
+/// Whether two secrets are equal, in time independent of their contents
+///
+/// Only the lengths are compared early, so the time taken reveals whether the
+/// lengths match but nothing about where equal-length inputs differ.
+pub fn secure_eq(a: impl AsRef<[u8]>, b: impl AsRef<[u8]>) -> bool {
+    let (a, b) = (a.as_ref(), b.as_ref());
+    if a.len() != b.len() {
+        return false;
+    }
+    let diff = a
+        .iter()
+        .zip(b)
+        .fold(0u8, |acc, (x, y)| black_box(acc | (x ^ y)));
+    black_box(diff) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_secure_eq() {
+        assert!(secure_eq("s3cr3t-token", "s3cr3t-token"));
+        assert!(secure_eq("", ""));
+        assert!(secure_eq(b"\x00\xff".as_slice(), vec![0u8, 255]));
+
+        assert!(!secure_eq("s3cr3t-token", "s3cr3t-tokeN"));
+        assert!(!secure_eq("s3cr3t-token", "x3cr3t-token"));
+        assert!(!secure_eq("s3cr3t-token", "s3cr3t-toke"));
+        assert!(!secure_eq("s3cr3t", "s3cr3t-token"));
+        assert!(!secure_eq("", "a"));
+    }
+}
@@ -0,0 +1,56 @@
+//! Finding severity shared by the agent contracts
+//!
+//! Included into each agent's `contracts` module with `#[path]` so engine
+//! findings, contract issues and CLI output rank and name severities the same
+//! way, and `Critical` survives every conversion between them.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// How serious a finding is
+///
+/// Severities order from least to most severe, so thresholds compare with
+/// `>=`. Errors and critical findings block.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// Informational - no action required
+    Info,
+    /// Should be addressed but does not block
+    Warning,
+    /// Must be fixed before deployment
+    #[default]
+    Error,
+    /// Security or stability risk
+    Critical,
+}
+
+impl Severity {
+    /// Get the string representation
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Info => "info",
+            Self::Warning => "warning",
+            Self::Error => "error",
+            Self::Critical => "critical",
+        }
+    }
+
+    /// Whether findings of this severity block (error or critical)
+    pub fn is_blocking(&self) -> bool {
+        *self >= Self::Error
+    }
+
+    /// Whether this severity is `threshold` or more severe
+    pub fn is_at_least(&self, threshold: Severity) -> bool {
+        *self >= threshold
+    }
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -0,0 +1,101 @@
+//! Decision verdicts shared by the agent contracts
+//!
+//! Included into each agent's `contracts` module with `#[path]` so every
+//! agent maps an outcome to a verdict the same way.
+
+use serde::{Deserialize, Serialize};
+
+/// What a decision means for the configuration or integration it covers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DecisionVerdict {
+    /// No issues that need attention
+    Allow,
+    /// Allowed, but with issues that should be addressed
+    AllowWithWarnings,
+    /// Issues exceed the thresholds; do not proceed
+    Block,
+}
+
+impl DecisionVerdict {
+    /// Get the string representation
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Allow => "allow",
+            Self::AllowWithWarnings => "allow_with_warnings",
+            Self::Block => "block",
+        }
+    }
+
+    /// Whether the outcome should stop the change
+    pub fn is_blocking(&self) -> bool {
+        matches!(self, Self::Block)
+    }
+}
+
+impl std::fmt::Display for DecisionVerdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Issue counts an outcome is judged by
+///
+/// Informational issues never affect the verdict, so they are not counted.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct IssueCounts {
+    pub critical: u32,
+    pub errors: u32,
+    pub warnings: u32,
+}
+
+/// Thresholds for mapping issue counts to a [`DecisionVerdict`]
+///
+/// - **Block** when any count is above its maximum.
+/// - **AllowWithWarnings** when nothing is above its maximum but there is at
+///   least one critical issue, error, or warning (tolerated errors included).
+/// - **Allow** when there are none.
+///
+/// The defaults block on any critical issue or error and never block on
+/// warnings.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct VerdictThresholds {
+    /// Critical issues tolerated before blocking
+    pub max_critical: u32,
+    /// Errors tolerated before blocking
+    pub max_errors: u32,
+    /// Warnings tolerated before blocking (`None` never blocks on warnings)
+    pub max_warnings: Option<u32>,
+}
+
+impl VerdictThresholds {
+    /// Set the number of critical issues tolerated before blocking
+    pub fn with_max_critical(mut self, max: u32) -> Self {
+        self.max_critical = max;
+        self
+    }
+
+    /// Set the number of errors tolerated before blocking
+    pub fn with_max_errors(mut self, max: u32) -> Self {
+        self.max_errors = max;
+        self
+    }
+
+    /// Block once more than `max` warnings are reported
+    pub fn with_max_warnings(mut self, max: u32) -> Self {
+        self.max_warnings = Some(max);
+        self
+    }
+
+    /// Map issue counts to a verdict
+    pub fn verdict(&self, counts: IssueCounts) -> DecisionVerdict {
+        let over_warnings = self.max_warnings.is_some_and(|max| counts.warnings > max);
+        if counts.critical > self.max_critical || counts.errors > self.max_errors || over_warnings {
+            DecisionVerdict::Block
+        } else if counts.critical + counts.errors + counts.warnings > 0 {
+            DecisionVerdict::AllowWithWarnings
+        } else {
+            DecisionVerdict::Allow
+        }
+    }
+}
@@ -0,0 +1,55 @@
+//! Bounded worker pool for the telemetry background tasks
+//!
+//! Included into each agent's `telemetry` module with `#[path]`. A fixed
+//! number of workers drain one queue concurrently, so a slow ruvector-service
+//! response holds up only the worker waiting on it instead of every queued
+//! emission behind it.
+//!
+//! Items are handed out in queue order, but with more than one worker they
+//! may finish out of order. Telemetry events are independent records with
+//! their own timestamps, so no ordering is kept between them.
+
+use std::future::Future;
+use std::sync::Arc;
+use tokio::sync::{mpsc, Mutex};
+
+/// Workers used when none are configured
+pub const DEFAULT_WORKERS: usize = 4;
+
+/// Read the worker count from `TELEMETRY_WORKERS`
+///
+/// Unset, unparsable, or zero values fall back to [`DEFAULT_WORKERS`].
+pub fn workers_from_env() -> usize {
+    std::env::var("TELEMETRY_WORKERS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .filter(|&n: &usize| n > 0)
+        .unwrap_or(DEFAULT_WORKERS)
+}
+
+/// Spawn `workers` tasks that run `handler` on each item from `receiver`
+///
+/// At least one worker is always spawned. The workers exit once the channel
+/// is closed and drained.
+pub fn spawn_workers<T, F, Fut>(receiver: mpsc::Receiver<T>, workers: usize, handler: F)
+where
+    T: Send + 'static,
+    F: Fn(T) -> Fut + Clone + Send + 'static,
+    Fut: Future<Output = ()> + Send,
+{
+    let receiver = Arc::new(Mutex::new(receiver));
+    for _ in 0..workers.max(1) {
+        let receiver = Arc::clone(&receiver);
+        let handler = handler.clone();
+        tokio::spawn(async move {
+            loop {
+                // Only hold the lock while waiting for the next item
+                let item = receiver.lock().await.recv().await;
+                match item {
+                    Some(item) => handler(item).await,
+                    None => break,
+                }
+            }
+        });
+    }
+}
@@ -7,6 +7,11 @@ use llm_config_core::benchmarks;
 use llm_config_crypto::{Algorithm, SecretKey};
 use std::path::PathBuf;
 
+// Lets `llm-config run` report allocation counts in benchmark memory metrics
+#[cfg(feature = "bench-alloc")]
+#[global_allocator]
+static GLOBAL: benchmarks::CountingAllocator = benchmarks::CountingAllocator;
+
 #[derive(Parser)]
 #[command(name = "llm-config")]
 #[command(about = "LLM Configuration Manager - Secure configuration and secrets management", long_about = None)]
@@ -161,6 +166,14 @@ enum Commands {
         /// Output format (json, table)
         #[arg(short, long, value_enum, default_value = "table")]
         format: BenchOutputFormat,
+
+        /// Seed fixtures and report the median of fixed rounds for comparable results
+        #[arg(long)]
+        deterministic: bool,
+
+        /// Fixture seed used with --deterministic
+        #[arg(long, requires = "deterministic")]
+        seed: Option<u64>,
     },
 }
 
@@ -419,7 +432,19 @@ fn run(cli: Cli) -> anyhow::Result<()> {
             list,
             output,
             format,
+            deterministic,
+            seed,
         } => {
+            let options = if deterministic {
+                let options = benchmarks::BenchOptions::deterministic();
+                match seed {
+                    Some(seed) => options.with_seed(seed),
+                    None => options,
+                }
+            } else {
+                benchmarks::BenchOptions::default()
+            };
+
             if list {
                 // List available benchmarks
                 println!("{}", "Available Benchmarks:".green().bold());
@@ -435,7 +460,7 @@ fn run(cli: Cli) -> anyhow::Result<()> {
             let results = if let Some(target_id) = target {
                 // Run specific benchmark
                 println!("{}", format!("Running benchmark: {}", target_id).green().bold());
-                match benchmarks::run_benchmark(&target_id) {
+                match benchmarks::run_benchmark_with(&target_id, options) {
                     Some(result) => vec![result],
                     None => {
                         println!("{}", format!("Benchmark '{}' not found", target_id).red());
@@ -445,15 +470,15 @@ fn run(cli: Cli) -> anyhow::Result<()> {
             } else if let Some(cat) = category {
                 // Run benchmarks by category
                 println!("{}", format!("Running {} benchmarks...", cat).green().bold());
-                benchmarks::run_benchmarks_by_category(&cat)
+                benchmarks::run_benchmarks_by_category_with(&cat, options)
             } else if all {
                 // Run all benchmarks
                 println!("{}", "Running all benchmarks...".green().bold());
-                benchmarks::run_all_benchmarks()
+                benchmarks::run_all_benchmarks_with(options)
             } else {
                 // Default: run all
                 println!("{}", "Running all benchmarks...".green().bold());
-                benchmarks::run_all_benchmarks()
+                benchmarks::run_all_benchmarks_with(options)
             };
 
             if results.is_empty() {
@@ -492,6 +517,9 @@ fn run(cli: Cli) -> anyhow::Result<()> {
                 }
             }
 
+            // Keep the previous summary to compare the new run against
+            let previous_summary = std::fs::read_to_string(benchmarks::io::summary_file(&output)).ok();
+
             // Save results to output directory
             if let Err(e) = benchmarks::io::write_benchmark_run(&output, &results) {
                 eprintln!("{} Failed to write results: {}", "Warning:".yellow(), e);
@@ -503,6 +531,13 @@ fn run(cli: Cli) -> anyhow::Result<()> {
             if let Err(e) = benchmarks::markdown::update_summary(&output) {
                 eprintln!("{} Failed to update summary: {}", "Warning:".yellow(), e);
             }
+
+            // Compare against the previous summary
+            if let Some(previous) = previous_summary {
+                if let Err(e) = benchmarks::markdown::write_comparison(&output, &previous, &results) {
+                    eprintln!("{} Failed to write comparison: {}", "Warning:".yellow(), e);
+                }
+            }
         }
     }
 
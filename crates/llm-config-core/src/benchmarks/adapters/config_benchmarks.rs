@@ -4,6 +4,8 @@
 //! get, set, list, merge, and environment override resolution.
 
 use super::BenchTarget;
+use crate::benchmarks::memory::MemoryProbe;
+use crate::benchmarks::mode::{fixture_bytes, fixture_key, fixture_values};
 use crate::benchmarks::result::BenchmarkResult;
 use crate::{ConfigManager, ConfigValue, Environment};
 use std::time::Instant;
@@ -109,29 +111,34 @@ impl BenchTarget for ConfigSetBenchmark {
         let temp_dir = TempDir::new().expect("Failed to create temp dir");
         let manager = ConfigManager::new(temp_dir.path()).expect("Failed to create manager");
 
+        let warmup_values = fixture_values(self.warmup_iterations() as usize, 16);
+        let values = fixture_values(self.iterations as usize, 16);
+
         // Warmup
-        for i in 0..self.warmup_iterations() {
+        for (i, value) in warmup_values.into_iter().enumerate() {
             let _ = manager.set(
                 "warmup/ns",
                 format!("key_{}", i),
-                ConfigValue::String(format!("value_{}", i)),
+                ConfigValue::String(value),
                 Environment::Development,
                 "benchmark",
             );
         }
 
         // Measure
+        let probe = MemoryProbe::start();
         let start = Instant::now();
-        for i in 0..self.iterations {
+        for (i, value) in values.into_iter().enumerate() {
             let _ = manager.set(
                 "bench/ns",
                 format!("key_{}", i),
-                ConfigValue::String(format!("value_{}", i)),
+                ConfigValue::String(value),
                 Environment::Development,
                 "benchmark",
             );
         }
         let duration = start.elapsed();
+        let memory = probe.finish();
 
         BenchmarkResult::throughput(
             self.id(),
@@ -139,6 +146,7 @@ impl BenchTarget for ConfigSetBenchmark {
             self.iterations as u64,
         )
         .with_metric("operation", serde_json::json!("set"))
+        .with_memory(memory)
     }
 }
 
@@ -181,11 +189,11 @@ impl BenchTarget for ConfigListBenchmark {
         let manager = ConfigManager::new(temp_dir.path()).expect("Failed to create manager");
 
         // Setup: create multiple entries
-        for i in 0..self.entry_count {
+        for (i, value) in fixture_values(self.entry_count as usize, 16).into_iter().enumerate() {
             let _ = manager.set(
                 "bench/ns",
                 format!("key_{}", i),
-                ConfigValue::String(format!("value_{}", i)),
+                ConfigValue::String(value),
                 Environment::Development,
                 "benchmark",
             );
@@ -389,22 +397,20 @@ impl BenchTarget for SecretSetBenchmark {
     }
 
     fn run(&self) -> BenchmarkResult {
-        use llm_config_crypto::{Algorithm, SecretKey};
-
         let temp_dir = TempDir::new().expect("Failed to create temp dir");
-        let key = SecretKey::generate(Algorithm::Aes256Gcm).expect("Failed to generate key");
+        let key = fixture_key();
         let manager = ConfigManager::new(temp_dir.path())
             .expect("Failed to create manager")
             .with_encryption_key(key);
 
-        let secret_data = b"super-secret-password-12345";
+        let secret_data = fixture_bytes(27);
 
         // Warmup
         for i in 0..self.warmup_iterations() {
             let _ = manager.set_secret(
                 "warmup/ns",
                 format!("secret_{}", i),
-                secret_data,
+                &secret_data,
                 Environment::Production,
                 "benchmark",
             );
@@ -416,7 +422,7 @@ impl BenchTarget for SecretSetBenchmark {
             let _ = manager.set_secret(
                 "bench/ns",
                 format!("secret_{}", i),
-                secret_data,
+                &secret_data,
                 Environment::Production,
                 "benchmark",
             );
@@ -464,21 +470,19 @@ impl BenchTarget for SecretGetBenchmark {
     }
 
     fn run(&self) -> BenchmarkResult {
-        use llm_config_crypto::{Algorithm, SecretKey};
-
         let temp_dir = TempDir::new().expect("Failed to create temp dir");
-        let key = SecretKey::generate(Algorithm::Aes256Gcm).expect("Failed to generate key");
+        let key = fixture_key();
         let manager = ConfigManager::new(temp_dir.path())
             .expect("Failed to create manager")
             .with_encryption_key(key);
 
         // Setup: create a secret
-        let secret_data = b"super-secret-password-12345";
+        let secret_data = fixture_bytes(27);
         manager
             .set_secret(
                 "bench/ns",
                 "test_secret",
-                secret_data,
+                &secret_data,
                 Environment::Production,
                 "benchmark",
             )
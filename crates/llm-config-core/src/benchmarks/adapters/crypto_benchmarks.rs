@@ -3,8 +3,10 @@
 //! Benchmark targets for cryptographic operations including
 //! encryption, decryption, and key generation.
 
-use super::BenchTarget;
+use super::{block_on_private_runtime, BenchTarget};
+use crate::benchmarks::mode::{fixture_bytes, fixture_key};
 use crate::benchmarks::result::BenchmarkResult;
+use llm_config_crypto::SecretKey;
 use std::time::Instant;
 
 /// Benchmark for encryption operations
@@ -42,10 +44,10 @@ impl BenchTarget for EncryptBenchmark {
     }
 
     fn run(&self) -> BenchmarkResult {
-        use llm_config_crypto::{encrypt, Algorithm, SecretKey};
+        use llm_config_crypto::encrypt;
 
-        let key = SecretKey::generate(Algorithm::Aes256Gcm).expect("Failed to generate key");
-        let plaintext: Vec<u8> = (0..self.payload_size).map(|i| (i % 256) as u8).collect();
+        let key = fixture_key();
+        let plaintext = fixture_bytes(self.payload_size);
 
         // Warmup
         for _ in 0..self.warmup_iterations() {
@@ -113,10 +115,10 @@ impl BenchTarget for DecryptBenchmark {
     }
 
     fn run(&self) -> BenchmarkResult {
-        use llm_config_crypto::{decrypt, encrypt, Algorithm, SecretKey};
+        use llm_config_crypto::{decrypt, encrypt};
 
-        let key = SecretKey::generate(Algorithm::Aes256Gcm).expect("Failed to generate key");
-        let plaintext: Vec<u8> = (0..self.payload_size).map(|i| (i % 256) as u8).collect();
+        let key = fixture_key();
+        let plaintext = fixture_bytes(self.payload_size);
 
         // Create encrypted data
         let encrypted = encrypt(&key, &plaintext, None).expect("Failed to encrypt");
@@ -183,7 +185,7 @@ impl BenchTarget for KeyGenerationBenchmark {
     }
 
     fn run(&self) -> BenchmarkResult {
-        use llm_config_crypto::{Algorithm, SecretKey};
+        use llm_config_crypto::Algorithm;
 
         // Warmup
         for _ in 0..self.warmup_iterations() {
@@ -207,3 +209,96 @@ impl BenchTarget for KeyGenerationBenchmark {
         .with_metric("key_size_bits", serde_json::json!(256))
     }
 }
+
+/// Benchmark for the encrypted-file provider round trip
+///
+/// Writes an encrypted config file once, then times a full reload
+/// (read, decrypt, parse) followed by a `get` on every iteration. This is the
+/// cost a user pays when the provider picks up a changed file, rather than
+/// the bare cipher primitive.
+pub struct EncryptedProviderRoundTripBenchmark {
+    iterations: u32,
+    secret_count: usize,
+}
+
+impl EncryptedProviderRoundTripBenchmark {
+    pub fn new() -> Self {
+        Self {
+            iterations: 100,
+            secret_count: 50,
+        }
+    }
+
+    async fn measure(&self, path: &std::path::Path, key: SecretKey) -> (std::time::Duration, u64) {
+        use crate::providers::{ConfigProvider, EncryptedFileProvider, SecretProvider};
+
+        let writer = EncryptedFileProvider::create(path, key.clone())
+            .expect("Failed to create encrypted file")
+            .with_auto_save(false);
+        for i in 0..self.secret_count {
+            writer
+                .set_secret("bench", &format!("secret_{}", i), &format!("value_{}", i))
+                .await
+                .expect("Failed to set secret");
+        }
+        writer.save().expect("Failed to save encrypted file");
+
+        let provider = EncryptedFileProvider::new(path, key).expect("Failed to open encrypted file");
+        let encrypted_bytes = std::fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+
+        // Warmup
+        for _ in 0..self.warmup_iterations() {
+            let _ = provider.refresh().await;
+            let _ = provider.get("bench", "secret_0").await;
+        }
+
+        // Measure: refresh forces a decrypt-and-parse before each get
+        let start = Instant::now();
+        for _ in 0..self.iterations {
+            let _ = provider.refresh().await;
+            let _ = provider.get("bench", "secret_0").await;
+        }
+        let duration = start.elapsed();
+
+        (duration, encrypted_bytes)
+    }
+}
+
+impl Default for EncryptedProviderRoundTripBenchmark {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BenchTarget for EncryptedProviderRoundTripBenchmark {
+    fn id(&self) -> &str {
+        "crypto_encrypted_provider_roundtrip"
+    }
+
+    fn description(&self) -> &str {
+        "Measures encrypted-file provider decrypt-and-parse plus get for 50 secrets"
+    }
+
+    fn category(&self) -> &str {
+        "crypto"
+    }
+
+    fn run(&self) -> BenchmarkResult {
+        // The temp dir (and the encrypted file inside it) is removed on drop
+        let temp_dir = tempfile::TempDir::new().expect("Failed to create temp dir");
+        let path = temp_dir.path().join("bench.enc");
+        let key = fixture_key();
+
+        let (duration, encrypted_bytes) = block_on_private_runtime(|| self.measure(&path, key));
+
+        BenchmarkResult::throughput(
+            self.id(),
+            duration.as_nanos(),
+            self.iterations as u64,
+        )
+        .with_metric("operation", serde_json::json!("encrypted_provider_roundtrip"))
+        .with_metric("algorithm", serde_json::json!("AES-256-GCM"))
+        .with_metric("secret_count", serde_json::json!(self.secret_count))
+        .with_metric("file_size_bytes", serde_json::json!(encrypted_bytes))
+    }
+}
@@ -8,10 +8,12 @@
 
 mod config_benchmarks;
 mod crypto_benchmarks;
+mod provider_benchmarks;
 mod storage_benchmarks;
 
 pub use config_benchmarks::*;
 pub use crypto_benchmarks::*;
+pub use provider_benchmarks::*;
 pub use storage_benchmarks::*;
 
 use super::result::BenchmarkResult;
@@ -58,6 +60,29 @@ pub trait BenchTarget: Send + Sync {
     }
 }
 
+/// Run an async measurement to completion on a private runtime.
+///
+/// The runtime lives on a scoped thread, so targets that drive async
+/// providers can run from inside or outside an existing Tokio runtime.
+pub(crate) fn block_on_private_runtime<F, Fut>(measure: F) -> Fut::Output
+where
+    F: FnOnce() -> Fut + Send,
+    Fut: std::future::Future,
+    Fut::Output: Send,
+{
+    std::thread::scope(|scope| {
+        scope
+            .spawn(|| {
+                tokio::runtime::Builder::new_current_thread()
+                    .build()
+                    .expect("Failed to build runtime")
+                    .block_on(measure())
+            })
+            .join()
+            .expect("Benchmark thread panicked")
+    })
+}
+
 /// Registry of all benchmark targets.
 ///
 /// Returns a vector of boxed trait objects implementing BenchTarget.
@@ -81,11 +106,15 @@ pub fn all_targets() -> Vec<Box<dyn BenchTarget>> {
         Box::new(EncryptBenchmark::new()),
         Box::new(DecryptBenchmark::new()),
         Box::new(KeyGenerationBenchmark::new()),
+        Box::new(EncryptedProviderRoundTripBenchmark::new()),
 
         // Storage benchmarks
         Box::new(StorageWriteBenchmark::new()),
         Box::new(StorageReadBenchmark::new()),
         Box::new(StorageListBenchmark::new()),
+
+        // Provider benchmarks
+        Box::new(ProviderChainResolveBenchmark::new()),
     ]
 }
 
@@ -160,5 +189,53 @@ mod tests {
     fn test_list_categories() {
         let categories = list_categories();
         assert!(!categories.is_empty());
+        assert!(categories.contains(&"providers".to_string()));
+    }
+
+    #[test]
+    fn test_crypto_targets_by_category() {
+        let targets = targets_by_category("crypto");
+        let ids: Vec<_> = targets.iter().map(|t| t.id()).collect();
+        assert!(ids.contains(&"crypto_encrypted_provider_roundtrip"));
+    }
+
+    #[test]
+    fn test_encrypted_provider_roundtrip_metrics() {
+        let result = get_target("crypto_encrypted_provider_roundtrip").unwrap().run();
+        assert!(result.throughput_ops_per_sec().is_some());
+        assert!(result.metrics["file_size_bytes"].as_u64().unwrap() > 0);
+    }
+
+    #[test]
+    fn test_provider_targets_by_category() {
+        let targets = targets_by_category("providers");
+        let ids: Vec<_> = targets.iter().map(|t| t.id()).collect();
+        assert_eq!(ids, vec!["provider_chain_resolve"]);
+    }
+
+    #[test]
+    fn test_config_set_memory_metric() {
+        let result = get_target("config_set").unwrap().run();
+        let memory = &result.metrics["memory"];
+        assert!(memory.is_object());
+
+        if cfg!(feature = "bench-alloc") {
+            assert!(memory["allocations"].as_u64().unwrap() > 0);
+            assert!(memory["allocated_bytes"].as_u64().unwrap() > 0);
+        }
+        if cfg!(target_os = "linux") {
+            assert!(memory["peak_rss_bytes"].as_u64().unwrap() > 0);
+        }
+    }
+
+    #[test]
+    fn test_provider_chain_resolve_metrics() {
+        let result = get_target("provider_chain_resolve").unwrap().run();
+        assert_eq!(result.target_id, "provider_chain_resolve");
+        assert!(result.duration_ns().is_some());
+        assert!(result.throughput_ops_per_sec().is_some());
+        assert_eq!(result.metrics["providers"], 3);
+        assert!(result.metrics["latency_ns"]["p99"].is_u64());
+        assert!(result.metrics["list"]["latency_ns"]["p50"].is_u64());
     }
 }
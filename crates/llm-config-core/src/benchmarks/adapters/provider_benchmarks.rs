@@ -0,0 +1,143 @@
+//! Provider benchmarks
+//!
+//! Benchmark targets for external configuration providers, covering the
+//! provider chain lookup and merge hot paths.
+
+use super::{block_on_private_runtime, BenchTarget};
+use crate::benchmarks::result::BenchmarkResult;
+use crate::providers::{ConfigProvider, JsonProvider, ProviderChain};
+use std::time::{Duration, Instant};
+
+/// Latency percentiles (in nanoseconds) for a set of samples
+fn latency_metrics(samples: &mut [Duration]) -> serde_json::Value {
+    samples.sort_unstable();
+    let percentile = |p: f64| -> u128 {
+        if samples.is_empty() {
+            return 0;
+        }
+        let index = ((samples.len() - 1) as f64 * p).round() as usize;
+        samples[index].as_nanos()
+    };
+
+    serde_json::json!({
+        "p50": percentile(0.50),
+        "p95": percentile(0.95),
+        "p99": percentile(0.99),
+        "max": samples.last().map(|d| d.as_nanos()).unwrap_or(0),
+    })
+}
+
+/// Benchmark for provider chain resolution
+///
+/// Builds a chain of three in-memory JSON providers and measures `get` for a
+/// key only the lowest-priority provider holds (so every provider is
+/// consulted), plus `list`, which merges the namespace across all providers.
+pub struct ProviderChainResolveBenchmark {
+    iterations: u32,
+    keys_per_provider: usize,
+}
+
+impl ProviderChainResolveBenchmark {
+    pub fn new() -> Self {
+        Self {
+            iterations: 100,
+            keys_per_provider: 50,
+        }
+    }
+
+    fn build_chain(&self) -> ProviderChain {
+        let mut chain = ProviderChain::new();
+        for layer in ["override", "environment", "base"] {
+            let mut keys = serde_json::Map::new();
+            for i in 0..self.keys_per_provider {
+                keys.insert(format!("{}_key_{}", layer, i), serde_json::json!(i));
+                keys.insert(format!("shared_key_{}", i), serde_json::json!(layer));
+            }
+            let document = serde_json::json!({ "bench": keys });
+            let provider = JsonProvider::from_string(&document.to_string())
+                .expect("Failed to build JSON provider");
+            chain.add_provider(provider);
+        }
+        chain
+    }
+
+    async fn measure(&self, chain: &ProviderChain) -> (Duration, Vec<Duration>, Duration, Vec<Duration>) {
+        // Warmup
+        for _ in 0..self.warmup_iterations() {
+            let _ = chain.get("bench", "base_key_0").await;
+            let _ = chain.list("bench", None).await;
+        }
+
+        let mut get_samples = Vec::with_capacity(self.iterations as usize);
+        let get_start = Instant::now();
+        for _ in 0..self.iterations {
+            let op_start = Instant::now();
+            let _ = chain.get("bench", "base_key_0").await;
+            get_samples.push(op_start.elapsed());
+        }
+        let get_duration = get_start.elapsed();
+
+        let mut list_samples = Vec::with_capacity(self.iterations as usize);
+        let list_start = Instant::now();
+        for _ in 0..self.iterations {
+            let op_start = Instant::now();
+            let _ = chain.list("bench", None).await;
+            list_samples.push(op_start.elapsed());
+        }
+        let list_duration = list_start.elapsed();
+
+        (get_duration, get_samples, list_duration, list_samples)
+    }
+}
+
+impl Default for ProviderChainResolveBenchmark {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BenchTarget for ProviderChainResolveBenchmark {
+    fn id(&self) -> &str {
+        "provider_chain_resolve"
+    }
+
+    fn description(&self) -> &str {
+        "Measures provider chain get fallthrough and list latency across 3 providers"
+    }
+
+    fn category(&self) -> &str {
+        "providers"
+    }
+
+    fn run(&self) -> BenchmarkResult {
+        let chain = self.build_chain();
+
+        let (get_duration, mut get_samples, list_duration, mut list_samples) =
+            block_on_private_runtime(|| self.measure(&chain));
+
+        let list_ops_per_sec = if list_duration.as_secs_f64() > 0.0 {
+            self.iterations as f64 / list_duration.as_secs_f64()
+        } else {
+            0.0
+        };
+
+        BenchmarkResult::throughput(
+            self.id(),
+            get_duration.as_nanos(),
+            self.iterations as u64,
+        )
+        .with_metric("operation", serde_json::json!("chain_get"))
+        .with_metric("providers", serde_json::json!(chain.len()))
+        .with_metric("keys_per_provider", serde_json::json!(self.keys_per_provider))
+        .with_metric("latency_ns", latency_metrics(&mut get_samples))
+        .with_metric(
+            "list",
+            serde_json::json!({
+                "operation": "chain_list",
+                "duration_ns": list_duration.as_nanos(),
+                "throughput_ops_per_sec": list_ops_per_sec,
+                "latency_ns": latency_metrics(&mut list_samples),
+            }),
+        )
+    }
+}
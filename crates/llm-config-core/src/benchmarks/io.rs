@@ -12,6 +12,7 @@ use std::path::{Path, PathBuf};
 pub const OUTPUT_DIR: &str = "benchmarks/output";
 pub const RAW_OUTPUT_DIR: &str = "benchmarks/output/raw";
 pub const SUMMARY_FILE: &str = "benchmarks/output/summary.md";
+pub const COMPARISON_FILE: &str = "benchmarks/output/comparison.md";
 
 /// Write benchmark results to a JSON file in the raw output directory
 pub fn write_raw_results(
@@ -153,6 +154,11 @@ pub fn summary_file(base_path: &Path) -> PathBuf {
     base_path.join(SUMMARY_FILE)
 }
 
+/// Get the canonical comparison file path
+pub fn comparison_file(base_path: &Path) -> PathBuf {
+    base_path.join(COMPARISON_FILE)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -4,7 +4,8 @@
 //! from benchmark results.
 
 use super::result::BenchmarkResult;
-use super::io::{summary_file, read_raw_results};
+use super::io::{comparison_file, summary_file, read_raw_results};
+use std::collections::BTreeMap;
 use std::fs;
 use std::io::{self, Write};
 use std::path::Path;
@@ -117,7 +118,90 @@ pub fn update_summary(base_path: &Path) -> io::Result<()> {
     write_summary(base_path, &results)
 }
 
+/// Parse the per-target metrics from a summary written by [`generate_summary`]
+///
+/// Reads the `### <target>` headings and their JSON blocks from the
+/// "Detailed Results" section. Targets whose block fails to parse are skipped.
+pub fn parse_summary_metrics(markdown: &str) -> BTreeMap<String, serde_json::Value> {
+    let mut metrics = BTreeMap::new();
+    let mut lines = markdown.lines();
+
+    while let Some(line) = lines.next() {
+        let Some(target_id) = line.strip_prefix("### ") else {
+            continue;
+        };
+
+        if lines.by_ref().find(|l| !l.trim().is_empty()) != Some("```json") {
+            continue;
+        }
+
+        let block: Vec<&str> = lines.by_ref().take_while(|l| *l != "```").collect();
+        if let Ok(value) = serde_json::from_str::<serde_json::Value>(&block.join("\n")) {
+            metrics.insert(target_id.trim().to_string(), value);
+        }
+    }
+
+    metrics
+}
+
+/// Format a metric delta as a direction indicator and percent change
+fn format_delta(baseline: f64, current: f64) -> String {
+    if baseline == 0.0 {
+        return if current > 0.0 {
+            "▲ from 0".to_string()
+        } else if current < 0.0 {
+            "▼ from 0".to_string()
+        } else {
+            "=".to_string()
+        };
+    }
+
+    let pct = ((current - baseline) / baseline.abs()) * 100.0;
+    if pct > 0.0 {
+        format!("▲ +{:.1}%", pct)
+    } else if pct < 0.0 {
+        format!("▼ {:.1}%", pct)
+    } else {
+        "= 0.0%".to_string()
+    }
+}
+
+/// Latest result for each target, keyed by target ID
+fn latest_by_target(results: &[BenchmarkResult]) -> BTreeMap<&str, &BenchmarkResult> {
+    let mut latest: BTreeMap<&str, &BenchmarkResult> = BTreeMap::new();
+    for result in results {
+        latest
+            .entry(result.target_id.as_str())
+            .and_modify(|existing| {
+                if result.timestamp > existing.timestamp {
+                    *existing = result;
+                }
+            })
+            .or_insert(result);
+    }
+    latest
+}
+
+/// Numeric top-level metrics of a result
+fn numeric_metrics(result: Option<&&BenchmarkResult>) -> BTreeMap<String, f64> {
+    result
+        .and_then(|r| r.metrics.as_object())
+        .map(|m| {
+            m.iter()
+                .filter_map(|(k, v)| v.as_f64().map(|n| (k.clone(), n)))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
 /// Generate a comparison report between two sets of results
+///
+/// Only targets in `current` are compared, so a run limited to some targets
+/// does not report the others as removed. Every numeric top-level metric
+/// present in either result gets a row with the baseline value, current
+/// value, and a ▲/▼ percent delta. Whether an increase is good depends on
+/// the metric (duration vs. throughput), so the indicator only shows
+/// direction.
 pub fn generate_comparison(
     baseline: &[BenchmarkResult],
     current: &[BenchmarkResult],
@@ -131,67 +215,72 @@ pub fn generate_comparison(
         return md;
     }
 
-    md.push_str("| Target | Baseline (ms) | Current (ms) | Change |\n");
-    md.push_str("|--------|---------------|--------------|--------|\n");
-
-    // Create maps for quick lookup
-    let baseline_map: std::collections::HashMap<_, _> = baseline
-        .iter()
-        .map(|r| (r.target_id.clone(), r))
-        .collect();
-
-    let current_map: std::collections::HashMap<_, _> = current
-        .iter()
-        .map(|r| (r.target_id.clone(), r))
-        .collect();
+    md.push_str("▲ means the value went up, ▼ means it went down.\n\n");
+    md.push_str("| Target | Metric | Baseline | Current | Delta |\n");
+    md.push_str("|--------|--------|----------|---------|-------|\n");
+
+    let baseline_map = latest_by_target(baseline);
+    let current_map = latest_by_target(current);
+
+    for (target_id, result) in &current_map {
+        let before = numeric_metrics(baseline_map.get(target_id));
+        let after = numeric_metrics(Some(result));
+
+        let mut names: Vec<&String> = before.keys().chain(after.keys()).collect();
+        names.sort();
+        names.dedup();
+
+        for name in names {
+            let cell = |v: Option<&f64>| v.map(|n| format!("{:.3}", n)).unwrap_or_else(|| "-".to_string());
+            let delta = match (before.get(name), after.get(name)) {
+                (Some(b), Some(c)) => format_delta(*b, *c),
+                (None, Some(_)) => "new".to_string(),
+                (Some(_), None) => "removed".to_string(),
+                (None, None) => "-".to_string(),
+            };
+
+            md.push_str(&format!(
+                "| {} | {} | {} | {} | {} |\n",
+                target_id,
+                name,
+                cell(before.get(name)),
+                cell(after.get(name)),
+                delta
+            ));
+        }
+    }
 
-    // Get all unique targets
-    let mut all_targets: std::collections::HashSet<_> = baseline_map.keys().collect();
-    all_targets.extend(current_map.keys());
+    md
+}
 
-    let mut sorted_targets: Vec<_> = all_targets.into_iter().collect();
-    sorted_targets.sort();
+/// Results recorded in a summary written by [`generate_summary`]
+///
+/// Timestamps are not recorded per target in the detailed section, so the
+/// results carry the time of parsing.
+pub fn parse_summary_results(markdown: &str) -> Vec<BenchmarkResult> {
+    parse_summary_metrics(markdown)
+        .into_iter()
+        .map(|(target_id, metrics)| BenchmarkResult::new(target_id, metrics))
+        .collect()
+}
 
-    for target_id in sorted_targets {
-        let baseline_ms = baseline_map
-            .get(target_id)
-            .and_then(|r| r.metrics.get("duration_ms"))
-            .and_then(|v| v.as_f64());
-
-        let current_ms = current_map
-            .get(target_id)
-            .and_then(|r| r.metrics.get("duration_ms"))
-            .and_then(|v| v.as_f64());
-
-        let baseline_str = baseline_ms
-            .map(|v| format!("{:.3}", v))
-            .unwrap_or_else(|| "-".to_string());
-
-        let current_str = current_ms
-            .map(|v| format!("{:.3}", v))
-            .unwrap_or_else(|| "-".to_string());
-
-        let change = match (baseline_ms, current_ms) {
-            (Some(b), Some(c)) => {
-                let pct = ((c - b) / b) * 100.0;
-                if pct > 5.0 {
-                    format!("+{:.1}% (slower)", pct)
-                } else if pct < -5.0 {
-                    format!("{:.1}% (faster)", pct)
-                } else {
-                    "~same".to_string()
-                }
-            }
-            _ => "-".to_string(),
-        };
+/// Write `comparison.md` comparing the current run to a previous summary
+pub fn write_comparison(
+    base_path: &Path,
+    previous_summary: &str,
+    current: &[BenchmarkResult],
+) -> io::Result<()> {
+    let comparison_path = comparison_file(base_path);
 
-        md.push_str(&format!(
-            "| {} | {} | {} | {} |\n",
-            target_id, baseline_str, current_str, change
-        ));
+    if let Some(parent) = comparison_path.parent() {
+        fs::create_dir_all(parent)?;
     }
 
-    md
+    let markdown = generate_comparison(&parse_summary_results(previous_summary), current);
+    let mut file = fs::File::create(&comparison_path)?;
+    file.write_all(markdown.as_bytes())?;
+
+    Ok(())
 }
 
 #[cfg(test)]
@@ -242,4 +331,51 @@ mod tests {
         assert!(comparison.contains("Baseline"));
         assert!(comparison.contains("Current"));
     }
+
+    #[test]
+    fn test_generate_comparison_from_summary() {
+        let previous = generate_summary(&[
+            BenchmarkResult::throughput("config_get", 2_000_000, 100),
+            BenchmarkResult::throughput("config_set", 1_000_000, 100),
+            BenchmarkResult::timing("storage_write", 1_000_000),
+        ]);
+        let current = vec![
+            BenchmarkResult::throughput("config_get", 1_000_000, 100),
+            BenchmarkResult::throughput("config_set", 1_500_000, 100),
+            BenchmarkResult::timing("config_list", 500_000),
+        ];
+
+        let parsed = parse_summary_metrics(&previous);
+        assert_eq!(parsed.len(), 3);
+        assert_eq!(parsed["config_get"]["duration_ns"], 2_000_000);
+
+        let comparison = generate_comparison(&parse_summary_results(&previous), &current);
+        assert!(comparison.contains("| config_get | duration_ns | 2000000.000 | 1000000.000 | ▼ -50.0% |"));
+        assert!(comparison.contains("| config_get | throughput_ops_per_sec | 50000.000 | 100000.000 | ▲ +100.0% |"));
+        assert!(comparison.contains("| config_set | duration_ms | 1.000 | 1.500 | ▲ +50.0% |"));
+        assert!(comparison.contains("| config_list | duration_ns | - | 500000.000 | new |"));
+
+        // Targets outside the current run are not reported as removed
+        assert!(!comparison.contains("storage_write"));
+    }
+
+    #[test]
+    fn test_generate_comparison_from_zero() {
+        let baseline = vec![BenchmarkResult::timing("test", 0)];
+        let current = vec![BenchmarkResult::timing("test", 1_000_000)];
+
+        let comparison = generate_comparison(&baseline, &current);
+        assert!(comparison.contains("| test | duration_ns | 0.000 | 1000000.000 | ▲ from 0 |"));
+    }
+
+    #[test]
+    fn test_write_comparison() {
+        let temp_dir = TempDir::new().unwrap();
+        let previous = generate_summary(&[BenchmarkResult::timing("test_target", 1000)]);
+
+        write_comparison(temp_dir.path(), &previous, &[BenchmarkResult::timing("test_target", 900)]).unwrap();
+
+        let content = fs::read_to_string(comparison_file(temp_dir.path())).unwrap();
+        assert!(content.contains("▼ -10.0%"));
+    }
 }
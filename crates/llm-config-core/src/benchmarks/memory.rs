@@ -0,0 +1,201 @@
+//! Memory usage capture for benchmark targets
+//!
+//! Adapters opt in by wrapping their measured section in a [`MemoryProbe`]
+//! and attaching the result with [`BenchmarkResult::with_memory`]. Two
+//! measurements are recorded under the `memory` metric:
+//!
+//! - `allocations` / `allocated_bytes`: heap allocations made on the
+//!   measuring thread while the probe was active. Only reported with the
+//!   `bench-alloc` feature, and only meaningful when the binary installs
+//!   [`CountingAllocator`] as its global allocator (the CLI does this when
+//!   built with its own `bench-alloc` feature):
+//!
+//!   ```rust,ignore
+//!   #[cfg(feature = "bench-alloc")]
+//!   #[global_allocator]
+//!   static GLOBAL: llm_config_core::benchmarks::CountingAllocator =
+//!       llm_config_core::benchmarks::CountingAllocator;
+//!   ```
+//! - `peak_rss_bytes`: the process high-water-mark resident set size read
+//!   from `/proc/self/status` (`VmHWM`). Linux only.
+//!
+//! # Caveats
+//!
+//! - Allocation counters are thread-local. Work an adapter hands off to
+//!   another thread (for example a private async runtime) is not counted.
+//! - Peak RSS is process-wide and never decreases, so it reflects everything
+//!   the process has done so far, not just the measured section. Compare it
+//!   across runs of the same target rather than between targets.
+//! - The counting allocator adds a small constant cost to every allocation,
+//!   which shows up in timing metrics. Do not compare timings from builds
+//!   with and without `bench-alloc`.
+//!
+//! [`BenchmarkResult::with_memory`]: super::BenchmarkResult::with_memory
+
+pub use counting::CountingAllocator;
+
+/// Memory usage recorded for a measured section
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MemoryUsage {
+    /// Number of heap allocations (requires `bench-alloc`)
+    pub allocations: Option<u64>,
+    /// Total bytes requested from the allocator (requires `bench-alloc`)
+    pub allocated_bytes: Option<u64>,
+    /// Process peak resident set size in bytes (Linux only)
+    pub peak_rss_bytes: Option<u64>,
+}
+
+impl MemoryUsage {
+    /// Convert to the JSON shape stored in `BenchmarkResult::metrics`
+    pub fn to_metric(&self) -> serde_json::Value {
+        serde_json::json!({
+            "allocations": self.allocations,
+            "allocated_bytes": self.allocated_bytes,
+            "peak_rss_bytes": self.peak_rss_bytes,
+            "method": Self::method(),
+        })
+    }
+
+    /// Description of how the counts were obtained
+    pub fn method() -> &'static str {
+        if cfg!(feature = "bench-alloc") {
+            "counting_allocator+vmhwm"
+        } else {
+            "vmhwm"
+        }
+    }
+}
+
+/// Captures memory usage between `start` and `finish`
+#[derive(Debug, Clone, Copy)]
+pub struct MemoryProbe {
+    start: (u64, u64),
+}
+
+impl MemoryProbe {
+    /// Begin capturing on the current thread
+    pub fn start() -> Self {
+        Self {
+            start: thread_counters(),
+        }
+    }
+
+    /// Stop capturing and return the usage since `start`
+    pub fn finish(self) -> MemoryUsage {
+        let (allocations, allocated_bytes) = if cfg!(feature = "bench-alloc") {
+            let (count, bytes) = thread_counters();
+            (
+                Some(count.saturating_sub(self.start.0)),
+                Some(bytes.saturating_sub(self.start.1)),
+            )
+        } else {
+            (None, None)
+        };
+
+        MemoryUsage {
+            allocations,
+            allocated_bytes,
+            peak_rss_bytes: peak_rss_bytes(),
+        }
+    }
+}
+
+#[cfg(feature = "bench-alloc")]
+fn thread_counters() -> (u64, u64) {
+    counting::snapshot()
+}
+
+#[cfg(not(feature = "bench-alloc"))]
+fn thread_counters() -> (u64, u64) {
+    (0, 0)
+}
+
+/// Read the process peak resident set size, if the platform exposes it
+pub fn peak_rss_bytes() -> Option<u64> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    status
+        .lines()
+        .find_map(|line| line.strip_prefix("VmHWM:"))
+        .and_then(|rest| rest.trim().strip_suffix("kB"))
+        .and_then(|kb| kb.trim().parse::<u64>().ok())
+        .map(|kb| kb * 1024)
+}
+
+mod counting {
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+
+    thread_local! {
+        static ALLOCATIONS: Cell<u64> = const { Cell::new(0) };
+        static ALLOCATED_BYTES: Cell<u64> = const { Cell::new(0) };
+    }
+
+    /// Global allocator wrapper that counts allocations per thread
+    ///
+    /// Not installed by this crate; binaries opt in with
+    /// `#[global_allocator]`.
+    pub struct CountingAllocator;
+
+    fn record(size: usize) {
+        // `try_with` avoids panicking during thread-local teardown
+        let _ = ALLOCATIONS.try_with(|c| c.set(c.get() + 1));
+        let _ = ALLOCATED_BYTES.try_with(|c| c.set(c.get() + size as u64));
+    }
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            record(layout.size());
+            System.alloc(layout)
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            record(layout.size());
+            System.alloc_zeroed(layout)
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            record(new_size);
+            System.realloc(ptr, layout, new_size)
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout)
+        }
+    }
+
+    #[cfg_attr(not(any(test, feature = "bench-alloc")), allow(dead_code))]
+    pub(super) fn snapshot() -> (u64, u64) {
+        (
+            ALLOCATIONS.try_with(Cell::get).unwrap_or(0),
+            ALLOCATED_BYTES.try_with(Cell::get).unwrap_or(0),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::alloc::{GlobalAlloc, Layout};
+
+    // Count allocations in the test binary the same way the CLI does
+    #[cfg(feature = "bench-alloc")]
+    #[global_allocator]
+    static GLOBAL: CountingAllocator = CountingAllocator;
+
+    #[test]
+    fn test_counting_allocator_records_thread_allocations() {
+        let layout = Layout::from_size_align(64, 8).unwrap();
+
+        let before = counting::snapshot();
+        // SAFETY: the layout has a non-zero size and is freed with itself
+        unsafe {
+            let ptr = CountingAllocator.alloc(layout);
+            assert!(!ptr.is_null());
+            CountingAllocator.dealloc(ptr, layout);
+        }
+        let after = counting::snapshot();
+
+        assert_eq!(after.0 - before.0, 1);
+        assert_eq!(after.1 - before.1, 64);
+    }
+}
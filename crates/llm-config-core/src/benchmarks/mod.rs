@@ -8,6 +8,8 @@
 //! - `result`: The canonical `BenchmarkResult` struct with standardized fields
 //! - `io`: I/O operations for reading/writing benchmark results
 //! - `markdown`: Markdown report generation
+//! - `memory`: Opt-in memory usage capture for adapters
+//! - `mode`: Run options, including deterministic mode for regression gating
 //! - `adapters`: Benchmark target implementations using the `BenchTarget` trait
 //!
 //! # Usage
@@ -30,9 +32,13 @@
 pub mod result;
 pub mod io;
 pub mod markdown;
+pub mod memory;
+pub mod mode;
 pub mod adapters;
 
 pub use result::BenchmarkResult;
+pub use memory::{CountingAllocator, MemoryProbe, MemoryUsage};
+pub use mode::BenchOptions;
 pub use adapters::{BenchTarget, all_targets, get_target, list_target_ids};
 
 use std::path::Path;
@@ -56,12 +62,17 @@ use std::path::Path;
 /// }
 /// ```
 pub fn run_all_benchmarks() -> Vec<BenchmarkResult> {
+    run_all_benchmarks_with(BenchOptions::default())
+}
+
+/// Run all registered benchmarks with the given options.
+pub fn run_all_benchmarks_with(options: BenchOptions) -> Vec<BenchmarkResult> {
     let targets = all_targets();
     let mut results = Vec::with_capacity(targets.len());
 
     for target in targets {
         tracing::info!(target_id = target.id(), "Running benchmark");
-        let result = target.run();
+        let result = run_target(target.as_ref(), options);
         tracing::info!(
             target_id = target.id(),
             duration_ns = ?result.duration_ns(),
@@ -75,12 +86,17 @@ pub fn run_all_benchmarks() -> Vec<BenchmarkResult> {
 
 /// Run benchmarks for a specific category.
 pub fn run_benchmarks_by_category(category: &str) -> Vec<BenchmarkResult> {
+    run_benchmarks_by_category_with(category, BenchOptions::default())
+}
+
+/// Run benchmarks for a specific category with the given options.
+pub fn run_benchmarks_by_category_with(category: &str, options: BenchOptions) -> Vec<BenchmarkResult> {
     let targets = adapters::targets_by_category(category);
     let mut results = Vec::with_capacity(targets.len());
 
     for target in targets {
         tracing::info!(target_id = target.id(), category = category, "Running benchmark");
-        let result = target.run();
+        let result = run_target(target.as_ref(), options);
         results.push(result);
     }
 
@@ -89,9 +105,41 @@ pub fn run_benchmarks_by_category(category: &str) -> Vec<BenchmarkResult> {
 
 /// Run a specific benchmark by ID.
 pub fn run_benchmark(target_id: &str) -> Option<BenchmarkResult> {
+    run_benchmark_with(target_id, BenchOptions::default())
+}
+
+/// Run a specific benchmark by ID with the given options.
+pub fn run_benchmark_with(target_id: &str, options: BenchOptions) -> Option<BenchmarkResult> {
     get_target(target_id).map(|target| {
         tracing::info!(target_id = target.id(), "Running benchmark");
-        target.run()
+        run_target(target.as_ref(), options)
+    })
+}
+
+/// Run a single target under `options`.
+///
+/// In deterministic mode the target runs [`mode::DETERMINISTIC_ROUNDS`]
+/// times with seeded fixtures and the median-duration round is returned,
+/// annotated with a `deterministic` metric.
+pub fn run_target(target: &dyn BenchTarget, options: BenchOptions) -> BenchmarkResult {
+    options.scope(|| {
+        if !options.deterministic {
+            return target.run();
+        }
+
+        let mut rounds: Vec<BenchmarkResult> = (0..mode::DETERMINISTIC_ROUNDS)
+            .map(|_| target.run())
+            .collect();
+        rounds.sort_by_key(|r| r.duration_ns().unwrap_or(0));
+        let median = rounds.swap_remove(rounds.len() / 2);
+
+        median.with_metric(
+            "deterministic",
+            serde_json::json!({
+                "seed": options.seed,
+                "rounds": mode::DETERMINISTIC_ROUNDS,
+            }),
+        )
     })
 }
 
@@ -189,6 +237,56 @@ mod tests {
         assert!(temp_dir.path().join(io::SUMMARY_FILE).exists());
     }
 
+    #[test]
+    fn test_deterministic_runs_are_comparable() {
+        let options = BenchOptions::deterministic();
+        let first = run_benchmark_with("crypto_encrypt", options).unwrap();
+        let second = run_benchmark_with("crypto_encrypt", options).unwrap();
+
+        // Non-timing metrics are reproduced exactly
+        let strip = |result: &BenchmarkResult| {
+            let mut metrics = result.metrics.clone();
+            let map = metrics.as_object_mut().unwrap();
+            for key in mode::MEASURED_METRICS {
+                map.remove(*key);
+            }
+            metrics
+        };
+        assert_eq!(strip(&first), strip(&second));
+        assert_eq!(first.metrics["deterministic"]["rounds"], mode::DETERMINISTIC_ROUNDS);
+    }
+
+    #[test]
+    fn test_deterministic_fixture_values_are_seeded() {
+        let options = BenchOptions::deterministic();
+        let first = options.scope(|| mode::fixture_values(4, 16));
+        assert_eq!(first, options.scope(|| mode::fixture_values(4, 16)));
+        assert_eq!(first.len(), 4);
+        assert!(first.iter().all(|v| v.len() == 16));
+        assert_ne!(first[0], first[1]);
+    }
+
+    #[test]
+    fn test_options_scope_restores_on_panic() {
+        let result = std::panic::catch_unwind(|| {
+            BenchOptions::deterministic().scope(|| panic!("target failed"))
+        });
+        assert!(result.is_err());
+        assert!(!BenchOptions::current().deterministic);
+    }
+
+    #[test]
+    fn test_deterministic_fixture_key_is_seeded() {
+        let options = BenchOptions::deterministic();
+        let first = options.scope(mode::fixture_key);
+        let second = options.scope(mode::fixture_key);
+        assert_eq!(first.as_bytes(), second.as_bytes());
+
+        let other = options.with_seed(7).scope(mode::fixture_key);
+        assert_ne!(first.as_bytes(), other.as_bytes());
+        assert!(!BenchOptions::current().deterministic);
+    }
+
     #[test]
     fn test_list_benchmarks() {
         let benchmarks = list_benchmarks();
@@ -0,0 +1,157 @@
+//! Benchmark run options and deterministic mode
+//!
+//! Deterministic mode makes results comparable across runs and machines for
+//! regression gating:
+//!
+//! - Fixture keys, payloads, and config values are derived from a fixed seed
+//!   instead of the system RNG (see [`fixture_key`], [`fixture_bytes`], and
+//!   [`fixture_values`]).
+//! - Each target runs a fixed number of rounds ([`DETERMINISTIC_ROUNDS`]) with
+//!   its pinned iteration count, and the round with the median duration is
+//!   reported, which filters out one-off scheduler and cache noise.
+//!
+//! Timing is still measured on real hardware, so absolute numbers differ
+//! between machines; everything other than timing is reproducible.
+
+use llm_config_crypto::{Algorithm, SecretKey};
+use std::cell::Cell;
+
+/// Seed used when none is given
+pub const DEFAULT_SEED: u64 = 0x5EED_C0F1_6000_0001;
+
+/// Measurement rounds per target in deterministic mode
+pub const DETERMINISTIC_ROUNDS: usize = 5;
+
+/// Top-level metric keys measured on the machine, which vary between runs
+///
+/// Besides plain timings this covers `list` (a nested group of timings for a
+/// second operation) and `memory` (allocation counts and peak RSS). Every
+/// other metric is derived from the target's configuration and fixtures, so
+/// it is reproduced exactly in deterministic mode.
+pub const MEASURED_METRICS: &[&str] = &[
+    "duration_ns",
+    "duration_ms",
+    "duration_s",
+    "throughput_ops_per_sec",
+    "throughput_bytes_per_sec",
+    "latency_ns",
+    "list",
+    "memory",
+];
+
+thread_local! {
+    static CURRENT: Cell<BenchOptions> = const { Cell::new(BenchOptions::standard()) };
+}
+
+/// Options controlling how benchmark targets run
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchOptions {
+    /// Seed fixtures and report the median of several rounds
+    pub deterministic: bool,
+    /// Seed for fixture data in deterministic mode
+    pub seed: u64,
+}
+
+impl BenchOptions {
+    const fn standard() -> Self {
+        Self {
+            deterministic: false,
+            seed: DEFAULT_SEED,
+        }
+    }
+
+    /// Deterministic mode with the default seed
+    pub fn deterministic() -> Self {
+        Self {
+            deterministic: true,
+            seed: DEFAULT_SEED,
+        }
+    }
+
+    /// Use a specific fixture seed
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Options in effect for the current benchmark run
+    pub fn current() -> Self {
+        CURRENT.with(Cell::get)
+    }
+
+    /// Run `f` with these options in effect on the current thread
+    ///
+    /// The previous options are restored afterwards, even if `f` panics.
+    pub fn scope<R>(self, f: impl FnOnce() -> R) -> R {
+        struct Restore(BenchOptions);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                CURRENT.with(|c| c.set(self.0));
+            }
+        }
+
+        let _restore = Restore(CURRENT.with(|c| c.replace(self)));
+        f()
+    }
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+/// SplitMix64 step; small, fast, and stable across platforms
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Fixture bytes for the current run
+///
+/// Seeded from [`BenchOptions::seed`] in deterministic mode, random otherwise.
+pub fn fixture_bytes(len: usize) -> Vec<u8> {
+    let options = BenchOptions::current();
+    let mut state = if options.deterministic {
+        options.seed
+    } else {
+        uuid::Uuid::new_v4().as_u64_pair().0
+    };
+
+    let mut bytes = Vec::with_capacity(len + 8);
+    while bytes.len() < len {
+        bytes.extend_from_slice(&splitmix64(&mut state).to_le_bytes());
+    }
+    bytes.truncate(len);
+    bytes
+}
+
+/// Fixture string values for the current run, each `len` hex characters
+///
+/// Drawn from one [`fixture_bytes`] stream, so values differ from each other
+/// but the whole list repeats for a given seed in deterministic mode.
+pub fn fixture_values(count: usize, len: usize) -> Vec<String> {
+    let chunk = len.div_ceil(2).max(1);
+    fixture_bytes(count * chunk)
+        .chunks(chunk)
+        .map(|bytes| {
+            let mut value: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+            value.truncate(len);
+            value
+        })
+        .collect()
+}
+
+/// AES-256-GCM fixture key for the current run
+pub fn fixture_key() -> SecretKey {
+    if BenchOptions::current().deterministic {
+        SecretKey::from_bytes(Algorithm::Aes256Gcm, fixture_bytes(32))
+            .expect("Fixture key has the AES-256 key length")
+    } else {
+        SecretKey::generate(Algorithm::Aes256Gcm).expect("Failed to generate key")
+    }
+}
@@ -100,6 +100,14 @@ impl BenchmarkResult {
         self
     }
 
+    /// Attach memory usage captured by a [`MemoryProbe`](super::MemoryProbe)
+    ///
+    /// Stored under the `memory` metric; see [`super::memory`] for how each
+    /// field is measured.
+    pub fn with_memory(self, usage: super::MemoryUsage) -> Self {
+        self.with_metric("memory", usage.to_metric())
+    }
+
     /// Get a metric value by key
     pub fn get_metric(&self, key: &str) -> Option<&serde_json::Value> {
         self.metrics.get(key)
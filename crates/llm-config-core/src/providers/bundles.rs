@@ -19,6 +19,11 @@
 //!
 //! Nested keys are flattened using dots: `nested.key`
 //!
+//! Arrays are stored as JSON strings by default. With
+//! [`ArrayFlattening::Indexed`], elements are flattened into indexed keys
+//! instead (`servers[0].host`), so each element can be validated on its own.
+//! An empty array is kept as a single `servers` key holding `[]`.
+//!
 //! # Example
 //!
 //! ```rust,ignore
@@ -30,6 +35,9 @@
 //!
 //! // Or auto-detect format
 //! let bundle = BundleProvider::from_file("config.yaml")?;
+//!
+//! // Or merge every file in a directory (later files override earlier ones)
+//! let merged = BundleProvider::from_dir("config.d")?;
 //! ```
 
 use super::traits::{
@@ -40,23 +48,39 @@ use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::RwLock;
 
+/// How arrays are represented when nested values are flattened into keys
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ArrayFlattening {
+    /// Store each array as a single JSON string value (`servers` -> `[...]`)
+    #[default]
+    JsonString,
+    /// Flatten elements into indexed keys (`servers[0].host`), matching the
+    /// element paths used by validation
+    ///
+    /// Empty arrays are kept as a single key holding `[]`, so they remain
+    /// visible and still override earlier values when files are merged.
+    Indexed,
+}
+
 /// Parsed configuration structure
 #[derive(Debug, Default, Clone)]
 struct ParsedConfig {
-    /// Namespace -> Key -> Value mapping
-    namespaces: HashMap<String, HashMap<String, String>>,
+    /// Namespace -> Key -> Value mapping (leaves keep their source type)
+    namespaces: HashMap<String, HashMap<String, JsonValue>>,
+    /// Namespace -> Key -> contributing file (populated when merging files)
+    sources: HashMap<String, HashMap<String, String>>,
 }
 
 impl ParsedConfig {
     /// Parse from JSON value
-    fn from_json(value: JsonValue) -> ProviderResult<Self> {
+    fn from_json(value: JsonValue, arrays: ArrayFlattening) -> ProviderResult<Self> {
         let mut config = Self::default();
 
         if let JsonValue::Object(root) = value {
             for (namespace, ns_value) in root {
                 if let JsonValue::Object(ns_obj) = ns_value {
                     let mut ns_map = HashMap::new();
-                    Self::flatten_object(&ns_obj, "", &mut ns_map);
+                    Self::flatten_object(&ns_obj, "", arrays, &mut ns_map);
                     config.namespaces.insert(namespace, ns_map);
                 }
             }
@@ -69,7 +93,8 @@ impl ParsedConfig {
     fn flatten_object(
         obj: &serde_json::Map<String, JsonValue>,
         prefix: &str,
-        result: &mut HashMap<String, String>,
+        arrays: ArrayFlattening,
+        result: &mut HashMap<String, JsonValue>,
     ) {
         for (key, value) in obj {
             let full_key = if prefix.is_empty() {
@@ -78,35 +103,127 @@ impl ParsedConfig {
                 format!("{}.{}", prefix, key)
             };
 
-            match value {
-                JsonValue::Object(nested) => {
-                    Self::flatten_object(nested, &full_key, result);
-                }
-                JsonValue::Array(arr) => {
-                    // Store array as JSON string
-                    result.insert(full_key, serde_json::to_string(arr).unwrap_or_default());
-                }
-                JsonValue::String(s) => {
-                    result.insert(full_key, s.clone());
-                }
-                JsonValue::Number(n) => {
-                    result.insert(full_key, n.to_string());
+            Self::flatten_value(value, full_key, arrays, result);
+        }
+    }
+
+    fn flatten_value(
+        value: &JsonValue,
+        full_key: String,
+        arrays: ArrayFlattening,
+        result: &mut HashMap<String, JsonValue>,
+    ) {
+        match value {
+            JsonValue::Object(nested) => {
+                Self::flatten_object(nested, &full_key, arrays, result);
+            }
+            JsonValue::Array(arr) if arrays == ArrayFlattening::Indexed && !arr.is_empty() => {
+                for (index, element) in arr.iter().enumerate() {
+                    Self::flatten_value(element, format!("{}[{}]", full_key, index), arrays, result);
                 }
-                JsonValue::Bool(b) => {
-                    result.insert(full_key, b.to_string());
+            }
+            leaf => {
+                result.insert(full_key, leaf.clone());
+            }
+        }
+    }
+
+    /// Build a provider value, keeping the typed leaf alongside its string form
+    fn provider_value(value: &JsonValue, source: &str) -> ProviderValue {
+        let text = match value {
+            JsonValue::String(s) => s.clone(),
+            // Arrays are stored as a JSON string; null as "null"
+            other => other.to_string(),
+        };
+        ProviderValue::new(text, source).with_typed(value.clone())
+    }
+
+    /// Parse a file, choosing the format from its extension
+    ///
+    /// Returns `Ok(None)` for unsupported extensions.
+    fn from_file(path: &Path, arrays: ArrayFlattening) -> ProviderResult<Option<Self>> {
+        let extension = path.extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or("")
+            .to_lowercase();
+
+        let parse: fn(&str) -> ProviderResult<JsonValue> = match extension.as_str() {
+            "json" => JsonProvider::parse_document,
+            "toml" => TomlProvider::parse_document,
+            "yaml" | "yml" => YamlProvider::parse_document,
+            _ => return Ok(None),
+        };
+
+        let content = std::fs::read_to_string(path)?;
+        let json_value = parse(&content).map_err(|e| match e {
+            ProviderError::SerializationError(msg) => {
+                ProviderError::SerializationError(format!("{}: {}", path.display(), msg))
+            }
+            other => other,
+        })?;
+
+        Self::from_json(json_value, arrays).map(Some)
+    }
+
+    /// Deep-merge `other` over `self`, recording `source` for each key it sets
+    ///
+    /// A leaf in `other` replaces any nested keys below it, and nested keys in
+    /// `other` replace a leaf at their parent, matching a deep merge of the
+    /// unflattened documents. Arrays are replaced whole: an indexed array in
+    /// `other` clears every element of the earlier array, so a shorter array
+    /// leaves no stale elements behind.
+    fn merge(&mut self, other: ParsedConfig, source: &str) {
+        for (namespace, entries) in other.namespaces {
+            let values = self.namespaces.entry(namespace.clone()).or_default();
+            let sources = self.sources.entry(namespace).or_default();
+
+            let mut array_roots: Vec<&str> = Vec::new();
+            for (key, value) in &entries {
+                if value.as_array().is_some_and(|a| a.is_empty()) {
+                    array_roots.push(key);
                 }
-                JsonValue::Null => {
-                    result.insert(full_key, "null".to_string());
+                array_roots.extend(key.match_indices('[').map(|(i, _)| &key[..i]));
+            }
+            for root in array_roots {
+                let is_element = |k: &String| {
+                    k == root
+                        || k.strip_prefix(root)
+                            .is_some_and(|rest| rest.starts_with('[') || rest.starts_with('.'))
+                };
+                values.retain(|k, _| !is_element(k));
+                sources.retain(|k, _| !is_element(k));
+            }
+
+            for (key, value) in entries {
+                let nested_prefix = format!("{}.", key);
+                let element_prefix = format!("{}[", key);
+                let below =
+                    |k: &String| k.starts_with(&nested_prefix) || k.starts_with(&element_prefix);
+                values.retain(|k, _| !below(k));
+                sources.retain(|k, _| !below(k));
+
+                let mut parent = key.as_str();
+                while let Some((head, _)) = parent.rsplit_once('.') {
+                    values.remove(head);
+                    sources.remove(head);
+                    parent = head;
                 }
+
+                sources.insert(key.clone(), source.to_string());
+                values.insert(key, value);
             }
         }
     }
 
-    fn get(&self, namespace: &str, key: &str) -> Option<&String> {
+    fn get(&self, namespace: &str, key: &str) -> Option<&JsonValue> {
         self.namespaces.get(namespace)?.get(key)
     }
 
-    fn list_namespace(&self, namespace: &str) -> Option<&HashMap<String, String>> {
+    fn source(&self, namespace: &str, key: &str) -> Option<&String> {
+        self.sources.get(namespace)?.get(key)
+    }
+
+    fn list_namespace(&self, namespace: &str) -> Option<&HashMap<String, JsonValue>> {
         self.namespaces.get(namespace)
     }
 }
@@ -115,6 +232,9 @@ impl ParsedConfig {
 #[derive(Debug)]
 pub struct JsonProvider {
     path: PathBuf,
+    /// Document parsed by `from_string`, re-flattened on reload
+    inline: Option<JsonValue>,
+    arrays: ArrayFlattening,
     cache: RwLock<Option<ParsedConfig>>,
 }
 
@@ -130,28 +250,45 @@ impl JsonProvider {
 
         Ok(Self {
             path,
+            inline: None,
+            arrays: ArrayFlattening::default(),
             cache: RwLock::new(None),
         })
     }
 
+    /// Parse a JSON document
+    fn parse_document(content: &str) -> ProviderResult<JsonValue> {
+        serde_json::from_str(content)
+            .map_err(|e| ProviderError::SerializationError(e.to_string()))
+    }
+
     /// Parse JSON from a string
     pub fn from_string(content: &str) -> ProviderResult<Self> {
-        let value: JsonValue = serde_json::from_str(content)
-            .map_err(|e| ProviderError::SerializationError(e.to_string()))?;
+        let value = Self::parse_document(content)?;
 
-        let config = ParsedConfig::from_json(value)?;
+        let config = ParsedConfig::from_json(value.clone(), ArrayFlattening::default())?;
 
         Ok(Self {
             path: PathBuf::new(),
+            inline: Some(value),
+            arrays: ArrayFlattening::default(),
             cache: RwLock::new(Some(config)),
         })
     }
 
     fn load(&self) -> ProviderResult<ParsedConfig> {
+        if let Some(value) = &self.inline {
+            return ParsedConfig::from_json(value.clone(), self.arrays);
+        }
         let content = std::fs::read_to_string(&self.path)?;
-        let value: JsonValue = serde_json::from_str(&content)
-            .map_err(|e| ProviderError::SerializationError(e.to_string()))?;
-        ParsedConfig::from_json(value)
+        ParsedConfig::from_json(Self::parse_document(&content)?, self.arrays)
+    }
+
+    /// Set how arrays are flattened into keys (defaults to JSON strings)
+    pub fn with_array_flattening(mut self, arrays: ArrayFlattening) -> Self {
+        self.arrays = arrays;
+        *self.cache.get_mut().unwrap_or_else(|e| e.into_inner()) = None;
+        self
     }
 
     fn ensure_loaded(&self) -> ProviderResult<()> {
@@ -159,7 +296,7 @@ impl JsonProvider {
             .map_err(|e| ProviderError::Other(e.to_string()))?
             .is_some();
 
-        if !loaded && !self.path.as_os_str().is_empty() {
+        if !loaded {
             let config = self.load()?;
             *self.cache.write()
                 .map_err(|e| ProviderError::Other(e.to_string()))? = Some(config);
@@ -188,7 +325,7 @@ impl ConfigProvider for JsonProvider {
             .ok_or_else(|| ProviderError::Other("Config not loaded".to_string()))?;
 
         match config.get(namespace, key) {
-            Some(value) => Ok(ProviderValue::new(value.clone(), "json")),
+            Some(value) => Ok(ParsedConfig::provider_value(value, "json")),
             None => Err(ProviderError::NotFound {
                 namespace: namespace.to_string(),
                 key: key.to_string(),
@@ -214,7 +351,7 @@ impl ConfigProvider for JsonProvider {
                         continue;
                     }
                 }
-                result.insert(key.clone(), ProviderValue::new(value.clone(), "json"));
+                result.insert(key.clone(), ParsedConfig::provider_value(value, "json"));
             }
         }
 
@@ -237,12 +374,23 @@ impl ConfigProvider for JsonProvider {
             Ok(ProviderHealth::unhealthy("json", "File not found"))
         }
     }
+
+    fn watch_paths(&self) -> Vec<PathBuf> {
+        if self.inline.is_some() {
+            Vec::new()
+        } else {
+            vec![self.path.clone()]
+        }
+    }
 }
 
 /// TOML configuration file provider
 #[derive(Debug)]
 pub struct TomlProvider {
     path: PathBuf,
+    /// Document parsed by `from_string`, re-flattened on reload
+    inline: Option<JsonValue>,
+    arrays: ArrayFlattening,
     cache: RwLock<Option<ParsedConfig>>,
 }
 
@@ -258,31 +406,45 @@ impl TomlProvider {
 
         Ok(Self {
             path,
+            inline: None,
+            arrays: ArrayFlattening::default(),
             cache: RwLock::new(None),
         })
     }
 
-    /// Parse TOML from a string
-    pub fn from_string(content: &str) -> ProviderResult<Self> {
+    /// Parse a TOML document, converted to JSON for uniform handling
+    fn parse_document(content: &str) -> ProviderResult<JsonValue> {
         let value: toml::Value = toml::from_str(content)
             .map_err(|e| ProviderError::SerializationError(e.to_string()))?;
+        Ok(toml_to_json(value))
+    }
 
-        // Convert TOML to JSON for uniform handling
-        let json_value = toml_to_json(value);
-        let config = ParsedConfig::from_json(json_value)?;
+    /// Parse TOML from a string
+    pub fn from_string(content: &str) -> ProviderResult<Self> {
+        let json_value = Self::parse_document(content)?;
+        let config = ParsedConfig::from_json(json_value.clone(), ArrayFlattening::default())?;
 
         Ok(Self {
             path: PathBuf::new(),
+            inline: Some(json_value),
+            arrays: ArrayFlattening::default(),
             cache: RwLock::new(Some(config)),
         })
     }
 
     fn load(&self) -> ProviderResult<ParsedConfig> {
+        if let Some(value) = &self.inline {
+            return ParsedConfig::from_json(value.clone(), self.arrays);
+        }
         let content = std::fs::read_to_string(&self.path)?;
-        let value: toml::Value = toml::from_str(&content)
-            .map_err(|e| ProviderError::SerializationError(e.to_string()))?;
-        let json_value = toml_to_json(value);
-        ParsedConfig::from_json(json_value)
+        ParsedConfig::from_json(Self::parse_document(&content)?, self.arrays)
+    }
+
+    /// Set how arrays are flattened into keys (defaults to JSON strings)
+    pub fn with_array_flattening(mut self, arrays: ArrayFlattening) -> Self {
+        self.arrays = arrays;
+        *self.cache.get_mut().unwrap_or_else(|e| e.into_inner()) = None;
+        self
     }
 
     fn ensure_loaded(&self) -> ProviderResult<()> {
@@ -290,7 +452,7 @@ impl TomlProvider {
             .map_err(|e| ProviderError::Other(e.to_string()))?
             .is_some();
 
-        if !loaded && !self.path.as_os_str().is_empty() {
+        if !loaded {
             let config = self.load()?;
             *self.cache.write()
                 .map_err(|e| ProviderError::Other(e.to_string()))? = Some(config);
@@ -344,7 +506,7 @@ impl ConfigProvider for TomlProvider {
             .ok_or_else(|| ProviderError::Other("Config not loaded".to_string()))?;
 
         match config.get(namespace, key) {
-            Some(value) => Ok(ProviderValue::new(value.clone(), "toml")),
+            Some(value) => Ok(ParsedConfig::provider_value(value, "toml")),
             None => Err(ProviderError::NotFound {
                 namespace: namespace.to_string(),
                 key: key.to_string(),
@@ -370,7 +532,7 @@ impl ConfigProvider for TomlProvider {
                         continue;
                     }
                 }
-                result.insert(key.clone(), ProviderValue::new(value.clone(), "toml"));
+                result.insert(key.clone(), ParsedConfig::provider_value(value, "toml"));
             }
         }
 
@@ -393,12 +555,23 @@ impl ConfigProvider for TomlProvider {
             Ok(ProviderHealth::unhealthy("toml", "File not found"))
         }
     }
+
+    fn watch_paths(&self) -> Vec<PathBuf> {
+        if self.inline.is_some() {
+            Vec::new()
+        } else {
+            vec![self.path.clone()]
+        }
+    }
 }
 
 /// YAML configuration file provider
 #[derive(Debug)]
 pub struct YamlProvider {
     path: PathBuf,
+    /// Document parsed by `from_string`, re-flattened on reload
+    inline: Option<JsonValue>,
+    arrays: ArrayFlattening,
     cache: RwLock<Option<ParsedConfig>>,
 }
 
@@ -414,31 +587,45 @@ impl YamlProvider {
 
         Ok(Self {
             path,
+            inline: None,
+            arrays: ArrayFlattening::default(),
             cache: RwLock::new(None),
         })
     }
 
-    /// Parse YAML from a string
-    pub fn from_string(content: &str) -> ProviderResult<Self> {
+    /// Parse a YAML document, converted to JSON for uniform handling
+    fn parse_document(content: &str) -> ProviderResult<JsonValue> {
         let value: serde_yaml::Value = serde_yaml::from_str(content)
             .map_err(|e| ProviderError::SerializationError(e.to_string()))?;
+        Ok(yaml_to_json(value))
+    }
 
-        // Convert YAML to JSON for uniform handling
-        let json_value = yaml_to_json(value);
-        let config = ParsedConfig::from_json(json_value)?;
+    /// Parse YAML from a string
+    pub fn from_string(content: &str) -> ProviderResult<Self> {
+        let json_value = Self::parse_document(content)?;
+        let config = ParsedConfig::from_json(json_value.clone(), ArrayFlattening::default())?;
 
         Ok(Self {
             path: PathBuf::new(),
+            inline: Some(json_value),
+            arrays: ArrayFlattening::default(),
             cache: RwLock::new(Some(config)),
         })
     }
 
     fn load(&self) -> ProviderResult<ParsedConfig> {
+        if let Some(value) = &self.inline {
+            return ParsedConfig::from_json(value.clone(), self.arrays);
+        }
         let content = std::fs::read_to_string(&self.path)?;
-        let value: serde_yaml::Value = serde_yaml::from_str(&content)
-            .map_err(|e| ProviderError::SerializationError(e.to_string()))?;
-        let json_value = yaml_to_json(value);
-        ParsedConfig::from_json(json_value)
+        ParsedConfig::from_json(Self::parse_document(&content)?, self.arrays)
+    }
+
+    /// Set how arrays are flattened into keys (defaults to JSON strings)
+    pub fn with_array_flattening(mut self, arrays: ArrayFlattening) -> Self {
+        self.arrays = arrays;
+        *self.cache.get_mut().unwrap_or_else(|e| e.into_inner()) = None;
+        self
     }
 
     fn ensure_loaded(&self) -> ProviderResult<()> {
@@ -446,7 +633,7 @@ impl YamlProvider {
             .map_err(|e| ProviderError::Other(e.to_string()))?
             .is_some();
 
-        if !loaded && !self.path.as_os_str().is_empty() {
+        if !loaded {
             let config = self.load()?;
             *self.cache.write()
                 .map_err(|e| ProviderError::Other(e.to_string()))? = Some(config);
@@ -512,7 +699,7 @@ impl ConfigProvider for YamlProvider {
             .ok_or_else(|| ProviderError::Other("Config not loaded".to_string()))?;
 
         match config.get(namespace, key) {
-            Some(value) => Ok(ProviderValue::new(value.clone(), "yaml")),
+            Some(value) => Ok(ParsedConfig::provider_value(value, "yaml")),
             None => Err(ProviderError::NotFound {
                 namespace: namespace.to_string(),
                 key: key.to_string(),
@@ -538,7 +725,7 @@ impl ConfigProvider for YamlProvider {
                         continue;
                     }
                 }
-                result.insert(key.clone(), ProviderValue::new(value.clone(), "yaml"));
+                result.insert(key.clone(), ParsedConfig::provider_value(value, "yaml"));
             }
         }
 
@@ -561,6 +748,169 @@ impl ConfigProvider for YamlProvider {
             Ok(ProviderHealth::unhealthy("yaml", "File not found"))
         }
     }
+
+    fn watch_paths(&self) -> Vec<PathBuf> {
+        if self.inline.is_some() {
+            Vec::new()
+        } else {
+            vec![self.path.clone()]
+        }
+    }
+}
+
+/// Directory bundle provider
+///
+/// Loads every JSON, TOML, and YAML file in a directory in lexical file name
+/// order and deep-merges them, so later files override earlier ones (e.g.
+/// `00-base.yaml` then `10-prod.yaml`). Each value's `source` is the path of
+/// the file that contributed it.
+#[derive(Debug)]
+pub struct MergedBundleProvider {
+    dir: PathBuf,
+    arrays: ArrayFlattening,
+    cache: RwLock<Option<ParsedConfig>>,
+}
+
+impl MergedBundleProvider {
+    /// Create a provider from a directory of configuration files
+    pub fn from_dir(dir: impl AsRef<Path>) -> ProviderResult<Self> {
+        let dir = dir.as_ref().to_path_buf();
+        if !dir.is_dir() {
+            return Err(ProviderError::ConfigurationError(
+                format!("Bundle directory not found: {}", dir.display())
+            ));
+        }
+
+        Ok(Self {
+            dir,
+            arrays: ArrayFlattening::default(),
+            cache: RwLock::new(None),
+        })
+    }
+
+    /// Supported files in the directory, in lexical order
+    pub fn files(&self) -> ProviderResult<Vec<PathBuf>> {
+        let mut files: Vec<PathBuf> = std::fs::read_dir(&self.dir)?
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| path.is_file())
+            .filter(|path| {
+                matches!(
+                    path.extension().and_then(|e| e.to_str()).map(|e| e.to_lowercase()).as_deref(),
+                    Some("json" | "toml" | "yaml" | "yml")
+                )
+            })
+            .collect();
+        files.sort();
+        Ok(files)
+    }
+
+    fn load(&self) -> ProviderResult<ParsedConfig> {
+        let mut merged = ParsedConfig::default();
+        for path in self.files()? {
+            if let Some(config) = ParsedConfig::from_file(&path, self.arrays)? {
+                merged.merge(config, &path.display().to_string());
+            }
+        }
+        Ok(merged)
+    }
+
+    /// Set how arrays are flattened into keys (defaults to JSON strings)
+    pub fn with_array_flattening(mut self, arrays: ArrayFlattening) -> Self {
+        self.arrays = arrays;
+        *self.cache.get_mut().unwrap_or_else(|e| e.into_inner()) = None;
+        self
+    }
+
+    fn ensure_loaded(&self) -> ProviderResult<()> {
+        let loaded = self.cache.read()
+            .map_err(|e| ProviderError::Other(e.to_string()))?
+            .is_some();
+
+        if !loaded {
+            let config = self.load()?;
+            *self.cache.write()
+                .map_err(|e| ProviderError::Other(e.to_string()))? = Some(config);
+        }
+        Ok(())
+    }
+
+    fn value(config: &ParsedConfig, namespace: &str, key: &str, value: &JsonValue) -> ProviderValue {
+        let source = config.source(namespace, key).map(String::as_str).unwrap_or("bundle");
+        ParsedConfig::provider_value(value, source)
+    }
+}
+
+#[async_trait::async_trait]
+impl ConfigProvider for MergedBundleProvider {
+    fn name(&self) -> &str {
+        "bundle"
+    }
+
+    async fn is_available(&self) -> bool {
+        self.dir.is_dir()
+    }
+
+    async fn get(&self, namespace: &str, key: &str) -> ProviderResult<ProviderValue> {
+        self.ensure_loaded()?;
+
+        let cache = self.cache.read()
+            .map_err(|e| ProviderError::Other(e.to_string()))?;
+
+        let config = cache.as_ref()
+            .ok_or_else(|| ProviderError::Other("Config not loaded".to_string()))?;
+
+        match config.get(namespace, key) {
+            Some(value) => Ok(Self::value(config, namespace, key, value)),
+            None => Err(ProviderError::NotFound {
+                namespace: namespace.to_string(),
+                key: key.to_string(),
+            }),
+        }
+    }
+
+    async fn list(&self, namespace: &str, prefix: Option<&str>) -> ProviderResult<HashMap<String, ProviderValue>> {
+        self.ensure_loaded()?;
+
+        let cache = self.cache.read()
+            .map_err(|e| ProviderError::Other(e.to_string()))?;
+
+        let config = cache.as_ref()
+            .ok_or_else(|| ProviderError::Other("Config not loaded".to_string()))?;
+
+        let mut result = HashMap::new();
+
+        if let Some(ns_content) = config.list_namespace(namespace) {
+            for (key, value) in ns_content {
+                if let Some(p) = prefix {
+                    if !key.starts_with(p) {
+                        continue;
+                    }
+                }
+                result.insert(key.clone(), Self::value(config, namespace, key, value));
+            }
+        }
+
+        Ok(result)
+    }
+
+    async fn refresh(&self) -> ProviderResult<()> {
+        let config = self.load()?;
+        *self.cache.write()
+            .map_err(|e| ProviderError::Other(e.to_string()))? = Some(config);
+        Ok(())
+    }
+
+    fn health_check(&self) -> ProviderResult<ProviderHealth> {
+        if self.dir.is_dir() {
+            Ok(ProviderHealth::healthy("bundle"))
+        } else {
+            Ok(ProviderHealth::unhealthy("bundle", "Directory not found"))
+        }
+    }
+
+    fn watch_paths(&self) -> Vec<PathBuf> {
+        vec![self.dir.clone()]
+    }
 }
 
 /// Auto-detecting bundle provider
@@ -572,6 +922,7 @@ pub enum BundleProvider {
     Json(JsonProvider),
     Toml(TomlProvider),
     Yaml(YamlProvider),
+    Merged(MergedBundleProvider),
 }
 
 impl BundleProvider {
@@ -592,6 +943,23 @@ impl BundleProvider {
             )),
         }
     }
+
+    /// Create a bundle provider that merges all supported files in a directory
+    ///
+    /// See [`MergedBundleProvider`] for ordering and override rules.
+    pub fn from_dir(path: impl AsRef<Path>) -> ProviderResult<Self> {
+        Ok(BundleProvider::Merged(MergedBundleProvider::from_dir(path)?))
+    }
+
+    /// Set how arrays are flattened into keys (defaults to JSON strings)
+    pub fn with_array_flattening(self, arrays: ArrayFlattening) -> Self {
+        match self {
+            BundleProvider::Json(p) => BundleProvider::Json(p.with_array_flattening(arrays)),
+            BundleProvider::Toml(p) => BundleProvider::Toml(p.with_array_flattening(arrays)),
+            BundleProvider::Yaml(p) => BundleProvider::Yaml(p.with_array_flattening(arrays)),
+            BundleProvider::Merged(p) => BundleProvider::Merged(p.with_array_flattening(arrays)),
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -601,6 +969,7 @@ impl ConfigProvider for BundleProvider {
             BundleProvider::Json(p) => p.name(),
             BundleProvider::Toml(p) => p.name(),
             BundleProvider::Yaml(p) => p.name(),
+            BundleProvider::Merged(p) => p.name(),
         }
     }
 
@@ -609,6 +978,7 @@ impl ConfigProvider for BundleProvider {
             BundleProvider::Json(p) => p.is_available().await,
             BundleProvider::Toml(p) => p.is_available().await,
             BundleProvider::Yaml(p) => p.is_available().await,
+            BundleProvider::Merged(p) => p.is_available().await,
         }
     }
 
@@ -617,6 +987,7 @@ impl ConfigProvider for BundleProvider {
             BundleProvider::Json(p) => p.get(namespace, key).await,
             BundleProvider::Toml(p) => p.get(namespace, key).await,
             BundleProvider::Yaml(p) => p.get(namespace, key).await,
+            BundleProvider::Merged(p) => p.get(namespace, key).await,
         }
     }
 
@@ -625,6 +996,7 @@ impl ConfigProvider for BundleProvider {
             BundleProvider::Json(p) => p.list(namespace, prefix).await,
             BundleProvider::Toml(p) => p.list(namespace, prefix).await,
             BundleProvider::Yaml(p) => p.list(namespace, prefix).await,
+            BundleProvider::Merged(p) => p.list(namespace, prefix).await,
         }
     }
 
@@ -633,6 +1005,7 @@ impl ConfigProvider for BundleProvider {
             BundleProvider::Json(p) => p.refresh().await,
             BundleProvider::Toml(p) => p.refresh().await,
             BundleProvider::Yaml(p) => p.refresh().await,
+            BundleProvider::Merged(p) => p.refresh().await,
         }
     }
 
@@ -641,6 +1014,16 @@ impl ConfigProvider for BundleProvider {
             BundleProvider::Json(p) => p.health_check(),
             BundleProvider::Toml(p) => p.health_check(),
             BundleProvider::Yaml(p) => p.health_check(),
+            BundleProvider::Merged(p) => p.health_check(),
+        }
+    }
+
+    fn watch_paths(&self) -> Vec<PathBuf> {
+        match self {
+            BundleProvider::Json(p) => p.watch_paths(),
+            BundleProvider::Toml(p) => p.watch_paths(),
+            BundleProvider::Yaml(p) => p.watch_paths(),
+            BundleProvider::Merged(p) => p.watch_paths(),
         }
     }
 }
@@ -649,6 +1032,144 @@ impl ConfigProvider for BundleProvider {
 mod tests {
     use super::*;
 
+    #[tokio::test]
+    async fn test_yaml_types_are_preserved() {
+        let yaml = r#"
+server:
+  port: 8080
+  debug: true
+  ratio: 0.5
+  version: "42"
+  tags: [a, b]
+        "#;
+
+        let provider = YamlProvider::from_string(yaml).unwrap();
+
+        let port = provider.get("server", "port").await.unwrap();
+        assert_eq!(port.value, "8080");
+        assert_eq!(port.typed(), serde_json::json!(8080));
+        assert!(port.typed().is_i64());
+
+        assert_eq!(provider.get("server", "debug").await.unwrap().typed(), serde_json::json!(true));
+        assert_eq!(provider.get("server", "ratio").await.unwrap().typed(), serde_json::json!(0.5));
+        assert_eq!(provider.get("server", "version").await.unwrap().typed(), serde_json::json!("42"));
+
+        let tags = provider.get("server", "tags").await.unwrap();
+        assert_eq!(tags.value, r#"["a","b"]"#);
+        assert_eq!(tags.typed(), serde_json::json!(["a", "b"]));
+    }
+
+    #[tokio::test]
+    async fn test_toml_array_of_tables_flattening_modes() {
+        let toml = r#"
+            [cluster]
+            name = "edge"
+
+            [[cluster.servers]]
+            host = "a.internal"
+            port = 8080
+
+            [[cluster.servers]]
+            host = "b.internal"
+            port = 8081
+        "#;
+
+        let json_mode = TomlProvider::from_string(toml).unwrap();
+        let servers = json_mode.get("cluster", "servers").await.unwrap();
+        let parsed: JsonValue = serde_json::from_str(&servers.value).unwrap();
+        assert_eq!(parsed[1]["host"], "b.internal");
+        assert!(json_mode.get("cluster", "servers[0].host").await.is_err());
+
+        let indexed = TomlProvider::from_string(toml)
+            .unwrap()
+            .with_array_flattening(ArrayFlattening::Indexed);
+        assert_eq!(indexed.get("cluster", "servers[0].host").await.unwrap().value, "a.internal");
+        assert_eq!(indexed.get("cluster", "servers[1].port").await.unwrap().value, "8081");
+        assert!(indexed.get("cluster", "servers").await.is_err());
+
+        let listed = indexed.list("cluster", Some("servers[")).await.unwrap();
+        assert_eq!(listed.len(), 4);
+        assert_eq!(indexed.get("cluster", "name").await.unwrap().value, "edge");
+    }
+
+    #[tokio::test]
+    async fn test_bundle_from_dir_later_file_overrides() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("base.yaml"),
+            "database:\n  host: localhost\n  port: 5432\napp:\n  name: api\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("prod.json"),
+            r#"{"database": {"host": "db.prod.internal"}}"#,
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("README.md"), "ignored").unwrap();
+
+        let provider = BundleProvider::from_dir(dir.path()).unwrap();
+
+        let host = provider.get("database", "host").await.unwrap();
+        assert_eq!(host.value, "db.prod.internal");
+        assert!(host.metadata.source.ends_with("prod.json"));
+
+        let port = provider.get("database", "port").await.unwrap();
+        assert_eq!(port.value, "5432");
+        assert!(port.metadata.source.ends_with("base.yaml"));
+
+        let app = provider.list("app", None).await.unwrap();
+        assert!(app["name"].metadata.source.ends_with("base.yaml"));
+    }
+
+    #[tokio::test]
+    async fn test_bundle_from_dir_indexed_arrays_replace_whole() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("00-base.yaml"),
+            "cluster:\n  servers:\n    - host: a\n    - host: b\n    - host: c\n  zones: [x, y]\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("10-prod.json"),
+            r#"{"cluster": {"servers": [{"host": "prod"}], "zones": []}}"#,
+        )
+        .unwrap();
+
+        let provider = MergedBundleProvider::from_dir(dir.path())
+            .unwrap()
+            .with_array_flattening(ArrayFlattening::Indexed);
+
+        let servers = provider.list("cluster", Some("servers")).await.unwrap();
+        assert_eq!(servers.len(), 1);
+        assert_eq!(servers["servers[0].host"].value, "prod");
+        assert!(servers["servers[0].host"].metadata.source.ends_with("10-prod.json"));
+
+        // An empty array overrides the earlier elements and stays visible
+        let zones = provider.list("cluster", Some("zones")).await.unwrap();
+        assert_eq!(zones.len(), 1);
+        assert_eq!(zones["zones"].value, "[]");
+    }
+
+    #[tokio::test]
+    async fn test_bundle_from_dir_leaf_replaces_nested_keys() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("a.toml"),
+            "[cache]\nredis = { host = \"localhost\", port = 6379 }\n",
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("b.yaml"), "cache:\n  redis: disabled\n").unwrap();
+
+        let provider = BundleProvider::from_dir(dir.path()).unwrap();
+
+        assert_eq!(provider.get("cache", "redis").await.unwrap().value, "disabled");
+        assert!(matches!(
+            provider.get("cache", "redis.host").await,
+            Err(ProviderError::NotFound { .. })
+        ));
+        assert!(BundleProvider::from_dir(dir.path().join("missing")).is_err());
+    }
+
     #[tokio::test]
     async fn test_json_provider_from_string() {
         let json = r#"{
@@ -16,11 +16,73 @@
 //!
 //! // First provider to return a value wins
 //! let value = chain.get("database", "host").await?;
+//!
+//! // Keep long-lived processes current by refreshing every five minutes
+//! let chain = Arc::new(chain);
+//! let handle = chain.clone().spawn_refresh(Duration::from_secs(300));
+//!
+//! // Or refresh as soon as a provider's file changes on disk
+//! let watcher = chain.clone().spawn_watch(Duration::from_millis(200))?;
 //! ```
-
+//!
+//! # Change Notifications
+//!
+//! Namespaces registered with [`ProviderChain::watch_namespace`] are
+//! snapshotted on every refresh. Keys whose value differs from the previous
+//! refresh are passed to callbacks registered with
+//! [`ProviderChain::on_change`]. Secret values are masked.
+//!
+//! [`ProviderChain::spawn_watch`] watches the files reported by each
+//! provider's [`ConfigProvider::watch_paths`] and refreshes when they change.
+//! Bursts of file events within the debounce window are coalesced into one
+//! refresh, so each key is reported once (oldest value to newest). A
+//! namespace whose values cannot be listed is skipped rather than reported
+//! as removed.
+
+use super::env::is_sensitive_key;
 use super::traits::{ConfigProvider, ProviderError, ProviderResult, ProviderValue, ProviderHealth};
-use std::collections::HashMap;
-use std::sync::Arc;
+use notify::{RecursiveMode, Watcher};
+use std::collections::{BTreeSet, HashMap};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Duration;
+
+/// Placeholder shown instead of secret values in change notifications
+pub const MASKED_VALUE: &str = "********";
+
+/// Shortest interval accepted by [`ProviderChain::spawn_refresh`]
+pub const MIN_REFRESH_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Callback invoked for each changed key
+pub type ChangeCallback = Arc<dyn Fn(&ConfigChange) + Send + Sync>;
+
+/// A configuration key whose value changed between two refreshes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigChange {
+    /// Namespace containing the key
+    pub namespace: String,
+    /// Key that changed
+    pub key: String,
+    /// Previous value (`None` if the key was added; masked if secret)
+    pub old_value: Option<String>,
+    /// New value (`None` if the key was removed; masked if secret)
+    pub new_value: Option<String>,
+    /// Whether the values were masked because the key is secret
+    pub masked: bool,
+}
+
+/// Values of watched namespaces, keyed by namespace
+type Snapshot = HashMap<String, HashMap<String, ProviderValue>>;
+
+/// Watched namespaces, registered callbacks, and the last snapshot
+#[derive(Default)]
+struct ChangeWatch {
+    namespaces: BTreeSet<String>,
+    callbacks: RwLock<Vec<ChangeCallback>>,
+    snapshot: Mutex<Option<Snapshot>>,
+    /// Serializes refreshes so concurrent callers diff against one baseline
+    refreshing: tokio::sync::Mutex<()>,
+}
 
 /// A chain of configuration providers with priority ordering
 ///
@@ -29,6 +91,7 @@ use std::sync::Arc;
 #[derive(Default)]
 pub struct ProviderChain {
     providers: Vec<Arc<dyn ConfigProvider>>,
+    watch: ChangeWatch,
 }
 
 impl std::fmt::Debug for ProviderChain {
@@ -86,6 +149,295 @@ impl ProviderChain {
             .collect()
     }
 
+    /// Watch a namespace for changes reported to [`ProviderChain::on_change`]
+    pub fn watch_namespace(&mut self, namespace: impl Into<String>) {
+        self.watch.namespaces.insert(namespace.into());
+    }
+
+    /// Watch a namespace for changes (builder pattern)
+    pub fn with_watched_namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.watch_namespace(namespace);
+        self
+    }
+
+    /// Register a callback fired for each changed key in a watched namespace
+    ///
+    /// Changes are detected by [`ProviderChain::refresh_all`], which runs
+    /// when a watched file changes (see [`ProviderChain::spawn_watch`]) or on
+    /// a schedule (see [`ProviderChain::spawn_refresh`]). Secret values are
+    /// masked before the callback sees them.
+    pub fn on_change<F>(&self, callback: F)
+    where
+        F: Fn(&ConfigChange) + Send + Sync + 'static,
+    {
+        if let Ok(mut callbacks) = self.watch.callbacks.write() {
+            callbacks.push(Arc::new(callback));
+        }
+    }
+
+    /// Current values of all watched namespaces
+    ///
+    /// Unlike [`ConfigProvider::list`] on the chain, a provider error is not
+    /// skipped: the namespace is left out instead, so a transient error is
+    /// not mistaken for its keys being removed.
+    async fn watched_snapshot(&self) -> Snapshot {
+        let mut snapshot = HashMap::new();
+        for namespace in &self.watch.namespaces {
+            match self.list_strict(namespace).await {
+                Ok(values) => {
+                    snapshot.insert(namespace.clone(), values);
+                }
+                Err((provider, e)) => {
+                    tracing::warn!(
+                        namespace = namespace.as_str(),
+                        provider = provider.as_str(),
+                        error = %e,
+                        "Failed to list watched namespace"
+                    );
+                }
+            }
+        }
+        snapshot
+    }
+
+    /// List a namespace across providers, failing if any provider fails
+    async fn list_strict(
+        &self,
+        namespace: &str,
+    ) -> Result<HashMap<String, ProviderValue>, (String, ProviderError)> {
+        let mut result = HashMap::new();
+        for provider in self.providers.iter().rev() {
+            let values = provider
+                .list(namespace, None)
+                .await
+                .map_err(|e| (provider.name().to_string(), e))?;
+            result.extend(values);
+        }
+        Ok(result)
+    }
+
+    /// Diff two snapshots into changes, masking secret values
+    ///
+    /// Only namespaces present in both snapshots are compared.
+    fn diff_snapshots(before: &Snapshot, after: &Snapshot) -> Vec<ConfigChange> {
+        let mut changes = Vec::new();
+
+        for (namespace, new_values) in after {
+            let Some(old_values) = before.get(namespace) else {
+                continue;
+            };
+            let keys: BTreeSet<&String> = old_values.keys().chain(new_values.keys()).collect();
+
+            for key in keys {
+                let old = old_values.get(key);
+                let new = new_values.get(key);
+                if old.map(|v| &v.value) == new.map(|v| &v.value) {
+                    continue;
+                }
+
+                let masked = is_sensitive_key(key)
+                    || old.is_some_and(|v| v.metadata.is_secret)
+                    || new.is_some_and(|v| v.metadata.is_secret);
+                let render = |v: Option<&ProviderValue>| {
+                    v.map(|v| if masked { MASKED_VALUE.to_string() } else { v.value.clone() })
+                };
+
+                changes.push(ConfigChange {
+                    namespace: namespace.clone(),
+                    key: key.clone(),
+                    old_value: render(old),
+                    new_value: render(new),
+                    masked,
+                });
+            }
+        }
+
+        changes.sort_by(|a, b| (&a.namespace, &a.key).cmp(&(&b.namespace, &b.key)));
+        changes
+    }
+
+    /// Refresh every provider in the chain
+    ///
+    /// A failing provider does not stop the others from refreshing; its
+    /// error is recorded in the returned report. Changes to watched
+    /// namespaces since the previous refresh are included in the report and
+    /// passed to `on_change` callbacks.
+    pub async fn refresh_all(&self) -> ChainRefreshReport {
+        let mut report = ChainRefreshReport::default();
+        let _refreshing = self.watch.refreshing.lock().await;
+
+        let watching = !self.watch.namespaces.is_empty();
+        let previous = if watching {
+            let stored = self.watch.snapshot.lock().ok().and_then(|s| s.clone());
+            match stored {
+                Some(snapshot) => Some(snapshot),
+                None => Some(self.watched_snapshot().await),
+            }
+        } else {
+            None
+        };
+
+        for provider in &self.providers {
+            match provider.refresh().await {
+                Ok(()) => report.refreshed.push(provider.name().to_string()),
+                Err(e) => {
+                    tracing::warn!(
+                        provider = provider.name(),
+                        error = %e,
+                        "Provider refresh failed"
+                    );
+                    report.failures.push((provider.name().to_string(), e));
+                }
+            }
+        }
+
+        if let Some(mut before) = previous {
+            let after = self.watched_snapshot().await;
+            report.changes = Self::diff_snapshots(&before, &after);
+            // Namespaces that failed to list keep their previous values
+            before.extend(after);
+            if let Ok(mut stored) = self.watch.snapshot.lock() {
+                *stored = Some(before);
+            }
+
+            if !report.changes.is_empty() {
+                let callbacks = self
+                    .watch
+                    .callbacks
+                    .read()
+                    .map(|c| c.clone())
+                    .unwrap_or_default();
+                for change in &report.changes {
+                    for callback in &callbacks {
+                        callback(change);
+                    }
+                }
+            }
+        }
+
+        report
+    }
+
+    /// Refresh the chain in the background every `interval`
+    ///
+    /// The first refresh happens one interval after spawning. The task runs
+    /// until the returned handle is stopped or dropped. Intervals shorter
+    /// than [`MIN_REFRESH_INTERVAL`] (including zero) are raised to it. Must
+    /// be called from within a Tokio runtime.
+    pub fn spawn_refresh(self: Arc<Self>, interval: Duration) -> RefreshHandle {
+        if interval < MIN_REFRESH_INTERVAL {
+            tracing::warn!(
+                requested = ?interval,
+                minimum = ?MIN_REFRESH_INTERVAL,
+                "Refresh interval too short, using the minimum"
+            );
+        }
+        let interval = interval.max(MIN_REFRESH_INTERVAL);
+
+        let task = tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            // The first tick completes immediately
+            ticker.tick().await;
+
+            loop {
+                ticker.tick().await;
+                let report = self.refresh_all().await;
+                tracing::debug!(
+                    refreshed = report.refreshed.len(),
+                    failed = report.failures.len(),
+                    "Scheduled provider refresh complete"
+                );
+            }
+        });
+
+        RefreshHandle { task, watcher: None }
+    }
+
+    /// Refresh the chain whenever a provider's file changes on disk
+    ///
+    /// Watches every provider's [`ConfigProvider::watch_paths`]. Events arriving within
+    /// `debounce` of each other are coalesced into a single refresh, so an
+    /// editor's save (often several writes) produces one notification per
+    /// changed key. The current values of watched namespaces are recorded
+    /// before watching starts, so the first edit is reported against them.
+    /// Must be called from within a Tokio runtime.
+    pub fn spawn_watch(self: Arc<Self>, debounce: Duration) -> ProviderResult<RefreshHandle> {
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+
+        let mut targets = Vec::new();
+        for path in self.watch_paths() {
+            let path = std::fs::canonicalize(&path).unwrap_or(path);
+            if path.is_dir() {
+                targets.push((path.clone(), path, RecursiveMode::Recursive));
+            } else if let Some(parent) = path.parent() {
+                // Watch the parent so replace-on-save (rename over the file)
+                // is still seen
+                targets.push((parent.to_path_buf(), path, RecursiveMode::NonRecursive));
+            }
+        }
+
+        let matches: Vec<PathBuf> = targets.iter().map(|(_, path, _)| path.clone()).collect();
+        let mut watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+            let Ok(event) = event else {
+                return;
+            };
+            if event.kind.is_access() {
+                return;
+            }
+            if event.paths.iter().any(|p| matches.iter().any(|m| p.starts_with(m))) {
+                let _ = tx.send(());
+            }
+        })
+        .map_err(|e| ProviderError::Other(format!("Failed to create file watcher: {}", e)))?;
+
+        for (target, _, mode) in &targets {
+            watcher.watch(target, *mode).map_err(|e| {
+                ProviderError::Other(format!("Failed to watch {}: {}", target.display(), e))
+            })?;
+        }
+
+        let task = tokio::spawn(async move {
+            self.record_baseline().await;
+
+            while rx.recv().await.is_some() {
+                // Wait for the burst of events to settle
+                loop {
+                    match tokio::time::timeout(debounce, rx.recv()).await {
+                        Ok(Some(())) => continue,
+                        Ok(None) => return,
+                        Err(_) => break,
+                    }
+                }
+
+                let report = self.refresh_all().await;
+                tracing::debug!(
+                    refreshed = report.refreshed.len(),
+                    failed = report.failures.len(),
+                    changes = report.changes.len(),
+                    "File change refresh complete"
+                );
+            }
+        });
+
+        Ok(RefreshHandle { task, watcher: Some(watcher) })
+    }
+
+    /// Record the current values of watched namespaces if none are stored
+    async fn record_baseline(&self) {
+        if self.watch.namespaces.is_empty() {
+            return;
+        }
+        let _refreshing = self.watch.refreshing.lock().await;
+        let missing = self.watch.snapshot.lock().map(|s| s.is_none()).unwrap_or(false);
+        if missing {
+            let snapshot = self.watched_snapshot().await;
+            if let Ok(mut stored) = self.watch.snapshot.lock() {
+                *stored = Some(snapshot);
+            }
+        }
+    }
+
     /// Get health status summary
     pub fn health_summary(&self) -> ChainHealthSummary {
         let statuses = self.health_check_all();
@@ -101,6 +453,53 @@ impl ProviderChain {
     }
 }
 
+/// Outcome of refreshing every provider in a chain
+#[derive(Debug, Default)]
+pub struct ChainRefreshReport {
+    /// Providers that refreshed successfully, in priority order
+    pub refreshed: Vec<String>,
+    /// Providers that failed to refresh, with their errors
+    pub failures: Vec<(String, ProviderError)>,
+    /// Changes to watched namespaces since the previous refresh
+    pub changes: Vec<ConfigChange>,
+}
+
+impl ChainRefreshReport {
+    /// Check if every provider refreshed successfully
+    pub fn is_ok(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+/// Handle to a background refresh task started by
+/// [`ProviderChain::spawn_refresh`] or [`ProviderChain::spawn_watch`]
+///
+/// Dropping the handle stops the task and any file watcher.
+#[derive(Debug)]
+pub struct RefreshHandle {
+    task: tokio::task::JoinHandle<()>,
+    watcher: Option<notify::RecommendedWatcher>,
+}
+
+impl RefreshHandle {
+    /// Stop the background refresh
+    pub fn stop(mut self) {
+        self.watcher.take();
+        self.task.abort();
+    }
+
+    /// Check if the background task is still running
+    pub fn is_running(&self) -> bool {
+        !self.task.is_finished()
+    }
+}
+
+impl Drop for RefreshHandle {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
 /// Health summary for the entire provider chain
 #[derive(Debug, Clone)]
 pub struct ChainHealthSummary {
@@ -202,10 +601,8 @@ impl ConfigProvider for ProviderChain {
     }
 
     async fn refresh(&self) -> ProviderResult<()> {
-        for provider in &self.providers {
-            // Refresh all providers, ignore individual errors
-            let _ = provider.refresh().await;
-        }
+        // Refresh all providers; individual errors are logged, not returned
+        self.refresh_all().await;
         Ok(())
     }
 
@@ -217,6 +614,13 @@ impl ConfigProvider for ProviderChain {
             Ok(ProviderHealth::unhealthy("chain", "No healthy providers"))
         }
     }
+
+    fn watch_paths(&self) -> Vec<PathBuf> {
+        let mut paths: Vec<PathBuf> = self.providers.iter().flat_map(|p| p.watch_paths()).collect();
+        paths.sort();
+        paths.dedup();
+        paths
+    }
 }
 
 /// Builder for creating provider chains with common patterns
@@ -331,6 +735,217 @@ mod tests {
     use super::*;
     use super::super::env::EnvProvider;
     use super::super::bundles::JsonProvider;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Provider that counts refreshes and optionally fails them
+    #[derive(Debug)]
+    struct RefreshProbe {
+        name: &'static str,
+        fail: bool,
+        refreshes: AtomicUsize,
+    }
+
+    impl RefreshProbe {
+        fn new(name: &'static str, fail: bool) -> Arc<Self> {
+            Arc::new(Self { name, fail, refreshes: AtomicUsize::new(0) })
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl ConfigProvider for RefreshProbe {
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        async fn get(&self, namespace: &str, key: &str) -> ProviderResult<ProviderValue> {
+            Err(ProviderError::NotFound {
+                namespace: namespace.to_string(),
+                key: key.to_string(),
+            })
+        }
+
+        async fn refresh(&self) -> ProviderResult<()> {
+            self.refreshes.fetch_add(1, Ordering::SeqCst);
+            if self.fail {
+                Err(ProviderError::ConnectionError("backend unreachable".to_string()))
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_refresh_all_collects_errors() {
+        let first = RefreshProbe::new("first", false);
+        let broken = RefreshProbe::new("broken", true);
+        let last = RefreshProbe::new("last", false);
+
+        let mut chain = ProviderChain::new();
+        chain.add_arc_provider(first.clone());
+        chain.add_arc_provider(broken.clone());
+        chain.add_arc_provider(last.clone());
+
+        let report = chain.refresh_all().await;
+        assert!(!report.is_ok());
+        assert_eq!(report.refreshed, vec!["first", "last"]);
+        assert_eq!(report.failures.len(), 1);
+        assert_eq!(report.failures[0].0, "broken");
+        assert!(matches!(report.failures[0].1, ProviderError::ConnectionError(_)));
+
+        // Every provider was refreshed despite the failure in the middle
+        assert_eq!(first.refreshes.load(Ordering::SeqCst), 1);
+        assert_eq!(broken.refreshes.load(Ordering::SeqCst), 1);
+        assert_eq!(last.refreshes.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_on_change_fires_for_edited_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("app.json");
+        std::fs::write(&path, r#"{"app": {"name": "api", "db_password": "old-secret"}}"#).unwrap();
+
+        let chain = ProviderChain::new()
+            .with_provider(JsonProvider::from_file(&path).unwrap())
+            .with_watched_namespace("app");
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let sink = seen.clone();
+        chain.on_change(move |change| sink.lock().unwrap().push(change.clone()));
+
+        // Baseline refresh: nothing changed yet
+        assert!(chain.refresh_all().await.changes.is_empty());
+
+        // Two rapid edits between refreshes coalesce into one change per key
+        std::fs::write(&path, r#"{"app": {"name": "api-v2", "db_password": "mid-secret"}}"#).unwrap();
+        std::fs::write(&path, r#"{"app": {"name": "api-v3", "db_password": "new-secret"}}"#).unwrap();
+        chain.refresh_all().await;
+
+        let seen = seen.lock().unwrap();
+        assert_eq!(seen.len(), 2);
+
+        let password = seen.iter().find(|c| c.key == "db_password").unwrap();
+        assert!(password.masked);
+        assert_eq!(password.old_value.as_deref(), Some(MASKED_VALUE));
+        assert_eq!(password.new_value.as_deref(), Some(MASKED_VALUE));
+
+        let name = seen.iter().find(|c| c.key == "name").unwrap();
+        assert_eq!(name.namespace, "app");
+        assert_eq!(name.old_value.as_deref(), Some("api"));
+        assert_eq!(name.new_value.as_deref(), Some("api-v3"));
+        assert!(!name.masked);
+
+        let rendered = format!("{:?}", *seen);
+        assert!(!rendered.contains("old-secret") && !rendered.contains("new-secret"));
+    }
+
+    #[tokio::test]
+    async fn test_spawn_watch_notifies_on_file_edit() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("app.json");
+        std::fs::write(&path, r#"{"app": {"name": "api"}}"#).unwrap();
+
+        let chain = ProviderChain::new()
+            .with_provider(JsonProvider::from_file(&path).unwrap())
+            .with_watched_namespace("app");
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let sink = seen.clone();
+        chain.on_change(move |change| sink.lock().unwrap().push(change.clone()));
+
+        let chain = Arc::new(chain);
+        let handle = chain.clone().spawn_watch(Duration::from_millis(50)).unwrap();
+        // Let the task record the baseline before editing
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        std::fs::write(&path, r#"{"app": {"name": "api-v2"}}"#).unwrap();
+        std::fs::write(&path, r#"{"app": {"name": "api-v3"}}"#).unwrap();
+
+        let deadline = tokio::time::Instant::now() + Duration::from_secs(5);
+        while seen.lock().unwrap().is_empty() && tokio::time::Instant::now() < deadline {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        assert!(handle.is_running());
+
+        let seen = seen.lock().unwrap();
+        assert_eq!(seen.len(), 1);
+        assert_eq!(seen[0].old_value.as_deref(), Some("api"));
+        assert_eq!(seen[0].new_value.as_deref(), Some("api-v3"));
+    }
+
+    /// Provider whose `list` can be made to fail
+    #[derive(Debug, Default)]
+    struct FlakyList {
+        failing: std::sync::atomic::AtomicBool,
+    }
+
+    #[async_trait::async_trait]
+    impl ConfigProvider for FlakyList {
+        fn name(&self) -> &str {
+            "flaky"
+        }
+
+        async fn get(&self, namespace: &str, key: &str) -> ProviderResult<ProviderValue> {
+            Err(ProviderError::NotFound {
+                namespace: namespace.to_string(),
+                key: key.to_string(),
+            })
+        }
+
+        async fn list(&self, _namespace: &str, _prefix: Option<&str>) -> ProviderResult<HashMap<String, ProviderValue>> {
+            if self.failing.load(Ordering::SeqCst) {
+                return Err(ProviderError::ConnectionError("backend unreachable".to_string()));
+            }
+            Ok(HashMap::from([("name".to_string(), ProviderValue::new("api", "flaky"))]))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_failed_list_is_not_reported_as_removal() {
+        let flaky = Arc::new(FlakyList::default());
+        let mut chain = ProviderChain::new().with_watched_namespace("app");
+        chain.add_arc_provider(flaky.clone());
+
+        assert!(chain.refresh_all().await.changes.is_empty());
+
+        flaky.failing.store(true, Ordering::SeqCst);
+        assert!(chain.refresh_all().await.changes.is_empty());
+
+        // Recovery is compared against the last good values
+        flaky.failing.store(false, Ordering::SeqCst);
+        assert!(chain.refresh_all().await.changes.is_empty());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_scheduled_refresh() {
+        let probe = RefreshProbe::new("probe", false);
+        let mut chain = ProviderChain::new();
+        chain.add_arc_provider(probe.clone());
+
+        let handle = Arc::new(chain).spawn_refresh(Duration::from_secs(60));
+
+        // Nothing happens before the first interval elapses
+        tokio::time::sleep(Duration::from_secs(59)).await;
+        assert_eq!(probe.refreshes.load(Ordering::SeqCst), 0);
+
+        tokio::time::sleep(Duration::from_secs(122)).await;
+        assert!(handle.is_running());
+        assert_eq!(probe.refreshes.load(Ordering::SeqCst), 3);
+
+        handle.stop();
+        tokio::time::sleep(Duration::from_secs(600)).await;
+        assert_eq!(probe.refreshes.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_zero_refresh_interval_is_clamped() {
+        let probe = RefreshProbe::new("probe", false);
+        let mut chain = ProviderChain::new();
+        chain.add_arc_provider(probe.clone());
+
+        let handle = Arc::new(chain).spawn_refresh(Duration::ZERO);
+        tokio::time::sleep(MIN_REFRESH_INTERVAL * 5 + MIN_REFRESH_INTERVAL / 2).await;
+
+        assert!(handle.is_running());
+        assert_eq!(probe.refreshes.load(Ordering::SeqCst), 5);
+    }
 
     #[tokio::test]
     async fn test_empty_chain() {
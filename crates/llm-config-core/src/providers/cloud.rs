@@ -6,7 +6,8 @@
 //! - **AWS SSM Parameter Store**: Hierarchical parameter storage
 //! - **AWS Secrets Manager**: Rotating secrets with audit trails
 //! - **GCP Secret Manager**: Google Cloud secret storage
-//! - **Azure Key Vault**: Microsoft Azure secret management
+//! - **Azure Key Vault**: Microsoft Azure secret management (plus certificates
+//!   and key metadata with the `cloud` feature)
 //!
 //! # Design Philosophy
 //!
@@ -16,7 +17,20 @@
 //!
 //! For production use, implement the `ConfigProvider` trait with actual
 //! SDK calls, or use these stubs with environment variable fallbacks
-//! for local development.
+//! for local development. The exception is Azure Key Vault certificates and
+//! keys, which the `cloud` feature reads from the Key Vault REST API.
+//!
+//! An SDK can also be plugged into a stub with `with_secret_client`, passing
+//! a [`CloudSecretClient`] that fetches secrets by their cloud resource name.
+//! Payloads are returned as a [`SecretString`] so they are zeroized once
+//! dropped.
+//!
+//! # Guards
+//!
+//! Every `get` is bounded by [`CloudProviderConfig::timeout`] and
+//! [`CloudProviderConfig::max_value_bytes`], so a hung API call or a giant
+//! secret fails with `ProviderError::Timeout` or `ProviderError::TooLarge`
+//! instead of stalling the caller.
 //!
 //! # Example
 //!
@@ -28,13 +42,20 @@
 //! let secret = aws.get("production", "database/password").await?;
 //! ```
 
+use super::secret::SecretString;
 use super::traits::{
     ConfigProvider, SecretProvider, ProviderError, ProviderResult,
     ProviderValue, ProviderHealth, ValueMetadata,
 };
 use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Arc;
 use std::time::Duration;
 
+/// Default limit on the size of a single secret value (64 KiB, the largest
+/// secret AWS Secrets Manager and GCP Secret Manager store)
+pub const DEFAULT_MAX_VALUE_BYTES: usize = 64 * 1024;
+
 /// Configuration for cloud providers
 ///
 /// This struct holds common configuration for all cloud providers.
@@ -59,8 +80,12 @@ pub struct CloudProviderConfig {
     pub azure_client_id: Option<String>,
     /// Azure client secret
     pub azure_client_secret: Option<String>,
-    /// Request timeout
+    /// Azure AD authority host used to obtain Key Vault access tokens
+    pub azure_authority_host: String,
+    /// Request timeout, also bounding each `get`
     pub timeout: Duration,
+    /// Maximum size of a single value, in bytes
+    pub max_value_bytes: usize,
     /// Maximum number of retries
     pub max_retries: u32,
 }
@@ -77,7 +102,9 @@ impl Default for CloudProviderConfig {
             azure_tenant_id: None,
             azure_client_id: None,
             azure_client_secret: None,
+            azure_authority_host: "https://login.microsoftonline.com".to_string(),
             timeout: Duration::from_secs(30),
+            max_value_bytes: DEFAULT_MAX_VALUE_BYTES,
             max_retries: 3,
         }
     }
@@ -86,7 +113,7 @@ impl Default for CloudProviderConfig {
 impl CloudProviderConfig {
     /// Load configuration from environment variables
     pub fn from_env() -> Self {
-        Self {
+        let config = Self {
             aws_region: std::env::var("AWS_REGION")
                 .or_else(|_| std::env::var("AWS_DEFAULT_REGION"))
                 .ok(),
@@ -101,6 +128,11 @@ impl CloudProviderConfig {
             azure_client_id: std::env::var("AZURE_CLIENT_ID").ok(),
             azure_client_secret: std::env::var("AZURE_CLIENT_SECRET").ok(),
             ..Default::default()
+        };
+
+        match std::env::var("AZURE_AUTHORITY_HOST") {
+            Ok(host) => config.with_azure_authority_host(host),
+            Err(_) => config,
         }
     }
 
@@ -122,11 +154,73 @@ impl CloudProviderConfig {
         self
     }
 
+    /// Set Azure AD service principal credentials
+    pub fn with_azure_credentials(
+        mut self,
+        tenant_id: impl Into<String>,
+        client_id: impl Into<String>,
+        client_secret: impl Into<String>,
+    ) -> Self {
+        self.azure_tenant_id = Some(tenant_id.into());
+        self.azure_client_id = Some(client_id.into());
+        self.azure_client_secret = Some(client_secret.into());
+        self
+    }
+
+    /// Set the Azure AD authority host (e.g. for sovereign clouds)
+    pub fn with_azure_authority_host(mut self, host: impl Into<String>) -> Self {
+        self.azure_authority_host = host.into().trim_end_matches('/').to_string();
+        self
+    }
+
     /// Set timeout
     pub fn with_timeout(mut self, timeout: Duration) -> Self {
         self.timeout = timeout;
         self
     }
+
+    /// Set the maximum size of a single value, in bytes
+    pub fn with_max_value_bytes(mut self, max_value_bytes: usize) -> Self {
+        self.max_value_bytes = max_value_bytes;
+        self
+    }
+
+    /// Run a fetch for `namespace/key` within the timeout and size limit
+    async fn guard<F>(&self, provider: &str, namespace: &str, key: &str, fetch: F) -> ProviderResult<SecretString>
+    where
+        F: Future<Output = ProviderResult<SecretString>>,
+    {
+        let value = tokio::time::timeout(self.timeout, fetch)
+            .await
+            .map_err(|_| {
+                ProviderError::Timeout(format!(
+                    "{}: get {}/{} took longer than {:?}",
+                    provider, namespace, key, self.timeout
+                ))
+            })??;
+
+        if value.len() > self.max_value_bytes {
+            return Err(ProviderError::TooLarge {
+                namespace: namespace.to_string(),
+                key: key.to_string(),
+                size: value.len(),
+                limit: self.max_value_bytes,
+            });
+        }
+        Ok(value)
+    }
+}
+
+/// Client for a cloud secret manager's API
+///
+/// Implement this over a cloud SDK and pass it to a provider's
+/// `with_secret_client` to replace the environment variable stub.
+#[async_trait::async_trait]
+pub trait CloudSecretClient: Send + Sync + std::fmt::Debug {
+    /// Fetch the raw payload of the secret with the provider-specific `name`
+    ///
+    /// Missing secrets should be reported as `ProviderError::NotFound`.
+    async fn get_secret(&self, name: &str) -> ProviderResult<SecretString>;
 }
 
 // ============================================================================
@@ -156,6 +250,8 @@ pub struct AwsSsmProvider {
     config: CloudProviderConfig,
     /// Path prefix (default: "/")
     prefix: String,
+    /// SDK client used instead of the stub, if set
+    secret_client: Option<Arc<dyn CloudSecretClient>>,
 }
 
 impl AwsSsmProvider {
@@ -164,9 +260,17 @@ impl AwsSsmProvider {
         Ok(Self {
             config,
             prefix: "/".to_string(),
+            secret_client: None,
         })
     }
 
+    /// Fetch parameters through `client` (by parameter path) instead of the
+    /// environment variable stub
+    pub fn with_secret_client(mut self, client: Arc<dyn CloudSecretClient>) -> Self {
+        self.secret_client = Some(client);
+        self
+    }
+
     /// Set a path prefix for all parameters
     pub fn with_prefix(mut self, prefix: impl Into<String>) -> Self {
         self.prefix = prefix.into();
@@ -185,7 +289,7 @@ impl AwsSsmProvider {
     }
 
     /// Stub: Get parameter from environment variable fallback
-    fn get_stub(&self, namespace: &str, key: &str) -> ProviderResult<String> {
+    fn get_stub(&self, namespace: &str, key: &str) -> ProviderResult<SecretString> {
         // For local development, fall back to env vars
         let env_key = format!(
             "AWS_SSM_{}_{}",
@@ -193,7 +297,7 @@ impl AwsSsmProvider {
             key.to_uppercase().replace('/', "_")
         );
 
-        std::env::var(&env_key).map_err(|_| ProviderError::NotFound {
+        std::env::var(&env_key).map(SecretString::from).map_err(|_| ProviderError::NotFound {
             namespace: namespace.to_string(),
             key: key.to_string(),
         })
@@ -219,10 +323,16 @@ impl ConfigProvider for AwsSsmProvider {
         //     .send()
         //     .await?;
 
-        let value = self.get_stub(namespace, key)?;
         let path = self.build_path(namespace, key);
-
-        Ok(ProviderValue::secret(value, "aws_ssm")
+        let fetch = async {
+            match &self.secret_client {
+                Some(client) => client.get_secret(&path).await,
+                None => self.get_stub(namespace, key),
+            }
+        };
+        let value = self.config.guard("aws_ssm", namespace, key, fetch).await?;
+
+        Ok(ProviderValue::from_secret(value, "aws_ssm")
             .with_version(format!("path:{}", path)))
     }
 
@@ -262,6 +372,8 @@ pub struct AwsSecretsManagerProvider {
     config: CloudProviderConfig,
     /// Separator between namespace and key (default: "/")
     separator: String,
+    /// SDK client used instead of the stub, if set
+    secret_client: Option<Arc<dyn CloudSecretClient>>,
 }
 
 impl AwsSecretsManagerProvider {
@@ -270,30 +382,37 @@ impl AwsSecretsManagerProvider {
         Ok(Self {
             config,
             separator: "/".to_string(),
+            secret_client: None,
         })
     }
 
+    /// Fetch secrets through `client` (by secret name) instead of the
+    /// environment variable stub
+    pub fn with_secret_client(mut self, client: Arc<dyn CloudSecretClient>) -> Self {
+        self.secret_client = Some(client);
+        self
+    }
+
     /// Use a different separator (e.g., "-" or "_")
     pub fn with_separator(mut self, sep: impl Into<String>) -> Self {
         self.separator = sep.into();
         self
     }
 
-    /// Build the secret name (used when real SDK is integrated)
-    #[allow(dead_code)]
+    /// Build the secret name
     fn build_name(&self, namespace: &str, key: &str) -> String {
         format!("{}{}{}", namespace, self.separator, key)
     }
 
     /// Stub: Get secret from environment variable fallback
-    fn get_stub(&self, namespace: &str, key: &str) -> ProviderResult<String> {
+    fn get_stub(&self, namespace: &str, key: &str) -> ProviderResult<SecretString> {
         let env_key = format!(
             "AWS_SECRET_{}_{}",
             namespace.to_uppercase().replace(&self.separator, "_"),
             key.to_uppercase().replace(&self.separator, "_")
         );
 
-        std::env::var(&env_key).map_err(|_| ProviderError::NotFound {
+        std::env::var(&env_key).map(SecretString::from).map_err(|_| ProviderError::NotFound {
             namespace: namespace.to_string(),
             key: key.to_string(),
         })
@@ -318,9 +437,18 @@ impl ConfigProvider for AwsSecretsManagerProvider {
         //     .send()
         //     .await?;
 
-        let value = self.get_stub(namespace, key)?;
-
-        Ok(ProviderValue::secret(value, "aws_secrets_manager"))
+        let fetch = async {
+            match &self.secret_client {
+                Some(client) => client.get_secret(&self.build_name(namespace, key)).await,
+                None => self.get_stub(namespace, key),
+            }
+        };
+        let value = self
+            .config
+            .guard("aws_secrets_manager", namespace, key, fetch)
+            .await?;
+
+        Ok(ProviderValue::from_secret(value, "aws_secrets_manager"))
     }
 
     fn health_check(&self) -> ProviderResult<ProviderHealth> {
@@ -396,16 +524,27 @@ impl SecretProvider for AwsSecretsManagerProvider {
 #[derive(Debug)]
 pub struct GcpSecretManagerProvider {
     config: CloudProviderConfig,
+    /// SDK client used instead of the stub, if set
+    secret_client: Option<Arc<dyn CloudSecretClient>>,
 }
 
 impl GcpSecretManagerProvider {
     /// Create a new GCP Secret Manager provider
     pub fn new(config: CloudProviderConfig) -> ProviderResult<Self> {
-        Ok(Self { config })
+        Ok(Self {
+            config,
+            secret_client: None,
+        })
     }
 
-    /// Build the secret resource name (used when real SDK is integrated)
-    #[allow(dead_code)]
+    /// Fetch secrets through `client` (by resource name) instead of the
+    /// environment variable stub
+    pub fn with_secret_client(mut self, client: Arc<dyn CloudSecretClient>) -> Self {
+        self.secret_client = Some(client);
+        self
+    }
+
+    /// Build the secret resource name
     fn build_resource_name(&self, namespace: &str, key: &str) -> Option<String> {
         let project = self.config.gcp_project_id.as_ref()?;
         Some(format!(
@@ -415,14 +554,14 @@ impl GcpSecretManagerProvider {
     }
 
     /// Stub: Get secret from environment variable fallback
-    fn get_stub(&self, namespace: &str, key: &str) -> ProviderResult<String> {
+    fn get_stub(&self, namespace: &str, key: &str) -> ProviderResult<SecretString> {
         let env_key = format!(
             "GCP_SECRET_{}_{}",
             namespace.to_uppercase(),
             key.to_uppercase()
         );
 
-        std::env::var(&env_key).map_err(|_| ProviderError::NotFound {
+        std::env::var(&env_key).map(SecretString::from).map_err(|_| ProviderError::NotFound {
             namespace: namespace.to_string(),
             key: key.to_string(),
         })
@@ -446,9 +585,23 @@ impl ConfigProvider for GcpSecretManagerProvider {
         //     .access(&self.build_resource_name(namespace, key))
         //     .await?;
 
-        let value = self.get_stub(namespace, key)?;
-
-        Ok(ProviderValue::secret(value, "gcp_secret_manager"))
+        let fetch = async {
+            match &self.secret_client {
+                Some(client) => {
+                    let name = self.build_resource_name(namespace, key).ok_or_else(|| {
+                        ProviderError::ConfigurationError("GCP_PROJECT_ID not configured".to_string())
+                    })?;
+                    client.get_secret(&name).await
+                }
+                None => self.get_stub(namespace, key),
+            }
+        };
+        let value = self
+            .config
+            .guard("gcp_secret_manager", namespace, key, fetch)
+            .await?;
+
+        Ok(ProviderValue::from_secret(value, "gcp_secret_manager"))
     }
 
     fn health_check(&self) -> ProviderResult<ProviderHealth> {
@@ -509,33 +662,65 @@ impl SecretProvider for GcpSecretManagerProvider {
 /// # Stub Implementation
 ///
 /// Falls back to `AZURE_SECRET_{NAMESPACE}_{KEY}` environment variables.
+///
+/// # Certificates and Keys
+///
+/// With the `cloud` feature, [`get_certificate`](Self::get_certificate) and
+/// [`get_key`](Self::get_key) call the Key Vault REST API directly,
+/// authenticating as the configured service principal (client credentials).
 #[derive(Debug)]
 pub struct AzureKeyVaultProvider {
     config: CloudProviderConfig,
+    /// SDK client used instead of the stub, if set
+    secret_client: Option<Arc<dyn CloudSecretClient>>,
+    #[cfg(feature = "cloud")]
+    client: reqwest::Client,
+    /// Cached access token and the instant it expires
+    #[cfg(feature = "cloud")]
+    token: std::sync::Mutex<Option<(String, std::time::Instant)>>,
 }
 
 impl AzureKeyVaultProvider {
     /// Create a new Azure Key Vault provider
     pub fn new(config: CloudProviderConfig) -> ProviderResult<Self> {
-        Ok(Self { config })
+        #[cfg(feature = "cloud")]
+        let client = reqwest::Client::builder()
+            .timeout(config.timeout)
+            .build()
+            .map_err(|e| ProviderError::ConfigurationError(e.to_string()))?;
+
+        Ok(Self {
+            config,
+            secret_client: None,
+            #[cfg(feature = "cloud")]
+            client,
+            #[cfg(feature = "cloud")]
+            token: std::sync::Mutex::new(None),
+        })
     }
 
-    /// Build the secret URL (used when real SDK is integrated)
-    #[allow(dead_code)]
+    /// Fetch secrets through `client` (by secret URL) instead of the
+    /// environment variable stub
+    pub fn with_secret_client(mut self, client: Arc<dyn CloudSecretClient>) -> Self {
+        self.secret_client = Some(client);
+        self
+    }
+
+    /// Build the secret URL
     fn build_url(&self, namespace: &str, key: &str) -> Option<String> {
         let vault_url = self.config.azure_vault_url.as_ref()?;
         Some(format!("{}/secrets/{}-{}", vault_url, namespace, key))
     }
 
     /// Stub: Get secret from environment variable fallback
-    fn get_stub(&self, namespace: &str, key: &str) -> ProviderResult<String> {
+    fn get_stub(&self, namespace: &str, key: &str) -> ProviderResult<SecretString> {
         let env_key = format!(
             "AZURE_SECRET_{}_{}",
             namespace.to_uppercase(),
             key.to_uppercase()
         );
 
-        std::env::var(&env_key).map_err(|_| ProviderError::NotFound {
+        std::env::var(&env_key).map(SecretString::from).map_err(|_| ProviderError::NotFound {
             namespace: namespace.to_string(),
             key: key.to_string(),
         })
@@ -558,9 +743,23 @@ impl ConfigProvider for AzureKeyVaultProvider {
         // let client = SecretClient::new(&vault_url, credential)?;
         // let secret = client.get(&secret_name).await?;
 
-        let value = self.get_stub(namespace, key)?;
-
-        Ok(ProviderValue::secret(value, "azure_key_vault"))
+        let fetch = async {
+            match &self.secret_client {
+                Some(client) => {
+                    let url = self.build_url(namespace, key).ok_or_else(|| {
+                        ProviderError::ConfigurationError("AZURE_VAULT_URL not configured".to_string())
+                    })?;
+                    client.get_secret(&url).await
+                }
+                None => self.get_stub(namespace, key),
+            }
+        };
+        let value = self
+            .config
+            .guard("azure_key_vault", namespace, key, fetch)
+            .await?;
+
+        Ok(ProviderValue::from_secret(value, "azure_key_vault"))
     }
 
     fn health_check(&self) -> ProviderResult<ProviderHealth> {
@@ -606,6 +805,273 @@ impl SecretProvider for AzureKeyVaultProvider {
     }
 }
 
+/// A certificate stored in Azure Key Vault
+///
+/// Carries the PEM-encoded certificate for mTLS integration checks and its
+/// expiry so callers can warn before the certificate lapses.
+#[cfg(feature = "cloud")]
+#[derive(Debug, Clone)]
+pub struct KeyVaultCertificate {
+    /// Certificate name in the vault (`{namespace}-{key}`)
+    pub name: String,
+    /// PEM-encoded certificate
+    pub pem: String,
+    /// Expiry (`attributes.exp` in Key Vault)
+    pub expires_on: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[cfg(feature = "cloud")]
+impl KeyVaultCertificate {
+    /// Whether the certificate has expired as of `now`
+    pub fn is_expired_at(&self, now: chrono::DateTime<chrono::Utc>) -> bool {
+        self.expires_on.is_some_and(|exp| exp <= now)
+    }
+
+    /// Time remaining until expiry as of `now` (negative once expired)
+    pub fn expires_in(&self, now: chrono::DateTime<chrono::Utc>) -> Option<chrono::Duration> {
+        self.expires_on.map(|exp| exp - now)
+    }
+
+    /// Convert to a provider value, recording expiry in the metadata
+    pub fn into_provider_value(self) -> ProviderValue {
+        let mut value = ProviderValue::new(self.pem, "azure_key_vault");
+        value
+            .metadata
+            .extra
+            .insert("content_type".to_string(), "application/x-pem-file".to_string());
+        if let Some(exp) = self.expires_on {
+            value
+                .metadata
+                .extra
+                .insert("expires_on".to_string(), exp.to_rfc3339());
+        }
+        value
+    }
+}
+
+/// Metadata for a key stored in Azure Key Vault
+///
+/// Key material never leaves the vault, so only metadata is returned.
+#[cfg(feature = "cloud")]
+#[derive(Debug, Clone)]
+pub struct KeyVaultKey {
+    /// Key name in the vault (`{namespace}-{key}`)
+    pub name: String,
+    /// Key type (e.g., "RSA", "EC", "RSA-HSM")
+    pub key_type: String,
+    /// Whether the key is enabled
+    pub enabled: bool,
+    /// Expiry (`attributes.exp` in Key Vault)
+    pub expires_on: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[cfg(feature = "cloud")]
+const KEY_VAULT_API_VERSION: &str = "7.4";
+
+#[cfg(feature = "cloud")]
+const KEY_VAULT_SCOPE: &str = "https://vault.azure.net/.default";
+
+/// Refresh access tokens this long before they expire
+#[cfg(feature = "cloud")]
+const TOKEN_REFRESH_MARGIN: Duration = Duration::from_secs(60);
+
+#[cfg(feature = "cloud")]
+impl AzureKeyVaultProvider {
+    /// Get a certificate with its expiry
+    ///
+    /// Reads `{vault_url}/certificates/{namespace}-{key}` and converts the
+    /// DER content (`cer`) to PEM.
+    pub async fn get_certificate(
+        &self,
+        namespace: &str,
+        key: &str,
+    ) -> ProviderResult<KeyVaultCertificate> {
+        let document = self.get_object("certificates", namespace, key).await?;
+
+        let der = document["cer"].as_str().ok_or_else(|| {
+            ProviderError::SerializationError(format!(
+                "Certificate '{}-{}' has no content",
+                namespace, key
+            ))
+        })?;
+
+        Ok(KeyVaultCertificate {
+            name: format!("{}-{}", namespace, key),
+            pem: der_base64_to_pem(der),
+            expires_on: expiry_from_attributes(&document),
+        })
+    }
+
+    /// Get key metadata (key material is never returned)
+    ///
+    /// Reads `{vault_url}/keys/{namespace}-{key}`.
+    pub async fn get_key(&self, namespace: &str, key: &str) -> ProviderResult<KeyVaultKey> {
+        let document = self.get_object("keys", namespace, key).await?;
+
+        let key_type = document["key"]["kty"].as_str().ok_or_else(|| {
+            ProviderError::SerializationError(format!(
+                "Key '{}-{}' has no key type",
+                namespace, key
+            ))
+        })?;
+
+        Ok(KeyVaultKey {
+            name: format!("{}-{}", namespace, key),
+            key_type: key_type.to_string(),
+            enabled: document["attributes"]["enabled"].as_bool().unwrap_or(true),
+            expires_on: expiry_from_attributes(&document),
+        })
+    }
+
+    /// Fetch a vault object (`certificates` or `keys`) as JSON
+    async fn get_object(
+        &self,
+        kind: &str,
+        namespace: &str,
+        key: &str,
+    ) -> ProviderResult<serde_json::Value> {
+        let vault_url = self.config.azure_vault_url.as_deref().ok_or_else(|| {
+            ProviderError::ConfigurationError("AZURE_VAULT_URL not configured".to_string())
+        })?;
+        let token = self.access_token().await?;
+
+        let response = self
+            .client
+            .get(format!(
+                "{}/{}/{}-{}",
+                vault_url.trim_end_matches('/'),
+                kind,
+                namespace,
+                key
+            ))
+            .query(&[("api-version", KEY_VAULT_API_VERSION)])
+            .bearer_auth(token)
+            .send()
+            .await
+            .map_err(request_error)?;
+
+        match response.status() {
+            reqwest::StatusCode::NOT_FOUND => Err(ProviderError::NotFound {
+                namespace: namespace.to_string(),
+                key: key.to_string(),
+            }),
+            status => {
+                check_status(status)?;
+                let body = response.bytes().await.map_err(request_error)?;
+                if body.len() > self.config.max_value_bytes {
+                    return Err(ProviderError::TooLarge {
+                        namespace: namespace.to_string(),
+                        key: key.to_string(),
+                        size: body.len(),
+                        limit: self.config.max_value_bytes,
+                    });
+                }
+                serde_json::from_slice(&body)
+                    .map_err(|e| ProviderError::SerializationError(e.to_string()))
+            }
+        }
+    }
+
+    /// Access token for Key Vault, reusing the cached one until near expiry
+    async fn access_token(&self) -> ProviderResult<String> {
+        let now = std::time::Instant::now();
+        if let Some((token, expires_at)) = self.token.lock().unwrap().as_ref() {
+            if now + TOKEN_REFRESH_MARGIN < *expires_at {
+                return Ok(token.clone());
+            }
+        }
+
+        let (Some(tenant_id), Some(client_id), Some(client_secret)) = (
+            self.config.azure_tenant_id.as_deref(),
+            self.config.azure_client_id.as_deref(),
+            self.config.azure_client_secret.as_deref(),
+        ) else {
+            return Err(ProviderError::ConfigurationError(
+                "AZURE_TENANT_ID, AZURE_CLIENT_ID and AZURE_CLIENT_SECRET are required".to_string(),
+            ));
+        };
+
+        let response = self
+            .client
+            .post(format!(
+                "{}/{}/oauth2/v2.0/token",
+                self.config.azure_authority_host, tenant_id
+            ))
+            .form(&[
+                ("grant_type", "client_credentials"),
+                ("client_id", client_id),
+                ("client_secret", client_secret),
+                ("scope", KEY_VAULT_SCOPE),
+            ])
+            .send()
+            .await
+            .map_err(request_error)?;
+        check_status(response.status())?;
+
+        let body: serde_json::Value = response
+            .json()
+            .await
+            .map_err(|e| ProviderError::SerializationError(e.to_string()))?;
+        let token = body["access_token"]
+            .as_str()
+            .ok_or_else(|| {
+                ProviderError::AuthenticationFailed("Token response has no access_token".to_string())
+            })?
+            .to_string();
+        let expires_in = body["expires_in"].as_u64().unwrap_or(0);
+
+        *self.token.lock().unwrap() =
+            Some((token.clone(), now + Duration::from_secs(expires_in)));
+        Ok(token)
+    }
+}
+
+#[cfg(feature = "cloud")]
+fn request_error(e: reqwest::Error) -> ProviderError {
+    if e.is_timeout() {
+        ProviderError::Timeout(format!("Azure Key Vault: {}", e))
+    } else {
+        ProviderError::ConnectionError(format!("Azure Key Vault: {}", e))
+    }
+}
+
+#[cfg(feature = "cloud")]
+fn check_status(status: reqwest::StatusCode) -> ProviderResult<()> {
+    if status == reqwest::StatusCode::UNAUTHORIZED || status == reqwest::StatusCode::FORBIDDEN {
+        return Err(ProviderError::AuthenticationFailed(format!(
+            "Azure Key Vault returned {}",
+            status
+        )));
+    }
+    if !status.is_success() {
+        return Err(ProviderError::Unavailable(format!(
+            "Azure Key Vault returned {}",
+            status
+        )));
+    }
+    Ok(())
+}
+
+/// Expiry from a Key Vault object's `attributes.exp` (Unix seconds)
+#[cfg(feature = "cloud")]
+fn expiry_from_attributes(document: &serde_json::Value) -> Option<chrono::DateTime<chrono::Utc>> {
+    document["attributes"]["exp"]
+        .as_i64()
+        .and_then(|secs| chrono::DateTime::from_timestamp(secs, 0))
+}
+
+/// Wrap base64 DER certificate content as PEM
+#[cfg(feature = "cloud")]
+fn der_base64_to_pem(der: &str) -> String {
+    let mut pem = String::from("-----BEGIN CERTIFICATE-----\n");
+    for line in der.as_bytes().chunks(64) {
+        pem.push_str(&String::from_utf8_lossy(line));
+        pem.push('\n');
+    }
+    pem.push_str("-----END CERTIFICATE-----\n");
+    pem
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -681,6 +1147,228 @@ mod tests {
         std::env::remove_var("AZURE_SECRET_SERVICE_TOKEN");
     }
 
+    /// SDK stand-in returning a fixed payload after a delay, recording the
+    /// names it was asked for
+    #[derive(Debug)]
+    struct MockSecretClient {
+        payload: String,
+        delay: Duration,
+        requested: std::sync::Mutex<Vec<String>>,
+    }
+
+    impl MockSecretClient {
+        fn new(payload: impl Into<String>, delay: Duration) -> Arc<Self> {
+            Arc::new(Self {
+                payload: payload.into(),
+                delay,
+                requested: std::sync::Mutex::new(Vec::new()),
+            })
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl CloudSecretClient for MockSecretClient {
+        async fn get_secret(&self, name: &str) -> ProviderResult<SecretString> {
+            self.requested.lock().unwrap().push(name.to_string());
+            tokio::time::sleep(self.delay).await;
+            Ok(SecretString::new(self.payload.as_str()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_secret_client_is_asked_by_resource_name() {
+        let client = MockSecretClient::new("hunter2", Duration::ZERO);
+        let provider = GcpSecretManagerProvider::new(
+            CloudProviderConfig::default().with_gcp_project("my-project"),
+        )
+        .unwrap()
+        .with_secret_client(client.clone());
+
+        let value = provider.get("db", "password").await.unwrap();
+        assert_eq!(value.value, "hunter2");
+        assert_eq!(
+            *client.requested.lock().unwrap(),
+            vec!["projects/my-project/secrets/db-password/versions/latest"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_oversized_payload_is_rejected() {
+        let config = CloudProviderConfig::default()
+            .with_aws_region("us-east-1")
+            .with_max_value_bytes(16);
+
+        let provider = AwsSecretsManagerProvider::new(config.clone())
+            .unwrap()
+            .with_secret_client(MockSecretClient::new("x".repeat(17), Duration::ZERO));
+        match provider.get("app", "blob").await {
+            Err(ProviderError::TooLarge { namespace, key, size, limit }) => {
+                assert_eq!((namespace.as_str(), key.as_str()), ("app", "blob"));
+                assert_eq!((size, limit), (17, 16));
+            }
+            other => panic!("expected TooLarge, got {:?}", other),
+        }
+
+        let provider = AwsSecretsManagerProvider::new(config)
+            .unwrap()
+            .with_secret_client(MockSecretClient::new("x".repeat(16), Duration::ZERO));
+        assert_eq!(provider.get("app", "blob").await.unwrap().value.len(), 16);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_slow_client_times_out() {
+        let config = CloudProviderConfig::default()
+            .with_aws_region("us-east-1")
+            .with_timeout(Duration::from_secs(1));
+        let provider = AwsSsmProvider::new(config)
+            .unwrap()
+            .with_secret_client(MockSecretClient::new("value", Duration::from_secs(30)));
+
+        let started = tokio::time::Instant::now();
+        match provider.get("production", "database/host").await {
+            Err(ProviderError::Timeout(message)) => {
+                assert!(message.contains("aws_ssm"), "{}", message);
+                assert!(message.contains("production/database/host"), "{}", message);
+            }
+            other => panic!("expected Timeout, got {:?}", other),
+        }
+        assert!(started.elapsed() < Duration::from_secs(30));
+    }
+
+    /// Serve canned Key Vault and Azure AD responses; `respond` maps the raw
+    /// request to a status and body
+    #[cfg(feature = "cloud")]
+    async fn mock_key_vault<F>(respond: F) -> String
+    where
+        F: Fn(&str) -> (u16, String) + Send + Sync + 'static,
+    {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let respond = std::sync::Arc::new(respond);
+
+        tokio::spawn(async move {
+            loop {
+                let Ok((mut socket, _)) = listener.accept().await else {
+                    break;
+                };
+                let respond = respond.clone();
+                tokio::spawn(async move {
+                    let mut buf = vec![0u8; 8192];
+                    let n = socket.read(&mut buf).await.unwrap_or(0);
+                    let request = String::from_utf8_lossy(&buf[..n]).to_string();
+                    let (status, body) = respond(&request);
+                    let response = format!(
+                        "HTTP/1.1 {} X\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                        status,
+                        body.len(),
+                        body
+                    );
+                    let _ = socket.write_all(response.as_bytes()).await;
+                });
+            }
+        });
+
+        format!("http://{}", addr)
+    }
+
+    #[cfg(feature = "cloud")]
+    fn key_vault_provider(endpoint: &str) -> AzureKeyVaultProvider {
+        let config = CloudProviderConfig::default()
+            .with_azure_vault(format!("{}/vault", endpoint))
+            .with_azure_authority_host(endpoint)
+            .with_azure_credentials("tenant-1", "client-1", "shh");
+        AzureKeyVaultProvider::new(config).unwrap()
+    }
+
+    /// Respond to token requests and authorized Key Vault reads
+    #[cfg(feature = "cloud")]
+    fn key_vault_response(
+        request: &str,
+        token_requests: &std::sync::atomic::AtomicUsize,
+    ) -> (u16, String) {
+        if request.starts_with("POST /tenant-1/oauth2/v2.0/token") {
+            token_requests.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            if request.contains("grant_type=client_credentials")
+                && request.contains("client_secret=shh")
+            {
+                return (200, r#"{"access_token":"kv-token","expires_in":3600}"#.to_string());
+            }
+            return (400, String::new());
+        }
+        if !request.to_lowercase().contains("authorization: bearer kv-token") {
+            return (401, String::new());
+        }
+        if request.starts_with("GET /vault/certificates/mtls-client?api-version=7.4") {
+            // 2030-01-01T00:00:00Z
+            (200, r#"{"cer":"MIIB","attributes":{"enabled":true,"exp":1893456000}}"#.to_string())
+        } else if request.starts_with("GET /vault/keys/signing-jwt?api-version=7.4") {
+            (200, r#"{"key":{"kid":"k","kty":"RSA-HSM"},"attributes":{"enabled":false}}"#.to_string())
+        } else {
+            (404, r#"{"error":{"code":"NotFound"}}"#.to_string())
+        }
+    }
+
+    #[cfg(feature = "cloud")]
+    #[tokio::test]
+    async fn test_azure_key_vault_certificate_with_expiry() {
+        let token_requests = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let counter = token_requests.clone();
+        let endpoint = mock_key_vault(move |request| key_vault_response(request, &counter)).await;
+        let provider = key_vault_provider(&endpoint);
+
+        let cert = provider.get_certificate("mtls", "client").await.unwrap();
+        assert_eq!(
+            cert.pem,
+            "-----BEGIN CERTIFICATE-----\nMIIB\n-----END CERTIFICATE-----\n"
+        );
+        let expires_on = cert.expires_on.unwrap();
+        assert_eq!(expires_on.to_rfc3339(), "2030-01-01T00:00:00+00:00");
+        assert!(!cert.is_expired_at(expires_on - chrono::Duration::days(1)));
+        assert!(cert.is_expired_at(expires_on));
+
+        let value = cert.into_provider_value();
+        assert!(!value.metadata.is_secret);
+        assert_eq!(
+            value.metadata.extra.get("expires_on").map(String::as_str),
+            Some("2030-01-01T00:00:00+00:00")
+        );
+
+        // The access token is reused for later requests
+        provider.get_certificate("mtls", "client").await.unwrap();
+        assert_eq!(token_requests.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    #[cfg(feature = "cloud")]
+    #[tokio::test]
+    async fn test_azure_key_vault_key_metadata() {
+        let token_requests = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let counter = token_requests.clone();
+        let endpoint = mock_key_vault(move |request| key_vault_response(request, &counter)).await;
+        let provider = key_vault_provider(&endpoint);
+
+        let key = provider.get_key("signing", "jwt").await.unwrap();
+        assert_eq!(key.name, "signing-jwt");
+        assert_eq!(key.key_type, "RSA-HSM");
+        assert!(!key.enabled);
+        assert!(key.expires_on.is_none());
+
+        let missing = provider.get_certificate("signing", "jwt").await;
+        assert!(matches!(missing, Err(ProviderError::NotFound { .. })));
+    }
+
+    #[cfg(feature = "cloud")]
+    #[tokio::test]
+    async fn test_azure_key_vault_requires_credentials() {
+        let config = CloudProviderConfig::default()
+            .with_azure_vault("https://myvault.vault.azure.net");
+        let provider = AzureKeyVaultProvider::new(config).unwrap();
+
+        let result = provider.get_key("signing", "jwt").await;
+        assert!(matches!(result, Err(ProviderError::ConfigurationError(_))));
+    }
+
     #[tokio::test]
     async fn test_provider_not_found() {
         let config = CloudProviderConfig::default()
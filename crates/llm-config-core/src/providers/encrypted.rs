@@ -38,6 +38,7 @@ use super::traits::{
 };
 use llm_config_crypto::{decrypt, encrypt, EncryptedData, SecretKey};
 use serde::{Deserialize, Serialize};
+use zeroize::Zeroizing;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::RwLock;
@@ -164,6 +165,7 @@ impl EncryptedFileProvider {
         }
 
         let decrypted = decrypt(&self.key, &encrypted_file.encrypted)
+            .map(Zeroizing::new)
             .map_err(|e| ProviderError::EncryptionError(e.to_string()))?;
 
         let config: ConfigContent = serde_json::from_slice(&decrypted)
@@ -183,6 +185,7 @@ impl EncryptedFileProvider {
         })?;
 
         let json = serde_json::to_vec(content)
+            .map(Zeroizing::new)
             .map_err(|e| ProviderError::SerializationError(e.to_string()))?;
 
         let encrypted = encrypt(&self.key, &json, Some("config"))
@@ -12,6 +12,18 @@
 //! - All names are uppercased
 //! - Example: namespace="database", key="host" → `DATABASE__HOST`
 //!
+//! With a prefix, the prefix is stripped before mapping, and any further
+//! separators become dots in the key:
+//!
+//! | Variable                    | Prefix | Namespace  | Key         |
+//! |-----------------------------|--------|------------|-------------|
+//! | `APP__DATABASE__HOST`       | `APP`  | `database` | `host`      |
+//! | `APP__DATABASE__POOL__SIZE` | `APP`  | `database` | `pool.size` |
+//!
+//! Parsed namespaces and keys are lowercased unless
+//! [`EnvNamingConfig::with_lowercase_keys`] disables it. Dashes in keys are
+//! written as underscores, so they do not round-trip.
+//!
 //! # Example
 //!
 //! ```rust,ignore
@@ -40,6 +52,8 @@ pub struct EnvNamingConfig {
     pub prefix: Option<String>,
     /// Whether to uppercase variable names (default: true)
     pub uppercase: bool,
+    /// Whether parsed namespaces and keys are lowercased (default: true)
+    pub lowercase_keys: bool,
 }
 
 impl Default for EnvNamingConfig {
@@ -48,6 +62,7 @@ impl Default for EnvNamingConfig {
             separator: "__".to_string(),
             prefix: None,
             uppercase: true,
+            lowercase_keys: true,
         }
     }
 }
@@ -81,32 +96,82 @@ impl EnvNamingConfig {
         }
     }
 
+    /// Set the separator between prefix, namespace, and key segments
+    pub fn with_separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Set whether parsed namespaces and keys are lowercased
+    pub fn with_lowercase_keys(mut self, lowercase_keys: bool) -> Self {
+        self.lowercase_keys = lowercase_keys;
+        self
+    }
+
     /// Parse an environment variable name into namespace and key
+    ///
+    /// The prefix (if any) is stripped, the first segment becomes the
+    /// namespace, and remaining segments are joined with dots into the key.
     pub fn parse_name(&self, name: &str) -> Option<(String, String)> {
-        let name = if self.uppercase {
-            name.to_uppercase()
+        let rest = self.strip_prefix(name)?;
+        let (namespace, key) = rest.split_once(self.separator.as_str())?;
+        if namespace.is_empty() || key.is_empty() {
+            return None;
+        }
+
+        Some((self.normalize(namespace), self.key_from_segments(key)))
+    }
+
+    /// Key for a variable if it belongs to `namespace`
+    pub fn key_in_namespace(&self, namespace: &str, name: &str) -> Option<String> {
+        let ns_prefix = self.build_name(namespace, "");
+        let head = name.get(..ns_prefix.len())?;
+        let matches = if self.uppercase {
+            head.eq_ignore_ascii_case(&ns_prefix)
         } else {
-            name.to_string()
+            head == ns_prefix
         };
 
-        // Strip prefix if present
-        let name = if let Some(ref prefix) = self.prefix {
-            let prefix_with_sep = format!("{}{}", prefix.to_uppercase(), self.separator);
-            name.strip_prefix(&prefix_with_sep)?.to_string()
+        let key = &name[ns_prefix.len()..];
+        (matches && !key.is_empty()).then(|| self.key_from_segments(key))
+    }
+
+    fn strip_prefix<'a>(&self, name: &'a str) -> Option<&'a str> {
+        let Some(prefix) = &self.prefix else {
+            return Some(name);
+        };
+
+        let expected = format!("{}{}", prefix, self.separator);
+        let head = name.get(..expected.len())?;
+        let matches = if self.uppercase {
+            head.eq_ignore_ascii_case(&expected)
         } else {
-            name
+            head == expected
         };
+        matches.then(|| &name[expected.len()..])
+    }
+
+    fn key_from_segments(&self, segments: &str) -> String {
+        self.normalize(&segments.replace(self.separator.as_str(), "."))
+    }
 
-        // Split on separator
-        let parts: Vec<&str> = name.splitn(2, &self.separator).collect();
-        if parts.len() == 2 {
-            Some((parts[0].to_lowercase(), parts[1].to_lowercase()))
+    fn normalize(&self, part: &str) -> String {
+        if self.lowercase_keys {
+            part.to_lowercase()
         } else {
-            None
+            part.to_string()
         }
     }
 }
 
+/// Check if a key name suggests a secret value
+pub(crate) fn is_sensitive_key(key: &str) -> bool {
+    let key = key.to_lowercase();
+    ["secret", "password", "token", "key"]
+        .iter()
+        .any(|word| key.contains(word))
+}
+
 /// Provider for system environment variables
 ///
 /// This provider reads configuration values from the process environment.
@@ -158,11 +223,7 @@ impl ConfigProvider for EnvProvider {
             Ok(value) => {
                 let mut pv = ProviderValue::new(value, "env");
                 // Mark as secret if the key contains sensitive words
-                if key.to_lowercase().contains("secret")
-                    || key.to_lowercase().contains("password")
-                    || key.to_lowercase().contains("token")
-                    || key.to_lowercase().contains("key")
-                {
+                if is_sensitive_key(key) {
                     pv.metadata.is_secret = true;
                 }
                 Ok(pv)
@@ -183,25 +244,16 @@ impl ConfigProvider for EnvProvider {
 
     async fn list(&self, namespace: &str, prefix: Option<&str>) -> ProviderResult<HashMap<String, ProviderValue>> {
         let mut result = HashMap::new();
-        let ns_prefix = self.naming.build_name(namespace, "");
 
         for (name, value) in std::env::vars() {
-            let name_upper = if self.naming.uppercase {
-                name.to_uppercase()
-            } else {
-                name.clone()
-            };
-
-            if name_upper.starts_with(&ns_prefix) {
-                if let Some((_, key)) = self.naming.parse_name(&name) {
-                    // Apply prefix filter if specified
-                    if let Some(p) = prefix {
-                        if !key.starts_with(&p.to_lowercase()) {
-                            continue;
-                        }
+            if let Some(key) = self.naming.key_in_namespace(namespace, &name) {
+                // Apply prefix filter if specified
+                if let Some(p) = prefix {
+                    if !key.starts_with(&self.naming.normalize(p)) {
+                        continue;
                     }
-                    result.insert(key, ProviderValue::new(value, "env"));
                 }
+                result.insert(key, ProviderValue::new(value, "env"));
             }
         }
 
@@ -391,18 +443,15 @@ impl ConfigProvider for DotEnvProvider {
         })?;
 
         let mut result = HashMap::new();
-        let ns_prefix = self.naming.build_name(namespace, "");
 
         for (name, value) in cache.iter() {
-            if name.starts_with(&ns_prefix) {
-                if let Some((_, key)) = self.naming.parse_name(name) {
-                    if let Some(p) = prefix {
-                        if !key.starts_with(&p.to_lowercase()) {
-                            continue;
-                        }
+            if let Some(key) = self.naming.key_in_namespace(namespace, name) {
+                if let Some(p) = prefix {
+                    if !key.starts_with(&self.naming.normalize(p)) {
+                        continue;
                     }
-                    result.insert(key, ProviderValue::new(value.clone(), "dotenv"));
                 }
+                result.insert(key, ProviderValue::new(value.clone(), "dotenv"));
             }
         }
 
@@ -420,6 +469,10 @@ impl ConfigProvider for DotEnvProvider {
             Ok(ProviderHealth::unhealthy("dotenv", format!("File not found: {}", self.path.display())))
         }
     }
+
+    fn watch_paths(&self) -> Vec<PathBuf> {
+        vec![self.path.clone()]
+    }
 }
 
 #[cfg(test)]
@@ -454,6 +507,45 @@ mod tests {
         assert_eq!(config.build_name("app/config", "db/host"), "APP__CONFIG__DB__HOST");
     }
 
+    #[test]
+    fn test_naming_parse_with_prefix_and_nested_key() {
+        let config = EnvNamingConfig::with_prefix("APP");
+        assert_eq!(
+            config.parse_name("APP__DATABASE__POOL__SIZE"),
+            Some(("database".to_string(), "pool.size".to_string()))
+        );
+        assert_eq!(config.parse_name("OTHER__DATABASE__HOST"), None);
+        assert_eq!(config.parse_name("APP__DATABASE"), None);
+
+        let preserving = EnvNamingConfig::with_prefix("APP").with_lowercase_keys(false);
+        assert_eq!(
+            preserving.parse_name("app__Database__Host"),
+            Some(("Database".to_string(), "Host".to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_env_provider_prefix_convention() {
+        std::env::set_var("APP__DATABASE__HOST", "x");
+        std::env::set_var("APP__DATABASE__POOL__SIZE", "10");
+        std::env::set_var("APP__CACHE__HOST", "y");
+
+        let provider = EnvProvider::with_prefix("APP");
+        let host = provider.get("database", "host").await;
+        let listed = provider.list("database", None).await.unwrap();
+        let pool = provider.get("database", "pool.size").await;
+
+        std::env::remove_var("APP__DATABASE__HOST");
+        std::env::remove_var("APP__DATABASE__POOL__SIZE");
+        std::env::remove_var("APP__CACHE__HOST");
+
+        assert_eq!(host.unwrap().value, "x");
+        assert_eq!(pool.unwrap().value, "10");
+        assert_eq!(listed.len(), 2);
+        assert_eq!(listed["host"].value, "x");
+        assert_eq!(listed["pool.size"].value, "10");
+    }
+
     #[tokio::test]
     async fn test_env_provider_not_found() {
         let provider = EnvProvider::new();
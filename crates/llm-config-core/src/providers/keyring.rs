@@ -21,6 +21,7 @@
 //! let password = keyring.get("database", "password").await?;
 //! ```
 
+use super::secret::SecretString;
 use super::traits::{
     ConfigProvider, SecretProvider, ProviderError, ProviderResult,
     ProviderValue, ProviderHealth, ValueMetadata,
@@ -116,7 +117,7 @@ impl KeyringProvider {
     }
 
     /// Get a value from the keyring (platform-specific implementation)
-    fn get_from_keyring(&self, full_key: &str) -> ProviderResult<String> {
+    fn get_from_keyring(&self, full_key: &str) -> ProviderResult<SecretString> {
         // Note: This is a stub implementation. A real implementation would use:
         // - macOS: Security.framework via security-framework crate
         // - Windows: CredRead via windows crate
@@ -131,7 +132,7 @@ impl KeyringProvider {
                 // For now, we fall back to environment variables with a KEYRING_ prefix
                 // This allows testing without actual keyring access
                 let env_key = format!("KEYRING_{}", full_key.replace('/', "_").to_uppercase());
-                std::env::var(&env_key).map_err(|_| {
+                std::env::var(&env_key).map(SecretString::from).map_err(|_| {
                     ProviderError::NotFound {
                         namespace: "keyring".to_string(),
                         key: full_key.to_string(),
@@ -216,7 +217,7 @@ impl ConfigProvider for KeyringProvider {
         let value = self.get_from_keyring(&full_key)?;
 
         // All keyring values are considered secrets
-        Ok(ProviderValue::secret(value, "keyring"))
+        Ok(ProviderValue::from_secret(value, "keyring"))
     }
 
     async fn list(&self, namespace: &str, _prefix: Option<&str>) -> ProviderResult<HashMap<String, ProviderValue>> {
@@ -0,0 +1,578 @@
+//! Cloud Instance Metadata Provider
+//!
+//! This module provides an adapter that reads identity and placement
+//! information (region, zone, account/project, instance id) from the cloud
+//! instance metadata service, so environment-aware configuration does not
+//! need these values hardcoded.
+//!
+//! # Supported Platforms
+//!
+//! - **AWS**: IMDSv2 (session token obtained via `PUT /latest/api/token`)
+//! - **GCP**: Compute metadata server (`Metadata-Flavor: Google`)
+//! - **Azure**: Instance Metadata Service (`Metadata: true`)
+//!
+//! # Keys
+//!
+//! Values are exposed in the [`METADATA_NAMESPACE`] namespace. Lookups in
+//! any other namespace return `ProviderError::NotFound`, so this provider
+//! can sit in a `ProviderChain` without shadowing other sources.
+//!
+//! | Key               | AWS | GCP | Azure |
+//! |-------------------|-----|-----|-------|
+//! | `region`          | ✓   | ✓   | ✓     |
+//! | `zone`            | ✓   | ✓   | ✓     |
+//! | `instance_id`     | ✓   | ✓   | ✓     |
+//! | `account_id`      | ✓   |     |       |
+//! | `project_id`      |     | ✓   |       |
+//! | `subscription_id` |     |     | ✓     |
+//! | `resource_group`  |     |     | ✓     |
+//!
+//! Metadata services are link-local and respond quickly, so requests use a
+//! short timeout (one second by default) to fail fast off-cloud. Requests
+//! bypass any configured HTTP proxy, the AWS session token is reused until
+//! shortly before it expires, and Azure `list` reads all keys from a single
+//! instance document.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use llm_config_core::providers::{CloudPlatform, InstanceMetadataProvider};
+//!
+//! let provider = InstanceMetadataProvider::for_platform(CloudPlatform::Aws)?;
+//! let region = provider.get("instance", "region").await?;
+//! ```
+
+use super::traits::{ConfigProvider, ProviderError, ProviderResult, ProviderValue};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Namespace under which instance metadata values are exposed
+pub const METADATA_NAMESPACE: &str = "instance";
+
+/// Keys this provider can resolve (availability varies by platform)
+pub const METADATA_KEYS: &[&str] = &[
+    "region",
+    "zone",
+    "instance_id",
+    "account_id",
+    "project_id",
+    "subscription_id",
+    "resource_group",
+];
+
+const AWS_TOKEN_TTL_SECONDS: u64 = 21600;
+
+/// Refresh the IMDSv2 session token this long before it expires
+const AWS_TOKEN_REFRESH_MARGIN: Duration = Duration::from_secs(60);
+const AZURE_API_VERSION: &str = "2021-02-01";
+
+/// Cloud platform whose metadata service is queried
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CloudPlatform {
+    /// Amazon Web Services (IMDSv2)
+    Aws,
+    /// Google Cloud Platform
+    Gcp,
+    /// Microsoft Azure
+    Azure,
+}
+
+impl CloudPlatform {
+    /// Default metadata service endpoint for this platform
+    pub fn default_endpoint(&self) -> &'static str {
+        match self {
+            CloudPlatform::Aws => "http://169.254.169.254",
+            CloudPlatform::Gcp => "http://metadata.google.internal",
+            CloudPlatform::Azure => "http://169.254.169.254",
+        }
+    }
+}
+
+/// Configuration for the instance metadata provider
+#[derive(Debug, Clone)]
+pub struct InstanceMetadataConfig {
+    /// Platform whose metadata service is queried
+    pub platform: CloudPlatform,
+    /// Base URL of the metadata service
+    pub endpoint: String,
+    /// Per-request timeout
+    pub timeout: Duration,
+}
+
+impl InstanceMetadataConfig {
+    /// Create a configuration using the platform's default endpoint
+    pub fn new(platform: CloudPlatform) -> Self {
+        Self {
+            platform,
+            endpoint: platform.default_endpoint().to_string(),
+            timeout: Duration::from_secs(1),
+        }
+    }
+
+    /// Override the metadata service endpoint
+    pub fn with_endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.endpoint = endpoint.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Set the per-request timeout
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+}
+
+/// Provider that reads configuration from the cloud instance metadata service
+#[derive(Debug)]
+pub struct InstanceMetadataProvider {
+    config: InstanceMetadataConfig,
+    client: reqwest::Client,
+    /// Cached IMDSv2 session token and the instant it expires
+    aws_token: Mutex<Option<(String, Instant)>>,
+}
+
+impl InstanceMetadataProvider {
+    /// Create a new instance metadata provider
+    pub fn new(config: InstanceMetadataConfig) -> ProviderResult<Self> {
+        // Metadata services are link-local; a proxy would never reach them
+        let client = reqwest::Client::builder()
+            .timeout(config.timeout)
+            .connect_timeout(config.timeout)
+            .no_proxy()
+            .build()
+            .map_err(|e| ProviderError::ConfigurationError(e.to_string()))?;
+
+        Ok(Self {
+            config,
+            client,
+            aws_token: Mutex::new(None),
+        })
+    }
+
+    /// Create a provider for a platform using its default endpoint
+    pub fn for_platform(platform: CloudPlatform) -> ProviderResult<Self> {
+        Self::new(InstanceMetadataConfig::new(platform))
+    }
+
+    /// Fetch a single metadata value for the configured platform
+    async fn fetch(&self, key: &str) -> ProviderResult<Option<String>> {
+        match self.config.platform {
+            CloudPlatform::Aws => self.fetch_aws(key).await,
+            CloudPlatform::Gcp => self.fetch_gcp(key).await,
+            CloudPlatform::Azure => self.fetch_azure(key).await,
+        }
+    }
+
+    async fn fetch_aws(&self, key: &str) -> ProviderResult<Option<String>> {
+        let path = match key {
+            "region" => "/latest/meta-data/placement/region",
+            "zone" => "/latest/meta-data/placement/availability-zone",
+            "instance_id" => "/latest/meta-data/instance-id",
+            "account_id" => "/latest/dynamic/instance-identity/document",
+            _ => return Ok(None),
+        };
+
+        let token = self.aws_token().await?;
+        let body = self
+            .send(
+                self.client
+                    .get(format!("{}{}", self.config.endpoint, path))
+                    .header("X-aws-ec2-metadata-token", token),
+            )
+            .await?;
+
+        if key == "account_id" {
+            let document: serde_json::Value = serde_json::from_str(&body)
+                .map_err(|e| ProviderError::SerializationError(e.to_string()))?;
+            return Ok(document["accountId"].as_str().map(str::to_string));
+        }
+
+        Ok(Some(body.trim().to_string()))
+    }
+
+    /// IMDSv2 session token, reused until shortly before it expires
+    async fn aws_token(&self) -> ProviderResult<String> {
+        let now = Instant::now();
+        if let Some((token, expires_at)) = self.aws_token.lock().unwrap().as_ref() {
+            if now + AWS_TOKEN_REFRESH_MARGIN < *expires_at {
+                return Ok(token.clone());
+            }
+        }
+
+        // IMDSv2 requires a session token on every metadata request
+        let token = self
+            .send(
+                self.client
+                    .put(format!("{}/latest/api/token", self.config.endpoint))
+                    .header(
+                        "X-aws-ec2-metadata-token-ttl-seconds",
+                        AWS_TOKEN_TTL_SECONDS.to_string(),
+                    ),
+            )
+            .await?
+            .trim()
+            .to_string();
+
+        *self.aws_token.lock().unwrap() = Some((
+            token.clone(),
+            now + Duration::from_secs(AWS_TOKEN_TTL_SECONDS),
+        ));
+        Ok(token)
+    }
+
+    async fn fetch_gcp(&self, key: &str) -> ProviderResult<Option<String>> {
+        let path = match key {
+            "project_id" => "/computeMetadata/v1/project/project-id",
+            "instance_id" => "/computeMetadata/v1/instance/id",
+            "zone" | "region" => "/computeMetadata/v1/instance/zone",
+            _ => return Ok(None),
+        };
+
+        let body = self
+            .send(
+                self.client
+                    .get(format!("{}{}", self.config.endpoint, path))
+                    .header("Metadata-Flavor", "Google"),
+            )
+            .await?;
+        let body = body.trim();
+
+        // Zones come back as `projects/{number}/zones/{zone}`
+        let zone = body.rsplit('/').next().unwrap_or(body);
+        Ok(Some(match key {
+            "zone" => zone.to_string(),
+            "region" => zone
+                .rsplit_once('-')
+                .map(|(region, _)| region)
+                .unwrap_or(zone)
+                .to_string(),
+            _ => body.to_string(),
+        }))
+    }
+
+    async fn fetch_azure(&self, key: &str) -> ProviderResult<Option<String>> {
+        if azure_field(key).is_none() {
+            return Ok(None);
+        }
+        let document = self.fetch_azure_document().await?;
+        Ok(azure_value(&document, key))
+    }
+
+    /// Fetch the Azure IMDS instance document, which holds every Azure key
+    async fn fetch_azure_document(&self) -> ProviderResult<serde_json::Value> {
+        let body = self
+            .send(
+                self.client
+                    .get(format!(
+                        "{}/metadata/instance?api-version={}",
+                        self.config.endpoint, AZURE_API_VERSION
+                    ))
+                    .header("Metadata", "true"),
+            )
+            .await?;
+
+        serde_json::from_str(&body).map_err(|e| ProviderError::SerializationError(e.to_string()))
+    }
+
+    async fn send(&self, request: reqwest::RequestBuilder) -> ProviderResult<String> {
+        let response = request.send().await.map_err(|e| {
+            if e.is_timeout() {
+                ProviderError::Timeout(format!("Instance metadata service: {}", e))
+            } else {
+                ProviderError::ConnectionError(format!("Instance metadata service: {}", e))
+            }
+        })?;
+
+        let status = response.status();
+        if status == reqwest::StatusCode::NOT_FOUND {
+            return Err(ProviderError::NotFound {
+                namespace: METADATA_NAMESPACE.to_string(),
+                key: response.url().path().to_string(),
+            });
+        }
+        if status == reqwest::StatusCode::UNAUTHORIZED || status == reqwest::StatusCode::FORBIDDEN {
+            return Err(ProviderError::AuthenticationFailed(format!(
+                "Instance metadata service returned {}",
+                status
+            )));
+        }
+        if !status.is_success() {
+            return Err(ProviderError::Unavailable(format!(
+                "Instance metadata service returned {}",
+                status
+            )));
+        }
+
+        response
+            .text()
+            .await
+            .map_err(|e| ProviderError::ConnectionError(e.to_string()))
+    }
+}
+
+/// Field of the Azure IMDS `compute` object holding `key`
+fn azure_field(key: &str) -> Option<&'static str> {
+    match key {
+        "region" => Some("location"),
+        "zone" => Some("zone"),
+        "instance_id" => Some("vmId"),
+        "subscription_id" => Some("subscriptionId"),
+        "resource_group" => Some("resourceGroupName"),
+        _ => None,
+    }
+}
+
+/// Non-empty value for `key` in an Azure IMDS instance document
+fn azure_value(document: &serde_json::Value, key: &str) -> Option<String> {
+    document["compute"][azure_field(key)?]
+        .as_str()
+        .filter(|v| !v.is_empty())
+        .map(str::to_string)
+}
+
+#[async_trait::async_trait]
+impl ConfigProvider for InstanceMetadataProvider {
+    fn name(&self) -> &str {
+        "instance_metadata"
+    }
+
+    async fn is_available(&self) -> bool {
+        self.fetch("instance_id").await.map(|v| v.is_some()).unwrap_or(false)
+    }
+
+    async fn get(&self, namespace: &str, key: &str) -> ProviderResult<ProviderValue> {
+        let not_found = || ProviderError::NotFound {
+            namespace: namespace.to_string(),
+            key: key.to_string(),
+        };
+
+        if namespace != METADATA_NAMESPACE {
+            return Err(not_found());
+        }
+
+        let value = match self.fetch(key).await {
+            Ok(Some(value)) => value,
+            Ok(None) | Err(ProviderError::NotFound { .. }) => return Err(not_found()),
+            Err(e) => return Err(e),
+        };
+
+        Ok(ProviderValue::new(value, "instance_metadata"))
+    }
+
+    async fn list(
+        &self,
+        namespace: &str,
+        prefix: Option<&str>,
+    ) -> ProviderResult<HashMap<String, ProviderValue>> {
+        let mut result = HashMap::new();
+        if namespace != METADATA_NAMESPACE {
+            return Ok(result);
+        }
+
+        // Azure serves every key from one document, so fetch it once
+        if self.config.platform == CloudPlatform::Azure {
+            let document = match self.fetch_azure_document().await {
+                Ok(document) => document,
+                Err(ProviderError::NotFound { .. }) => return Ok(result),
+                Err(e) => return Err(e),
+            };
+            for key in METADATA_KEYS {
+                if prefix.is_some_and(|p| !key.starts_with(p)) {
+                    continue;
+                }
+                if let Some(value) = azure_value(&document, key) {
+                    result.insert(
+                        key.to_string(),
+                        ProviderValue::new(value, "instance_metadata"),
+                    );
+                }
+            }
+            return Ok(result);
+        }
+
+        for key in METADATA_KEYS {
+            if prefix.is_some_and(|p| !key.starts_with(p)) {
+                continue;
+            }
+            match self.get(namespace, key).await {
+                Ok(value) => {
+                    result.insert(key.to_string(), value);
+                }
+                Err(ProviderError::NotFound { .. }) => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    /// Serve canned metadata responses; `respond` maps the raw request to
+    /// a status and body
+    async fn mock_metadata_server<F>(respond: F) -> String
+    where
+        F: Fn(&str) -> (u16, String) + Send + Sync + 'static,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let respond = Arc::new(respond);
+
+        tokio::spawn(async move {
+            loop {
+                let Ok((mut socket, _)) = listener.accept().await else {
+                    break;
+                };
+                let respond = respond.clone();
+                tokio::spawn(async move {
+                    let mut buf = vec![0u8; 8192];
+                    let n = socket.read(&mut buf).await.unwrap_or(0);
+                    let request = String::from_utf8_lossy(&buf[..n]).to_string();
+                    let (status, body) = respond(&request);
+                    let response = format!(
+                        "HTTP/1.1 {} X\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                        status,
+                        body.len(),
+                        body
+                    );
+                    let _ = socket.write_all(response.as_bytes()).await;
+                });
+            }
+        });
+
+        format!("http://{}", addr)
+    }
+
+    fn provider(platform: CloudPlatform, endpoint: String) -> InstanceMetadataProvider {
+        InstanceMetadataProvider::new(
+            InstanceMetadataConfig::new(platform).with_endpoint(endpoint),
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_aws_imdsv2_requires_token() {
+        let token_requests = Arc::new(AtomicUsize::new(0));
+        let counter = token_requests.clone();
+        let endpoint = mock_metadata_server(move |request| {
+            let lower = request.to_lowercase();
+            if request.starts_with("PUT /latest/api/token") {
+                counter.fetch_add(1, Ordering::SeqCst);
+                if lower.contains("x-aws-ec2-metadata-token-ttl-seconds: 21600") {
+                    (200, "test-token".to_string())
+                } else {
+                    (400, String::new())
+                }
+            } else if !lower.contains("x-aws-ec2-metadata-token: test-token") {
+                (401, String::new())
+            } else if request.starts_with("GET /latest/meta-data/placement/region") {
+                (200, "us-west-2".to_string())
+            } else if request.starts_with("GET /latest/dynamic/instance-identity/document") {
+                (200, r#"{"accountId":"123456789012","region":"us-west-2"}"#.to_string())
+            } else {
+                (404, String::new())
+            }
+        })
+        .await;
+        let provider = provider(CloudPlatform::Aws, endpoint);
+
+        let region = provider.get(METADATA_NAMESPACE, "region").await.unwrap();
+        assert_eq!(region.value, "us-west-2");
+        assert_eq!(region.metadata.source, "instance_metadata");
+        assert!(!region.metadata.is_secret);
+
+        let account = provider.get(METADATA_NAMESPACE, "account_id").await.unwrap();
+        assert_eq!(account.value, "123456789012");
+        assert_eq!(token_requests.load(Ordering::SeqCst), 1);
+
+        assert!(matches!(
+            provider.get(METADATA_NAMESPACE, "instance_id").await,
+            Err(ProviderError::NotFound { .. })
+        ));
+        assert!(matches!(
+            provider.get("database", "region").await,
+            Err(ProviderError::NotFound { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_gcp_zone_and_region() {
+        let endpoint = mock_metadata_server(|request| {
+            if !request.to_lowercase().contains("metadata-flavor: google") {
+                (403, String::new())
+            } else if request.starts_with("GET /computeMetadata/v1/instance/zone") {
+                (200, "projects/42/zones/us-central1-a".to_string())
+            } else if request.starts_with("GET /computeMetadata/v1/project/project-id") {
+                (200, "my-project".to_string())
+            } else {
+                (404, String::new())
+            }
+        })
+        .await;
+        let provider = provider(CloudPlatform::Gcp, endpoint);
+
+        let values = provider.list(METADATA_NAMESPACE, None).await.unwrap();
+        assert_eq!(values["zone"].value, "us-central1-a");
+        assert_eq!(values["region"].value, "us-central1");
+        assert_eq!(values["project_id"].value, "my-project");
+        assert!(!values.contains_key("account_id"));
+    }
+
+    #[tokio::test]
+    async fn test_azure_imds_compute_fields() {
+        let document_requests = Arc::new(AtomicUsize::new(0));
+        let counter = document_requests.clone();
+        let endpoint = mock_metadata_server(move |request| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            if request.starts_with("GET /metadata/instance?api-version=2021-02-01")
+                && request.to_lowercase().contains("metadata: true")
+            {
+                (
+                    200,
+                    r#"{"compute":{"location":"westeurope","vmId":"vm-1","subscriptionId":"sub-1","resourceGroupName":"rg-1","zone":""}}"#
+                        .to_string(),
+                )
+            } else {
+                (400, String::new())
+            }
+        })
+        .await;
+        let provider = provider(CloudPlatform::Azure, endpoint);
+
+        assert_eq!(provider.get(METADATA_NAMESPACE, "region").await.unwrap().value, "westeurope");
+        assert_eq!(provider.get(METADATA_NAMESPACE, "resource_group").await.unwrap().value, "rg-1");
+        assert!(matches!(
+            provider.get(METADATA_NAMESPACE, "zone").await,
+            Err(ProviderError::NotFound { .. })
+        ));
+
+        // `list` reads every key from a single document request
+        document_requests.store(0, Ordering::SeqCst);
+        let values = provider.list(METADATA_NAMESPACE, None).await.unwrap();
+        assert_eq!(values.len(), 4);
+        assert_eq!(values["subscription_id"].value, "sub-1");
+        assert!(!values.contains_key("zone"));
+        assert_eq!(document_requests.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_unreachable_service_times_out_quickly() {
+        let config = InstanceMetadataConfig::new(CloudPlatform::Gcp)
+            .with_endpoint("http://127.0.0.1:9")
+            .with_timeout(Duration::from_millis(200));
+        let provider = InstanceMetadataProvider::new(config).unwrap();
+
+        let start = std::time::Instant::now();
+        assert!(provider.get(METADATA_NAMESPACE, "zone").await.is_err());
+        assert!(!provider.is_available().await);
+        assert!(start.elapsed() < Duration::from_secs(2));
+    }
+}
@@ -10,8 +10,11 @@
 //! - **Environment Variables**: Load from `.env` files and raw environment
 //! - **OS Keyring**: Platform-specific secure credential storage
 //! - **Local Encrypted Files**: AES-encrypted local config files
-//! - **Config Bundles**: JSON, TOML, and YAML configuration files
+//! - **Config Bundles**: JSON, TOML, and YAML configuration files (single files or
+//!   merged directories)
 //! - **Cloud Secret Managers**: AWS SSM/Secrets Manager, GCP Secret Manager, Azure Key Vault
+//! - **Instance Metadata**: Region/identity from AWS, GCP, and Azure metadata services
+//!   (`instance-metadata` feature)
 //!
 //! # Architecture
 //!
@@ -35,6 +38,7 @@
 //! ```
 
 pub mod traits;
+pub mod secret;
 pub mod env;
 pub mod keyring;
 pub mod encrypted;
@@ -42,19 +46,29 @@ pub mod bundles;
 pub mod cloud;
 pub mod vault;
 pub mod chain;
+#[cfg(feature = "instance-metadata")]
+pub mod metadata;
 
 // Re-export core types
 pub use traits::{ConfigProvider, SecretProvider, ProviderError, ProviderResult};
-pub use chain::ProviderChain;
+pub use secret::SecretString;
+pub use chain::{ProviderChain, ChainRefreshReport, ConfigChange, RefreshHandle, MIN_REFRESH_INTERVAL};
 
 // Re-export provider implementations
 pub use env::{EnvProvider, DotEnvProvider};
 pub use keyring::KeyringProvider;
 pub use encrypted::EncryptedFileProvider;
-pub use bundles::{JsonProvider, TomlProvider, YamlProvider, BundleProvider};
+pub use bundles::{
+    JsonProvider, TomlProvider, YamlProvider, BundleProvider, MergedBundleProvider,
+    ArrayFlattening,
+};
 pub use cloud::{
     AwsSsmProvider, AwsSecretsManagerProvider,
     GcpSecretManagerProvider, AzureKeyVaultProvider,
-    CloudProviderConfig,
+    CloudProviderConfig, CloudSecretClient,
 };
+#[cfg(feature = "cloud")]
+pub use cloud::{KeyVaultCertificate, KeyVaultKey};
 pub use vault::{VaultProvider, VaultConfig, VaultAuthMethod};
+#[cfg(feature = "instance-metadata")]
+pub use metadata::{CloudPlatform, InstanceMetadataConfig, InstanceMetadataProvider};
@@ -0,0 +1,83 @@
+//! In-memory handling of secret values
+//!
+//! Secrets fetched by providers are held in a [`SecretString`], which
+//! overwrites its buffer when dropped and never prints its contents through
+//! `Debug`. The plaintext is only reachable through [`SecretString::expose`].
+
+use std::fmt;
+use zeroize::Zeroizing;
+
+/// Placeholder printed instead of a secret value
+pub const REDACTED: &str = "[REDACTED]";
+
+/// A string that is zeroized on drop and redacted in `Debug` output
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct SecretString(Zeroizing<String>);
+
+impl SecretString {
+    /// Wrap a secret value
+    pub fn new(value: impl Into<String>) -> Self {
+        Self(Zeroizing::new(value.into()))
+    }
+
+    /// Borrow the plaintext value
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+
+    /// Length of the value in bytes
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Whether the value is empty
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Move the plaintext out without copying it
+    ///
+    /// The caller takes over responsibility for scrubbing the returned buffer.
+    pub(crate) fn into_inner(mut self) -> String {
+        std::mem::take(&mut *self.0)
+    }
+}
+
+impl From<String> for SecretString {
+    fn from(value: String) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<&str> for SecretString {
+    fn from(value: &str) -> Self {
+        Self::new(value)
+    }
+}
+
+impl fmt::Debug for SecretString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SecretString").field(&REDACTED).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_debug_redacts_value() {
+        let secret = SecretString::new("hunter2");
+        let debug = format!("{:?}", secret);
+        assert!(!debug.contains("hunter2"));
+        assert!(debug.contains(REDACTED));
+    }
+
+    #[test]
+    fn test_expose_returns_value() {
+        let secret = SecretString::from("hunter2");
+        assert_eq!(secret.expose(), "hunter2");
+        assert_eq!(secret.len(), 7);
+        assert_eq!(secret.clone().into_inner(), "hunter2");
+    }
+}
@@ -6,7 +6,11 @@
 
 use std::collections::HashMap;
 use std::fmt;
+use std::path::PathBuf;
 use thiserror::Error;
+use zeroize::Zeroize;
+
+use super::secret::{SecretString, REDACTED};
 
 /// Errors that can occur when interacting with configuration providers
 #[derive(Error, Debug)]
@@ -54,6 +58,15 @@ pub enum ProviderError {
     #[error("Timeout: {0}")]
     Timeout(String),
 
+    /// A value exceeded the provider's size limit
+    #[error("Value too large: {namespace}/{key} is {size} bytes (limit {limit})")]
+    TooLarge {
+        namespace: String,
+        key: String,
+        size: usize,
+        limit: usize,
+    },
+
     /// Generic provider error
     #[error("Provider error: {0}")]
     Other(String),
@@ -78,12 +91,18 @@ pub struct ValueMetadata {
 }
 
 /// A configuration value with associated metadata
-#[derive(Debug, Clone)]
+///
+/// Secret values (`metadata.is_secret`) are zeroized when dropped and
+/// redacted in `Debug` output.
+#[derive(Clone)]
 pub struct ProviderValue {
     /// The raw string value
     pub value: String,
     /// Metadata about the value
     pub metadata: ValueMetadata,
+    /// The value with its source type, when the backend knows it
+    /// (e.g. JSON/TOML/YAML bundles)
+    pub typed_value: Option<serde_json::Value>,
 }
 
 impl ProviderValue {
@@ -95,6 +114,7 @@ impl ProviderValue {
                 source: source.into(),
                 ..Default::default()
             },
+            typed_value: None,
         }
     }
 
@@ -107,9 +127,20 @@ impl ProviderValue {
                 is_secret: true,
                 ..Default::default()
             },
+            typed_value: None,
         }
     }
 
+    /// Create a secret value, taking ownership of the secret's buffer
+    pub fn from_secret(value: SecretString, source: impl Into<String>) -> Self {
+        Self::secret(value.into_inner(), source)
+    }
+
+    /// Copy the value into a [`SecretString`]
+    pub fn to_secret(&self) -> SecretString {
+        SecretString::new(self.value.as_str())
+    }
+
     /// Add version metadata
     pub fn with_version(mut self, version: impl Into<String>) -> Self {
         self.metadata.version = Some(version.into());
@@ -121,6 +152,48 @@ impl ProviderValue {
         self.metadata.last_modified = Some(timestamp);
         self
     }
+
+    /// Attach the typed representation of the value
+    pub fn with_typed(mut self, typed: serde_json::Value) -> Self {
+        self.typed_value = Some(typed);
+        self
+    }
+
+    /// The value as JSON, preserving its source type where known
+    ///
+    /// Backends that only deal in strings (environment variables, secret
+    /// managers) yield a JSON string.
+    pub fn typed(&self) -> serde_json::Value {
+        self.typed_value
+            .clone()
+            .unwrap_or_else(|| serde_json::Value::String(self.value.clone()))
+    }
+}
+
+impl fmt::Debug for ProviderValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("ProviderValue");
+        if self.metadata.is_secret {
+            debug
+                .field("value", &REDACTED)
+                .field("metadata", &self.metadata)
+                .field("typed_value", &self.typed_value.as_ref().map(|_| REDACTED));
+        } else {
+            debug
+                .field("value", &self.value)
+                .field("metadata", &self.metadata)
+                .field("typed_value", &self.typed_value);
+        }
+        debug.finish()
+    }
+}
+
+impl Drop for ProviderValue {
+    fn drop(&mut self) {
+        if self.metadata.is_secret {
+            self.value.zeroize();
+        }
+    }
 }
 
 /// Core trait for configuration providers
@@ -198,6 +271,15 @@ pub trait ConfigProvider: Send + Sync + fmt::Debug {
     fn health_check(&self) -> ProviderResult<ProviderHealth> {
         Ok(ProviderHealth::healthy(self.name()))
     }
+
+    /// Files or directories this provider reads from
+    ///
+    /// Used by `ProviderChain::spawn_watch` to refresh when the source
+    /// changes on disk. Default implementation returns nothing (for providers
+    /// not backed by local files).
+    fn watch_paths(&self) -> Vec<PathBuf> {
+        Vec::new()
+    }
 }
 
 /// Trait for providers that can also store/write secrets
@@ -297,6 +379,31 @@ mod tests {
         assert!(value.metadata.is_secret);
     }
 
+    #[test]
+    fn test_secret_value_is_redacted_in_debug() {
+        let value = ProviderValue::from_secret(SecretString::new("hunter2"), "keyring")
+            .with_typed(serde_json::json!("hunter2"));
+        assert_eq!(value.value, "hunter2");
+        assert_eq!(value.to_secret().expose(), "hunter2");
+
+        let debug = format!("{:?}", value);
+        assert!(!debug.contains("hunter2"), "{}", debug);
+        assert!(debug.contains(REDACTED));
+
+        let plain = ProviderValue::new("visible", "env");
+        assert!(format!("{:?}", plain).contains("visible"));
+    }
+
+    #[test]
+    fn test_typed_value_defaults_to_string() {
+        let value = ProviderValue::new("42", "env");
+        assert_eq!(value.typed(), serde_json::json!("42"));
+
+        let value = ProviderValue::new("42", "yaml").with_typed(serde_json::json!(42));
+        assert_eq!(value.typed(), serde_json::json!(42));
+        assert_eq!(value.value, "42");
+    }
+
     #[test]
     fn test_provider_error_display() {
         let err = ProviderError::NotFound {